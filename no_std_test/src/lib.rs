@@ -0,0 +1,55 @@
+//! The color conversions that are tested without `std`.
+//!
+//! This is built for `thumbv6m-none-eabi`, which is a Cortex-M0 without a
+//! floating point unit. The float arithmetic is then done by the compiler's
+//! built-in soft-float routines, and the more complex functions by `libm`, or
+//! by palette's own implementations if the `deterministic` feature is
+//! enabled.
+
+#![no_std]
+
+use palette::{
+    FromColor, Hsl, Hsluv, Hsv, Hwb, Lab, Lch, Lchuv, LinSrgb, Luv, Oklab, Oklch, Srgb, SrgbLuma,
+    Xyz, Yxy,
+};
+
+/// Runs a color through every color space, to make sure all of the float
+/// operations they depend on are available without `std`.
+pub fn convert_all(color: Srgb<u8>) -> Srgb<u8> {
+    let rgb: Srgb = color.into_format();
+    let linear: LinSrgb = rgb.into_linear();
+
+    let xyz = Xyz::from_color(linear);
+    let yxy = Yxy::from_color(xyz);
+    let lab = Lab::from_color(yxy);
+    let lch = Lch::from_color(lab);
+    let luv = Luv::from_color(lch);
+    let lchuv = Lchuv::from_color(luv);
+    let hsluv = Hsluv::from_color(lchuv);
+    let oklab = Oklab::from_color(hsluv);
+    let oklch = Oklch::from_color(oklab);
+    let hsl = Hsl::from_color(Srgb::from_color(oklch));
+    let hsv = Hsv::from_color(hsl);
+    let hwb = Hwb::from_color(hsv);
+    let _luma = SrgbLuma::from_color(hwb);
+
+    Srgb::from_color(hwb).into_format()
+}
+
+#[cfg(test)]
+mod test {
+    use super::convert_all;
+    use palette::Srgb;
+
+    #[test]
+    fn round_trip() {
+        for &color in &[
+            Srgb::new(255u8, 0, 255),
+            Srgb::new(0, 0, 0),
+            Srgb::new(255, 255, 255),
+            Srgb::new(12, 200, 93),
+        ] {
+            assert_eq!(convert_all(color), color);
+        }
+    }
+}
@@ -6,12 +6,19 @@ use core::panic::PanicInfo;
 
 extern crate libc;
 
+#[cfg(feature = "nightly")]
+use no_std_test::convert_all;
+
 #[cfg(feature = "nightly")]
 #[start]
 fn start(_argc: isize, _argv: *const *const u8) -> isize {
-    let _magenta = palette::Srgb::new(255u8, 0, 255);
+    let magenta = palette::Srgb::new(255u8, 0, 255);
 
-    0
+    if convert_all(magenta) == magenta {
+        0
+    } else {
+        1
+    }
 }
 
 #[cfg(feature = "nightly")]
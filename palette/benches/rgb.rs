@@ -1,6 +1,7 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use palette::convert::FromColorUnclamped;
 use palette::encoding;
+use palette::rgb::Rgb;
 use palette::{Hsl, Hsv, Hwb, IntoColor, LinSrgb, Srgb};
 
 type SrgbHsv = Hsv<encoding::Srgb>;
@@ -17,6 +18,7 @@ use data_color_mine::{load_data, ColorMine};
 
 /* Benches the following conversions:
     - rgb to linear
+    - rgb to linear, with the fast transfer function
     - rgb to hsl
     - hsv to hsl
     - rgb to hsv
@@ -33,6 +35,7 @@ use data_color_mine::{load_data, ColorMine};
     - hwb to linear hwb
     - linear hwb to hwb
     - linsrgb to rgb
+    - linsrgb to rgb, with the fast transfer function
     - rgb_u8 to linsrgb_f32
     - linsrgb_f32 to rgb_u8
 */
@@ -53,6 +56,16 @@ fn rgb_conversion(c: &mut Criterion) {
             }
         })
     });
+    group.bench_with_input("rgb to linsrgb (fast)", &colormine, |b, colormine| {
+        b.iter(|| {
+            for c in colormine {
+                black_box(
+                    Rgb::<encoding::SrgbFast, f32>::new(c.rgb.red, c.rgb.green, c.rgb.blue)
+                        .into_linear(),
+                );
+            }
+        })
+    });
     group.bench_with_input("rgb to hsl", &colormine, |b, colormine| {
         b.iter(|| {
             for c in colormine {
@@ -165,6 +178,13 @@ fn rgb_conversion(c: &mut Criterion) {
             }
         })
     });
+    group.bench_with_input("linsrgb to rgb (fast)", &colormine, |b, colormine| {
+        b.iter(|| {
+            for c in colormine {
+                black_box(Rgb::<encoding::SrgbFast, f32>::from_linear(c.linear_rgb));
+            }
+        })
+    });
     group.bench_with_input("rgb_u8 to linsrgb_f32", &rgb_u8, |b, rgb_u8| {
         b.iter(|| {
             for c in rgb_u8 {
@@ -0,0 +1,60 @@
+use std::fs::File;
+use std::io::Write;
+
+pub fn build() {
+    use std::path::Path;
+
+    let out_dir = ::std::env::var("OUT_DIR").unwrap();
+    let dest_path = Path::new(&out_dir).join("srgb_lut.rs");
+    let mut writer = File::create(dest_path).expect("couldn't create srgb_lut.rs");
+
+    let to_linear: Vec<f32> = (0..256)
+        .map(|value| into_linear(value as f64 / 255.0) as f32)
+        .collect();
+
+    // The linear values where the rounded sRGB value goes from `n` to `n + 1`.
+    let thresholds: Vec<f32> = (0..255)
+        .map(|value| into_linear((value as f64 + 0.5) / 255.0) as f32)
+        .collect();
+
+    writeln!(
+        writer,
+        "const SRGB_U8_TO_LINEAR: [f32; 256] = {:?};",
+        to_linear
+    )
+    .unwrap();
+    writeln!(
+        writer,
+        "const LINEAR_TO_SRGB_U8_THRESHOLDS: [f32; 255] = {:?};",
+        thresholds
+    )
+    .unwrap();
+
+    // The first possible sRGB value for each of 4096 equally sized ranges of
+    // linear values, so only a few thresholds have to be checked.
+    let starts: Vec<u8> = (0..4096)
+        .map(|bucket| {
+            let start = bucket as f32 / 4096.0;
+            thresholds
+                .iter()
+                .filter(|&&threshold| threshold <= start)
+                .count() as u8
+        })
+        .collect();
+
+    writeln!(
+        writer,
+        "const LINEAR_TO_SRGB_U8_STARTS: [u8; 4096] = {:?};",
+        starts
+    )
+    .unwrap();
+}
+
+// This is the same calculation as in `encoding::Srgb`.
+fn into_linear(x: f64) -> f64 {
+    if x <= 0.04045 {
+        x / 12.92
+    } else {
+        ((x + 0.055) / 1.055).powf(2.4)
+    }
+}
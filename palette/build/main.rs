@@ -1,5 +1,9 @@
+mod lut;
+mod matrix;
 mod named;
 
 fn main() {
+    lut::build();
+    matrix::build();
     named::build();
 }
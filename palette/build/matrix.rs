@@ -0,0 +1,136 @@
+use std::fs::File;
+use std::io::Write;
+
+type Mat3 = [f64; 9];
+
+/// The sRGB primaries, as `(x, y, Y)`, and the D65 white point, as `XYZ`.
+/// These have to match `encoding::Srgb` and `white_point::D65`.
+const SRGB_PRIMARIES: [[f64; 3]; 3] = [
+    [0.6400, 0.3300, 0.212656],
+    [0.3000, 0.6000, 0.715158],
+    [0.1500, 0.0600, 0.072186],
+];
+const D65: [f64; 3] = [0.95047, 1.0, 1.08883];
+
+/// The Rec. 2020 primaries, as `(x, y, Y)`. These have to match
+/// `encoding::Rec2020`.
+const REC2020_PRIMARIES: [[f64; 3]; 3] = [
+    [0.7080, 0.2920, 0.262722],
+    [0.1700, 0.7970, 0.677989],
+    [0.1310, 0.0460, 0.059289],
+];
+
+/// The Display P3 primaries, as `(x, y, Y)`. These have to match
+/// `encoding::DisplayP3`.
+const DISPLAY_P3_PRIMARIES: [[f64; 3]; 3] = [
+    [0.6800, 0.3200, 0.229004],
+    [0.2650, 0.6900, 0.691727],
+    [0.1500, 0.0600, 0.079270],
+];
+
+/// The ProPhoto RGB primaries, as `(x, y, Y)`, and the D50 white point, as
+/// `XYZ`. These have to match `encoding::ProPhotoRgb` and `white_point::D50`.
+const PROPHOTO_RGB_PRIMARIES: [[f64; 3]; 3] = [
+    [0.7347, 0.2653, 0.288040],
+    [0.1596, 0.8404, 0.711874],
+    [0.0366, 0.0001, 0.000086],
+];
+const D50: [f64; 3] = [0.96422, 1.0, 0.82521];
+
+/// The DCI-P3 primaries, as `(x, y, Y)`, and the DCI white point, as `XYZ`.
+/// These have to match `encoding::DciP3` and `white_point::Dci`.
+const DCI_P3_PRIMARIES: [[f64; 3]; 3] = [
+    [0.6800, 0.3200, 0.209492],
+    [0.2650, 0.6900, 0.721595],
+    [0.1500, 0.0600, 0.068913],
+];
+const DCI: [f64; 3] = [0.894587, 1.0, 0.954416];
+
+pub fn build() {
+    build_space("srgb_matrices.rs", SRGB_PRIMARIES, D65);
+    build_space("rec2020_matrices.rs", REC2020_PRIMARIES, D65);
+    build_space("display_p3_matrices.rs", DISPLAY_P3_PRIMARIES, D65);
+    build_space("prophoto_rgb_matrices.rs", PROPHOTO_RGB_PRIMARIES, D50);
+    build_space("dci_p3_matrices.rs", DCI_P3_PRIMARIES, DCI);
+}
+
+fn build_space(file_name: &str, primaries: [[f64; 3]; 3], white_point: [f64; 3]) {
+    use std::path::Path;
+
+    let out_dir = ::std::env::var("OUT_DIR").unwrap();
+    let dest_path = Path::new(&out_dir).join(file_name);
+    let mut writer =
+        File::create(dest_path).unwrap_or_else(|_| panic!("couldn't create {}", file_name));
+
+    let rgb_to_xyz = rgb_to_xyz_matrix(primaries, white_point);
+    let xyz_to_rgb = matrix_inverse(&rgb_to_xyz);
+
+    write_matrix(&mut writer, "RGB_TO_XYZ", &rgb_to_xyz);
+    write_matrix(&mut writer, "XYZ_TO_RGB", &xyz_to_rgb);
+}
+
+fn write_matrix(writer: &mut File, name: &str, matrix: &Mat3) {
+    writeln!(writer, "const {}: [f64; 9] = {:?};", name, matrix).unwrap();
+}
+
+// This is the same calculation as in `matrix::rgb_to_xyz_matrix`.
+fn rgb_to_xyz_matrix(primaries: [[f64; 3]; 3], white_point: [f64; 3]) -> Mat3 {
+    let [r, g, b] = [
+        yxy_to_xyz(primaries[0]),
+        yxy_to_xyz(primaries[1]),
+        yxy_to_xyz(primaries[2]),
+    ];
+
+    let t = [r[0], g[0], b[0], r[1], g[1], b[1], r[2], g[2], b[2]];
+    let inverse = matrix_inverse(&t);
+    let s = [
+        inverse[0] * white_point[0] + inverse[1] * white_point[1] + inverse[2] * white_point[2],
+        inverse[3] * white_point[0] + inverse[4] * white_point[1] + inverse[5] * white_point[2],
+        inverse[6] * white_point[0] + inverse[7] * white_point[1] + inverse[8] * white_point[2],
+    ];
+
+    [
+        t[0] * s[0],
+        t[1] * s[1],
+        t[2] * s[2],
+        t[3] * s[0],
+        t[4] * s[1],
+        t[5] * s[2],
+        t[6] * s[0],
+        t[7] * s[1],
+        t[8] * s[2],
+    ]
+}
+
+fn yxy_to_xyz([x, y, luma]: [f64; 3]) -> [f64; 3] {
+    [x * luma / y, luma, (1.0 - x - y) * luma / y]
+}
+
+// This is the same calculation as in `matrix::matrix_inverse`.
+fn matrix_inverse(a: &Mat3) -> Mat3 {
+    let d0 = a[4] * a[8] - a[5] * a[7];
+    let d1 = a[3] * a[8] - a[5] * a[6];
+    let d2 = a[3] * a[7] - a[4] * a[6];
+    let det = a[0] * d0 - a[1] * d1 + a[2] * d2;
+    let d3 = a[1] * a[8] - a[2] * a[7];
+    let d4 = a[0] * a[8] - a[2] * a[6];
+    let d5 = a[0] * a[7] - a[1] * a[6];
+    let d6 = a[1] * a[5] - a[2] * a[4];
+    let d7 = a[0] * a[5] - a[2] * a[3];
+    let d8 = a[0] * a[4] - a[1] * a[3];
+
+    assert!(det.is_normal(), "the matrix is not invertible");
+    let det = det.recip();
+
+    [
+        d0 * det,
+        -d3 * det,
+        d6 * det,
+        -d1 * det,
+        d4 * det,
+        -d7 * det,
+        d2 * det,
+        -d5 * det,
+        d8 * det,
+    ]
+}
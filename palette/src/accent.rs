@@ -0,0 +1,255 @@
+//! Resolve accent colors that are readable on a surface.
+//!
+//! An accent color, such as a brand color, is often used for text and icons
+//! on top of a surface. It may not have enough contrast against that surface,
+//! especially when the surface changes between light and dark themes.
+//! [`AccentRules`] resolves a usable color by trying a chain of fallbacks, in
+//! order:
+//!
+//! 1. The accent color itself.
+//! 2. The accent color, lightened or darkened in [`Oklch`] until it has
+//!    enough contrast, if that doesn't change its lightness too much.
+//! 3. Black or white, whichever has the most contrast.
+//!
+//! ```
+//! use palette::accent::{AccentRules, AccentSource};
+//! use palette::{RelativeContrast, Srgb};
+//!
+//! let rules = AccentRules::new();
+//! let accent = Srgb::new(0.95f32, 0.6, 0.1);
+//!
+//! // Orange is too light for text on white, so it's darkened.
+//! let on_white = rules.resolve(accent, Srgb::new(1.0, 1.0, 1.0));
+//! assert_eq!(on_white.source, AccentSource::Adjusted);
+//! assert!(on_white.color.has_min_contrast_text(Srgb::new(1.0, 1.0, 1.0)));
+//!
+//! // It's readable as it is on a dark surface.
+//! let on_dark = rules.resolve(accent, Srgb::new(0.1, 0.1, 0.12));
+//! assert_eq!(on_dark.source, AccentSource::Accent);
+//! ```
+//!
+//! The contrast ratios are the same as for
+//! [`RelativeContrast`](crate::RelativeContrast), and the chroma is reduced
+//! if a color would otherwise fall outside the sRGB gamut.
+
+use crate::convert::{IntoColor, IntoColorUnclamped};
+use crate::interaction::{luminance, with_contrast};
+use crate::theme::fit_chroma;
+use crate::{contrast_ratio, from_f64, FloatComponent, Oklch};
+
+/// Rules for resolving an accent color on a surface.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct AccentRules<T> {
+    /// The minimum contrast ratio against the surface.
+    pub min_contrast: T,
+
+    /// How far the Oklab lightness of the accent color may move before it's
+    /// replaced with black or white.
+    pub max_lightness_shift: T,
+}
+
+impl<T> AccentRules<T>
+where
+    T: FloatComponent,
+{
+    /// Create rules with a minimum contrast of `4.5`, which is the WCAG
+    /// level for normal text, and a maximum lightness shift of `0.3`.
+    pub fn new() -> Self {
+        AccentRules {
+            min_contrast: from_f64(4.5),
+            max_lightness_shift: from_f64(0.3),
+        }
+    }
+
+    /// Change the minimum contrast ratio against the surface.
+    pub fn with_min_contrast(mut self, min_contrast: T) -> Self {
+        self.min_contrast = min_contrast;
+        self
+    }
+
+    /// Change how far the lightness of the accent color may move.
+    pub fn with_max_lightness_shift(mut self, max_lightness_shift: T) -> Self {
+        self.max_lightness_shift = max_lightness_shift;
+        self
+    }
+
+    /// Resolve a color for `accent` on `surface`, in Oklch.
+    pub fn resolve_oklch(
+        &self,
+        accent: Oklch<T>,
+        surface: Oklch<T>,
+    ) -> ResolvedAccent<Oklch<T>, T> {
+        let surface_luminance = luminance(surface);
+        let contrast = |color: Oklch<T>| contrast_ratio(luminance(color), surface_luminance);
+
+        let accent = fit_chroma(accent);
+        let accent_contrast = contrast(accent);
+        if accent_contrast >= self.min_contrast {
+            return ResolvedAccent {
+                color: accent,
+                source: AccentSource::Accent,
+                contrast: accent_contrast,
+            };
+        }
+
+        // Try both directions, since a color with a similar luminance as the
+        // surface may be closer to enough contrast on the other side of it.
+        let mut adjusted: Option<(Oklch<T>, T)> = None;
+        for &darken in &[true, false] {
+            let color = with_contrast(accent, surface_luminance, self.min_contrast, darken);
+            let shift = (color.l - accent.l).abs();
+            let color_contrast = contrast(color);
+
+            let is_better = match adjusted {
+                Some((best, _)) => shift < (best.l - accent.l).abs(),
+                None => true,
+            };
+
+            if color_contrast >= self.min_contrast && shift <= self.max_lightness_shift && is_better
+            {
+                adjusted = Some((color, color_contrast));
+            }
+        }
+
+        if let Some((color, contrast)) = adjusted {
+            return ResolvedAccent {
+                color,
+                source: AccentSource::Adjusted,
+                contrast,
+            };
+        }
+
+        let black = Oklch::new(T::zero(), T::zero(), T::zero());
+        let white = Oklch::new(T::one(), T::zero(), T::zero());
+        let black_contrast = contrast(black);
+        let white_contrast = contrast(white);
+
+        if black_contrast >= white_contrast {
+            ResolvedAccent {
+                color: black,
+                source: AccentSource::Black,
+                contrast: black_contrast,
+            }
+        } else {
+            ResolvedAccent {
+                color: white,
+                source: AccentSource::White,
+                contrast: white_contrast,
+            }
+        }
+    }
+
+    /// Resolve a color for `accent` on `surface`.
+    pub fn resolve<C>(&self, accent: C, surface: C) -> ResolvedAccent<C, T>
+    where
+        C: IntoColorUnclamped<Oklch<T>>,
+        Oklch<T>: IntoColor<C>,
+    {
+        let resolved = self.resolve_oklch(
+            accent.into_color_unclamped(),
+            surface.into_color_unclamped(),
+        );
+
+        ResolvedAccent {
+            color: resolved.color.into_color(),
+            source: resolved.source,
+            contrast: resolved.contrast,
+        }
+    }
+}
+
+impl<T> Default for AccentRules<T>
+where
+    T: FloatComponent,
+{
+    fn default() -> Self {
+        AccentRules::new()
+    }
+}
+
+/// An accent color that was resolved with [`AccentRules`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct ResolvedAccent<C, T> {
+    /// The color to use on the surface.
+    pub color: C,
+
+    /// Which step of the fallback chain the color is from.
+    pub source: AccentSource,
+
+    /// The contrast ratio of the color against the surface. It's below the
+    /// minimum if not even black or white had enough contrast.
+    pub contrast: T,
+}
+
+/// The steps of the fallback chain in [`AccentRules`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub enum AccentSource {
+    /// The accent color had enough contrast as it is.
+    Accent,
+    /// The accent color was lightened or darkened.
+    Adjusted,
+    /// The accent color was replaced with black.
+    Black,
+    /// The accent color was replaced with white.
+    White,
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AccentRules, AccentSource};
+    use crate::{Oklch, RelativeContrast, Srgb};
+
+    #[test]
+    fn fallback_chain() {
+        let rules = AccentRules::<f64>::new();
+        let accent = Oklch::new(0.6, 0.15, 250.0);
+
+        let dark = rules.resolve_oklch(accent, Oklch::new(0.15, 0.0, 0.0));
+        assert_eq!(dark.source, AccentSource::Accent);
+        assert_eq!(dark.color.l, accent.l);
+
+        let light = rules.resolve_oklch(accent, Oklch::new(1.0, 0.0, 0.0));
+        assert_eq!(light.source, AccentSource::Adjusted);
+        assert!(light.color.l < accent.l);
+        assert!(light.color.l > accent.l - 0.3);
+        assert!(light.contrast >= 4.5);
+        assert_eq!(light.color.hue, accent.hue);
+
+        // The shift that's needed is too large.
+        let strict = rules.with_max_lightness_shift(0.01);
+        let gray = strict.resolve_oklch(accent, Oklch::new(1.0, 0.0, 0.0));
+        assert_eq!(gray.source, AccentSource::Black);
+        assert_eq!(gray.color.l, 0.0);
+
+        let on_dark = strict.resolve_oklch(Oklch::new(0.3, 0.1, 30.0), Oklch::new(0.2, 0.0, 0.0));
+        assert_eq!(on_dark.source, AccentSource::White);
+    }
+
+    #[test]
+    fn unreachable_contrast() {
+        let rules = AccentRules::new().with_min_contrast(30.0);
+        let resolved =
+            rules.resolve_oklch(Oklch::new(0.6f64, 0.15, 250.0), Oklch::new(1.0, 0.0, 0.0));
+
+        assert_eq!(resolved.source, AccentSource::Black);
+        assert!(resolved.contrast < 30.0);
+        assert!(resolved.contrast > 20.0);
+    }
+
+    #[test]
+    fn resolve_srgb() {
+        let surface = Srgb::new(1.0f64, 1.0, 1.0);
+        let resolved = AccentRules::new().resolve(Srgb::new(0.3, 0.8, 0.4), surface);
+
+        assert_eq!(resolved.source, AccentSource::Adjusted);
+        assert!(resolved.color.get_contrast_ratio(surface) >= 4.5 - 1e-6);
+        assert_relative_eq!(
+            resolved.color.get_contrast_ratio(surface),
+            resolved.contrast,
+            epsilon = 1e-6
+        );
+    }
+}
@@ -0,0 +1,318 @@
+//! Adjust colors for the ambient light, like a night shift mode.
+//!
+//! Screens that keep their full brightness and a cool white point in a dim,
+//! warm room are tiring to look at. An [`AmbientAdaptation`] turns a
+//! measurement of the ambient light, in lux and as a correlated color
+//! temperature (CCT), into an [`AmbientTransform`] that makes colors warmer
+//! and dimmer to match. The same transform can then be applied to every
+//! color in a theme.
+//!
+//! ```
+//! use palette::ambient::{AmbientAdaptation, AmbientLight};
+//! use palette::Srgb;
+//!
+//! // A room lit by a warm, dim lamp.
+//! let lamp = AmbientLight::new(20.0, 2700.0);
+//! let transform = AmbientAdaptation::new().transform(lamp);
+//!
+//! let white = transform.apply(Srgb::new(1.0f32, 1.0, 1.0));
+//! assert!(white.red > white.blue);
+//! assert!(white.green < 1.0);
+//! ```
+//!
+//! The color temperature is converted to a chromaticity with the cubic
+//! spline approximation of the Planckian locus by Kim et al., which is valid
+//! from 1667 K to 25000 K.
+
+use crate::chromatic_adaptation::{Method, TransformMatrix};
+use crate::convert::IntoColorUnclamped;
+use crate::matrix::{multiply_xyz, Mat3};
+use crate::white_point::{Any, WhitePoint, D65};
+use crate::{clamp, from_f64, Clamp, FloatComponent, LinSrgb, Srgb, Xyz, Yxy};
+
+/// A measurement of the ambient light.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct AmbientLight<T> {
+    /// The illuminance, in lux.
+    pub illuminance: T,
+
+    /// The correlated color temperature, in kelvin.
+    pub cct: T,
+}
+
+impl<T> AmbientLight<T> {
+    /// Create an ambient light measurement from an illuminance in lux and a
+    /// correlated color temperature in kelvin.
+    pub fn new(illuminance: T, cct: T) -> Self {
+        AmbientLight { illuminance, cct }
+    }
+}
+
+/// Settings for adapting colors to the ambient light.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct AmbientAdaptation<T> {
+    /// How much of the difference between the ambient white and D65 to
+    /// follow, from `0.0` to `1.0`. The default is `0.6`, since a full
+    /// adaptation to warm light looks too orange.
+    pub strength: T,
+
+    /// The illuminance, in lux, where the brightness reaches its minimum.
+    /// The default is 10 lux, which is a dim room.
+    pub dim_illuminance: T,
+
+    /// The illuminance, in lux, where the brightness is not reduced at all.
+    /// The default is 500 lux, which is a bright office.
+    pub bright_illuminance: T,
+
+    /// The relative brightness at and below `dim_illuminance`. The default
+    /// is `0.7`.
+    pub min_brightness: T,
+
+    /// The chromatic adaptation method. The default is Bradford.
+    pub method: Method,
+}
+
+impl<T> AmbientAdaptation<T>
+where
+    T: FloatComponent,
+{
+    /// Create the default settings.
+    pub fn new() -> Self {
+        AmbientAdaptation {
+            strength: from_f64(0.6),
+            dim_illuminance: from_f64(10.0),
+            bright_illuminance: from_f64(500.0),
+            min_brightness: from_f64(0.7),
+            method: Method::Bradford,
+        }
+    }
+
+    /// Change the adaptation strength.
+    pub fn with_strength(mut self, strength: T) -> Self {
+        self.strength = strength;
+        self
+    }
+
+    /// Change the illuminance range, in lux, where the brightness changes.
+    pub fn with_illuminance_range(mut self, dim: T, bright: T) -> Self {
+        self.dim_illuminance = dim;
+        self.bright_illuminance = bright;
+        self
+    }
+
+    /// Change the minimum relative brightness.
+    pub fn with_min_brightness(mut self, min_brightness: T) -> Self {
+        self.min_brightness = min_brightness;
+        self
+    }
+
+    /// Change the chromatic adaptation method.
+    pub fn with_method(mut self, method: Method) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Calculate the relative brightness for an illuminance in lux. It
+    /// follows the logarithm of the illuminance, since that's closer to how
+    /// the brightness of the surroundings is perceived.
+    pub fn brightness(&self, illuminance: T) -> T {
+        let dim = self.dim_illuminance.max(T::epsilon());
+        let bright = self.bright_illuminance.max(dim);
+        if bright <= dim {
+            return T::one();
+        }
+
+        let illuminance = clamp(illuminance, dim, bright);
+        let factor = (illuminance / dim).ln() / (bright / dim).ln();
+
+        self.min_brightness + factor * (T::one() - self.min_brightness)
+    }
+
+    /// Calculate the white point that colors are adapted to, for an ambient
+    /// color temperature.
+    pub fn white_point(&self, cct: T) -> Xyz<Any, T> {
+        let d65: Yxy<Any, T> = D65::get_xyz().into_color_unclamped();
+        let reference = planckian_chromaticity(from_f64(6504.0));
+        let ambient = planckian_chromaticity(cct);
+        let strength = clamp(self.strength, T::zero(), T::one());
+
+        // The shift along the Planckian locus is applied to D65, so 6504 K
+        // gives exactly D65.
+        let white = Yxy::new(
+            d65.x + strength * (ambient.x - reference.x),
+            d65.y + strength * (ambient.y - reference.y),
+            T::one(),
+        );
+
+        white.into_color_unclamped()
+    }
+
+    /// Create the transform for an ambient light measurement.
+    pub fn transform(&self, ambient: AmbientLight<T>) -> AmbientTransform<T> {
+        let matrix = self
+            .method
+            .generate_transform_matrix(D65::get_xyz(), self.white_point(ambient.cct));
+
+        // Scale the transform so white doesn't clip in any channel.
+        let mut transform = AmbientTransform {
+            matrix,
+            scale: T::one(),
+        };
+        let white = transform.apply_linear(LinSrgb::new(T::one(), T::one(), T::one()));
+        let max = white.red.max(white.green).max(white.blue);
+        transform.scale = self.brightness(ambient.illuminance) / max;
+
+        transform
+    }
+}
+
+impl<T> Default for AmbientAdaptation<T>
+where
+    T: FloatComponent,
+{
+    fn default() -> Self {
+        AmbientAdaptation::new()
+    }
+}
+
+/// A transform that adapts colors to the ambient light, created by
+/// [`AmbientAdaptation::transform`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AmbientTransform<T> {
+    matrix: Mat3<T>,
+    scale: T,
+}
+
+impl<T> AmbientTransform<T>
+where
+    T: FloatComponent,
+{
+    /// The chromatic adaptation matrix, in XYZ.
+    pub fn matrix(&self) -> Mat3<T> {
+        self.matrix
+    }
+
+    /// The relative brightness that the adapted colors are scaled with.
+    pub fn scale(&self) -> T {
+        self.scale
+    }
+
+    /// Adapt a linear sRGB color. The result may be outside the sRGB gamut.
+    pub fn apply_linear(&self, color: LinSrgb<T>) -> LinSrgb<T> {
+        let xyz: Xyz<D65, T> = color.into_color_unclamped();
+        let adapted = multiply_xyz(&self.matrix, &xyz.with_white_point()) * self.scale;
+
+        adapted.with_white_point::<D65>().into_color_unclamped()
+    }
+
+    /// Adapt an sRGB color, and clamp the result to the sRGB gamut.
+    pub fn apply(&self, color: Srgb<T>) -> Srgb<T> {
+        let linear = self.apply_linear(color.into_linear());
+        Srgb::from_linear(linear).clamp()
+    }
+
+    /// Adapt all colors in a slice, such as a theme, in place.
+    pub fn apply_slice(&self, colors: &mut [Srgb<T>]) {
+        for color in colors {
+            *color = self.apply(*color);
+        }
+    }
+}
+
+/// Calculate the chromaticity of the Planckian locus for the correlated color
+/// temperature `cct`, in kelvin.
+///
+/// This uses the cubic spline approximation by Kim et al. The temperature is
+/// clamped to its valid range, from 1667 K to 25000 K.
+pub fn planckian_chromaticity<T: FloatComponent>(cct: T) -> Yxy<Any, T> {
+    let cct = clamp(cct, from_f64(1667.0), from_f64(25000.0));
+    let t = from_f64::<T>(1.0e3) / cct;
+    let (t2, t3) = (t * t, t * t * t);
+
+    let x = if cct <= from_f64(4000.0) {
+        from_f64::<T>(-0.2661239) * t3 - from_f64::<T>(0.2343589) * t2
+            + from_f64::<T>(0.8776956) * t
+            + from_f64::<T>(0.179910)
+    } else {
+        from_f64::<T>(-3.0258469) * t3
+            + from_f64::<T>(2.1070379) * t2
+            + from_f64::<T>(0.2226347) * t
+            + from_f64::<T>(0.240390)
+    };
+
+    let (x2, x3) = (x * x, x * x * x);
+    let y = if cct <= from_f64(2222.0) {
+        from_f64::<T>(-1.1063814) * x3 - from_f64::<T>(1.34811020) * x2
+            + from_f64::<T>(2.18555832) * x
+            - from_f64::<T>(0.20219683)
+    } else if cct <= from_f64(4000.0) {
+        from_f64::<T>(-0.9549476) * x3 - from_f64::<T>(1.37418593) * x2
+            + from_f64::<T>(2.09137015) * x
+            - from_f64::<T>(0.16748867)
+    } else {
+        from_f64::<T>(3.0817580) * x3 - from_f64::<T>(5.87338670) * x2
+            + from_f64::<T>(3.75112997) * x
+            - from_f64::<T>(0.37001483)
+    };
+
+    Yxy::new(x, y, T::one())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{planckian_chromaticity, AmbientAdaptation, AmbientLight};
+    use crate::Srgb;
+
+    #[test]
+    fn planckian_locus() {
+        // Illuminant A is a Planckian radiator at about 2856 K.
+        let a = planckian_chromaticity(2856.0f64);
+        assert_relative_eq!(a.x, 0.44757, epsilon = 1e-3);
+        assert_relative_eq!(a.y, 0.40745, epsilon = 1e-3);
+
+        let hot = planckian_chromaticity(10000.0f64);
+        assert_relative_eq!(hot.x, 0.2807, epsilon = 1e-3);
+        assert_relative_eq!(hot.y, 0.2884, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn neutral_daylight() {
+        let transform = AmbientAdaptation::new().transform(AmbientLight::new(1000.0f64, 6504.0));
+        let color = Srgb::new(0.2, 0.5, 0.9);
+
+        assert_relative_eq!(transform.scale(), 1.0, epsilon = 1e-6);
+        assert_relative_eq!(transform.apply(color), color, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn warm_and_dim() {
+        let adaptation = AmbientAdaptation::new();
+        let transform = adaptation.transform(AmbientLight::new(1.0f64, 2000.0));
+
+        let mut theme = [Srgb::new(1.0, 1.0, 1.0), Srgb::new(0.5, 0.5, 0.5)];
+        transform.apply_slice(&mut theme);
+
+        let [white, gray] = theme;
+        assert!(white.red > white.green && white.green > white.blue);
+        assert!(gray.red < white.red);
+
+        // The brightest channel is scaled to the minimum brightness.
+        let linear = transform.apply_linear(crate::LinSrgb::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(linear.red, 0.7, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn brightness() {
+        let adaptation = AmbientAdaptation::<f64>::new();
+
+        assert_relative_eq!(adaptation.brightness(0.0), 0.7);
+        assert_relative_eq!(adaptation.brightness(10.0), 0.7);
+        assert_relative_eq!(adaptation.brightness(500.0), 1.0);
+        assert_relative_eq!(adaptation.brightness(100_000.0), 1.0);
+
+        let middle = (10.0f64 * 500.0).sqrt();
+        assert_relative_eq!(adaptation.brightness(middle), 0.85, epsilon = 1e-9);
+    }
+}
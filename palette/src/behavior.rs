@@ -0,0 +1,161 @@
+//! Versioned algorithms, for keeping the results stable across upgrades.
+//!
+//! Some algorithms in palette have more than one reasonable implementation,
+//! and better ones may be found over time. The types in this module select a
+//! version of these algorithms, where each version is a type that implements
+//! [`Behavior`]. A version never changes its results once it has been
+//! released, so projects that need identical outputs after upgrading palette
+//! can pin a version, while [`Latest`] always points to the newest one:
+//!
+//! ```
+//! use palette::behavior::{Behavior, Latest, V1};
+//! use palette::LinSrgb;
+//!
+//! let color = LinSrgb::new(1.2f32, 0.5, -0.1);
+//!
+//! // The output of `V1` will stay the same in future versions of palette.
+//! let pinned = V1::gamut_map(color);
+//! assert_eq!(pinned, LinSrgb::new(1.0, 0.5, 0.0));
+//!
+//! // `Latest` may switch to a better algorithm.
+//! let improved = Latest::gamut_map(color);
+//! ```
+//!
+//! The versions are also type parameters of functions that use these
+//! algorithms, such as [`gamut::map_into_srgb`](crate::gamut::map_into_srgb).
+//!
+//! Floating point results may still differ between platforms, unless the
+//! `deterministic` feature is enabled.
+
+use crate::convert::IntoColorUnclamped;
+use crate::{from_f64, Clamp, FloatComponent, IsWithinBounds, LinSrgb, Oklch};
+
+/// A version of the algorithms in this module.
+pub trait Behavior: 'static {
+    /// The version number.
+    const VERSION: u32;
+
+    /// Map a color into the sRGB gamut.
+    fn gamut_map<T>(color: LinSrgb<T>) -> LinSrgb<T>
+    where
+        T: FloatComponent;
+}
+
+/// The first version.
+///
+/// * [`gamut_map`](Behavior::gamut_map) clamps each component.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct V1;
+
+impl Behavior for V1 {
+    const VERSION: u32 = 1;
+
+    fn gamut_map<T>(color: LinSrgb<T>) -> LinSrgb<T>
+    where
+        T: FloatComponent,
+    {
+        color.clamp()
+    }
+}
+
+/// The second version.
+///
+/// * [`gamut_map`](Behavior::gamut_map) reduces the chroma in
+///   [`Oklch`](crate::Oklch) until the color fits, which keeps its lightness
+///   and hue. Colors that are too light or too dark for the gamut are
+///   clamped after that.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct V2;
+
+impl Behavior for V2 {
+    const VERSION: u32 = 2;
+
+    fn gamut_map<T>(color: LinSrgb<T>) -> LinSrgb<T>
+    where
+        T: FloatComponent,
+    {
+        if color.is_within_bounds() {
+            return color;
+        }
+
+        let oklch: Oklch<T> = color.into_color_unclamped();
+        let mapped: LinSrgb<T> = v2_fit_chroma(oklch).into_color_unclamped();
+        mapped.clamp()
+    }
+}
+
+/// Reduce the chroma of `color` until it's within the sRGB gamut, with a
+/// binary search in 24 steps.
+///
+/// This is a copy of the chroma reduction in [`theme`](crate::theme), which
+/// may change, while this has to stay the same for `V2`.
+fn v2_fit_chroma<T: FloatComponent>(mut color: Oklch<T>) -> Oklch<T> {
+    let in_gamut = |color: Oklch<T>| {
+        let rgb: LinSrgb<T> = color.into_color_unclamped();
+        rgb.is_within_bounds()
+    };
+
+    if in_gamut(color) {
+        return color;
+    }
+
+    let mut low = T::zero();
+    let mut high = color.chroma;
+    for _ in 0..24 {
+        let middle = (low + high) / from_f64(2.0);
+        if in_gamut(Oklch {
+            chroma: middle,
+            ..color
+        }) {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    color.chroma = low;
+    color
+}
+
+/// The newest version. This will change when new versions are added.
+pub type Latest = V2;
+
+#[cfg(test)]
+mod test {
+    use super::{Behavior, Latest, V1, V2};
+    use crate::convert::IntoColorUnclamped;
+    use crate::{IsWithinBounds, LinSrgb, Oklch};
+
+    #[test]
+    fn versions() {
+        assert_eq!(V1::VERSION, 1);
+        assert_eq!(V2::VERSION, 2);
+        assert_eq!(Latest::VERSION, 2);
+    }
+
+    #[test]
+    fn v1_gamut_map() {
+        let mapped = V1::gamut_map(LinSrgb::new(1.5f64, 0.5, -0.5));
+        assert_eq!(mapped, LinSrgb::new(1.0, 0.5, 0.0));
+    }
+
+    #[test]
+    fn v2_gamut_map() {
+        let inside = LinSrgb::new(0.2f64, 0.4, 0.6);
+        assert_eq!(V2::gamut_map(inside), inside);
+
+        let outside: LinSrgb<f64> = Oklch::new(0.7, 0.4, 150.0).into_color_unclamped();
+        let mapped = V2::gamut_map(outside);
+        assert!(mapped.is_within_bounds());
+
+        let original: Oklch<f64> = outside.into_color_unclamped();
+        let mapped: Oklch<f64> = mapped.into_color_unclamped();
+        assert_relative_eq!(mapped.l, original.l, epsilon = 1e-4);
+        assert_relative_eq!(
+            mapped.hue.to_positive_degrees(),
+            original.hue.to_positive_degrees(),
+            epsilon = 0.1
+        );
+        assert!(mapped.chroma < original.chroma);
+    }
+}
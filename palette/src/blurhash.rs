@@ -0,0 +1,240 @@
+//! Color math for BlurHash and similar image placeholders.
+//!
+//! [BlurHash](https://blurha.sh) describes an image with a few components of
+//! a discrete cosine transform (DCT), computed in linear sRGB. The first
+//! component, the DC component, is the average color, and is stored as an
+//! 8 bit sRGB color. The other components, the AC components, are the
+//! variations around it, and are stored with a perceptual quantization to
+//! 19 levels per channel.
+//!
+//! These functions implement the color parts of the format: the basis
+//! functions, the components of an image, and their quantization. The
+//! encoding of the quantized values as base 83 text is left to the BlurHash
+//! implementations.
+//!
+//! ```
+//! use palette::blurhash;
+//! use palette::LinSrgb;
+//!
+//! // A 4 by 1 image, black on the left and white on the right.
+//! let black = LinSrgb::new(0.0f32, 0.0, 0.0);
+//! let white = LinSrgb::new(1.0, 1.0, 1.0);
+//! let pixels = [black, black, white, white];
+//!
+//! let dc = blurhash::component(&pixels, 4, 0, 0);
+//! let ac = blurhash::component(&pixels, 4, 1, 0);
+//! assert!((dc.red - 0.5).abs() < 1e-6);
+//! assert!(ac.red < 0.0);
+//!
+//! let maximum = blurhash::decode_maximum(blurhash::encode_maximum(0.5f32));
+//! let quantized = blurhash::encode_ac(ac, maximum);
+//! let restored: LinSrgb<f32> = blurhash::decode_ac(quantized, maximum);
+//! assert!((restored.red - ac.red).abs() < 0.05);
+//!
+//! let average: LinSrgb<f32> = blurhash::decode_dc(blurhash::encode_dc(dc));
+//! ```
+//!
+//! [ThumbHash](https://evanw.github.io/thumbhash/) uses the same kind of
+//! basis, but samples the pixel centers, as in [`basis_centered`].
+
+use crate::{clamp, float, from_f64, FloatComponent, LinSrgb, Srgb};
+
+/// The value of the BlurHash basis function for the component `component`,
+/// at the pixel `position` of a row or column with `size` pixels.
+///
+/// This is `cos(π · component · position / size)`.
+pub fn basis<T: FloatComponent>(component: usize, position: usize, size: usize) -> T {
+    let pi = from_f64::<T>(core::f64::consts::PI);
+    float::cos(pi * from_f64((component * position) as f64) / from_f64(size as f64))
+}
+
+/// The value of the basis function for the component `component`, at the
+/// center of the pixel `position` of a row or column with `size` pixels.
+///
+/// This is `cos(π · component · (position + 0.5) / size)`, which is the
+/// basis of the type II DCT.
+pub fn basis_centered<T: FloatComponent>(component: usize, position: usize, size: usize) -> T {
+    let pi = from_f64::<T>(core::f64::consts::PI);
+    let position = from_f64::<T>(position as f64) + from_f64(0.5);
+    float::cos(pi * from_f64::<T>(component as f64) * position / from_f64(size as f64))
+}
+
+/// Calculate the component `(x, y)` of an image with rows of `width`
+/// pixels, as BlurHash does.
+///
+/// The DC component, `(0, 0)`, is the average color. The AC components are
+/// scaled by two, as in the reference implementation.
+///
+/// # Panics
+///
+/// Panics if the image is empty, or if the number of pixels is not a
+/// multiple of `width`.
+pub fn component<T: FloatComponent>(
+    pixels: &[LinSrgb<T>],
+    width: usize,
+    x: usize,
+    y: usize,
+) -> LinSrgb<T> {
+    assert!(width > 0 && !pixels.is_empty());
+    let height = pixels.len() / width;
+    assert_eq!(width * height, pixels.len());
+
+    let mut sum = LinSrgb::new(T::zero(), T::zero(), T::zero());
+    for (index, &pixel) in pixels.iter().enumerate() {
+        let weight = basis::<T>(x, index % width, width) * basis(y, index / width, height);
+        sum = sum + pixel * weight;
+    }
+
+    let normalization = if x == 0 && y == 0 {
+        T::one()
+    } else {
+        from_f64(2.0)
+    };
+
+    sum * (normalization / from_f64((width * height) as f64))
+}
+
+/// Encode the DC component as a 24 bit sRGB value, `0xRRGGBB`.
+pub fn encode_dc<T: FloatComponent>(color: LinSrgb<T>) -> u32 {
+    let srgb = Srgb::from_linear(color);
+    let encode = |value: T| {
+        (clamp(value, T::zero(), T::one()) * from_f64(255.0))
+            .round()
+            .to_u32()
+            .unwrap_or(0)
+    };
+
+    (encode(srgb.red) << 16) | (encode(srgb.green) << 8) | encode(srgb.blue)
+}
+
+/// Decode a DC component from a 24 bit sRGB value, `0xRRGGBB`.
+pub fn decode_dc<T: FloatComponent>(value: u32) -> LinSrgb<T> {
+    let decode = |value: u32| from_f64::<T>(f64::from(value & 0xff) / 255.0);
+    Srgb::new(decode(value >> 16), decode(value >> 8), decode(value)).into_linear()
+}
+
+/// Encode the largest absolute AC value as one of 83 levels, from `0` to
+/// `82`.
+pub fn encode_maximum<T: FloatComponent>(maximum: T) -> u32 {
+    let level = (maximum * from_f64(166.0) - from_f64(0.5)).floor();
+    clamp(level, T::zero(), from_f64(82.0))
+        .to_u32()
+        .unwrap_or(0)
+}
+
+/// Decode the largest absolute AC value from one of 83 levels.
+pub fn decode_maximum<T: FloatComponent>(level: u32) -> T {
+    from_f64::<T>(f64::from(level) + 1.0) / from_f64(166.0)
+}
+
+/// Encode an AC component, relative to the largest absolute AC value
+/// `maximum`, as a value from `0` to `19³ - 1`.
+///
+/// Each channel is quantized to 19 levels, after a square root that gives
+/// small values more precision.
+pub fn encode_ac<T: FloatComponent>(color: LinSrgb<T>, maximum: T) -> u32 {
+    let quantize = |value: T| {
+        let level =
+            (sign_pow(value / maximum, from_f64(0.5)) * from_f64(9.0) + from_f64(9.5)).floor();
+        clamp(level, T::zero(), from_f64(18.0))
+            .to_u32()
+            .unwrap_or(0)
+    };
+
+    quantize(color.red) * 19 * 19 + quantize(color.green) * 19 + quantize(color.blue)
+}
+
+/// Decode an AC component, relative to the largest absolute AC value
+/// `maximum`, from a value from `0` to `19³ - 1`.
+pub fn decode_ac<T: FloatComponent>(value: u32, maximum: T) -> LinSrgb<T> {
+    let unquantize = |level: u32| {
+        let value = (from_f64::<T>(f64::from(level)) - from_f64(9.0)) / from_f64(9.0);
+        sign_pow(value, from_f64(2.0)) * maximum
+    };
+
+    LinSrgb::new(
+        unquantize(value / (19 * 19)),
+        unquantize((value / 19) % 19),
+        unquantize(value % 19),
+    )
+}
+
+fn sign_pow<T: FloatComponent>(value: T, exponent: T) -> T {
+    let magnitude = float::powf(value.abs(), exponent);
+    if value < T::zero() {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn dc() {
+        assert_eq!(encode_dc(LinSrgb::new(0.0f64, 1.0, 0.0)), 0x00ff00);
+
+        let decoded: LinSrgb<f64> = decode_dc(0x7f00ff);
+        assert_relative_eq!(decoded.red, 0.212231, epsilon = 1e-5);
+        assert_eq!(encode_dc(decoded), 0x7f00ff);
+    }
+
+    #[test]
+    fn ac_round_trip() {
+        let maximum = 0.4f64;
+        for &value in &[-0.4, -0.1, 0.0, 0.05, 0.3, 0.4] {
+            let color = LinSrgb::new(value, -value, value / 2.0);
+            let encoded = encode_ac(color, maximum);
+            assert!(encoded < 19 * 19 * 19);
+
+            let decoded = decode_ac(encoded, maximum);
+            assert_relative_eq!(decoded, color, epsilon = 0.04);
+        }
+
+        assert_eq!(
+            encode_ac(LinSrgb::new(0.0f64, 0.0, 0.0), 1.0),
+            9 * 19 * 19 + 9 * 19 + 9
+        );
+        assert_eq!(
+            encode_ac(LinSrgb::new(-1.0f64, 1.0, 2.0), 1.0),
+            18 * 19 + 18
+        );
+    }
+
+    #[test]
+    fn maximum() {
+        assert_eq!(encode_maximum(0.0f64), 0);
+        assert_eq!(encode_maximum(1.0f64), 82);
+        assert_eq!(encode_maximum(decode_maximum::<f64>(40)), 40);
+    }
+
+    #[test]
+    fn components() {
+        let black = LinSrgb::new(0.0f64, 0.0, 0.0);
+        let white = LinSrgb::new(1.0, 1.0, 1.0);
+        let pixels = [black, black, white, white, black, black, white, white];
+
+        assert_relative_eq!(component(&pixels, 4, 0, 0).red, 0.5, epsilon = 1e-9);
+        assert_relative_eq!(
+            component(&pixels, 4, 1, 0).red,
+            -core::f64::consts::FRAC_1_SQRT_2 / 2.0,
+            epsilon = 1e-9
+        );
+
+        // The basis samples the top edge of each row, so even identical
+        // rows give a vertical component. BlurHash decoders expect this.
+        assert_relative_eq!(component(&pixels, 4, 0, 1).red, 0.5, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn centered_basis() {
+        assert_relative_eq!(basis_centered::<f64>(0, 3, 4), 1.0);
+        assert_relative_eq!(
+            basis_centered::<f64>(1, 0, 4),
+            (core::f64::consts::PI / 8.0).cos(),
+            epsilon = 1e-12
+        );
+    }
+}
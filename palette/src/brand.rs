@@ -0,0 +1,299 @@
+//! Tints and shades of a brand color, with their text accessibility.
+//!
+//! Design systems usually need a brand color in many lightness levels, for
+//! backgrounds, borders and text, together with a record of which text colors
+//! are readable on each of them. A [`BrandScale`] generates the tints and
+//! shades in [`Oklch`], where the steps are close to perceptually even,
+//! reduces their chroma when needed to keep them in the sRGB gamut, and checks
+//! white, black and the brand color itself as text on each of them, with the
+//! [WCAG contrast levels](crate::RelativeContrast).
+//!
+//! ```
+//! use palette::brand::{BrandScale, ContrastLevel, TextColor};
+//! use palette::Srgb;
+//!
+//! let brand = Srgb::new(0.13f32, 0.4, 0.85);
+//! let matrix = BrandScale::new(9).generate(brand);
+//!
+//! assert_eq!(matrix.swatches.len(), 9);
+//!
+//! // The lightest tint is a good background for black text.
+//! let lightest = &matrix.swatches[0];
+//! assert_eq!(lightest.contrast(TextColor::Black).level, ContrastLevel::Aaa);
+//!
+//! // Find the tints that can be used with white text.
+//! let for_white = matrix.passing(TextColor::White, ContrastLevel::Aa).count();
+//! assert!(for_white > 0);
+//! ```
+//!
+//! This module is only available if the `std` feature is enabled.
+
+use crate::convert::{IntoColor, IntoColorUnclamped};
+use crate::theme::fit_chroma;
+use crate::{from_f64, FloatComponent, Oklch, RelativeContrast, Srgb};
+
+/// Settings for generating the tints and shades of a brand color.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct BrandScale<T> {
+    /// The number of swatches.
+    pub steps: usize,
+
+    /// The Oklab lightness of the lightest tint.
+    pub lightest: T,
+
+    /// The Oklab lightness of the darkest shade.
+    pub darkest: T,
+}
+
+impl<T> BrandScale<T>
+where
+    T: FloatComponent,
+{
+    /// Create a scale with `steps` swatches, with lightness from `0.97` to
+    /// `0.25`.
+    pub fn new(steps: usize) -> Self {
+        BrandScale {
+            steps,
+            lightest: from_f64(0.97),
+            darkest: from_f64(0.25),
+        }
+    }
+
+    /// Change the lightness range, in Oklab lightness.
+    pub fn with_lightness_range(mut self, lightest: T, darkest: T) -> Self {
+        self.lightest = lightest;
+        self.darkest = darkest;
+        self
+    }
+
+    /// Generate the swatches for `brand`, from the lightest to the darkest.
+    ///
+    /// Every swatch has the hue and, as far as the gamut allows, the chroma
+    /// of the brand color.
+    pub fn generate(&self, brand: Srgb<T>) -> AccessibilityMatrix<T> {
+        let brand_oklch: Oklch<T> = brand.into_color_unclamped();
+        let white = Srgb::new(T::one(), T::one(), T::one());
+        let black = Srgb::new(T::zero(), T::zero(), T::zero());
+
+        let last = from_f64::<T>(self.steps.saturating_sub(1).max(1) as f64);
+        let swatches = (0..self.steps)
+            .map(|index| {
+                let factor = from_f64::<T>(index as f64) / last;
+                let oklch = fit_chroma(Oklch {
+                    l: self.lightest + factor * (self.darkest - self.lightest),
+                    ..brand_oklch
+                });
+                let color: Srgb<T> = oklch.into_color();
+
+                Swatch {
+                    color,
+                    lightness: oklch.l,
+                    white: TextContrast::new(color, white),
+                    black: TextContrast::new(color, black),
+                    brand: TextContrast::new(color, brand),
+                }
+            })
+            .collect();
+
+        AccessibilityMatrix { brand, swatches }
+    }
+}
+
+/// The tints and shades of a brand color, created by
+/// [`BrandScale::generate`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct AccessibilityMatrix<T> {
+    /// The brand color.
+    pub brand: Srgb<T>,
+
+    /// The swatches, from the lightest to the darkest.
+    pub swatches: Vec<Swatch<T>>,
+}
+
+impl<T> AccessibilityMatrix<T>
+where
+    T: FloatComponent,
+{
+    /// The swatches where `text` reaches at least `level`.
+    pub fn passing(
+        &self,
+        text: TextColor,
+        level: ContrastLevel,
+    ) -> impl Iterator<Item = &Swatch<T>> + '_ {
+        self.swatches
+            .iter()
+            .filter(move |swatch| swatch.contrast(text).level >= level)
+    }
+}
+
+/// A tint or shade, and how text colors perform on it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct Swatch<T> {
+    /// The color of the swatch.
+    pub color: Srgb<T>,
+
+    /// The Oklab lightness of the swatch.
+    pub lightness: T,
+
+    /// White text on the swatch.
+    pub white: TextContrast<T>,
+
+    /// Black text on the swatch.
+    pub black: TextContrast<T>,
+
+    /// Text in the brand color on the swatch.
+    pub brand: TextContrast<T>,
+}
+
+impl<T> Swatch<T> {
+    /// The contrast of `text` on the swatch.
+    pub fn contrast(&self, text: TextColor) -> &TextContrast<T> {
+        match text {
+            TextColor::White => &self.white,
+            TextColor::Black => &self.black,
+            TextColor::Brand => &self.brand,
+        }
+    }
+}
+
+/// A text color that is checked against each swatch.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub enum TextColor {
+    /// White text.
+    White,
+
+    /// Black text.
+    Black,
+
+    /// Text in the brand color.
+    Brand,
+}
+
+/// The contrast between a text color and a background.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct TextContrast<T> {
+    /// The contrast ratio.
+    pub ratio: T,
+
+    /// The highest WCAG level that the contrast ratio reaches.
+    pub level: ContrastLevel,
+}
+
+impl<T> TextContrast<T>
+where
+    T: FloatComponent,
+{
+    fn new(background: Srgb<T>, text: Srgb<T>) -> Self {
+        let ratio = background.get_contrast_ratio(text);
+
+        TextContrast {
+            ratio,
+            level: ContrastLevel::from_ratio(ratio),
+        }
+    }
+}
+
+/// The WCAG text contrast levels, from the lowest to the highest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub enum ContrastLevel {
+    /// The contrast ratio is below 3:1.
+    Fail,
+
+    /// At least 3:1, which is Level AA for large text.
+    AaLarge,
+
+    /// At least 4.5:1, which is Level AA for normal text and Level AAA for
+    /// large text.
+    Aa,
+
+    /// At least 7:1, which is Level AAA for normal text.
+    Aaa,
+}
+
+impl ContrastLevel {
+    /// Find the highest level that `ratio` reaches.
+    pub fn from_ratio<T: FloatComponent>(ratio: T) -> Self {
+        if ratio >= from_f64(7.0) {
+            ContrastLevel::Aaa
+        } else if ratio >= from_f64(4.5) {
+            ContrastLevel::Aa
+        } else if ratio >= from_f64(3.0) {
+            ContrastLevel::AaLarge
+        } else {
+            ContrastLevel::Fail
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{BrandScale, ContrastLevel, TextColor};
+    use crate::convert::IntoColorUnclamped;
+    use crate::{Oklch, RelativeContrast, Srgb};
+
+    #[test]
+    fn levels() {
+        assert_eq!(ContrastLevel::from_ratio(2.9f64), ContrastLevel::Fail);
+        assert_eq!(ContrastLevel::from_ratio(3.0f64), ContrastLevel::AaLarge);
+        assert_eq!(ContrastLevel::from_ratio(4.5f64), ContrastLevel::Aa);
+        assert_eq!(ContrastLevel::from_ratio(21.0f64), ContrastLevel::Aaa);
+        assert!(ContrastLevel::Aaa > ContrastLevel::Aa);
+    }
+
+    #[test]
+    fn swatches() {
+        let brand = Srgb::new(0.9f64, 0.3, 0.1);
+        let brand_hue = IntoColorUnclamped::<Oklch<f64>>::into_color_unclamped(brand).hue;
+        let matrix = BrandScale::new(5).generate(brand);
+
+        assert_eq!(matrix.swatches.len(), 5);
+        assert_relative_eq!(matrix.swatches[0].lightness, 0.97);
+        assert_relative_eq!(matrix.swatches[4].lightness, 0.25);
+
+        for pair in matrix.swatches.windows(2) {
+            assert!(pair[0].lightness > pair[1].lightness);
+        }
+
+        for swatch in &matrix.swatches {
+            let oklch: Oklch<f64> = swatch.color.into_color_unclamped();
+            assert_relative_eq!(oklch.l, swatch.lightness, epsilon = 1e-3);
+            assert_relative_eq!(
+                oklch.hue.to_positive_degrees(),
+                brand_hue.to_positive_degrees(),
+                epsilon = 1.0
+            );
+            assert_relative_eq!(
+                swatch.white.ratio,
+                swatch.color.get_contrast_ratio(Srgb::new(1.0, 1.0, 1.0))
+            );
+        }
+    }
+
+    #[test]
+    fn passing() {
+        let matrix = BrandScale::new(11).generate(Srgb::new(0.2f64, 0.5, 0.3));
+
+        let white: Vec<_> = matrix
+            .passing(TextColor::White, ContrastLevel::Aa)
+            .collect();
+        let black: Vec<_> = matrix
+            .passing(TextColor::Black, ContrastLevel::Aa)
+            .collect();
+
+        // White text works on the dark end, and black text on the light end.
+        assert!(!white.is_empty() && !black.is_empty());
+        assert!(white.iter().all(|swatch| swatch.lightness < 0.7));
+        assert!(black.iter().all(|swatch| swatch.lightness > 0.5));
+
+        let brand: Vec<_> = matrix
+            .passing(TextColor::Brand, ContrastLevel::AaLarge)
+            .collect();
+        assert!(brand.len() < matrix.swatches.len());
+    }
+}
@@ -1365,6 +1365,7 @@ pub enum VecCastErrorKind {
 
 #[cfg(test)]
 mod test {
+    #[cfg(feature = "std")]
     use crate::{LinSrgb, Srgb};
 
     #[cfg(feature = "std")]
@@ -1394,6 +1395,7 @@ mod test {
         assert_eq!(colors.capacity(), 8);
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn map_vec_in_place() {
         fn do_things(rgb: Srgb) -> LinSrgb {
@@ -1413,6 +1415,7 @@ mod test {
         )
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn map_slice_box_in_place() {
         fn do_things(rgb: Srgb) -> LinSrgb {
@@ -0,0 +1,341 @@
+//! Ordering of categorical palettes.
+//!
+//! The colors of a categorical palette are often assigned to series in
+//! order, and the neighboring series in a legend or a stacked chart are the
+//! ones that are compared most often. [`adjacency_order`] finds an order
+//! where neighboring colors are as different as possible, by maximizing the
+//! smallest difference between neighbors in [`Oklab`]. Ties are broken by
+//! the total difference.
+//!
+//! ```
+//! use palette::categorical;
+//! use palette::Srgb;
+//!
+//! let mut colors = [
+//!     Srgb::new(0.9f32, 0.2, 0.2),
+//!     Srgb::new(0.8, 0.3, 0.2),
+//!     Srgb::new(0.2, 0.3, 0.9),
+//!     Srgb::new(0.3, 0.2, 0.8),
+//! ];
+//!
+//! let before = categorical::min_adjacent_difference(&colors);
+//! categorical::reorder(&mut colors);
+//! assert!(categorical::min_adjacent_difference(&colors) > before);
+//! ```
+//!
+//! Palettes with up to [`EXACT_LIMIT`] colors are ordered with an exact
+//! search. Larger palettes are ordered greedily, by starting from each color
+//! and always continuing with the most different remaining color, and
+//! keeping the best result.
+//!
+//! This module is only available if the `std` feature is enabled (this is the
+//! default).
+
+use core::cmp::Ordering;
+
+use crate::convert::IntoColorUnclamped;
+use crate::{FloatComponent, Oklab};
+
+/// The largest palette that is ordered with an exact search.
+pub const EXACT_LIMIT: usize = 9;
+
+/// Find the order of `colors` that makes neighboring colors as different as
+/// possible. The result is a list of indices into `colors`.
+pub fn adjacency_order<C, T>(colors: &[C]) -> Vec<usize>
+where
+    C: Copy + IntoColorUnclamped<Oklab<T>>,
+    T: FloatComponent,
+{
+    let differences = DifferenceTable::new(colors);
+
+    if colors.len() <= EXACT_LIMIT {
+        exact_order(&differences)
+    } else {
+        greedy_order(&differences)
+    }
+}
+
+/// Reorder `colors` in place, to make neighboring colors as different as
+/// possible. See [`adjacency_order`].
+pub fn reorder<C, T>(colors: &mut [C])
+where
+    C: Copy + IntoColorUnclamped<Oklab<T>>,
+    T: FloatComponent,
+{
+    let order = adjacency_order(colors);
+    let original = colors.to_vec();
+
+    for (color, index) in colors.iter_mut().zip(order) {
+        *color = original[index];
+    }
+}
+
+/// The smallest difference between neighboring colors in `colors`, in
+/// Oklab. It's infinite if there are fewer than two colors.
+pub fn min_adjacent_difference<C, T>(colors: &[C]) -> T
+where
+    C: Copy + IntoColorUnclamped<Oklab<T>>,
+    T: FloatComponent,
+{
+    colors
+        .windows(2)
+        .map(|pair| {
+            difference(
+                pair[0].into_color_unclamped(),
+                pair[1].into_color_unclamped(),
+            )
+        })
+        .fold(T::infinity(), T::min)
+}
+
+fn difference<T: FloatComponent>(a: Oklab<T>, b: Oklab<T>) -> T {
+    let difference = a - b;
+    (difference.l * difference.l + difference.a * difference.a + difference.b * difference.b).sqrt()
+}
+
+struct DifferenceTable<T> {
+    len: usize,
+    differences: Vec<T>,
+}
+
+impl<T: FloatComponent> DifferenceTable<T> {
+    fn new<C>(colors: &[C]) -> Self
+    where
+        C: Copy + IntoColorUnclamped<Oklab<T>>,
+    {
+        let oklab: Vec<Oklab<T>> = colors
+            .iter()
+            .map(|&color| color.into_color_unclamped())
+            .collect();
+
+        let differences = oklab
+            .iter()
+            .flat_map(|&a| oklab.iter().map(move |&b| difference(a, b)))
+            .collect();
+
+        DifferenceTable {
+            len: colors.len(),
+            differences,
+        }
+    }
+
+    fn get(&self, a: usize, b: usize) -> T {
+        self.differences[a * self.len + b]
+    }
+}
+
+/// The smallest and the total difference of a path.
+#[derive(Clone, Copy)]
+struct Score<T> {
+    min: T,
+    total: T,
+}
+
+impl<T: FloatComponent> Score<T> {
+    fn empty() -> Self {
+        Score {
+            min: T::infinity(),
+            total: T::zero(),
+        }
+    }
+
+    fn add(self, difference: T) -> Self {
+        Score {
+            min: self.min.min(difference),
+            total: self.total + difference,
+        }
+    }
+
+    fn is_better_than(&self, other: &Self) -> bool {
+        match self.min.partial_cmp(&other.min) {
+            Some(Ordering::Greater) => true,
+            Some(Ordering::Equal) => self.total > other.total,
+            _ => false,
+        }
+    }
+}
+
+fn exact_order<T: FloatComponent>(differences: &DifferenceTable<T>) -> Vec<usize> {
+    let mut best = (Score::empty(), (0..differences.len).collect::<Vec<_>>());
+    let mut best_min = T::neg_infinity();
+    let mut path = Vec::with_capacity(differences.len);
+    let mut used = vec![false; differences.len];
+
+    for start in 0..differences.len {
+        path.push(start);
+        used[start] = true;
+        search(
+            differences,
+            &mut path,
+            &mut used,
+            Score::empty(),
+            &mut best,
+            &mut best_min,
+        );
+        used[start] = false;
+        path.pop();
+    }
+
+    best.1
+}
+
+fn search<T: FloatComponent>(
+    differences: &DifferenceTable<T>,
+    path: &mut Vec<usize>,
+    used: &mut [bool],
+    score: Score<T>,
+    best: &mut (Score<T>, Vec<usize>),
+    best_min: &mut T,
+) {
+    // The smallest difference can only shrink, so paths that are already
+    // worse than the best one can be skipped.
+    if score.min < *best_min {
+        return;
+    }
+
+    if path.len() == differences.len {
+        if *best_min == T::neg_infinity() || score.is_better_than(&best.0) {
+            *best_min = score.min;
+            best.0 = score;
+            best.1.clear();
+            best.1.extend_from_slice(path);
+        }
+        return;
+    }
+
+    let last = path[path.len() - 1];
+    for next in 0..differences.len {
+        if used[next] {
+            continue;
+        }
+
+        path.push(next);
+        used[next] = true;
+        let next_score = score.add(differences.get(last, next));
+        search(differences, path, used, next_score, best, best_min);
+        used[next] = false;
+        path.pop();
+    }
+}
+
+fn greedy_order<T: FloatComponent>(differences: &DifferenceTable<T>) -> Vec<usize> {
+    let mut best: Option<(Score<T>, Vec<usize>)> = None;
+
+    for start in 0..differences.len {
+        let mut path = vec![start];
+        let mut used = vec![false; differences.len];
+        used[start] = true;
+        let mut score = Score::empty();
+
+        while path.len() < differences.len {
+            let last = path[path.len() - 1];
+            let next = (0..differences.len)
+                .filter(|&index| !used[index])
+                .max_by(|&a, &b| {
+                    differences
+                        .get(last, a)
+                        .partial_cmp(&differences.get(last, b))
+                        .unwrap_or(Ordering::Equal)
+                })
+                .expect("there should be unused colors left");
+
+            score = score.add(differences.get(last, next));
+            used[next] = true;
+            path.push(next);
+        }
+
+        let is_better = match best {
+            Some((ref best_score, _)) => score.is_better_than(best_score),
+            None => true,
+        };
+        if is_better {
+            best = Some((score, path));
+        }
+    }
+
+    best.map(|(_, path)| path).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{adjacency_order, min_adjacent_difference, reorder, EXACT_LIMIT};
+    use crate::{Oklab, Oklch};
+
+    #[test]
+    fn small() {
+        let empty: [Oklab<f64>; 0] = [];
+        assert!(adjacency_order(&empty).is_empty());
+        assert_eq!(adjacency_order(&[Oklab::new(0.5f64, 0.0, 0.0)]), vec![0]);
+    }
+
+    #[test]
+    fn exact() {
+        // Two groups of similar colors should alternate.
+        let mut colors = [
+            Oklab::new(0.3f64, 0.0, 0.0),
+            Oklab::new(0.32, 0.0, 0.0),
+            Oklab::new(0.34, 0.0, 0.0),
+            Oklab::new(0.8, 0.0, 0.0),
+            Oklab::new(0.82, 0.0, 0.0),
+            Oklab::new(0.84, 0.0, 0.0),
+        ];
+
+        reorder(&mut colors);
+        for pair in colors.windows(2) {
+            assert!((pair[0].l - pair[1].l).abs() > 0.4);
+        }
+
+        // For example 0.8, 0.3, 0.82, 0.32, 0.84, 0.34.
+        assert_relative_eq!(min_adjacent_difference(&colors), 0.5, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn exact_is_optimal() {
+        // Compare with all orders of a small palette.
+        let colors: Vec<_> = (0..5)
+            .map(|i| Oklch::new(0.5 + i as f64 * 0.05, 0.1, i as f64 * 50.0))
+            .collect();
+
+        let order = adjacency_order(&colors);
+        let ordered: Vec<_> = order.iter().map(|&index| colors[index]).collect();
+        let best = min_adjacent_difference(&ordered);
+
+        let mut indices = [0, 1, 2, 3, 4];
+        let mut max: f64 = 0.0;
+        permutations(&mut indices, 0, &mut |order| {
+            let ordered: Vec<_> = order.iter().map(|&index| colors[index]).collect();
+            max = max.max(min_adjacent_difference(&ordered));
+        });
+
+        assert_relative_eq!(best, max, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn greedy() {
+        let colors: Vec<_> = (0..EXACT_LIMIT * 2)
+            .map(|i| Oklch::new(0.6f64, 0.12, i as f64 * 20.0))
+            .collect();
+
+        let mut order = adjacency_order(&colors);
+        assert!(
+            min_adjacent_difference(&order.iter().map(|&i| colors[i]).collect::<Vec<_>>())
+                > min_adjacent_difference(&colors)
+        );
+
+        order.sort_unstable();
+        assert_eq!(order, (0..colors.len()).collect::<Vec<_>>());
+    }
+
+    fn permutations(indices: &mut [usize], start: usize, visit: &mut impl FnMut(&[usize])) {
+        if start == indices.len() {
+            visit(indices);
+            return;
+        }
+
+        for i in start..indices.len() {
+            indices.swap(start, i);
+            permutations(indices, start + 1, visit);
+            indices.swap(start, i);
+        }
+    }
+}
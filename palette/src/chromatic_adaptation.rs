@@ -24,17 +24,25 @@
 //! println!("{:?}", c)
 //! ```
 use crate::convert::{FromColorUnclamped, IntoColorUnclamped};
+use crate::encoding::Linear;
 use crate::float::Float;
-use crate::from_f64;
-use crate::matrix::{multiply_3x3, multiply_xyz, Mat3};
+use crate::lms::{self, LmsMatrix};
+use crate::matrix::{
+    mat3_from_f64, multiply_3x3, multiply_xyz, rgb_to_xyz_matrix, xyz_to_rgb_matrix, Mat3,
+};
+use crate::rgb::{Rgb, RgbSpace};
 use crate::white_point::{Any, WhitePoint};
-use crate::{FloatComponent, Xyz};
+use crate::{cast, FloatComponent, Xyz};
 
 /// Chromatic adaptation methods implemented in the library
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
 pub enum Method {
-    /// Bradford chromatic adaptation method
+    /// Bradford chromatic adaptation method, with the
+    /// [`Bradford`](crate::lms::Bradford) cone response matrix
     Bradford,
-    /// VonKries chromatic adaptation method
+    /// VonKries chromatic adaptation method, with the
+    /// [`VonKries`](crate::lms::VonKries) cone response matrix
     VonKries,
     /// XyzScaling chromatic adaptation method
     XyzScaling,
@@ -92,56 +100,39 @@ impl<T> TransformMatrix<T> for Method
 where
     T: FloatComponent,
 {
-    #[rustfmt::skip]
     #[inline]
     fn get_cone_response(&self) -> ConeResponseMatrices<T> {
         match *self {
-             Method::Bradford => {
-                ConeResponseMatrices::<T> {
-                    ma: [
-                        from_f64(0.8951000), from_f64(0.2664000), from_f64(-0.1614000),
-                        from_f64(-0.7502000), from_f64(1.7135000), from_f64(0.0367000),
-                        from_f64(0.0389000), from_f64(-0.0685000), from_f64(1.0296000)
-                    ],
-                    inv_ma: [
-                        from_f64(0.9869929), from_f64(-0.1470543), from_f64(0.1599627),
-                        from_f64(0.4323053), from_f64(0.5183603), from_f64(0.0492912),
-                        from_f64(-0.0085287), from_f64(0.0400428), from_f64(0.9684867)
-                    ],
-                }
-            }
-             Method::VonKries => {
-                ConeResponseMatrices::<T> {
-                    ma: [
-                        from_f64(0.4002400), from_f64(0.7076000), from_f64(-0.0808100),
-                        from_f64(-0.2263000), from_f64(1.1653200), from_f64(0.0457000),
-                        from_f64(0.0000000), from_f64(0.0000000), from_f64(0.9182200)
-                    ],
-                    inv_ma: [
-                        from_f64(1.8599364), from_f64(-1.1293816), from_f64(0.2198974),
-                        from_f64(0.3611914), from_f64(0.6388125), from_f64(-0.0000064),
-                        from_f64(0.0000000), from_f64(0.0000000), from_f64(1.0890636)
-                    ],
-                }
-            }
-             Method::XyzScaling => {
-                ConeResponseMatrices::<T> {
-                    ma: [
-                        from_f64(1.0000000), from_f64(0.0000000), from_f64(0.0000000),
-                        from_f64(0.0000000), from_f64(1.0000000), from_f64(0.0000000),
-                        from_f64(0.0000000), from_f64(0.0000000), from_f64(1.0000000)
-                    ],
-                    inv_ma: [
-                        from_f64(1.0000000), from_f64(0.0000000), from_f64(0.0000000),
-                        from_f64(0.0000000), from_f64(1.0000000), from_f64(0.0000000),
-                        from_f64(0.0000000), from_f64(0.0000000), from_f64(1.0000000)
-                    ],
-                }
-            }
+            Method::Bradford => ConeResponseMatrices::from_lms_matrix::<lms::Bradford>(),
+            Method::VonKries => ConeResponseMatrices::from_lms_matrix::<lms::VonKries>(),
+            Method::XyzScaling => ConeResponseMatrices {
+                ma: mat3_from_f64(&IDENTITY),
+                inv_ma: mat3_from_f64(&IDENTITY),
+            },
         }
     }
 }
 
+impl<T> ConeResponseMatrices<T>
+where
+    T: FloatComponent,
+{
+    /// Get the cone response matrices of an [`LmsMatrix`].
+    fn from_lms_matrix<M: LmsMatrix>() -> Self {
+        ConeResponseMatrices {
+            ma: mat3_from_f64(&M::XYZ_TO_LMS),
+            inv_ma: mat3_from_f64(&M::LMS_TO_XYZ),
+        }
+    }
+}
+
+#[rustfmt::skip]
+const IDENTITY: Mat3<f64> = [
+    1.0, 0.0, 0.0,
+    0.0, 1.0, 0.0,
+    0.0, 0.0, 1.0,
+];
+
 /// Trait to convert color from one reference white point to another
 ///
 /// Converts a color from the source white point (Swp) to the destination white
@@ -218,6 +209,181 @@ where
     }
 }
 
+/// Adapt `input` from the white point `Swp` to `Dwp`, with Bradford
+/// chromatic adaptation, and write the result to `output`.
+///
+/// The adaptation matrix is only computed once, instead of once per color
+/// as with [`AdaptFrom`], which makes a big difference for images.
+///
+/// # Panics
+///
+/// Panics if `input` and `output` have different lengths.
+pub fn adapt_slice<Swp, Dwp, T>(input: &[Xyz<Swp, T>], output: &mut [Xyz<Dwp, T>])
+where
+    T: FloatComponent,
+    Swp: WhitePoint<T>,
+    Dwp: WhitePoint<T>,
+{
+    adapt_slice_using(input, output, Method::Bradford);
+}
+
+/// Adapt `input` from the white point `Swp` to `Dwp`, with the chromatic
+/// adaptation `method`, and write the result to `output`.
+///
+/// # Panics
+///
+/// Panics if `input` and `output` have different lengths.
+pub fn adapt_slice_using<Swp, Dwp, T, M>(
+    input: &[Xyz<Swp, T>],
+    output: &mut [Xyz<Dwp, T>],
+    method: M,
+) where
+    T: FloatComponent,
+    Swp: WhitePoint<T>,
+    Dwp: WhitePoint<T>,
+    M: TransformMatrix<T>,
+{
+    assert_eq!(
+        input.len(),
+        output.len(),
+        "the input and output slices must have the same length"
+    );
+
+    let matrix = method.generate_transform_matrix(Swp::get_xyz(), Dwp::get_xyz());
+    let input = cast::into_array_slice(input);
+    let output = cast::into_array_slice_mut(output);
+
+    for (input, output) in input.iter().zip(output) {
+        *output = multiply_array(&matrix, *input);
+    }
+}
+
+/// Adapt `colors` from the white point `Swp` to `Dwp` in place, with
+/// Bradford chromatic adaptation.
+///
+/// The adaptation matrix is only computed once, instead of once per color
+/// as with [`AdaptFrom`]:
+///
+/// ```
+/// use palette::chromatic_adaptation::{self, AdaptFrom};
+/// use palette::white_point::{A, D65};
+/// use palette::Xyz;
+///
+/// let mut colors = vec![Xyz::<A, f32>::new(0.3, 0.2, 0.1); 1024];
+/// let adapted: &mut [Xyz<D65, f32>] = chromatic_adaptation::adapt_slice_in_place(&mut colors);
+///
+/// let expected: Xyz<D65, f32> = Xyz::adapt_from(Xyz::<A, f32>::new(0.3, 0.2, 0.1));
+/// assert_eq!(adapted[0], expected);
+/// ```
+pub fn adapt_slice_in_place<Swp, Dwp, T>(colors: &mut [Xyz<Swp, T>]) -> &mut [Xyz<Dwp, T>]
+where
+    T: FloatComponent,
+    Swp: WhitePoint<T>,
+    Dwp: WhitePoint<T>,
+{
+    adapt_slice_in_place_using(colors, Method::Bradford)
+}
+
+/// Adapt `colors` from the white point `Swp` to `Dwp` in place, with the
+/// chromatic adaptation `method`.
+pub fn adapt_slice_in_place_using<Swp, Dwp, T, M>(
+    colors: &mut [Xyz<Swp, T>],
+    method: M,
+) -> &mut [Xyz<Dwp, T>]
+where
+    T: FloatComponent,
+    Swp: WhitePoint<T>,
+    Dwp: WhitePoint<T>,
+    M: TransformMatrix<T>,
+{
+    let matrix = method.generate_transform_matrix(Swp::get_xyz(), Dwp::get_xyz());
+    let colors = cast::into_array_slice_mut(colors);
+    transform_in_place(colors, &matrix);
+    cast::from_array_slice_mut(colors)
+}
+
+/// Convert linear RGB `colors` from the space `Src` to `Dst` in place, with
+/// Bradford chromatic adaptation between their white points.
+///
+/// The RGB to XYZ, adaptation and XYZ to RGB matrices are combined into one
+/// matrix before the colors are converted. Encoded RGB, such as [`Srgb`],
+/// has to be converted to linear RGB first, for example with
+/// [`into_linear`](crate::rgb::Rgb::into_linear).
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::chromatic_adaptation::adapt_rgb_slice_in_place;
+/// use palette::encoding::Srgb;
+/// use palette::rgb::{Rgb, RgbSpace};
+/// use palette::white_point::D50;
+/// use palette::LinSrgb;
+///
+/// // sRGB primaries with a D50 white point, as used by some print workflows.
+/// type SrgbD50 = (Srgb, D50);
+///
+/// let mut colors = vec![LinSrgb::new(1.0f64, 1.0, 1.0), LinSrgb::new(0.5, 0.2, 0.1)];
+/// let adapted: &mut [Rgb<palette::encoding::Linear<SrgbD50>, f64>] =
+///     adapt_rgb_slice_in_place(&mut colors);
+///
+/// // White stays white.
+/// assert_relative_eq!(adapted[0].red, 1.0, epsilon = 1e-4);
+/// assert_relative_eq!(adapted[0].blue, 1.0, epsilon = 1e-4);
+/// ```
+///
+/// [`Srgb`]: crate::Srgb
+pub fn adapt_rgb_slice_in_place<Src, Dst, T>(
+    colors: &mut [Rgb<Linear<Src>, T>],
+) -> &mut [Rgb<Linear<Dst>, T>]
+where
+    T: FloatComponent,
+    Src: RgbSpace<T>,
+    Dst: RgbSpace<T>,
+{
+    adapt_rgb_slice_in_place_using(colors, Method::Bradford)
+}
+
+/// Convert linear RGB `colors` from the space `Src` to `Dst` in place, with
+/// the chromatic adaptation `method` between their white points.
+pub fn adapt_rgb_slice_in_place_using<Src, Dst, T, M>(
+    colors: &mut [Rgb<Linear<Src>, T>],
+    method: M,
+) -> &mut [Rgb<Linear<Dst>, T>]
+where
+    T: FloatComponent,
+    Src: RgbSpace<T>,
+    Dst: RgbSpace<T>,
+    M: TransformMatrix<T>,
+{
+    let adaptation =
+        method.generate_transform_matrix(Src::WhitePoint::get_xyz(), Dst::WhitePoint::get_xyz());
+    let matrix = multiply_3x3(
+        &xyz_to_rgb_matrix::<Dst, T>(),
+        &multiply_3x3(&adaptation, &rgb_to_xyz_matrix::<Src, T>()),
+    );
+
+    let colors = cast::into_array_slice_mut(colors);
+    transform_in_place(colors, &matrix);
+    cast::from_array_slice_mut(colors)
+}
+
+#[inline]
+fn transform_in_place<T: Float>(colors: &mut [[T; 3]], matrix: &Mat3<T>) {
+    for color in colors {
+        *color = multiply_array(matrix, *color);
+    }
+}
+
+#[inline(always)]
+fn multiply_array<T: Float>(matrix: &Mat3<T>, [x, y, z]: [T; 3]) -> [T; 3] {
+    let [m0, m1, m2, m3, m4, m5, m6, m7, m8] = *matrix;
+
+    [
+        m0 * x + m1 * y + m2 * z,
+        m3 * x + m4 * y + m5 * z,
+        m6 * x + m7 * y + m8 * z,
+    ]
+}
+
 #[cfg(test)]
 mod test {
     use super::{AdaptFrom, AdaptInto, Method, TransformMatrix};
@@ -296,4 +462,49 @@ mod test {
         let computed_xyz_scaling: Xyz<C, _> = input_a.adapt_into_using(Method::XyzScaling);
         assert_relative_eq!(expected_xyz_scaling, computed_xyz_scaling, epsilon = 0.0001);
     }
+
+    #[test]
+    fn slices_match_single_colors() {
+        use super::{adapt_rgb_slice_in_place, adapt_slice, adapt_slice_in_place_using};
+        use crate::encoding::{Linear, Srgb};
+        use crate::rgb::Rgb;
+        use crate::LinSrgb;
+
+        let colors = [
+            Xyz::<A, f64>::new(0.315756, 0.162732, 0.015905),
+            Xyz::new(0.5, 0.5, 0.5),
+            Xyz::new(0.1, 0.7, 0.3),
+        ];
+
+        let mut output = [Xyz::<C, f64>::default(); 3];
+        adapt_slice(&colors, &mut output);
+        for (&input, &output) in colors.iter().zip(&output) {
+            let expected: Xyz<C, f64> = input.adapt_into();
+            assert_relative_eq!(output, expected, epsilon = 1e-12);
+        }
+
+        let mut in_place = colors;
+        let in_place: &mut [Xyz<C, f64>] =
+            adapt_slice_in_place_using(&mut in_place, Method::VonKries);
+        for (&input, &output) in colors.iter().zip(&*in_place) {
+            let expected: Xyz<C, f64> = input.adapt_into_using(Method::VonKries);
+            assert_relative_eq!(output, expected, epsilon = 1e-12);
+        }
+
+        let rgb = [LinSrgb::new(0.8f64, 0.3, 0.1), LinSrgb::new(0.1, 0.5, 0.9)];
+        let mut in_place = rgb;
+        let in_place: &mut [Rgb<Linear<(Srgb, D50)>, f64>] =
+            adapt_rgb_slice_in_place(&mut in_place);
+        for (&input, &output) in rgb.iter().zip(&*in_place) {
+            let expected: Rgb<Linear<(Srgb, D50)>, f64> = Rgb::adapt_from(input);
+            assert_relative_eq!(output, expected, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn adapt_slice_different_lengths() {
+        let colors = [Xyz::<A, f64>::new(0.5, 0.5, 0.5); 2];
+        super::adapt_slice(&colors, &mut [Xyz::<C, f64>::default(); 3]);
+    }
 }
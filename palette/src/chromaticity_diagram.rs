@@ -0,0 +1,329 @@
+//! Plot colors in a chromaticity diagram.
+//!
+//! A chromaticity diagram shows where colors are, without their brightness,
+//! relative to the spectral locus, which is the outline of all visible
+//! colors. It's a quick way to see if a set of colors are within a gamut,
+//! or how far a conversion pushed them outside of it.
+//!
+//! [`ChromaticityPlot`] projects colors to either CIE 1931 xy or CIE 1976
+//! u'v' coordinates, together with the spectral locus and optionally the
+//! triangle of an RGB gamut. The coordinates can be passed to any plotting
+//! tool, or drawn as a simple SVG image:
+//!
+//! ```
+//! use palette::chromaticity_diagram::{ChromaticityPlot, Projection};
+//! use palette::encoding::Srgb as SrgbSpace;
+//! use palette::Srgb;
+//!
+//! let colors = [Srgb::new(1.0f64, 0.0, 0.0), Srgb::new(0.5, 0.5, 0.5)];
+//! let plot = ChromaticityPlot::new(Projection::Xy, &colors).with_rgb_gamut::<SrgbSpace>();
+//!
+//! let [x, y] = plot.points()[0].unwrap();
+//! assert!((x - 0.64).abs() < 1e-4 && (y - 0.33).abs() < 1e-4);
+//!
+//! // Red is one of the corners of the sRGB gamut.
+//! assert_eq!(plot.gamut().len(), 3);
+//!
+//! let svg = plot.render_svg(400);
+//! assert!(svg.starts_with("<svg"));
+//! ```
+//!
+//! The spectral locus is the CIE 1931 2° observer, sampled every 10 nm from
+//! 380 nm to 700 nm, which is detailed enough for debugging.
+//!
+//! This module is only available if the `chromaticity_diagram` feature is
+//! enabled.
+
+use core::fmt::Write;
+
+use crate::convert::IntoColor;
+use crate::rgb::Primaries;
+use crate::white_point::D65;
+use crate::{FloatComponent, Xyz, Yxy};
+
+/// The CIE 1931 xy chromaticity of the spectral locus, as `(wavelength, x,
+/// y)`, where the wavelength is in nanometers.
+pub const SPECTRAL_LOCUS: [(u32, f64, f64); 33] = [
+    (380, 0.1741, 0.0050),
+    (390, 0.1738, 0.0049),
+    (400, 0.1733, 0.0048),
+    (410, 0.1726, 0.0048),
+    (420, 0.1714, 0.0051),
+    (430, 0.1689, 0.0069),
+    (440, 0.1644, 0.0109),
+    (450, 0.1566, 0.0177),
+    (460, 0.1440, 0.0297),
+    (470, 0.1241, 0.0578),
+    (480, 0.0913, 0.1327),
+    (490, 0.0454, 0.2950),
+    (500, 0.0082, 0.5384),
+    (510, 0.0139, 0.7502),
+    (520, 0.0743, 0.8338),
+    (530, 0.1547, 0.8059),
+    (540, 0.2296, 0.7543),
+    (550, 0.3016, 0.6923),
+    (560, 0.3731, 0.6245),
+    (570, 0.4441, 0.5547),
+    (580, 0.5125, 0.4866),
+    (590, 0.5752, 0.4242),
+    (600, 0.6270, 0.3725),
+    (610, 0.6658, 0.3340),
+    (620, 0.6915, 0.3083),
+    (630, 0.7079, 0.2920),
+    (640, 0.7190, 0.2809),
+    (650, 0.7260, 0.2740),
+    (660, 0.7300, 0.2700),
+    (670, 0.7320, 0.2680),
+    (680, 0.7334, 0.2666),
+    (690, 0.7344, 0.2656),
+    (700, 0.7347, 0.2653),
+];
+
+/// The coordinate system of a chromaticity diagram.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Projection {
+    /// The CIE 1931 xy chromaticity diagram.
+    Xy,
+
+    /// The CIE 1976 u'v' chromaticity diagram, which is more perceptually
+    /// uniform than xy.
+    UvPrime,
+}
+
+impl Projection {
+    /// Project an xy chromaticity to the coordinates of this diagram.
+    pub fn project_xy<T: FloatComponent>(self, x: T, y: T) -> [T; 2] {
+        match self {
+            Projection::Xy => [x, y],
+            Projection::UvPrime => {
+                let denominator = T::from_f64(-2.0) * x + T::from_f64(12.0) * y + T::from_f64(3.0);
+                [
+                    T::from_f64(4.0) * x / denominator,
+                    T::from_f64(9.0) * y / denominator,
+                ]
+            }
+        }
+    }
+
+    /// Project the chromaticity of `color` to the coordinates of this
+    /// diagram.
+    ///
+    /// Returns `None` for black and other colors where `x + y + z` is zero
+    /// or not finite, since they don't have a chromaticity.
+    pub fn project<Wp, T: FloatComponent>(self, color: Xyz<Wp, T>) -> Option<[T; 2]> {
+        let sum = color.x + color.y + color.z;
+        if !sum.is_normal() {
+            return None;
+        }
+
+        Some(self.project_xy(color.x / sum, color.y / sum))
+    }
+
+    /// The `[width, height]` of the area that contains the spectral locus,
+    /// starting at `[0.0, 0.0]`.
+    fn extent(self) -> [f64; 2] {
+        match self {
+            Projection::Xy => [0.8, 0.9],
+            Projection::UvPrime => [0.7, 0.6],
+        }
+    }
+}
+
+/// Plot-ready chromaticity coordinates of a set of colors.
+///
+/// See the [module documentation](crate::chromaticity_diagram) for an
+/// example.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChromaticityPlot<T = f32> {
+    projection: Projection,
+    points: Vec<Option<[T; 2]>>,
+    locus: Vec<[T; 2]>,
+    gamut: Vec<[T; 2]>,
+}
+
+impl<T> ChromaticityPlot<T>
+where
+    T: FloatComponent,
+{
+    /// Project `colors` to the coordinates of `projection`.
+    ///
+    /// The colors are converted to XYZ with the D65 white point. Use
+    /// [`with_white_point`](ChromaticityPlot::with_white_point) for other
+    /// white points.
+    pub fn new<C>(projection: Projection, colors: &[C]) -> Self
+    where
+        C: IntoColor<Xyz<D65, T>> + Copy,
+    {
+        Self::with_white_point::<C, D65>(projection, colors)
+    }
+
+    /// Project `colors` to the coordinates of `projection`, converting them
+    /// to XYZ with the white point `Wp`.
+    pub fn with_white_point<C, Wp>(projection: Projection, colors: &[C]) -> Self
+    where
+        C: IntoColor<Xyz<Wp, T>> + Copy,
+    {
+        let points = colors
+            .iter()
+            .map(|&color| projection.project(color.into_color()))
+            .collect();
+
+        let locus = SPECTRAL_LOCUS
+            .iter()
+            .map(|&(_, x, y)| projection.project_xy(T::from_f64(x), T::from_f64(y)))
+            .collect();
+
+        ChromaticityPlot {
+            projection,
+            points,
+            locus,
+            gamut: Vec::new(),
+        }
+    }
+
+    /// Add the triangle of the RGB primaries `P`, such as
+    /// [`encoding::Srgb`](crate::encoding::Srgb), as a gamut reference.
+    pub fn with_rgb_gamut<P>(mut self) -> Self
+    where
+        P: Primaries<T>,
+    {
+        let projection = self.projection;
+        let project = |primary: Yxy<_, T>| projection.project_xy(primary.x, primary.y);
+        self.gamut = vec![project(P::red()), project(P::green()), project(P::blue())];
+        self
+    }
+
+    /// The projection of the coordinates.
+    pub fn projection(&self) -> Projection {
+        self.projection
+    }
+
+    /// The coordinates of the colors, in the same order as they were given.
+    ///
+    /// Colors without a chromaticity, such as black, are `None`.
+    pub fn points(&self) -> &[Option<[T; 2]>] {
+        &self.points
+    }
+
+    /// The coordinates of the spectral locus, from the shortest to the
+    /// longest wavelength. The line of purples closes it, between the last
+    /// and the first point.
+    pub fn spectral_locus(&self) -> &[[T; 2]] {
+        &self.locus
+    }
+
+    /// The coordinates of the red, green and blue primaries of the gamut, or
+    /// an empty slice if there's no gamut.
+    pub fn gamut(&self) -> &[[T; 2]] {
+        &self.gamut
+    }
+
+    /// Draw the diagram as an SVG image, that is `width` pixels wide.
+    ///
+    /// The spectral locus and the gamut are drawn as outlines, and each
+    /// color as a small circle. The height follows from the aspect ratio of
+    /// the projection.
+    pub fn render_svg(&self, width: u32) -> String {
+        let [extent_x, extent_y] = self.projection.extent();
+        let scale = f64::from(width) / extent_x;
+        let height = (extent_y * scale).round() as u32;
+
+        let to_pixels = |[x, y]: [T; 2]| {
+            let x = x.to_f64().unwrap_or(0.0) * scale;
+            let y = f64::from(height) - y.to_f64().unwrap_or(0.0) * scale;
+            (x, y)
+        };
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {0} {1}\">\n",
+            width, height
+        );
+
+        write_polygon(
+            &mut svg,
+            self.locus.iter().map(|&point| to_pixels(point)),
+            "#000000",
+        );
+
+        if !self.gamut.is_empty() {
+            write_polygon(
+                &mut svg,
+                self.gamut.iter().map(|&point| to_pixels(point)),
+                "#808080",
+            );
+        }
+
+        for &point in self.points.iter().flatten() {
+            let (x, y) = to_pixels(point);
+            let _ = writeln!(
+                svg,
+                r##"<circle cx="{:.1}" cy="{:.1}" r="3" fill="#ffffff" stroke="#000000"/>"##,
+                x, y
+            );
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+fn write_polygon<I>(svg: &mut String, points: I, stroke: &str)
+where
+    I: Iterator<Item = (f64, f64)>,
+{
+    svg.push_str("<polygon points=\"");
+
+    for (index, (x, y)) in points.enumerate() {
+        if index > 0 {
+            svg.push(' ');
+        }
+
+        let _ = write!(svg, "{:.1},{:.1}", x, y);
+    }
+
+    let _ = writeln!(svg, r#"" fill="none" stroke="{}"/>"#, stroke);
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ChromaticityPlot, Projection, SPECTRAL_LOCUS};
+    use crate::encoding::Srgb as SrgbSpace;
+    use crate::white_point::{WhitePoint, D65};
+    use crate::{Srgb, Xyz};
+
+    #[test]
+    fn uv_prime_white() {
+        let white: Xyz<_, f64> = D65::get_xyz();
+        let [u, v] = Projection::UvPrime.project(white).unwrap();
+        assert_relative_eq!(u, 0.1978, epsilon = 1e-4);
+        assert_relative_eq!(v, 0.4683, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn black_has_no_chromaticity() {
+        let plot = ChromaticityPlot::new(
+            Projection::Xy,
+            &[Srgb::new(0.0f64, 0.0, 0.0), Srgb::new(1.0, 1.0, 1.0)],
+        );
+
+        assert_eq!(plot.points()[0], None);
+        let [x, y] = plot.points()[1].unwrap();
+        assert_relative_eq!(x, 0.3127, epsilon = 1e-4);
+        assert_relative_eq!(y, 0.3290, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn gamut_and_locus() {
+        let plot = ChromaticityPlot::<f64>::new::<Srgb<f64>>(Projection::UvPrime, &[])
+            .with_rgb_gamut::<SrgbSpace>();
+
+        assert_eq!(plot.spectral_locus().len(), SPECTRAL_LOCUS.len());
+        let [u, v] = plot.gamut()[1];
+        assert_relative_eq!(u, 0.125, epsilon = 1e-3);
+        assert_relative_eq!(v, 0.5625, epsilon = 1e-3);
+
+        let svg = plot.render_svg(700);
+        assert!(svg.contains(r#"width="700" height="600""#));
+        assert_eq!(svg.matches("<polygon").count(), 2);
+    }
+}
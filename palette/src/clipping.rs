@@ -0,0 +1,400 @@
+//! Helpers for detecting clipped pixels in buffers of colors.
+//!
+//! Exposure tools often show "zebra" stripes over parts of an image that are
+//! too bright or too dark, and export pipelines may want to warn when a
+//! conversion pushes a lot of pixels outside of a comfortable range. The
+//! [`Thresholds`] type in this module checks each channel of a color, after
+//! converting it to a target color type and encoding, against a lower and an
+//! upper limit.
+//!
+//! ```
+//! use palette::{clipping::Thresholds, LinSrgb, Srgb};
+//!
+//! // Check the pixels as 8 bit sRGB, marking anything at 0 or 255.
+//! let thresholds = Thresholds::new(Srgb::new(0u8, 0, 0), Srgb::new(255u8, 255, 255));
+//!
+//! let pixels = [
+//!     LinSrgb::new(0.5f32, 0.5, 0.5),
+//!     LinSrgb::new(1.2, 0.5, 0.5),
+//!     LinSrgb::new(0.5, 0.0, 0.5),
+//! ];
+//!
+//! let stats = thresholds.count(
+//!     pixels
+//!         .iter()
+//!         .map(|&color| Srgb::<f32>::from_linear(color).into_format::<u8>()),
+//! );
+//! assert_eq!(stats.total, 3);
+//! assert_eq!(stats.clipped, 2);
+//! assert_eq!(stats.above, [1, 0, 0]);
+//! assert_eq!(stats.below, [0, 1, 0]);
+//! ```
+
+use crate::cast::{self, ArrayCast};
+use crate::convert::IntoColorUnclamped;
+
+/// Lower and upper limits for each channel of the color type `C`.
+///
+/// A channel is considered clipped if it's less than or equal to the
+/// corresponding channel in `low`, or greater than or equal to the
+/// corresponding channel in `high`. Colors are converted to `C` with
+/// [`IntoColorUnclamped`] before they are checked, so values outside of the
+/// normal range of `C` will also be detected.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct Thresholds<C> {
+    /// The lower limits. Channels at or below these values are clipped.
+    pub low: C,
+
+    /// The upper limits. Channels at or above these values are clipped.
+    pub high: C,
+}
+
+impl<C> Thresholds<C> {
+    /// Create thresholds from lower and upper limits.
+    pub const fn new(low: C, high: C) -> Self {
+        Thresholds { low, high }
+    }
+}
+
+impl<C, T, const N: usize> Thresholds<C>
+where
+    C: ArrayCast<Array = [T; N]>,
+    T: PartialOrd,
+{
+    /// Check which channels of `color` are clipped.
+    ///
+    /// ```
+    /// use palette::{clipping::Thresholds, Srgb};
+    ///
+    /// let thresholds = Thresholds::new(Srgb::new(0.05, 0.05, 0.05), Srgb::new(0.95, 0.95, 0.95));
+    /// let clipping = thresholds.check(&Srgb::new(1.0, 0.5, 0.0));
+    ///
+    /// assert_eq!(clipping.above, [true, false, false]);
+    /// assert_eq!(clipping.below, [false, false, true]);
+    /// assert!(clipping.is_clipped());
+    /// ```
+    pub fn check(&self, color: &C) -> Clipping<N> {
+        let color = cast::into_array_ref(color);
+        let low = cast::into_array_ref(&self.low);
+        let high = cast::into_array_ref(&self.high);
+
+        let mut clipping = Clipping::new();
+
+        for i in 0..N {
+            clipping.below[i] = color[i] <= low[i];
+            clipping.above[i] = color[i] >= high[i];
+        }
+
+        clipping
+    }
+
+    /// Convert `color` to `C` and check which of its channels are clipped.
+    ///
+    /// The conversion is unclamped, so values that end up outside the
+    /// normal range of `C` will also be detected.
+    ///
+    /// ```
+    /// use palette::{clipping::Thresholds, Hsv, Srgb};
+    ///
+    /// let thresholds = Thresholds::new(Srgb::new(0.0, 0.0, 0.0), Srgb::new(1.0, 1.0, 1.0));
+    /// let clipping = thresholds.check_color(Hsv::new(0.0, 1.0, 1.0));
+    ///
+    /// assert_eq!(clipping.above, [true, false, false]);
+    /// assert_eq!(clipping.below, [false, true, true]);
+    /// ```
+    pub fn check_color<U>(&self, color: U) -> Clipping<N>
+    where
+        U: IntoColorUnclamped<C>,
+    {
+        self.check(&color.into_color_unclamped())
+    }
+
+    /// Count the clipped channels and colors in `colors`.
+    pub fn count<I>(&self, colors: I) -> ClippingStats<N>
+    where
+        I: IntoIterator<Item = C>,
+    {
+        let mut stats = ClippingStats::new();
+
+        for color in colors {
+            stats.add(&self.check(&color));
+        }
+
+        stats
+    }
+
+    /// Check each color in `colors` and write the result to the
+    /// corresponding position in `mask`.
+    ///
+    /// Any extra colors or mask entries are left unchecked and untouched.
+    pub fn mask_into<I>(&self, colors: I, mask: &mut [Clipping<N>])
+    where
+        I: IntoIterator<Item = C>,
+    {
+        for (color, clipping) in colors.into_iter().zip(mask) {
+            *clipping = self.check(&color);
+        }
+    }
+
+    /// Check each color in `colors` and collect the results into a mask with
+    /// one entry per color.
+    ///
+    /// This method is only available if the `std` feature is enabled (this is
+    /// the default).
+    #[cfg(feature = "std")]
+    pub fn mask<I>(&self, colors: I) -> Vec<Clipping<N>>
+    where
+        I: IntoIterator<Item = C>,
+    {
+        colors.into_iter().map(|color| self.check(&color)).collect()
+    }
+
+    /// Replace each clipped color in `colors` with `marker`, and return the
+    /// number of replaced colors. Each color is converted to `C` with
+    /// `convert` before it's checked, which makes it possible to mark a
+    /// working buffer based on how it would look after export.
+    ///
+    /// This is useful for drawing zebra patterns or otherwise highlighting
+    /// clipped areas in a preview.
+    ///
+    /// ```
+    /// use palette::{clipping::Thresholds, LinSrgb, Srgb};
+    ///
+    /// let thresholds = Thresholds::new(Srgb::new(0u8, 0, 0), Srgb::new(250u8, 250, 250));
+    /// let mut pixels = [LinSrgb::new(0.2, 0.3, 0.4), LinSrgb::new(0.99, 0.3, 0.4)];
+    ///
+    /// let marked = thresholds.mark(&mut pixels, LinSrgb::new(1.0, 0.0, 1.0), |&color| {
+    ///     Srgb::from_linear(color).into_format()
+    /// });
+    ///
+    /// assert_eq!(marked, 1);
+    /// assert_eq!(pixels[1], LinSrgb::new(1.0, 0.0, 1.0));
+    /// ```
+    pub fn mark<U, F>(&self, colors: &mut [U], marker: U, mut convert: F) -> usize
+    where
+        U: Clone,
+        F: FnMut(&U) -> C,
+    {
+        let mut marked = 0;
+
+        for color in colors {
+            if self.check(&convert(color)).is_clipped() {
+                *color = marker.clone();
+                marked += 1;
+            }
+        }
+
+        marked
+    }
+}
+
+/// The clipping status of each channel in a single color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Clipping<const N: usize> {
+    /// `true` for each channel that is at or below its lower limit.
+    pub below: [bool; N],
+
+    /// `true` for each channel that is at or above its upper limit.
+    pub above: [bool; N],
+}
+
+impl<const N: usize> Clipping<N> {
+    /// Create a clipping status where no channel is clipped.
+    pub const fn new() -> Self {
+        Clipping {
+            below: [false; N],
+            above: [false; N],
+        }
+    }
+
+    /// Check if any channel is at or below its lower limit.
+    pub fn is_below(&self) -> bool {
+        self.below.iter().any(|&below| below)
+    }
+
+    /// Check if any channel is at or above its upper limit.
+    pub fn is_above(&self) -> bool {
+        self.above.iter().any(|&above| above)
+    }
+
+    /// Check if any channel is clipped in either direction.
+    pub fn is_clipped(&self) -> bool {
+        self.is_below() || self.is_above()
+    }
+}
+
+impl<const N: usize> Default for Clipping<N> {
+    fn default() -> Self {
+        Clipping::new()
+    }
+}
+
+/// Clipping statistics for a collection of colors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClippingStats<const N: usize> {
+    /// The number of colors where each channel is at or below its lower limit.
+    pub below: [usize; N],
+
+    /// The number of colors where each channel is at or above its upper limit.
+    pub above: [usize; N],
+
+    /// The number of colors with at least one clipped channel.
+    pub clipped: usize,
+
+    /// The total number of checked colors.
+    pub total: usize,
+}
+
+impl<const N: usize> ClippingStats<N> {
+    /// Create empty statistics.
+    pub const fn new() -> Self {
+        ClippingStats {
+            below: [0; N],
+            above: [0; N],
+            clipped: 0,
+            total: 0,
+        }
+    }
+
+    /// Add the clipping status of a single color to the statistics.
+    pub fn add(&mut self, clipping: &Clipping<N>) {
+        for i in 0..N {
+            self.below[i] += clipping.below[i] as usize;
+            self.above[i] += clipping.above[i] as usize;
+        }
+
+        self.clipped += clipping.is_clipped() as usize;
+        self.total += 1;
+    }
+
+    /// The fraction of the checked colors that have at least one clipped
+    /// channel, in the range `[0.0, 1.0]`. Returns `0.0` if no colors were
+    /// checked.
+    pub fn clipped_fraction(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.clipped as f64 / self.total as f64
+        }
+    }
+}
+
+impl<const N: usize> Default for ClippingStats<N> {
+    fn default() -> Self {
+        ClippingStats::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Clipping, Thresholds};
+    use crate::white_point::D65;
+    use crate::{Lab, LinSrgb, Srgb, Srgba};
+
+    #[test]
+    fn check_inclusive_limits() {
+        let thresholds = Thresholds::new(Srgb::new(10u8, 10, 10), Srgb::new(200u8, 200, 200));
+
+        assert!(!thresholds.check(&Srgb::new(11u8, 100, 199)).is_clipped());
+
+        let clipping = thresholds.check(&Srgb::new(10u8, 100, 200));
+        assert_eq!(clipping.below, [true, false, false]);
+        assert_eq!(clipping.above, [false, false, true]);
+    }
+
+    #[test]
+    fn check_color() {
+        let thresholds = Thresholds::new(LinSrgb::new(0.0, 0.0, 0.0), LinSrgb::new(1.0, 1.0, 1.0));
+
+        // Saturated Lab colors end up outside the RGB gamut.
+        let clipping = thresholds.check_color(Lab::<D65, f64>::new(50.0, 100.0, 0.0));
+        assert_eq!(clipping.above, [true, false, false]);
+        assert_eq!(clipping.below, [false, true, false]);
+    }
+
+    #[test]
+    fn mark() {
+        let thresholds = Thresholds::new(Srgb::new(0u8, 0, 0), Srgb::new(255u8, 255, 255));
+        let mut colors = [
+            LinSrgb::new(0.5f32, 0.5, 0.5),
+            LinSrgb::new(1.5, 0.5, 0.5),
+            LinSrgb::new(0.5, -0.5, 0.5),
+        ];
+
+        let marked = thresholds.mark(&mut colors, LinSrgb::new(0.0, 0.0, 0.0), |&color| {
+            Srgb::from_linear(color).into_format()
+        });
+
+        assert_eq!(marked, 2);
+        assert_eq!(
+            colors,
+            [
+                LinSrgb::new(0.5, 0.5, 0.5),
+                LinSrgb::new(0.0, 0.0, 0.0),
+                LinSrgb::new(0.0, 0.0, 0.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn check_alpha() {
+        let thresholds = Thresholds::new(
+            Srgba::new(0.0, 0.0, 0.0, 0.0),
+            Srgba::new(1.0, 1.0, 1.0, 1.0),
+        );
+
+        let clipping = thresholds.check(&Srgba::new(0.5, 0.5, 0.5, 1.0));
+        assert_eq!(clipping.above, [false, false, false, true]);
+        assert!(!clipping.is_below());
+    }
+
+    #[test]
+    fn count() {
+        let thresholds = Thresholds::new(Srgb::new(0.0, 0.0, 0.0), Srgb::new(1.0, 1.0, 1.0));
+        let colors = [
+            Srgb::new(0.5, 0.5, 0.5),
+            Srgb::new(1.0, 1.0, 0.5),
+            Srgb::new(0.0, 1.0, 0.5),
+            Srgb::new(0.2, 0.3, 0.4),
+        ];
+
+        let stats = thresholds.count(colors.iter().copied());
+        assert_eq!(stats.below, [1, 0, 0]);
+        assert_eq!(stats.above, [1, 2, 0]);
+        assert_eq!(stats.clipped, 2);
+        assert_eq!(stats.total, 4);
+        assert_eq!(stats.clipped_fraction(), 0.5);
+    }
+
+    #[test]
+    fn count_empty() {
+        let thresholds = Thresholds::new(Srgb::new(0.0, 0.0, 0.0), Srgb::new(1.0, 1.0, 1.0));
+        let stats = thresholds.count(core::iter::empty::<Srgb>());
+
+        assert_eq!(stats.total, 0);
+        assert_eq!(stats.clipped_fraction(), 0.0);
+    }
+
+    #[test]
+    fn mask_into() {
+        let thresholds = Thresholds::new(Srgb::new(0.0, 0.0, 0.0), Srgb::new(1.0, 1.0, 1.0));
+        let colors = [Srgb::new(0.5, 0.5, 0.5), Srgb::new(1.0, 0.5, 0.5)];
+
+        let mut mask = [Clipping::new(); 2];
+        thresholds.mask_into(colors.iter().copied(), &mut mask);
+        assert!(!mask[0].is_clipped());
+        assert!(mask[1].is_above());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn mask() {
+        let thresholds = Thresholds::new(Srgb::new(0.0, 0.0, 0.0), Srgb::new(1.0, 1.0, 1.0));
+        let colors = [Srgb::new(0.5, 0.5, 0.5), Srgb::new(1.0, 0.5, 0.5)];
+
+        let mask = thresholds.mask(colors.iter().copied());
+        assert!(!mask[0].is_clipped());
+        assert!(mask[1].is_above());
+    }
+}
@@ -0,0 +1,717 @@
+use core::any::TypeId;
+use core::marker::PhantomData;
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::convert::FromColorUnclamped;
+use crate::encoding::Srgb;
+use crate::rgb::{Rgb, RgbSpace, RgbStandard};
+use crate::{
+    clamp, clamp_assign, from_f64, Alpha, Clamp, ClampAssign, Component, ComponentWise,
+    FloatComponent, IsWithinBounds,
+};
+
+/// CMYK with an alpha component. See the [`Cmyka` implementation in
+/// `Alpha`](crate::Alpha#Cmyka).
+pub type Cmyka<S = Srgb, T = f32> = Alpha<Cmyk<S, T>, T>;
+
+/// Device independent CMYK.
+///
+/// CMYK describes a color as the amounts of cyan, magenta, yellow and black
+/// ink that are printed on white paper. The conversion to and from
+/// [RGB](crate::rgb::Rgb) is the naive one, where each ink simply subtracts
+/// its opposite RGB primary, and black is used as much as possible:
+///
+/// ```text
+/// black = 1 - max(red, green, blue)
+/// cyan = (1 - red - black) / (1 - black)
+/// red = (1 - cyan) * (1 - black)
+/// ```
+///
+/// Real printing depends on the inks, the paper and the printer, and needs
+/// an ICC profile to be accurate, so this is best used for exchanging CMYK
+/// values with tools that use the same convention. The conversion is done in
+/// the encoded RGB space of `S`, which is sRGB by default. Converting RGB to
+/// CMYK and back is lossless for colors within bounds, while converting CMYK
+/// to RGB and back replaces the gray part of the cyan, magenta and yellow
+/// with black.
+///
+/// ```
+/// use palette::{Cmyk, FromColor, Srgb};
+///
+/// let orange = Cmyk::from_color(Srgb::new(1.0f32, 0.5, 0.0));
+/// assert_eq!(orange, Cmyk::new(0.0, 0.5, 1.0, 0.0));
+///
+/// let gray: Srgb = Srgb::from_color(Cmyk::new(0.0, 0.0, 0.0, 0.25));
+/// assert_eq!(gray, Srgb::new(0.75, 0.75, 0.75));
+/// ```
+#[derive(Debug, ArrayCast, FromColorUnclamped, WithAlpha)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[palette(
+    palette_internal,
+    rgb_standard = "S",
+    component = "T",
+    skip_derives(Rgb, Cmyk)
+)]
+#[repr(C)]
+pub struct Cmyk<S = Srgb, T = f32> {
+    /// The amount of cyan ink, from 0.0 to 1.0.
+    pub cyan: T,
+
+    /// The amount of magenta ink, from 0.0 to 1.0.
+    pub magenta: T,
+
+    /// The amount of yellow ink, from 0.0 to 1.0.
+    pub yellow: T,
+
+    /// The amount of black ink, from 0.0 to 1.0.
+    #[doc(alias = "key")]
+    pub black: T,
+
+    /// The RGB standard this color is converted through. The default is the
+    /// sRGB standard.
+    #[cfg_attr(feature = "serializing", serde(skip))]
+    #[palette(unsafe_zero_sized)]
+    pub standard: PhantomData<S>,
+}
+
+impl<S, T> Copy for Cmyk<S, T> where T: Copy {}
+
+impl<S, T> Clone for Cmyk<S, T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Cmyk<S, T> {
+        Cmyk {
+            cyan: self.cyan.clone(),
+            magenta: self.magenta.clone(),
+            yellow: self.yellow.clone(),
+            black: self.black.clone(),
+            standard: PhantomData,
+        }
+    }
+}
+
+impl<S, T> Cmyk<S, T> {
+    /// Create a CMYK color.
+    pub const fn new(cyan: T, magenta: T, yellow: T, black: T) -> Cmyk<S, T> {
+        Cmyk {
+            cyan,
+            magenta,
+            yellow,
+            black,
+            standard: PhantomData,
+        }
+    }
+
+    /// Convert into another component type.
+    pub fn into_format<U>(self) -> Cmyk<S, U>
+    where
+        T: Component,
+        U: crate::FromComponent<T>,
+    {
+        Cmyk::new(
+            U::from_component(self.cyan),
+            U::from_component(self.magenta),
+            U::from_component(self.yellow),
+            U::from_component(self.black),
+        )
+    }
+
+    /// Convert from another component type.
+    pub fn from_format<U>(color: Cmyk<S, U>) -> Self
+    where
+        U: Component,
+        T: crate::FromComponent<U>,
+    {
+        color.into_format()
+    }
+
+    /// Convert to a `(cyan, magenta, yellow, black)` tuple.
+    pub fn into_components(self) -> (T, T, T, T) {
+        (self.cyan, self.magenta, self.yellow, self.black)
+    }
+
+    /// Convert from a `(cyan, magenta, yellow, black)` tuple.
+    pub fn from_components((cyan, magenta, yellow, black): (T, T, T, T)) -> Self {
+        Self::new(cyan, magenta, yellow, black)
+    }
+
+    #[inline]
+    fn reinterpret_as<St>(self) -> Cmyk<St, T> {
+        Cmyk::new(self.cyan, self.magenta, self.yellow, self.black)
+    }
+}
+
+impl<S, T> Cmyk<S, T>
+where
+    T: Component,
+{
+    /// Return the minimum value of each component.
+    pub fn min_component() -> T {
+        T::zero()
+    }
+
+    /// Return the maximum value of each component.
+    pub fn max_component() -> T {
+        T::max_intensity()
+    }
+}
+
+impl<S, T> Cmyk<S, T>
+where
+    T: FloatComponent,
+{
+    /// Get the total area coverage (TAC), which is the sum of all inks.
+    ///
+    /// A coverage of `1.0` is 100%, so solid cyan and black together have a
+    /// coverage of `2.0`. Printing processes have a limit for how much ink
+    /// the paper can take, which is typically around 300% for coated paper,
+    /// 260% for uncoated paper and 240% for newsprint.
+    pub fn total_area_coverage(&self) -> T {
+        self.cyan + self.magenta + self.yellow + self.black
+    }
+
+    /// Reduce the total area coverage to at most `max_coverage`.
+    ///
+    /// The gray part of the cyan, magenta and yellow is replaced with black,
+    /// as little as needed, which keeps the color the same. If that isn't
+    /// enough, the cyan, magenta and yellow are reduced in proportion, which
+    /// makes the color lighter.
+    ///
+    /// ```
+    /// use palette::{Cmyk, FromColor, Srgb};
+    ///
+    /// let dark_brown: Cmyk = Cmyk::new(0.7, 0.8, 0.9, 0.6);
+    /// let limited = dark_brown.limit_coverage(2.8);
+    /// assert!((limited.total_area_coverage() - 2.8).abs() < 1e-4);
+    ///
+    /// // Still the same color.
+    /// let before = Srgb::from_color(dark_brown);
+    /// let after = Srgb::from_color(limited);
+    /// assert!((before.red - after.red).abs() < 1e-4);
+    /// ```
+    pub fn limit_coverage(self, max_coverage: T) -> Self {
+        if self.total_area_coverage() <= max_coverage {
+            return self;
+        }
+
+        let gray = self.cyan.min(self.magenta).min(self.yellow);
+        let replaced = self.replace_gray(gray);
+
+        if replaced.total_area_coverage() > max_coverage {
+            let black = clamp(replaced.black, T::zero(), max_coverage.max(T::zero()));
+            let inks = replaced.cyan + replaced.magenta + replaced.yellow;
+            let scale = if inks > T::zero() {
+                clamp((max_coverage - black) / inks, T::zero(), T::one())
+            } else {
+                T::zero()
+            };
+
+            return Cmyk::new(
+                replaced.cyan * scale,
+                replaced.magenta * scale,
+                replaced.yellow * scale,
+                black,
+            );
+        }
+
+        // The coverage is concave in the amount of replaced gray, so there's
+        // a single point where it crosses the limit.
+        let mut enough = gray;
+        let mut not_enough = T::zero();
+        for _ in 0..32 {
+            let middle = (enough + not_enough) / from_f64(2.0);
+            if self.replace_gray(middle).total_area_coverage() <= max_coverage {
+                enough = middle;
+            } else {
+                not_enough = middle;
+            }
+        }
+
+        self.replace_gray(enough)
+    }
+
+    /// Create a rich black from `recipe`, with the underprint reduced to stay
+    /// within `max_coverage`.
+    ///
+    /// ```
+    /// use palette::{Cmyk, RichBlack};
+    ///
+    /// let black: Cmyk = Cmyk::rich_black(RichBlack::Neutral, 3.0);
+    /// assert_eq!(black, Cmyk::new(0.6, 0.4, 0.4, 1.0));
+    ///
+    /// // Newsprint can't take that much ink.
+    /// let black: Cmyk = Cmyk::rich_black(RichBlack::Neutral, 2.4);
+    /// assert!(black.total_area_coverage() <= 2.4);
+    /// ```
+    pub fn rich_black(recipe: RichBlack, max_coverage: T) -> Self {
+        let (cyan, magenta, yellow) = recipe.underprint();
+        let (cyan, magenta, yellow): (T, T, T) =
+            (from_f64(cyan), from_f64(magenta), from_f64(yellow));
+
+        let underprint = cyan + magenta + yellow;
+        let scale = clamp((max_coverage - T::one()) / underprint, T::zero(), T::one());
+
+        Cmyk::new(cyan * scale, magenta * scale, yellow * scale, T::one())
+    }
+
+    /// Move `amount` of the gray component from cyan, magenta and yellow to
+    /// black, without changing the color.
+    fn replace_gray(self, amount: T) -> Self {
+        if amount >= T::one() {
+            return Cmyk::new(T::zero(), T::zero(), T::zero(), T::one());
+        }
+
+        let remove = |ink: T| (ink - amount) / (T::one() - amount);
+        Cmyk::new(
+            remove(self.cyan),
+            remove(self.magenta),
+            remove(self.yellow),
+            T::one() - (T::one() - self.black) * (T::one() - amount),
+        )
+    }
+}
+
+/// Recipes for rich black, which is black ink with an underprint of cyan,
+/// magenta and yellow.
+///
+/// Black ink alone looks dark gray in large areas, so they are printed with
+/// an underprint to make them deeper. Rich black should only be used for
+/// large areas and large text, since small details get blurry when the
+/// inks are not perfectly aligned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RichBlack {
+    /// A neutral black, with 60% cyan, 40% magenta and 40% yellow.
+    Neutral,
+
+    /// A cool, slightly blue black, with 60% cyan.
+    Cool,
+
+    /// A warm, slightly red black, with 60% magenta and 30% yellow.
+    Warm,
+}
+
+impl RichBlack {
+    fn underprint(self) -> (f64, f64, f64) {
+        match self {
+            RichBlack::Neutral => (0.6, 0.4, 0.4),
+            RichBlack::Cool => (0.6, 0.0, 0.0),
+            RichBlack::Warm => (0.0, 0.6, 0.3),
+        }
+    }
+}
+
+impl<S, T> PartialEq for Cmyk<S, T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cyan == other.cyan
+            && self.magenta == other.magenta
+            && self.yellow == other.yellow
+            && self.black == other.black
+    }
+}
+
+impl<S, T> Eq for Cmyk<S, T> where T: Eq {}
+
+///<span id="Cmyka"></span>[`Cmyka`](crate::Cmyka) implementations.
+impl<S, T, A> Alpha<Cmyk<S, T>, A> {
+    /// Create a CMYK color with transparency.
+    pub const fn new(cyan: T, magenta: T, yellow: T, black: T, alpha: A) -> Self {
+        Alpha {
+            color: Cmyk::new(cyan, magenta, yellow, black),
+            alpha,
+        }
+    }
+
+    /// Convert to a `(cyan, magenta, yellow, black, alpha)` tuple.
+    pub fn into_components(self) -> (T, T, T, T, A) {
+        (
+            self.color.cyan,
+            self.color.magenta,
+            self.color.yellow,
+            self.color.black,
+            self.alpha,
+        )
+    }
+
+    /// Convert from a `(cyan, magenta, yellow, black, alpha)` tuple.
+    pub fn from_components((cyan, magenta, yellow, black, alpha): (T, T, T, T, A)) -> Self {
+        Self::new(cyan, magenta, yellow, black, alpha)
+    }
+}
+
+impl<S1, S2, T> FromColorUnclamped<Cmyk<S1, T>> for Cmyk<S2, T>
+where
+    S1: RgbStandard<T>,
+    S2: RgbStandard<T>,
+    S1::Space: RgbSpace<T, WhitePoint = <S2::Space as RgbSpace<T>>::WhitePoint>,
+    T: FloatComponent,
+{
+    fn from_color_unclamped(cmyk: Cmyk<S1, T>) -> Self {
+        trace!(conversion cmyk);
+        if TypeId::of::<S1>() == TypeId::of::<S2>() {
+            cmyk.reinterpret_as()
+        } else {
+            let rgb = Rgb::<S1, T>::from_color_unclamped(cmyk);
+            let converted_rgb = Rgb::<S2, T>::from_color_unclamped(rgb);
+            Self::from_color_unclamped(converted_rgb)
+        }
+    }
+}
+
+impl<S, T> FromColorUnclamped<Rgb<S, T>> for Cmyk<S, T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Rgb<S, T>) -> Self {
+        trace!(conversion color);
+        let max = color.red.max(color.green).max(color.blue);
+        let black = T::one() - max;
+
+        // Black is the only ink when there's no light at all.
+        if max <= T::zero() {
+            return Cmyk::new(T::zero(), T::zero(), T::zero(), black);
+        }
+
+        Cmyk::new(
+            (max - color.red) / max,
+            (max - color.green) / max,
+            (max - color.blue) / max,
+            black,
+        )
+    }
+}
+
+impl<S, T> From<(T, T, T, T)> for Cmyk<S, T> {
+    fn from(components: (T, T, T, T)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<S, T> From<Cmyk<S, T>> for (T, T, T, T) {
+    fn from(color: Cmyk<S, T>) -> (T, T, T, T) {
+        color.into_components()
+    }
+}
+
+impl<S, T, A> From<(T, T, T, T, A)> for Alpha<Cmyk<S, T>, A> {
+    fn from(components: (T, T, T, T, A)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<S, T, A> From<Alpha<Cmyk<S, T>, A>> for (T, T, T, T, A) {
+    fn from(color: Alpha<Cmyk<S, T>, A>) -> (T, T, T, T, A) {
+        color.into_components()
+    }
+}
+
+impl_tuple_ref_conversions!([S, T] [] Cmyk<S, T>, (T, T, T, T) => (T, T, T, T));
+impl_tuple_ref_conversions!([S, T, A] [] Alpha<Cmyk<S, T>, A>, (T, T, T, T, A) => (T, T, T, T, A));
+
+impl<S, T> IsWithinBounds for Cmyk<S, T>
+where
+    T: Component,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn is_within_bounds(&self) -> bool {
+        self.cyan >= Self::min_component() && self.cyan <= Self::max_component() &&
+        self.magenta >= Self::min_component() && self.magenta <= Self::max_component() &&
+        self.yellow >= Self::min_component() && self.yellow <= Self::max_component() &&
+        self.black >= Self::min_component() && self.black <= Self::max_component()
+    }
+}
+
+impl<S, T> Clamp for Cmyk<S, T>
+where
+    T: Component,
+{
+    #[inline]
+    fn clamp(self) -> Self {
+        Self::new(
+            clamp(self.cyan, Self::min_component(), Self::max_component()),
+            clamp(self.magenta, Self::min_component(), Self::max_component()),
+            clamp(self.yellow, Self::min_component(), Self::max_component()),
+            clamp(self.black, Self::min_component(), Self::max_component()),
+        )
+    }
+}
+
+impl<S, T> ClampAssign for Cmyk<S, T>
+where
+    T: Component,
+{
+    #[inline]
+    fn clamp_assign(&mut self) {
+        clamp_assign(&mut self.cyan, Self::min_component(), Self::max_component());
+        clamp_assign(
+            &mut self.magenta,
+            Self::min_component(),
+            Self::max_component(),
+        );
+        clamp_assign(
+            &mut self.yellow,
+            Self::min_component(),
+            Self::max_component(),
+        );
+        clamp_assign(
+            &mut self.black,
+            Self::min_component(),
+            Self::max_component(),
+        );
+    }
+}
+
+impl<S, T> ComponentWise for Cmyk<S, T>
+where
+    T: Clone,
+{
+    type Scalar = T;
+
+    fn component_wise<F: FnMut(T, T) -> T>(&self, other: &Cmyk<S, T>, mut f: F) -> Cmyk<S, T> {
+        Cmyk::new(
+            f(self.cyan.clone(), other.cyan.clone()),
+            f(self.magenta.clone(), other.magenta.clone()),
+            f(self.yellow.clone(), other.yellow.clone()),
+            f(self.black.clone(), other.black.clone()),
+        )
+    }
+
+    fn component_wise_self<F: FnMut(T) -> T>(&self, mut f: F) -> Cmyk<S, T> {
+        Cmyk::new(
+            f(self.cyan.clone()),
+            f(self.magenta.clone()),
+            f(self.yellow.clone()),
+            f(self.black.clone()),
+        )
+    }
+}
+
+impl<S, T> Default for Cmyk<S, T>
+where
+    T: Component,
+{
+    fn default() -> Cmyk<S, T> {
+        Cmyk::new(T::zero(), T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl_array_casts!(Cmyk<S, T>, [T; 4]);
+impl_component_access!(Cmyk<S, T>, [T; 4]);
+
+impl<S, T> AbsDiffEq for Cmyk<S, T>
+where
+    T: AbsDiffEq,
+    T::Epsilon: Clone,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    #[rustfmt::skip]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.cyan.abs_diff_eq(&other.cyan, epsilon.clone()) &&
+            self.magenta.abs_diff_eq(&other.magenta, epsilon.clone()) &&
+            self.yellow.abs_diff_eq(&other.yellow, epsilon.clone()) &&
+            self.black.abs_diff_eq(&other.black, epsilon)
+    }
+}
+
+impl<S, T> RelativeEq for Cmyk<S, T>
+where
+    T: RelativeEq,
+    T::Epsilon: Clone,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    #[rustfmt::skip]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.cyan.relative_eq(&other.cyan, epsilon.clone(), max_relative.clone()) &&
+            self.magenta.relative_eq(&other.magenta, epsilon.clone(), max_relative.clone()) &&
+            self.yellow.relative_eq(&other.yellow, epsilon.clone(), max_relative.clone()) &&
+            self.black.relative_eq(&other.black, epsilon, max_relative)
+    }
+}
+
+impl<S, T> UlpsEq for Cmyk<S, T>
+where
+    T: UlpsEq,
+    T::Epsilon: Clone,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    #[rustfmt::skip]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.cyan.ulps_eq(&other.cyan, epsilon.clone(), max_ulps) &&
+            self.magenta.ulps_eq(&other.magenta, epsilon.clone(), max_ulps) &&
+            self.yellow.ulps_eq(&other.yellow, epsilon.clone(), max_ulps) &&
+            self.black.ulps_eq(&other.black, epsilon, max_ulps)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<S, T> bytemuck::Zeroable for Cmyk<S, T> where T: bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<S: 'static, T> bytemuck::Pod for Cmyk<S, T> where T: bytemuck::Pod {}
+
+#[cfg(test)]
+mod test {
+    use super::{Cmyk, RichBlack};
+    use crate::encoding::Srgb;
+    use crate::{Clamp, ComponentWise, FromColor, Hsv, IntoColor, Lab};
+
+    #[test]
+    fn from_rgb() {
+        let cmyk = Cmyk::from_color(crate::Srgb::new(1.0f64, 0.0, 0.0));
+        assert_relative_eq!(cmyk, Cmyk::new(0.0, 1.0, 1.0, 0.0));
+
+        let cmyk = Cmyk::from_color(crate::Srgb::new(0.2f64, 0.4, 0.8));
+        assert_relative_eq!(cmyk, Cmyk::new(0.75, 0.5, 0.0, 0.2), epsilon = 1e-12);
+
+        let black = Cmyk::from_color(crate::Srgb::new(0.0f64, 0.0, 0.0));
+        assert_relative_eq!(black, Cmyk::new(0.0, 0.0, 0.0, 1.0));
+
+        let white = Cmyk::from_color(crate::Srgb::new(1.0f64, 1.0, 1.0));
+        assert_relative_eq!(white, Cmyk::new(0.0, 0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn round_trip() {
+        for &(red, green, blue) in &[
+            (0.2f64, 0.4, 0.8),
+            (0.9, 0.1, 0.5),
+            (0.33, 0.33, 0.33),
+            (0.0, 0.7, 0.0),
+        ] {
+            let rgb = crate::Srgb::new(red, green, blue);
+            let cmyk = Cmyk::<Srgb, f64>::from_color(rgb);
+            let back = crate::Srgb::from_color(cmyk);
+            assert_relative_eq!(back, rgb, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn other_color_types() {
+        let cmyk = Cmyk::<Srgb, f64>::new(0.0, 0.6, 0.9, 0.2);
+
+        let hsv: Hsv<Srgb, f64> = cmyk.into_color();
+        let from_hsv = Cmyk::from_color(hsv);
+        assert_relative_eq!(from_hsv, cmyk, epsilon = 1e-12);
+
+        let lab: Lab<_, f64> = cmyk.into_color();
+        let from_lab = Cmyk::<Srgb, f64>::from_color(lab);
+        assert_relative_eq!(from_lab, cmyk, epsilon = 1e-6);
+
+        // The conversion happens in the encoded space.
+        let srgb: crate::Srgb<f64> = cmyk.into_color();
+        assert_relative_eq!(srgb, crate::Srgb::new(0.8, 0.32, 0.08), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn clamp() {
+        let clamped = Cmyk::<Srgb, f64>::new(-0.5, 0.5, 1.5, 2.0).clamp();
+        assert_relative_eq!(clamped, Cmyk::new(0.0, 0.5, 1.0, 1.0));
+    }
+
+    #[test]
+    fn component_wise() {
+        let a = Cmyk::<Srgb, f64>::new(0.1, 0.2, 0.3, 0.4);
+        let b = Cmyk::new(0.4, 0.3, 0.2, 0.1);
+        assert_relative_eq!(
+            a.component_wise(&b, |a, b| a + b),
+            Cmyk::new(0.5, 0.5, 0.5, 0.5)
+        );
+        assert_relative_eq!(
+            a.component_wise_self(|a| 1.0 - a),
+            Cmyk::new(0.9, 0.8, 0.7, 0.6)
+        );
+    }
+
+    #[test]
+    fn total_area_coverage() {
+        let cmyk = Cmyk::<Srgb, f64>::new(1.0, 0.5, 0.25, 1.0);
+        assert_relative_eq!(cmyk.total_area_coverage(), 2.75);
+    }
+
+    #[test]
+    fn limit_coverage() {
+        let cmyk = Cmyk::<Srgb, f64>::new(0.7, 0.8, 0.9, 0.6);
+        assert_eq!(cmyk.limit_coverage(3.5), cmyk);
+
+        // Replacing gray keeps the color.
+        let limited = cmyk.limit_coverage(2.5);
+        assert_relative_eq!(limited.total_area_coverage(), 2.5, epsilon = 1e-6);
+        assert!(limited.black > cmyk.black);
+        assert_relative_eq!(
+            crate::Srgb::from_color(limited),
+            crate::Srgb::from_color(cmyk),
+            epsilon = 1e-6
+        );
+
+        // Too low to keep the color.
+        let limited = cmyk.limit_coverage(1.2);
+        assert_relative_eq!(limited.total_area_coverage(), 1.2, epsilon = 1e-9);
+        assert_eq!(limited.cyan, 0.0);
+
+        let limited = Cmyk::<Srgb, f64>::new(0.5, 0.0, 0.0, 1.0).limit_coverage(0.8);
+        assert_relative_eq!(limited, Cmyk::new(0.0, 0.0, 0.0, 0.8));
+    }
+
+    #[test]
+    fn rich_black() {
+        let neutral = Cmyk::<Srgb, f64>::rich_black(RichBlack::Neutral, 3.0);
+        assert_relative_eq!(neutral, Cmyk::new(0.6, 0.4, 0.4, 1.0));
+
+        let warm = Cmyk::<Srgb, f64>::rich_black(RichBlack::Warm, 1.6);
+        assert_relative_eq!(warm, Cmyk::new(0.0, 0.4, 0.2, 1.0), epsilon = 1e-12);
+
+        let cool = Cmyk::<Srgb, f64>::rich_black(RichBlack::Cool, 0.5);
+        assert_relative_eq!(cool, Cmyk::new(0.0, 0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn check_min_max_components() {
+        assert_relative_eq!(Cmyk::<Srgb>::min_component(), 0.0);
+        assert_relative_eq!(Cmyk::<Srgb>::max_component(), 1.0);
+    }
+
+    raw_pixel_conversion_tests!(Cmyk<Srgb>: cyan, magenta, yellow, black);
+    raw_pixel_conversion_fail_tests!(Cmyk<Srgb>: cyan, magenta, yellow, black);
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn serialize() {
+        let serialized = ::serde_json::to_string(&Cmyk::<Srgb>::new(0.3, 0.8, 0.1, 0.5)).unwrap();
+
+        assert_eq!(
+            serialized,
+            r#"{"cyan":0.3,"magenta":0.8,"yellow":0.1,"black":0.5}"#
+        );
+    }
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn deserialize() {
+        let deserialized: Cmyk =
+            ::serde_json::from_str(r#"{"cyan":0.3,"magenta":0.8,"yellow":0.1,"black":0.5}"#)
+                .unwrap();
+
+        assert_eq!(deserialized, Cmyk::new(0.3, 0.8, 0.1, 0.5));
+    }
+}
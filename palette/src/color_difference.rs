@@ -1,4 +1,6 @@
-use crate::{convert::IntoColorUnclamped, float::Float, from_f64, FromF64, Lab, Lch};
+use crate::float::{self, Float};
+use crate::white_point::D65;
+use crate::{convert::IntoColorUnclamped, from_f64, FloatComponent, FromF64, Lab, Lch, Oklab};
 
 /// A trait for calculating the color difference between two colors.
 pub trait ColorDifference {
@@ -10,6 +12,131 @@ pub trait ColorDifference {
     fn get_color_difference(self, other: Self) -> Self::Scalar;
 }
 
+/// A trait for calculating the color difference between two colors, with a
+/// selectable formula.
+///
+/// Some of the formulas are not symmetric, and treat `self` as the reference
+/// color, also known as the standard, and `other` as the sample.
+///
+/// ```
+/// use palette::{Cie94Application, DeltaE, DeltaEFormula, Lab};
+///
+/// let reference: Lab = Lab::new(50.0, 2.6772, -79.7751);
+/// let sample = Lab::new(50.0, 0.0, -82.7485);
+///
+/// let cie94 = reference.delta_e(sample, DeltaEFormula::Cie94(Cie94Application::GraphicArts));
+/// let cmc = reference.delta_e(sample, DeltaEFormula::Cmc { lightness: 2.0, chroma: 1.0 });
+///
+/// assert!((cie94 - 1.3950).abs() < 0.0001);
+/// assert!((cmc - 1.7387).abs() < 0.0001);
+/// ```
+pub trait DeltaE {
+    /// The type of the calculated color difference.
+    type Scalar;
+
+    /// Return the difference between `self`, as the reference, and `other`,
+    /// using `formula`.
+    #[must_use]
+    fn delta_e(self, other: Self, formula: DeltaEFormula<Self::Scalar>) -> Self::Scalar;
+}
+
+/// Calculate the difference between two colors of any types, with
+/// `formula`, where `reference` is the reference color.
+///
+/// Both colors are converted to [`Lab`] with the D65 white point, without
+/// clamping, so they don't have to be converted by hand first:
+///
+/// ```
+/// use palette::{delta_e_between, DeltaEFormula, Hsv, Lch, Srgb};
+///
+/// let reference = Srgb::new(0.8f64, 0.2, 0.1);
+/// let sample = Hsv::new_srgb(10.0f64, 0.85, 0.8);
+///
+/// let difference = delta_e_between(reference, sample, DeltaEFormula::Ciede2000);
+/// assert!(difference < 2.0);
+///
+/// // Lab and Lch are accepted too.
+/// let lch = Lch::new(50.0f64, 60.0, 30.0);
+/// assert!(delta_e_between(lch, sample, DeltaEFormula::Cie76) > 0.0);
+/// ```
+///
+/// Colors with another white point, such as D50 print colors, can be
+/// compared with [`DeltaE`] after converting them to `Lab` of that white
+/// point.
+pub fn delta_e_between<A, B, T>(reference: A, sample: B, formula: DeltaEFormula<T>) -> T
+where
+    A: IntoColorUnclamped<Lab<D65, T>>,
+    B: IntoColorUnclamped<Lab<D65, T>>,
+    T: FloatComponent,
+{
+    let reference: Lab<D65, T> = reference.into_color_unclamped();
+    reference.delta_e(sample.into_color_unclamped(), formula)
+}
+
+/// Calculate the difference between two colors of any types, as the
+/// Euclidean distance in [`Oklab`], also known as ΔEOK.
+///
+/// Both colors are converted to `Oklab` without clamping. The difference is
+/// symmetric, and a difference of about `0.02` is just noticeable, which is
+/// roughly a 100th of the scale of [`delta_e_between`].
+///
+/// ```
+/// use palette::{delta_e_ok_between, Hsl, Srgb};
+///
+/// let a = Srgb::new(0.8f64, 0.2, 0.1);
+/// let b = Hsl::new_srgb(7.5f64, 0.777, 0.45);
+///
+/// assert!(delta_e_ok_between(a, b) < 0.02);
+/// assert_eq!(delta_e_ok_between(a, a), 0.0);
+/// ```
+pub fn delta_e_ok_between<A, B, T>(a: A, b: B) -> T
+where
+    A: IntoColorUnclamped<Oklab<T>>,
+    B: IntoColorUnclamped<Oklab<T>>,
+    T: FloatComponent,
+{
+    let a: Oklab<T> = a.into_color_unclamped();
+    let b: Oklab<T> = b.into_color_unclamped();
+    let (dl, da, db) = (a.l - b.l, a.a - b.a, a.b - b.b);
+
+    (dl * dl + da * da + db * db).sqrt()
+}
+
+/// A color difference formula, for [`DeltaE`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub enum DeltaEFormula<T> {
+    /// The Euclidean distance in CIE L\*a\*b\*, from 1976.
+    Cie76,
+
+    /// The CIE94 formula, with the parameters for an application area.
+    Cie94(Cie94Application),
+
+    /// The CMC l:c formula, from the Colour Measurement Committee of the
+    /// Society of Dyers and Colourists. The usual weights are 2:1 for
+    /// acceptability and 1:1 for perceptibility.
+    Cmc {
+        /// The lightness weight, `l`.
+        lightness: T,
+        /// The chroma weight, `c`.
+        chroma: T,
+    },
+
+    /// The CIEDE2000 formula, which is the same as
+    /// [`ColorDifference::get_color_difference`].
+    Ciede2000,
+}
+
+/// The application area parameters of CIE94.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub enum Cie94Application {
+    /// `kL = 1`, `K1 = 0.045` and `K2 = 0.015`.
+    GraphicArts,
+    /// `kL = 2`, `K1 = 0.048` and `K2 = 0.014`.
+    Textiles,
+}
+
 /// Container of components necessary to calculate CIEDE color difference
 pub struct LabColorDiff<T> {
     /// Lab color lightness
@@ -77,7 +204,7 @@ pub fn get_ciede_difference<T: Float + FromF64>(this: LabColorDiff<T>, other: La
         if b == T::zero() && a_prime == T::zero() {
             from_f64(0.0)
         } else {
-            let result = b.atan2(a_prime).to_degrees();
+            let result = float::atan2(b, a_prime).to_degrees();
             if result < T::zero() {
                 result + from_f64(360.0)
             } else {
@@ -102,7 +229,7 @@ pub fn get_ciede_difference<T: Float + FromF64>(this: LabColorDiff<T>, other: La
 
     let delta_big_h_prime = from_f64::<T>(2.0)
         * (c_one_prime * c_two_prime).sqrt()
-        * (delta_h_prime / from_f64(2.0) * pi_over_180).sin();
+        * float::sin(delta_h_prime / from_f64(2.0) * pi_over_180);
     let h_bar_prime = if c_one_prime == T::zero() || c_two_prime == T::zero() {
         h_one_prime + h_two_prime
     } else if h_prime_difference > from_f64(180.0) {
@@ -115,10 +242,10 @@ pub fn get_ciede_difference<T: Float + FromF64>(this: LabColorDiff<T>, other: La
     let c_bar_prime = (c_one_prime + c_two_prime) / from_f64(2.0);
 
     let t: T = from_f64::<T>(1.0)
-        - from_f64::<T>(0.17) * ((h_bar_prime - from_f64(30.0)) * pi_over_180).cos()
-        + from_f64::<T>(0.24) * ((h_bar_prime * from_f64(2.0)) * pi_over_180).cos()
-        + from_f64::<T>(0.32) * ((h_bar_prime * from_f64(3.0) + from_f64(6.0)) * pi_over_180).cos()
-        - from_f64::<T>(0.20) * ((h_bar_prime * from_f64(4.0) - from_f64(63.0)) * pi_over_180).cos();
+        - from_f64::<T>(0.17) * float::cos((h_bar_prime - from_f64(30.0)) * pi_over_180)
+        + from_f64::<T>(0.24) * float::cos((h_bar_prime * from_f64(2.0)) * pi_over_180)
+        + from_f64::<T>(0.32) * float::cos((h_bar_prime * from_f64(3.0) + from_f64(6.0)) * pi_over_180)
+        - from_f64::<T>(0.20) * float::cos((h_bar_prime * from_f64(4.0) - from_f64(63.0)) * pi_over_180);
     let s_l = from_f64::<T>(1.0)
         + ((from_f64::<T>(0.015) * (l_bar - from_f64(50.0)) * (l_bar - from_f64(50.0)))
             / ((l_bar - from_f64(50.0)) * (l_bar - from_f64(50.0)) + from_f64(20.0)).sqrt());
@@ -126,13 +253,14 @@ pub fn get_ciede_difference<T: Float + FromF64>(this: LabColorDiff<T>, other: La
     let s_h = from_f64::<T>(1.0) + from_f64::<T>(0.015) * c_bar_prime * t;
 
     let delta_theta = from_f64::<T>(30.0)
-        * (-(((h_bar_prime - from_f64(275.0)) / from_f64(25.0))
-            * ((h_bar_prime - from_f64(275.0)) / from_f64(25.0))))
-        .exp();
-    let c_bar_prime_pow_seven = c_bar_prime.powi(7);
+        * float::exp(
+            -(((h_bar_prime - from_f64(275.0)) / from_f64(25.0))
+                * ((h_bar_prime - from_f64(275.0)) / from_f64(25.0))),
+        );
+    let c_bar_prime_pow_seven = float::powi(c_bar_prime, 7);
     let r_c: T = from_f64::<T>(2.0)
         * (c_bar_prime_pow_seven / (c_bar_prime_pow_seven + twenty_five_pow_seven)).sqrt();
-    let r_t = -r_c * (from_f64::<T>(2.0) * delta_theta * pi_over_180).sin();
+    let r_t = -r_c * float::sin(from_f64::<T>(2.0) * delta_theta * pi_over_180);
 
     let one = from_f64::<T>(1.0);
     let k_l = one;
@@ -147,3 +275,236 @@ pub fn get_ciede_difference<T: Float + FromF64>(this: LabColorDiff<T>, other: La
         + (r_t * delta_c_prime * delta_big_h_prime) / (k_c * s_c * k_h * s_h))
         .sqrt()
 }
+
+/// Calculate the color difference with `formula`, where `this` is the
+/// reference color.
+pub fn get_delta_e<T: Float + FromF64>(
+    this: LabColorDiff<T>,
+    other: LabColorDiff<T>,
+    formula: DeltaEFormula<T>,
+) -> T {
+    match formula {
+        DeltaEFormula::Cie76 => get_cie76_difference(this, other),
+        DeltaEFormula::Cie94(application) => get_cie94_difference(this, other, application),
+        DeltaEFormula::Cmc { lightness, chroma } => {
+            get_cmc_difference(this, other, lightness, chroma)
+        }
+        DeltaEFormula::Ciede2000 => get_ciede_difference(this, other),
+    }
+}
+
+/// Calculate the CIE76 color difference, which is the Euclidean distance in
+/// Lab.
+pub fn get_cie76_difference<T: Float>(this: LabColorDiff<T>, other: LabColorDiff<T>) -> T {
+    let delta_l = this.l - other.l;
+    let delta_a = this.a - other.a;
+    let delta_b = this.b - other.b;
+
+    (delta_l * delta_l + delta_a * delta_a + delta_b * delta_b).sqrt()
+}
+
+/// The squared hue difference, `ΔH²`, from the Lab differences.
+fn get_delta_h_squared<T: Float>(this: &LabColorDiff<T>, other: &LabColorDiff<T>) -> T {
+    let delta_a = this.a - other.a;
+    let delta_b = this.b - other.b;
+    let delta_c = this.chroma - other.chroma;
+
+    (delta_a * delta_a + delta_b * delta_b - delta_c * delta_c).max(T::zero())
+}
+
+/// Calculate the CIE94 color difference, where `this` is the reference
+/// color.
+pub fn get_cie94_difference<T: Float + FromF64>(
+    this: LabColorDiff<T>,
+    other: LabColorDiff<T>,
+    application: Cie94Application,
+) -> T {
+    let (k_l, k_1, k_2) = match application {
+        Cie94Application::GraphicArts => (1.0, 0.045, 0.015),
+        Cie94Application::Textiles => (2.0, 0.048, 0.014),
+    };
+
+    let delta_l = (this.l - other.l) / from_f64(k_l);
+    let delta_c = this.chroma - other.chroma;
+    let delta_h_squared = get_delta_h_squared(&this, &other);
+
+    let s_c = from_f64::<T>(1.0) + from_f64::<T>(k_1) * this.chroma;
+    let s_h = from_f64::<T>(1.0) + from_f64::<T>(k_2) * this.chroma;
+
+    (delta_l * delta_l + (delta_c / s_c) * (delta_c / s_c) + delta_h_squared / (s_h * s_h)).sqrt()
+}
+
+/// Calculate the CMC l:c color difference, where `this` is the reference
+/// color.
+pub fn get_cmc_difference<T: Float + FromF64>(
+    this: LabColorDiff<T>,
+    other: LabColorDiff<T>,
+    lightness: T,
+    chroma: T,
+) -> T {
+    let delta_l = this.l - other.l;
+    let delta_c = this.chroma - other.chroma;
+    let delta_h_squared = get_delta_h_squared(&this, &other);
+
+    let mut hue = float::atan2(this.b, this.a).to_degrees();
+    if hue < T::zero() {
+        hue = hue + from_f64(360.0);
+    }
+
+    let s_l = if this.l < from_f64(16.0) {
+        from_f64(0.511)
+    } else {
+        from_f64::<T>(0.040975) * this.l / (from_f64::<T>(1.0) + from_f64::<T>(0.01765) * this.l)
+    };
+    let s_c = from_f64::<T>(0.0638) * this.chroma
+        / (from_f64::<T>(1.0) + from_f64::<T>(0.0131) * this.chroma)
+        + from_f64(0.638);
+
+    let chroma_pow_four = this.chroma * this.chroma * this.chroma * this.chroma;
+    let f = (chroma_pow_four / (chroma_pow_four + from_f64(1900.0))).sqrt();
+    let t = if hue >= from_f64(164.0) && hue <= from_f64(345.0) {
+        from_f64::<T>(0.56)
+            + (from_f64::<T>(0.2) * float::cos((hue + from_f64(168.0)).to_radians())).abs()
+    } else {
+        from_f64::<T>(0.36)
+            + (from_f64::<T>(0.4) * float::cos((hue + from_f64(35.0)).to_radians())).abs()
+    };
+    let s_h = s_c * (f * t + from_f64(1.0) - f);
+
+    let delta_l = delta_l / (lightness * s_l);
+    let delta_c = delta_c / (chroma * s_c);
+
+    (delta_l * delta_l + delta_c * delta_c + delta_h_squared / (s_h * s_h)).sqrt()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Cie94Application, DeltaE, DeltaEFormula};
+    use crate::convert::FromColorUnclamped;
+    use crate::white_point::D65;
+    use crate::{ColorDifference, Lab, Lch};
+
+    type Lab64 = Lab<D65, f64>;
+
+    // The expected values are from an independent implementation of the
+    // formulas.
+    #[test]
+    fn cie94() {
+        let reference: Lab64 = Lab::new(60.0, 20.0, 30.0);
+        let sample = Lab::new(55.0, 25.0, 10.0);
+
+        let graphic_arts = DeltaEFormula::Cie94(Cie94Application::GraphicArts);
+        let textiles = DeltaEFormula::Cie94(Cie94Application::Textiles);
+
+        assert_relative_eq!(
+            reference.delta_e(sample, graphic_arts),
+            13.45444545454557,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            reference.delta_e(sample, textiles),
+            12.973419371804901,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn cmc() {
+        let reference: Lab64 = Lab::new(60.0, 20.0, 30.0);
+        let sample = Lab::new(55.0, 25.0, 10.0);
+
+        let acceptability = DeltaEFormula::Cmc {
+            lightness: 2.0,
+            chroma: 1.0,
+        };
+        let perceptibility = DeltaEFormula::Cmc {
+            lightness: 1.0,
+            chroma: 1.0,
+        };
+
+        assert_relative_eq!(
+            reference.delta_e(sample, acceptability),
+            23.204314653783555,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            reference.delta_e(sample, perceptibility),
+            23.4859887077466,
+            epsilon = 1e-9
+        );
+
+        // Dark reference colors use a constant lightness weight.
+        let dark: Lab64 = Lab::new(10.0, 1.0, -2.0);
+        assert_relative_eq!(
+            dark.delta_e(Lab::new(12.0, 3.0, 0.0), acceptability),
+            4.273616111370194,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn cie76_and_ciede2000() {
+        let reference: Lab64 = Lab::new(50.0, 10.0, 0.0);
+        let sample = Lab::new(53.0, 6.0, 0.0);
+
+        assert_relative_eq!(reference.delta_e(sample, DeltaEFormula::Cie76), 5.0);
+        assert_relative_eq!(
+            reference.delta_e(sample, DeltaEFormula::Ciede2000),
+            reference.get_color_difference(sample)
+        );
+    }
+
+    #[test]
+    fn lch_matches_lab() {
+        let reference: Lab64 = Lab::new(60.0, 20.0, 30.0);
+        let sample = Lab::new(55.0, 25.0, 10.0);
+        let formula = DeltaEFormula::Cmc {
+            lightness: 2.0,
+            chroma: 1.0,
+        };
+
+        assert_relative_eq!(
+            Lch::from_color_unclamped(reference)
+                .delta_e(Lch::from_color_unclamped(sample), formula),
+            reference.delta_e(sample, formula),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn between_different_types() {
+        use super::{delta_e_between, delta_e_ok_between};
+        use crate::{Hsv, Oklab, Srgb};
+
+        let reference = Srgb::new(0.8f64, 0.2, 0.1);
+        let sample = Hsv::new_srgb(30.0f64, 0.5, 0.6);
+
+        for &formula in &[
+            DeltaEFormula::Cie76,
+            DeltaEFormula::Cie94(Cie94Application::Textiles),
+            DeltaEFormula::Cmc {
+                lightness: 2.0,
+                chroma: 1.0,
+            },
+            DeltaEFormula::Ciede2000,
+        ] {
+            let expected = Lab64::from_color_unclamped(reference)
+                .delta_e(Lab64::from_color_unclamped(sample), formula);
+            assert_eq!(delta_e_between(reference, sample, formula), expected);
+        }
+
+        let a = Oklab::from_color_unclamped(reference);
+        let b = Oklab::from_color_unclamped(sample);
+        let expected = ((a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt();
+        assert_relative_eq!(
+            delta_e_ok_between(reference, sample),
+            expected,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            delta_e_ok_between(reference, sample),
+            delta_e_ok_between(sample, reference),
+            epsilon = 1e-12
+        );
+    }
+}
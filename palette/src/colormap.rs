@@ -0,0 +1,275 @@
+//! Perceptually balanced diverging and cyclic colormaps.
+//!
+//! The colormaps are constructed in [`Oklch`], where lightness and chroma are
+//! close to perceptually uniform, which makes it possible to guarantee some
+//! properties instead of relying on presets:
+//!
+//! * A [`Diverging`] map has two arms with the same lightness and chroma at
+//!   their ends, that meet at a neutral gray in the middle. The lightness
+//!   changes at the same rate along both arms, so neither side looks more
+//!   important than the other.
+//! * A [`Cyclic`] map goes around the hue circle and ends where it started,
+//!   so there's no visible seam when it's used for angles or phases.
+//!
+//! The colors may be outside the gamut of the output color space, especially
+//! with high chroma, and may need to be clamped after conversion. Any
+//! colormap, including custom ones, can be checked with [`ColormapLint`].
+//! Maps that should stay readable with color vision deficiencies can be
+//! generated with [`CvdSafeRamp`].
+//!
+//! ```
+//! use palette::colormap::{Cyclic, Diverging};
+//! use palette::{FromColor, Srgb};
+//!
+//! // Blue to red, through a light gray.
+//! let diverging = Diverging::new(255.0, 25.0, 0.55, 0.12, 0.95);
+//! let colors: Vec<Srgb> = diverging.take(9).map(Srgb::from_color).collect();
+//!
+//! // A cyclic map with a constant lightness.
+//! let cyclic = Cyclic::new(0.7, 0.1, 0.0);
+//! assert_eq!(cyclic.get(0.0), cyclic.get(1.0));
+//! ```
+
+#[cfg(feature = "std")]
+mod lint;
+#[cfg(feature = "std")]
+mod safe;
+
+#[cfg(feature = "std")]
+pub use self::lint::{ColormapAnalysis, ColormapLint, ColormapProblem, ProblemKind};
+#[cfg(feature = "std")]
+pub use self::safe::CvdSafeRamp;
+
+use crate::{clamp, float, from_f64, FloatComponent, OklabHue, Oklch};
+
+/// A diverging colormap, with two hues that meet at a neutral midpoint.
+///
+/// The factor goes from `0.0`, at the end with the first hue, to `1.0`, at
+/// the end with the second hue, with the neutral color at `0.5`.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct Diverging<T> {
+    /// The hue of the lower end.
+    pub low_hue: OklabHue<T>,
+
+    /// The hue of the upper end.
+    pub high_hue: OklabHue<T>,
+
+    /// The lightness of both ends.
+    pub end_lightness: T,
+
+    /// The chroma of both ends.
+    pub end_chroma: T,
+
+    /// The lightness of the neutral midpoint.
+    pub neutral_lightness: T,
+}
+
+impl<T> Diverging<T>
+where
+    T: FloatComponent,
+{
+    /// Create a diverging map from the hues of the two ends, their shared
+    /// lightness and chroma, and the lightness of the neutral midpoint.
+    pub fn new<H: Into<OklabHue<T>>>(
+        low_hue: H,
+        high_hue: H,
+        end_lightness: T,
+        end_chroma: T,
+        neutral_lightness: T,
+    ) -> Self {
+        Diverging {
+            low_hue: low_hue.into(),
+            high_hue: high_hue.into(),
+            end_lightness,
+            end_chroma,
+            neutral_lightness,
+        }
+    }
+
+    /// Create a diverging map with ends that are similar to `low` and
+    /// `high`.
+    ///
+    /// The hues are kept, but the lightness of the ends is set to their
+    /// average, and the chroma to the lower of the two, so the arms are
+    /// balanced.
+    pub fn from_ends(low: Oklch<T>, high: Oklch<T>, neutral_lightness: T) -> Self {
+        let two = from_f64::<T>(2.0);
+
+        Diverging {
+            low_hue: low.hue,
+            high_hue: high.hue,
+            end_lightness: (low.l + high.l) / two,
+            end_chroma: low.chroma.min(high.chroma),
+            neutral_lightness,
+        }
+    }
+
+    /// Get the color at `factor`, which is clamped to `[0.0, 1.0]`.
+    pub fn get(&self, factor: T) -> Oklch<T> {
+        let factor = clamp(factor, T::zero(), T::one());
+        let half = from_f64::<T>(0.5);
+
+        // The distance from the midpoint, from 0.0 in the middle to 1.0 at
+        // the ends, is the same for both arms.
+        let (distance, hue) = if factor < half {
+            ((half - factor) / half, self.low_hue)
+        } else {
+            ((factor - half) / half, self.high_hue)
+        };
+
+        Oklch {
+            l: self.neutral_lightness + distance * (self.end_lightness - self.neutral_lightness),
+            chroma: distance * self.end_chroma,
+            hue,
+        }
+    }
+
+    /// Get `n` evenly spaced colors, from the lower end to the upper end.
+    pub fn take(&self, n: usize) -> impl Iterator<Item = Oklch<T>> + '_ {
+        evenly_spaced(n).map(move |factor| self.get(factor))
+    }
+}
+
+/// A cyclic colormap, that goes once around the hue circle.
+///
+/// The factor goes from `0.0` to `1.0`, and both ends have the same color.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct Cyclic<T> {
+    /// The average lightness.
+    pub lightness: T,
+
+    /// The chroma, which is the same everywhere.
+    pub chroma: T,
+
+    /// The hue at the start and end of the cycle.
+    pub start_hue: OklabHue<T>,
+
+    /// How far the lightness goes above and below the average, in a cosine
+    /// wave that makes one full period. `0.0` gives a constant lightness.
+    pub lightness_amplitude: T,
+}
+
+impl<T> Cyclic<T>
+where
+    T: FloatComponent,
+{
+    /// Create a cyclic map with a constant lightness and chroma.
+    pub fn new<H: Into<OklabHue<T>>>(lightness: T, chroma: T, start_hue: H) -> Self {
+        Cyclic {
+            lightness,
+            chroma,
+            start_hue: start_hue.into(),
+            lightness_amplitude: T::zero(),
+        }
+    }
+
+    /// Let the lightness vary by `amplitude` around the average, which makes
+    /// the map easier to read for people with color vision deficiencies.
+    pub fn with_lightness_amplitude(mut self, amplitude: T) -> Self {
+        self.lightness_amplitude = amplitude;
+        self
+    }
+
+    /// Get the color at `factor`. Factors outside `[0.0, 1.0]` wrap around.
+    pub fn get(&self, factor: T) -> Oklch<T> {
+        let factor = factor - factor.floor();
+        let full_turn = from_f64::<T>(360.0);
+        let angle = (factor * full_turn).to_radians();
+
+        Oklch {
+            l: self.lightness + self.lightness_amplitude * float::cos(angle),
+            chroma: self.chroma,
+            hue: self.start_hue + factor * full_turn,
+        }
+    }
+
+    /// Get `n` colors that are evenly spaced around the cycle, without
+    /// repeating the first color at the end.
+    pub fn take(&self, n: usize) -> impl Iterator<Item = Oklch<T>> + '_ {
+        (0..n).map(move |index| self.get(from_f64::<T>(index as f64) / from_f64(n as f64)))
+    }
+}
+
+/// `n` evenly spaced factors from `0.0` to `1.0`, inclusive.
+fn evenly_spaced<T: FloatComponent>(n: usize) -> impl Iterator<Item = T> {
+    let last = from_f64::<T>(n.saturating_sub(1).max(1) as f64);
+    (0..n).map(move |index| from_f64::<T>(index as f64) / last)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Cyclic, Diverging};
+    use crate::{OklabHue, Oklch};
+
+    #[test]
+    fn diverging_is_balanced() {
+        let map = Diverging::new(250.0f64, 30.0, 0.5, 0.15, 0.95);
+
+        let middle = map.get(0.5);
+        assert_relative_eq!(middle.l, 0.95);
+        assert_relative_eq!(middle.chroma, 0.0);
+
+        for &offset in &[0.1, 0.25, 0.4, 0.5] {
+            let low = map.get(0.5 - offset);
+            let high = map.get(0.5 + offset);
+
+            assert_relative_eq!(low.l, high.l, epsilon = 1e-12);
+            assert_relative_eq!(low.chroma, high.chroma, epsilon = 1e-12);
+            assert_eq!(low.hue, OklabHue::from(250.0));
+            assert_eq!(high.hue, OklabHue::from(30.0));
+        }
+
+        assert_relative_eq!(map.get(0.0).l, 0.5);
+        assert_relative_eq!(map.get(-1.0).chroma, 0.15);
+    }
+
+    #[test]
+    fn diverging_from_ends() {
+        let map = Diverging::from_ends(
+            Oklch::new(0.4f64, 0.2, 260.0),
+            Oklch::new(0.6, 0.15, 40.0),
+            0.9,
+        );
+
+        assert_relative_eq!(map.end_lightness, 0.5);
+        assert_relative_eq!(map.end_chroma, 0.15);
+
+        let colors: Vec<_> = map.take(5).collect();
+        assert_eq!(colors.len(), 5);
+        assert_relative_eq!(colors[2].l, 0.9);
+        assert_relative_eq!(colors[0].l, colors[4].l);
+    }
+
+    #[test]
+    fn cyclic_matches_ends() {
+        let map = Cyclic::new(0.7f64, 0.1, 120.0).with_lightness_amplitude(0.1);
+
+        let start = map.get(0.0);
+        let end = map.get(1.0);
+        assert_relative_eq!(start, end, epsilon = 1e-12);
+        assert_relative_eq!(start.l, 0.8);
+        assert_relative_eq!(map.get(0.5).l, 0.6, epsilon = 1e-12);
+        assert_relative_eq!(
+            map.get(0.25).hue.to_positive_degrees(),
+            210.0,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(map.get(1.25), map.get(0.25), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn cyclic_take() {
+        let map = Cyclic::new(0.7f64, 0.1, 0.0);
+        let hues: Vec<_> = map
+            .take(4)
+            .map(|color| color.hue.to_positive_degrees())
+            .collect();
+
+        assert_eq!(hues.len(), 4);
+        for (hue, expected) in hues.iter().zip(&[0.0, 90.0, 180.0, 270.0]) {
+            assert_relative_eq!(hue, expected, epsilon = 1e-9);
+        }
+    }
+}
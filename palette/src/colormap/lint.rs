@@ -0,0 +1,303 @@
+use core::cmp::Ordering;
+use core::ops::Range;
+
+use crate::convert::IntoColorUnclamped;
+use crate::{from_f64, FloatComponent, Oklab};
+
+/// Settings for analyzing the perceptual uniformity of a colormap.
+///
+/// The analysis measures the lightness and the color difference between
+/// neighboring colors in [`Oklab`], and reports the regions where the
+/// lightness changes direction, or where a step is much larger than the
+/// typical step, which shows up as a band.
+///
+/// ```
+/// use palette::colormap::{ColormapLint, ProblemKind};
+/// use palette::Srgb;
+///
+/// let colors = [
+///     Srgb::new(0.1, 0.1, 0.3),
+///     Srgb::new(0.2, 0.2, 0.4),
+///     Srgb::new(0.3, 0.3, 0.5),
+///     Srgb::new(0.9, 0.8, 0.2),
+///     Srgb::new(0.8, 0.7, 0.1),
+/// ];
+///
+/// let analysis = ColormapLint::new().analyze(&colors);
+///
+/// assert!(!analysis.is_lightness_monotonic());
+/// assert_eq!(analysis.problems[0].kind, ProblemKind::DeltaESpike);
+/// assert_eq!(analysis.problems[0].range, 2..4);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct ColormapLint<T> {
+    /// How many times larger than the median step a step can be before it's
+    /// reported as a spike.
+    pub spike_ratio: T,
+
+    /// How much the lightness can go against the overall direction before
+    /// it's reported as a reversal.
+    pub lightness_tolerance: T,
+}
+
+impl<T> ColormapLint<T>
+where
+    T: FloatComponent,
+{
+    /// Create the default settings, which report steps that are more than
+    /// twice the median step, and lightness reversals larger than `0.001`.
+    pub fn new() -> Self {
+        ColormapLint {
+            spike_ratio: from_f64(2.0),
+            lightness_tolerance: from_f64(0.001),
+        }
+    }
+
+    /// Change the spike ratio.
+    pub fn with_spike_ratio(mut self, spike_ratio: T) -> Self {
+        self.spike_ratio = spike_ratio;
+        self
+    }
+
+    /// Change the lightness tolerance.
+    pub fn with_lightness_tolerance(mut self, lightness_tolerance: T) -> Self {
+        self.lightness_tolerance = lightness_tolerance;
+        self
+    }
+
+    /// Analyze the colormap `colors`, which is expected to be sampled evenly.
+    ///
+    /// The overall lightness direction is from the first to the last color.
+    /// Diverging maps change direction in the middle on purpose, so each of
+    /// their halves should be analyzed separately.
+    pub fn analyze<C>(&self, colors: &[C]) -> ColormapAnalysis<T>
+    where
+        C: Copy + IntoColorUnclamped<Oklab<T>>,
+    {
+        let oklab: Vec<Oklab<T>> = colors
+            .iter()
+            .map(|&color| color.into_color_unclamped())
+            .collect();
+
+        let lightness: Vec<T> = oklab.iter().map(|color| color.l).collect();
+        let steps: Vec<T> = oklab
+            .windows(2)
+            .map(|pair| {
+                let difference = pair[0] - pair[1];
+                (difference.l * difference.l
+                    + difference.a * difference.a
+                    + difference.b * difference.b)
+                    .sqrt()
+            })
+            .collect();
+
+        let direction = match (lightness.first(), lightness.last()) {
+            (Some(&first), Some(&last)) if last < first => -T::one(),
+            _ => T::one(),
+        };
+
+        let reversals = lightness.windows(2).map(|pair| {
+            let backwards = (pair[0] - pair[1]) * direction;
+            if backwards > self.lightness_tolerance {
+                Some(backwards)
+            } else {
+                None
+            }
+        });
+
+        let median = median(&steps);
+        let spikes = steps.iter().map(|&step| {
+            if median > T::zero() && step > median * self.spike_ratio {
+                Some(step / median)
+            } else {
+                None
+            }
+        });
+
+        let mut problems = Vec::new();
+        collect_regions(ProblemKind::LightnessReversal, reversals, &mut problems);
+        collect_regions(ProblemKind::DeltaESpike, spikes, &mut problems);
+        problems.sort_by_key(|problem| problem.range.start);
+
+        ColormapAnalysis {
+            lightness,
+            steps,
+            problems,
+        }
+    }
+}
+
+impl<T> Default for ColormapLint<T>
+where
+    T: FloatComponent,
+{
+    fn default() -> Self {
+        ColormapLint::new()
+    }
+}
+
+/// The result of analyzing a colormap with [`ColormapLint`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColormapAnalysis<T> {
+    /// The Oklab lightness of each color.
+    pub lightness: Vec<T>,
+
+    /// The Oklab color difference between each pair of neighboring colors.
+    pub steps: Vec<T>,
+
+    /// The problem regions, ordered by where they start.
+    pub problems: Vec<ColormapProblem<T>>,
+}
+
+impl<T> ColormapAnalysis<T>
+where
+    T: FloatComponent,
+{
+    /// Check that no lightness reversals were found.
+    pub fn is_lightness_monotonic(&self) -> bool {
+        !self
+            .problems
+            .iter()
+            .any(|problem| problem.kind == ProblemKind::LightnessReversal)
+    }
+
+    /// The ratio between the smallest and the largest step, where `1.0`
+    /// means that all steps are perceptually equal.
+    pub fn uniformity(&self) -> T {
+        let min = self.steps.iter().copied().fold(T::infinity(), T::min);
+        let max = self.steps.iter().copied().fold(T::zero(), T::max);
+
+        if max > T::zero() {
+            min / max
+        } else {
+            T::one()
+        }
+    }
+}
+
+/// A region of a colormap with a perceptual problem.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct ColormapProblem<T> {
+    /// The kind of problem.
+    pub kind: ProblemKind,
+
+    /// The indices of the colors in the region, where the problem is between
+    /// each neighboring pair.
+    pub range: Range<usize>,
+
+    /// The worst case in the region. This is the largest lightness change
+    /// against the overall direction, for reversals, and the largest ratio
+    /// between a step and the median step, for spikes.
+    pub worst: T,
+}
+
+/// A kind of problem in a colormap.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub enum ProblemKind {
+    /// The lightness goes against the overall direction of the map.
+    LightnessReversal,
+
+    /// A step is much larger than the typical step, which looks like a band
+    /// or an edge.
+    DeltaESpike,
+}
+
+/// Merge consecutive flagged steps into problem regions.
+fn collect_regions<T, I>(kind: ProblemKind, steps: I, problems: &mut Vec<ColormapProblem<T>>)
+where
+    T: FloatComponent,
+    I: Iterator<Item = Option<T>>,
+{
+    let mut current: Option<ColormapProblem<T>> = None;
+
+    for (index, flagged) in steps.enumerate() {
+        match (flagged, current.as_mut()) {
+            (Some(value), Some(problem)) => {
+                problem.range.end = index + 2;
+                problem.worst = problem.worst.max(value);
+            }
+            (Some(value), None) => {
+                current = Some(ColormapProblem {
+                    kind,
+                    range: index..index + 2,
+                    worst: value,
+                });
+            }
+            (None, _) => problems.extend(current.take()),
+        }
+    }
+
+    problems.extend(current);
+}
+
+fn median<T: FloatComponent>(values: &[T]) -> T {
+    if values.is_empty() {
+        return T::zero();
+    }
+
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+    let middle = sorted.len() / 2;
+    if middle * 2 == sorted.len() {
+        (sorted[middle - 1] + sorted[middle]) / from_f64(2.0)
+    } else {
+        sorted[middle]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ColormapLint, ProblemKind};
+    use crate::colormap::{Cyclic, Diverging};
+    use crate::Oklab;
+
+    #[test]
+    fn uniform_ramp() {
+        let colors: Vec<_> = (0..10)
+            .map(|i| Oklab::new(0.2 + i as f64 * 0.07, 0.05, -0.02))
+            .collect();
+
+        let analysis = ColormapLint::new().analyze(&colors);
+
+        assert!(analysis.problems.is_empty());
+        assert!(analysis.is_lightness_monotonic());
+        assert_relative_eq!(analysis.uniformity(), 1.0, epsilon = 1e-9);
+        assert_eq!(analysis.steps.len(), 9);
+    }
+
+    #[test]
+    fn reversal_and_spike() {
+        let lightness = [0.9, 0.8, 0.7, 0.75, 0.6, 0.5, 0.1, 0.0];
+        let colors: Vec<_> = lightness.iter().map(|&l| Oklab::new(l, 0.0, 0.0)).collect();
+
+        let analysis = ColormapLint::new().analyze(&colors);
+        assert_eq!(analysis.problems.len(), 2);
+
+        let reversal = &analysis.problems[0];
+        assert_eq!(reversal.kind, ProblemKind::LightnessReversal);
+        assert_eq!(reversal.range, 2..4);
+        assert_relative_eq!(reversal.worst, 0.05, epsilon = 1e-9);
+
+        let spike = &analysis.problems[1];
+        assert_eq!(spike.kind, ProblemKind::DeltaESpike);
+        assert_eq!(spike.range, 5..7);
+        assert_relative_eq!(spike.worst, 4.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn generated_maps() {
+        let diverging = Diverging::new(250.0f64, 30.0, 0.5, 0.1, 0.95);
+        let half: Vec<_> = diverging.take(21).take(11).collect();
+        let analysis = ColormapLint::new().analyze(&half);
+        assert!(analysis.problems.is_empty(), "{:?}", analysis.problems);
+
+        let cyclic: Vec<_> = Cyclic::new(0.7f64, 0.1, 0.0).take(36).collect();
+        let analysis = ColormapLint::new().analyze(&cyclic);
+        assert!(analysis.problems.is_empty(), "{:?}", analysis.problems);
+        assert!(analysis.uniformity() > 0.99);
+    }
+}
@@ -0,0 +1,245 @@
+use crate::convert::IntoColorUnclamped;
+use crate::cvd::{CvdSimulation, Deficiency};
+use crate::theme::fit_chroma;
+use crate::{from_f64, FloatComponent, LinSrgb, Oklab, OklabHue, Oklch};
+
+use super::Diverging;
+
+/// A generator for colormaps that stay readable with color vision
+/// deficiencies.
+///
+/// The generator searches through hue paths in [`Oklch`], and picks the one
+/// where the smallest color difference that matters is as large as
+/// possible, both with normal vision and when the colors are
+/// [simulated](crate::cvd) with each of the deficiencies. The difference is
+/// measured in [`Oklab`].
+///
+/// ```
+/// use palette::colormap::CvdSafeRamp;
+/// use palette::{FromColor, Srgb};
+///
+/// let generator = CvdSafeRamp::new();
+///
+/// let sequential = generator.sequential(7);
+/// let diverging = generator.diverging(9);
+///
+/// assert!(generator.score(&sequential) > 0.05);
+/// let colors: Vec<Srgb> = diverging.into_iter().map(Srgb::from_color).collect();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct CvdSafeRamp<T> {
+    /// The Oklab lightness of the lightest color. This is the neutral
+    /// midpoint of diverging maps.
+    pub lightest: T,
+
+    /// The Oklab lightness of the darkest color. This is both ends of
+    /// diverging maps.
+    pub darkest: T,
+
+    /// The chroma to aim for. It's reduced where the colors would otherwise
+    /// be outside the sRGB gamut.
+    pub chroma: T,
+
+    /// The deficiencies to optimize for, in addition to normal vision.
+    pub deficiencies: Vec<Deficiency>,
+}
+
+impl<T> CvdSafeRamp<T>
+where
+    T: FloatComponent,
+{
+    /// Create a generator for lightness from `0.95` to `0.35` and a chroma of
+    /// `0.15`, that optimizes for all deficiencies.
+    pub fn new() -> Self {
+        CvdSafeRamp {
+            lightest: from_f64(0.95),
+            darkest: from_f64(0.35),
+            chroma: from_f64(0.15),
+            deficiencies: Deficiency::ALL.to_vec(),
+        }
+    }
+
+    /// Change the lightness range, in Oklab lightness.
+    pub fn with_lightness_range(mut self, lightest: T, darkest: T) -> Self {
+        self.lightest = lightest;
+        self.darkest = darkest;
+        self
+    }
+
+    /// Change the chroma.
+    pub fn with_chroma(mut self, chroma: T) -> Self {
+        self.chroma = chroma;
+        self
+    }
+
+    /// Change the deficiencies to optimize for.
+    pub fn with_deficiencies(mut self, deficiencies: &[Deficiency]) -> Self {
+        self.deficiencies = deficiencies.to_vec();
+        self
+    }
+
+    /// Generate a sequential map with `n` colors, from the lightest to the
+    /// darkest.
+    ///
+    /// The hue may rotate along the map, if that makes the steps more
+    /// distinct.
+    pub fn sequential(&self, n: usize) -> Vec<Oklch<T>> {
+        let mut best = (T::neg_infinity(), Vec::new());
+
+        for start in 0..HUE_STEPS {
+            for rotation in -5..=5 {
+                let start = hue_angle::<T>(start);
+                let rotation = from_f64::<T>(rotation as f64 * 30.0);
+
+                let colors: Vec<_> = evenly_spaced::<T>(n)
+                    .map(|factor| {
+                        fit_chroma(Oklch {
+                            l: self.lightest + factor * (self.darkest - self.lightest),
+                            chroma: self.chroma,
+                            hue: OklabHue::from(start + factor * rotation),
+                        })
+                    })
+                    .collect();
+
+                let score = self.score(&colors);
+                if score > best.0 {
+                    best = (score, colors);
+                }
+            }
+        }
+
+        best.1
+    }
+
+    /// Generate a diverging map with `n` colors, with the darkest colors at
+    /// the ends and the lightest color in the middle.
+    ///
+    /// The two arms are also compared to each other, so colors at the same
+    /// distance from the middle can be told apart.
+    pub fn diverging(&self, n: usize) -> Vec<Oklch<T>> {
+        let mut best = (T::neg_infinity(), Vec::new());
+
+        for low in 0..HUE_STEPS {
+            for offset in HUE_STEPS / 4..=HUE_STEPS * 3 / 4 {
+                let low = hue_angle::<T>(low);
+                let high = low + hue_angle::<T>(offset);
+                let map = Diverging::new(low, high, self.darkest, self.chroma, self.lightest);
+                let colors: Vec<_> = map.take(n).map(fit_chroma).collect();
+
+                let arms = (0..n / 2).map(|index| (colors[index], colors[n - 1 - index]));
+                let score = self.score(&colors).min(self.pair_score(arms));
+                if score > best.0 {
+                    best = (score, colors);
+                }
+            }
+        }
+
+        best.1
+    }
+
+    /// The smallest color difference between neighboring colors in
+    /// `colors`, with normal vision or any of the deficiencies.
+    pub fn score(&self, colors: &[Oklch<T>]) -> T {
+        self.pair_score(colors.windows(2).map(|pair| (pair[0], pair[1])))
+    }
+
+    fn pair_score<I>(&self, pairs: I) -> T
+    where
+        I: Iterator<Item = (Oklch<T>, Oklch<T>)> + Clone,
+    {
+        let normal = CvdSimulation::new(Deficiency::Protanopia, T::zero());
+        let simulations = core::iter::once(normal).chain(
+            self.deficiencies
+                .iter()
+                .map(|&deficiency| CvdSimulation::full(deficiency)),
+        );
+
+        let mut score = T::infinity();
+        for simulation in simulations {
+            for (a, b) in pairs.clone() {
+                let a = simulate(&simulation, a);
+                let b = simulate(&simulation, b);
+                let difference = a - b;
+                let distance = (difference.l * difference.l
+                    + difference.a * difference.a
+                    + difference.b * difference.b)
+                    .sqrt();
+
+                score = score.min(distance);
+            }
+        }
+
+        score
+    }
+}
+
+impl<T> Default for CvdSafeRamp<T>
+where
+    T: FloatComponent,
+{
+    fn default() -> Self {
+        CvdSafeRamp::new()
+    }
+}
+
+/// The number of hues that are tried, evenly spaced around the circle.
+const HUE_STEPS: usize = 24;
+
+fn hue_angle<T: FloatComponent>(step: usize) -> T {
+    from_f64(step as f64 * 360.0 / HUE_STEPS as f64)
+}
+
+fn evenly_spaced<T: FloatComponent>(n: usize) -> impl Iterator<Item = T> {
+    let last = from_f64::<T>(n.saturating_sub(1).max(1) as f64);
+    (0..n).map(move |index| from_f64::<T>(index as f64) / last)
+}
+
+fn simulate<T: FloatComponent>(simulation: &CvdSimulation<T>, color: Oklch<T>) -> Oklab<T> {
+    let linear: LinSrgb<T> = color.into_color_unclamped();
+    simulation.simulate_linear(linear).into_color_unclamped()
+}
+
+#[cfg(test)]
+mod test {
+    use super::CvdSafeRamp;
+    use crate::cvd::Deficiency;
+    use crate::Oklch;
+
+    #[test]
+    fn sequential() {
+        let generator = CvdSafeRamp::<f64>::new();
+        let colors = generator.sequential(6);
+
+        assert_eq!(colors.len(), 6);
+        assert_relative_eq!(colors[0].l, 0.95);
+        assert_relative_eq!(colors[5].l, 0.35);
+
+        // Better than a ramp with a constant hue that is bad for
+        // deuteranopia.
+        let constant: Vec<_> = (0..6)
+            .map(|i| Oklch::new(0.95 - i as f64 * 0.12, 0.15, 140.0))
+            .map(crate::theme::fit_chroma)
+            .collect();
+        assert!(generator.score(&colors) >= generator.score(&constant));
+    }
+
+    #[test]
+    fn diverging() {
+        let generator = CvdSafeRamp::<f64>::new().with_deficiencies(&[Deficiency::Deuteranopia]);
+        let colors = generator.diverging(7);
+
+        assert_eq!(colors.len(), 7);
+        assert_relative_eq!(colors[3].l, 0.95);
+        assert_relative_eq!(colors[0].l, colors[6].l);
+        assert!(colors[0].hue != colors[6].hue);
+
+        // Red and green arms are a poor choice for deuteranopia.
+        let red_green: Vec<_> = crate::colormap::Diverging::new(30.0, 140.0, 0.35, 0.15, 0.95)
+            .take(7)
+            .collect();
+        let score =
+            |colors: &[Oklch<f64>]| generator.pair_score(core::iter::once((colors[0], colors[6])));
+        assert!(score(&colors) > score(&red_green));
+    }
+}
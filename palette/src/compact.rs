@@ -0,0 +1,299 @@
+//! Compact storage for large numbers of perceptual colors.
+//!
+//! Colors in [`Lab`] and [`Oklab`] take 12 bytes with `f32` components, which
+//! adds up when millions of them are cached. The component orders in this
+//! module can be used with [`Packed`] to store them in 3 or 6 bytes instead,
+//! and a slice of packed colors can be cast to and from raw bytes with
+//! [`cast`](crate::cast).
+//!
+//! ```
+//! use palette::compact::{PackedOklab, PackedOklabF16};
+//! use palette::Oklab;
+//!
+//! let color = Oklab::new(0.62f32, 0.1, -0.05);
+//!
+//! let small = PackedOklab::pack(color);
+//! let restored: Oklab = small.unpack();
+//! assert!((restored.a - color.a).abs() <= 0.0016);
+//!
+//! let half = PackedOklabF16::pack(color);
+//! let restored: Oklab = half.unpack();
+//! assert!((restored.l - color.l).abs() < 0.0005);
+//! ```
+//!
+//! The quantization of each format is:
+//!
+//! | Format | Size | Lightness | a and b |
+//! |---|---|---|---|
+//! | [`LabU8`] | 3 bytes | `0` to `100`, in steps of `100/255` | `-128` to `127`, in steps of `1` |
+//! | [`OklabU8`] | 3 bytes | `0` to `1`, in steps of `1/255` | `-0.4` to `0.396875`, in steps of `1/320` |
+//! | [`OklabF16`] | 6 bytes | half precision float | half precision float |
+//!
+//! Values outside the ranges of the 8 bit formats are clamped. [`LabU8`] is
+//! the same as the 8 bit CIELAB encoding of ICC profiles. The steps of
+//! [`OklabU8`] are well below the smallest noticeable difference in Oklab,
+//! which is around `0.02`.
+
+use crate::cast::{ComponentOrder, Packed};
+use crate::{clamp, from_f64, FloatComponent, Lab, Oklab};
+
+/// Lab packed as three bytes, in the 8 bit CIELAB encoding of ICC profiles.
+///
+/// See the [module documentation](self) for the quantization.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LabU8;
+
+impl<Wp, T> ComponentOrder<Lab<Wp, T>, [u8; 3]> for LabU8
+where
+    T: FloatComponent,
+{
+    #[inline]
+    fn pack(color: Lab<Wp, T>) -> [u8; 3] {
+        [
+            quantize(color.l * from_f64(2.55), T::zero()),
+            quantize(color.a, from_f64(128.0)),
+            quantize(color.b, from_f64(128.0)),
+        ]
+    }
+
+    #[inline]
+    fn unpack(packed: [u8; 3]) -> Lab<Wp, T> {
+        let [l, a, b] = packed;
+        Lab::new(
+            from_f64::<T>(f64::from(l)) / from_f64(2.55),
+            from_f64::<T>(f64::from(a) - 128.0),
+            from_f64::<T>(f64::from(b) - 128.0),
+        )
+    }
+}
+
+/// Oklab packed as three bytes.
+///
+/// See the [module documentation](self) for the quantization.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OklabU8;
+
+impl<T> ComponentOrder<Oklab<T>, [u8; 3]> for OklabU8
+where
+    T: FloatComponent,
+{
+    #[inline]
+    fn pack(color: Oklab<T>) -> [u8; 3] {
+        [
+            quantize(color.l * from_f64(255.0), T::zero()),
+            quantize(color.a * from_f64(320.0), from_f64(128.0)),
+            quantize(color.b * from_f64(320.0), from_f64(128.0)),
+        ]
+    }
+
+    #[inline]
+    fn unpack(packed: [u8; 3]) -> Oklab<T> {
+        let [l, a, b] = packed;
+        Oklab::new(
+            from_f64(f64::from(l) / 255.0),
+            from_f64((f64::from(a) - 128.0) / 320.0),
+            from_f64((f64::from(b) - 128.0) / 320.0),
+        )
+    }
+}
+
+/// Oklab packed as three half precision floats, stored as their bits.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OklabF16;
+
+impl<T> ComponentOrder<Oklab<T>, [u16; 3]> for OklabF16
+where
+    T: FloatComponent,
+{
+    #[inline]
+    fn pack(color: Oklab<T>) -> [u16; 3] {
+        let pack = |value: T| to_f16_bits(value.to_f32().unwrap_or(0.0));
+        [pack(color.l), pack(color.a), pack(color.b)]
+    }
+
+    #[inline]
+    fn unpack(packed: [u16; 3]) -> Oklab<T> {
+        let [l, a, b] = packed;
+        let unpack = |bits: u16| from_f64::<T>(f64::from(from_f16_bits(bits)));
+        Oklab::new(unpack(l), unpack(a), unpack(b))
+    }
+}
+
+/// Lab packed as three bytes. See [`LabU8`].
+pub type PackedLab = Packed<LabU8, [u8; 3]>;
+
+/// Oklab packed as three bytes. See [`OklabU8`].
+pub type PackedOklab = Packed<OklabU8, [u8; 3]>;
+
+/// Oklab packed as three half precision floats. See [`OklabF16`].
+pub type PackedOklabF16 = Packed<OklabF16, [u16; 3]>;
+
+/// Convert an `f32` to the bits of the nearest half precision float.
+///
+/// Values that are too large become infinite, and values that are too small
+/// become zero. Ties are rounded to even.
+pub fn to_f16_bits(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exponent = ((bits >> 23) & 0xff) as i32;
+    let mantissa = bits & 0x007f_ffff;
+
+    // Infinity and NaN.
+    if exponent == 0xff {
+        let nan = if mantissa != 0 { 0x0200 } else { 0 };
+        return sign | 0x7c00 | nan;
+    }
+
+    let exponent = exponent - 127 + 15;
+    if exponent >= 0x1f {
+        return sign | 0x7c00;
+    }
+
+    // Subnormal half precision floats, including zero.
+    if exponent <= 0 {
+        if exponent < -10 {
+            return sign;
+        }
+
+        let mantissa = mantissa | 0x0080_0000;
+        let shift = (14 - exponent) as u32;
+        let half = 1 << (shift - 1);
+        let rounded = mantissa + half - 1 + ((mantissa >> shift) & 1);
+        return sign | (rounded >> shift) as u16;
+    }
+
+    // Rounding may carry into the exponent, which gives the right result,
+    // including infinity.
+    let mut result = ((exponent as u32) << 10) | (mantissa >> 13);
+    let remainder = mantissa & 0x1fff;
+    if remainder > 0x1000 || (remainder == 0x1000 && result & 1 == 1) {
+        result += 1;
+    }
+
+    sign | result as u16
+}
+
+/// Convert the bits of a half precision float to an `f32`, which is always
+/// exact.
+pub fn from_f16_bits(bits: u16) -> f32 {
+    let sign = u32::from(bits & 0x8000) << 16;
+    let exponent = u32::from((bits >> 10) & 0x1f);
+    let mantissa = u32::from(bits & 0x03ff);
+
+    match exponent {
+        0 => {
+            let value = mantissa as f32 / (1 << 24) as f32;
+            if sign != 0 {
+                -value
+            } else {
+                value
+            }
+        }
+        0x1f => f32::from_bits(sign | 0x7f80_0000 | (mantissa << 13)),
+        _ => f32::from_bits(sign | ((exponent + 127 - 15) << 23) | (mantissa << 13)),
+    }
+}
+
+/// Round and clamp a value to a byte, after adding `offset`.
+fn quantize<T: FloatComponent>(value: T, offset: T) -> u8 {
+    clamp((value + offset).round(), T::zero(), from_f64(255.0))
+        .to_u8()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{from_f16_bits, to_f16_bits, PackedLab, PackedOklab, PackedOklabF16};
+    use crate::cast;
+    use crate::white_point::D65;
+    use crate::{Lab, Oklab, Oklch};
+
+    #[test]
+    fn sizes() {
+        use core::mem::size_of;
+
+        assert_eq!(size_of::<PackedLab>(), 3);
+        assert_eq!(size_of::<PackedOklab>(), 3);
+        assert_eq!(size_of::<PackedOklabF16>(), 6);
+
+        // The hue types don't add anything to the size of the colors.
+        assert_eq!(size_of::<Oklch<f32>>(), 12);
+        assert_eq!(size_of::<Oklch<f64>>(), 24);
+    }
+
+    #[test]
+    fn lab() {
+        let color = Lab::<D65, f64>::new(53.2, 80.1, -67.3);
+        let packed = PackedLab::pack(color);
+        assert_eq!(packed.color, [136, 208, 61]);
+
+        let restored: Lab<D65, f64> = packed.unpack();
+        assert_relative_eq!(restored.l, color.l, epsilon = 50.0 / 255.0);
+        assert_relative_eq!(restored.a, color.a, epsilon = 0.5);
+        assert_relative_eq!(restored.b, color.b, epsilon = 0.5);
+
+        let clamped: Lab<D65, f64> =
+            PackedLab::pack(Lab::<D65, f64>::new(120.0, -200.0, 200.0)).unpack();
+        assert_relative_eq!(clamped, Lab::new(100.0, -128.0, 127.0));
+    }
+
+    #[test]
+    fn oklab() {
+        let gray = Oklab::new(0.5f32, 0.0, 0.0);
+        assert_eq!(PackedOklab::pack(gray).color, [128, 128, 128]);
+
+        for &color in &[
+            Oklab::new(0.0f64, 0.0, 0.0),
+            Oklab::new(1.0, 0.0, 0.0),
+            Oklab::new(0.63, 0.22, 0.13),
+            Oklab::new(0.45, -0.03, -0.31),
+        ] {
+            let restored: Oklab<f64> = PackedOklab::pack(color).unpack();
+            assert_relative_eq!(restored.l, color.l, epsilon = 0.5 / 255.0);
+            assert_relative_eq!(restored.a, color.a, epsilon = 0.5 / 320.0);
+            assert_relative_eq!(restored.b, color.b, epsilon = 0.5 / 320.0);
+
+            let restored: Oklab<f64> = PackedOklabF16::pack(color).unpack();
+            assert_relative_eq!(restored, color, epsilon = 5e-4);
+        }
+    }
+
+    #[test]
+    fn cast_slices() {
+        let colors = [
+            PackedOklab::pack(Oklab::new(0.2f32, 0.1, -0.1)),
+            PackedOklab::pack(Oklab::new(0.8f32, -0.1, 0.1)),
+        ];
+
+        let bytes: &[[u8; 3]] = cast::into_array_slice(&colors);
+        assert_eq!(bytes.len(), 2);
+        let back: &[PackedOklab] = cast::from_array_slice(bytes);
+        assert_eq!(back, &colors);
+    }
+
+    #[test]
+    fn half_precision() {
+        assert_eq!(to_f16_bits(0.0), 0x0000);
+        assert_eq!(to_f16_bits(-0.0), 0x8000);
+        assert_eq!(to_f16_bits(1.0), 0x3c00);
+        assert_eq!(to_f16_bits(-2.0), 0xc000);
+        assert_eq!(to_f16_bits(65504.0), 0x7bff);
+        assert_eq!(to_f16_bits(1.0e6), 0x7c00);
+        assert_eq!(to_f16_bits(f32::INFINITY), 0x7c00);
+        assert!(from_f16_bits(to_f16_bits(f32::NAN)).is_nan());
+
+        // The smallest subnormal and rounding to it.
+        assert_eq!(to_f16_bits(5.960_464_5e-8), 0x0001);
+        assert_eq!(to_f16_bits(2.0e-8), 0x0000);
+        assert_eq!(to_f16_bits(6.103_515_6e-5), 0x0400);
+
+        // Ties round to even: 1 + 2^-11 is between 1 and the next float.
+        assert_eq!(to_f16_bits(1.0 + 1.0 / 2048.0), 0x3c00);
+        assert_eq!(to_f16_bits(1.0 + 3.0 / 2048.0), 0x3c02);
+
+        for bits in (0..0x7c00).step_by(7) {
+            assert_eq!(to_f16_bits(from_f16_bits(bits)), bits);
+            assert_eq!(to_f16_bits(-from_f16_bits(bits)), bits | 0x8000);
+        }
+    }
+}
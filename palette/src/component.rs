@@ -1,7 +1,7 @@
 use num_traits::Zero;
 
 use crate::float::Float;
-use crate::{clamp, FromF64};
+use crate::{clamp, from_f64, FromF64};
 
 /// Common trait for color components.
 pub trait Component: Copy + Zero + PartialOrd {
@@ -93,6 +93,160 @@ impl<T: Component> IntoComponent<T> for T {
     }
 }
 
+/// How a float component is rounded when it's converted to an integer
+/// component.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Rounding {
+    /// Round down.
+    Floor,
+
+    /// Round to the nearest integer, and to the nearest even integer when
+    /// it's exactly between two. This is what [`FromComponent`] does.
+    Nearest,
+
+    /// Round down or up at random, with a probability that depends on the
+    /// distance to each integer. The value is a uniformly distributed random
+    /// number in `[0.0, 1.0)`, that should be different for each component.
+    ///
+    /// The errors of stochastic rounding are uncorrelated and average out to
+    /// zero, which hides the banding in smooth gradients.
+    Stochastic(f64),
+}
+
+/// Converts from a float color component type, while performing the
+/// appropriate scaling and rounding, and fails if the value doesn't fit.
+///
+/// Values that round to the minimum or maximum are accepted, so `-0.001`
+/// becomes `0u8` with [`Rounding::Nearest`], while `-0.01` is an error.
+///
+/// ```
+/// use palette::{Rounding, TryFromComponent, TryFromComponentErrorKind};
+///
+/// assert_eq!(u8::try_from_component(0.5f32, Rounding::Floor), Ok(127));
+///
+/// let error = u8::try_from_component(1.5f32, Rounding::Nearest).unwrap_err();
+/// assert_eq!(error.kind(), TryFromComponentErrorKind::AboveMaximum);
+/// ```
+pub trait TryFromComponent<T: Component>: Sized {
+    /// Converts `other` into `Self`, while performing the appropriate scaling
+    /// and rounding, or returns an error if `other` is out of range.
+    fn try_from_component(other: T, rounding: Rounding) -> Result<Self, TryFromComponentError>;
+}
+
+/// Converts from a float color component type, while performing the
+/// appropriate scaling and rounding, and saturates at the minimum and maximum
+/// values.
+///
+/// `NaN` becomes the minimum value.
+///
+/// ```
+/// use palette::{Rounding, SaturatingFromComponent};
+///
+/// assert_eq!(u8::saturating_from_component(0.5f32, Rounding::Floor), 127);
+/// assert_eq!(u8::saturating_from_component(1.5f32, Rounding::Nearest), 255);
+/// assert_eq!(u8::saturating_from_component(0.5f32, Rounding::Stochastic(0.9)), 128);
+/// ```
+pub trait SaturatingFromComponent<T: Component> {
+    /// Converts `other` into `Self`, while performing the appropriate
+    /// scaling, rounding and clamping.
+    #[must_use]
+    fn saturating_from_component(other: T, rounding: Rounding) -> Self;
+}
+
+/// The reason why [`TryFromComponent`] failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TryFromComponentErrorKind {
+    /// The component was `NaN`.
+    NotANumber,
+    /// The rounded component was less than the minimum value.
+    BelowMinimum,
+    /// The rounded component was greater than the maximum value.
+    AboveMaximum,
+}
+
+/// The error type returned when [`TryFromComponent`] fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TryFromComponentError {
+    kind: TryFromComponentErrorKind,
+}
+
+impl TryFromComponentError {
+    /// The reason for the error.
+    pub fn kind(&self) -> TryFromComponentErrorKind {
+        self.kind
+    }
+}
+
+impl core::fmt::Display for TryFromComponentError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self.kind {
+            TryFromComponentErrorKind::NotANumber => "component is not a number",
+            TryFromComponentErrorKind::BelowMinimum => "component is below the minimum value",
+            TryFromComponentErrorKind::AboveMaximum => "component is above the maximum value",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromComponentError {}
+
+// Scales and rounds a component, without clamping it.
+fn scale_and_round<T: Float + FromF64>(value: T, max: T, rounding: Rounding) -> T {
+    let scaled = value * max;
+
+    match rounding {
+        Rounding::Floor => scaled.floor(),
+        Rounding::Nearest => {
+            let rounded = scaled.round();
+            if (rounded - scaled).abs() == from_f64(0.5) {
+                (scaled / from_f64(2.0)).round() * from_f64(2.0)
+            } else {
+                rounded
+            }
+        }
+        Rounding::Stochastic(random) => (scaled + from_f64(random)).floor(),
+    }
+}
+
+macro_rules! convert_float_to_uint_with_rounding {
+    ($float: ident via $temporary: ident; $($target: ident),+) => {
+        $(
+            impl TryFromComponent<$float> for $target {
+                fn try_from_component(
+                    other: $float,
+                    rounding: Rounding,
+                ) -> Result<Self, TryFromComponentError> {
+                    let max = $target::max_intensity() as $temporary;
+                    let rounded = scale_and_round(<$temporary>::from(other), max, rounding);
+
+                    let kind = if rounded.is_nan() {
+                        TryFromComponentErrorKind::NotANumber
+                    } else if rounded < 0.0 {
+                        TryFromComponentErrorKind::BelowMinimum
+                    } else if rounded > max {
+                        TryFromComponentErrorKind::AboveMaximum
+                    } else {
+                        return Ok(rounded as $target);
+                    };
+
+                    Err(TryFromComponentError { kind })
+                }
+            }
+
+            impl SaturatingFromComponent<$float> for $target {
+                #[inline]
+                fn saturating_from_component(other: $float, rounding: Rounding) -> Self {
+                    let max = $target::max_intensity() as $temporary;
+                    let rounded = scale_and_round(<$temporary>::from(other), max, rounding);
+
+                    // `as` casts from floats saturate, and turn `NaN` into 0.
+                    rounded as $target
+                }
+            }
+        )+
+    };
+}
+
 // C23 = 2^23, in f32
 // C52 = 2^52, in f64
 const C23: u32 = 0x4b00_0000;
@@ -248,9 +402,16 @@ convert_uint_to_uint!(u64; via f64 (u8, u16, u32, u128););
 convert_uint_to_float!(u128; via f64 (f32, f64););
 convert_uint_to_uint!(u128; via f64 (u8, u16, u32, u64););
 
+convert_float_to_uint_with_rounding!(f32 via f32; u8, u16);
+convert_float_to_uint_with_rounding!(f32 via f64; u32, u64, u128);
+convert_float_to_uint_with_rounding!(f64 via f64; u8, u16, u32, u64, u128);
+
 #[cfg(test)]
 mod test {
-    use crate::IntoComponent;
+    use crate::{
+        IntoComponent, Rounding, SaturatingFromComponent, TryFromComponent,
+        TryFromComponentErrorKind,
+    };
     use approx::assert_relative_eq;
 
     #[test]
@@ -362,4 +523,73 @@ mod test {
             )
         }
     }
+
+    #[test]
+    fn saturating_rounding_modes() {
+        assert_eq!(u8::saturating_from_component(0.5f32, Rounding::Floor), 127);
+        assert_eq!(
+            u8::saturating_from_component(0.5f32, Rounding::Nearest),
+            128
+        );
+        assert_eq!(
+            u8::saturating_from_component(0.5f64, Rounding::Nearest),
+            128
+        );
+        assert_eq!(
+            u8::saturating_from_component(0.5f32, Rounding::Stochastic(0.4)),
+            127
+        );
+        assert_eq!(
+            u8::saturating_from_component(0.5f32, Rounding::Stochastic(0.6)),
+            128
+        );
+        assert_eq!(
+            u16::saturating_from_component(1.0f64, Rounding::Floor),
+            65535
+        );
+        assert_eq!(u8::saturating_from_component(-3.0f32, Rounding::Nearest), 0);
+        assert_eq!(
+            u8::saturating_from_component(3.0f32, Rounding::Nearest),
+            255
+        );
+        assert_eq!(
+            u8::saturating_from_component(f32::NAN, Rounding::Nearest),
+            0
+        );
+    }
+
+    #[test]
+    fn nearest_matches_into_component() {
+        for n in 0..=1000 {
+            let value = n as f32 / 1000.0;
+            assert_eq!(
+                u8::saturating_from_component(value, Rounding::Nearest),
+                IntoComponent::<u8>::into_component(value)
+            );
+        }
+    }
+
+    #[test]
+    fn try_from_component() {
+        assert_eq!(u8::try_from_component(1.0f32, Rounding::Nearest), Ok(255));
+        assert_eq!(u8::try_from_component(-0.001f32, Rounding::Nearest), Ok(0));
+        assert_eq!(
+            u8::try_from_component(-0.01f32, Rounding::Nearest)
+                .unwrap_err()
+                .kind(),
+            TryFromComponentErrorKind::BelowMinimum
+        );
+        assert_eq!(
+            u16::try_from_component(1.01f64, Rounding::Floor)
+                .unwrap_err()
+                .kind(),
+            TryFromComponentErrorKind::AboveMaximum
+        );
+        assert_eq!(
+            u8::try_from_component(f64::NAN, Rounding::Floor)
+                .unwrap_err()
+                .kind(),
+            TryFromComponentErrorKind::NotANumber
+        );
+    }
 }
@@ -277,13 +277,37 @@
 //!     assert_relative_eq!(color, LinSrgba::new(0.496933, 0.0, 1.0, 0.3));
 //! }
 //! ```
+//!
+//! # Trait Objects
+//!
+//! The conversion traits are generic, so they can't be used as trait objects.
+//! [`AnyIntoColor`] can, for when colors of different types need to be kept
+//! in the same collection. It converts through [`Xyz`], with `f64`
+//! components and the D65 white point by default, and is implemented for
+//! every color that can be converted to that `Xyz` type.
+//!
+//! ```
+//! use palette::convert::AnyIntoColor;
+//! use palette::{Hsl, Lab, Oklch, Srgb};
+//!
+//! let colors: Vec<Box<dyn AnyIntoColor>> = vec![
+//!     Box::new(Srgb::new(0.8f64, 0.2, 0.1)),
+//!     Box::new(Hsl::new_srgb(120.0f64, 0.5, 0.5)),
+//!     Box::new(Oklch::new(0.7f64, 0.1, 250.0)),
+//! ];
+//!
+//! let lab: Vec<Lab<_, f64>> = colors.iter().map(|color| color.to_color()).collect();
+//! ```
 
 use core::fmt::{self, Display, Formatter};
 
 #[doc(hidden)]
 pub use palette_derive::FromColorUnclamped;
 
-use crate::{Clamp, IsWithinBounds};
+#[cfg(feature = "std")]
+use crate::float::Float;
+use crate::white_point::D65;
+use crate::{Clamp, IsWithinBounds, Xyz};
 
 /// The error type for a color conversion that converted a color into a color
 /// with invalid values.
@@ -532,7 +556,9 @@ where
 {
     #[inline]
     fn from_color(t: T) -> Self {
-        Self::from_color_unclamped(t).clamp()
+        let color = Self::from_color_unclamped(t);
+        trace!(clamp);
+        color.clamp()
     }
 }
 
@@ -629,6 +655,375 @@ where
     }
 }
 
+/// Convert all colors in place, like [`FromColor`] for `Vec`, and report the
+/// colors that had problems.
+///
+/// Each color is converted and clamped, and the conversion of the batch
+/// continues even if some colors can't be converted cleanly. The problems are
+/// collected in a [`ConversionReport`], with the index of each color:
+///
+/// * Colors with NaN or infinite components are reported as
+///   [`NotFinite`](ConversionIssueKind::NotFinite). Their converted values
+///   are not meaningful and should be replaced by the caller.
+/// * Colors that end up further than `tolerance` outside the bounds of `U`,
+///   in any of its components, are reported as
+///   [`OutOfBounds`](ConversionIssueKind::OutOfBounds). They are clamped, as
+///   with `FromColor`.
+///
+/// ```
+/// use palette::convert::{self, ConversionIssueKind};
+/// use palette::{LinSrgb, Srgb};
+///
+/// let colors = vec![
+///     LinSrgb::new(0.5f32, 0.5, 0.5),
+///     LinSrgb::new(f32::NAN, 0.5, 0.5),
+///     LinSrgb::new(1.5, 0.5, 0.5),
+/// ];
+/// let (srgb, report) = convert::from_color_vec_with_report::<_, Srgb, _>(colors, 0.001);
+///
+/// assert_eq!(srgb.len(), 3);
+/// assert_eq!(report.len(), 3);
+/// assert_eq!(report.issues().len(), 2);
+/// assert_eq!(report.issues()[0].index, 1);
+/// assert_eq!(report.issues()[0].kind, ConversionIssueKind::NotFinite);
+/// assert_eq!(report.to_string(), "2 of 3 colors had issues: 1 not finite, 1 out of bounds");
+/// ```
+///
+/// A `Box<[T]>` can be converted with `into_vec` and `into_boxed_slice`,
+/// which doesn't reallocate.
+///
+/// This function is only available with the `std` feature.
+#[cfg(feature = "std")]
+pub fn from_color_vec_with_report<T, U, F>(
+    colors: Vec<T>,
+    tolerance: F,
+) -> (Vec<U>, ConversionReport<F>)
+where
+    T: crate::cast::ArrayCast,
+    T::Array: crate::ArrayExt<Item = F>,
+    U: crate::cast::ArrayCast<Array = T::Array> + FromColorUnclamped<T> + Clamp + Clone,
+    F: Float,
+{
+    let mut report = ConversionReport {
+        len: colors.len(),
+        issues: Vec::new(),
+    };
+    let mut index = 0;
+
+    let colors = crate::cast::map_vec_in_place(colors, |color: T| {
+        let (color, issue) = convert_with_issue(color, tolerance);
+        report.push(index, issue);
+        index += 1;
+        color
+    });
+
+    (colors, report)
+}
+
+/// Convert the colors in `input` into `output`, like
+/// [`from_color_vec_with_report`], and report the colors that had problems.
+///
+/// ```
+/// use palette::convert;
+/// use palette::{LinSrgb, Srgb};
+///
+/// let colors = [LinSrgb::new(0.5f32, 0.5, 0.5), LinSrgb::new(1.5, 0.5, 0.5)];
+/// let mut srgb = [Srgb::new(0.0, 0.0, 0.0); 2];
+/// let report = convert::from_color_slice_with_report(&colors, &mut srgb, 0.001);
+///
+/// assert_eq!(report.out_of_bounds_count(), 1);
+/// assert_eq!(srgb[1].red, 1.0);
+/// ```
+///
+/// This function is only available with the `std` feature.
+///
+/// # Panics
+///
+/// Panics if `input` and `output` don't have the same length.
+#[cfg(feature = "std")]
+pub fn from_color_slice_with_report<T, U, F>(
+    input: &[T],
+    output: &mut [U],
+    tolerance: F,
+) -> ConversionReport<F>
+where
+    T: crate::cast::ArrayCast + Clone,
+    T::Array: crate::ArrayExt<Item = F>,
+    U: crate::cast::ArrayCast + FromColorUnclamped<T> + Clamp + Clone,
+    U::Array: crate::ArrayExt<Item = F>,
+    F: Float,
+{
+    assert_eq!(
+        input.len(),
+        output.len(),
+        "the input and output slices must have the same length"
+    );
+
+    let mut report = ConversionReport {
+        len: input.len(),
+        issues: Vec::new(),
+    };
+
+    for (index, (color, output)) in input.iter().zip(output).enumerate() {
+        let (color, issue) = convert_with_issue(color.clone(), tolerance);
+        report.push(index, issue);
+        *output = color;
+    }
+
+    report
+}
+
+/// Convert all colors in `colors` in place, like
+/// [`from_color_vec_with_report`], and report the colors that had problems.
+///
+/// The converted colors are returned as a slice of `U`, which has to have
+/// the same memory layout as `T`.
+///
+/// ```
+/// use palette::convert;
+/// use palette::{LinSrgb, Srgb};
+///
+/// let mut colors = [LinSrgb::new(0.5f32, 0.5, 0.5), LinSrgb::new(f32::NAN, 0.5, 0.5)];
+/// let (srgb, report): (&mut [Srgb], _) =
+///     convert::from_color_slice_in_place_with_report(&mut colors, 0.001);
+///
+/// assert_eq!(srgb.len(), 2);
+/// assert_eq!(report.not_finite_count(), 1);
+/// ```
+///
+/// This function is only available with the `std` feature.
+#[cfg(feature = "std")]
+pub fn from_color_slice_in_place_with_report<T, U, F>(
+    colors: &mut [T],
+    tolerance: F,
+) -> (&mut [U], ConversionReport<F>)
+where
+    T: crate::cast::ArrayCast + Copy,
+    T::Array: crate::ArrayExt<Item = F>,
+    U: crate::cast::ArrayCast<Array = T::Array> + FromColorUnclamped<T> + Clamp + Clone,
+    F: Float,
+{
+    let mut report = ConversionReport {
+        len: colors.len(),
+        issues: Vec::new(),
+    };
+
+    let colors = crate::cast::into_array_slice_mut(colors);
+    for (index, item) in colors.iter_mut().enumerate() {
+        let color = *crate::cast::from_array_ref::<T>(item);
+        let (color, issue) = convert_with_issue::<T, U, F>(color, tolerance);
+        report.push(index, issue);
+        *item = crate::cast::into_array(color);
+    }
+
+    (crate::cast::from_array_slice_mut(colors), report)
+}
+
+/// Convert and clamp `color`, and check if it had any issues.
+#[cfg(feature = "std")]
+fn convert_with_issue<T, U, F>(color: T, tolerance: F) -> (U, Option<ConversionIssueKind<F>>)
+where
+    T: crate::cast::ArrayCast,
+    T::Array: crate::ArrayExt<Item = F>,
+    U: crate::cast::ArrayCast + FromColorUnclamped<T> + Clamp + Clone,
+    U::Array: crate::ArrayExt<Item = F>,
+    F: Float,
+{
+    let is_finite = crate::cast::into_component_slice(core::slice::from_ref(&color))
+        .iter()
+        .all(|component| component.is_finite());
+
+    let unclamped = U::from_color_unclamped(color);
+    let clamped = unclamped.clone().clamp();
+
+    if !is_finite {
+        return (clamped, Some(ConversionIssueKind::NotFinite));
+    }
+
+    let distance = {
+        let unclamped = crate::cast::into_component_slice(core::slice::from_ref(&unclamped));
+        let clamped = crate::cast::into_component_slice(core::slice::from_ref(&clamped));
+        unclamped
+            .iter()
+            .zip(clamped)
+            .fold(F::zero(), |distance, (&unclamped, &clamped)| {
+                distance.max((unclamped - clamped).abs())
+            })
+    };
+
+    if distance > tolerance {
+        (clamped, Some(ConversionIssueKind::OutOfBounds { distance }))
+    } else {
+        (clamped, None)
+    }
+}
+
+/// The problems that were found while converting a batch of colors with
+/// [`from_color_vec_with_report`], [`from_color_slice_with_report`] or
+/// [`from_color_slice_in_place_with_report`].
+///
+/// It only stores the colors that had issues, so it stays small when most
+/// of the batch is fine. Its `Display` implementation gives a one line
+/// summary, for logging.
+///
+/// This type is only available with the `std` feature.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConversionReport<T> {
+    len: usize,
+    issues: Vec<ConversionIssue<T>>,
+}
+
+#[cfg(feature = "std")]
+impl<T> ConversionReport<T> {
+    fn push(&mut self, index: usize, kind: Option<ConversionIssueKind<T>>) {
+        if let Some(kind) = kind {
+            self.issues.push(ConversionIssue { index, kind });
+        }
+    }
+
+    /// The number of colors that were converted, including the ones with
+    /// issues.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if the batch was empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Check if all colors were converted without issues.
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// The issues, in the order of the colors.
+    pub fn issues(&self) -> &[ConversionIssue<T>] {
+        &self.issues
+    }
+
+    /// The number of colors that had NaN or infinite components.
+    pub fn not_finite_count(&self) -> usize {
+        self.issues
+            .iter()
+            .filter(|issue| matches!(issue.kind, ConversionIssueKind::NotFinite))
+            .count()
+    }
+
+    /// The number of colors that were further out of bounds than the
+    /// tolerance.
+    pub fn out_of_bounds_count(&self) -> usize {
+        self.issues
+            .iter()
+            .filter(|issue| matches!(issue.kind, ConversionIssueKind::OutOfBounds { .. }))
+            .count()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Display for ConversionReport<T> {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        if self.is_ok() {
+            return write!(fmt, "{} colors had no issues", self.len);
+        }
+
+        write!(
+            fmt,
+            "{} of {} colors had issues: {} not finite, {} out of bounds",
+            self.issues.len(),
+            self.len,
+            self.not_finite_count(),
+            self.out_of_bounds_count()
+        )
+    }
+}
+
+/// A problem with one color in a batch conversion.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConversionIssue<T> {
+    /// The index of the color in the input.
+    pub index: usize,
+    /// What the problem was.
+    pub kind: ConversionIssueKind<T>,
+}
+
+/// The kinds of problems in a batch conversion.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConversionIssueKind<T> {
+    /// The input color had NaN or infinite components.
+    NotFinite,
+    /// The converted color was outside the bounds of its color space, by
+    /// more than the tolerance.
+    OutOfBounds {
+        /// The largest difference between a component and its clamped
+        /// value.
+        distance: T,
+    },
+}
+
+/// A color that can be converted to other colors through a trait object.
+///
+/// This is an object safe alternative to the conversion traits, for mixing
+/// colors of different types in the same collection. It's implemented for
+/// all colors that can be converted to `Xyz<Wp, T>`. The white point and
+/// component type are `D65` and `f64` by default, so `dyn AnyIntoColor`
+/// accepts the built-in color types with `f64` components and the default
+/// white point. Other white points and component types are chosen with the
+/// type parameters, such as `dyn AnyIntoColor<D50, f32>`, but all colors in
+/// the same collection have to be convertible to the same `Xyz` type. The
+/// conversion to other color types is done with
+/// [`to_color`](#method.to_color) and
+/// [`to_color_unclamped`](#method.to_color_unclamped), on `dyn AnyIntoColor`.
+///
+/// ```
+/// use palette::convert::AnyIntoColor;
+/// use palette::white_point::D50;
+/// use palette::{Hsv, Lab, Srgb};
+///
+/// let color: &dyn AnyIntoColor = &Hsv::new_srgb(0.0f64, 1.0, 1.0);
+/// let rgb: Srgb<f64> = color.to_color();
+/// assert!((rgb.red - 1.0).abs() < 1e-9 && rgb.green.abs() < 1e-9);
+///
+/// let color: &dyn AnyIntoColor<D50, f32> = &Lab::<D50, f32>::new(50.0, 10.0, -20.0);
+/// let lab: Lab<D50, f32> = color.to_color();
+/// assert!((lab.l - 50.0).abs() < 1e-4);
+/// ```
+pub trait AnyIntoColor<Wp = D65, T = f64> {
+    /// Convert the color to XYZ.
+    fn to_xyz(&self) -> Xyz<Wp, T>;
+}
+
+impl<C, Wp, T> AnyIntoColor<Wp, T> for C
+where
+    C: Clone + IntoColorUnclamped<Xyz<Wp, T>>,
+{
+    #[inline]
+    fn to_xyz(&self) -> Xyz<Wp, T> {
+        self.clone().into_color_unclamped()
+    }
+}
+
+impl<'a, Wp, T> dyn AnyIntoColor<Wp, T> + 'a {
+    /// Convert the color to `C`, and clamp it to its valid range.
+    #[inline]
+    pub fn to_color<C>(&self) -> C
+    where
+        C: FromColor<Xyz<Wp, T>>,
+    {
+        C::from_color(self.to_xyz())
+    }
+
+    /// Convert the color to `C`, without clamping it.
+    #[inline]
+    pub fn to_color_unclamped<C>(&self) -> C
+    where
+        C: FromColorUnclamped<Xyz<Wp, T>>,
+    {
+        C::from_color_unclamped(self.to_xyz())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::marker::PhantomData;
@@ -1055,4 +1450,111 @@ mod tests {
         let _hwb: Hwb<_, f64> = color.into_color();
         let _luma: Luma<Linear<crate::white_point::E>, f64> = color.into_color();
     }
+
+    #[test]
+    fn any_into_color() {
+        use super::AnyIntoColor;
+        use crate::{Oklab, Srgb};
+
+        let red = Srgb::new(1.0f64, 0.0, 0.0);
+        let colors: Vec<Box<dyn AnyIntoColor>> = vec![
+            Box::new(red),
+            Box::new(Hsv::new_srgb(0.0f64, 1.0, 1.0)),
+            Box::new(Oklab::<f64>::from_color(red)),
+            Box::new(Lab::<crate::white_point::D65, f64>::from_color(red)),
+        ];
+
+        for color in &colors {
+            let srgb: Srgb<f64> = color.to_color();
+            assert_relative_eq!(srgb, red, epsilon = 1e-6);
+        }
+
+        let gray: &dyn AnyIntoColor = &Hsl::new_srgb(0.0f64, 0.0, 0.5);
+        let srgb: Srgb<f64> = gray.to_color_unclamped();
+        assert_relative_eq!(srgb, Srgb::new(0.5, 0.5, 0.5), epsilon = 1e-6);
+
+        let lab = Lab::<crate::white_point::D50, f32>::new(50.0, 10.0, -20.0);
+        let colors: Vec<Box<dyn AnyIntoColor<crate::white_point::D50, f32>>> =
+            vec![Box::new(lab), Box::new(Lch::from_color(lab))];
+
+        for color in &colors {
+            let back: Lab<_, f32> = color.to_color();
+            assert_relative_eq!(back, lab, epsilon = 1e-3);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_color_vec_with_report() {
+        use super::{ConversionIssue, ConversionIssueKind};
+        use crate::{LinSrgb, Srgb};
+
+        let colors = vec![
+            LinSrgb::new(0.2f64, 0.4, 0.6),
+            LinSrgb::new(1.0005, 0.5, 0.5),
+            LinSrgb::new(0.5, f64::INFINITY, 0.5),
+            LinSrgb::new(0.5, 0.5, -0.5),
+        ];
+        let (srgb, report) =
+            super::from_color_vec_with_report::<_, Srgb<f64>, _>(colors.clone(), 0.001);
+
+        assert_eq!(srgb.len(), 4);
+        assert_relative_eq!(srgb[0], Srgb::from_linear(colors[0]), epsilon = 1e-6);
+        assert_eq!(srgb[1].red, 1.0);
+        assert_eq!(report.len(), 4);
+        assert!(!report.is_ok());
+        assert_eq!(report.not_finite_count(), 1);
+        assert_eq!(report.out_of_bounds_count(), 1);
+        assert_eq!(
+            report.issues()[0],
+            ConversionIssue {
+                index: 2,
+                kind: ConversionIssueKind::NotFinite
+            }
+        );
+        assert_eq!(report.issues()[1].index, 3);
+
+        let (empty, report) =
+            super::from_color_vec_with_report::<Srgb<f64>, LinSrgb<f64>, _>(Vec::new(), 0.0);
+        assert!(empty.is_empty());
+        assert!(report.is_empty() && report.is_ok());
+        assert_eq!(report.to_string(), "0 colors had no issues");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_color_slice_with_report() {
+        use crate::{LinSrgb, Srgb};
+
+        let colors = [
+            LinSrgb::new(0.2f64, 0.4, 0.6),
+            LinSrgb::new(0.5, f64::NAN, 0.5),
+            LinSrgb::new(0.5, 0.5, 2.0),
+        ];
+        let (expected, expected_report) =
+            super::from_color_vec_with_report::<_, Srgb<f64>, _>(colors.to_vec(), 0.001);
+
+        let mut output = [Srgb::new(0.0, 0.0, 0.0); 3];
+        let report = super::from_color_slice_with_report(&colors, &mut output, 0.001);
+        assert_eq!(report, expected_report);
+        assert_relative_eq!(output[0], expected[0]);
+        assert_relative_eq!(output[2], expected[2]);
+
+        let mut in_place = colors;
+        let (output, report): (&mut [Srgb<f64>], _) =
+            super::from_color_slice_in_place_with_report(&mut in_place, 0.001);
+        assert_eq!(report, expected_report);
+        assert_relative_eq!(output[0], expected[0]);
+        assert_relative_eq!(output[2], expected[2]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic(expected = "the input and output slices must have the same length")]
+    fn from_color_slice_with_report_length_mismatch() {
+        use crate::{LinSrgb, Srgb};
+
+        let mut output = [Srgb::new(0.0, 0.0, 0.0); 2];
+        super::from_color_slice_with_report(&[LinSrgb::new(0.5f64, 0.5, 0.5)], &mut output, 0.0);
+    }
 }
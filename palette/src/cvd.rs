@@ -0,0 +1,172 @@
+//! Simulation of color vision deficiencies.
+//!
+//! People with a color vision deficiency (CVD) have one type of cone cell
+//! that is missing or shifted, which makes some colors that look different
+//! for others look the same. A [`CvdSimulation`] shows how a color would be
+//! seen with one of the three kinds of deficiency, to check that colors that
+//! carry meaning can still be told apart.
+//!
+//! The simulation uses the matrices by Machado, Oliveira and Fernandes
+//! (2009), applied in linear sRGB. Partial deficiencies, called anomalous
+//! trichromacy, are approximated by blending between normal vision and the
+//! full deficiency.
+//!
+//! ```
+//! use palette::cvd::{CvdSimulation, Deficiency};
+//! use palette::Srgb;
+//!
+//! let simulation = CvdSimulation::full(Deficiency::Deuteranopia);
+//!
+//! // Red and green are hard to tell apart.
+//! let red = simulation.simulate(Srgb::new(0.8f32, 0.2, 0.1));
+//! let green = simulation.simulate(Srgb::new(0.4, 0.5, 0.1));
+//! assert!((red.red - green.red).abs() < 0.15);
+//! ```
+
+use crate::matrix::{mat3_from_f64, Mat3};
+use crate::{clamp, Clamp, FloatComponent, LinSrgb, Srgb};
+
+/// A kind of color vision deficiency.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub enum Deficiency {
+    /// Missing long wavelength (red) cones.
+    Protanopia,
+
+    /// Missing medium wavelength (green) cones. This is the most common
+    /// kind.
+    Deuteranopia,
+
+    /// Missing short wavelength (blue) cones.
+    Tritanopia,
+}
+
+impl Deficiency {
+    /// All kinds of deficiency.
+    pub const ALL: [Deficiency; 3] = [
+        Deficiency::Protanopia,
+        Deficiency::Deuteranopia,
+        Deficiency::Tritanopia,
+    ];
+
+    fn matrix(self) -> Mat3<f64> {
+        match self {
+            Deficiency::Protanopia => [
+                0.152286, 1.052583, -0.204868, 0.114503, 0.786281, 0.099216, -0.003882, -0.048116,
+                1.051998,
+            ],
+            Deficiency::Deuteranopia => [
+                0.367322, 0.860646, -0.227968, 0.280085, 0.672501, 0.047413, -0.011820, 0.042940,
+                0.968881,
+            ],
+            Deficiency::Tritanopia => [
+                1.255528, -0.076749, -0.178779, -0.078411, 0.930809, 0.147602, 0.004733, 0.691367,
+                0.303900,
+            ],
+        }
+    }
+}
+
+/// A simulation of a color vision deficiency.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CvdSimulation<T> {
+    matrix: Mat3<T>,
+}
+
+impl<T> CvdSimulation<T>
+where
+    T: FloatComponent,
+{
+    /// Simulate `deficiency` with a `severity` from `0.0`, for normal vision,
+    /// to `1.0`, for a full deficiency.
+    pub fn new(deficiency: Deficiency, severity: T) -> Self {
+        let severity = clamp(severity, T::zero(), T::one());
+        let full: Mat3<T> = mat3_from_f64(&deficiency.matrix());
+
+        let mut matrix = full;
+        for (index, value) in matrix.iter_mut().enumerate() {
+            let identity = if index % 4 == 0 { T::one() } else { T::zero() };
+            *value = identity + severity * (*value - identity);
+        }
+
+        CvdSimulation { matrix }
+    }
+
+    /// Simulate the full `deficiency`.
+    pub fn full(deficiency: Deficiency) -> Self {
+        CvdSimulation::new(deficiency, T::one())
+    }
+
+    /// The simulation matrix, in linear sRGB.
+    pub fn matrix(&self) -> Mat3<T> {
+        self.matrix
+    }
+
+    /// Simulate how a linear sRGB color is seen. The result may be slightly
+    /// outside the sRGB gamut.
+    pub fn simulate_linear(&self, color: LinSrgb<T>) -> LinSrgb<T> {
+        let m = &self.matrix;
+
+        LinSrgb::new(
+            m[0] * color.red + m[1] * color.green + m[2] * color.blue,
+            m[3] * color.red + m[4] * color.green + m[5] * color.blue,
+            m[6] * color.red + m[7] * color.green + m[8] * color.blue,
+        )
+    }
+
+    /// Simulate how an sRGB color is seen, and clamp the result to the sRGB
+    /// gamut.
+    pub fn simulate(&self, color: Srgb<T>) -> Srgb<T> {
+        Srgb::from_linear(self.simulate_linear(color.into_linear())).clamp()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CvdSimulation, Deficiency};
+    use crate::{LinSrgb, Srgb};
+
+    #[test]
+    fn neutral_colors() {
+        for &deficiency in &Deficiency::ALL {
+            let simulation = CvdSimulation::full(deficiency);
+            for &value in &[0.0f64, 0.3, 1.0] {
+                let gray = LinSrgb::new(value, value, value);
+                assert_relative_eq!(simulation.simulate_linear(gray), gray, epsilon = 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn severity() {
+        let color = Srgb::new(0.8f64, 0.2, 0.1);
+
+        let none = CvdSimulation::new(Deficiency::Protanopia, 0.0);
+        assert_relative_eq!(none.simulate(color), color, epsilon = 1e-4);
+
+        let half =
+            CvdSimulation::new(Deficiency::Protanopia, 0.5).simulate_linear(color.into_linear());
+        let full = CvdSimulation::full(Deficiency::Protanopia).simulate_linear(color.into_linear());
+        let linear = color.into_linear();
+        assert_relative_eq!(half.red, (linear.red + full.red) / 2.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn confusion() {
+        let red = Srgb::new(0.8f64, 0.2, 0.1);
+        let green = Srgb::new(0.4, 0.5, 0.1);
+        let distance = |a: Srgb<f64>, b: Srgb<f64>| {
+            (a.red - b.red).abs() + (a.green - b.green).abs() + (a.blue - b.blue).abs()
+        };
+
+        let deutan = CvdSimulation::full(Deficiency::Deuteranopia);
+        let tritan = CvdSimulation::full(Deficiency::Tritanopia);
+
+        assert!(
+            distance(deutan.simulate(red), deutan.simulate(green)) < distance(red, green) / 2.0
+        );
+        assert!(
+            distance(tritan.simulate(red), tritan.simulate(green)) > distance(red, green) / 2.0
+        );
+    }
+}
@@ -0,0 +1,119 @@
+//! Types and conversions for D50 workflows, such as print and ICC profiles.
+//!
+//! Most types in this library default to the D65 white point, which is the
+//! white point of sRGB and of most displays. Print workflows, and the
+//! profile connection space (PCS) of ICC profiles, use D50 instead. Mixing
+//! the two without chromatic adaptation makes every color slightly blue or
+//! yellow, which is easy to miss until it's printed.
+//!
+//! This module has aliases for the D50 versions of the CIE color spaces, and
+//! functions for converting between D65 and D50 colors with Bradford
+//! chromatic adaptation, which is the method that ICC profiles use:
+//!
+//! ```
+//! use approx::assert_relative_eq;
+//! use palette::d50::{self, LabD50};
+//! use palette::Srgb;
+//!
+//! // The white of the display is the white of the paper.
+//! let white: LabD50 = d50::d65_to_d50(Srgb::new(1.0f32, 1.0, 1.0));
+//! assert_relative_eq!(white, LabD50::new(100.0, 0.0, 0.0), epsilon = 1e-2);
+//!
+//! // And back, for showing PCS data on the screen.
+//! let color: Srgb = d50::d50_to_d65(LabD50::new(50.0f32, 20.0, -30.0));
+//! assert!(color.blue > color.red);
+//! ```
+//!
+//! The conversion functions accept and return any colors that can be
+//! converted to and from [`Xyz`], so they work with RGB, [`Lab`] and [`Lch`]
+//! alike. They are the same as [`AdaptFrom`] with the default method, but
+//! with the white points fixed, so they can't silently be mixed up.
+
+use crate::chromatic_adaptation::AdaptFrom;
+use crate::convert::{FromColorUnclamped, IntoColorUnclamped};
+use crate::white_point::{D50, D65};
+use crate::{Alpha, FloatComponent, Lab, Lch, Luv, Xyz};
+
+/// CIE L\*a\*b\* (CIELAB) with the D50 white point.
+pub type LabD50<T = f32> = Lab<D50, T>;
+/// CIE L\*a\*b\* (CIELAB) with the D50 white point and an alpha component.
+pub type LabaD50<T = f32> = Alpha<Lab<D50, T>, T>;
+
+/// CIE L\*C\*h° with the D50 white point.
+pub type LchD50<T = f32> = Lch<D50, T>;
+/// CIE L\*C\*h° with the D50 white point and an alpha component.
+pub type LchaD50<T = f32> = Alpha<Lch<D50, T>, T>;
+
+/// CIE L\*u\*v\* (CIELUV) with the D50 white point.
+pub type LuvD50<T = f32> = Luv<D50, T>;
+/// CIE L\*u\*v\* (CIELUV) with the D50 white point and an alpha component.
+pub type LuvaD50<T = f32> = Alpha<Luv<D50, T>, T>;
+
+/// CIE 1931 XYZ with the D50 white point.
+pub type XyzD50<T = f32> = Xyz<D50, T>;
+/// CIE 1931 XYZ with the D50 white point and an alpha component.
+pub type XyzaD50<T = f32> = Alpha<Xyz<D50, T>, T>;
+
+/// Convert a D65 color, such as sRGB, to a D50 color, with Bradford
+/// chromatic adaptation.
+///
+/// The result isn't clamped.
+pub fn d65_to_d50<S, D, T>(color: S) -> D
+where
+    T: FloatComponent,
+    S: IntoColorUnclamped<Xyz<D65, T>>,
+    D: FromColorUnclamped<Xyz<D50, T>>,
+{
+    D::adapt_from(color)
+}
+
+/// Convert a D50 color, such as ICC PCS data, to a D65 color, with Bradford
+/// chromatic adaptation.
+///
+/// The result isn't clamped, so colors that are outside the gamut of the
+/// destination space can be detected and handled.
+pub fn d50_to_d65<S, D, T>(color: S) -> D
+where
+    T: FloatComponent,
+    S: IntoColorUnclamped<Xyz<D50, T>>,
+    D: FromColorUnclamped<Xyz<D65, T>>,
+{
+    D::adapt_from(color)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{d50_to_d65, d65_to_d50, LabD50, LchD50, XyzD50};
+    use crate::white_point::{WhitePoint, D50, D65};
+    use crate::{FromColor, LinSrgb, Srgb, Xyz};
+
+    #[test]
+    fn white_maps_to_white() {
+        let white: XyzD50<f64> = d65_to_d50(Srgb::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(white, D50::get_xyz().with_white_point(), epsilon = 1e-4);
+
+        let back: Srgb<f64> = d50_to_d65(LabD50::new(100.0, 0.0, 0.0));
+        assert_relative_eq!(back, Srgb::new(1.0, 1.0, 1.0), epsilon = 1e-4);
+    }
+
+    #[test]
+    fn round_trip() {
+        let color = LinSrgb::new(0.8f64, 0.3, 0.1);
+        let lch: LchD50<f64> = d65_to_d50(color);
+        let back: LinSrgb<f64> = d50_to_d65(lch);
+        assert_relative_eq!(back, color, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn differs_from_relabeling() {
+        // Converting without adaptation makes neutral colors blue.
+        let gray = Srgb::new(0.5f64, 0.5, 0.5);
+        let xyz: XyzD50<f64> = Xyz::<D65, f64>::from_color(gray).with_white_point();
+        let relabeled = LabD50::from_color(xyz);
+        let adapted: LabD50<f64> = d65_to_d50(gray);
+
+        assert_relative_eq!(adapted.a, 0.0, epsilon = 1e-3);
+        assert_relative_eq!(adapted.b, 0.0, epsilon = 1e-3);
+        assert!(relabeled.b < -5.0);
+    }
+}
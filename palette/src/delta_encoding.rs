@@ -0,0 +1,441 @@
+//! Compact encoding of ordered palettes and gradients.
+//!
+//! Neighboring colors in palettes and gradients tend to be similar, so
+//! storing the differences between them takes less space than storing each
+//! color. [`DeltaEncoder`] quantizes the colors in [`Oklab`], with a
+//! guaranteed maximum error, and stores the differences between them as
+//! variable length integers:
+//!
+//! ```
+//! use palette::delta_encoding::{self, DeltaEncoder};
+//! use palette::{FromColor, Oklab, Srgb};
+//!
+//! let theme = [
+//!     Srgb::new(0.95f32, 0.95, 0.97),
+//!     Srgb::new(0.90, 0.91, 0.94),
+//!     Srgb::new(0.82, 0.84, 0.89),
+//!     Srgb::new(0.20, 0.40, 0.80),
+//! ];
+//!
+//! let encoder = DeltaEncoder::new(0.002);
+//! let bytes = encoder.encode(&theme);
+//! assert!(bytes.len() < 40);
+//!
+//! let decoded: Vec<Srgb> = delta_encoding::decode(&bytes).unwrap();
+//! for (original, decoded) in theme.iter().zip(&decoded) {
+//!     let original = Oklab::from_color(*original);
+//!     let decoded = Oklab::from_color(*decoded);
+//!     let error = ((original.l - decoded.l).powi(2)
+//!         + (original.a - decoded.a).powi(2)
+//!         + (original.b - decoded.b).powi(2))
+//!     .sqrt();
+//!     assert!(error <= 0.002);
+//! }
+//! ```
+//!
+//! The colors are quantized to a grid before the differences are
+//! calculated, so the error doesn't accumulate along the sequence. The
+//! maximum error is the Euclidean distance in Oklab, where `0.02` is around
+//! the smallest noticeable difference. Non-finite components are encoded as
+//! `0.0`.
+//!
+//! Gradients can be stored as their stops, with
+//! [`encode_stops`](DeltaEncoder::encode_stops) and [`decode_stops`]. The
+//! positions of the stops are stored as `f32` values.
+
+use core::fmt;
+
+use crate::convert::{FromColorUnclamped, IntoColorUnclamped};
+use crate::{FloatComponent, Oklab};
+
+/// The first byte of an encoded palette.
+const PALETTE_TAG: u8 = 1;
+
+/// The first byte of encoded gradient stops.
+const STOPS_TAG: u8 = 2;
+
+/// An encoder for sequences of colors, with a maximum error.
+///
+/// See the [module documentation](crate::delta_encoding) for an example.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DeltaEncoder<T = f32> {
+    step: T,
+}
+
+impl<T> DeltaEncoder<T>
+where
+    T: FloatComponent,
+{
+    /// Create an encoder, where the decoded colors are at most `max_error`
+    /// from the original colors, in Oklab.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_error` is not a positive, finite number.
+    pub fn new(max_error: T) -> Self {
+        assert!(
+            max_error.is_finite() && max_error > T::zero(),
+            "the maximum error must be a positive, finite number"
+        );
+
+        // The error in each component is at most half a step, which is
+        // `step * sqrt(3) / 2` in three dimensions.
+        DeltaEncoder {
+            step: max_error * T::from_f64(2.0) / T::from_f64(3.0).sqrt(),
+        }
+    }
+
+    /// The maximum error of the decoded colors, in Oklab.
+    pub fn max_error(&self) -> T {
+        self.step * T::from_f64(3.0).sqrt() / T::from_f64(2.0)
+    }
+
+    /// The size of the quantization steps of each component.
+    pub fn step(&self) -> T {
+        self.step
+    }
+
+    /// Encode a sequence of colors.
+    pub fn encode<C>(&self, colors: &[C]) -> Vec<u8>
+    where
+        C: IntoColorUnclamped<Oklab<T>> + Copy,
+    {
+        let mut bytes = self.header(PALETTE_TAG, colors.len());
+        let mut previous = [0; 3];
+
+        for &color in colors {
+            self.write_color(&mut bytes, &mut previous, color.into_color_unclamped());
+        }
+
+        bytes
+    }
+
+    /// Encode the stops of a gradient, as `(position, color)` pairs.
+    pub fn encode_stops<C>(&self, stops: &[(T, C)]) -> Vec<u8>
+    where
+        C: IntoColorUnclamped<Oklab<T>> + Copy,
+    {
+        let mut bytes = self.header(STOPS_TAG, stops.len());
+        let mut previous = [0; 3];
+
+        for &(position, color) in stops {
+            let position = position.to_f32().unwrap_or(f32::NAN);
+            bytes.extend_from_slice(&position.to_le_bytes());
+            self.write_color(&mut bytes, &mut previous, color.into_color_unclamped());
+        }
+
+        bytes
+    }
+
+    fn header(&self, tag: u8, length: usize) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(8 + length * 3);
+        bytes.push(tag);
+        bytes.extend_from_slice(&self.step.to_f32().unwrap_or(0.0).to_le_bytes());
+        write_varint(&mut bytes, length as u64);
+        bytes
+    }
+
+    fn write_color(&self, bytes: &mut Vec<u8>, previous: &mut [i64; 3], color: Oklab<T>) {
+        let step = self.step;
+        let quantize = |value: T| (value / step).round().to_i64().unwrap_or(0);
+        let current = [quantize(color.l), quantize(color.a), quantize(color.b)];
+
+        for (&current, previous) in current.iter().zip(previous.iter_mut()) {
+            write_varint(bytes, zigzag(current.wrapping_sub(*previous)));
+            *previous = current;
+        }
+    }
+}
+
+impl<T> Default for DeltaEncoder<T>
+where
+    T: FloatComponent,
+{
+    /// Create an encoder with a maximum error of `0.002`.
+    fn default() -> Self {
+        DeltaEncoder::new(T::from_f64(0.002))
+    }
+}
+
+/// Decode a sequence of colors, that was encoded with
+/// [`DeltaEncoder::encode`].
+pub fn decode<C, T>(bytes: &[u8]) -> Result<Vec<C>, DecodeError>
+where
+    C: FromColorUnclamped<Oklab<T>>,
+    T: FloatComponent,
+{
+    let mut reader = Reader::new(bytes, PALETTE_TAG)?;
+    let mut colors = Vec::with_capacity(reader.length.min(bytes.len()));
+
+    for _ in 0..reader.length {
+        colors.push(C::from_color_unclamped(reader.read_color()?));
+    }
+
+    reader.finish()?;
+    Ok(colors)
+}
+
+/// Decode the stops of a gradient, that were encoded with
+/// [`DeltaEncoder::encode_stops`].
+///
+/// The result can be passed to
+/// [`Gradient::with_domain`](crate::Gradient::with_domain).
+pub fn decode_stops<C, T>(bytes: &[u8]) -> Result<Vec<(T, C)>, DecodeError>
+where
+    C: FromColorUnclamped<Oklab<T>>,
+    T: FloatComponent,
+{
+    let mut reader = Reader::new(bytes, STOPS_TAG)?;
+    let mut stops = Vec::with_capacity(reader.length.min(bytes.len()));
+
+    for _ in 0..reader.length {
+        let position = f32::from_le_bytes(reader.read_array()?);
+        let color = C::from_color_unclamped(reader.read_color()?);
+        stops.push((T::from_f64(f64::from(position)), color));
+    }
+
+    reader.finish()?;
+    Ok(stops)
+}
+
+struct Reader<'a, T> {
+    bytes: &'a [u8],
+    step: T,
+    length: usize,
+    previous: [i64; 3],
+}
+
+impl<'a, T> Reader<'a, T>
+where
+    T: FloatComponent,
+{
+    fn new(bytes: &'a [u8], tag: u8) -> Result<Self, DecodeError> {
+        let (&first, bytes) = bytes.split_first().ok_or(DecodeError {
+            kind: DecodeErrorKind::UnexpectedEnd,
+        })?;
+
+        if first != tag {
+            return Err(DecodeError {
+                kind: DecodeErrorKind::InvalidHeader,
+            });
+        }
+
+        let mut reader = Reader {
+            bytes,
+            step: T::zero(),
+            length: 0,
+            previous: [0; 3],
+        };
+
+        let step = f32::from_le_bytes(reader.read_array()?);
+        if !(step.is_finite() && step > 0.0) {
+            return Err(DecodeError {
+                kind: DecodeErrorKind::InvalidHeader,
+            });
+        }
+
+        reader.step = T::from_f64(f64::from(step));
+        reader.length = reader.read_varint()? as usize;
+
+        Ok(reader)
+    }
+
+    fn read_color(&mut self) -> Result<Oklab<T>, DecodeError> {
+        let mut current = [T::zero(); 3];
+
+        for (index, current) in current.iter_mut().enumerate() {
+            let delta = unzigzag(self.read_varint()?);
+            self.previous[index] = self.previous[index].wrapping_add(delta);
+            *current = T::from_f64(self.previous[index] as f64) * self.step;
+        }
+
+        let [l, a, b] = current;
+        Ok(Oklab::new(l, a, b))
+    }
+
+    fn read_array(&mut self) -> Result<[u8; 4], DecodeError> {
+        if self.bytes.len() < 4 {
+            return Err(DecodeError {
+                kind: DecodeErrorKind::UnexpectedEnd,
+            });
+        }
+
+        let (array, rest) = self.bytes.split_at(4);
+        self.bytes = rest;
+        Ok([array[0], array[1], array[2], array[3]])
+    }
+
+    fn read_varint(&mut self) -> Result<u64, DecodeError> {
+        let mut value = 0u64;
+
+        for shift in (0..64).step_by(7) {
+            let (&byte, rest) = self.bytes.split_first().ok_or(DecodeError {
+                kind: DecodeErrorKind::UnexpectedEnd,
+            })?;
+            self.bytes = rest;
+
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        Err(DecodeError {
+            kind: DecodeErrorKind::InvalidVarint,
+        })
+    }
+
+    fn finish(self) -> Result<(), DecodeError> {
+        if self.bytes.is_empty() {
+            Ok(())
+        } else {
+            Err(DecodeError {
+                kind: DecodeErrorKind::TrailingBytes,
+            })
+        }
+    }
+}
+
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+
+    bytes.push(value as u8);
+}
+
+/// Map signed integers to unsigned integers, so small negative numbers are
+/// small too.
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn unzigzag(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
+
+/// The reason why encoded colors couldn't be decoded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DecodeErrorKind {
+    /// The data ended too early.
+    UnexpectedEnd,
+    /// The data doesn't start with a valid header, or it's a palette when
+    /// gradient stops were expected, or the other way around.
+    InvalidHeader,
+    /// A variable length integer is too long.
+    InvalidVarint,
+    /// There is more data after the last color.
+    TrailingBytes,
+}
+
+/// An error from decoding colors with [`decode`] or [`decode_stops`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DecodeError {
+    kind: DecodeErrorKind,
+}
+
+impl DecodeError {
+    /// The reason for the error.
+    pub fn kind(&self) -> DecodeErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self.kind {
+            DecodeErrorKind::UnexpectedEnd => "unexpected end of encoded colors",
+            DecodeErrorKind::InvalidHeader => "invalid header for encoded colors",
+            DecodeErrorKind::InvalidVarint => "invalid variable length integer",
+            DecodeErrorKind::TrailingBytes => "unexpected data after the encoded colors",
+        };
+
+        f.write_str(description)
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+#[cfg(test)]
+mod test {
+    use super::{decode, decode_stops, unzigzag, zigzag, DecodeErrorKind, DeltaEncoder};
+    use crate::{Gradient, LinSrgb, Oklab};
+
+    fn distance(a: Oklab<f64>, b: Oklab<f64>) -> f64 {
+        ((a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt()
+    }
+
+    #[test]
+    fn zigzag_round_trip() {
+        for &value in &[0i64, 1, -1, 63, -64, i64::MAX, i64::MIN] {
+            assert_eq!(unzigzag(zigzag(value)), value);
+        }
+        assert_eq!(zigzag(-1), 1);
+        assert_eq!(zigzag(1), 2);
+    }
+
+    #[test]
+    fn error_bound() {
+        let colors: Vec<Oklab<f64>> = (0..200)
+            .map(|i| {
+                let t = f64::from(i) / 200.0;
+                Oklab::new(t, (t * 17.0).sin() * 0.3, (t * 5.0).cos() * 0.3)
+            })
+            .collect();
+
+        for &max_error in &[0.0005, 0.002, 0.02] {
+            let encoder = DeltaEncoder::new(max_error);
+            let bytes = encoder.encode(&colors);
+            let decoded: Vec<Oklab<f64>> = decode(&bytes).unwrap();
+
+            assert_eq!(decoded.len(), colors.len());
+            for (&original, &decoded) in colors.iter().zip(&decoded) {
+                assert!(distance(original, decoded) <= max_error * 1.0001);
+            }
+        }
+
+        // Smooth sequences take less than 12 bytes per color.
+        let bytes = DeltaEncoder::new(0.002).encode(&colors);
+        assert!(bytes.len() < colors.len() * 6, "{} bytes", bytes.len());
+    }
+
+    #[test]
+    fn gradient_stops() {
+        let stops = vec![
+            (0.0, LinSrgb::new(1.0f64, 0.1, 0.1)),
+            (0.25, LinSrgb::new(0.1, 0.1, 1.0)),
+            (1.0, LinSrgb::new(0.1, 1.0, 0.1)),
+        ];
+
+        let bytes = DeltaEncoder::default().encode_stops(&stops);
+        let decoded: Vec<(f64, LinSrgb<f64>)> = decode_stops(&bytes).unwrap();
+
+        assert_eq!(decoded[1].0, 0.25);
+        let gradient = Gradient::with_domain(decoded);
+        assert_relative_eq!(gradient.get(0.0), stops[0].1, epsilon = 0.01);
+    }
+
+    #[test]
+    fn invalid_data() {
+        let bytes = DeltaEncoder::<f32>::default().encode(&[Oklab::new(0.5f32, 0.1, 0.1)]);
+
+        let error = decode::<Oklab, f32>(&bytes[..bytes.len() - 1]).unwrap_err();
+        assert_eq!(error.kind(), DecodeErrorKind::UnexpectedEnd);
+
+        let mut longer = bytes.clone();
+        longer.push(0);
+        let error = decode::<Oklab, f32>(&longer).unwrap_err();
+        assert_eq!(error.kind(), DecodeErrorKind::TrailingBytes);
+
+        let error = decode_stops::<Oklab, f32>(&bytes).unwrap_err();
+        assert_eq!(error.kind(), DecodeErrorKind::InvalidHeader);
+        assert_eq!(error.to_string(), "invalid header for encoded colors");
+
+        let error = decode::<Oklab, f32>(&[
+            1, 0, 0, 128, 63, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        ])
+        .unwrap_err();
+        assert_eq!(error.kind(), DecodeErrorKind::InvalidVarint);
+    }
+}
@@ -0,0 +1,316 @@
+//! Perceptual differences between structs of colors.
+//!
+//! [`ColorDiff`] compares two instances of a struct field by field, such as
+//! two versions of a theme, and reports the [`DeltaE`](crate::DeltaE) of each
+//! color field. It's meant for design review tools and snapshot tests, that
+//! want to know what changed and by how much, perceptually.
+//!
+//! `ColorDiff` can be derived. Each field is converted to [`Lab`](crate::Lab)
+//! and compared, except for fields marked with `#[palette(diff_skip)]`, which
+//! are ignored, and fields marked with `#[palette(diff_nested)]`, which are
+//! compared with their own `ColorDiff` implementation. The white point and
+//! the component type of `Lab` are `D65` and `f32` by default, and can be
+//! changed with `#[palette(white_point = "...", component = "...")]`, as
+//! for [`FromColorUnclamped`](crate::convert::FromColorUnclamped).
+//!
+//! ```
+//! use palette::diff::ColorDiff;
+//! use palette::{DeltaEFormula, Srgb};
+//!
+//! #[derive(ColorDiff)]
+//! struct Buttons {
+//!     background: Srgb,
+//!     text: Srgb,
+//! }
+//!
+//! #[derive(ColorDiff)]
+//! struct Theme {
+//!     background: Srgb,
+//!     #[palette(diff_nested)]
+//!     buttons: Buttons,
+//!     #[palette(diff_skip)]
+//!     name: &'static str,
+//! }
+//!
+//! let before = Theme {
+//!     background: Srgb::new(1.0, 1.0, 1.0),
+//!     buttons: Buttons {
+//!         background: Srgb::new(0.2, 0.4, 0.9),
+//!         text: Srgb::new(1.0, 1.0, 1.0),
+//!     },
+//!     name: "light",
+//! };
+//! let after = Theme {
+//!     buttons: Buttons {
+//!         background: Srgb::new(0.25, 0.4, 0.85),
+//!         ..before.buttons
+//!     },
+//!     ..before
+//! };
+//!
+//! let report = before.diff(&after, DeltaEFormula::Ciede2000);
+//! let changed: Vec<_> = report.changed(1.0).map(|entry| entry.field.as_str()).collect();
+//! assert_eq!(changed, ["buttons.background"]);
+//! ```
+
+use core::fmt;
+
+use crate::DeltaEFormula;
+
+pub use palette_derive::ColorDiff;
+
+/// The path to a field in a struct, such as `buttons.background`.
+///
+/// Each nested struct adds a segment, which refers to the path of its
+/// parent, so building a path doesn't allocate.
+#[derive(Clone, Copy, Debug)]
+pub struct FieldPath<'a> {
+    parent: Option<&'a FieldPath<'a>>,
+    name: &'static str,
+}
+
+impl<'a> FieldPath<'a> {
+    /// Create the path to a top level field.
+    pub fn new(name: &'static str) -> Self {
+        FieldPath { parent: None, name }
+    }
+
+    /// Create the path to the field `name` in the struct at `parent`.
+    ///
+    /// This is `FieldPath::new(name)` if `parent` is `None`.
+    pub fn child(parent: Option<&'a FieldPath<'a>>, name: &'static str) -> Self {
+        FieldPath { parent, name }
+    }
+
+    /// The name of the field, without its parents.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The path to the struct that contains the field, if any.
+    pub fn parent(&self) -> Option<&'a FieldPath<'a>> {
+        self.parent
+    }
+}
+
+impl fmt::Display for FieldPath<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(parent) = self.parent {
+            write!(f, "{}.", parent)?;
+        }
+
+        f.write_str(self.name)
+    }
+}
+
+/// Field by field color differences between two instances of a type.
+///
+/// See the [module documentation](crate::diff) for how to derive it.
+pub trait ColorDiff {
+    /// The type of the calculated color differences.
+    type Scalar;
+
+    /// Call `visitor` with the path and the difference of each compared
+    /// field, where `self` is the reference.
+    ///
+    /// The paths are children of `parent`, or top level paths if `parent` is
+    /// `None`.
+    fn visit_differences(
+        &self,
+        other: &Self,
+        formula: DeltaEFormula<Self::Scalar>,
+        parent: Option<&FieldPath<'_>>,
+        visitor: &mut dyn FnMut(&FieldPath<'_>, Self::Scalar),
+    );
+
+    /// Collect the differences between `self` and `other` into a report.
+    ///
+    /// This is only available if the `std` feature is enabled (this is the
+    /// default).
+    #[cfg(feature = "std")]
+    fn diff(&self, other: &Self, formula: DeltaEFormula<Self::Scalar>) -> DiffReport<Self::Scalar> {
+        let mut entries = Vec::new();
+        self.visit_differences(other, formula, None, &mut |path, delta_e| {
+            entries.push(FieldDiff {
+                field: path.to_string(),
+                delta_e,
+            })
+        });
+
+        DiffReport { entries }
+    }
+}
+
+/// The difference in one field of a [`DiffReport`].
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct FieldDiff<T> {
+    /// The path to the field, such as `buttons.background`.
+    pub field: String,
+
+    /// The color difference.
+    pub delta_e: T,
+}
+
+/// The differences between two instances of a [`ColorDiff`] type.
+///
+/// The entries are in field order. The `Display` output has one line per
+/// field, which works well for snapshot tests.
+///
+/// This is only available if the `std` feature is enabled (this is the
+/// default).
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct DiffReport<T> {
+    entries: Vec<FieldDiff<T>>,
+}
+
+#[cfg(feature = "std")]
+impl<T> DiffReport<T> {
+    /// The differences for all compared fields.
+    pub fn entries(&self) -> &[FieldDiff<T>] {
+        &self.entries
+    }
+
+    /// The entry for the field at `field`, such as `"buttons.background"`.
+    pub fn get(&self, field: &str) -> Option<&FieldDiff<T>> {
+        self.entries.iter().find(|entry| entry.field == field)
+    }
+
+    /// Iterate over the fields that differ by more than `threshold`.
+    pub fn changed(&self, threshold: T) -> impl Iterator<Item = &FieldDiff<T>> + '_
+    where
+        T: PartialOrd + Copy,
+    {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.delta_e > threshold)
+    }
+
+    /// The entry with the largest difference, or `None` if nothing was
+    /// compared.
+    pub fn max(&self) -> Option<&FieldDiff<T>>
+    where
+        T: PartialOrd,
+    {
+        let mut max: Option<&FieldDiff<T>> = None;
+
+        for entry in &self.entries {
+            let is_larger = match max {
+                Some(max) => entry.delta_e > max.delta_e,
+                None => true,
+            };
+
+            if is_larger {
+                max = Some(entry);
+            }
+        }
+
+        max
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> fmt::Display for DiffReport<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in &self.entries {
+            match f.precision() {
+                Some(precision) => writeln!(f, "{}: {:.*}", entry.field, precision, entry.delta_e)?,
+                None => writeln!(f, "{}: {}", entry.field, entry.delta_e)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ColorDiff, FieldPath};
+    use crate::white_point::D50;
+    use crate::{DeltaEFormula, Lab, Srgb};
+
+    #[derive(ColorDiff)]
+    #[palette(palette_internal, component = "T")]
+    struct Inner<T: crate::FloatComponent>(Srgb<T>, #[palette(diff_skip)] u8);
+
+    #[derive(ColorDiff)]
+    #[palette(palette_internal, white_point = "D50", component = "T")]
+    struct Outer<T: crate::FloatComponent> {
+        first: Lab<D50, T>,
+        #[palette(diff_nested)]
+        inner: Inner<T>,
+    }
+
+    #[test]
+    fn paths() {
+        let parent = FieldPath::new("a");
+        let child = FieldPath::child(Some(&parent), "b");
+        assert_eq!(child.to_string(), "a.b");
+        assert_eq!(FieldPath::child(None, "c").to_string(), "c");
+    }
+
+    #[test]
+    fn visit() {
+        let a = Outer {
+            first: Lab::new(50.0f64, 10.0, -20.0),
+            inner: Inner(Srgb::new(0.5, 0.5, 0.5), 1),
+        };
+        let b = Outer {
+            first: Lab::new(52.0f64, 10.0, -20.0),
+            inner: Inner(Srgb::new(0.5, 0.5, 0.5), 2),
+        };
+
+        // The skipped field is different, but it's not visited.
+        assert_ne!(a.inner.1, b.inner.1);
+
+        let mut visited = 0;
+        a.visit_differences(&b, DeltaEFormula::Cie76, None, &mut |path, delta_e| {
+            match visited {
+                0 => {
+                    assert_eq!(path.to_string(), "first");
+                    assert_relative_eq!(delta_e, 2.0);
+                }
+                1 => {
+                    assert_eq!(path.to_string(), "inner.0");
+                    assert_eq!(delta_e, 0.0);
+                }
+                _ => panic!("unexpected field {}", path),
+            }
+
+            visited += 1;
+        });
+        assert_eq!(visited, 2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn report() {
+        let a = Outer {
+            first: Lab::new(50.0f64, 10.0, -20.0),
+            inner: Inner(Srgb::new(0.5, 0.5, 0.5), 1),
+        };
+        let b = Outer {
+            first: Lab::new(52.0f64, 10.0, -20.0),
+            inner: Inner(Srgb::new(0.5, 0.5, 0.5), 2),
+        };
+
+        // The skipped field is different, but it's not in the report.
+        assert_ne!(a.inner.1, b.inner.1);
+
+        let report = a.diff(&b, DeltaEFormula::Cie76);
+        assert_eq!(report.entries().len(), 2);
+
+        assert_relative_eq!(report.get("first").unwrap().delta_e, 2.0);
+        assert_eq!(report.get("inner.0").unwrap().delta_e, 0.0);
+        assert_eq!(report.max().unwrap().field, "first");
+        assert_eq!(report.changed(0.5).count(), 1);
+
+        assert_eq!(format!("{:.2}", report), "first: 2.00\ninner.0: 0.00\n");
+    }
+}
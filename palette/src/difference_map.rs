@@ -0,0 +1,210 @@
+//! Per-pixel color difference maps.
+//!
+//! A difference map has one ΔE value for each pixel in an image, which is the
+//! difference between the pixel and a reference color, or the nearest color
+//! in a palette. It can be used for finding pixels that are close to a brand
+//! color, or for highlighting defects in a print or a render:
+//!
+//! ```
+//! use palette::difference_map::{delta_e_map, indices_within};
+//! use palette::{DeltaEFormula, FromColor, Lab, Srgb};
+//!
+//! let brand_red = Lab::from_color(Srgb::new(0.8f32, 0.1, 0.15));
+//! let pixels = [
+//!     Srgb::new(0.81f32, 0.1, 0.15),
+//!     Srgb::new(0.1, 0.4, 0.8),
+//!     Srgb::new(0.8, 0.12, 0.14),
+//! ];
+//!
+//! let mut map = [0.0; 3];
+//! delta_e_map(&pixels, brand_red, DeltaEFormula::Ciede2000, &mut map);
+//!
+//! let close: Vec<usize> = indices_within(&map, 2.0).collect();
+//! assert_eq!(close, [0, 2]);
+//! ```
+//!
+//! The pixels are converted to [`Lab`] in small batches, and the
+//! [`Cie76`](DeltaEFormula::Cie76) formula compares squared distances, with a
+//! single square root per pixel. This keeps the inner loops simple enough for
+//! the compiler to vectorize.
+
+use crate::convert::IntoColor;
+use crate::{DeltaE, DeltaEFormula, FloatComponent, Lab};
+
+/// The number of pixels that are converted to `Lab` at a time.
+const BATCH_SIZE: usize = 64;
+
+/// Calculate the difference between each pixel and `reference`, and write it
+/// to `output`.
+///
+/// The reference is the first color in the formula, which matters for the
+/// formulas that aren't symmetric.
+///
+/// # Panics
+///
+/// Panics if `pixels` and `output` have different lengths.
+pub fn delta_e_map<C, Wp, T>(
+    pixels: &[C],
+    reference: Lab<Wp, T>,
+    formula: DeltaEFormula<T>,
+    output: &mut [T],
+) where
+    C: IntoColor<Lab<Wp, T>> + Copy,
+    T: FloatComponent,
+{
+    delta_e_map_nearest(pixels, &[reference], formula, output);
+}
+
+/// Calculate the difference between each pixel and the nearest color in
+/// `palette`, and write it to `output`.
+///
+/// The palette colors are the first colors in the formula, which matters for
+/// the formulas that aren't symmetric. Each difference is infinite if the
+/// palette is empty.
+///
+/// # Panics
+///
+/// Panics if `pixels` and `output` have different lengths.
+pub fn delta_e_map_nearest<C, Wp, T>(
+    pixels: &[C],
+    palette: &[Lab<Wp, T>],
+    formula: DeltaEFormula<T>,
+    output: &mut [T],
+) where
+    C: IntoColor<Lab<Wp, T>> + Copy,
+    T: FloatComponent,
+{
+    assert_eq!(
+        pixels.len(),
+        output.len(),
+        "the output must have one value per pixel"
+    );
+
+    let mut batch = [Lab::new(T::zero(), T::zero(), T::zero()); BATCH_SIZE];
+
+    for (pixels, output) in pixels.chunks(BATCH_SIZE).zip(output.chunks_mut(BATCH_SIZE)) {
+        let batch = &mut batch[..pixels.len()];
+        for (lab, &pixel) in batch.iter_mut().zip(pixels) {
+            *lab = pixel.into_color();
+        }
+
+        for difference in output.iter_mut() {
+            *difference = T::infinity();
+        }
+
+        if let DeltaEFormula::Cie76 = formula {
+            for reference in palette {
+                for (difference, lab) in output.iter_mut().zip(&*batch) {
+                    let l = reference.l - lab.l;
+                    let a = reference.a - lab.a;
+                    let b = reference.b - lab.b;
+                    *difference = difference.min(l * l + a * a + b * b);
+                }
+            }
+
+            for difference in output.iter_mut() {
+                *difference = difference.sqrt();
+            }
+        } else {
+            for &reference in palette {
+                for (difference, &lab) in output.iter_mut().zip(&*batch) {
+                    *difference = difference.min(reference.delta_e(lab, formula));
+                }
+            }
+        }
+    }
+}
+
+/// Iterate over the indices of the values in a difference map that are less
+/// than or equal to `threshold`.
+pub fn indices_within<T>(map: &[T], threshold: T) -> impl Iterator<Item = usize> + '_
+where
+    T: PartialOrd + Copy,
+{
+    map.iter()
+        .enumerate()
+        .filter(move |&(_, &difference)| difference <= threshold)
+        .map(|(index, _)| index)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{delta_e_map, delta_e_map_nearest, indices_within};
+    use crate::white_point::D65;
+    use crate::{Cie94Application, DeltaE, DeltaEFormula, FromColor, Lab, Srgb};
+
+    fn pixels() -> Vec<Srgb<f64>> {
+        (0..150)
+            .map(|index| {
+                let index = index as f64;
+                Srgb::new(index / 150.0, (index * 7.0 % 150.0) / 150.0, 0.5)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn matches_delta_e() {
+        let reference = Lab::<D65, f64>::new(50.0, 20.0, -10.0);
+        let formulas = [
+            DeltaEFormula::Cie76,
+            DeltaEFormula::Cie94(Cie94Application::GraphicArts),
+            DeltaEFormula::Cmc {
+                lightness: 2.0,
+                chroma: 1.0,
+            },
+            DeltaEFormula::Ciede2000,
+        ];
+
+        let pixels = pixels();
+        let mut map = vec![0.0; pixels.len()];
+        for &formula in &formulas {
+            delta_e_map(&pixels, reference, formula, &mut map);
+
+            for (&pixel, &difference) in pixels.iter().zip(&map) {
+                let expected = reference.delta_e(Lab::from_color(pixel), formula);
+                assert_relative_eq!(difference, expected, epsilon = 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn nearest() {
+        let palette = [
+            Lab::<D65, f64>::new(20.0, 0.0, 0.0),
+            Lab::new(80.0, 0.0, 0.0),
+        ];
+
+        let pixels = pixels();
+        let mut map = vec![0.0; pixels.len()];
+        delta_e_map_nearest(&pixels, &palette, DeltaEFormula::Ciede2000, &mut map);
+
+        for (&pixel, &difference) in pixels.iter().zip(&map) {
+            let lab = Lab::from_color(pixel);
+            let expected = palette[0]
+                .delta_e(lab, DeltaEFormula::Ciede2000)
+                .min(palette[1].delta_e(lab, DeltaEFormula::Ciede2000));
+            assert_relative_eq!(difference, expected, epsilon = 1e-9);
+        }
+
+        delta_e_map_nearest::<_, D65, f64>(&pixels, &[], DeltaEFormula::Cie76, &mut map);
+        assert!(map.iter().all(|difference| difference.is_infinite()));
+    }
+
+    #[test]
+    fn within() {
+        let map = [3.0, 0.5, 1.0, 7.0];
+        assert_eq!(indices_within(&map, 1.0).collect::<Vec<_>>(), [1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the output must have one value per pixel")]
+    fn wrong_output_length() {
+        let mut map = [0.0; 2];
+        delta_e_map(
+            &[Srgb::new(0.1f32, 0.2, 0.3)],
+            Lab::<D65, f32>::new(50.0, 0.0, 0.0),
+            DeltaEFormula::Cie76,
+            &mut map,
+        );
+    }
+}
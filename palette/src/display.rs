@@ -0,0 +1,178 @@
+//! Preview colors as they would look on another display.
+//!
+//! Displays differ from the sRGB reference display in more ways than their
+//! primaries. A [`DisplaySimulation`] describes three of them, and simulates
+//! how an sRGB signal would look on such a display, when viewed on a
+//! reference display:
+//!
+//! 1. The native gamma, which replaces the sRGB transfer function when the
+//!    signal is decoded. A higher gamma makes the midtones darker.
+//! 2. The white point, which the display shows for full white. The whole
+//!    image is shifted towards it with a [chromatic adaptation
+//!    transform](crate::chromatic_adaptation), which is how a white point
+//!    change in the display's channel gains behaves.
+//! 3. The peak luminance, relative to the luminance of the reference
+//!    display. Dimmer displays give a darker result, and brighter displays
+//!    give a brighter result, that may be clipped.
+//!
+//! ```
+//! use palette::display::DisplaySimulation;
+//! use palette::convert::FromColorUnclamped;
+//! use palette::{Srgb, Xyz, Yxy};
+//!
+//! // A cool 9300 K display with gamma 2.4, that is brighter than the
+//! // 80 cd/m² reference.
+//! let white: Xyz = Xyz::from_color_unclamped(Yxy::new(0.2831, 0.2971, 1.0));
+//! let display = DisplaySimulation::new(white.with_white_point(), 2.4, 120.0);
+//!
+//! let preview = display.simulate(Srgb::new(0.8f32, 0.8, 0.8));
+//! assert!(preview.blue > preview.red);
+//! ```
+//!
+//! The simulation is only as accurate as the description of the display.
+//! Properly calibrating and profiling the displays is still necessary for
+//! critical work.
+
+use crate::chromatic_adaptation::{Method, TransformMatrix};
+use crate::convert::{FromColorUnclamped, IntoColorUnclamped};
+use crate::matrix::multiply_xyz;
+use crate::white_point::{Any, WhitePoint, D65};
+use crate::{clamp, float, from_f64, Clamp, FloatComponent, LinSrgb, Srgb, Xyz};
+
+/// A description of a display, for simulating how colors look on it.
+///
+/// The display is assumed to have sRGB primaries.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct DisplaySimulation<T> {
+    /// The white point of the display, normalized to a luminance of 1.0.
+    pub white_point: Xyz<Any, T>,
+
+    /// The native gamma of the display.
+    pub gamma: T,
+
+    /// The peak luminance of the display, in cd/m².
+    pub peak_luminance: T,
+
+    /// The peak luminance of the reference display, in cd/m². The default is
+    /// 80 cd/m², as in the sRGB specification.
+    pub reference_luminance: T,
+
+    /// The chromatic adaptation method for the white point shift. The
+    /// default is Bradford.
+    pub adaptation: Method,
+}
+
+impl<T> DisplaySimulation<T>
+where
+    T: FloatComponent,
+{
+    /// Describe a display with a white point, a native gamma and a peak
+    /// luminance in cd/m².
+    pub fn new(white_point: Xyz<Any, T>, gamma: T, peak_luminance: T) -> Self {
+        DisplaySimulation {
+            white_point,
+            gamma,
+            peak_luminance,
+            reference_luminance: from_f64(80.0),
+            adaptation: Method::Bradford,
+        }
+    }
+
+    /// Describe a display that matches the reference display, apart from a
+    /// gamma of 2.2 instead of the sRGB transfer function.
+    pub fn reference() -> Self {
+        DisplaySimulation::new(D65::get_xyz(), from_f64(2.2), from_f64(80.0))
+    }
+
+    /// Change the peak luminance of the reference display, in cd/m².
+    pub fn with_reference_luminance(mut self, reference_luminance: T) -> Self {
+        self.reference_luminance = reference_luminance;
+        self
+    }
+
+    /// Change the chromatic adaptation method.
+    pub fn with_adaptation(mut self, adaptation: Method) -> Self {
+        self.adaptation = adaptation;
+        self
+    }
+
+    /// Simulate the light that the display emits for the signal `color`, as
+    /// it would be seen when adapted to the reference display.
+    ///
+    /// A luminance of 1.0 is the white of the reference display, and the
+    /// result may be outside the sRGB gamut.
+    pub fn simulate_xyz(&self, color: Srgb<T>) -> Xyz<D65, T> {
+        let decode = |signal: T| float::powf(clamp(signal, T::zero(), T::one()), self.gamma);
+        let linear = LinSrgb::new(decode(color.red), decode(color.green), decode(color.blue));
+
+        let matrix = self
+            .adaptation
+            .generate_transform_matrix(D65::get_xyz(), self.white_point);
+        let xyz: Xyz<Any, T> = Xyz::from_color_unclamped(linear).with_white_point();
+        let adapted = multiply_xyz(&matrix, &xyz);
+
+        (adapted * (self.peak_luminance / self.reference_luminance)).with_white_point()
+    }
+
+    /// Simulate how the signal `color` would look on the display, when
+    /// shown on the reference display. Colors that the reference display
+    /// can't show are clamped.
+    pub fn simulate(&self, color: Srgb<T>) -> Srgb<T> {
+        let srgb: Srgb<T> = self.simulate_xyz(color).into_color_unclamped();
+        srgb.clamp()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::DisplaySimulation;
+    use crate::white_point::{WhitePoint, D50, D65};
+    use crate::{Srgb, Xyz};
+
+    #[test]
+    fn reference_gamma() {
+        let display = DisplaySimulation::<f64>::reference();
+
+        // Gamma 2.2 is close to the sRGB transfer function, but darker in
+        // the shadows.
+        let black = Srgb::new(0.0, 0.0, 0.0);
+        let white = Srgb::new(1.0, 1.0, 1.0);
+        let dark = Srgb::new(0.1, 0.1, 0.1);
+
+        assert_relative_eq!(display.simulate(black), black, epsilon = 1e-6);
+        assert_relative_eq!(display.simulate(white), white, epsilon = 1e-6);
+        assert!(display.simulate(dark).red < 0.1);
+
+        let xyz = display.simulate_xyz(Srgb::new(0.5, 0.5, 0.5));
+        assert_relative_eq!(xyz.y, 0.5f64.powf(2.2), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn white_point() {
+        let display = DisplaySimulation::new(D50::get_xyz(), 2.2, 80.0);
+        let white = display.simulate_xyz(Srgb::new(1.0f64, 1.0, 1.0));
+
+        assert_relative_eq!(white, Xyz::new(0.96422, 1.0, 0.82521), epsilon = 1e-4);
+
+        let preview = display.simulate(Srgb::new(1.0, 1.0, 1.0));
+        assert!(preview.red > preview.blue);
+    }
+
+    #[test]
+    fn luminance() {
+        let dim = DisplaySimulation::new(D65::get_xyz(), 2.2, 40.0);
+        let bright = DisplaySimulation::new(D65::get_xyz(), 2.2, 160.0);
+        let gray = Srgb::new(0.5f64, 0.5, 0.5);
+
+        assert_relative_eq!(
+            dim.simulate_xyz(gray).y * 4.0,
+            bright.simulate_xyz(gray).y,
+            epsilon = 1e-9
+        );
+        assert_eq!(
+            bright.simulate(Srgb::new(1.0, 1.0, 1.0)),
+            Srgb::new(1.0, 1.0, 1.0)
+        );
+    }
+}
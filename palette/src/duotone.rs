@@ -0,0 +1,245 @@
+//! Separate colors into two inks, for duotone printing.
+//!
+//! A duotone is printed with only two inks, usually a spot color and black,
+//! on white paper. [`Duotone`] finds the amount of each ink that comes
+//! closest to each color of an image, by minimizing the color difference
+//! with non-negative least squares. The printed color is modeled as a
+//! linear mix of the paper and ink colors in [`Lab`], so the difference
+//! that's minimized is the CIE76 ΔE.
+//!
+//! ```
+//! use palette::duotone::Duotone;
+//! use palette::{Lab, Srgb, IntoColor};
+//!
+//! // A dark blue spot color, and black.
+//! let duotone = Duotone::new(Lab::new(35.0f32, 10.0, -50.0), Lab::new(15.0, 0.0, 0.0));
+//!
+//! let sky: Lab = Srgb::new(0.5f32, 0.6, 0.9).into_color();
+//! let [blue, black] = duotone.separate(sky);
+//! assert!(blue > 0.3);
+//! assert!(black < 0.1);
+//!
+//! // A preview of the print.
+//! let printed = duotone.composite([blue, black]);
+//! assert!(printed.b < 0.0);
+//! ```
+
+use crate::convert::IntoColorUnclamped;
+use crate::white_point::{WhitePoint, D65};
+use crate::{clamp, from_f64, FloatComponent, Lab};
+
+/// Separation of colors into two inks, printed on paper.
+///
+/// The ink amounts are from `0.0`, which is no ink, to `1.0`, which is the
+/// solid ink color.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct Duotone<Wp = D65, T = f32> {
+    /// The color of the paper, where there's no ink.
+    pub paper: Lab<Wp, T>,
+
+    /// The solid color of the first ink.
+    pub first: Lab<Wp, T>,
+
+    /// The solid color of the second ink.
+    pub second: Lab<Wp, T>,
+}
+
+impl<Wp, T> Duotone<Wp, T>
+where
+    T: FloatComponent,
+    Wp: WhitePoint<T>,
+{
+    /// Create a separation for `first` and `second`, which are the colors
+    /// of the solid inks on white paper.
+    pub fn new(first: Lab<Wp, T>, second: Lab<Wp, T>) -> Self {
+        Duotone {
+            paper: Lab::new(from_f64(100.0), T::zero(), T::zero()),
+            first,
+            second,
+        }
+    }
+
+    /// Change the color of the paper, which is white by default.
+    pub fn with_paper(mut self, paper: Lab<Wp, T>) -> Self {
+        self.paper = paper;
+        self
+    }
+
+    /// Find the amounts of the two inks that print the closest color to
+    /// `color`, as `[first, second]`.
+    pub fn separate<C>(&self, color: C) -> [T; 2]
+    where
+        C: IntoColorUnclamped<Lab<Wp, T>>,
+    {
+        let color: Lab<Wp, T> = color.into_color_unclamped();
+        let target = self.offset(color);
+        let first = self.offset(self.first);
+        let second = self.offset(self.second);
+
+        let first_first = dot(first, first);
+        let first_second = dot(first, second);
+        let second_second = dot(second, second);
+        let first_target = dot(first, target);
+        let second_target = dot(second, target);
+
+        let error = |amounts: [T; 2]| {
+            let [x, y] = amounts;
+            let mut sum = T::zero();
+            for i in 0..3 {
+                let difference = first[i] * x + second[i] * y - target[i];
+                sum = sum + difference * difference;
+            }
+            sum
+        };
+
+        // The unconstrained solution is the best one, if it's within bounds.
+        let determinant = first_first * second_second - first_second * first_second;
+        if determinant > T::epsilon() {
+            let x = (second_second * first_target - first_second * second_target) / determinant;
+            let y = (first_first * second_target - first_second * first_target) / determinant;
+            if is_amount(x) && is_amount(y) {
+                return [x, y];
+            }
+        }
+
+        // Otherwise it's on an edge, where one of the amounts is `0.0` or
+        // `1.0` and the other is the best one along that edge.
+        let along = |projection: T, length_squared: T| {
+            if length_squared > T::zero() {
+                clamp(projection / length_squared, T::zero(), T::one())
+            } else {
+                T::zero()
+            }
+        };
+
+        let mut best = [T::zero(); 2];
+        let mut best_error = T::infinity();
+        for &fixed in &[T::zero(), T::one()] {
+            let candidates = [
+                [
+                    fixed,
+                    along(second_target - fixed * first_second, second_second),
+                ],
+                [
+                    along(first_target - fixed * first_second, first_first),
+                    fixed,
+                ],
+            ];
+
+            for &candidate in &candidates {
+                let candidate_error = error(candidate);
+                if candidate_error < best_error {
+                    best = candidate;
+                    best_error = candidate_error;
+                }
+            }
+        }
+
+        best
+    }
+
+    /// Separate each color in `colors`, with [`separate`](Duotone::separate).
+    ///
+    /// This method is only available if the `std` feature is enabled (this is
+    /// the default).
+    #[cfg(feature = "std")]
+    pub fn separate_all<C, I>(&self, colors: I) -> Vec<[T; 2]>
+    where
+        C: IntoColorUnclamped<Lab<Wp, T>>,
+        I: IntoIterator<Item = C>,
+    {
+        colors
+            .into_iter()
+            .map(|color| self.separate(color))
+            .collect()
+    }
+
+    /// Get the printed color for the ink `amounts`, as `[first, second]`.
+    pub fn composite(&self, amounts: [T; 2]) -> Lab<Wp, T> {
+        let [x, y] = amounts;
+        let first = self.offset(self.first);
+        let second = self.offset(self.second);
+
+        Lab::new(
+            self.paper.l + first[0] * x + second[0] * y,
+            self.paper.a + first[1] * x + second[1] * y,
+            self.paper.b + first[2] * x + second[2] * y,
+        )
+    }
+
+    fn offset(&self, color: Lab<Wp, T>) -> [T; 3] {
+        [
+            color.l - self.paper.l,
+            color.a - self.paper.a,
+            color.b - self.paper.b,
+        ]
+    }
+}
+
+fn dot<T: FloatComponent>(a: [T; 3], b: [T; 3]) -> T {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn is_amount<T: FloatComponent>(amount: T) -> bool {
+    amount >= T::zero() && amount <= T::one()
+}
+
+#[cfg(test)]
+mod test {
+    use super::Duotone;
+    use crate::white_point::D65;
+    use crate::Lab;
+
+    fn duotone() -> Duotone<D65, f64> {
+        Duotone::new(Lab::new(55.0, 70.0, 50.0), Lab::new(15.0, 0.0, 0.0))
+    }
+
+    #[test]
+    fn exact_mixes() {
+        let duotone = duotone();
+
+        for &amounts in &[[0.0, 0.0], [1.0, 0.0], [0.3, 0.6], [1.0, 1.0], [0.0, 0.5]] {
+            let color = duotone.composite(amounts);
+            let separated = duotone.separate(color);
+            assert_relative_eq!(separated[0], amounts[0], epsilon = 1e-9);
+            assert_relative_eq!(separated[1], amounts[1], epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn out_of_range() {
+        let duotone = duotone();
+
+        // Blue can't be printed with red ink, so only black is used.
+        let [red, black] = duotone.separate(Lab::new(40.0, 10.0, -60.0));
+        assert_eq!(red, 0.0);
+        assert!(black > 0.0 && black < 1.0);
+
+        // Darker than both inks.
+        let [_, black] = duotone.separate(Lab::new(0.0, 0.0, 0.0));
+        assert_eq!(black, 1.0);
+
+        // Lighter than the paper.
+        let brighter = Lab::new(105.0, 0.0, 0.0);
+        assert_eq!(duotone.separate(brighter), [0.0, 0.0]);
+    }
+
+    #[test]
+    fn same_inks() {
+        let ink = Lab::new(50.0, 0.0, 0.0);
+        let duotone = Duotone::<D65, f64>::new(ink, ink);
+        let [first, second] = duotone.separate(Lab::new(75.0, 0.0, 0.0));
+        assert_relative_eq!(first + second, 0.5, epsilon = 1e-9);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn separate_all() {
+        let duotone = duotone();
+        let colors = [duotone.composite([0.2, 0.4]), duotone.paper];
+        let amounts = duotone.separate_all(colors.iter().copied());
+        assert_eq!(amounts.len(), 2);
+        assert_eq!(amounts[1], [0.0, 0.0]);
+    }
+}
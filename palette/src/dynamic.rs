@@ -0,0 +1,304 @@
+//! Colors with a color space that is chosen at run-time.
+//!
+//! The rest of the crate encodes color spaces in the type system, which
+//! doesn't map well to languages without generics, or to situations where
+//! the space isn't known until run-time. [`DynamicColor`] is a small, stable
+//! alternative, with a [`Space`] tag and three `f64` components, that is
+//! suitable for binding generators such as PyO3 or uniffi. All conversions
+//! go through the statically typed colors, so the results are the same.
+//!
+//! The RGB based spaces use the sRGB standard, and the CIE based spaces use
+//! the D65 white point. Hues are in degrees.
+//!
+//! ```
+//! use palette::dynamic::{DynamicColor, Metric, Space};
+//!
+//! let orange = DynamicColor::new(Space::Srgb, [1.0, 0.5, 0.0]);
+//! let oklch = orange.convert(Space::Oklch);
+//! assert_eq!(oklch.space, Space::Oklch);
+//!
+//! let space: Space = "lab".parse().unwrap();
+//! let red = DynamicColor::new(space, [53.24, 80.09, 67.20]);
+//! let difference = orange.difference(red, Metric::Ciede2000);
+//! assert!(difference > 10.0);
+//! ```
+//!
+//! [`ColorOp`] describes common color operations as data, so they can be
+//! stored, sent between processes, or chained in a node graph.
+//!
+//! [`DynamicRgbSpace`] describes an RGB space at run-time, such as the space
+//! of a connected display, from its EDID data.
+//!
+//! [`ImageColorSpace`] interprets the color space metadata of an image, from
+//! its PNG chunks or EXIF tags.
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::cast;
+use crate::color_difference::ColorDifference;
+use crate::convert::FromColorUnclamped;
+use crate::encoding::Srgb;
+use crate::white_point::D65;
+use crate::{Clamp, Hsl, Hsluv, Hsv, Hwb, Lab, Lch, Lchuv, LinSrgb, Luv, Oklab, Oklch, Xyz, Yxy};
+
+pub use self::edid::{DynamicRgbSpace, ParseEdidError, ParseEdidErrorKind};
+pub use self::metadata::{ImageColorSpace, PngColorChunks};
+pub use self::op::{apply_all, ColorOp};
+
+mod edid;
+mod metadata;
+mod op;
+
+macro_rules! impl_spaces {
+    ($($(#[$doc:meta])* $variant:ident => $ty:ty, $name:expr, [$($component:expr),+];)+) => {
+        /// A color space tag for [`DynamicColor`].
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+        pub enum Space {
+            $(
+                $(#[$doc])*
+                $variant,
+            )+
+        }
+
+        impl Space {
+            /// All supported color spaces.
+            pub const ALL: &'static [Space] = &[$(Space::$variant),+];
+
+            /// The lower case name of the color space, as accepted by
+            /// `from_str`.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Space::$variant => $name,)+
+                }
+            }
+
+            /// The names of the three components, in order.
+            pub fn component_names(self) -> [&'static str; 3] {
+                match self {
+                    $(Space::$variant => [$($component),+],)+
+                }
+            }
+
+            fn components_to_xyz(self, components: [f64; 3]) -> Xyz<D65, f64> {
+                match self {
+                    $(Space::$variant => {
+                        Xyz::from_color_unclamped(cast::from_array::<$ty>(components))
+                    })+
+                }
+            }
+
+            fn xyz_to_components(self, xyz: Xyz<D65, f64>) -> [f64; 3] {
+                match self {
+                    $(Space::$variant => cast::into_array(<$ty>::from_color_unclamped(xyz)),)+
+                }
+            }
+
+            fn clamp(self, components: [f64; 3]) -> [f64; 3] {
+                match self {
+                    $(Space::$variant => {
+                        cast::into_array(cast::from_array::<$ty>(components).clamp())
+                    })+
+                }
+            }
+        }
+
+        impl FromStr for Space {
+            type Err = ParseSpaceError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $($name => Ok(Space::$variant),)+
+                    _ => Err(ParseSpaceError),
+                }
+            }
+        }
+    };
+}
+
+impl_spaces! {
+    /// Non-linear sRGB.
+    Srgb => crate::Srgb<f64>, "srgb", ["red", "green", "blue"];
+    /// Linear sRGB.
+    LinSrgb => LinSrgb<f64>, "linear_srgb", ["red", "green", "blue"];
+    /// HSL, based on sRGB.
+    Hsl => Hsl<Srgb, f64>, "hsl", ["hue", "saturation", "lightness"];
+    /// HSV, based on sRGB.
+    Hsv => Hsv<Srgb, f64>, "hsv", ["hue", "saturation", "value"];
+    /// HWB, based on sRGB.
+    Hwb => Hwb<Srgb, f64>, "hwb", ["hue", "whiteness", "blackness"];
+    /// CIE L\*a\*b\*.
+    Lab => Lab<D65, f64>, "lab", ["l", "a", "b"];
+    /// CIE L\*C\*h°.
+    Lch => Lch<D65, f64>, "lch", ["l", "chroma", "hue"];
+    /// CIE L\*u\*v\*.
+    Luv => Luv<D65, f64>, "luv", ["l", "u", "v"];
+    /// CIE L\*C\*uv h°uv.
+    Lchuv => Lchuv<D65, f64>, "lchuv", ["l", "chroma", "hue"];
+    /// HSLuv.
+    Hsluv => Hsluv<D65, f64>, "hsluv", ["hue", "saturation", "l"];
+    /// Oklab.
+    Oklab => Oklab<f64>, "oklab", ["l", "a", "b"];
+    /// Oklch.
+    Oklch => Oklch<f64>, "oklch", ["l", "chroma", "hue"];
+    /// CIE 1931 XYZ.
+    Xyz => Xyz<D65, f64>, "xyz", ["x", "y", "z"];
+    /// CIE 1931 Yxy (xyY).
+    Yxy => Yxy<D65, f64>, "yxy", ["x", "y", "luma"];
+}
+
+impl fmt::Display for Space {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Error type for parsing an unknown color space name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseSpaceError;
+
+impl fmt::Display for ParseSpaceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("unknown color space name")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseSpaceError {}
+
+/// A color difference metric for [`DynamicColor::difference`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub enum Metric {
+    /// The Euclidean distance in CIE L\*a\*b\*, also known as ΔE\*76.
+    Cie76,
+    /// The CIEDE2000 color difference.
+    Ciede2000,
+    /// The Euclidean distance in Oklab.
+    Oklab,
+}
+
+/// A color with a run-time color space and `f64` components.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct DynamicColor {
+    /// The color space of `components`.
+    pub space: Space,
+
+    /// The components, in the order given by [`Space::component_names`].
+    pub components: [f64; 3],
+}
+
+impl DynamicColor {
+    /// Create a color in `space`.
+    pub const fn new(space: Space, components: [f64; 3]) -> Self {
+        DynamicColor { space, components }
+    }
+
+    /// Convert the color to another space, without clamping the result.
+    pub fn convert(self, space: Space) -> Self {
+        if space == self.space {
+            return self;
+        }
+
+        let xyz = self.space.components_to_xyz(self.components);
+        DynamicColor::new(space, space.xyz_to_components(xyz))
+    }
+
+    /// Change out-of-bounds components to the nearest valid values.
+    pub fn clamp(self) -> Self {
+        DynamicColor::new(self.space, self.space.clamp(self.components))
+    }
+
+    /// Calculate the difference between two colors, which may be in
+    /// different spaces.
+    pub fn difference(self, other: DynamicColor, metric: Metric) -> f64 {
+        match metric {
+            Metric::Cie76 => {
+                let [l1, a1, b1] = self.convert(Space::Lab).components;
+                let [l2, a2, b2] = other.convert(Space::Lab).components;
+                distance([l1 - l2, a1 - a2, b1 - b2])
+            }
+            Metric::Ciede2000 => {
+                let lab1: Lab<D65, f64> = cast::from_array(self.convert(Space::Lab).components);
+                let lab2: Lab<D65, f64> = cast::from_array(other.convert(Space::Lab).components);
+                lab1.get_color_difference(lab2)
+            }
+            Metric::Oklab => {
+                let [l1, a1, b1] = self.convert(Space::Oklab).components;
+                let [l2, a2, b2] = other.convert(Space::Oklab).components;
+                distance([l1 - l2, a1 - a2, b1 - b2])
+            }
+        }
+    }
+}
+
+fn distance([x, y, z]: [f64; 3]) -> f64 {
+    crate::float::Float::sqrt(x * x + y * y + z * z)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{DynamicColor, Metric, ParseSpaceError, Space};
+    use crate::white_point::D65;
+    use crate::{FromColor, Lab, Oklch, Srgb};
+
+    #[test]
+    fn names() {
+        for &space in Space::ALL {
+            assert_eq!(space.name().parse(), Ok(space));
+        }
+
+        assert_eq!("Lab".parse::<Space>(), Err(ParseSpaceError));
+    }
+
+    #[test]
+    fn same_as_static() {
+        let srgb = Srgb::new(0.8, 0.3, 0.1);
+        let expected = Oklch::from_color(srgb);
+
+        let converted = DynamicColor::new(Space::Srgb, [0.8, 0.3, 0.1]).convert(Space::Oklch);
+        assert_relative_eq!(
+            Oklch::new(
+                converted.components[0],
+                converted.components[1],
+                converted.components[2]
+            ),
+            expected,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        let color = DynamicColor::new(Space::Srgb, [0.2, 0.4, 0.6]);
+
+        for &space in Space::ALL {
+            let [r, g, b] = color.convert(space).convert(Space::Srgb).components;
+            assert_relative_eq!(Srgb::new(r, g, b), Srgb::new(0.2, 0.4, 0.6), epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn clamp() {
+        let color = DynamicColor::new(Space::Srgb, [1.2, 0.5, -0.1]).clamp();
+        assert_eq!(color.components, [1.0, 0.5, 0.0]);
+    }
+
+    #[test]
+    fn difference() {
+        let red = DynamicColor::new(Space::Srgb, [1.0, 0.0, 0.0]);
+        let red_lab = red.convert(Space::Lab);
+
+        assert_relative_eq!(red.difference(red_lab, Metric::Ciede2000), 0.0);
+
+        let lab1: Lab<D65, f64> = Lab::new(50.0, 10.0, 0.0);
+        let lab2: Lab<D65, f64> = Lab::new(53.0, 6.0, 0.0);
+        let color1 = DynamicColor::new(Space::Lab, [lab1.l, lab1.a, lab1.b]);
+        let color2 = DynamicColor::new(Space::Lab, [lab2.l, lab2.a, lab2.b]);
+
+        assert_relative_eq!(color1.difference(color2, Metric::Cie76), 5.0);
+        assert!(color1.difference(color2, Metric::Oklab) > 0.0);
+    }
+}
@@ -0,0 +1,366 @@
+use core::fmt;
+
+use crate::chromatic_adaptation::{Method, TransformMatrix};
+use crate::float;
+use crate::matrix::{matrix_inverse, multiply_3x3, multiply_xyz};
+use crate::white_point::{Any, WhitePoint, D65};
+use crate::{Mat3, Xyz};
+
+use super::{DynamicColor, Space};
+
+/// The fixed header of an EDID base block.
+const EDID_HEADER: [u8; 8] = [0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00];
+
+/// The offset of the gamma byte in an EDID base block.
+const GAMMA_OFFSET: usize = 0x17;
+
+/// The offset of the color characteristics in an EDID base block.
+const CHROMATICITY_OFFSET: usize = 0x19;
+
+/// The gamma that is assumed if it's not given.
+const DEFAULT_GAMMA: f64 = 2.2;
+
+/// An RGB space that is described at run-time, such as the space of a
+/// connected display.
+///
+/// The space is described by the chromaticity coordinates, `[x, y]`, of its
+/// primaries and white point, and a gamma. It can read these from the color
+/// characteristics in a display's EDID, which makes it possible to color
+/// manage against the display without a full ICC profile. The colors are
+/// exchanged as [`DynamicColor`]s, and chromatically adapted to and from
+/// their D65 white point.
+///
+/// ```
+/// use palette::dynamic::{DynamicColor, DynamicRgbSpace, Space};
+///
+/// // The color characteristics bytes of a wide gamut display.
+/// let characteristics = [0x0f, 0x91, 0xae, 0x52, 0x43, 0xb0, 0x26, 0x0f, 0x50, 0x54];
+/// let display = DynamicRgbSpace::from_edid(&characteristics).unwrap();
+///
+/// // Find the display values for an sRGB color, which is inside the wider
+/// // gamut of the display.
+/// let orange = DynamicColor::new(Space::Srgb, [1.0, 0.5, 0.0]);
+/// let [red, green, blue] = display.dynamic_to_rgb(orange);
+/// assert!(red < 1.0 && blue > 0.0);
+///
+/// let back = display.rgb_to_dynamic([red, green, blue]).convert(Space::Srgb);
+/// assert!((back.components[1] - 0.5).abs() < 1e-6);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct DynamicRgbSpace {
+    /// The chromaticity of the red primary.
+    pub red: [f64; 2],
+
+    /// The chromaticity of the green primary.
+    pub green: [f64; 2],
+
+    /// The chromaticity of the blue primary.
+    pub blue: [f64; 2],
+
+    /// The chromaticity of the white point.
+    pub white: [f64; 2],
+
+    /// The exponent of the transfer function, or `None` if it's unknown, in
+    /// which case `2.2` is used.
+    pub gamma: Option<f64>,
+}
+
+impl DynamicRgbSpace {
+    /// The Adobe RGB (1998) space, with the D65 white point and a gamma of
+    /// `563 / 256`.
+    pub const ADOBE_RGB: DynamicRgbSpace = DynamicRgbSpace {
+        red: [0.64, 0.33],
+        green: [0.21, 0.71],
+        blue: [0.15, 0.06],
+        white: [0.3127, 0.329],
+        gamma: Some(2.199_218_75),
+    };
+
+    /// Read the space from EDID data.
+    ///
+    /// The data can either be the 10 bytes of color characteristics, or a
+    /// whole EDID base block of 128 bytes or more. The gamma is only read
+    /// from a whole block, where the header and the checksum are checked
+    /// too.
+    pub fn from_edid(bytes: &[u8]) -> Result<Self, ParseEdidError> {
+        let (characteristics, gamma) = match bytes.len() {
+            10 => (bytes, None),
+            length if length >= 128 => {
+                let block = &bytes[..128];
+                if block[..8] != EDID_HEADER {
+                    return Err(ParseEdidError {
+                        kind: ParseEdidErrorKind::InvalidHeader,
+                    });
+                }
+
+                let checksum = block.iter().fold(0u8, |sum, &byte| sum.wrapping_add(byte));
+                if checksum != 0 {
+                    return Err(ParseEdidError {
+                        kind: ParseEdidErrorKind::InvalidChecksum,
+                    });
+                }
+
+                // 0xff means that the gamma is defined in an extension block.
+                let gamma = match block[GAMMA_OFFSET] {
+                    0xff => None,
+                    byte => Some((f64::from(byte) + 100.0) / 100.0),
+                };
+
+                (&block[CHROMATICITY_OFFSET..CHROMATICITY_OFFSET + 10], gamma)
+            }
+            _ => {
+                return Err(ParseEdidError {
+                    kind: ParseEdidErrorKind::InvalidLength,
+                })
+            }
+        };
+
+        // Each coordinate has 10 bits. The two low bits of all of them are
+        // packed into the first two bytes, followed by the eight high bits.
+        let coordinate = |index: usize| {
+            let low_byte = characteristics[index / 4];
+            let low = (low_byte >> (6 - (index % 4) * 2)) & 0b11;
+            let high = characteristics[2 + index];
+            f64::from(u16::from(high) << 2 | u16::from(low)) / 1024.0
+        };
+
+        let space = DynamicRgbSpace {
+            red: [coordinate(0), coordinate(1)],
+            green: [coordinate(2), coordinate(3)],
+            blue: [coordinate(4), coordinate(5)],
+            white: [coordinate(6), coordinate(7)],
+            gamma,
+        };
+
+        if space.is_valid() {
+            Ok(space)
+        } else {
+            Err(ParseEdidError {
+                kind: ParseEdidErrorKind::InvalidChromaticity,
+            })
+        }
+    }
+
+    /// Check if the chromaticities describe a usable space, where the white
+    /// point and the primaries are valid and the primaries span a triangle.
+    pub fn is_valid(&self) -> bool {
+        let valid = |[x, y]: [f64; 2]| x >= 0.0 && y > 0.0 && x + y <= 1.0;
+
+        valid(self.red)
+            && valid(self.green)
+            && valid(self.blue)
+            && valid(self.white)
+            && self
+                .rgb_to_xyz_matrix()
+                .iter()
+                .chain(&self.xyz_to_rgb_matrix())
+                .all(|value| value.is_finite())
+    }
+
+    /// The XYZ values of the white point.
+    pub fn white_point(&self) -> Xyz<Any, f64> {
+        let [x, y, z] = chromaticity_to_xyz(self.white);
+        Xyz::new(x, y, z)
+    }
+
+    /// The matrix that converts linear RGB values to XYZ, relative to the
+    /// white point of the space.
+    pub fn rgb_to_xyz_matrix(&self) -> Mat3<f64> {
+        let [rx, ry, rz] = chromaticity_to_xyz(self.red);
+        let [gx, gy, gz] = chromaticity_to_xyz(self.green);
+        let [bx, by, bz] = chromaticity_to_xyz(self.blue);
+        let primaries = [rx, gx, bx, ry, gy, by, rz, gz, bz];
+
+        // Scale the primaries so they add up to the white point.
+        let scale = multiply_xyz(&matrix_inverse(&primaries), &self.white_point());
+        let scale = [scale.x, 0.0, 0.0, 0.0, scale.y, 0.0, 0.0, 0.0, scale.z];
+
+        multiply_3x3(&primaries, &scale)
+    }
+
+    /// The matrix that converts XYZ values, relative to the white point of
+    /// the space, to linear RGB.
+    pub fn xyz_to_rgb_matrix(&self) -> Mat3<f64> {
+        matrix_inverse(&self.rgb_to_xyz_matrix())
+    }
+
+    /// Convert encoded RGB values in this space to a [`DynamicColor`] in
+    /// [`Space::Xyz`], adapted to D65.
+    pub fn rgb_to_dynamic(&self, rgb: [f64; 3]) -> DynamicColor {
+        let gamma = self.gamma.unwrap_or(DEFAULT_GAMMA);
+        let [red, green, blue] = rgb;
+        let linear = Xyz::new(
+            signed_powf(red, gamma),
+            signed_powf(green, gamma),
+            signed_powf(blue, gamma),
+        );
+
+        let xyz = multiply_xyz(&self.rgb_to_xyz_matrix(), &linear);
+        let adaptation =
+            Method::Bradford.generate_transform_matrix(self.white_point(), D65::get_xyz());
+        let xyz = multiply_xyz(&adaptation, &xyz);
+
+        DynamicColor::new(Space::Xyz, [xyz.x, xyz.y, xyz.z])
+    }
+
+    /// Convert `color` to encoded RGB values in this space, adapted from
+    /// D65, without clamping them.
+    pub fn dynamic_to_rgb(&self, color: DynamicColor) -> [f64; 3] {
+        let [x, y, z] = color.convert(Space::Xyz).components;
+
+        let adaptation =
+            Method::Bradford.generate_transform_matrix(D65::get_xyz(), self.white_point());
+        let xyz = multiply_xyz(&adaptation, &Xyz::new(x, y, z));
+        let linear = multiply_xyz(&self.xyz_to_rgb_matrix(), &xyz);
+
+        let inverse_gamma = 1.0 / self.gamma.unwrap_or(DEFAULT_GAMMA);
+        [
+            signed_powf(linear.x, inverse_gamma),
+            signed_powf(linear.y, inverse_gamma),
+            signed_powf(linear.z, inverse_gamma),
+        ]
+    }
+}
+
+fn chromaticity_to_xyz([x, y]: [f64; 2]) -> [f64; 3] {
+    [x / y, 1.0, (1.0 - x - y) / y]
+}
+
+/// Raise the magnitude of `x` to `exponent`, and keep the sign, so values
+/// outside the gamut can be converted back.
+fn signed_powf(x: f64, exponent: f64) -> f64 {
+    if x < 0.0 {
+        -float::powf(-x, exponent)
+    } else {
+        float::powf(x, exponent)
+    }
+}
+
+/// The reason why EDID data couldn't be read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParseEdidErrorKind {
+    /// The data is neither 10 bytes of color characteristics, nor a whole
+    /// base block.
+    InvalidLength,
+    /// The base block doesn't start with the EDID header.
+    InvalidHeader,
+    /// The bytes in the base block don't add up to a multiple of 256.
+    InvalidChecksum,
+    /// The chromaticities don't describe a usable RGB space.
+    InvalidChromaticity,
+}
+
+/// An error from reading EDID data with [`DynamicRgbSpace::from_edid`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParseEdidError {
+    kind: ParseEdidErrorKind,
+}
+
+impl ParseEdidError {
+    /// The reason for the error.
+    pub fn kind(&self) -> ParseEdidErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for ParseEdidError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self.kind {
+            ParseEdidErrorKind::InvalidLength => "invalid length of EDID data",
+            ParseEdidErrorKind::InvalidHeader => "invalid EDID header",
+            ParseEdidErrorKind::InvalidChecksum => "invalid EDID checksum",
+            ParseEdidErrorKind::InvalidChromaticity => "invalid chromaticity in EDID data",
+        };
+
+        f.write_str(description)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseEdidError {}
+
+#[cfg(test)]
+mod test {
+    use super::{DynamicRgbSpace, ParseEdidErrorKind};
+    use crate::dynamic::{DynamicColor, Space};
+    use crate::encoding::Srgb;
+    use crate::matrix::rgb_to_xyz_matrix;
+
+    /// The color characteristics of a display with the sRGB primaries and
+    /// D65, rounded to 10 bits.
+    const SRGB_CHARACTERISTICS: [u8; 10] =
+        [0xee, 0x91, 0xa3, 0x54, 0x4c, 0x99, 0x26, 0x0f, 0x50, 0x54];
+
+    fn base_block(gamma: u8) -> Vec<u8> {
+        let mut block = vec![0; 128];
+        block[..8].copy_from_slice(&super::EDID_HEADER);
+        block[super::GAMMA_OFFSET] = gamma;
+        block[super::CHROMATICITY_OFFSET..super::CHROMATICITY_OFFSET + 10]
+            .copy_from_slice(&SRGB_CHARACTERISTICS);
+
+        let sum = block.iter().fold(0u8, |sum, &byte| sum.wrapping_add(byte));
+        block[127] = 0u8.wrapping_sub(sum);
+        block
+    }
+
+    #[test]
+    fn characteristics() {
+        let space = DynamicRgbSpace::from_edid(&SRGB_CHARACTERISTICS).unwrap();
+
+        assert_relative_eq!(space.red[0], 0.6396, epsilon = 1e-4);
+        assert_relative_eq!(space.red[1], 0.3300, epsilon = 1e-3);
+        assert_relative_eq!(space.green[0], 0.2998, epsilon = 1e-3);
+        assert_relative_eq!(space.blue[1], 0.0595, epsilon = 1e-3);
+        assert_relative_eq!(space.white[0], 0.3125, epsilon = 1e-3);
+        assert_relative_eq!(space.white[1], 0.3291, epsilon = 1e-3);
+        assert_eq!(space.gamma, None);
+
+        let expected = rgb_to_xyz_matrix::<Srgb, f64>();
+        for (&value, &expected) in space.rgb_to_xyz_matrix().iter().zip(&expected) {
+            assert_relative_eq!(value, expected, epsilon = 2e-3);
+        }
+    }
+
+    #[test]
+    fn base_block_with_gamma() {
+        let space = DynamicRgbSpace::from_edid(&base_block(120)).unwrap();
+        assert_eq!(space.gamma, Some(2.2));
+
+        let space = DynamicRgbSpace::from_edid(&base_block(0xff)).unwrap();
+        assert_eq!(space.gamma, None);
+
+        let mut block = base_block(120);
+        block[127] = block[127].wrapping_add(1);
+        let error = DynamicRgbSpace::from_edid(&block).unwrap_err();
+        assert_eq!(error.kind(), ParseEdidErrorKind::InvalidChecksum);
+
+        block[0] = 1;
+        let error = DynamicRgbSpace::from_edid(&block).unwrap_err();
+        assert_eq!(error.kind(), ParseEdidErrorKind::InvalidHeader);
+
+        let error = DynamicRgbSpace::from_edid(&block[..20]).unwrap_err();
+        assert_eq!(error.kind(), ParseEdidErrorKind::InvalidLength);
+
+        let error = DynamicRgbSpace::from_edid(&[0; 10]).unwrap_err();
+        assert_eq!(error.kind(), ParseEdidErrorKind::InvalidChromaticity);
+    }
+
+    #[test]
+    fn dynamic_colors() {
+        let space = DynamicRgbSpace::from_edid(&SRGB_CHARACTERISTICS).unwrap();
+
+        // White is white, regardless of the white point.
+        let white = space.rgb_to_dynamic([1.0, 1.0, 1.0]).convert(Space::Srgb);
+        for &component in &white.components {
+            assert_relative_eq!(component, 1.0, epsilon = 1e-6);
+        }
+
+        let color = DynamicColor::new(Space::Oklab, [0.6, -0.09, -0.03]);
+        let rgb = space.dynamic_to_rgb(color);
+        let back = space.rgb_to_dynamic(rgb).convert(Space::Oklab);
+        for (&component, &expected) in back.components.iter().zip(&color.components) {
+            assert_relative_eq!(component, expected, epsilon = 1e-5);
+        }
+    }
+}
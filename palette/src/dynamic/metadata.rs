@@ -0,0 +1,344 @@
+use super::{DynamicColor, DynamicRgbSpace, Space};
+
+/// The EXIF `ColorSpace` value for sRGB.
+const EXIF_SRGB: u16 = 1;
+
+/// The EXIF `ColorSpace` value for Adobe RGB. It's not in the standard, but
+/// some cameras write it.
+const EXIF_ADOBE_RGB: u16 = 2;
+
+/// The EXIF `ColorSpace` value for an uncalibrated space.
+const EXIF_UNCALIBRATED: u16 = 0xffff;
+
+/// The primaries and white point of sRGB, as `[white, red, green, blue]`.
+const SRGB_CHROMATICITIES: [[f64; 2]; 4] =
+    [[0.3127, 0.329], [0.64, 0.33], [0.3, 0.6], [0.15, 0.06]];
+
+/// How far the PNG chromaticities can be from the sRGB chromaticities, and
+/// still be treated as sRGB.
+const CHROMATICITY_TOLERANCE: f64 = 0.001;
+
+/// How far the PNG gamma can be from the expected gamma, and still be treated
+/// as the same.
+const GAMMA_TOLERANCE: f64 = 0.01;
+
+/// The color space of an image, as described by its metadata.
+///
+/// Image formats describe the color space of the pixels in different ways.
+/// [`from_png`](ImageColorSpace::from_png) and
+/// [`from_exif`](ImageColorSpace::from_exif) interpret the common ones, and
+/// follow the precedence rules of the formats, so a decoder can pick the
+/// matching type without hard coding the rules:
+///
+/// ```
+/// use palette::dynamic::{ImageColorSpace, PngColorChunks, Space};
+///
+/// // A PNG with only a gAMA chunk of 1.0.
+/// let gama = 100_000u32.to_be_bytes();
+/// let chunks = PngColorChunks {
+///     gama: Some(&gama),
+///     ..PngColorChunks::default()
+/// };
+///
+/// let space = ImageColorSpace::from_png(&chunks);
+/// assert_eq!(space, ImageColorSpace::LinearSrgb);
+///
+/// let color = space.rgb_to_dynamic([0.5, 0.5, 0.5]).unwrap();
+/// assert_eq!(color.space, Space::LinSrgb);
+///
+/// // A JPEG from a camera in Adobe RGB mode.
+/// let space = ImageColorSpace::from_exif(0xffff, Some("R03"));
+/// assert!(matches!(space, ImageColorSpace::Rgb(_)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub enum ImageColorSpace {
+    /// sRGB, for [`Srgb`](crate::Srgb) and [`Space::Srgb`].
+    Srgb,
+
+    /// Linear sRGB, for [`LinSrgb`](crate::LinSrgb) and [`Space::LinSrgb`].
+    LinearSrgb,
+
+    /// An RGB space that is described by its chromaticities and gamma, such
+    /// as Adobe RGB.
+    Rgb(DynamicRgbSpace),
+
+    /// The image has an embedded ICC profile, which needs a color management
+    /// system to interpret.
+    IccProfile,
+
+    /// The metadata doesn't describe the color space. Such images are usually
+    /// treated as sRGB.
+    Unknown,
+}
+
+impl ImageColorSpace {
+    /// Interpret the color space chunks of a PNG image.
+    ///
+    /// As the PNG specification says, an `sRGB` or `iCCP` chunk overrides the
+    /// `gAMA` and `cHRM` chunks. If there's only a `gAMA` chunk, the sRGB
+    /// primaries are assumed, and if there's only a `cHRM` chunk, the gamma
+    /// is unknown and [`DynamicRgbSpace`] assumes `2.2`. The gamma and
+    /// chromaticities that encoders write for sRGB, for decoders that don't
+    /// read the `sRGB` chunk, are recognized as sRGB. Chunks with invalid data
+    /// are ignored.
+    pub fn from_png(chunks: &PngColorChunks) -> Self {
+        if matches!(chunks.srgb, Some(data) if data.len() == 1) {
+            return ImageColorSpace::Srgb;
+        }
+
+        if chunks.iccp.is_some() {
+            return ImageColorSpace::IccProfile;
+        }
+
+        // The gAMA chunk has the exponent for encoding, times 100000.
+        let gamma = chunks
+            .gama
+            .and_then(read_png_values::<1>)
+            .filter(|&[value]| value > 0.0)
+            .map(|[value]| 1.0 / value);
+
+        let chromaticities = chunks.chrm.and_then(read_png_values::<8>).map(|values| {
+            [
+                [values[0], values[1]],
+                [values[2], values[3]],
+                [values[4], values[5]],
+                [values[6], values[7]],
+            ]
+        });
+
+        if gamma.is_none() && chromaticities.is_none() {
+            return ImageColorSpace::Unknown;
+        }
+
+        let [white, red, green, blue] = chromaticities.unwrap_or(SRGB_CHROMATICITIES);
+        let has_srgb_primaries = [white, red, green, blue]
+            .iter()
+            .zip(&SRGB_CHROMATICITIES)
+            .all(|(&[x, y], &[srgb_x, srgb_y])| {
+                (x - srgb_x).abs() <= CHROMATICITY_TOLERANCE
+                    && (y - srgb_y).abs() <= CHROMATICITY_TOLERANCE
+            });
+
+        if has_srgb_primaries {
+            match gamma {
+                Some(gamma) if (gamma - 1.0).abs() <= GAMMA_TOLERANCE => {
+                    return ImageColorSpace::LinearSrgb
+                }
+                Some(gamma) if (gamma - 2.2).abs() <= GAMMA_TOLERANCE => {
+                    return ImageColorSpace::Srgb
+                }
+                _ => {}
+            }
+        }
+
+        let space = DynamicRgbSpace {
+            red,
+            green,
+            blue,
+            white,
+            gamma,
+        };
+
+        if space.is_valid() {
+            ImageColorSpace::Rgb(space)
+        } else {
+            ImageColorSpace::Unknown
+        }
+    }
+
+    /// Interpret the EXIF `ColorSpace` tag value, and the interoperability
+    /// index from the interoperability IFD, if there is one.
+    ///
+    /// The value `1` is sRGB. The Design rule for Camera File system (DCF)
+    /// marks Adobe RGB images with the value `0xffff`, for uncalibrated,
+    /// and the interoperability index `"R03"`. Some cameras write the value
+    /// `2` for Adobe RGB instead, which is also recognized. Any other
+    /// uncalibrated space is unknown.
+    pub fn from_exif(color_space: u16, interoperability_index: Option<&str>) -> Self {
+        match (color_space, interoperability_index) {
+            (EXIF_SRGB, _) | (_, Some("R98")) => ImageColorSpace::Srgb,
+            (EXIF_ADOBE_RGB, _) | (EXIF_UNCALIBRATED, Some("R03")) => {
+                ImageColorSpace::Rgb(DynamicRgbSpace::ADOBE_RGB)
+            }
+            _ => ImageColorSpace::Unknown,
+        }
+    }
+
+    /// Convert encoded RGB values in this space to a [`DynamicColor`].
+    ///
+    /// sRGB and linear sRGB values become colors in [`Space::Srgb`] and
+    /// [`Space::LinSrgb`], and values in other RGB spaces become colors in
+    /// [`Space::Xyz`], as with [`DynamicRgbSpace::rgb_to_dynamic`]. Values in
+    /// an unknown space are treated as sRGB. Returns `None` for an ICC
+    /// profile.
+    pub fn rgb_to_dynamic(&self, rgb: [f64; 3]) -> Option<DynamicColor> {
+        match self {
+            ImageColorSpace::Srgb | ImageColorSpace::Unknown => {
+                Some(DynamicColor::new(Space::Srgb, rgb))
+            }
+            ImageColorSpace::LinearSrgb => Some(DynamicColor::new(Space::LinSrgb, rgb)),
+            ImageColorSpace::Rgb(space) => Some(space.rgb_to_dynamic(rgb)),
+            ImageColorSpace::IccProfile => None,
+        }
+    }
+}
+
+/// The data of the color space chunks of a PNG image, for
+/// [`ImageColorSpace::from_png`].
+///
+/// Each field is the data of the chunk, without the length, type and CRC, or
+/// `None` if the image doesn't have the chunk.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PngColorChunks<'a> {
+    /// The `sRGB` chunk, with the rendering intent.
+    pub srgb: Option<&'a [u8]>,
+
+    /// The `iCCP` chunk, with the embedded ICC profile.
+    pub iccp: Option<&'a [u8]>,
+
+    /// The `gAMA` chunk, with the image gamma.
+    pub gama: Option<&'a [u8]>,
+
+    /// The `cHRM` chunk, with the primary chromaticities and the white point.
+    pub chrm: Option<&'a [u8]>,
+}
+
+/// Read `N` big endian values, that are stored as `u32` times 100000, from
+/// the data of a PNG chunk.
+fn read_png_values<const N: usize>(data: &[u8]) -> Option<[f64; N]> {
+    if data.len() != N * 4 {
+        return None;
+    }
+
+    let mut values = [0.0; N];
+    for (value, bytes) in values.iter_mut().zip(data.chunks(4)) {
+        let integer = u32::from(bytes[0]) << 24
+            | u32::from(bytes[1]) << 16
+            | u32::from(bytes[2]) << 8
+            | u32::from(bytes[3]);
+        *value = f64::from(integer) / 100_000.0;
+    }
+
+    Some(values)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ImageColorSpace, PngColorChunks};
+    use crate::dynamic::{DynamicRgbSpace, Space};
+
+    fn chrm(values: [u32; 8]) -> Vec<u8> {
+        values
+            .iter()
+            .flat_map(|value| value.to_be_bytes().to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn png_precedence() {
+        let gama = 100_000u32.to_be_bytes();
+        let mut chunks = PngColorChunks {
+            srgb: Some(&[0]),
+            iccp: Some(&[1, 2, 3]),
+            gama: Some(&gama),
+            chrm: None,
+        };
+        assert_eq!(ImageColorSpace::from_png(&chunks), ImageColorSpace::Srgb);
+
+        chunks.srgb = None;
+        assert_eq!(
+            ImageColorSpace::from_png(&chunks),
+            ImageColorSpace::IccProfile
+        );
+
+        chunks.iccp = None;
+        assert_eq!(
+            ImageColorSpace::from_png(&chunks),
+            ImageColorSpace::LinearSrgb
+        );
+
+        chunks.gama = None;
+        assert_eq!(ImageColorSpace::from_png(&chunks), ImageColorSpace::Unknown);
+    }
+
+    #[test]
+    fn png_srgb_fallback() {
+        // The chunks that sRGB encoders write for older decoders.
+        let gama = 45_455u32.to_be_bytes();
+        let chrm = chrm([31270, 32900, 64000, 33000, 30000, 60000, 15000, 6000]);
+        let chunks = PngColorChunks {
+            gama: Some(&gama),
+            chrm: Some(&chrm),
+            ..PngColorChunks::default()
+        };
+        assert_eq!(ImageColorSpace::from_png(&chunks), ImageColorSpace::Srgb);
+    }
+
+    #[test]
+    fn png_custom_space() {
+        let gama = 55_555u32.to_be_bytes();
+        let chrm = chrm([31270, 32900, 64000, 33000, 21000, 71000, 15000, 6000]);
+        let chunks = PngColorChunks {
+            gama: Some(&gama),
+            chrm: Some(&chrm),
+            ..PngColorChunks::default()
+        };
+
+        match ImageColorSpace::from_png(&chunks) {
+            ImageColorSpace::Rgb(space) => {
+                assert_eq!(space.green, [0.21, 0.71]);
+                assert_relative_eq!(space.gamma.unwrap(), 1.8, epsilon = 1e-4);
+            }
+            other => panic!("unexpected space {:?}", other),
+        }
+
+        // Invalid chunks are ignored.
+        let chunks = PngColorChunks {
+            gama: Some(&[0, 0, 0, 0]),
+            chrm: Some(&[1, 2, 3]),
+            ..PngColorChunks::default()
+        };
+        assert_eq!(ImageColorSpace::from_png(&chunks), ImageColorSpace::Unknown);
+    }
+
+    #[test]
+    fn exif() {
+        assert_eq!(ImageColorSpace::from_exif(1, None), ImageColorSpace::Srgb);
+        assert_eq!(
+            ImageColorSpace::from_exif(0xffff, Some("R98")),
+            ImageColorSpace::Srgb
+        );
+        assert_eq!(
+            ImageColorSpace::from_exif(0xffff, Some("R03")),
+            ImageColorSpace::Rgb(DynamicRgbSpace::ADOBE_RGB)
+        );
+        assert_eq!(
+            ImageColorSpace::from_exif(2, None),
+            ImageColorSpace::Rgb(DynamicRgbSpace::ADOBE_RGB)
+        );
+        assert_eq!(
+            ImageColorSpace::from_exif(0xffff, None),
+            ImageColorSpace::Unknown
+        );
+    }
+
+    #[test]
+    fn to_dynamic() {
+        let white = [1.0, 1.0, 1.0];
+        assert_eq!(
+            ImageColorSpace::Unknown
+                .rgb_to_dynamic(white)
+                .unwrap()
+                .space,
+            Space::Srgb
+        );
+        assert_eq!(ImageColorSpace::IccProfile.rgb_to_dynamic(white), None);
+
+        let adobe = ImageColorSpace::Rgb(DynamicRgbSpace::ADOBE_RGB);
+        let color = adobe.rgb_to_dynamic(white).unwrap().convert(Space::Srgb);
+        for &component in &color.components {
+            assert_relative_eq!(component, 1.0, epsilon = 1e-3);
+        }
+    }
+}
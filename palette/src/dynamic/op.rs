@@ -0,0 +1,282 @@
+use crate::cast;
+use crate::theme::fit_chroma;
+use crate::{
+    Clamp, Darken, Desaturate, IsWithinBounds, Lighten, LinSrgb, Mix, Oklab, Oklch, Saturate,
+    ShiftHue,
+};
+
+use super::{DynamicColor, Space};
+
+/// A color operation that can be described as data.
+///
+/// Each operation takes a [`DynamicColor`] and returns a new one, in the same
+/// space as the input, except for [`ConvertTo`](ColorOp::ConvertTo). The
+/// operations that change lightness, chroma or hue do it in [`Oklch`], where
+/// the changes are close to perceptually uniform, and the color is converted
+/// back afterwards. With the `serializing` feature, the operations can be
+/// stored and loaded, for example as the nodes of a node graph.
+///
+/// ```
+/// use palette::dynamic::{self, ColorOp, DynamicColor, Space};
+///
+/// let ops = [
+///     ColorOp::Lighten { factor: 0.2 },
+///     ColorOp::ShiftHue { degrees: 30.0 },
+///     ColorOp::GamutMap,
+///     ColorOp::ConvertTo { space: Space::Hsl },
+/// ];
+///
+/// let mut colors = [
+///     DynamicColor::new(Space::Srgb, [0.8, 0.2, 0.1]),
+///     DynamicColor::new(Space::Srgb, [0.1, 0.3, 0.7]),
+/// ];
+/// dynamic::apply_all(&ops, &mut colors);
+///
+/// assert!(colors.iter().all(|color| color.space == Space::Hsl));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub enum ColorOp {
+    /// Mix with `color` in Oklab. A `factor` of `0.0` keeps the input, and
+    /// `1.0` gives `color`.
+    Mix {
+        /// The color to mix with.
+        color: DynamicColor,
+        /// How much of `color` to mix in.
+        factor: f64,
+    },
+
+    /// Make the color lighter, as with [`Lighten::lighten`].
+    Lighten {
+        /// The relative amount to lighten by, from `0.0` to `1.0`.
+        factor: f64,
+    },
+
+    /// Make the color darker, as with [`Darken::darken`].
+    Darken {
+        /// The relative amount to darken by, from `0.0` to `1.0`.
+        factor: f64,
+    },
+
+    /// Make the color more colorful, as with [`Saturate::saturate`].
+    Saturate {
+        /// The relative amount to saturate by, from `0.0` to `1.0`.
+        factor: f64,
+    },
+
+    /// Make the color less colorful, as with [`Desaturate::desaturate`].
+    Desaturate {
+        /// The relative amount to desaturate by, from `0.0` to `1.0`.
+        factor: f64,
+    },
+
+    /// Rotate the hue.
+    ShiftHue {
+        /// The rotation, in degrees.
+        degrees: f64,
+    },
+
+    /// Convert the color to another space.
+    ConvertTo {
+        /// The space to convert to.
+        space: Space,
+    },
+
+    /// Change out-of-bounds components to the nearest valid values, as
+    /// with [`DynamicColor::clamp`].
+    Clamp,
+
+    /// Bring the color into the sRGB gamut by reducing its chroma in Oklch,
+    /// while keeping its lightness and hue.
+    GamutMap,
+
+    /// Multiply the color with a 3 by 3 matrix, in row major order, in
+    /// linear sRGB.
+    Matrix {
+        /// The matrix elements.
+        matrix: [f64; 9],
+    },
+}
+
+impl ColorOp {
+    /// Apply the operation to `color`.
+    pub fn apply(&self, color: DynamicColor) -> DynamicColor {
+        match *self {
+            ColorOp::Mix {
+                color: other,
+                factor,
+            } => {
+                let mixed = to_oklab(color).mix(to_oklab(other), factor);
+                from_array(Space::Oklab, cast::into_array(mixed), color.space)
+            }
+            ColorOp::Lighten { factor } => map_oklch(color, |oklch| oklch.lighten(factor)),
+            ColorOp::Darken { factor } => map_oklch(color, |oklch| oklch.darken(factor)),
+            ColorOp::Saturate { factor } => map_oklch(color, |oklch| oklch.saturate(factor)),
+            ColorOp::Desaturate { factor } => map_oklch(color, |oklch| oklch.desaturate(factor)),
+            ColorOp::ShiftHue { degrees } => map_oklch(color, |oklch| oklch.shift_hue(degrees)),
+            ColorOp::ConvertTo { space } => color.convert(space),
+            ColorOp::Clamp => color.clamp(),
+            ColorOp::GamutMap => {
+                let linear: LinSrgb<f64> =
+                    cast::from_array(color.convert(Space::LinSrgb).components);
+                if linear.is_within_bounds() {
+                    return color;
+                }
+
+                // Clamp what is left after the chroma reduction, which is
+                // only rounding errors.
+                let mapped = map_oklch(color, fit_chroma).convert(Space::LinSrgb);
+                let clamped = cast::from_array::<LinSrgb<f64>>(mapped.components).clamp();
+                from_array(Space::LinSrgb, cast::into_array(clamped), color.space)
+            }
+            ColorOp::Matrix { matrix: m } => {
+                let [r, g, b] = color.convert(Space::LinSrgb).components;
+                let transformed = [
+                    m[0] * r + m[1] * g + m[2] * b,
+                    m[3] * r + m[4] * g + m[5] * b,
+                    m[6] * r + m[7] * g + m[8] * b,
+                ];
+                from_array(Space::LinSrgb, transformed, color.space)
+            }
+        }
+    }
+
+    /// Apply the operation to each color in `colors`.
+    pub fn apply_slice(&self, colors: &mut [DynamicColor]) {
+        for color in colors {
+            *color = self.apply(*color);
+        }
+    }
+}
+
+/// Apply each operation in `ops`, in order, to each color in `colors`.
+pub fn apply_all(ops: &[ColorOp], colors: &mut [DynamicColor]) {
+    for color in colors {
+        *color = ops.iter().fold(*color, |color, op| op.apply(color));
+    }
+}
+
+fn to_oklab(color: DynamicColor) -> Oklab<f64> {
+    cast::from_array(color.convert(Space::Oklab).components)
+}
+
+fn map_oklch<F>(color: DynamicColor, f: F) -> DynamicColor
+where
+    F: FnOnce(Oklch<f64>) -> Oklch<f64>,
+{
+    let oklch: Oklch<f64> = cast::from_array(color.convert(Space::Oklch).components);
+    from_array(Space::Oklch, cast::into_array(f(oklch)), color.space)
+}
+
+fn from_array(space: Space, components: [f64; 3], target: Space) -> DynamicColor {
+    DynamicColor::new(space, components).convert(target)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{apply_all, ColorOp};
+    use crate::dynamic::{DynamicColor, Space};
+
+    #[test]
+    fn keeps_space() {
+        let color = DynamicColor::new(Space::Hsv, [20.0, 0.7, 0.8]);
+
+        for op in &[
+            ColorOp::Lighten { factor: 0.3 },
+            ColorOp::Darken { factor: 0.3 },
+            ColorOp::Saturate { factor: 0.3 },
+            ColorOp::Desaturate { factor: 0.3 },
+            ColorOp::ShiftHue { degrees: 40.0 },
+            ColorOp::Clamp,
+            ColorOp::GamutMap,
+        ] {
+            assert_eq!(op.apply(color).space, Space::Hsv);
+        }
+    }
+
+    #[test]
+    fn lighten_and_darken() {
+        let color = DynamicColor::new(Space::Oklch, [0.5, 0.1, 30.0]);
+
+        let lighter = ColorOp::Lighten { factor: 0.5 }.apply(color);
+        assert_relative_eq!(lighter.components[0], 0.75, epsilon = 1e-12);
+
+        let darker = ColorOp::Darken { factor: 0.5 }.apply(color);
+        assert_relative_eq!(darker.components[0], 0.25, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn mix() {
+        let black = DynamicColor::new(Space::Oklab, [0.0, 0.0, 0.0]);
+        let white = DynamicColor::new(Space::Srgb, [1.0, 1.0, 1.0]);
+
+        let mixed = ColorOp::Mix {
+            color: white,
+            factor: 0.25,
+        }
+        .apply(black);
+        assert_eq!(mixed.space, Space::Oklab);
+        assert_relative_eq!(mixed.components[0], 0.25, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn gamut_map() {
+        let color = DynamicColor::new(Space::Oklch, [0.7, 0.4, 150.0]);
+        let mapped = ColorOp::GamutMap
+            .apply(color)
+            .convert(Space::LinSrgb)
+            .components;
+        assert!(mapped.iter().all(|value| (0.0..=1.0).contains(value)));
+
+        let inside = DynamicColor::new(Space::Srgb, [0.3, 0.6, 0.2]);
+        assert_eq!(ColorOp::GamutMap.apply(inside), inside);
+    }
+
+    #[test]
+    fn matrix() {
+        let swap = ColorOp::Matrix {
+            matrix: [0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0],
+        };
+        let color = DynamicColor::new(Space::LinSrgb, [0.2, 0.4, 0.6]);
+        assert_eq!(swap.apply(color).components, [0.4, 0.2, 0.6]);
+    }
+
+    #[test]
+    fn sequence() {
+        let ops = [
+            ColorOp::ConvertTo { space: Space::Lab },
+            ColorOp::ShiftHue { degrees: 180.0 },
+            ColorOp::ShiftHue { degrees: 180.0 },
+            ColorOp::ConvertTo { space: Space::Srgb },
+        ];
+
+        let mut colors = [DynamicColor::new(Space::Srgb, [0.2, 0.4, 0.6])];
+        apply_all(&ops, &mut colors);
+
+        assert_eq!(colors[0].space, Space::Srgb);
+        for (&value, &expected) in colors[0].components.iter().zip(&[0.2, 0.4, 0.6]) {
+            assert_relative_eq!(value, expected, epsilon = 1e-6);
+        }
+    }
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn serialize() {
+        let ops = [
+            ColorOp::Lighten { factor: 0.5 },
+            ColorOp::ConvertTo {
+                space: Space::Oklch,
+            },
+            ColorOp::GamutMap,
+        ];
+
+        let serialized = ::serde_json::to_string(&ops).unwrap();
+        assert_eq!(
+            serialized,
+            r#"[{"Lighten":{"factor":0.5}},{"ConvertTo":{"space":"Oklch"}},"GamutMap"]"#
+        );
+
+        let deserialized: Vec<ColorOp> = ::serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, ops);
+    }
+}
@@ -0,0 +1,271 @@
+//! Easing functions for non-linear interpolation.
+//!
+//! An easing function reshapes the interpolation factor, so a transition can
+//! start slowly and speed up, for example. They are used by passing the
+//! factor through [`Easing::ease`] before mixing, or by using
+//! [`Easing::mix`] and [`Gradient::get_eased`](crate::Gradient::get_eased).
+//!
+//! ```
+//! use palette::easing::{CubicBezier, Easing, Smoothstep};
+//! use palette::LinSrgb;
+//!
+//! let a = LinSrgb::new(0.0f32, 0.0, 0.0);
+//! let b = LinSrgb::new(1.0, 1.0, 1.0);
+//!
+//! let smooth = Smoothstep.mix(a, b, 0.25);
+//! let ease_in_out = CubicBezier::ease_in_out().mix(a, b, 0.25);
+//!
+//! assert!(smooth.red < 0.25);
+//! assert!(ease_in_out.red < 0.25);
+//! ```
+//!
+//! Closures of the type `Fn(T) -> T` are easing functions too.
+
+use crate::float::Float;
+use crate::{clamp, from_f64, FromF64, Mix};
+
+/// A function that maps an interpolation factor in `[0.0, 1.0]` to another
+/// factor, where `0.0` maps to `0.0` and `1.0` maps to `1.0`.
+pub trait Easing<T> {
+    /// Reshape the factor `t`.
+    #[must_use]
+    fn ease(&self, t: T) -> T;
+
+    /// Mix `a` and `b`, with the factor reshaped by this function.
+    #[must_use]
+    #[inline]
+    fn mix<C: Mix<Scalar = T>>(&self, a: C, b: C, factor: T) -> C {
+        a.mix(b, self.ease(factor))
+    }
+}
+
+impl<T, F> Easing<T> for F
+where
+    F: Fn(T) -> T,
+{
+    #[inline]
+    fn ease(&self, t: T) -> T {
+        self(t)
+    }
+}
+
+/// Linear easing, which keeps the factor as it is.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct Linear;
+
+impl<T> Easing<T> for Linear {
+    #[inline]
+    fn ease(&self, t: T) -> T {
+        t
+    }
+}
+
+/// The smoothstep function, `3t² - 2t³`, which starts and ends with zero
+/// slope.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct Smoothstep;
+
+impl<T: Float + FromF64> Easing<T> for Smoothstep {
+    #[inline]
+    fn ease(&self, t: T) -> T {
+        let t = clamp(t, T::zero(), T::one());
+        t * t * (from_f64::<T>(3.0) - from_f64::<T>(2.0) * t)
+    }
+}
+
+/// A cubic Bézier curve from `(0, 0)` to `(1, 1)`, with the control points
+/// `(x1, y1)` and `(x2, y2)`, like the CSS `cubic-bezier()` timing function.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct CubicBezier<T> {
+    /// The x coordinate of the first control point.
+    pub x1: T,
+    /// The y coordinate of the first control point.
+    pub y1: T,
+    /// The x coordinate of the second control point.
+    pub x2: T,
+    /// The y coordinate of the second control point.
+    pub y2: T,
+}
+
+impl<T: Float + FromF64> CubicBezier<T> {
+    /// Create a curve with the control points `(x1, y1)` and `(x2, y2)`. The
+    /// x coordinates are clamped to `[0.0, 1.0]`, to keep the curve a
+    /// function of x.
+    pub fn new(x1: T, y1: T, x2: T, y2: T) -> Self {
+        CubicBezier {
+            x1: clamp(x1, T::zero(), T::one()),
+            y1,
+            x2: clamp(x2, T::zero(), T::one()),
+            y2,
+        }
+    }
+
+    /// The CSS `ease` curve, `cubic-bezier(0.25, 0.1, 0.25, 1.0)`.
+    pub fn ease() -> Self {
+        Self::from_f64(0.25, 0.1, 0.25, 1.0)
+    }
+
+    /// The CSS `ease-in` curve, `cubic-bezier(0.42, 0.0, 1.0, 1.0)`.
+    pub fn ease_in() -> Self {
+        Self::from_f64(0.42, 0.0, 1.0, 1.0)
+    }
+
+    /// The CSS `ease-out` curve, `cubic-bezier(0.0, 0.0, 0.58, 1.0)`.
+    pub fn ease_out() -> Self {
+        Self::from_f64(0.0, 0.0, 0.58, 1.0)
+    }
+
+    /// The CSS `ease-in-out` curve, `cubic-bezier(0.42, 0.0, 0.58, 1.0)`.
+    pub fn ease_in_out() -> Self {
+        Self::from_f64(0.42, 0.0, 0.58, 1.0)
+    }
+
+    fn from_f64(x1: f64, y1: f64, x2: f64, y2: f64) -> Self {
+        CubicBezier::new(from_f64(x1), from_f64(y1), from_f64(x2), from_f64(y2))
+    }
+
+    /// Find the curve parameter where the x coordinate is `x`.
+    fn solve_x(&self, x: T) -> T {
+        let epsilon = from_f64::<T>(1e-7);
+
+        // Newton's method converges quickly for most curves...
+        let mut s = x;
+        for _ in 0..8 {
+            let error = bezier(self.x1, self.x2, s) - x;
+            if error.abs() < epsilon {
+                return s;
+            }
+
+            let slope = bezier_slope(self.x1, self.x2, s);
+            if slope.abs() < epsilon {
+                break;
+            }
+
+            s = s - error / slope;
+        }
+
+        // ...but falls back to bisection when the slope is too flat.
+        let mut low = T::zero();
+        let mut high = T::one();
+        s = x;
+        for _ in 0..64 {
+            let error = bezier(self.x1, self.x2, s) - x;
+            if error.abs() < epsilon {
+                break;
+            }
+
+            if error > T::zero() {
+                high = s;
+            } else {
+                low = s;
+            }
+            s = (low + high) / from_f64(2.0);
+        }
+
+        s
+    }
+}
+
+impl<T: Float + FromF64> Easing<T> for CubicBezier<T> {
+    fn ease(&self, t: T) -> T {
+        if t <= T::zero() {
+            return T::zero();
+        }
+        if t >= T::one() {
+            return T::one();
+        }
+
+        bezier(self.y1, self.y2, self.solve_x(t))
+    }
+}
+
+/// One coordinate of a cubic Bézier curve from 0 to 1, with the control
+/// points `p1` and `p2`.
+fn bezier<T: Float + FromF64>(p1: T, p2: T, s: T) -> T {
+    let three = from_f64::<T>(3.0);
+    let inverse = T::one() - s;
+
+    three * inverse * inverse * s * p1 + three * inverse * s * s * p2 + s * s * s
+}
+
+/// The derivative of [`bezier`] with respect to `s`.
+fn bezier_slope<T: Float + FromF64>(p1: T, p2: T, s: T) -> T {
+    let three = from_f64::<T>(3.0);
+    let six = from_f64::<T>(6.0);
+    let inverse = T::one() - s;
+
+    three * inverse * inverse * p1 + six * inverse * s * (p2 - p1) + three * s * s * (T::one() - p2)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CubicBezier, Easing, Linear, Smoothstep};
+    use crate::LinSrgb;
+
+    #[test]
+    fn end_points() {
+        let curves = [
+            CubicBezier::ease(),
+            CubicBezier::ease_in(),
+            CubicBezier::ease_out(),
+            CubicBezier::ease_in_out(),
+        ];
+
+        for curve in &curves {
+            assert_eq!(curve.ease(0.0f64), 0.0);
+            assert_eq!(curve.ease(1.0f64), 1.0);
+        }
+
+        assert_eq!(Smoothstep.ease(0.0f64), 0.0);
+        assert_eq!(Smoothstep.ease(1.0f64), 1.0);
+        assert_eq!(Linear.ease(0.3f64), 0.3);
+    }
+
+    #[test]
+    fn smoothstep() {
+        assert_relative_eq!(Smoothstep.ease(0.5f64), 0.5);
+        assert_relative_eq!(Smoothstep.ease(0.25f64), 0.15625);
+        assert_relative_eq!(Smoothstep.ease(2.0f64), 1.0);
+    }
+
+    #[test]
+    fn cubic_bezier() {
+        // A curve with control points on the diagonal is linear.
+        let linear = CubicBezier::new(0.25f64, 0.25, 0.75, 0.75);
+        for &t in &[0.1, 0.3, 0.5, 0.9] {
+            assert_relative_eq!(linear.ease(t), t, epsilon = 1e-6);
+        }
+
+        // The symmetric curve passes through the middle.
+        assert_relative_eq!(CubicBezier::ease_in_out().ease(0.5f64), 0.5, epsilon = 1e-6);
+
+        // Reference values from the CSS `ease` curve.
+        let ease = CubicBezier::<f64>::ease();
+        assert_relative_eq!(ease.ease(0.25), 0.40851, epsilon = 1e-4);
+        assert_relative_eq!(ease.ease(0.5), 0.80240, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn steep_curve() {
+        let curve = CubicBezier::new(1.0f64, 0.0, 1.0, 0.0);
+        let mut previous = 0.0;
+
+        for i in 1..=10 {
+            let value = curve.ease(i as f64 / 10.0);
+            assert!(value >= previous);
+            previous = value;
+        }
+    }
+
+    #[test]
+    fn closures_and_mix() {
+        let square = |t: f64| t * t;
+        let a = LinSrgb::new(0.0, 0.0, 0.0);
+        let b = LinSrgb::new(1.0, 1.0, 1.0);
+
+        assert_relative_eq!(square.mix(a, b, 0.5), LinSrgb::new(0.25, 0.25, 0.25));
+    }
+}
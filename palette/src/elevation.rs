@@ -0,0 +1,157 @@
+//! Elevation overlays for dark themes.
+//!
+//! Dark themes can't use shadows to show that a surface is raised above
+//! another, so they make raised surfaces lighter instead, by covering them
+//! with a translucent white or primary color overlay. The higher the
+//! surface, the more opaque the overlay.
+//!
+//! Compositing the overlay with the encoded sRGB values, as is often done by
+//! hand, makes the steps uneven and too light. The functions in this module
+//! composite in linear RGB, like a translucent layer would be rendered with
+//! linear blending, and can also find the opacity that gives a specific
+//! target color:
+//!
+//! ```
+//! use approx::assert_relative_eq;
+//! use palette::elevation;
+//! use palette::Srgb;
+//!
+//! let surface = Srgb::new(0.07f32, 0.07, 0.07);
+//! let white = Srgb::new(1.0, 1.0, 1.0);
+//!
+//! // A dialog at 24dp gets a 16% overlay.
+//! let opacity = elevation::material_opacity(24.0);
+//! let dialog = elevation::overlay(surface, white, opacity);
+//! assert!(dialog.red > surface.red);
+//!
+//! // The opacity can be recovered from the result.
+//! let recovered = elevation::overlay_opacity(surface, white, dialog);
+//! assert_relative_eq!(recovered, opacity, epsilon = 1e-4);
+//! ```
+
+use crate::rgb::{Rgb, RgbStandard};
+use crate::{clamp, from_f64, FloatComponent};
+
+/// Composite `overlay` with `opacity` over `surface`, in linear RGB.
+///
+/// The opacity is clamped to the range from `0.0` to `1.0`.
+pub fn overlay<S, T>(surface: Rgb<S, T>, overlay: Rgb<S, T>, opacity: T) -> Rgb<S, T>
+where
+    S: RgbStandard<T>,
+    T: FloatComponent,
+{
+    let opacity = clamp(opacity, T::zero(), T::one());
+    let surface = surface.into_linear();
+    let overlay = overlay.into_linear();
+    let mix = |below: T, above: T| below + (above - below) * opacity;
+
+    Rgb::from_linear(Rgb::new(
+        mix(surface.red, overlay.red),
+        mix(surface.green, overlay.green),
+        mix(surface.blue, overlay.blue),
+    ))
+}
+
+/// Find the opacity of `overlay` that makes `surface` look like `target`,
+/// when composited with [`overlay`].
+///
+/// The target doesn't have to be an exact result of the overlay. The
+/// opacity is then the one that gives the closest color in linear RGB. It's
+/// clamped to the range from `0.0` to `1.0`, and is `0.0` if the overlay is
+/// the same color as the surface.
+pub fn overlay_opacity<S, T>(surface: Rgb<S, T>, overlay: Rgb<S, T>, target: Rgb<S, T>) -> T
+where
+    S: RgbStandard<T>,
+    T: FloatComponent,
+{
+    let surface = surface.into_linear();
+    let overlay = overlay.into_linear();
+    let target = target.into_linear();
+
+    let direction = [
+        overlay.red - surface.red,
+        overlay.green - surface.green,
+        overlay.blue - surface.blue,
+    ];
+    let offset = [
+        target.red - surface.red,
+        target.green - surface.green,
+        target.blue - surface.blue,
+    ];
+
+    let length_squared =
+        direction[0] * direction[0] + direction[1] * direction[1] + direction[2] * direction[2];
+    if length_squared <= T::zero() {
+        return T::zero();
+    }
+
+    let projection = direction[0] * offset[0] + direction[1] * offset[1] + direction[2] * offset[2];
+    clamp(projection / length_squared, T::zero(), T::one())
+}
+
+/// Get the overlay opacity for a surface at `elevation`, in density
+/// independent pixels (dp), as specified by the Material Design dark theme.
+///
+/// The opacity is `(4.5 * ln(elevation + 1) + 2) / 100`, which gives 5% at
+/// 1dp, 12% at 8dp and 16% at 24dp. It's `0.0` for surfaces without
+/// elevation.
+pub fn material_opacity<T>(elevation: T) -> T
+where
+    T: FloatComponent,
+{
+    if elevation <= T::zero() {
+        return T::zero();
+    }
+
+    let percent = from_f64::<T>(4.5) * (elevation + T::one()).ln() + from_f64(2.0);
+    clamp(percent / from_f64(100.0), T::zero(), T::one())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{material_opacity, overlay, overlay_opacity};
+    use crate::{LinSrgb, Srgb};
+
+    #[test]
+    fn linear_compositing() {
+        let surface = Srgb::new(0.0f64, 0.0, 0.0);
+        let white = Srgb::new(1.0, 1.0, 1.0);
+        let result = overlay(surface, white, 0.5);
+
+        // Half of the light, not half of the encoded value.
+        assert_relative_eq!(
+            result.into_linear(),
+            LinSrgb::new(0.5, 0.5, 0.5),
+            epsilon = 1e-6
+        );
+        assert!(result.red > 0.7);
+
+        assert_eq!(overlay(surface, white, 0.0), surface);
+        assert_relative_eq!(overlay(surface, white, 2.0), white, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn opacity_round_trip() {
+        let surface = Srgb::new(0.12f64, 0.1, 0.14);
+        let primary = Srgb::new(0.73, 0.53, 0.99);
+
+        for &opacity in &[0.0, 0.05, 0.11, 0.16, 0.5, 1.0] {
+            let result = overlay(surface, primary, opacity);
+            let recovered = overlay_opacity(surface, primary, result);
+            assert_relative_eq!(recovered, opacity, epsilon = 1e-5);
+        }
+
+        // Unreachable targets get the closest opacity.
+        let black = Srgb::new(0.0, 0.0, 0.0);
+        assert_eq!(overlay_opacity(surface, primary, black), 0.0);
+        assert_eq!(overlay_opacity(surface, surface, primary), 0.0);
+    }
+
+    #[test]
+    fn material() {
+        assert_eq!(material_opacity(0.0f64), 0.0);
+        assert_relative_eq!(material_opacity(1.0f64), 0.0512, epsilon = 1e-4);
+        assert_relative_eq!(material_opacity(8.0f64), 0.1189, epsilon = 1e-4);
+        assert_relative_eq!(material_opacity(24.0f64), 0.1648, epsilon = 1e-4);
+    }
+}
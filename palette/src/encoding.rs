@@ -1,12 +1,28 @@
 //! Various encoding traits, types and standards.
 
+pub use self::dci_p3::DciP3;
+pub use self::display_p3::DisplayP3;
 pub use self::gamma::{F2p2, Gamma};
 pub use self::linear::Linear;
-pub use self::srgb::Srgb;
+pub use self::lstar::{Lstar, LstarConstants};
+pub use self::piecewise_gamma::{PiecewiseConstants, PiecewiseGamma};
+pub use self::prophoto_rgb::ProPhotoRgb;
+pub use self::rec2020::{Rec2020, Rec2020Constants};
+pub use self::rec709::{Rec709, Rec709Constants};
+pub use self::srgb::{Srgb, SrgbConstants};
+pub use self::srgb_fast::SrgbFast;
 
+pub mod dci_p3;
+pub mod display_p3;
 pub mod gamma;
 pub mod linear;
+pub mod lstar;
+pub mod piecewise_gamma;
+pub mod prophoto_rgb;
+pub mod rec2020;
+pub mod rec709;
 pub mod srgb;
+pub mod srgb_fast;
 
 /// A transfer function to and from linear space.
 pub trait TransferFn<T>: 'static {
@@ -0,0 +1,145 @@
+//! The DCI-P3 standard.
+
+use crate::encoding::TransferFn;
+use crate::float::{self, Float};
+use crate::luma::LumaStandard;
+use crate::matrix::{mat3_from_f64, Mat3};
+use crate::rgb::{Primaries, RgbSpace, RgbStandard};
+use crate::white_point::{Any, Dci};
+use crate::{from_f64, FromF64, Yxy};
+
+// The RGB <-> XYZ matrices, generated by `build/matrix.rs`.
+include!(concat!(env!("OUT_DIR"), "/dci_p3_matrices.rs"));
+
+/// The DCI-P3 color space, for digital cinema.
+///
+/// DCI-P3 has the same primaries as [`DisplayP3`](crate::encoding::DisplayP3),
+/// but the greenish [`Dci`] white point and a pure 2.6 gamma, for projection
+/// in dark theaters. The two are easy to mix up, but the same RGB values
+/// are different colors in each of them.
+///
+/// The white point is not D65, so converting to the other RGB standards
+/// needs chromatic adaptation, unless the DCI white should be kept as it is:
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::chromatic_adaptation::AdaptFrom;
+/// use palette::encoding::{DciP3, DisplayP3};
+/// use palette::rgb::Rgb;
+///
+/// let white = Rgb::<DciP3, f64>::new(1.0, 1.0, 1.0);
+/// let display = Rgb::<DisplayP3, f64>::adapt_from(white);
+/// assert_relative_eq!(display, Rgb::new(1.0, 1.0, 1.0), epsilon = 1e-4);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DciP3;
+
+impl<T: FromF64> Primaries<T> for DciP3 {
+    fn red() -> Yxy<Any, T> {
+        Yxy::new(from_f64(0.6800), from_f64(0.3200), from_f64(0.209492))
+    }
+    fn green() -> Yxy<Any, T> {
+        Yxy::new(from_f64(0.2650), from_f64(0.6900), from_f64(0.721595))
+    }
+    fn blue() -> Yxy<Any, T> {
+        Yxy::new(from_f64(0.1500), from_f64(0.0600), from_f64(0.068913))
+    }
+}
+
+impl<T> RgbSpace<T> for DciP3
+where
+    T: FromF64,
+{
+    type Primaries = DciP3;
+    type WhitePoint = Dci;
+
+    #[inline(always)]
+    fn rgb_to_xyz_matrix() -> Option<Mat3<T>> {
+        Some(mat3_from_f64(&RGB_TO_XYZ))
+    }
+
+    #[inline(always)]
+    fn xyz_to_rgb_matrix() -> Option<Mat3<T>> {
+        Some(mat3_from_f64(&XYZ_TO_RGB))
+    }
+}
+
+impl<T> RgbStandard<T> for DciP3
+where
+    T: FromF64 + Float,
+{
+    type Space = DciP3;
+    type TransferFn = DciP3;
+}
+
+impl<T> LumaStandard<T> for DciP3
+where
+    T: FromF64 + Float,
+{
+    type WhitePoint = Dci;
+    type TransferFn = DciP3;
+}
+
+impl<T> TransferFn<T> for DciP3
+where
+    T: Float + FromF64,
+{
+    fn into_linear(x: T) -> T {
+        float::powf(x.max(T::zero()), from_f64(2.6))
+    }
+
+    fn from_linear(x: T) -> T {
+        float::powf(x.max(T::zero()), from_f64(1.0 / 2.6))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::DciP3;
+    use crate::encoding::{DisplayP3, TransferFn};
+    use crate::rgb::Rgb;
+    use crate::white_point::{Dci, D65};
+    use crate::{FromColor, Xyz};
+
+    #[test]
+    fn transfer_function() {
+        let half = <DciP3 as TransferFn<f64>>::into_linear(0.5);
+        assert_relative_eq!(half, 0.1649385, epsilon = 1e-5);
+
+        for &x in &[0.0f64, 0.01, 0.3, 0.9, 1.0] {
+            let linear = <DciP3 as TransferFn<f64>>::into_linear(x);
+            let encoded = <DciP3 as TransferFn<f64>>::from_linear(linear);
+            assert_relative_eq!(encoded, x, epsilon = 1e-5);
+        }
+    }
+
+    #[test]
+    fn white_points() {
+        let white = Xyz::from_color(Rgb::<DciP3, f64>::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(
+            white,
+            Xyz::<Dci, _>::new(0.894587, 1.0, 0.954416),
+            epsilon = 1e-9
+        );
+
+        // The same RGB values are different colors in Display P3.
+        let display_white = Xyz::from_color(Rgb::<DisplayP3, f64>::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(
+            display_white,
+            Xyz::<D65, _>::new(0.95047, 1.0, 1.08883),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn same_primaries_as_display_p3() {
+        let dci = Xyz::from_color(Rgb::<DciP3, f64>::new(0.0, 1.0, 0.0));
+        let display = Xyz::from_color(Rgb::<DisplayP3, f64>::new(0.0, 1.0, 0.0));
+
+        let chromaticity = |x: f64, y: f64, z: f64| (x / (x + y + z), y / (x + y + z));
+        let (dci_x, dci_y) = chromaticity(dci.x, dci.y, dci.z);
+        let (display_x, display_y) = chromaticity(display.x, display.y, display.z);
+        assert_relative_eq!(dci_x, display_x, epsilon = 1e-6);
+        assert_relative_eq!(dci_y, display_y, epsilon = 1e-6);
+    }
+}
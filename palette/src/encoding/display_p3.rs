@@ -0,0 +1,110 @@
+//! The Display P3 standard.
+
+use crate::encoding::Srgb;
+use crate::float::Float;
+use crate::luma::LumaStandard;
+use crate::matrix::{mat3_from_f64, Mat3};
+use crate::rgb::{Primaries, RgbSpace, RgbStandard};
+use crate::white_point::{Any, D65};
+use crate::{from_f64, FromF64, Yxy};
+
+// The RGB <-> XYZ matrices, generated by `build/matrix.rs`.
+include!(concat!(env!("OUT_DIR"), "/display_p3_matrices.rs"));
+
+/// The Display P3 color space.
+///
+/// Display P3 has the wide gamut primaries of DCI-P3, with the D65 white
+/// point and the sRGB transfer function. It's the color space of many
+/// modern displays, and of `color(display-p3 ...)` in CSS.
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::encoding::DisplayP3;
+/// use palette::rgb::Rgb;
+/// use palette::{FromColor, Srgb};
+///
+/// // CSS: color(display-p3 0.9175 0.2003 0.1386)
+/// let red = Rgb::<DisplayP3, f64>::from_color(Srgb::new(1.0, 0.0, 0.0));
+/// assert_relative_eq!(red, Rgb::new(0.9175, 0.2003, 0.1386), epsilon = 1e-4);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DisplayP3;
+
+impl<T: FromF64> Primaries<T> for DisplayP3 {
+    fn red() -> Yxy<Any, T> {
+        Yxy::new(from_f64(0.6800), from_f64(0.3200), from_f64(0.229004))
+    }
+    fn green() -> Yxy<Any, T> {
+        Yxy::new(from_f64(0.2650), from_f64(0.6900), from_f64(0.691727))
+    }
+    fn blue() -> Yxy<Any, T> {
+        Yxy::new(from_f64(0.1500), from_f64(0.0600), from_f64(0.079270))
+    }
+}
+
+impl<T> RgbSpace<T> for DisplayP3
+where
+    T: FromF64,
+{
+    type Primaries = DisplayP3;
+    type WhitePoint = D65;
+
+    #[inline(always)]
+    fn rgb_to_xyz_matrix() -> Option<Mat3<T>> {
+        Some(mat3_from_f64(&RGB_TO_XYZ))
+    }
+
+    #[inline(always)]
+    fn xyz_to_rgb_matrix() -> Option<Mat3<T>> {
+        Some(mat3_from_f64(&XYZ_TO_RGB))
+    }
+}
+
+impl<T> RgbStandard<T> for DisplayP3
+where
+    T: FromF64 + Float,
+{
+    type Space = DisplayP3;
+    type TransferFn = Srgb;
+}
+
+impl<T> LumaStandard<T> for DisplayP3
+where
+    T: FromF64 + Float,
+{
+    type WhitePoint = D65;
+    type TransferFn = Srgb;
+}
+
+#[cfg(test)]
+mod test {
+    use super::DisplayP3;
+    use crate::convert::FromColorUnclamped;
+    use crate::rgb::Rgb;
+    use crate::{FromColor, Srgb};
+
+    type DisplayP3Rgb = Rgb<DisplayP3, f64>;
+
+    // The reference values are converted with the CSS Color 4 sample code,
+    // and rounded to four decimals.
+    #[test]
+    fn css_reference_values() {
+        let green = DisplayP3Rgb::from_color(Srgb::new(0.0, 1.0, 0.0));
+        assert_relative_eq!(green, Rgb::new(0.4584, 0.9853, 0.2983), epsilon = 1e-4);
+
+        let blue = DisplayP3Rgb::from_color(Srgb::new(0.0, 0.0, 1.0));
+        assert_relative_eq!(blue, Rgb::new(0.0, 0.0, 0.9596), epsilon = 1e-4);
+
+        let purple = DisplayP3Rgb::from_color(Srgb::new(0.5, 0.25, 0.75));
+        assert_relative_eq!(purple, Rgb::new(0.4674, 0.2631, 0.7241), epsilon = 1e-4);
+    }
+
+    #[test]
+    fn white_and_wide_gamut() {
+        let white = DisplayP3Rgb::from_color(Srgb::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(white, Rgb::new(1.0, 1.0, 1.0), epsilon = 1e-5);
+
+        let p3_red = Srgb::<f64>::from_color_unclamped(DisplayP3Rgb::new(1.0, 0.0, 0.0));
+        assert!(p3_red.red > 1.0, "Display P3 red is outside sRGB");
+    }
+}
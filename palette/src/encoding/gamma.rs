@@ -3,7 +3,7 @@
 use core::marker::PhantomData;
 
 use crate::encoding::TransferFn;
-use crate::float::Float;
+use crate::float::{self, Float};
 use crate::luma::LumaStandard;
 use crate::rgb::{RgbSpace, RgbStandard};
 use crate::white_point::WhitePoint;
@@ -57,12 +57,12 @@ where
 {
     #[inline]
     fn into_linear(x: T) -> T {
-        x.powf(T::one() / from_f64(N::VALUE))
+        float::powf(x, T::one() / from_f64(N::VALUE))
     }
 
     #[inline]
     fn from_linear(x: T) -> T {
-        x.powf(from_f64(N::VALUE))
+        float::powf(x, from_f64(N::VALUE))
     }
 }
 
@@ -0,0 +1,115 @@
+//! The CIE L\* lightness encoding.
+
+use core::marker::PhantomData;
+
+use crate::encoding::{PiecewiseConstants, PiecewiseGamma};
+use crate::float::Float;
+use crate::luma::LumaStandard;
+use crate::white_point::{WhitePoint, D65};
+use crate::FromF64;
+
+/// Luminance encoded as CIE L\* lightness, scaled to the range from `0.0`
+/// to `1.0`.
+///
+/// L\* is the lightness component of [`Lab`](crate::Lab) and
+/// [`Luv`](crate::Luv), and is close to how light a gray looks. Storing
+/// luminance as L\* spreads the values evenly over the perceived steps, so
+/// an 8 bit `Luma<Lstar, u8>` has no visible banding, and the stored value is
+/// the lightness that many design and accessibility tools show. An encoded
+/// value of `0.5`, or `128` as `u8`, is L\* 50, which is a middle gray with
+/// 18% relative luminance.
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::encoding::Lstar;
+/// use palette::luma::Luma;
+/// use palette::{FromColor, Lab};
+///
+/// let gray = Luma::<Lstar, u8>::new(128);
+/// let lab = Lab::from_color(gray.into_format::<f32>());
+/// assert_relative_eq!(lab.l, 128.0 / 2.55, epsilon = 1e-3);
+///
+/// // A middle gray reflects 18% of the light.
+/// let linear = Luma::<Lstar, f64>::new(0.5).into_linear();
+/// assert_relative_eq!(linear.luma, 0.1842, epsilon = 1e-4);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Lstar<Wp = D65>(PhantomData<Wp>);
+
+impl<T, Wp> LumaStandard<T> for Lstar<Wp>
+where
+    T: Float + FromF64,
+    Wp: WhitePoint<T>,
+{
+    type WhitePoint = Wp;
+    type TransferFn = PiecewiseGamma<LstarConstants>;
+}
+
+/// The constants of the L\* transfer function, for [`PiecewiseGamma`],
+/// with L\* divided by `100`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LstarConstants;
+
+impl PiecewiseConstants for LstarConstants {
+    const GAMMA: f64 = 3.0;
+    const ALPHA: f64 = 1.16;
+    /// The relative luminance where the linear segment ends, `(6 / 29)^3`.
+    const BETA: f64 = 216.0 / 24389.0;
+    const SLOPE: f64 = 24389.0 / 2700.0;
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Lstar, LstarConstants};
+    use crate::encoding::{PiecewiseGamma, TransferFn};
+    use crate::luma::{LinLuma, Luma};
+    use crate::{FromColor, Lab};
+
+    type LstarFn = PiecewiseGamma<LstarConstants>;
+
+    #[test]
+    fn transfer_function() {
+        assert_eq!(LstarFn::from_linear(0.0), 0.0);
+        assert_relative_eq!(LstarFn::from_linear(1.0), 1.0, epsilon = 1e-6);
+        assert_relative_eq!(LstarFn::into_linear(1.0), 1.0);
+
+        // Both segments meet at L* 8.
+        let toe = 216.0 / 24389.0;
+        assert_relative_eq!(LstarFn::from_linear(toe), 0.08, epsilon = 1e-6);
+
+        for &x in &[0.0f64, 0.01, 0.08, 0.1, 0.5, 0.9, 1.0] {
+            let linear = LstarFn::into_linear(x);
+            let encoded = LstarFn::from_linear(linear);
+            assert_relative_eq!(encoded, x, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn matches_lab() {
+        for &l in &[0.0f64, 0.05, 0.2, 0.5, 0.75, 1.0] {
+            let lab = Lab::from_color(Luma::<Lstar, f64>::new(l));
+            assert_relative_eq!(lab.l, l * 100.0, epsilon = 1e-4);
+            assert_relative_eq!(lab.a, 0.0, epsilon = 1e-4);
+            assert_relative_eq!(lab.b, 0.0, epsilon = 1e-4);
+        }
+
+        let linear = LinLuma::new(0.2f64);
+        let lstar = Luma::<Lstar, f64>::from_linear(linear);
+        assert_relative_eq!(
+            lstar.luma * 100.0,
+            Lab::from_color(linear).l,
+            epsilon = 1e-4
+        );
+    }
+
+    #[test]
+    fn u8_round_trip() {
+        for value in 0..=255u8 {
+            let linear = Luma::<Lstar, u8>::new(value)
+                .into_format::<f64>()
+                .into_linear();
+            let back: Luma<Lstar, u8> = Luma::from_linear(linear).into_format();
+            assert_eq!(back.luma, value);
+        }
+    }
+}
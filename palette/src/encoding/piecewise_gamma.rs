@@ -0,0 +1,126 @@
+//! Transfer functions with a linear segment and a power function.
+
+use core::marker::PhantomData;
+
+use crate::encoding::TransferFn;
+use crate::float::{self, Float};
+use crate::{from_f64, FromF64};
+
+/// The constants of a [`PiecewiseGamma`] transfer function.
+///
+/// The encoded value `V` is calculated from the linear value `L` as
+///
+/// ```text
+/// V = SLOPE * L                               if L < BETA
+/// V = ALPHA * L^(1 / GAMMA) - (ALPHA - 1)     otherwise
+/// ```
+///
+/// The two segments should meet at `BETA`, which is the case when `SLOPE *
+/// BETA = ALPHA * BETA^(1 / GAMMA) - (ALPHA - 1)`.
+pub trait PiecewiseConstants: 'static {
+    /// The exponent of the power function, when decoding.
+    const GAMMA: f64;
+
+    /// The scale of the power function.
+    const ALPHA: f64;
+
+    /// The linear value where the linear segment ends.
+    const BETA: f64;
+
+    /// The slope of the linear segment.
+    const SLOPE: f64;
+}
+
+/// A transfer function with a linear segment near black, and a power
+/// function for the rest of the range.
+///
+/// Many standards, such as sRGB, Rec. 709 and CIE L\*, have transfer
+/// functions of this shape, with different constants. A new encoding can
+/// be defined by implementing [`PiecewiseConstants`] for a type, and using
+/// `PiecewiseGamma` with it as the transfer function:
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::encoding::{PiecewiseConstants, PiecewiseGamma, TransferFn};
+///
+/// /// The transfer function of SMPTE 240M.
+/// struct Smpte240mConstants;
+///
+/// impl PiecewiseConstants for Smpte240mConstants {
+///     const GAMMA: f64 = 1.0 / 0.45;
+///     const ALPHA: f64 = 1.1115;
+///     const BETA: f64 = 0.0228;
+///     const SLOPE: f64 = 4.0;
+/// }
+///
+/// type Smpte240mFn = PiecewiseGamma<Smpte240mConstants>;
+///
+/// let encoded: f64 = Smpte240mFn::from_linear(0.5);
+/// assert_relative_eq!(encoded, 0.7022, epsilon = 1e-4);
+/// assert_relative_eq!(Smpte240mFn::into_linear(encoded), 0.5, epsilon = 1e-6);
+/// ```
+///
+/// The transfer function can then be used in an
+/// [`RgbStandard`](crate::rgb::RgbStandard) or a
+/// [`LumaStandard`](crate::luma::LumaStandard).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PiecewiseGamma<C>(PhantomData<C>);
+
+impl<T, C> TransferFn<T> for PiecewiseGamma<C>
+where
+    T: Float + FromF64,
+    C: PiecewiseConstants,
+{
+    #[inline]
+    fn into_linear(x: T) -> T {
+        if x < from_f64(C::SLOPE * C::BETA) {
+            x / from_f64(C::SLOPE)
+        } else {
+            float::powf(
+                (x + from_f64(C::ALPHA - 1.0)) / from_f64(C::ALPHA),
+                from_f64(C::GAMMA),
+            )
+        }
+    }
+
+    #[inline]
+    fn from_linear(x: T) -> T {
+        if x < from_f64(C::BETA) {
+            x * from_f64(C::SLOPE)
+        } else {
+            float::powf(x, from_f64(1.0 / C::GAMMA)) * from_f64(C::ALPHA) - from_f64(C::ALPHA - 1.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PiecewiseGamma;
+    use crate::encoding::{Srgb, SrgbConstants, TransferFn};
+
+    fn assert_same<A, B>()
+    where
+        A: TransferFn<f64>,
+        B: TransferFn<f64>,
+    {
+        for step in 0..=100 {
+            let x = f64::from(step) / 100.0;
+            assert_relative_eq!(A::from_linear(x), B::from_linear(x), epsilon = 1e-6);
+            assert_relative_eq!(A::into_linear(x), B::into_linear(x), epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn matches_srgb() {
+        assert_same::<PiecewiseGamma<SrgbConstants>, Srgb>();
+    }
+
+    #[test]
+    fn round_trip() {
+        for &x in &[-0.1f64, 0.0, 0.001, 0.02, 0.5, 1.0, 1.5] {
+            let encoded = PiecewiseGamma::<SrgbConstants>::from_linear(x);
+            let decoded = PiecewiseGamma::<SrgbConstants>::into_linear(encoded);
+            assert_relative_eq!(decoded, x, epsilon = 1e-6);
+        }
+    }
+}
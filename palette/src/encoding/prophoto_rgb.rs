@@ -0,0 +1,164 @@
+//! The ProPhoto RGB standard.
+
+use crate::encoding::TransferFn;
+use crate::float::{self, Float};
+use crate::luma::LumaStandard;
+use crate::matrix::{mat3_from_f64, Mat3};
+use crate::rgb::{Primaries, RgbSpace, RgbStandard};
+use crate::white_point::{Any, D50};
+use crate::{from_f64, FromF64, Yxy};
+
+// The RGB <-> XYZ matrices, generated by `build/matrix.rs`.
+include!(concat!(env!("OUT_DIR"), "/prophoto_rgb_matrices.rs"));
+
+/// The linear value where the linear segment of the transfer function ends.
+const LINEAR_END: f64 = 1.0 / 512.0;
+
+/// The ProPhoto RGB color space, also known as ROMM RGB.
+///
+/// ProPhoto RGB has very wide primaries, that cover most of the colors that
+/// can be seen, and is used for editing and archiving photos. Its white
+/// point is D50, and its transfer function is a 1.8 gamma with a short
+/// linear segment near black.
+///
+/// The other RGB standards in palette use D65, so converting between them
+/// needs chromatic adaptation. [`AdaptFrom`](crate::chromatic_adaptation::AdaptFrom)
+/// and [`AdaptInto`](crate::chromatic_adaptation::AdaptInto) take care of
+/// that:
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::chromatic_adaptation::AdaptFrom;
+/// use palette::encoding::ProPhotoRgb;
+/// use palette::rgb::Rgb;
+/// use palette::Srgb;
+///
+/// let white = Rgb::<ProPhotoRgb, f64>::new(1.0, 1.0, 1.0);
+/// let srgb = Srgb::adapt_from(white);
+/// assert_relative_eq!(srgb, Srgb::new(1.0, 1.0, 1.0), epsilon = 1e-4);
+///
+/// let back = Rgb::<ProPhotoRgb, f64>::adapt_from(srgb);
+/// assert_relative_eq!(back, white, epsilon = 1e-4);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ProPhotoRgb;
+
+impl<T: FromF64> Primaries<T> for ProPhotoRgb {
+    fn red() -> Yxy<Any, T> {
+        Yxy::new(from_f64(0.7347), from_f64(0.2653), from_f64(0.288040))
+    }
+    fn green() -> Yxy<Any, T> {
+        Yxy::new(from_f64(0.1596), from_f64(0.8404), from_f64(0.711874))
+    }
+    fn blue() -> Yxy<Any, T> {
+        Yxy::new(from_f64(0.0366), from_f64(0.0001), from_f64(0.000086))
+    }
+}
+
+impl<T> RgbSpace<T> for ProPhotoRgb
+where
+    T: FromF64,
+{
+    type Primaries = ProPhotoRgb;
+    type WhitePoint = D50;
+
+    #[inline(always)]
+    fn rgb_to_xyz_matrix() -> Option<Mat3<T>> {
+        Some(mat3_from_f64(&RGB_TO_XYZ))
+    }
+
+    #[inline(always)]
+    fn xyz_to_rgb_matrix() -> Option<Mat3<T>> {
+        Some(mat3_from_f64(&XYZ_TO_RGB))
+    }
+}
+
+impl<T> RgbStandard<T> for ProPhotoRgb
+where
+    T: FromF64 + Float,
+{
+    type Space = ProPhotoRgb;
+    type TransferFn = ProPhotoRgb;
+}
+
+impl<T> LumaStandard<T> for ProPhotoRgb
+where
+    T: FromF64 + Float,
+{
+    type WhitePoint = D50;
+    type TransferFn = ProPhotoRgb;
+}
+
+impl<T> TransferFn<T> for ProPhotoRgb
+where
+    T: Float + FromF64,
+{
+    fn into_linear(x: T) -> T {
+        if x < from_f64(16.0 * LINEAR_END) {
+            x / from_f64(16.0)
+        } else {
+            float::powf(x, from_f64(1.8))
+        }
+    }
+
+    fn from_linear(x: T) -> T {
+        if x < from_f64(LINEAR_END) {
+            x * from_f64(16.0)
+        } else {
+            float::powf(x, from_f64(1.0 / 1.8))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ProPhotoRgb;
+    use crate::chromatic_adaptation::AdaptInto;
+    use crate::encoding::TransferFn;
+    use crate::rgb::Rgb;
+    use crate::white_point::D50;
+    use crate::{FromColor, LinSrgb, Xyz};
+
+    type ProPhoto = Rgb<ProPhotoRgb, f64>;
+
+    #[test]
+    fn transfer_function() {
+        // The segments meet at 1/512.
+        let toe = <ProPhotoRgb as TransferFn<f64>>::from_linear(super::LINEAR_END);
+        assert_relative_eq!(toe, 16.0 / 512.0, epsilon = 1e-5);
+
+        let half = <ProPhotoRgb as TransferFn<f64>>::into_linear(0.5);
+        assert_relative_eq!(half, 0.2871746, epsilon = 1e-5);
+
+        for &x in &[0.0f64, 0.01, 0.03125, 0.3, 0.9, 1.0] {
+            let linear = <ProPhotoRgb as TransferFn<f64>>::into_linear(x);
+            let encoded = <ProPhotoRgb as TransferFn<f64>>::from_linear(linear);
+            assert_relative_eq!(encoded, x, epsilon = 1e-5);
+        }
+    }
+
+    #[test]
+    fn primaries() {
+        let white = Xyz::from_color(ProPhoto::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(
+            white,
+            Xyz::<D50, _>::new(0.96422, 1.0, 0.82521),
+            epsilon = 1e-9
+        );
+
+        let red = Xyz::from_color(ProPhoto::new(1.0, 0.0, 0.0));
+        assert_relative_eq!(red.x / (red.x + red.y + red.z), 0.7347, epsilon = 1e-6);
+        assert_relative_eq!(red.y, 0.288040, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn adapt_to_d65() {
+        // sRGB red is well within ProPhoto RGB, and survives the round trip.
+        let red = LinSrgb::new(1.0f64, 0.0, 0.0);
+        let prophoto: ProPhoto = red.adapt_into();
+        assert!(prophoto.red < 1.0 && prophoto.green > 0.0);
+
+        let back: LinSrgb<f64> = prophoto.adapt_into();
+        assert_relative_eq!(back, red, epsilon = 1e-6);
+    }
+}
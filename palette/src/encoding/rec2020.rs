@@ -0,0 +1,134 @@
+//! The Rec. 2020 standard.
+
+use crate::encoding::{PiecewiseConstants, PiecewiseGamma};
+use crate::float::Float;
+use crate::luma::LumaStandard;
+use crate::matrix::{mat3_from_f64, Mat3};
+use crate::rgb::{Primaries, RgbSpace, RgbStandard};
+use crate::white_point::{Any, D65};
+use crate::{from_f64, FromF64, Yxy};
+
+// The RGB <-> XYZ matrices, generated by `build/matrix.rs`.
+include!(concat!(env!("OUT_DIR"), "/rec2020_matrices.rs"));
+
+/// The ITU-R BT.2020 color space, also known as Rec. 2020.
+///
+/// Rec. 2020 is the wide gamut color space of ultra high definition
+/// television, with the D65 white point. Its transfer function is the same
+/// kind of linear segment and power function as in Rec. 709, with the more
+/// precise constants from BT.2020. It's the camera side transfer function,
+/// so the display side is usually the similar BT.1886 gamma.
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::encoding::Rec2020;
+/// use palette::rgb::Rgb;
+/// use palette::{FromColor, LinSrgb};
+///
+/// let red = Rgb::<Rec2020, f64>::from_color(LinSrgb::new(1.0, 0.0, 0.0));
+/// assert!(red.red < 1.0 && red.green > 0.0 && red.blue > 0.0);
+///
+/// let back = LinSrgb::from_color(red);
+/// assert_relative_eq!(back, LinSrgb::new(1.0, 0.0, 0.0), epsilon = 1e-5);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Rec2020;
+
+impl<T: FromF64> Primaries<T> for Rec2020 {
+    fn red() -> Yxy<Any, T> {
+        Yxy::new(from_f64(0.7080), from_f64(0.2920), from_f64(0.262722))
+    }
+    fn green() -> Yxy<Any, T> {
+        Yxy::new(from_f64(0.1700), from_f64(0.7970), from_f64(0.677989))
+    }
+    fn blue() -> Yxy<Any, T> {
+        Yxy::new(from_f64(0.1310), from_f64(0.0460), from_f64(0.059289))
+    }
+}
+
+impl<T> RgbSpace<T> for Rec2020
+where
+    T: FromF64,
+{
+    type Primaries = Rec2020;
+    type WhitePoint = D65;
+
+    #[inline(always)]
+    fn rgb_to_xyz_matrix() -> Option<Mat3<T>> {
+        Some(mat3_from_f64(&RGB_TO_XYZ))
+    }
+
+    #[inline(always)]
+    fn xyz_to_rgb_matrix() -> Option<Mat3<T>> {
+        Some(mat3_from_f64(&XYZ_TO_RGB))
+    }
+}
+
+impl<T> RgbStandard<T> for Rec2020
+where
+    T: FromF64 + Float,
+{
+    type Space = Rec2020;
+    type TransferFn = PiecewiseGamma<Rec2020Constants>;
+}
+
+impl<T> LumaStandard<T> for Rec2020
+where
+    T: FromF64 + Float,
+{
+    type WhitePoint = D65;
+    type TransferFn = PiecewiseGamma<Rec2020Constants>;
+}
+
+/// The constants of the Rec. 2020 transfer function, for
+/// [`PiecewiseGamma`]. They are the same as for Rec. 709, but more precise.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Rec2020Constants;
+
+impl PiecewiseConstants for Rec2020Constants {
+    const GAMMA: f64 = 1.0 / 0.45;
+    const ALPHA: f64 = 1.099_296_826_809_44;
+    const BETA: f64 = 0.018_053_968_510_807;
+    const SLOPE: f64 = 4.5;
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Rec2020, Rec2020Constants};
+    use crate::encoding::{PiecewiseConstants, PiecewiseGamma, TransferFn};
+    use crate::rgb::Rgb;
+    use crate::{FromColor, Xyz};
+
+    type Rec2020Fn = PiecewiseGamma<Rec2020Constants>;
+
+    #[test]
+    fn transfer_function() {
+        // The segments meet at `β`.
+        let toe = Rec2020Fn::from_linear(Rec2020Constants::BETA);
+        assert_relative_eq!(toe, 4.5 * Rec2020Constants::BETA, epsilon = 1e-5);
+
+        assert_relative_eq!(Rec2020Fn::from_linear(1.0), 1.0);
+        let half = Rec2020Fn::into_linear(0.5);
+        assert_relative_eq!(half, 0.2597194, epsilon = 1e-5);
+
+        for &x in &[0.0f64, 0.01, 0.0812, 0.3, 0.9] {
+            let linear = Rec2020Fn::into_linear(x);
+            let encoded = Rec2020Fn::from_linear(linear);
+            assert_relative_eq!(encoded, x, epsilon = 1e-5);
+        }
+    }
+
+    #[test]
+    fn primaries() {
+        let green = Xyz::from_color(Rgb::<Rec2020, f64>::new(0.0, 1.0, 0.0));
+        assert_relative_eq!(
+            green.x / (green.x + green.y + green.z),
+            0.170,
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(green.y, 0.677989, epsilon = 1e-6);
+
+        let white = Xyz::from_color(Rgb::<Rec2020, f64>::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(white, Xyz::new(0.95047, 1.0, 1.08883), epsilon = 1e-9);
+    }
+}
@@ -0,0 +1,108 @@
+//! The Rec. 709 standard.
+
+use crate::encoding::{PiecewiseConstants, PiecewiseGamma, Srgb};
+use crate::float::Float;
+use crate::luma::LumaStandard;
+use crate::rgb::RgbStandard;
+use crate::white_point::D65;
+use crate::FromF64;
+
+/// The ITU-R BT.709 color space, also known as Rec. 709.
+///
+/// Rec. 709 is the color space of high definition television. It has the
+/// same primaries and white point as sRGB, but a different transfer
+/// function. That's the camera side transfer function, or OETF, with a
+/// linear segment below `0.018` and a `0.45` power function above it, for
+/// scene referred values. It's not a good approximation of the sRGB transfer
+/// function, or of a gamma.
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::encoding::Rec709;
+/// use palette::rgb::Rgb;
+/// use palette::{FromColor, Srgb};
+///
+/// let gray = Rgb::<Rec709, f64>::new(0.5, 0.5, 0.5);
+/// let srgb = Srgb::from_color(gray);
+/// assert!(srgb.red > 0.5);
+///
+/// // The primaries are the same, so the linear values are too.
+/// assert_relative_eq!(gray.into_linear().red, srgb.into_linear().red, epsilon = 1e-6);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Rec709;
+
+impl<T> RgbStandard<T> for Rec709
+where
+    T: FromF64 + Float,
+{
+    type Space = Srgb;
+    type TransferFn = PiecewiseGamma<Rec709Constants>;
+}
+
+impl<T> LumaStandard<T> for Rec709
+where
+    T: FromF64 + Float,
+{
+    type WhitePoint = D65;
+    type TransferFn = PiecewiseGamma<Rec709Constants>;
+}
+
+/// The constants of the Rec. 709 transfer function, for
+/// [`PiecewiseGamma`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Rec709Constants;
+
+impl PiecewiseConstants for Rec709Constants {
+    const GAMMA: f64 = 1.0 / 0.45;
+    const ALPHA: f64 = 1.099;
+    const BETA: f64 = 0.018;
+    const SLOPE: f64 = 4.5;
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Rec709, Rec709Constants};
+    use crate::encoding::rec2020::Rec2020Constants;
+    use crate::encoding::{PiecewiseGamma, TransferFn};
+    use crate::rgb::Rgb;
+    use crate::{FromColor, LinSrgb};
+
+    type Rec709Fn = PiecewiseGamma<Rec709Constants>;
+    type Rec2020Fn = PiecewiseGamma<Rec2020Constants>;
+
+    #[test]
+    fn transfer_function() {
+        let toe = Rec709Fn::from_linear(0.018);
+        assert_relative_eq!(toe, 0.081, epsilon = 1e-3);
+
+        assert_relative_eq!(Rec709Fn::from_linear(1.0), 1.0);
+        let half = Rec709Fn::from_linear(0.5);
+        assert_relative_eq!(half, 0.7055, epsilon = 1e-4);
+
+        for &x in &[0.0f64, 0.01, 0.08, 0.1, 0.3, 0.9] {
+            let linear = Rec709Fn::into_linear(x);
+            let encoded = Rec709Fn::from_linear(linear);
+            assert_relative_eq!(encoded, x, epsilon = 1e-5);
+        }
+
+        // Rec. 2020 has the same curve with more precise constants.
+        for &x in &[0.05f64, 0.5] {
+            assert_relative_eq!(
+                Rec709Fn::from_linear(x),
+                Rec2020Fn::from_linear(x),
+                epsilon = 1e-3
+            );
+        }
+    }
+
+    #[test]
+    fn srgb_primaries() {
+        let red = Rgb::<Rec709, f64>::new(1.0, 0.0, 0.0);
+        assert_relative_eq!(
+            LinSrgb::from_color(red),
+            LinSrgb::new(1.0, 0.0, 0.0),
+            epsilon = 1e-9
+        );
+    }
+}
@@ -1,12 +1,19 @@
 //! The sRGB standard.
 
-use crate::encoding::TransferFn;
-use crate::float::Float;
+use crate::encoding::{PiecewiseConstants, TransferFn};
+use crate::float::{self, Float};
 use crate::luma::LumaStandard;
+use crate::matrix::{mat3_from_f64, Mat3};
 use crate::rgb::{Primaries, RgbSpace, RgbStandard};
 use crate::white_point::{Any, D65};
 use crate::{from_f64, FromF64, Yxy};
 
+// The RGB <-> XYZ matrices, generated by `build/matrix.rs`.
+include!(concat!(env!("OUT_DIR"), "/srgb_matrices.rs"));
+
+// The lookup tables for 8 bit components, generated by `build/lut.rs`.
+include!(concat!(env!("OUT_DIR"), "/srgb_lut.rs"));
+
 /// The sRGB color space.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Srgb;
@@ -29,6 +36,16 @@ where
 {
     type Primaries = Srgb;
     type WhitePoint = D65;
+
+    #[inline(always)]
+    fn rgb_to_xyz_matrix() -> Option<Mat3<T>> {
+        Some(mat3_from_f64(&RGB_TO_XYZ))
+    }
+
+    #[inline(always)]
+    fn xyz_to_rgb_matrix() -> Option<Mat3<T>> {
+        Some(mat3_from_f64(&XYZ_TO_RGB))
+    }
 }
 
 impl<T> RgbStandard<T> for Srgb
@@ -47,6 +64,50 @@ where
     type TransferFn = Srgb;
 }
 
+/// Convert an 8 bit sRGB encoded component to a linear `f32` component,
+/// using a lookup table.
+#[inline]
+pub(crate) fn u8_into_linear_f32(component: u8) -> f32 {
+    SRGB_U8_TO_LINEAR[usize::from(component)]
+}
+
+/// Convert a linear `f32` component to an 8 bit sRGB encoded component,
+/// rounded to the nearest value. This looks up where to start in a table of
+/// 4096 ranges, and then steps past the values where the result changes,
+/// instead of evaluating the transfer function. Values outside of `[0.0,
+/// 1.0]`, and NaN, are clamped.
+#[inline]
+pub(crate) fn linear_f32_into_u8(component: f32) -> u8 {
+    // The cast saturates, and turns NaN into 0.
+    let bucket = (component * 4096.0) as usize;
+    let mut index = usize::from(LINEAR_TO_SRGB_U8_STARTS[bucket.min(4095)]);
+
+    while index < LINEAR_TO_SRGB_U8_THRESHOLDS.len()
+        && component >= LINEAR_TO_SRGB_U8_THRESHOLDS[index]
+    {
+        index += 1;
+    }
+
+    index as u8
+}
+
+/// The constants of the sRGB transfer function, for
+/// [`PiecewiseGamma`](crate::encoding::PiecewiseGamma).
+///
+/// `PiecewiseGamma<SrgbConstants>` is within `1e-6` of [`Srgb`]'s transfer
+/// function, which isn't implemented with it. The standard's decoding
+/// threshold, `0.04045`, is rounded from `SLOPE * BETA`, and the 8 bit
+/// lookup tables are generated from the exact standard curve.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SrgbConstants;
+
+impl PiecewiseConstants for SrgbConstants {
+    const GAMMA: f64 = 2.4;
+    const ALPHA: f64 = 1.055;
+    const BETA: f64 = 0.0031308;
+    const SLOPE: f64 = 12.92;
+}
+
 impl<T> TransferFn<T> for Srgb
 where
     T: Float + FromF64,
@@ -56,7 +117,10 @@ where
         if x <= from_f64(0.04045) {
             x * from_f64::<T>(12.92).recip()
         } else {
-            ((x + from_f64(0.055)) * from_f64::<T>(1.055).recip()).powf(from_f64(2.4))
+            float::powf(
+                (x + from_f64(0.055)) * from_f64::<T>(1.055).recip(),
+                from_f64(2.4),
+            )
         }
     }
 
@@ -64,7 +128,29 @@ where
         if x <= from_f64(0.0031308) {
             x * from_f64(12.92)
         } else {
-            x.powf(T::one() / from_f64(2.4)) * from_f64(1.055) - from_f64(0.055)
+            float::powf(x, T::one() / from_f64(2.4)) * from_f64(1.055) - from_f64(0.055)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{linear_f32_into_u8, LINEAR_TO_SRGB_U8_THRESHOLDS};
+
+    #[test]
+    fn linear_f32_into_u8_matches_thresholds() {
+        let mut values: Vec<f32> = (0..=100_000).map(|i| i as f32 / 100_000.0).collect();
+        for &threshold in LINEAR_TO_SRGB_U8_THRESHOLDS.iter() {
+            values.push(threshold);
+            values.push(f32::from_bits(threshold.to_bits() - 1));
+        }
+
+        for &value in &values {
+            let expected = LINEAR_TO_SRGB_U8_THRESHOLDS
+                .iter()
+                .filter(|&&threshold| value >= threshold)
+                .count();
+            assert_eq!(usize::from(linear_f32_into_u8(value)), expected);
         }
     }
 }
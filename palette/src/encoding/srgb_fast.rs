@@ -0,0 +1,171 @@
+//! A faster approximation of the sRGB standard.
+
+use crate::encoding::{Srgb, TransferFn};
+use crate::float::Float;
+use crate::luma::LumaStandard;
+use crate::rgb::RgbStandard;
+use crate::white_point::D65;
+use crate::{from_f64, FromF64};
+
+// Polynomial approximation of `((x + 0.055) / 1.055)^2.4`, for `x` in
+// `[0.04045, 1.0]`, with a maximum relative error of `1.1e-4`.
+const INTO_LINEAR: [f64; 7] = [
+    0.000857193547,
+    0.0350805372,
+    0.490752548,
+    0.810142577,
+    -0.630524576,
+    0.417107612,
+    -0.123524569,
+];
+
+// Polynomial approximation of `1.055 * x^(1 / 2.4) - 0.055`, in `x^(1 / 4)`,
+// for `x` in `[0.0031308, 1.0]`, with a maximum absolute error of `7e-6`.
+const FROM_LINEAR: [f64; 6] = [
+    -0.0613400526,
+    0.162025064,
+    1.25540733,
+    -0.577485085,
+    0.289532781,
+    -0.0681465417,
+];
+
+/// The sRGB color space, with a faster, approximate transfer function.
+///
+/// This is the same color space as [`Srgb`], but the transfer function is
+/// approximated with polynomials instead of evaluating `powf`, which makes
+/// converting to and from linear RGB several times faster. The
+/// approximation is made for `f32` and 8 bit output:
+///
+/// * Converting into linear has a relative error below `1.1e-4`, and all 8
+///   bit values convert back to themselves with the exact transfer function.
+/// * Converting from linear has an absolute error below `7e-6`, which is
+///   rounded to the same 8 bit value as the exact result, or at most one step
+///   away when it's close to halfway between two values.
+///
+/// Values outside of `[0.0, 1.0]` fall back to the exact transfer function.
+///
+/// ```
+/// use palette::encoding::SrgbFast;
+/// use palette::rgb::Rgb;
+/// use palette::{LinSrgb, Srgb};
+///
+/// let fast = Rgb::<SrgbFast, f32>::new(0.5, 0.25, 1.0).into_linear();
+/// let exact = Srgb::new(0.5, 0.25, 1.0).into_linear();
+/// assert!((fast.red - exact.red).abs() < 1e-4);
+///
+/// // The linear colors are the same type.
+/// let back: Rgb<SrgbFast, f32> = Rgb::from_linear(exact);
+/// assert!((back.green - 0.25).abs() < 1e-5);
+/// # let _: LinSrgb = fast;
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SrgbFast;
+
+impl<T> RgbStandard<T> for SrgbFast
+where
+    T: FromF64 + Float,
+{
+    type Space = Srgb;
+    type TransferFn = SrgbFast;
+}
+
+impl<T> LumaStandard<T> for SrgbFast
+where
+    T: FromF64 + Float,
+{
+    type WhitePoint = D65;
+    type TransferFn = SrgbFast;
+}
+
+impl<T> TransferFn<T> for SrgbFast
+where
+    T: Float + FromF64,
+{
+    #[inline]
+    fn into_linear(x: T) -> T {
+        if x <= from_f64(0.04045) || x > T::one() {
+            <Srgb as TransferFn<T>>::into_linear(x)
+        } else {
+            polynomial(&INTO_LINEAR, x)
+        }
+    }
+
+    #[inline]
+    fn from_linear(x: T) -> T {
+        if x <= from_f64(0.0031308) || x > T::one() {
+            <Srgb as TransferFn<T>>::from_linear(x)
+        } else {
+            polynomial(&FROM_LINEAR, x.sqrt().sqrt())
+        }
+    }
+}
+
+/// Evaluate the polynomial with `coefficients` at `x`, with Horner's method.
+#[inline(always)]
+fn polynomial<T>(coefficients: &[f64], x: T) -> T
+where
+    T: Float + FromF64,
+{
+    coefficients
+        .iter()
+        .rev()
+        .fold(T::zero(), |result, &coefficient| {
+            result * x + from_f64(coefficient)
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::SrgbFast;
+    use crate::encoding::{Srgb, TransferFn};
+
+    #[test]
+    fn into_linear() {
+        for value in 0..=255u8 {
+            let x = f32::from(value) / 255.0;
+            let fast = <SrgbFast as TransferFn<f32>>::into_linear(x);
+            let exact = <Srgb as TransferFn<f64>>::into_linear(f64::from(x));
+            assert_relative_eq!(f64::from(fast), exact, max_relative = 1.2e-4);
+
+            let back = <Srgb as TransferFn<f64>>::from_linear(f64::from(fast));
+            assert_eq!((back * 255.0).round() as u8, value);
+        }
+    }
+
+    #[test]
+    fn from_linear() {
+        let mut off_by_one = 0;
+
+        for step in 0..=100_000 {
+            let x = step as f32 / 100_000.0;
+            let fast = <SrgbFast as TransferFn<f32>>::from_linear(x);
+            let exact = <Srgb as TransferFn<f64>>::from_linear(f64::from(x));
+            assert_relative_eq!(f64::from(fast), exact, epsilon = 8e-6);
+
+            let fast = (fast * 255.0).round() as i32;
+            let exact = (exact * 255.0).round() as i32;
+            assert!((fast - exact).abs() <= 1);
+            if fast != exact {
+                off_by_one += 1;
+            }
+        }
+
+        // It's only off when the exact value is close to halfway.
+        assert!(off_by_one < 500);
+    }
+
+    #[test]
+    fn out_of_range() {
+        for &x in &[-0.5f64, 1.5, 2.0] {
+            assert_eq!(
+                <SrgbFast as TransferFn<f64>>::into_linear(x),
+                <Srgb as TransferFn<f64>>::into_linear(x)
+            );
+            assert_eq!(
+                <SrgbFast as TransferFn<f64>>::from_linear(x),
+                <Srgb as TransferFn<f64>>::from_linear(x)
+            );
+        }
+    }
+}
@@ -2,8 +2,8 @@ use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 
 use crate::float::Float;
 use crate::{
-    from_f64, FloatComponent, FromF64, Hsluv, Lab, LabHue, Lch, Lchuv, Luv, LuvHue, OklabHue,
-    RgbHue, Xyz, Yxy,
+    from_f64, Cam16Hue, FloatComponent, FromF64, Hsluv, Lab, LabHue, Lch, Lchuv, Luv, LuvHue,
+    OklabHue, RgbHue, Xyz, Yxy,
 };
 
 macro_rules! impl_eq {
@@ -162,3 +162,4 @@ impl_eq_hue!(LabHue);
 impl_eq_hue!(RgbHue);
 impl_eq_hue!(LuvHue);
 impl_eq_hue!(OklabHue);
+impl_eq_hue!(Cam16Hue);
@@ -0,0 +1,589 @@
+//! User-defined color tweaks, written as small expressions.
+//!
+//! An [`Expression`] names a color space from the [`dynamic`](crate::dynamic)
+//! module and gives a formula for each of its components:
+//!
+//! ```
+//! use palette::dynamic::{DynamicColor, Space};
+//! use palette::expression::Expression;
+//!
+//! let tweak: Expression = "oklch(l * 1.1, c, h + 30)".parse().unwrap();
+//!
+//! let color = DynamicColor::new(Space::Srgb, [0.8, 0.3, 0.2]);
+//! let tweaked = tweak.apply(color);
+//! assert_eq!(tweaked.space, Space::Srgb);
+//! ```
+//!
+//! The input color is converted to the named space, the formulas are
+//! evaluated with the components of the converted color, and the result is
+//! converted back to the space of the input. The result is not clamped.
+//!
+//! Components are referred to by their names, as in
+//! [`Space::component_names`], or by the first letter of their names. The
+//! formulas can use numbers, `+`, `-`, `*`, `/`, parentheses, and the
+//! functions `abs(x)`, `min(a, b)`, `max(a, b)` and `clamp(x, low, high)`.
+//! Nothing else is available, so expressions from untrusted sources can't
+//! do more than compute numbers, and parsing rejects expressions that are
+//! too long or nested too deeply.
+//!
+//! This module is only available if the `expression` feature is enabled.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::dynamic::{DynamicColor, Space};
+
+/// The longest input, in bytes, that will be parsed. This also limits the
+/// size of the parsed expression.
+const MAX_LENGTH: usize = 4096;
+
+/// The deepest nesting of parentheses, function calls and negations that an
+/// expression may have.
+const MAX_DEPTH: usize = 32;
+
+/// A parsed color expression. See the [module documentation](self) for the
+/// syntax.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Expression {
+    space: Space,
+    components: [Node; 3],
+}
+
+impl Expression {
+    /// The color space the expression is evaluated in.
+    pub fn space(&self) -> Space {
+        self.space
+    }
+
+    /// Apply the expression to `color`, and return the result in the space
+    /// of `color`.
+    pub fn apply(&self, color: DynamicColor) -> DynamicColor {
+        let input = color.convert(self.space).components;
+        let [first, second, third] = &self.components;
+        let output = [
+            first.evaluate(&input),
+            second.evaluate(&input),
+            third.evaluate(&input),
+        ];
+
+        DynamicColor::new(self.space, output).convert(color.space)
+    }
+
+    /// Apply the expression to each color in `colors`.
+    pub fn apply_slice(&self, colors: &mut [DynamicColor]) {
+        for color in colors {
+            *color = self.apply(*color);
+        }
+    }
+}
+
+impl FromStr for Expression {
+    type Err = ParseExpressionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() > MAX_LENGTH {
+            return Err(ParseExpressionError {
+                kind: ParseExpressionErrorKind::TooLong,
+                position: MAX_LENGTH,
+            });
+        }
+
+        Parser::new(s).parse_expression()
+    }
+}
+
+/// The reason why an expression couldn't be parsed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParseExpressionErrorKind {
+    /// The color space name is not one of the [`Space`] names.
+    UnknownSpace,
+    /// A name is not a component of the color space or a function.
+    UnknownName,
+    /// A number couldn't be parsed.
+    InvalidNumber,
+    /// A character or symbol was not expected at its position.
+    UnexpectedToken,
+    /// The expression ended too early.
+    UnexpectedEnd,
+    /// The color space got the wrong number of components, or a function got
+    /// the wrong number of arguments.
+    WrongArgumentCount,
+    /// The expression is nested too deeply.
+    TooDeep,
+    /// The expression is longer than 4096 bytes.
+    TooLong,
+}
+
+/// An error from parsing an [`Expression`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParseExpressionError {
+    kind: ParseExpressionErrorKind,
+    position: usize,
+}
+
+impl ParseExpressionError {
+    /// The reason for the error.
+    pub fn kind(&self) -> ParseExpressionErrorKind {
+        self.kind
+    }
+
+    /// The byte offset in the input where the error was found.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for ParseExpressionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self.kind {
+            ParseExpressionErrorKind::UnknownSpace => "unknown color space",
+            ParseExpressionErrorKind::UnknownName => "unknown component or function",
+            ParseExpressionErrorKind::InvalidNumber => "invalid number",
+            ParseExpressionErrorKind::UnexpectedToken => "unexpected character",
+            ParseExpressionErrorKind::UnexpectedEnd => "unexpected end of expression",
+            ParseExpressionErrorKind::WrongArgumentCount => "wrong number of arguments",
+            ParseExpressionErrorKind::TooDeep => "expression is nested too deeply",
+            ParseExpressionErrorKind::TooLong => "expression is too long",
+        };
+
+        write!(f, "{} at position {}", description, self.position)
+    }
+}
+
+impl std::error::Error for ParseExpressionError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum BinaryOp {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Function {
+    Abs,
+    Min,
+    Max,
+    Clamp,
+}
+
+impl Function {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "abs" => Some(Function::Abs),
+            "min" => Some(Function::Min),
+            "max" => Some(Function::Max),
+            "clamp" => Some(Function::Clamp),
+            _ => None,
+        }
+    }
+
+    fn arity(self) -> usize {
+        match self {
+            Function::Abs => 1,
+            Function::Min | Function::Max => 2,
+            Function::Clamp => 3,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Node {
+    Number(f64),
+    Component(usize),
+    Negate(Box<Node>),
+    Binary(BinaryOp, Box<Node>, Box<Node>),
+    Call(Function, Vec<Node>),
+}
+
+impl Node {
+    fn evaluate(&self, components: &[f64; 3]) -> f64 {
+        match self {
+            Node::Number(value) => *value,
+            Node::Component(index) => components[*index],
+            Node::Negate(node) => -node.evaluate(components),
+            Node::Binary(op, left, right) => {
+                let left = left.evaluate(components);
+                let right = right.evaluate(components);
+                match op {
+                    BinaryOp::Add => left + right,
+                    BinaryOp::Subtract => left - right,
+                    BinaryOp::Multiply => left * right,
+                    BinaryOp::Divide => left / right,
+                }
+            }
+            Node::Call(function, arguments) => {
+                let argument = |index: usize| arguments[index].evaluate(components);
+                match function {
+                    Function::Abs => argument(0).abs(),
+                    Function::Min => argument(0).min(argument(1)),
+                    Function::Max => argument(0).max(argument(1)),
+                    Function::Clamp => argument(0).max(argument(1)).min(argument(2)),
+                }
+            }
+        }
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+    space: Space,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Parser {
+            input,
+            position: 0,
+            space: Space::Srgb,
+            depth: 0,
+        }
+    }
+
+    fn parse_expression(mut self) -> Result<Expression, ParseExpressionError> {
+        let space_position = self.skip_whitespace();
+        let name = self.identifier()?;
+        self.space = name
+            .parse()
+            .map_err(|_| self.error_at(ParseExpressionErrorKind::UnknownSpace, space_position))?;
+
+        let mut components = self.arguments(3)?.into_iter();
+        if self.peek().is_some() {
+            return Err(self.error(ParseExpressionErrorKind::UnexpectedToken));
+        }
+
+        match (components.next(), components.next(), components.next()) {
+            (Some(first), Some(second), Some(third)) => Ok(Expression {
+                space: self.space,
+                components: [first, second, third],
+            }),
+            _ => unreachable!("the number of components has been checked"),
+        }
+    }
+
+    fn sum(&mut self) -> Result<Node, ParseExpressionError> {
+        let mut node = self.product()?;
+
+        loop {
+            let op = match self.peek() {
+                Some(b'+') => BinaryOp::Add,
+                Some(b'-') => BinaryOp::Subtract,
+                _ => return Ok(node),
+            };
+            self.position += 1;
+            node = Node::Binary(op, Box::new(node), Box::new(self.product()?));
+        }
+    }
+
+    fn product(&mut self) -> Result<Node, ParseExpressionError> {
+        let mut node = self.unary()?;
+
+        loop {
+            let op = match self.peek() {
+                Some(b'*') => BinaryOp::Multiply,
+                Some(b'/') => BinaryOp::Divide,
+                _ => return Ok(node),
+            };
+            self.position += 1;
+            node = Node::Binary(op, Box::new(node), Box::new(self.unary()?));
+        }
+    }
+
+    fn unary(&mut self) -> Result<Node, ParseExpressionError> {
+        match self.peek() {
+            Some(b'-') => {
+                self.position += 1;
+                self.enter()?;
+                let node = Node::Negate(Box::new(self.unary()?));
+                self.depth -= 1;
+                Ok(node)
+            }
+            Some(b'+') => {
+                self.position += 1;
+                self.unary()
+            }
+            _ => self.atom(),
+        }
+    }
+
+    fn atom(&mut self) -> Result<Node, ParseExpressionError> {
+        match self.peek() {
+            Some(b'(') => {
+                self.position += 1;
+                self.enter()?;
+                let node = self.sum()?;
+                self.expect(b')')?;
+                self.depth -= 1;
+                Ok(node)
+            }
+            Some(byte) if byte.is_ascii_digit() || byte == b'.' => self.number(),
+            Some(byte) if is_identifier_start(byte) => {
+                let name_position = self.position;
+                let name = self.identifier()?;
+
+                if let Some(index) = component_index(self.space, name) {
+                    return Ok(Node::Component(index));
+                }
+
+                match Function::from_name(name) {
+                    Some(function) => self.call(function),
+                    None => {
+                        Err(self.error_at(ParseExpressionErrorKind::UnknownName, name_position))
+                    }
+                }
+            }
+            Some(_) => Err(self.error(ParseExpressionErrorKind::UnexpectedToken)),
+            None => Err(self.error(ParseExpressionErrorKind::UnexpectedEnd)),
+        }
+    }
+
+    fn call(&mut self, function: Function) -> Result<Node, ParseExpressionError> {
+        self.enter()?;
+        let arguments = self.arguments(function.arity())?;
+        self.depth -= 1;
+        Ok(Node::Call(function, arguments))
+    }
+
+    /// Parse exactly `count` comma separated arguments within parentheses.
+    fn arguments(&mut self, count: usize) -> Result<Vec<Node>, ParseExpressionError> {
+        self.expect(b'(')?;
+
+        let mut arguments = vec![self.sum()?];
+        while self.peek() == Some(b',') {
+            self.position += 1;
+            arguments.push(self.sum()?);
+        }
+
+        if arguments.len() != count {
+            return Err(self.error(ParseExpressionErrorKind::WrongArgumentCount));
+        }
+
+        self.expect(b')')?;
+        Ok(arguments)
+    }
+
+    fn number(&mut self) -> Result<Node, ParseExpressionError> {
+        let start = self.position;
+        let bytes = self.input.as_bytes();
+        while self.position < bytes.len()
+            && (bytes[self.position].is_ascii_digit() || bytes[self.position] == b'.')
+        {
+            self.position += 1;
+        }
+
+        self.input[start..self.position]
+            .parse()
+            .map(Node::Number)
+            .map_err(|_| self.error_at(ParseExpressionErrorKind::InvalidNumber, start))
+    }
+
+    fn identifier(&mut self) -> Result<&'a str, ParseExpressionError> {
+        let start = self.position;
+        let bytes = self.input.as_bytes();
+
+        match bytes.get(start) {
+            Some(&byte) if is_identifier_start(byte) => {}
+            Some(_) => return Err(self.error(ParseExpressionErrorKind::UnexpectedToken)),
+            None => return Err(self.error(ParseExpressionErrorKind::UnexpectedEnd)),
+        }
+
+        while self.position < bytes.len()
+            && (is_identifier_start(bytes[self.position]) || bytes[self.position].is_ascii_digit())
+        {
+            self.position += 1;
+        }
+
+        Ok(&self.input[start..self.position])
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<(), ParseExpressionError> {
+        match self.peek() {
+            Some(byte) if byte == expected => {
+                self.position += 1;
+                Ok(())
+            }
+            Some(_) => Err(self.error(ParseExpressionErrorKind::UnexpectedToken)),
+            None => Err(self.error(ParseExpressionErrorKind::UnexpectedEnd)),
+        }
+    }
+
+    fn enter(&mut self) -> Result<(), ParseExpressionError> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            Err(self.error(ParseExpressionErrorKind::TooDeep))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Skip whitespace and return the next byte, without consuming it.
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.input.as_bytes().get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) -> usize {
+        let bytes = self.input.as_bytes();
+        while self.position < bytes.len() && bytes[self.position].is_ascii_whitespace() {
+            self.position += 1;
+        }
+
+        self.position
+    }
+
+    fn error(&self, kind: ParseExpressionErrorKind) -> ParseExpressionError {
+        self.error_at(kind, self.position)
+    }
+
+    fn error_at(&self, kind: ParseExpressionErrorKind, position: usize) -> ParseExpressionError {
+        ParseExpressionError { kind, position }
+    }
+}
+
+fn is_identifier_start(byte: u8) -> bool {
+    byte.is_ascii_alphabetic() || byte == b'_'
+}
+
+fn component_index(space: Space, name: &str) -> Option<usize> {
+    space.component_names().iter().position(|component| {
+        *component == name || (name.len() == 1 && component.as_bytes()[0] == name.as_bytes()[0])
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Expression, ParseExpressionErrorKind};
+    use crate::dynamic::{ColorOp, DynamicColor, Space};
+
+    fn parse(input: &str) -> Expression {
+        input.parse().unwrap()
+    }
+
+    fn error(input: &str) -> (ParseExpressionErrorKind, usize) {
+        let error = input.parse::<Expression>().unwrap_err();
+        (error.kind(), error.position())
+    }
+
+    #[test]
+    fn identity() {
+        let color = DynamicColor::new(Space::Hsl, [120.0, 0.5, 0.4]);
+        let result = parse("hsl(hue, saturation, lightness)").apply(color);
+        assert_eq!(result, color);
+
+        let result = parse("hsl(h, s, l)").apply(color);
+        assert_eq!(result, color);
+    }
+
+    #[test]
+    fn oklch_tweak() {
+        let color = DynamicColor::new(Space::Oklch, [0.5, 0.1, 40.0]);
+        let result = parse("oklch(l*1.1, c, h+30)").apply(color);
+
+        assert_relative_eq!(result.components[0], 0.55, epsilon = 1e-12);
+        assert_relative_eq!(result.components[1], 0.1, epsilon = 1e-12);
+        assert_relative_eq!(result.components[2], 70.0, epsilon = 1e-12);
+
+        let shifted = ColorOp::ShiftHue { degrees: 30.0 }.apply(color);
+        assert_relative_eq!(
+            parse("oklch(l, c, h + 30)").apply(color).components[2],
+            shifted.components[2],
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn converts_back() {
+        let color = DynamicColor::new(Space::Srgb, [0.8, 0.3, 0.2]);
+        let result = parse("lab(l, a, b)").apply(color);
+
+        assert_eq!(result.space, Space::Srgb);
+        for (&value, &expected) in result.components.iter().zip(&color.components) {
+            assert_relative_eq!(value, expected, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn arithmetic() {
+        let color = DynamicColor::new(Space::LinSrgb, [0.2, 0.4, 0.6]);
+        let result = parse("linear_srgb(1 - r * 2, -(g + .1) / 2, --b)").apply(color);
+        assert_relative_eq!(result.components[0], 0.6, epsilon = 1e-12);
+        assert_relative_eq!(result.components[1], -0.25, epsilon = 1e-12);
+        assert_relative_eq!(result.components[2], 0.6, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn functions() {
+        let color = DynamicColor::new(Space::LinSrgb, [-0.2, 0.4, 1.6]);
+        let result = parse("linear_srgb(abs(r), min(g, max(r, 0.3)), clamp(b, 0, 1))").apply(color);
+        assert_relative_eq!(result.components[0], 0.2, epsilon = 1e-12);
+        assert_relative_eq!(result.components[1], 0.3, epsilon = 1e-12);
+        assert_relative_eq!(result.components[2], 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            error("rgba(r, g, b)"),
+            (ParseExpressionErrorKind::UnknownSpace, 0)
+        );
+        assert_eq!(
+            error("srgb(r, g, x)"),
+            (ParseExpressionErrorKind::UnknownName, 11)
+        );
+        assert_eq!(
+            error("srgb(r, g)"),
+            (ParseExpressionErrorKind::WrongArgumentCount, 9)
+        );
+        assert_eq!(
+            error("srgb(r, g, b, r)"),
+            (ParseExpressionErrorKind::WrongArgumentCount, 15)
+        );
+        assert_eq!(
+            error("srgb(r, g, min(b))"),
+            (ParseExpressionErrorKind::WrongArgumentCount, 16)
+        );
+        assert_eq!(
+            error("srgb(r, 1.2.3, b)"),
+            (ParseExpressionErrorKind::InvalidNumber, 8)
+        );
+        assert_eq!(
+            error("srgb(r, g, b"),
+            (ParseExpressionErrorKind::UnexpectedEnd, 12)
+        );
+        assert_eq!(
+            error("srgb(r, g, b) r"),
+            (ParseExpressionErrorKind::UnexpectedToken, 14)
+        );
+        assert_eq!(
+            error("srgb(r, g * , b)"),
+            (ParseExpressionErrorKind::UnexpectedToken, 12)
+        );
+    }
+
+    #[test]
+    fn too_deep() {
+        let deep = format!("srgb(r, g, {}b{})", "(".repeat(40), ")".repeat(40));
+        assert_eq!(error(&deep).0, ParseExpressionErrorKind::TooDeep);
+
+        let shallow = format!("srgb(r, g, {}b{})", "(".repeat(20), ")".repeat(20));
+        assert!(shallow.parse::<Expression>().is_ok());
+
+        let negations = format!("srgb(r, g, {}b)", "-".repeat(100));
+        assert_eq!(error(&negations).0, ParseExpressionErrorKind::TooDeep);
+
+        let long = format!("srgb(r, g, b{})", "+b".repeat(3000));
+        assert_eq!(error(&long), (ParseExpressionErrorKind::TooLong, 4096));
+    }
+
+    #[test]
+    fn display_error() {
+        let error = "srgb(r, g, x)".parse::<Expression>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unknown component or function at position 11"
+        );
+    }
+}
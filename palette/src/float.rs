@@ -3,6 +3,9 @@
 //! This module will just re-export the currently used floating point trait.
 //! Both for use in derive macros and for anyone who don't want to add it as an
 //! additional dependency.
+//!
+//! The [`fast`] module has reduced precision approximations of some of the
+//! math functions, for custom conversions on slow targets.
 
 #[cfg(any(feature = "std", feature = "libm"))]
 pub use num_traits::Float;
@@ -11,3 +14,198 @@ pub use num_traits::Float;
 compile_error!(
     "The palette crate needs a float library. Please enable the \"std\" or \"libm\" feature."
 );
+
+#[cfg(feature = "deterministic")]
+mod deterministic;
+pub mod fast;
+
+// The functions below are used for the non-trivial math in color
+// conversions. They forward to `Float` by default, and to the crate's own
+// implementations in `deterministic` when the `deterministic` feature is
+// enabled. The latter are computed in `f64` and then converted back to `T`.
+
+#[cfg(feature = "deterministic")]
+#[inline]
+fn via_f64<T: Float>(x: T, f: impl FnOnce(f64) -> f64) -> T {
+    T::from(f(x.to_f64().unwrap())).unwrap()
+}
+
+#[cfg(feature = "deterministic")]
+#[inline]
+fn via_f64_2<T: Float>(x: T, y: T, f: impl FnOnce(f64, f64) -> f64) -> T {
+    T::from(f(x.to_f64().unwrap(), y.to_f64().unwrap())).unwrap()
+}
+
+/// Compute `x^y`.
+#[inline]
+pub(crate) fn powf<T: Float>(x: T, y: T) -> T {
+    #[cfg(feature = "deterministic")]
+    {
+        via_f64_2(x, y, deterministic::powf)
+    }
+    #[cfg(not(feature = "deterministic"))]
+    {
+        x.powf(y)
+    }
+}
+
+/// Compute `x^n`.
+#[inline]
+pub(crate) fn powi<T: Float>(x: T, n: i32) -> T {
+    #[cfg(feature = "deterministic")]
+    {
+        deterministic::powi(x, n)
+    }
+    #[cfg(not(feature = "deterministic"))]
+    {
+        x.powi(n)
+    }
+}
+
+/// Compute the cube root of `x`.
+#[inline]
+pub(crate) fn cbrt<T: Float>(x: T) -> T {
+    #[cfg(feature = "deterministic")]
+    {
+        via_f64(x, deterministic::cbrt)
+    }
+    #[cfg(not(feature = "deterministic"))]
+    {
+        x.cbrt()
+    }
+}
+
+/// Compute `e^x`.
+#[inline]
+pub(crate) fn exp<T: Float>(x: T) -> T {
+    #[cfg(feature = "deterministic")]
+    {
+        via_f64(x, deterministic::exp)
+    }
+    #[cfg(not(feature = "deterministic"))]
+    {
+        x.exp()
+    }
+}
+
+/// Compute the sine of `x`, in radians.
+#[inline]
+pub(crate) fn sin<T: Float>(x: T) -> T {
+    #[cfg(feature = "deterministic")]
+    {
+        via_f64(x, deterministic::sin)
+    }
+    #[cfg(not(feature = "deterministic"))]
+    {
+        x.sin()
+    }
+}
+
+/// Compute the cosine of `x`, in radians.
+#[inline]
+pub(crate) fn cos<T: Float>(x: T) -> T {
+    #[cfg(feature = "deterministic")]
+    {
+        via_f64(x, deterministic::cos)
+    }
+    #[cfg(not(feature = "deterministic"))]
+    {
+        x.cos()
+    }
+}
+
+/// Compute the sine and cosine of `x`, in radians.
+#[inline]
+pub(crate) fn sin_cos<T: Float>(x: T) -> (T, T) {
+    #[cfg(feature = "deterministic")]
+    {
+        let (sin, cos) = deterministic::sin_cos(x.to_f64().unwrap());
+        (T::from(sin).unwrap(), T::from(cos).unwrap())
+    }
+    #[cfg(not(feature = "deterministic"))]
+    {
+        x.sin_cos()
+    }
+}
+
+/// Compute the four quadrant arctangent of `y` and `x`, in radians.
+#[inline]
+pub(crate) fn atan2<T: Float>(y: T, x: T) -> T {
+    #[cfg(feature = "deterministic")]
+    {
+        via_f64_2(y, x, deterministic::atan2)
+    }
+    #[cfg(not(feature = "deterministic"))]
+    {
+        y.atan2(x)
+    }
+}
+
+/// Compute `sqrt(x^2 + y^2)`.
+#[inline]
+pub(crate) fn hypot<T: Float>(x: T, y: T) -> T {
+    #[cfg(feature = "deterministic")]
+    {
+        via_f64_2(x, y, deterministic::hypot)
+    }
+    #[cfg(not(feature = "deterministic"))]
+    {
+        x.hypot(y)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    // These check the math functions against reference values, rather than
+    // against `std`, so they verify whichever backend is currently in use:
+    // `std`, `libm` or `deterministic`.
+
+    #[test]
+    fn powf() {
+        assert_relative_eq!(super::powf(0.5f32, 2.4), 0.18946457);
+        assert_relative_eq!(super::powf(0.5f64, 1.0 / 2.4), 0.7491535384383408);
+        assert_relative_eq!(super::powf(0.2f64, 2.2), 0.028991186547107816);
+        assert_eq!(super::powf(0.0f32, 2.4), 0.0);
+        assert_eq!(super::powf(1.0f64, 2.4), 1.0);
+    }
+
+    #[test]
+    fn powi() {
+        assert_eq!(super::powi(0.5f32, 3), 0.125);
+        assert_relative_eq!(super::powi(6.0f64 / 29.0, 3), 0.008856451679035631);
+    }
+
+    #[test]
+    fn cbrt() {
+        assert_relative_eq!(super::cbrt(8.0f32), 2.0);
+        assert_relative_eq!(super::cbrt(0.5f64), 0.7937005259840998);
+        assert_relative_eq!(super::cbrt(-0.001f64), -0.1);
+    }
+
+    #[test]
+    fn exp() {
+        assert_eq!(super::exp(0.0f32), 1.0);
+        assert_relative_eq!(super::exp(1.0f64), core::f64::consts::E);
+        assert_relative_eq!(super::exp(-2.5f64), 0.0820849986238988);
+    }
+
+    #[test]
+    fn trigonometry() {
+        use core::f64::consts::{FRAC_PI_2, FRAC_PI_6, PI};
+
+        assert_relative_eq!(super::sin(FRAC_PI_6), 0.5, epsilon = 1e-15);
+        assert_relative_eq!(super::cos(PI), -1.0, epsilon = 1e-15);
+        let (sin, cos) = super::sin_cos(FRAC_PI_2);
+        assert_relative_eq!(sin, 1.0, epsilon = 1e-15);
+        assert_relative_eq!(cos, 0.0, epsilon = 1e-15);
+
+        assert_relative_eq!(super::atan2(1.0f64, 1.0), PI / 4.0, epsilon = 1e-15);
+        assert_relative_eq!(
+            super::atan2(-1.0f32, -1.0),
+            -3.0 * core::f32::consts::PI / 4.0
+        );
+        assert_eq!(super::atan2(0.0f64, 1.0), 0.0);
+
+        assert_eq!(super::hypot(3.0f32, 4.0), 5.0);
+    }
+}
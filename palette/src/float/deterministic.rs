@@ -0,0 +1,482 @@
+//! Deterministic implementations of the transcendental functions that are
+//! used in color conversions.
+//!
+//! The functions in `std` and `libm` are allowed to differ between platforms
+//! and versions, since they are not required to be correctly rounded. These
+//! are implemented using only basic arithmetic and `sqrt`, which are exactly
+//! specified by IEEE 754, so they produce the same bits everywhere. They are
+//! accurate to within a few ULP in `f64`, which makes the results practically
+//! always correctly rounded when narrowed to `f32`.
+
+use core::f64::consts::{FRAC_2_PI, FRAC_PI_2, FRAC_PI_4, LOG2_E, PI, SQRT_2};
+
+use num_traits::Float;
+
+const LN2_HI: f64 = 6.931471803691238e-1;
+const LN2_LO: f64 = 1.9082149292705877e-10;
+
+const PIO2_1: f64 = 1.5707963267341256;
+const PIO2_2: f64 = 6.077100506303966e-11;
+const PIO2_3: f64 = 2.0222662487111665e-21;
+
+const PI_LO: f64 = 1.2246467991473532e-16;
+
+const ATAN_HI: [f64; 4] = [0.4636476090008061, FRAC_PI_4, 0.982793723247329, FRAC_PI_2];
+const ATAN_LO: [f64; 4] = [
+    2.2698777452961687e-17,
+    3.061616997868383e-17,
+    1.3903311031230998e-17,
+    6.123233995736766e-17,
+];
+
+/// Round to the nearest integer, with ties away from zero.
+#[inline]
+fn round_to_int(x: f64) -> i64 {
+    if x < 0.0 {
+        (x - 0.5) as i64
+    } else {
+        (x + 0.5) as i64
+    }
+}
+
+/// Multiply `x` by `2^k`.
+fn scale_by_pow2(mut x: f64, mut k: i64) -> f64 {
+    // Keep each factor a normal number.
+    while k > 1023 {
+        x *= f64::from_bits(2046 << 52);
+        k -= 1023;
+    }
+    while k < -1022 {
+        x *= f64::from_bits(1 << 52);
+        k += 1022;
+    }
+
+    x * f64::from_bits(((k + 1023) as u64) << 52)
+}
+
+/// Compute `e^x`.
+pub(crate) fn exp(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    if x > 709.782712893384 {
+        return f64::INFINITY;
+    }
+    if x < -745.1332191019412 {
+        return 0.0;
+    }
+
+    let k = round_to_int(x * LOG2_E);
+    let kf = k as f64;
+    let r = (x - kf * LN2_HI) - kf * LN2_LO;
+
+    // Taylor series for e^r, where |r| <= ln(2)/2.
+    let mut sum = 1.0;
+    let mut n = 17.0;
+    while n > 0.0 {
+        sum = 1.0 + sum * r / n;
+        n -= 1.0;
+    }
+
+    scale_by_pow2(sum, k)
+}
+
+/// Compute the natural logarithm of `x`.
+pub(crate) fn ln(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if x.is_infinite() {
+        return x;
+    }
+
+    let mut bits = x.to_bits();
+    let mut exponent = 0i64;
+
+    // Normalize subnormal numbers.
+    if bits >> 52 == 0 {
+        bits = (x * f64::from_bits(1077 << 52)).to_bits();
+        exponent -= 54;
+    }
+
+    exponent += ((bits >> 52) as i64) - 1023;
+    let mut m = f64::from_bits((bits & ((1 << 52) - 1)) | (1023 << 52));
+
+    if m > SQRT_2 {
+        m *= 0.5;
+        exponent += 1;
+    }
+
+    // ln(m) = 2 * atanh(s), where |s| <= 0.172.
+    let s = (m - 1.0) / (m + 1.0);
+    let s2 = s * s;
+    let mut sum = 0.0;
+    let mut n = 23.0;
+    while n > 1.0 {
+        sum = (sum + 1.0 / n) * s2;
+        n -= 2.0;
+    }
+    let ln_m = 2.0 * s + 2.0 * s * sum;
+
+    let e = exponent as f64;
+    e * LN2_HI + (e * LN2_LO + ln_m)
+}
+
+/// Check if `x` is an integer.
+#[inline]
+fn is_integer(x: f64) -> bool {
+    x.abs() >= 4503599627370496.0 || (x as i64) as f64 == x
+}
+
+/// Compute `x^y`.
+pub(crate) fn powf(x: f64, y: f64) -> f64 {
+    if y == 0.0 || x == 1.0 {
+        return 1.0;
+    }
+    if x.is_nan() || y.is_nan() {
+        return f64::NAN;
+    }
+
+    if x < 0.0 || (x == 0.0 && x.is_sign_negative()) {
+        if !is_integer(y) {
+            return f64::NAN;
+        }
+
+        let odd = y.abs() < 9007199254740992.0 && (y as i64) % 2 != 0;
+        let result = powf(-x, y);
+
+        return if odd { -result } else { result };
+    }
+
+    if is_integer(y) && y.abs() <= 1024.0 {
+        return powi(x, y as i32);
+    }
+
+    if x == 0.0 {
+        return if y > 0.0 { 0.0 } else { f64::INFINITY };
+    }
+    if x.is_infinite() {
+        return if y > 0.0 { f64::INFINITY } else { 0.0 };
+    }
+
+    exp(y * ln(x))
+}
+
+/// Compute `x^n` by repeated squaring.
+pub(crate) fn powi<T: Float>(x: T, n: i32) -> T {
+    let mut base = x;
+    let mut exponent = n.unsigned_abs();
+    let mut result = T::one();
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base;
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            base = base * base;
+        }
+    }
+
+    if n < 0 {
+        T::one() / result
+    } else {
+        result
+    }
+}
+
+/// Compute the cube root of `x`.
+pub(crate) fn cbrt(x: f64) -> f64 {
+    if x == 0.0 || x.is_nan() || x.is_infinite() {
+        return x;
+    }
+
+    let a = x.abs();
+    let mut y = exp(ln(a) / 3.0);
+
+    // Polish the estimate with a Newton-Raphson step.
+    y -= (y * y * y - a) / (3.0 * y * y);
+
+    if x < 0.0 {
+        -y
+    } else {
+        y
+    }
+}
+
+/// Reduce `x` to `r` in `[-pi/4, pi/4]`, where `x = r + n * pi/2`, and return
+/// `(r, n mod 4)`.
+fn reduce_pio2(x: f64) -> (f64, u8) {
+    let n = round_to_int(x * FRAC_2_PI);
+    let nf = n as f64;
+    let r = ((x - nf * PIO2_1) - nf * PIO2_2) - nf * PIO2_3;
+
+    (r, (n & 3) as u8)
+}
+
+/// Taylor series for `sin(r)`, where `|r| <= pi/4`.
+fn sin_kernel(r: f64) -> f64 {
+    let r2 = r * r;
+    let mut sum = 1.0;
+    let mut n = 19.0;
+    while n > 1.0 {
+        sum = 1.0 - sum * r2 / (n * (n - 1.0));
+        n -= 2.0;
+    }
+
+    r * sum
+}
+
+/// Taylor series for `cos(r)`, where `|r| <= pi/4`.
+fn cos_kernel(r: f64) -> f64 {
+    let r2 = r * r;
+    let mut sum = 1.0;
+    let mut n = 20.0;
+    while n > 0.0 {
+        sum = 1.0 - sum * r2 / (n * (n - 1.0));
+        n -= 2.0;
+    }
+
+    sum
+}
+
+/// Compute the sine and cosine of `x`.
+pub(crate) fn sin_cos(x: f64) -> (f64, f64) {
+    if x.is_nan() || x.is_infinite() {
+        return (f64::NAN, f64::NAN);
+    }
+
+    let (r, quadrant) = reduce_pio2(x);
+    let (sin, cos) = (sin_kernel(r), cos_kernel(r));
+
+    match quadrant {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    }
+}
+
+/// Compute the sine of `x`.
+#[inline]
+pub(crate) fn sin(x: f64) -> f64 {
+    sin_cos(x).0
+}
+
+/// Compute the cosine of `x`.
+#[inline]
+pub(crate) fn cos(x: f64) -> f64 {
+    sin_cos(x).1
+}
+
+/// Compute the arctangent of `x`.
+fn atan(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+
+    let a = x.abs();
+
+    let (offset, t) = if a >= 7.378697629483821e19 {
+        (Some(3), 0.0)
+    } else if a < 0.4375 {
+        (None, a)
+    } else if a < 0.6875 {
+        (Some(0), (2.0 * a - 1.0) / (2.0 + a))
+    } else if a < 1.1875 {
+        (Some(1), (a - 1.0) / (a + 1.0))
+    } else if a < 2.4375 {
+        (Some(2), (a - 1.5) / (1.0 + 1.5 * a))
+    } else {
+        (Some(3), -1.0 / a)
+    };
+
+    // Taylor series for atan(t), where |t| <= 0.4375.
+    let t2 = t * t;
+    let mut sum = 0.0;
+    let mut n = 49.0;
+    while n > 1.0 {
+        sum = (1.0 / n - sum) * t2;
+        n -= 2.0;
+    }
+    let atan_t = t - t * sum;
+
+    let result = match offset {
+        Some(i) => ATAN_HI[i] + (atan_t + ATAN_LO[i]),
+        None => atan_t,
+    };
+
+    if x < 0.0 {
+        -result
+    } else {
+        result
+    }
+}
+
+/// Compute the four quadrant arctangent of `y` and `x`.
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+    if x.is_nan() || y.is_nan() {
+        return f64::NAN;
+    }
+
+    let angle = if y == 0.0 {
+        if x.is_sign_negative() {
+            PI
+        } else {
+            0.0
+        }
+    } else if x == 0.0 {
+        FRAC_PI_2
+    } else if x.is_infinite() {
+        if y.is_infinite() {
+            if x > 0.0 {
+                ATAN_HI[1]
+            } else {
+                3.0 * ATAN_HI[1]
+            }
+        } else if x > 0.0 {
+            0.0
+        } else {
+            PI
+        }
+    } else if y.is_infinite() {
+        FRAC_PI_2
+    } else {
+        let a = atan((y / x).abs());
+        if x > 0.0 {
+            a
+        } else {
+            PI - (a - PI_LO)
+        }
+    };
+
+    if y.is_sign_negative() {
+        -angle
+    } else {
+        angle
+    }
+}
+
+/// Compute `sqrt(x^2 + y^2)` without intermediate overflow.
+pub(crate) fn hypot(x: f64, y: f64) -> f64 {
+    if x.is_infinite() || y.is_infinite() {
+        return f64::INFINITY;
+    }
+    if x.is_nan() || y.is_nan() {
+        return f64::NAN;
+    }
+
+    let (x, y) = (x.abs(), y.abs());
+    let (max, min) = if x > y { (x, y) } else { (y, x) };
+
+    if max == 0.0 {
+        return 0.0;
+    }
+
+    let ratio = min / max;
+    max * Float::sqrt(1.0 + ratio * ratio)
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn exp() {
+        for &x in &[-700.0, -20.5, -1.0, -1e-10, 0.0, 0.3, 1.0, 2.5, 88.7, 709.0] {
+            let expected: f64 = f64::exp(x);
+            assert_relative_eq!(super::exp(x), expected, max_relative = 1e-15);
+        }
+
+        assert_eq!(super::exp(0.0), 1.0);
+        assert_eq!(super::exp(1000.0), f64::INFINITY);
+        assert_eq!(super::exp(-1000.0), 0.0);
+    }
+
+    #[test]
+    fn ln() {
+        for &x in &[1e-310, 1e-20, 0.001, 0.5, 0.99, 1.0, 1.5, 2.0, 10.0, 1e300] {
+            let expected: f64 = f64::ln(x);
+            assert_relative_eq!(super::ln(x), expected, max_relative = 1e-15);
+        }
+
+        assert_eq!(super::ln(1.0), 0.0);
+        assert_eq!(super::ln(0.0), f64::NEG_INFINITY);
+        assert!(super::ln(-1.0).is_nan());
+    }
+
+    #[test]
+    fn powf() {
+        for &x in &[0.0001, 0.04, 0.5, 0.9, 1.0, 3.0, 100.0] {
+            for &y in &[-3.0, 1.0 / 2.4, 1.0 / 3.0, 2.2, 2.4, 7.0] {
+                let expected: f64 = f64::powf(x, y);
+                assert_relative_eq!(super::powf(x, y), expected, max_relative = 1e-14);
+            }
+        }
+
+        assert_eq!(super::powf(0.0, 2.4), 0.0);
+        assert_eq!(super::powf(-2.0, 3.0), -8.0);
+        assert!(super::powf(-2.0, 0.5).is_nan());
+    }
+
+    #[test]
+    fn powi() {
+        assert_eq!(super::powi(3.0f64, 3), 27.0);
+        assert_eq!(super::powi(2.0f32, -2), 0.25);
+        assert_eq!(super::powi(1.5f64, 0), 1.0);
+        assert_relative_eq!(
+            super::powi(1.1f64, 7),
+            f64::powi(1.1, 7),
+            max_relative = 1e-15
+        );
+    }
+
+    #[test]
+    fn cbrt() {
+        for &x in &[-27.0, -0.5, 1e-300, 0.008856, 0.5, 1.0, 8.0, 1e300] {
+            let expected: f64 = f64::cbrt(x);
+            assert_relative_eq!(super::cbrt(x), expected, max_relative = 1e-15);
+        }
+
+        assert_eq!(super::cbrt(0.0), 0.0);
+        assert_eq!(super::cbrt(27.0), 3.0);
+    }
+
+    #[test]
+    fn sin_cos() {
+        for i in -100..=100 {
+            let x = i as f64 * 0.173;
+            let (sin, cos) = super::sin_cos(x);
+            assert_abs_diff_eq!(sin, f64::sin(x), epsilon = 1e-15);
+            assert_abs_diff_eq!(cos, f64::cos(x), epsilon = 1e-15);
+        }
+
+        let angle = 300.0f64.to_radians();
+        assert_abs_diff_eq!(super::sin(angle), f64::sin(angle), epsilon = 1e-15);
+        assert_abs_diff_eq!(super::cos(angle), f64::cos(angle), epsilon = 1e-15);
+    }
+
+    #[test]
+    fn atan2() {
+        for i in -20..=20 {
+            for j in -20..=20 {
+                let (y, x) = (i as f64 * 0.37, j as f64 * 0.41);
+                let expected: f64 = f64::atan2(y, x);
+                assert_abs_diff_eq!(super::atan2(y, x), expected, epsilon = 1e-15);
+            }
+        }
+
+        assert_eq!(super::atan2(0.0, -1.0), core::f64::consts::PI);
+        assert_eq!(super::atan2(-0.0, -1.0), -core::f64::consts::PI);
+        assert_eq!(super::atan2(1.0, 0.0), core::f64::consts::FRAC_PI_2);
+        assert_eq!(super::atan2(1e200, 1e-200), core::f64::consts::FRAC_PI_2);
+    }
+
+    #[test]
+    fn hypot() {
+        assert_eq!(super::hypot(3.0, 4.0), 5.0);
+        assert_eq!(super::hypot(0.0, -0.0), 0.0);
+        assert_relative_eq!(super::hypot(1e300, 1e300), f64::hypot(1e300, 1e300));
+    }
+}
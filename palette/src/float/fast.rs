@@ -0,0 +1,318 @@
+//! Fast, reduced precision approximations of `cbrt`, `powf`, `atan2` and
+//! `sin_cos`.
+//!
+//! These are meant for targets where the exact functions dominate the cost
+//! of a conversion, such as microcontrollers without a floating point unit.
+//! They are computed in `f32`, using only basic arithmetic, and have the
+//! following maximum relative errors for normal, positive inputs:
+//!
+//! * `cbrt`: `3e-7`.
+//! * `powf`: `2e-6`, for exponents in `[-3.0, 3.0]` and results within the
+//!   normal `f32` range.
+//!
+//! `atan2` and `sin_cos` have a maximum absolute error of `1e-6` radians,
+//! and `sin_cos` is meant for angles in `[-2π, 2π]`.
+//!
+//! The color conversions in this crate always use the exact functions. These
+//! are for custom conversions, where the reduced precision is acceptable.
+//! The sRGB transfer function has its own approximation in
+//! [`SrgbFast`](crate::encoding::SrgbFast).
+//!
+//! ```
+//! use palette::float::fast;
+//!
+//! // Approximate CIE L* for a relative luminance of 0.2.
+//! let l = 116.0 * fast::cbrt(0.2) - 16.0;
+//! assert!((l - 51.8372).abs() < 1e-3);
+//! ```
+
+#[allow(unused)]
+use num_traits::Float;
+
+const SQRT_2: f32 = core::f32::consts::SQRT_2;
+const PI: f32 = core::f32::consts::PI;
+const FRAC_PI_2: f32 = core::f32::consts::FRAC_PI_2;
+const FRAC_PI_4: f32 = core::f32::consts::FRAC_PI_4;
+const FRAC_2_PI: f32 = core::f32::consts::FRAC_2_PI;
+
+/// The part of `π/2` that doesn't fit in `FRAC_PI_2`.
+const FRAC_PI_2_LOW: f32 = -4.371_139e-8;
+
+/// `tan(π/8)`.
+const TAN_FRAC_PI_8: f32 = 0.414_213_57;
+
+/// `2^24` and `2^-8`, for normalizing subnormal numbers.
+const TWO_POW_24: f32 = 16_777_216.0;
+const TWO_POW_MINUS_8: f32 = 0.003_906_25;
+
+// Polynomial approximation of `log2((1 + s) / (1 - s)) / s`, in `s^2`, for
+// `|s| <= 0.172`.
+const LOG2_0: f32 = 2.885_390_4;
+const LOG2_1: f32 = 0.961_588_4;
+const LOG2_2: f32 = 0.595_778_06;
+
+// Polynomial approximation of `2^f`, for `f` in `[0.0, 1.0)`.
+const EXP2_0: f32 = 0.999_999_9;
+const EXP2_1: f32 = 0.693_152_97;
+const EXP2_2: f32 = 0.240_154_53;
+const EXP2_3: f32 = 0.055_823_604;
+const EXP2_4: f32 = 0.008_992_584;
+const EXP2_5: f32 = 0.001_876_233;
+
+// Polynomial approximation of `(atan(x) - x) / x^3`, in `x^2`, for
+// `|x| <= tan(π/8)`.
+const ATAN_0: f32 = -0.333_329_5;
+const ATAN_1: f32 = 0.199_777_1;
+const ATAN_2: f32 = -0.138_776_86;
+const ATAN_3: f32 = 0.080_537_44;
+
+// Polynomial approximations of `sin(x)` and `cos(x)`, in `x^2`, for
+// `|x| <= π/4`.
+const SIN_0: f32 = -0.166_666_55;
+const SIN_1: f32 = 0.008_332_161;
+const SIN_2: f32 = -0.000_195_152_96;
+const COS_0: f32 = 0.041_666_646;
+const COS_1: f32 = -0.001_388_731_6;
+const COS_2: f32 = 0.000_024_433_157;
+
+/// Approximate the cube root of `x`, with a maximum relative error of
+/// `3e-7`.
+pub fn cbrt(x: f32) -> f32 {
+    if x == 0.0 || x.is_nan() || x.is_infinite() {
+        return x;
+    }
+
+    let mut a = x.abs();
+    let mut scale = 1.0;
+
+    if a < f32::MIN_POSITIVE {
+        a *= TWO_POW_24;
+        scale = TWO_POW_MINUS_8;
+    }
+
+    // Divide the exponent by 3 for an estimate within 4%, and refine it with
+    // two steps of Halley's method.
+    let mut y = f32::from_bits(a.to_bits() / 3 + 0x2a51_37a0);
+    for _ in 0..2 {
+        let y3 = y * y * y;
+        y *= (y3 + 2.0 * a) / (2.0 * y3 + a);
+    }
+    y *= scale;
+
+    if x < 0.0 {
+        -y
+    } else {
+        y
+    }
+}
+
+/// Approximate the base 2 logarithm of `x`, where `x` is positive and
+/// finite.
+fn log2(x: f32) -> f32 {
+    let mut bits = x.to_bits();
+    let mut exponent = 0;
+
+    if x < f32::MIN_POSITIVE {
+        bits = (x * TWO_POW_24).to_bits();
+        exponent -= 24;
+    }
+
+    exponent += ((bits >> 23) & 0xff) as i32 - 127;
+    let mut m = f32::from_bits((bits & 0x007f_ffff) | 0x3f80_0000);
+
+    if m > SQRT_2 {
+        m *= 0.5;
+        exponent += 1;
+    }
+
+    let s = (m - 1.0) / (m + 1.0);
+    let s2 = s * s;
+
+    exponent as f32 + s * (LOG2_0 + s2 * (LOG2_1 + s2 * LOG2_2))
+}
+
+/// Approximate `2^x`.
+fn exp2(x: f32) -> f32 {
+    if x.is_nan() {
+        return x;
+    }
+    if x >= 128.0 {
+        return f32::INFINITY;
+    }
+    if x < -126.0 {
+        return 0.0;
+    }
+
+    let mut i = x as i32;
+    if i as f32 > x {
+        i -= 1;
+    }
+    let f = x - i as f32;
+
+    let p = EXP2_0 + f * (EXP2_1 + f * (EXP2_2 + f * (EXP2_3 + f * (EXP2_4 + f * EXP2_5))));
+
+    p * f32::from_bits(((i + 127) as u32) << 23)
+}
+
+/// Approximate `x^y`, where `x` is not negative, with a maximum relative
+/// error of `2e-6` for `y` in `[-3.0, 3.0]`. The result is NaN if `x` is
+/// negative.
+pub fn powf(x: f32, y: f32) -> f32 {
+    if y == 0.0 || x == 1.0 {
+        return 1.0;
+    }
+    if x.is_nan() || y.is_nan() || x < 0.0 {
+        return f32::NAN;
+    }
+    if x == 0.0 {
+        return if y > 0.0 { 0.0 } else { f32::INFINITY };
+    }
+    if x.is_infinite() {
+        return if y > 0.0 { f32::INFINITY } else { 0.0 };
+    }
+
+    exp2(y * log2(x))
+}
+
+/// Approximate `atan(x)`, for `x` in `[0.0, 1.0]`.
+fn atan_unit(x: f32) -> f32 {
+    let (offset, x) = if x > TAN_FRAC_PI_8 {
+        (FRAC_PI_4, (x - 1.0) / (x + 1.0))
+    } else {
+        (0.0, x)
+    };
+
+    let x2 = x * x;
+    offset + x + x * x2 * (ATAN_0 + x2 * (ATAN_1 + x2 * (ATAN_2 + x2 * ATAN_3)))
+}
+
+/// Approximate the four quadrant arctangent of `y` and `x`, in radians,
+/// with a maximum absolute error of `1e-6`.
+pub fn atan2(y: f32, x: f32) -> f32 {
+    if x.is_nan() || y.is_nan() {
+        return f32::NAN;
+    }
+
+    let (x_abs, y_abs) = (x.abs(), y.abs());
+    if x_abs == 0.0 && y_abs == 0.0 {
+        return 0.0;
+    }
+
+    let mut angle = if y_abs > x_abs {
+        FRAC_PI_2 - atan_unit(x_abs / y_abs)
+    } else {
+        atan_unit(y_abs / x_abs)
+    };
+
+    if x < 0.0 {
+        angle = PI - angle;
+    }
+
+    if y < 0.0 {
+        -angle
+    } else {
+        angle
+    }
+}
+
+/// Approximate the sine and cosine of `x`, in radians, with a maximum
+/// absolute error of `1e-6` for `x` in `[-2π, 2π]`.
+pub fn sin_cos(x: f32) -> (f32, f32) {
+    if x.is_nan() || x.is_infinite() {
+        return (f32::NAN, f32::NAN);
+    }
+
+    // Reduce `x` to `[-π/4, π/4]`, and remember the quadrant.
+    let rounding = if x < 0.0 { -0.5 } else { 0.5 };
+    let quadrant = (x * FRAC_2_PI + rounding) as i32;
+    let x = (x - quadrant as f32 * FRAC_PI_2) - quadrant as f32 * FRAC_PI_2_LOW;
+
+    let x2 = x * x;
+    let sin = x + x * x2 * (SIN_0 + x2 * (SIN_1 + x2 * SIN_2));
+    let cos = 1.0 - 0.5 * x2 + x2 * x2 * (COS_0 + x2 * (COS_1 + x2 * COS_2));
+
+    match quadrant & 3 {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    fn relative_error(value: f32, expected: f64) -> f64 {
+        ((value as f64 - expected) / expected).abs()
+    }
+
+    #[test]
+    fn cbrt() {
+        let mut max_error: f64 = 0.0;
+
+        for i in 1..=10000 {
+            let x = i as f32 / 1000.0;
+            let error = relative_error(super::cbrt(x), (x as f64).cbrt());
+            max_error = max_error.max(error);
+        }
+
+        for &x in &[1e-40f32, 1e-30, -0.5, 3.0e30] {
+            let error = relative_error(super::cbrt(x), (x as f64).cbrt());
+            max_error = max_error.max(error);
+        }
+
+        assert!(max_error < 3e-7, "max error: {}", max_error);
+        assert_eq!(super::cbrt(0.0), 0.0);
+    }
+
+    #[test]
+    fn powf() {
+        let mut max_error: f64 = 0.0;
+
+        for &y in &[1.0f64 / 2.4, 1.0 / 3.0, 2.2, 2.4, 3.0, -3.0, -0.5] {
+            for i in 1..=10000 {
+                let (x, y) = (i as f32 / 5000.0, y as f32);
+                let expected = (x as f64).powf(y as f64);
+                let error = relative_error(super::powf(x, y), expected);
+                max_error = max_error.max(error);
+            }
+        }
+
+        assert!(max_error < 2e-6, "max error: {}", max_error);
+        assert_eq!(super::powf(0.0, 2.4), 0.0);
+        assert_eq!(super::powf(1.0, 2.4), 1.0);
+        assert!(super::powf(-1.0, 2.4).is_nan());
+    }
+
+    #[test]
+    fn atan2() {
+        let mut max_error: f64 = 0.0;
+
+        for i in 0..3600 {
+            let angle = (i as f64 / 10.0 - 180.0).to_radians();
+            for &radius in &[1e-3f64, 0.5, 150.0] {
+                let (y, x) = ((angle.sin() * radius) as f32, (angle.cos() * radius) as f32);
+                let error = (super::atan2(y, x) as f64 - (y as f64).atan2(x as f64)).abs();
+                max_error = max_error.max(error);
+            }
+        }
+
+        assert!(max_error < 1e-6, "max error: {}", max_error);
+        assert_eq!(super::atan2(0.0, 0.0), 0.0);
+        assert!(super::atan2(f32::NAN, 1.0).is_nan());
+    }
+
+    #[test]
+    fn sin_cos() {
+        let mut max_error: f64 = 0.0;
+
+        for i in -3600..=3600 {
+            let x = (i as f64 / 10.0).to_radians() as f32;
+            let (sin, cos) = super::sin_cos(x);
+            max_error = max_error
+                .max((sin as f64 - (x as f64).sin()).abs())
+                .max((cos as f64 - (x as f64).cos()).abs());
+        }
+
+        assert!(max_error < 1e-6, "max error: {}", max_error);
+    }
+}
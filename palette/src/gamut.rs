@@ -0,0 +1,322 @@
+//! Gamut volume and coverage calculations, and gamut mapping.
+//!
+//! The gamut of an RGB space is the set of colors it can represent, which
+//! is the RGB cube. These functions measure it in another space, usually a
+//! perceptually uniform one like [`Lab`](crate::Lab) or
+//! [`Oklab`](crate::Oklab), by dividing the cube into `subdivisions³`
+//! smaller boxes, that are smaller near black, splitting each of them into
+//! six tetrahedra, and converting their corners. More subdivisions give more accurate results,
+//! and 16 to 32 is usually enough for reporting.
+//!
+//! The RGB spaces and the measuring space must use the same white point.
+//! The shape of a gamut can be described with a [`GamutBoundary`], and
+//! colors can be mapped into the sRGB gamut with [`map_into_srgb`].
+//!
+//! ```
+//! use palette::encoding::Srgb;
+//! use palette::gamut;
+//! use palette::white_point::D65;
+//! use palette::Lab;
+//!
+//! let volume: f64 = gamut::volume::<Srgb, Lab<D65, f64>, _>(16);
+//! assert!(volume > 800_000.0 && volume < 850_000.0);
+//!
+//! let coverage: f64 = gamut::coverage::<Srgb, Srgb, Lab<D65, f64>, _>(8);
+//! assert!(coverage > 0.99);
+//! ```
+
+#[cfg(feature = "std")]
+mod boundary;
+
+#[cfg(feature = "std")]
+pub use self::boundary::GamutBoundary;
+
+use crate::behavior::Behavior;
+use crate::cast::{self, ArrayCast};
+use crate::convert::IntoColorUnclamped;
+use crate::encoding::Linear;
+use crate::rgb::{Rgb, RgbSpace};
+use crate::{from_f64, FloatComponent, LinSrgb};
+
+/// How the hue is kept when colors are mapped into a gamut.
+///
+/// The hue angle of CIE L\*a\*b\* doesn't follow the perceived hue for
+/// saturated colors. Hung and Berns showed that lines of constant perceived
+/// hue are curved in CIE L\*a\*b\*, and the curvature is largest for blue,
+/// which turns purple when its chroma is reduced at a constant hue angle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub enum HueCorrection {
+    /// Keep the hue angle of the color space that is used for the mapping.
+    None,
+
+    /// Keep the perceived hue, by following lines of constant hue in
+    /// [`Oklab`](crate::Oklab), which was designed to be hue linear.
+    Linearized,
+}
+
+/// Map `color` into the sRGB gamut, with the algorithm of the behavior
+/// version `B`.
+///
+/// Pin a version, such as [`V1`](crate::behavior::V1), to keep the results
+/// the same when palette is upgraded, or use
+/// [`Latest`](crate::behavior::Latest) for the newest algorithm.
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::behavior::V1;
+/// use palette::{gamut, Srgb};
+///
+/// let mapped = gamut::map_into_srgb::<V1, _, _>(Srgb::new(1.2f32, 0.5, -0.1));
+/// assert_relative_eq!(mapped, Srgb::new(1.0, 0.5, 0.0), epsilon = 1e-6);
+/// ```
+pub fn map_into_srgb<B, C, T>(color: C) -> C
+where
+    B: Behavior,
+    T: FloatComponent,
+    C: IntoColorUnclamped<LinSrgb<T>>,
+    LinSrgb<T>: IntoColorUnclamped<C>,
+{
+    B::gamut_map(color.into_color_unclamped()).into_color_unclamped()
+}
+
+/// The corners of the six tetrahedra in a box, as bit masks for red,
+/// green and blue, together with the sign of their orientation.
+const TETRAHEDRA: [([usize; 4], bool); 6] = [
+    ([0b000, 0b100, 0b110, 0b111], true),
+    ([0b000, 0b100, 0b101, 0b111], false),
+    ([0b000, 0b010, 0b110, 0b111], false),
+    ([0b000, 0b010, 0b011, 0b111], true),
+    ([0b000, 0b001, 0b101, 0b111], true),
+    ([0b000, 0b001, 0b011, 0b111], false),
+];
+
+/// Calculate the volume of the gamut of the RGB space `S`, when measured in
+/// the color space `C`.
+///
+/// The unit of the volume depends on `C`. CIE L\*a\*b\* volumes are
+/// sometimes reported in cubic ΔE units, for example.
+pub fn volume<S, C, T>(subdivisions: usize) -> T
+where
+    S: RgbSpace<T>,
+    T: FloatComponent,
+    Rgb<Linear<S>, T>: IntoColorUnclamped<C>,
+    C: ArrayCast<Array = [T; 3]>,
+{
+    let mut volume = T::zero();
+
+    for_each_tetrahedron::<S, C, T, _>(subdivisions, |_, signed_volume| {
+        volume = volume + signed_volume;
+    });
+
+    volume.abs()
+}
+
+/// Calculate the volume of the gamut of `S`, relative to the gamut of
+/// `R`, when both are measured in the color space `C`.
+///
+/// A value of `1.25` means that `S` is 25% larger than `R`, but it doesn't
+/// mean that `S` contains all of `R`. Use [`coverage`] for that.
+pub fn relative_volume<S, R, C, T>(subdivisions: usize) -> T
+where
+    S: RgbSpace<T>,
+    R: RgbSpace<T>,
+    T: FloatComponent,
+    Rgb<Linear<S>, T>: IntoColorUnclamped<C>,
+    Rgb<Linear<R>, T>: IntoColorUnclamped<C>,
+    C: ArrayCast<Array = [T; 3]>,
+{
+    volume::<S, C, T>(subdivisions) / volume::<R, C, T>(subdivisions)
+}
+
+/// Calculate how large part of the gamut of `R` that is covered by the
+/// gamut of `S`, when measured in the color space `C`.
+///
+/// The result is in `[0.0, 1.0]`, where `1.0` means that `S` contains all
+/// of `R`. Each tetrahedron of `R` counts as covered if its center is
+/// within `S`.
+pub fn coverage<S, R, C, T>(subdivisions: usize) -> T
+where
+    S: RgbSpace<T>,
+    R: RgbSpace<T>,
+    T: FloatComponent,
+    Rgb<Linear<R>, T>: IntoColorUnclamped<C>,
+    C: ArrayCast<Array = [T; 3]> + IntoColorUnclamped<Rgb<Linear<S>, T>>,
+{
+    let mut total = T::zero();
+    let mut covered = T::zero();
+    let quarter = from_f64::<T>(0.25);
+    let tolerance = from_f64::<T>(1e-9);
+
+    for_each_tetrahedron::<R, C, T, _>(subdivisions, |corners, signed_volume| {
+        let mut center = [T::zero(); 3];
+        for corner in &corners {
+            for (center, &component) in center.iter_mut().zip(corner) {
+                *center = *center + component * quarter;
+            }
+        }
+
+        let rgb: Rgb<Linear<S>, T> = cast::from_array::<C>(center).into_color_unclamped();
+        let inside = cast::into_array(rgb)
+            .iter()
+            .all(|&component| component >= -tolerance && component <= T::one() + tolerance);
+
+        let volume = signed_volume.abs();
+        total = total + volume;
+        if inside {
+            covered = covered + volume;
+        }
+    });
+
+    if total > T::zero() {
+        covered / total
+    } else {
+        T::zero()
+    }
+}
+
+/// Call `f` with the corners and signed volume of each tetrahedron in the
+/// gamut of `S`, converted to `C`.
+fn for_each_tetrahedron<S, C, T, F>(subdivisions: usize, mut f: F)
+where
+    S: RgbSpace<T>,
+    T: FloatComponent,
+    Rgb<Linear<S>, T>: IntoColorUnclamped<C>,
+    C: ArrayCast<Array = [T; 3]>,
+    F: FnMut([[T; 3]; 4], T),
+{
+    let subdivisions = subdivisions.max(1);
+    let sixth = from_f64::<T>(1.0 / 6.0);
+
+    // The steps are cubed, to make them smaller near black, where
+    // perceptual spaces change the most.
+    let to_component = |index: usize| {
+        let step = index as f64 / subdivisions as f64;
+        from_f64::<T>(step * step * step)
+    };
+    let to_color = |red: usize, green: usize, blue: usize| -> [T; 3] {
+        let rgb =
+            Rgb::<Linear<S>, T>::new(to_component(red), to_component(green), to_component(blue));
+        cast::into_array::<C>(rgb.into_color_unclamped())
+    };
+
+    for red in 0..subdivisions {
+        for green in 0..subdivisions {
+            for blue in 0..subdivisions {
+                let mut cube = [[T::zero(); 3]; 8];
+                for (index, corner) in cube.iter_mut().enumerate() {
+                    *corner = to_color(
+                        red + (index >> 2 & 1),
+                        green + (index >> 1 & 1),
+                        blue + (index & 1),
+                    );
+                }
+
+                for &(indices, positive) in &TETRAHEDRA {
+                    let corners = [
+                        cube[indices[0]],
+                        cube[indices[1]],
+                        cube[indices[2]],
+                        cube[indices[3]],
+                    ];
+                    let volume = determinant(&corners) * sixth;
+                    f(corners, if positive { volume } else { -volume });
+                }
+            }
+        }
+    }
+}
+
+/// The determinant of the edge vectors from the first corner.
+fn determinant<T: FloatComponent>(corners: &[[T; 3]; 4]) -> T {
+    let [origin, a, b, c] = *corners;
+    let a = [a[0] - origin[0], a[1] - origin[1], a[2] - origin[2]];
+    let b = [b[0] - origin[0], b[1] - origin[1], b[2] - origin[2]];
+    let c = [c[0] - origin[0], c[1] - origin[1], c[2] - origin[2]];
+
+    a[0] * (b[1] * c[2] - b[2] * c[1]) - a[1] * (b[0] * c[2] - b[2] * c[0])
+        + a[2] * (b[0] * c[1] - b[1] * c[0])
+}
+
+#[cfg(test)]
+mod test {
+    use super::{coverage, map_into_srgb, relative_volume, volume};
+    use crate::behavior::{V1, V2};
+    use crate::encoding::Srgb;
+    use crate::rgb::Primaries;
+    use crate::white_point::{Any, D65};
+    use crate::{Lab, Oklab, Oklch, Xyz, Yxy};
+
+    /// sRGB primaries, moved halfway towards the white point.
+    struct Narrow;
+
+    impl Primaries<f64> for Narrow {
+        fn red() -> Yxy<Any, f64> {
+            Yxy::new(0.4776, 0.3295, 0.212656)
+        }
+        fn green() -> Yxy<Any, f64> {
+            Yxy::new(0.3064, 0.4645, 0.715158)
+        }
+        fn blue() -> Yxy<Any, f64> {
+            Yxy::new(0.2314, 0.1945, 0.072186)
+        }
+    }
+
+    #[test]
+    fn xyz_volume() {
+        // The RGB to XYZ transform is linear, so the volume is the
+        // determinant of the matrix, no matter how many subdivisions.
+        let matrix = crate::matrix::rgb_to_xyz_matrix::<Srgb, f64>();
+        let [a, b, c, d, e, f, g, h, i] = matrix;
+        let determinant = a * (e * i - f * h) - b * (d * i - f * g) + c * (d * h - e * g);
+
+        assert_relative_eq!(
+            volume::<Srgb, Xyz<D65, f64>, _>(1),
+            determinant,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            volume::<Srgb, Xyz<D65, f64>, _>(3),
+            determinant,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn lab_volume_converges() {
+        let coarse: f64 = volume::<Srgb, Lab<D65, f64>, _>(8);
+        let fine: f64 = volume::<Srgb, Lab<D65, f64>, _>(24);
+
+        assert!((coarse - fine).abs() / fine < 0.005);
+        assert!(fine > 800_000.0 && fine < 850_000.0, "{}", fine);
+    }
+
+    #[test]
+    fn narrow_gamut() {
+        type NarrowSpace = (Narrow, D65);
+
+        let relative: f64 = relative_volume::<NarrowSpace, Srgb, Oklab<f64>, _>(8);
+        assert!(relative < 1.0);
+
+        let narrow_in_srgb: f64 = coverage::<Srgb, NarrowSpace, Oklab<f64>, _>(8);
+        let srgb_in_narrow: f64 = coverage::<NarrowSpace, Srgb, Oklab<f64>, _>(8);
+
+        assert_relative_eq!(narrow_in_srgb, 1.0);
+        assert!(srgb_in_narrow < 1.0);
+        assert!(srgb_in_narrow > 0.0);
+    }
+
+    #[test]
+    fn map_into_srgb_versions() {
+        let inside = crate::Srgb::new(0.2f64, 0.4, 0.6);
+        assert_relative_eq!(map_into_srgb::<V1, _, _>(inside), inside, epsilon = 1e-12);
+        assert_relative_eq!(map_into_srgb::<V2, _, _>(inside), inside, epsilon = 1e-12);
+
+        let outside = Oklch::new(0.7f64, 0.4, 150.0);
+        let v1 = map_into_srgb::<V1, _, _>(outside);
+        let v2 = map_into_srgb::<V2, _, _>(outside);
+        assert_ne!(v1, v2);
+        assert!(v2.chroma < 0.4);
+        assert_relative_eq!(v2.l, 0.7, epsilon = 1e-4);
+    }
+}
@@ -0,0 +1,515 @@
+use crate::cast::{self, ArrayCast};
+use crate::convert::FromColorUnclamped;
+use crate::convert::IntoColorUnclamped;
+use crate::encoding::Linear;
+use crate::float;
+use crate::rgb::{Rgb, RgbSpace};
+use crate::white_point::D65;
+use crate::{from_f64, FloatComponent, Lab, Oklab};
+
+use super::HueCorrection;
+
+/// A gamut boundary descriptor, computed with the segment maxima method.
+///
+/// The color space around a neutral center point is divided into segments,
+/// by hue angle and elevation angle, and the boundary is described by the
+/// largest distance from the center that is reached within each segment.
+/// The color type `C` is interpreted as a lightness component followed by
+/// two opponent components, such as [`Lab`](crate::Lab) or
+/// [`Oklab`](crate::Oklab).
+///
+/// The boundary can be computed for an RGB space with
+/// [`from_rgb`](GamutBoundary::from_rgb), or from measured colors, such as
+/// the patches of a printed target, with
+/// [`from_points`](GamutBoundary::from_points). It's a starting point for
+/// building gamut mapping algorithms.
+///
+/// ```
+/// use palette::encoding::Srgb;
+/// use palette::gamut::GamutBoundary;
+/// use palette::white_point::D65;
+/// use palette::Lab;
+///
+/// let center = Lab::new(50.0, 0.0, 0.0);
+/// let boundary = GamutBoundary::<Lab<D65, f64>, f64>::from_rgb::<Srgb>(center, 36, 18, 32);
+///
+/// let too_saturated = Lab::new(50.0, 0.0, -120.0);
+/// assert!(!boundary.contains(&too_saturated));
+///
+/// let mapped = boundary.project(&too_saturated);
+/// assert!(mapped.b > -120.0);
+/// assert!(boundary.max_chroma(50.0, 270.0) < 120.0);
+/// ```
+///
+/// This type is only available if the `std` feature is enabled (this is the
+/// default).
+#[derive(Clone, Debug)]
+pub struct GamutBoundary<C, T> {
+    center: C,
+    hue_segments: usize,
+    elevation_segments: usize,
+    radii: Vec<T>,
+}
+
+impl<C, T> GamutBoundary<C, T>
+where
+    C: ArrayCast<Array = [T; 3]> + Copy,
+    T: FloatComponent,
+{
+    /// Compute the boundary of the colors in `points`, with `hue_segments`
+    /// hue angle segments and `elevation_segments` elevation angle segments
+    /// around `center`.
+    ///
+    /// Segments without any points get the smallest distance of their
+    /// neighbors.
+    pub fn from_points<I>(
+        points: I,
+        center: C,
+        hue_segments: usize,
+        elevation_segments: usize,
+    ) -> Self
+    where
+        I: IntoIterator<Item = C>,
+    {
+        let hue_segments = hue_segments.max(1);
+        let elevation_segments = elevation_segments.max(1);
+
+        let mut boundary = GamutBoundary {
+            center,
+            hue_segments,
+            elevation_segments,
+            radii: vec![-T::one(); hue_segments * elevation_segments],
+        };
+
+        for point in points {
+            let (index, radius) = boundary.locate(&point);
+            if radius > boundary.radii[index] {
+                boundary.radii[index] = radius;
+            }
+        }
+
+        boundary.fill_empty_segments();
+        boundary
+    }
+
+    /// Compute the boundary of the gamut of the RGB space `S`, by sampling
+    /// each face of the RGB cube in a grid of `samples` by `samples`
+    /// colors.
+    ///
+    /// See [`from_points`](GamutBoundary::from_points) for the other
+    /// parameters.
+    pub fn from_rgb<S>(
+        center: C,
+        hue_segments: usize,
+        elevation_segments: usize,
+        samples: usize,
+    ) -> Self
+    where
+        S: RgbSpace<T>,
+        Rgb<Linear<S>, T>: IntoColorUnclamped<C>,
+    {
+        let steps = samples.max(2) - 1;
+
+        // The steps are cubed, to make them smaller near black, where
+        // perceptual spaces change the most.
+        let to_component = |index: usize| {
+            let step = index as f64 / steps as f64;
+            from_f64::<T>(step * step * step)
+        };
+
+        let faces = (0..3).flat_map(move |axis| {
+            (0..2).flat_map(move |side| {
+                (0..=steps).flat_map(move |i| {
+                    (0..=steps).map(move |j| {
+                        let fixed = if side == 0 { T::zero() } else { T::one() };
+                        let (i, j) = (to_component(i), to_component(j));
+                        let [red, green, blue] = match axis {
+                            0 => [fixed, i, j],
+                            1 => [i, fixed, j],
+                            _ => [i, j, fixed],
+                        };
+
+                        Rgb::<Linear<S>, T>::new(red, green, blue).into_color_unclamped()
+                    })
+                })
+            })
+        });
+
+        Self::from_points(faces, center, hue_segments, elevation_segments)
+    }
+
+    /// The center point of the boundary.
+    pub fn center(&self) -> C {
+        self.center
+    }
+
+    /// The distance from the center to the boundary, in the direction of
+    /// `color`.
+    pub fn boundary_distance(&self, color: &C) -> T {
+        let (index, _) = self.locate(color);
+        self.radii[index]
+    }
+
+    /// Check if `color` is within the boundary.
+    pub fn contains(&self, color: &C) -> bool {
+        let (index, radius) = self.locate(color);
+        radius <= self.radii[index]
+    }
+
+    /// Find the boundary point on the line from the center to `color`.
+    ///
+    /// This keeps the hue of `color`, since the center is neutral, and can
+    /// be used for clipping out-of-gamut colors towards the center.
+    pub fn project(&self, color: &C) -> C {
+        let center = *cast::into_array_ref(&self.center);
+        let color = *cast::into_array_ref(color);
+        let (index, radius) = self.locate_array(color);
+
+        if radius <= T::zero() {
+            return self.center;
+        }
+
+        let scale = self.radii[index] / radius;
+        cast::from_array([
+            center[0] + (color[0] - center[0]) * scale,
+            center[1] + (color[1] - center[1]) * scale,
+            center[2] + (color[2] - center[2]) * scale,
+        ])
+    }
+
+    /// Find the largest chroma that is within the boundary, for the given
+    /// lightness and hue, where `hue` is in degrees.
+    ///
+    /// This is the boundary point along a line of constant lightness and
+    /// hue, and it's found by bisection, so the result is approximate when
+    /// the boundary isn't convex.
+    pub fn max_chroma(&self, lightness: T, hue: T) -> T {
+        let (sin, cos) = float::sin_cos(hue.to_radians());
+        let center = *cast::into_array_ref(&self.center);
+
+        let max_radius = self.radii.iter().fold(
+            T::zero(),
+            |max, &radius| if radius > max { radius } else { max },
+        );
+
+        let mut low = T::zero();
+        let mut high = max_radius + (lightness - center[0]).abs();
+        let half = from_f64::<T>(0.5);
+
+        if !self.contains(&cast::from_array([lightness, center[1], center[2]])) {
+            return T::zero();
+        }
+
+        for _ in 0..32 {
+            let chroma = (low + high) * half;
+            let point = [
+                lightness,
+                center[1] + chroma * cos,
+                center[2] + chroma * sin,
+            ];
+
+            if self.contains(&cast::from_array(point)) {
+                low = chroma;
+            } else {
+                high = chroma;
+            }
+        }
+
+        low
+    }
+
+    /// Find the segment index and the distance from the center for a color.
+    fn locate(&self, color: &C) -> (usize, T) {
+        self.locate_array(*cast::into_array_ref(color))
+    }
+
+    fn locate_array(&self, color: [T; 3]) -> (usize, T) {
+        let center = cast::into_array_ref(&self.center);
+        let lightness = color[0] - center[0];
+        let a = color[1] - center[1];
+        let b = color[2] - center[2];
+
+        let chroma = float::hypot(a, b);
+        let radius = float::hypot(chroma, lightness);
+
+        let pi = from_f64::<T>(core::f64::consts::PI);
+        let hue = float::atan2(b, a) + pi;
+        let elevation = float::atan2(lightness, chroma) + pi * from_f64(0.5);
+
+        let hue_index = to_index(hue / (pi + pi), self.hue_segments);
+        let elevation_index = to_index(elevation / pi, self.elevation_segments);
+
+        (
+            hue_index * self.elevation_segments + elevation_index,
+            radius,
+        )
+    }
+
+    /// Give empty segments the smallest radius among their non-empty
+    /// neighbors, until all segments have a radius.
+    fn fill_empty_segments(&mut self) {
+        loop {
+            let mut filled = self.radii.clone();
+            let mut empty = false;
+            let mut changed = false;
+
+            for hue in 0..self.hue_segments {
+                for elevation in 0..self.elevation_segments {
+                    let index = hue * self.elevation_segments + elevation;
+                    if self.radii[index] >= T::zero() {
+                        continue;
+                    }
+
+                    let mut min: Option<T> = None;
+                    for &hue_offset in &[self.hue_segments - 1, 0, 1] {
+                        for &elevation_offset in &[-1isize, 0, 1] {
+                            let neighbor_elevation = elevation as isize + elevation_offset;
+                            if neighbor_elevation < 0
+                                || neighbor_elevation >= self.elevation_segments as isize
+                            {
+                                continue;
+                            }
+
+                            let neighbor_hue = (hue + hue_offset) % self.hue_segments;
+                            let radius = self.radii[neighbor_hue * self.elevation_segments
+                                + neighbor_elevation as usize];
+
+                            let is_smaller = match min {
+                                Some(min) => radius < min,
+                                None => true,
+                            };
+
+                            if radius >= T::zero() && is_smaller {
+                                min = Some(radius);
+                            }
+                        }
+                    }
+
+                    match min {
+                        Some(radius) => {
+                            filled[index] = radius;
+                            changed = true;
+                        }
+                        None => empty = true,
+                    }
+                }
+            }
+
+            self.radii = filled;
+
+            if !empty || !changed {
+                break;
+            }
+        }
+
+        // Nothing to fill from, when there were no points at all.
+        for radius in &mut self.radii {
+            if *radius < T::zero() {
+                *radius = T::zero();
+            }
+        }
+    }
+}
+
+impl<T> GamutBoundary<Lab<D65, T>, T>
+where
+    T: FloatComponent,
+{
+    /// Map `color` into the boundary, if it's outside, by moving it towards
+    /// the center.
+    ///
+    /// With [`HueCorrection::None`], this is the same as
+    /// [`project`](GamutBoundary::project), and the CIE L\*a\*b\* hue angle
+    /// is kept. With [`HueCorrection::Linearized`], the color follows a
+    /// line of constant perceived hue instead, which keeps saturated blues
+    /// from turning purple.
+    ///
+    /// ```
+    /// use palette::encoding::Srgb;
+    /// use palette::gamut::{GamutBoundary, HueCorrection};
+    /// use palette::white_point::D65;
+    /// use palette::{FromColor, Lab, Oklch};
+    ///
+    /// let center = Lab::new(50.0, 0.0, 0.0);
+    /// let boundary = GamutBoundary::<Lab<D65, f64>, f64>::from_rgb::<Srgb>(center, 36, 18, 32);
+    ///
+    /// let blue = Lab::new(30.0, 70.0, -120.0);
+    /// let clipped = boundary.clip(&blue, HueCorrection::Linearized);
+    ///
+    /// let hue_before = Oklch::from_color(blue).hue;
+    /// let hue_after = Oklch::from_color(clipped).hue;
+    /// assert!((hue_before - hue_after).to_degrees().abs() < 0.01);
+    /// ```
+    pub fn clip(&self, color: &Lab<D65, T>, hue_correction: HueCorrection) -> Lab<D65, T> {
+        if self.contains(color) {
+            return *color;
+        }
+
+        match hue_correction {
+            HueCorrection::None => self.project(color),
+            HueCorrection::Linearized => {
+                // The center is moved to the neutral axis of Oklab, to keep
+                // the hue constant.
+                let center = Oklab {
+                    a: T::zero(),
+                    b: T::zero(),
+                    ..Oklab::from_color_unclamped(self.center)
+                };
+                let direction = Oklab::from_color_unclamped(*color) - center;
+                let half = from_f64::<T>(0.5);
+
+                let mut inside = T::zero();
+                let mut outside = T::one();
+
+                for _ in 0..32 {
+                    let middle = (inside + outside) * half;
+                    if self.contains(&Lab::from_color_unclamped(center + direction * middle)) {
+                        inside = middle;
+                    } else {
+                        outside = middle;
+                    }
+                }
+
+                Lab::from_color_unclamped(center + direction * inside)
+            }
+        }
+    }
+}
+
+/// Map `fraction`, that is in `[0.0, 1.0]`, to a segment index.
+fn to_index<T: FloatComponent>(fraction: T, segments: usize) -> usize {
+    let index = (fraction * from_f64(segments as f64)).floor();
+    if index > T::zero() {
+        index.to_usize().unwrap_or(0).min(segments - 1)
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GamutBoundary;
+    use crate::encoding::Srgb;
+    use crate::gamut::HueCorrection;
+    use crate::white_point::D65;
+    use crate::{FromColor, Lab, Lch, Oklch};
+
+    type Boundary = GamutBoundary<Lab<D65, f64>, f64>;
+
+    fn srgb_boundary() -> Boundary {
+        Boundary::from_rgb::<Srgb>(Lab::new(50.0, 0.0, 0.0), 36, 18, 32)
+    }
+
+    #[test]
+    fn contains() {
+        let boundary = srgb_boundary();
+
+        let inside = Lab::from_color(crate::Srgb::new(0.5f64, 0.4, 0.3));
+        assert!(boundary.contains(&inside));
+        assert!(boundary.contains(&Lab::new(50.0, 0.0, 0.0)));
+        assert!(!boundary.contains(&Lab::new(50.0, 120.0, 0.0)));
+        assert!(!boundary.contains(&Lab::new(110.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn project() {
+        let boundary = srgb_boundary();
+        let outside = Lab::new(60.0, 0.0, 120.0);
+        let projected = boundary.project(&outside);
+
+        // The direction from the center is the same, so the hue is kept.
+        let hue = Lch::from_color(outside).hue;
+        assert_relative_eq!(Lch::from_color(projected).hue, hue, epsilon = 1e-9);
+        assert_relative_eq!(
+            boundary.boundary_distance(&projected),
+            boundary.boundary_distance(&outside)
+        );
+
+        // The projected point is close to the sRGB gamut surface.
+        let rgb = crate::LinSrgb::from_color(projected);
+        let max_out = [rgb.red, rgb.green, rgb.blue]
+            .iter()
+            .map(|&c| if c < 0.0 { -c } else { (c - 1.0).max(0.0) })
+            .fold(0.0f64, f64::max);
+        assert!(max_out < 0.05, "{:?}", rgb);
+    }
+
+    #[test]
+    fn max_chroma() {
+        let boundary = srgb_boundary();
+        let red = Lch::from_color(crate::Srgb::new(1.0f64, 0.0, 0.0));
+
+        let chroma = boundary.max_chroma(red.l, red.hue.to_positive_degrees());
+        assert!((chroma - red.chroma).abs() / red.chroma < 0.1, "{}", chroma);
+        assert_eq!(boundary.max_chroma(120.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn from_points() {
+        let points = (0..360).flat_map(|hue| {
+            (-8..=8).map(move |elevation| {
+                let hue = (hue as f64).to_radians();
+                let elevation = (elevation as f64 * 10.0).to_radians();
+                Lab::new(
+                    50.0 + 10.0 * elevation.sin(),
+                    10.0 * elevation.cos() * hue.cos(),
+                    10.0 * elevation.cos() * hue.sin(),
+                )
+            })
+        });
+
+        let boundary = Boundary::from_points(points, Lab::new(50.0, 0.0, 0.0), 12, 6);
+
+        assert!(boundary.contains(&Lab::new(55.0, 5.0, 0.0)));
+        assert!(!boundary.contains(&Lab::new(50.0, 12.0, 0.0)));
+        assert_relative_eq!(
+            boundary.boundary_distance(&Lab::new(50.0, 0.0, 5.0)),
+            10.0,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn empty_segments() {
+        let boundary = Boundary::from_points(
+            vec![Lab::new(50.0, 10.0, 0.0)],
+            Lab::new(50.0, 0.0, 0.0),
+            4,
+            4,
+        );
+
+        assert_relative_eq!(boundary.boundary_distance(&Lab::new(50.0, -1.0, 0.0)), 10.0);
+
+        let empty = Boundary::from_points(None, Lab::new(50.0, 0.0, 0.0), 4, 4);
+        assert_eq!(empty.boundary_distance(&Lab::new(50.0, 1.0, 0.0)), 0.0);
+    }
+
+    #[test]
+    fn clip() {
+        let boundary = srgb_boundary();
+
+        let inside = Lab::new(50.0, 10.0, 10.0);
+        assert_eq!(boundary.clip(&inside, HueCorrection::Linearized), inside);
+        assert_eq!(boundary.clip(&inside, HueCorrection::None), inside);
+
+        let blue = Lab::new(30.0, 70.0, -120.0);
+        let plain = boundary.clip(&blue, HueCorrection::None);
+        let linearized = boundary.clip(&blue, HueCorrection::Linearized);
+
+        assert!(boundary.contains(&linearized));
+        assert_relative_eq!(
+            Lch::from_color(plain).hue,
+            Lch::from_color(blue).hue,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(
+            Oklch::from_color(linearized).hue,
+            Oklch::from_color(blue).hue,
+            epsilon = 1e-3
+        );
+
+        // The Lab hue angle changes when the perceived hue is kept.
+        let hue_shift = (Lch::from_color(linearized).hue - Lch::from_color(blue).hue).to_degrees();
+        assert!(hue_shift.abs() > 1.0, "{}", hue_shift);
+    }
+}
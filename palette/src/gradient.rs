@@ -1,5 +1,9 @@
 //! Types for interpolation between multiple colors.
 //!
+//! Gradients can also be fitted to the color progression of an image, with
+//! [`GradientFit`], and sampled into 8 bit buffers, with
+//! [`fill_slice`](Gradient::fill_slice).
+//!
 //! This module is only available if the `std` feature is enabled (this is the
 //! default).
 
@@ -10,13 +14,19 @@ use std::ops::Sub;
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 use num_traits::{One, Zero};
 
+use crate::convert::IntoColorUnclamped;
+use crate::easing::{Easing, Linear};
 use crate::float::Float;
-use crate::{clamp, clamp_min, Mix};
+use crate::{clamp, clamp_min, FloatComponent, Mix, Srgb};
 use crate::{from_f64, FromF64};
 
+mod fit;
+
 #[cfg(feature = "named_gradients")]
 pub mod named;
 
+pub use self::fit::{Direction, GradientFit};
+
 impl<C, T> From<T> for Gradient<C, T>
 where
     C: Mix + Clone,
@@ -51,6 +61,34 @@ where
         C: Clone,
         C::Scalar: Float,
         T: AsRef<[(C::Scalar, C)]>,
+    {
+        self.get_eased(i, &Linear)
+    }
+
+    /// Get a color from the gradient, with `easing` applied between each
+    /// pair of control points. The color of the closest control point will
+    /// be returned if `i` is outside the domain.
+    ///
+    /// ```
+    /// use palette::easing::Smoothstep;
+    /// use palette::{Gradient, LinSrgb};
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     LinSrgb::new(0.0, 0.0, 0.0),
+    ///     LinSrgb::new(1.0, 1.0, 1.0),
+    ///     LinSrgb::new(0.0, 0.0, 0.0),
+    /// ]);
+    ///
+    /// // Smoothstep slows down near each control point.
+    /// assert!(gradient.get_eased(0.1, &Smoothstep).red < gradient.get(0.1).red);
+    /// assert!(gradient.get_eased(0.4, &Smoothstep).red > gradient.get(0.4).red);
+    /// ```
+    pub fn get_eased<E>(&self, i: C::Scalar, easing: &E) -> C
+    where
+        C: Clone,
+        C::Scalar: Float,
+        T: AsRef<[(C::Scalar, C)]>,
+        E: Easing<C::Scalar>,
     {
         let &(mut min, ref min_color) = self
             .0
@@ -94,7 +132,7 @@ where
 
         let factor = (i - min) / (max - min);
 
-        min_color.clone().mix(max_color.clone(), factor)
+        easing.mix(min_color.clone(), max_color.clone(), factor)
     }
 
     /// Create a gradient of colors with custom spacing and domain. There must
@@ -206,6 +244,78 @@ where
     }
 }
 
+impl<C, T> Gradient<C, T>
+where
+    C: Mix + Clone + IntoColorUnclamped<Srgb<C::Scalar>>,
+    C::Scalar: FloatComponent,
+    T: AsRef<[(C::Scalar, C)]>,
+{
+    /// Fill `buffer` with evenly spaced colors from the gradient, converted
+    /// to 8 bit sRGB. The first and last colors are the ends of the domain,
+    /// as with [`take`](Gradient::take).
+    ///
+    /// Smooth gradients tend to show bands when they are quantized to 8 bits.
+    /// With `dither`, a small offset is added to each color before rounding,
+    /// which breaks up the bands while keeping the average color. The offsets
+    /// follow the golden ratio sequence, which has a blue noise like spectrum
+    /// for one dimensional data, so the pattern is hard to notice. The result
+    /// is deterministic, and each channel is off by at most one step.
+    ///
+    /// ```
+    /// use palette::{Gradient, LinSrgb, Srgb};
+    ///
+    /// let gradient = Gradient::new(vec![
+    ///     LinSrgb::new(0.0f32, 0.0, 0.1),
+    ///     LinSrgb::new(0.0, 0.0, 0.12),
+    /// ]);
+    ///
+    /// let mut texture = vec![Srgb::new(0u8, 0, 0); 256];
+    /// gradient.fill_slice(&mut texture, true);
+    ///
+    /// assert!(texture[0].blue <= 90 && texture[255].blue >= 96);
+    /// ```
+    pub fn fill_slice(&self, buffer: &mut [Srgb<u8>], dither: bool) {
+        let golden_ratio: C::Scalar = from_f64(0.618_033_988_749_894_9);
+        let half: C::Scalar = from_f64(0.5);
+        let mut noise = half;
+        let colors = self.take(buffer.len());
+
+        for (pixel, color) in buffer.iter_mut().zip(colors) {
+            let color: Srgb<C::Scalar> = color.into_color_unclamped();
+
+            let offsets = if dither {
+                let offsets = [
+                    noise - half,
+                    fract(noise + from_f64(1.0 / 3.0)) - half,
+                    fract(noise + from_f64(2.0 / 3.0)) - half,
+                ];
+                noise = fract(noise + golden_ratio);
+                offsets
+            } else {
+                [C::Scalar::zero(); 3]
+            };
+
+            *pixel = Srgb::new(
+                quantize(color.red, offsets[0]),
+                quantize(color.green, offsets[1]),
+                quantize(color.blue, offsets[2]),
+            );
+        }
+    }
+}
+
+fn fract<T: Float>(value: T) -> T {
+    value - value.floor()
+}
+
+/// Convert a component to 8 bits, after adding `offset`, in steps.
+fn quantize<T: FloatComponent>(value: T, offset: T) -> u8 {
+    let value = clamp(value, T::zero(), T::one()) * from_f64(255.0) + offset;
+    clamp(value.round(), T::zero(), from_f64(255.0))
+        .to_u8()
+        .unwrap_or(0)
+}
+
 /// An iterator over interpolated colors.
 #[derive(Clone)]
 pub struct Take<'a, C, T = Vec<(<C as Mix>::Scalar, C)>>
@@ -594,7 +704,7 @@ fn clamp_max<T: PartialOrd>(value: T, max: T) -> T {
 #[cfg(test)]
 mod test {
     use super::{Gradient, Range};
-    use crate::LinSrgb;
+    use crate::{LinSrgb, Srgb};
 
     #[test]
     fn range_clamp() {
@@ -616,6 +726,50 @@ mod test {
         assert_relative_eq!(range.constrain(&(0.2..0.8).into()), (0.2..0.8).into());
     }
 
+    #[test]
+    fn fill_slice() {
+        let gradient = Gradient::new(vec![
+            LinSrgb::new(0.0f64, 0.0, 1.0),
+            LinSrgb::new(1.0, 0.0, 0.0),
+        ]);
+
+        let mut buffer = [Srgb::new(0u8, 0, 0); 5];
+        gradient.fill_slice(&mut buffer, false);
+        assert_eq!(buffer[0], Srgb::new(0, 0, 255));
+        assert_eq!(buffer[2], Srgb::new(188, 0, 188));
+        assert_eq!(buffer[4], Srgb::new(255, 0, 0));
+
+        let mut dithered = [Srgb::new(0u8, 0, 0); 5];
+        gradient.fill_slice(&mut dithered, true);
+        for (&a, &b) in buffer.iter().zip(&dithered) {
+            for (&a, &b) in [a.red, a.green, a.blue]
+                .iter()
+                .zip(&[b.red, b.green, b.blue])
+            {
+                assert!((i16::from(a) - i16::from(b)).abs() <= 1);
+            }
+        }
+    }
+
+    #[test]
+    fn fill_slice_dither_average() {
+        // A flat color between two 8 bit steps.
+        let level = 100.4 / 255.0;
+        let gradient = Gradient::new(vec![Srgb::new(level, level, level).into_linear()]);
+
+        let mut buffer = vec![Srgb::new(0u8, 0, 0); 1000];
+        gradient.fill_slice(&mut buffer, false);
+        assert!(buffer.iter().all(|color| color.red == 100));
+
+        gradient.fill_slice(&mut buffer, true);
+        let average = |channel: fn(&Srgb<u8>) -> u8| {
+            buffer.iter().map(|c| f64::from(channel(c))).sum::<f64>() / 1000.0
+        };
+        assert_relative_eq!(average(|c| c.red), 100.4, epsilon = 0.01);
+        assert_relative_eq!(average(|c| c.green), 100.4, epsilon = 0.01);
+        assert_relative_eq!(average(|c| c.blue), 100.4, epsilon = 0.01);
+    }
+
     #[test]
     fn simple_slice() {
         let g1 = Gradient::new(vec![
@@ -0,0 +1,355 @@
+use crate::convert::IntoColorUnclamped;
+use crate::{from_f64, FloatComponent, Oklab};
+
+use super::Gradient;
+
+/// The direction of the color progression in an image.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// From the left edge to the right edge.
+    Horizontal,
+
+    /// From the top edge to the bottom edge.
+    Vertical,
+}
+
+/// Settings for fitting a gradient to an image.
+///
+/// The image is first reduced to a profile, by averaging each column, for
+/// horizontal gradients, or each row, for vertical gradients, in [`Oklab`].
+/// The gradient is then fitted to the profile with least squares. For a
+/// given set of stop positions, the best stop colors are found exactly, and
+/// the positions of the inner stops are improved with a local search. The
+/// outer stops are always at `0.0` and `1.0`, which are the edges of the
+/// image.
+///
+/// ```
+/// use palette::gradient::{Direction, GradientFit};
+/// use palette::{IntoColor, Srgb};
+///
+/// // A tiny 4 by 2 image that goes from red to blue.
+/// let red = Srgb::new(1.0f32, 0.0, 0.0);
+/// let blue = Srgb::new(0.0, 0.0, 1.0);
+/// let pixels = [red, red, blue, blue, red, red, blue, blue];
+///
+/// let gradient = GradientFit::new(2, Direction::Horizontal).fit(&pixels, 4);
+/// let left: Srgb = gradient.get(0.0).into_color();
+/// assert!(left.red > left.blue);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GradientFit {
+    /// The number of stops in the gradient.
+    pub stops: usize,
+
+    /// The direction of the gradient.
+    pub direction: Direction,
+
+    /// The maximum number of passes over the inner stop positions.
+    pub iterations: usize,
+}
+
+impl GradientFit {
+    /// Create the settings for fitting a gradient with `stops` stops, in
+    /// `direction`. The positions are refined in up to 20 passes.
+    pub fn new(stops: usize, direction: Direction) -> Self {
+        GradientFit {
+            stops,
+            direction,
+            iterations: 20,
+        }
+    }
+
+    /// Change the maximum number of passes over the stop positions. `0`
+    /// keeps the stops evenly spaced.
+    pub fn with_iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Fit a gradient to an image with rows of `width` pixels.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the image is empty, or if the number of pixels is not a
+    /// multiple of `width`.
+    pub fn fit<C, T>(&self, pixels: &[C], width: usize) -> Gradient<Oklab<T>>
+    where
+        C: Copy + IntoColorUnclamped<Oklab<T>>,
+        T: FloatComponent,
+    {
+        assert!(width > 0 && !pixels.is_empty());
+        let height = pixels.len() / width;
+        assert_eq!(width * height, pixels.len());
+
+        let (length, count) = match self.direction {
+            Direction::Horizontal => (width, height),
+            Direction::Vertical => (height, width),
+        };
+
+        let mut profile = vec![[T::zero(); 3]; length];
+        for (index, &pixel) in pixels.iter().enumerate() {
+            let oklab: Oklab<T> = pixel.into_color_unclamped();
+            let position = match self.direction {
+                Direction::Horizontal => index % width,
+                Direction::Vertical => index / width,
+            };
+
+            let sum = &mut profile[position];
+            sum[0] = sum[0] + oklab.l;
+            sum[1] = sum[1] + oklab.a;
+            sum[2] = sum[2] + oklab.b;
+        }
+
+        let count = from_f64::<T>(count as f64);
+        let profile: Vec<_> = profile
+            .into_iter()
+            .map(|[l, a, b]| Oklab::new(l / count, a / count, b / count))
+            .collect();
+
+        self.fit_profile(&profile)
+    }
+
+    /// Fit a gradient to a profile, where the colors are evenly spaced
+    /// samples, such as the average of each column of an image.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the profile is empty.
+    pub fn fit_profile<T>(&self, profile: &[Oklab<T>]) -> Gradient<Oklab<T>>
+    where
+        T: FloatComponent,
+    {
+        assert!(!profile.is_empty());
+
+        let samples: Vec<_> = profile
+            .iter()
+            .enumerate()
+            .map(|(index, &color)| {
+                let position =
+                    (from_f64::<T>(index as f64) + from_f64(0.5)) / from_f64(profile.len() as f64);
+                (position, [color.l, color.a, color.b])
+            })
+            .collect();
+
+        if self.stops < 2 {
+            let mut mean = [T::zero(); 3];
+            for (_, color) in &samples {
+                for (mean, &value) in mean.iter_mut().zip(color) {
+                    *mean = *mean + value;
+                }
+            }
+            let count = from_f64::<T>(samples.len() as f64);
+            let color = Oklab::new(mean[0] / count, mean[1] / count, mean[2] / count);
+            return Gradient::with_domain(vec![(T::zero(), color)]);
+        }
+
+        let last = from_f64::<T>((self.stops - 1) as f64);
+        let mut positions: Vec<T> = (0..self.stops)
+            .map(|index| from_f64::<T>(index as f64) / last)
+            .collect();
+        let (mut colors, mut error) = solve(&samples, &positions);
+
+        // Move each inner stop back and forth, and halve the step size when
+        // nothing improves.
+        let mut step = T::one() / (last + last);
+        let min_gap = from_f64::<T>(1e-3);
+        for _ in 0..self.iterations {
+            let mut improved = false;
+
+            for index in 1..self.stops - 1 {
+                for &direction in &[-T::one(), T::one()] {
+                    let position = positions[index] + direction * step;
+                    if position <= positions[index - 1] + min_gap
+                        || position >= positions[index + 1] - min_gap
+                    {
+                        continue;
+                    }
+
+                    let previous = positions[index];
+                    positions[index] = position;
+                    let (new_colors, new_error) = solve(&samples, &positions);
+
+                    if new_error < error {
+                        colors = new_colors;
+                        error = new_error;
+                        improved = true;
+                        break;
+                    }
+
+                    positions[index] = previous;
+                }
+            }
+
+            if !improved {
+                step = step / from_f64(2.0);
+            }
+        }
+
+        Gradient::with_domain(
+            positions
+                .into_iter()
+                .zip(colors)
+                .map(|(position, [l, a, b])| (position, Oklab::new(l, a, b)))
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+/// Find the stop colors that give the least squared error for the stop
+/// `positions`, and the error.
+fn solve<T: FloatComponent>(samples: &[(T, [T; 3])], positions: &[T]) -> (Vec<[T; 3]>, T) {
+    let n = positions.len();
+    let mut diagonal = vec![T::zero(); n];
+    let mut off_diagonal = vec![T::zero(); n - 1];
+    let mut rhs = vec![[T::zero(); 3]; n];
+
+    // Each sample is a mix of the two stops around it.
+    for &(position, color) in samples {
+        let (index, factor) = segment(positions, position);
+        let weights = [T::one() - factor, factor];
+
+        diagonal[index] = diagonal[index] + weights[0] * weights[0];
+        diagonal[index + 1] = diagonal[index + 1] + weights[1] * weights[1];
+        off_diagonal[index] = off_diagonal[index] + weights[0] * weights[1];
+        for channel in 0..3 {
+            rhs[index][channel] = rhs[index][channel] + weights[0] * color[channel];
+            rhs[index + 1][channel] = rhs[index + 1][channel] + weights[1] * color[channel];
+        }
+    }
+
+    // A small pull towards the nearest sample keeps stops without any
+    // samples around them well defined.
+    let regularization = from_f64::<T>(1e-6);
+    for (index, &position) in positions.iter().enumerate() {
+        let nearest = samples
+            .iter()
+            .map(|&(sample_position, color)| ((sample_position - position).abs(), color))
+            .fold(
+                None,
+                |nearest: Option<(T, [T; 3])>, candidate| match nearest {
+                    Some(nearest) if nearest.0 <= candidate.0 => Some(nearest),
+                    _ => Some(candidate),
+                },
+            )
+            .map_or([T::zero(); 3], |(_, color)| color);
+
+        diagonal[index] = diagonal[index] + regularization;
+        for channel in 0..3 {
+            rhs[index][channel] = rhs[index][channel] + regularization * nearest[channel];
+        }
+    }
+
+    // The normal equations are tridiagonal, and are solved with the Thomas
+    // algorithm.
+    let mut c = vec![T::zero(); n];
+    let mut d = vec![[T::zero(); 3]; n];
+    for index in 0..n {
+        let lower = if index > 0 {
+            off_diagonal[index - 1]
+        } else {
+            T::zero()
+        };
+        let previous_c = if index > 0 { c[index - 1] } else { T::zero() };
+        let denominator = diagonal[index] - lower * previous_c;
+
+        if index + 1 < n {
+            c[index] = off_diagonal[index] / denominator;
+        }
+        for channel in 0..3 {
+            let previous_d = if index > 0 {
+                d[index - 1][channel]
+            } else {
+                T::zero()
+            };
+            d[index][channel] = (rhs[index][channel] - lower * previous_d) / denominator;
+        }
+    }
+
+    let mut colors = d;
+    for index in (0..n - 1).rev() {
+        let next = colors[index + 1];
+        for (value, &next) in colors[index].iter_mut().zip(&next) {
+            *value = *value - c[index] * next;
+        }
+    }
+
+    let mut error = T::zero();
+    for &(position, color) in samples {
+        let (index, factor) = segment(positions, position);
+        for channel in 0..3 {
+            let fitted = colors[index][channel]
+                + factor * (colors[index + 1][channel] - colors[index][channel]);
+            let difference = color[channel] - fitted;
+            error = error + difference * difference;
+        }
+    }
+
+    (colors, error)
+}
+
+/// Find the segment that contains `position`, and how far into it the
+/// position is.
+fn segment<T: FloatComponent>(positions: &[T], position: T) -> (usize, T) {
+    let index = positions[1..positions.len() - 1]
+        .iter()
+        .take_while(|&&stop| stop <= position)
+        .count();
+    let (start, end) = (positions[index], positions[index + 1]);
+    let factor = (position - start) / (end - start);
+
+    (index, factor.max(T::zero()).min(T::one()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Direction, GradientFit};
+    use crate::Oklab;
+
+    #[test]
+    fn exact_fit() {
+        // A profile that is piecewise linear with a bend at 0.3 should be
+        // matched by three stops.
+        let target = |x: f64| {
+            if x < 0.3 {
+                Oklab::new(0.2 + x, 0.1, -0.1)
+            } else {
+                Oklab::new(0.5 - (x - 0.3) * 0.5, 0.1 - (x - 0.3) * 0.2, -0.1)
+            }
+        };
+        let profile: Vec<_> = (0..100).map(|i| target((i as f64 + 0.5) / 100.0)).collect();
+
+        let gradient = GradientFit::new(3, Direction::Horizontal)
+            .with_iterations(40)
+            .fit_profile(&profile);
+
+        for i in 0..=20 {
+            let x = i as f64 / 20.0;
+            assert_relative_eq!(gradient.get(x), target(x), epsilon = 1e-2);
+        }
+    }
+
+    #[test]
+    fn image() {
+        let dark = Oklab::new(0.2f64, 0.0, 0.0);
+        let light = Oklab::new(0.9, 0.0, 0.0);
+
+        // Three rows of dark, one row of light, and four columns.
+        let mut pixels = vec![dark; 12];
+        pixels.extend(vec![light; 4]);
+
+        let vertical = GradientFit::new(2, Direction::Vertical).fit(&pixels, 4);
+        assert!(vertical.get(0.0).l < vertical.get(1.0).l);
+
+        let horizontal = GradientFit::new(2, Direction::Horizontal).fit(&pixels, 4);
+        assert_relative_eq!(horizontal.get(0.0), horizontal.get(1.0), epsilon = 1e-4);
+        assert_relative_eq!(horizontal.get(0.5).l, 0.375, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn single_stop() {
+        let profile = [Oklab::new(0.2f64, 0.0, 0.0), Oklab::new(0.6, 0.1, 0.0)];
+        let gradient = GradientFit::new(1, Direction::Horizontal).fit_profile(&profile);
+
+        assert_relative_eq!(gradient.get(0.3), Oklab::new(0.4, 0.05, 0.0));
+    }
+}
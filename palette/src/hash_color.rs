@@ -0,0 +1,303 @@
+//! Deterministic colors for hashable values.
+//!
+//! Avatars, log categories and graph nodes often get a color from their name
+//! or ID, so the same value always has the same color. Picking a random
+//! RGB color from a hash gives colors with very different lightness and
+//! saturation, and some of them are hard to read or to tell apart.
+//! [`HashColors`] picks the hue from the hash, and keeps the lightness and
+//! chroma fixed in [`Oklch`], so all colors look equally strong:
+//!
+//! ```
+//! use palette::hash_color::{self, HashColorPreset, HashColors};
+//! use palette::Srgb;
+//!
+//! let color: Srgb = hash_color::color_from_hash("alice");
+//! assert_eq!(color, hash_color::color_from_hash("alice"));
+//! assert_ne!(color, hash_color::color_from_hash("bob"));
+//!
+//! // Light colors for backgrounds behind dark text.
+//! let background: Srgb = HashColors::new()
+//!     .with_preset(HashColorPreset::Light)
+//!     .color("alice");
+//! ```
+//!
+//! The hue is the fractional part of the hash multiplied with the golden
+//! ratio, which spreads even similar hashes over the hue circle. The hash
+//! is computed with 64 bit FNV-1a instead of the standard library's default
+//! hasher, so the colors stay the same across program runs and Rust
+//! versions. Note that the [`Hash`] implementations of some types, such as
+//! slices and `usize`, depend on the pointer width of the platform.
+//!
+//! Hue alone can't separate colors for people with color vision
+//! deficiencies. [`with_cvd_safe`](HashColors::with_cvd_safe) picks the
+//! colors from the Okabe-Ito palette instead, which stays distinguishable
+//! for all common types of color vision deficiency, but only has seven
+//! colors.
+
+use core::hash::{Hash, Hasher};
+
+use crate::convert::{IntoColor, IntoColorUnclamped};
+use crate::theme::fit_chroma;
+use crate::{from_f64, FloatComponent, Oklch, Srgb};
+
+/// The 64 bit golden ratio constant, `2^64 / φ`, for Fibonacci hashing.
+const GOLDEN_RATIO: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// The Okabe-Ito palette, without black.
+const OKABE_ITO: [[u8; 3]; 7] = [
+    [230, 159, 0],
+    [86, 180, 233],
+    [0, 158, 115],
+    [240, 228, 66],
+    [0, 114, 178],
+    [213, 94, 0],
+    [204, 121, 167],
+];
+
+/// Get a color for `input`, with the [`Medium`](HashColorPreset::Medium)
+/// preset. See [`HashColors`] for more options.
+pub fn color_from_hash<C, T, H>(input: &H) -> C
+where
+    H: Hash + ?Sized,
+    T: FloatComponent,
+    Oklch<T>: IntoColor<C>,
+{
+    HashColors::new().color(input)
+}
+
+/// Lightness and chroma presets for [`HashColors`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub enum HashColorPreset {
+    /// Pale colors, for backgrounds behind dark text. The lightness is
+    /// `0.88` and the chroma is `0.06`.
+    Light,
+    /// Colors for chart marks, tags and avatars with white text on them. The
+    /// lightness is `0.65` and the chroma is `0.14`.
+    Medium,
+    /// Dark colors, for text on light backgrounds. The lightness is `0.45`
+    /// and the chroma is `0.12`.
+    Dark,
+}
+
+impl HashColorPreset {
+    fn lightness_and_chroma<T: FloatComponent>(self) -> (T, T) {
+        let (lightness, chroma) = match self {
+            HashColorPreset::Light => (0.88, 0.06),
+            HashColorPreset::Medium => (0.65, 0.14),
+            HashColorPreset::Dark => (0.45, 0.12),
+        };
+
+        (from_f64(lightness), from_f64(chroma))
+    }
+}
+
+/// Rules for picking colors for hashable values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct HashColors<T> {
+    /// The Oklab lightness of the colors.
+    pub lightness: T,
+
+    /// The Oklch chroma of the colors. It's reduced for hues where it would
+    /// be outside the sRGB gamut.
+    pub chroma: T,
+
+    /// Pick the colors from the Okabe-Ito palette, which is safe for color
+    /// vision deficiencies, instead of using the lightness and chroma.
+    pub cvd_safe: bool,
+}
+
+impl<T> HashColors<T>
+where
+    T: FloatComponent,
+{
+    /// Create rules with the [`Medium`](HashColorPreset::Medium) preset.
+    pub fn new() -> Self {
+        let (lightness, chroma) = HashColorPreset::Medium.lightness_and_chroma();
+
+        HashColors {
+            lightness,
+            chroma,
+            cvd_safe: false,
+        }
+    }
+
+    /// Change the lightness and chroma to `preset`.
+    pub fn with_preset(mut self, preset: HashColorPreset) -> Self {
+        let (lightness, chroma) = preset.lightness_and_chroma();
+        self.lightness = lightness;
+        self.chroma = chroma;
+        self
+    }
+
+    /// Change the lightness and chroma.
+    pub fn with_lightness_and_chroma(mut self, lightness: T, chroma: T) -> Self {
+        self.lightness = lightness;
+        self.chroma = chroma;
+        self
+    }
+
+    /// Pick the colors from the Okabe-Ito palette, if `cvd_safe` is `true`.
+    pub fn with_cvd_safe(mut self, cvd_safe: bool) -> Self {
+        self.cvd_safe = cvd_safe;
+        self
+    }
+
+    /// Get the color for `input`, in Oklch.
+    pub fn oklch<H>(&self, input: &H) -> Oklch<T>
+    where
+        H: Hash + ?Sized,
+    {
+        let hash = stable_hash(input).wrapping_mul(GOLDEN_RATIO);
+
+        if self.cvd_safe {
+            // The top bits of the product are the best mixed ones.
+            let index = ((hash >> 32) * OKABE_ITO.len() as u64) >> 32;
+            let [red, green, blue] = OKABE_ITO[index as usize];
+
+            let component = |value: u8| from_f64::<T>(f64::from(value) / 255.0);
+            return Srgb::new(component(red), component(green), component(blue))
+                .into_color_unclamped();
+        }
+
+        let fraction = (hash >> 11) as f64 / (1u64 << 53) as f64;
+        fit_chroma(Oklch::new(
+            self.lightness,
+            self.chroma,
+            from_f64::<T>(fraction * 360.0),
+        ))
+    }
+
+    /// Get the color for `input`.
+    pub fn color<C, H>(&self, input: &H) -> C
+    where
+        H: Hash + ?Sized,
+        Oklch<T>: IntoColor<C>,
+    {
+        self.oklch(input).into_color()
+    }
+}
+
+impl<T> Default for HashColors<T>
+where
+    T: FloatComponent,
+{
+    fn default() -> Self {
+        HashColors::new()
+    }
+}
+
+/// Hash `input` with 64 bit FNV-1a, which doesn't change between program
+/// runs or Rust versions.
+fn stable_hash<H: Hash + ?Sized>(input: &H) -> u64 {
+    let mut hasher = Fnv1a(0xcbf2_9ce4_8422_2325);
+    input.hash(&mut hasher);
+    hasher.finish()
+}
+
+struct Fnv1a(u64);
+
+impl Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{color_from_hash, stable_hash, HashColorPreset, HashColors};
+    use crate::cvd::{CvdSimulation, Deficiency};
+    use crate::{ColorDifference, FromColor, IsWithinBounds, Lab, Oklch, Srgb};
+
+    #[test]
+    fn stable() {
+        // The FNV-1a test vector for "a".
+        let mut hasher = super::Fnv1a(0xcbf2_9ce4_8422_2325);
+        core::hash::Hasher::write(&mut hasher, b"a");
+        assert_eq!(core::hash::Hasher::finish(&hasher), 0xaf63_dc4c_8601_ec8c);
+
+        assert_eq!(stable_hash("alice"), stable_hash("alice"));
+        assert_ne!(stable_hash("alice"), stable_hash("alicf"));
+
+        let color: Srgb<u8> =
+            Srgb::from_color(HashColors::<f64>::new().oklch("alice")).into_format();
+        let again: Srgb<u8> =
+            Srgb::from_color(HashColors::<f64>::new().oklch("alice")).into_format();
+        assert_eq!(color, again);
+    }
+
+    #[test]
+    fn presets() {
+        for (i, &preset) in [
+            HashColorPreset::Light,
+            HashColorPreset::Medium,
+            HashColorPreset::Dark,
+        ]
+        .iter()
+        .enumerate()
+        {
+            let rules = HashColors::<f64>::new().with_preset(preset);
+            for id in 0..100u32 {
+                let color = rules.oklch(&id);
+                assert_eq!(color.l, rules.lightness, "preset {}", i);
+                assert!(color.chroma <= rules.chroma);
+
+                let srgb: Srgb<f64> = rules.color(&id);
+                assert!(srgb.is_within_bounds());
+            }
+        }
+    }
+
+    #[test]
+    fn spreads_hues() {
+        let mut bins = [0; 12];
+        for id in 0..1200u32 {
+            let color: Oklch<f64> = color_from_hash(&id);
+            let hue = color.hue.to_positive_degrees();
+            bins[(hue / 30.0) as usize % 12] += 1;
+        }
+
+        for &count in &bins {
+            assert!(count > 50 && count < 150, "{:?}", bins);
+        }
+    }
+
+    #[test]
+    fn cvd_safe() {
+        let rules = HashColors::<f64>::new().with_cvd_safe(true);
+        let mut colors: Vec<Srgb<f64>> = Vec::new();
+        for id in 0..200u32 {
+            let color: Srgb<f64> = rules.color(&id);
+            if !colors.iter().any(|&other| {
+                Lab::from_color(other).get_color_difference(Lab::from_color(color)) < 1.0
+            }) {
+                colors.push(color);
+            }
+        }
+
+        assert_eq!(colors.len(), 7);
+
+        for &deficiency in &[
+            Deficiency::Protanopia,
+            Deficiency::Deuteranopia,
+            Deficiency::Tritanopia,
+        ] {
+            let simulation = CvdSimulation::full(deficiency);
+            for (i, &a) in colors.iter().enumerate() {
+                for &b in &colors[i + 1..] {
+                    let a: Lab<_, f64> = Lab::from_color(simulation.simulate(a));
+                    let b = Lab::from_color(simulation.simulate(b));
+                    assert!(a.get_color_difference(b) > 5.0);
+                }
+            }
+        }
+    }
+}
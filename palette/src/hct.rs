@@ -0,0 +1,932 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use core::ops::{Add, AddAssign};
+use num_traits::Zero;
+
+use crate::convert::FromColorUnclamped;
+use crate::float;
+use crate::white_point::D65;
+use crate::{
+    clamp, clamp_assign, clamp_min, clamp_min_assign, from_f64, Alpha, Cam16Hue, Clamp,
+    ClampAssign, FloatComponent, FromF64, GetHue, IsWithinBounds, LinSrgb, Mix, MixAssign, SetHue,
+    ShiftHue, ShiftHueAssign, Srgb, WithHue, Xyz,
+};
+
+/// HCT with an alpha component. See the [`Hcta` implementation in
+/// `Alpha`](crate::Alpha#Hcta).
+pub type Hcta<T = f32> = Alpha<Hct<T>, T>;
+
+/// The HCT color space, from Material Design.
+///
+/// HCT combines the hue and chroma of the CAM16 color appearance model with
+/// the lightness of [L\*a\*b\*](crate::Lab), which is called tone. Colors with
+/// the same difference in tone have about the same contrast, regardless of
+/// their hue and chroma, which makes HCT useful for generating color schemes.
+/// CAM16 is evaluated with the default viewing conditions of Material Design:
+/// a D65 white point, an average surround and a background with a tone of 50.
+///
+/// Many combinations of hue, chroma and tone are outside of the sRGB gamut.
+/// [`map_to_srgb_gamut`](Hct::map_to_srgb_gamut) reduces the chroma until the
+/// color fits, while keeping its hue and tone, and
+/// [`into_srgb`](Hct::into_srgb) does the same and converts the result:
+///
+/// ```
+/// use palette::{Hct, Srgb};
+///
+/// let color = Hct::new(280.0f32, 200.0, 40.0);
+/// let srgb: Srgb = color.into_srgb();
+/// ```
+///
+/// The conversion from HCT has to search for the CAM16 lightness that matches
+/// the tone, which makes it slower than the conversions between most other
+/// color spaces.
+#[derive(Debug, ArrayCast, FromColorUnclamped, WithAlpha)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[palette(
+    palette_internal,
+    white_point = "D65",
+    component = "T",
+    skip_derives(Hct, Xyz)
+)]
+#[repr(C)]
+pub struct Hct<T = f32> {
+    /// The CAM16 hue, in degrees. 0 and 360 are the same hue.
+    #[palette(unsafe_same_layout_as = "T")]
+    pub hue: Cam16Hue<T>,
+
+    /// The CAM16 chroma. 0 is gray, and the most saturated sRGB colors have a
+    /// chroma somewhere between 40 and 115, depending on their hue and tone.
+    pub chroma: T,
+
+    /// The L\* lightness. 0 gives absolute black and 100 gives the brightest
+    /// white.
+    pub tone: T,
+}
+
+impl<T> Copy for Hct<T> where T: Copy {}
+
+impl<T> Clone for Hct<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Hct<T> {
+        Hct {
+            hue: self.hue.clone(),
+            chroma: self.chroma.clone(),
+            tone: self.tone.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for Hct<T>
+where
+    T: PartialEq,
+    Cam16Hue<T>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.hue == other.hue && self.chroma == other.chroma && self.tone == other.tone
+    }
+}
+
+impl<T> Eq for Hct<T>
+where
+    T: Eq,
+    Cam16Hue<T>: Eq,
+{
+}
+
+impl<T> AbsDiffEq for Hct<T>
+where
+    T: FloatComponent + AbsDiffEq,
+    T::Epsilon: FloatComponent,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        self.hue.abs_diff_eq(&other.hue, epsilon)
+            && self.chroma.abs_diff_eq(&other.chroma, epsilon)
+            && self.tone.abs_diff_eq(&other.tone, epsilon)
+    }
+}
+
+impl<T> RelativeEq for Hct<T>
+where
+    T: FloatComponent + RelativeEq,
+    T::Epsilon: FloatComponent,
+{
+    fn default_max_relative() -> T::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.hue.relative_eq(&other.hue, epsilon, max_relative)
+            && self
+                .chroma
+                .relative_eq(&other.chroma, epsilon, max_relative)
+            && self.tone.relative_eq(&other.tone, epsilon, max_relative)
+    }
+}
+
+impl<T> UlpsEq for Hct<T>
+where
+    T: FloatComponent + UlpsEq,
+    T::Epsilon: FloatComponent,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.hue.ulps_eq(&other.hue, epsilon, max_ulps)
+            && self.chroma.ulps_eq(&other.chroma, epsilon, max_ulps)
+            && self.tone.ulps_eq(&other.tone, epsilon, max_ulps)
+    }
+}
+
+impl<T> Hct<T> {
+    /// Create an HCT color.
+    pub fn new<H: Into<Cam16Hue<T>>>(hue: H, chroma: T, tone: T) -> Self {
+        Self::new_const(hue.into(), chroma, tone)
+    }
+
+    /// Create an HCT color. This is the same as `Hct::new` without the
+    /// generic hue type. It's temporary until `const fn` supports traits.
+    pub const fn new_const(hue: Cam16Hue<T>, chroma: T, tone: T) -> Self {
+        Hct { hue, chroma, tone }
+    }
+
+    /// Convert to a `(hue, chroma, tone)` tuple.
+    pub fn into_components(self) -> (Cam16Hue<T>, T, T) {
+        (self.hue, self.chroma, self.tone)
+    }
+
+    /// Convert from a `(hue, chroma, tone)` tuple.
+    pub fn from_components<H: Into<Cam16Hue<T>>>((hue, chroma, tone): (H, T, T)) -> Self {
+        Self::new(hue, chroma, tone)
+    }
+}
+
+impl<T> Hct<T>
+where
+    T: Zero + FromF64,
+{
+    /// Return the `chroma` value minimum.
+    pub fn min_chroma() -> T {
+        T::zero()
+    }
+
+    /// Return the `tone` value minimum.
+    pub fn min_tone() -> T {
+        T::zero()
+    }
+
+    /// Return the `tone` value maximum.
+    pub fn max_tone() -> T {
+        from_f64(100.0)
+    }
+}
+
+impl<T> Hct<T>
+where
+    T: FloatComponent,
+{
+    /// Reduce the chroma until the color is within the sRGB gamut, while
+    /// keeping its hue and tone.
+    ///
+    /// Colors that are already within the gamut are returned unchanged. Very
+    /// dark and very light colors may still be slightly outside of the gamut
+    /// with a chroma of 0, since the gray colors of CAM16 aren't exactly the
+    /// same as the gray colors of sRGB.
+    pub fn map_to_srgb_gamut(self) -> Self {
+        self.map_to_srgb_gamut_with(&ViewingConditions::material())
+    }
+
+    /// Convert to sRGB, after reducing the chroma with
+    /// [`map_to_srgb_gamut`](Hct::map_to_srgb_gamut). The result is clamped
+    /// to remove the remaining rounding errors.
+    pub fn into_srgb(self) -> Srgb<T> {
+        let conditions = ViewingConditions::material();
+        let mapped = self.map_to_srgb_gamut_with(&conditions);
+        let linear = LinSrgb::from_color_unclamped(conditions.hct_to_xyz(mapped));
+        Srgb::from_color_unclamped(linear.clamp())
+    }
+
+    fn map_to_srgb_gamut_with(self, conditions: &ViewingConditions<T>) -> Self {
+        let color = Hct::new(self.hue, clamp_min(self.chroma, T::zero()), self.tone);
+        if color.chroma == T::zero() || fits_srgb(color, conditions) {
+            return color;
+        }
+
+        let mut low = T::zero();
+        let mut high = color.chroma;
+        for _ in 0..GAMUT_ITERATIONS {
+            let middle = (low + high) / from_f64(2.0);
+            if fits_srgb(Hct::new(color.hue, middle, color.tone), conditions) {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+
+        Hct::new(color.hue, low, color.tone)
+    }
+}
+
+///<span id="Hcta"></span>[`Hcta`](crate::Hcta) implementations.
+impl<T, A> Alpha<Hct<T>, A> {
+    /// Create an HCT color with transparency.
+    pub fn new<H: Into<Cam16Hue<T>>>(hue: H, chroma: T, tone: T, alpha: A) -> Self {
+        Self::new_const(hue.into(), chroma, tone, alpha)
+    }
+
+    /// Create an HCT color with transparency. This is the same as
+    /// `Hcta::new` without the generic hue type. It's temporary until `const
+    /// fn` supports traits.
+    pub const fn new_const(hue: Cam16Hue<T>, chroma: T, tone: T, alpha: A) -> Self {
+        Alpha {
+            color: Hct::new_const(hue, chroma, tone),
+            alpha,
+        }
+    }
+
+    /// Convert to a `(hue, chroma, tone, alpha)` tuple.
+    pub fn into_components(self) -> (Cam16Hue<T>, T, T, A) {
+        (
+            self.color.hue,
+            self.color.chroma,
+            self.color.tone,
+            self.alpha,
+        )
+    }
+
+    /// Convert from a `(hue, chroma, tone, alpha)` tuple.
+    pub fn from_components<H: Into<Cam16Hue<T>>>((hue, chroma, tone, alpha): (H, T, T, A)) -> Self {
+        Self::new(hue, chroma, tone, alpha)
+    }
+}
+
+impl<T> FromColorUnclamped<Hct<T>> for Hct<T> {
+    fn from_color_unclamped(color: Self) -> Self {
+        color
+    }
+}
+
+impl<T> FromColorUnclamped<Xyz<D65, T>> for Hct<T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Xyz<D65, T>) -> Self {
+        trace!(conversion color);
+        let conditions = ViewingConditions::material();
+        let hundred: T = from_f64(100.0);
+        let (_, chroma, hue) =
+            conditions.xyz_to_cam16([color.x * hundred, color.y * hundred, color.z * hundred]);
+
+        Hct::new(hue, chroma, lstar_from_y(color.y))
+    }
+}
+
+impl<T, H: Into<Cam16Hue<T>>> From<(H, T, T)> for Hct<T> {
+    fn from(components: (H, T, T)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T> From<Hct<T>> for (Cam16Hue<T>, T, T) {
+    fn from(color: Hct<T>) -> (Cam16Hue<T>, T, T) {
+        color.into_components()
+    }
+}
+
+impl<T, H: Into<Cam16Hue<T>>, A> From<(H, T, T, A)> for Alpha<Hct<T>, A> {
+    fn from(components: (H, T, T, A)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T, A> From<Alpha<Hct<T>, A>> for (Cam16Hue<T>, T, T, A) {
+    fn from(color: Alpha<Hct<T>, A>) -> (Cam16Hue<T>, T, T, A) {
+        color.into_components()
+    }
+}
+
+impl_tuple_ref_conversions!([T] [H] Hct<T>, (H, T, T) => (Cam16Hue<T>, T, T));
+impl_tuple_ref_conversions!([T, A] [H] Alpha<Hct<T>, A>, (H, T, T, A) => (Cam16Hue<T>, T, T, A));
+
+impl<T> IsWithinBounds for Hct<T>
+where
+    T: Zero + FromF64 + PartialOrd,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn is_within_bounds(&self) -> bool {
+        self.chroma >= Self::min_chroma() &&
+        self.tone >= Self::min_tone() && self.tone <= Self::max_tone()
+    }
+}
+
+impl<T> Clamp for Hct<T>
+where
+    T: Zero + FromF64 + PartialOrd,
+{
+    #[inline]
+    fn clamp(self) -> Self {
+        Self::new(
+            self.hue,
+            clamp_min(self.chroma, Self::min_chroma()),
+            clamp(self.tone, Self::min_tone(), Self::max_tone()),
+        )
+    }
+}
+
+impl<T> ClampAssign for Hct<T>
+where
+    T: Zero + FromF64 + PartialOrd,
+{
+    #[inline]
+    fn clamp_assign(&mut self) {
+        clamp_min_assign(&mut self.chroma, Self::min_chroma());
+        clamp_assign(&mut self.tone, Self::min_tone(), Self::max_tone());
+    }
+}
+
+impl<T> Mix for Hct<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn mix(self, other: Self, factor: T) -> Self {
+        let factor = clamp(factor, T::zero(), T::one());
+        let hue_diff = (other.hue - self.hue).to_degrees();
+
+        Hct {
+            hue: self.hue + factor * hue_diff,
+            chroma: self.chroma + factor * (other.chroma - self.chroma),
+            tone: self.tone + factor * (other.tone - self.tone),
+        }
+    }
+}
+
+impl<T> MixAssign for Hct<T>
+where
+    T: FloatComponent + AddAssign,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn mix_assign(&mut self, other: Self, factor: T) {
+        let factor = clamp(factor, T::zero(), T::one());
+        let hue_diff = (other.hue - self.hue).to_degrees();
+
+        self.hue += factor * hue_diff;
+        self.chroma += factor * (other.chroma - self.chroma);
+        self.tone += factor * (other.tone - self.tone);
+    }
+}
+
+impl<T> GetHue for Hct<T>
+where
+    T: Zero + PartialOrd + Clone,
+{
+    type Hue = Cam16Hue<T>;
+
+    #[inline]
+    fn get_hue(&self) -> Option<Cam16Hue<T>> {
+        if self.chroma <= T::zero() {
+            None
+        } else {
+            Some(self.hue.clone())
+        }
+    }
+}
+
+impl<T, H> WithHue<H> for Hct<T>
+where
+    H: Into<Cam16Hue<T>>,
+{
+    #[inline]
+    fn with_hue(mut self, hue: H) -> Self {
+        self.hue = hue.into();
+        self
+    }
+}
+
+impl<T, H> SetHue<H> for Hct<T>
+where
+    H: Into<Cam16Hue<T>>,
+{
+    #[inline]
+    fn set_hue(&mut self, hue: H) {
+        self.hue = hue.into();
+    }
+}
+
+impl<T> ShiftHue for Hct<T>
+where
+    T: Add<Output = T>,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn shift_hue(mut self, amount: Self::Scalar) -> Self {
+        self.hue = self.hue + amount;
+        self
+    }
+}
+
+impl<T> ShiftHueAssign for Hct<T>
+where
+    T: AddAssign,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn shift_hue_assign(&mut self, amount: Self::Scalar) {
+        self.hue += amount;
+    }
+}
+
+impl<T> Default for Hct<T>
+where
+    T: Zero,
+{
+    fn default() -> Self {
+        Self::new(T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl_array_casts!(Hct<T>, [T; 3]);
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Zeroable for Hct<T> where T: bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Pod for Hct<T> where T: bytemuck::Pod {}
+
+/// The number of bisection steps when searching for the CAM16 lightness.
+const LIGHTNESS_ITERATIONS: usize = 48;
+
+/// The number of bisection steps when searching for the largest chroma
+/// within the sRGB gamut.
+const GAMUT_ITERATIONS: usize = 32;
+
+/// How far outside of the sRGB gamut a color can be, and still count as
+/// within it. This covers the rounding errors from the conversions.
+const GAMUT_TOLERANCE: f64 = 1e-5;
+
+/// XYZ to CAM16's sharpened cone responses.
+const M16: [[f64; 3]; 3] = [
+    [0.401288, 0.650173, -0.051461],
+    [-0.250268, 1.204414, 0.045854],
+    [-0.002079, 0.048952, 0.953127],
+];
+
+/// CAM16's sharpened cone responses to XYZ.
+const M16_INV: [[f64; 3]; 3] = [
+    [1.86206786, -1.01125463, 0.14918677],
+    [0.38752654, 0.62144744, -0.00897398],
+    [-0.01584150, -0.03412294, 1.04996444],
+];
+
+fn multiply<T: FloatComponent>(matrix: &[[f64; 3]; 3], [a, b, c]: [T; 3]) -> [T; 3] {
+    let row = |row: &[f64; 3]| -> T {
+        from_f64::<T>(row[0]) * a + from_f64::<T>(row[1]) * b + from_f64::<T>(row[2]) * c
+    };
+
+    [row(&matrix[0]), row(&matrix[1]), row(&matrix[2])]
+}
+
+fn fits_srgb<T: FloatComponent>(color: Hct<T>, conditions: &ViewingConditions<T>) -> bool {
+    let LinSrgb {
+        red, green, blue, ..
+    } = LinSrgb::from_color_unclamped(conditions.hct_to_xyz(color));
+    let min = -from_f64::<T>(GAMUT_TOLERANCE);
+    let max = T::one() + from_f64(GAMUT_TOLERANCE);
+
+    [red, green, blue]
+        .iter()
+        .all(|&component| component >= min && component <= max)
+}
+
+/// Convert relative luminance, from 0 to 1, to L\*.
+fn lstar_from_y<T: FloatComponent>(y: T) -> T {
+    let epsilon: T = from_f64(216.0 / 24389.0);
+    let kappa: T = from_f64(24389.0 / 27.0);
+
+    if y > epsilon {
+        from_f64::<T>(116.0) * float::cbrt(y) - from_f64(16.0)
+    } else {
+        kappa * y
+    }
+}
+
+/// Convert L\* to relative luminance, from 0 to 1.
+fn y_from_lstar<T: FloatComponent>(lstar: T) -> T {
+    let kappa: T = from_f64(24389.0 / 27.0);
+
+    if lstar > kappa * from_f64(216.0 / 24389.0) {
+        let f = (lstar + from_f64(16.0)) / from_f64(116.0);
+        f * f * f
+    } else {
+        lstar / kappa
+    }
+}
+
+/// The viewing conditions of CAM16, with the derived parameters.
+struct ViewingConditions<T> {
+    n: T,
+    aw: T,
+    nbb: T,
+    ncb: T,
+    c: T,
+    nc: T,
+    rgb_d: [T; 3],
+    fl: T,
+    z: T,
+}
+
+impl<T> ViewingConditions<T>
+where
+    T: FloatComponent,
+{
+    /// The default viewing conditions of Material Design.
+    fn material() -> Self {
+        let white_point = [from_f64(95.047), from_f64(100.0), from_f64(108.883)];
+        let adapting_luminance =
+            from_f64::<T>(200.0 / core::f64::consts::PI) * y_from_lstar(from_f64(50.0));
+        let background_y: T = y_from_lstar(from_f64(50.0));
+
+        // An average surround.
+        let f = T::one();
+        let c = from_f64(0.69);
+
+        let rgb_w = multiply(&M16, white_point);
+        let degree = f
+            * (T::one()
+                - from_f64::<T>(1.0 / 3.6)
+                    * float::exp((-adapting_luminance - from_f64(42.0)) / from_f64(92.0)));
+        let degree = clamp(degree, T::zero(), T::one());
+        let hundred: T = from_f64(100.0);
+        let rgb_d = [
+            degree * hundred / rgb_w[0] + T::one() - degree,
+            degree * hundred / rgb_w[1] + T::one() - degree,
+            degree * hundred / rgb_w[2] + T::one() - degree,
+        ];
+
+        let five_la = from_f64::<T>(5.0) * adapting_luminance;
+        let k = T::one() / (five_la + T::one());
+        let k4 = k * k * k * k;
+        let k4f = T::one() - k4;
+        let fl = k4 * adapting_luminance + from_f64::<T>(0.1) * k4f * k4f * float::cbrt(five_la);
+
+        let n = background_y;
+        let z = from_f64::<T>(1.48) + n.sqrt();
+        let nbb = from_f64::<T>(0.725) / float::powf(n, from_f64(0.2));
+
+        let mut conditions = ViewingConditions {
+            n,
+            aw: T::zero(),
+            nbb,
+            ncb: nbb,
+            c,
+            nc: f,
+            rgb_d,
+            fl,
+            z,
+        };
+
+        let [red, green, blue] = conditions.adapt([
+            rgb_d[0] * rgb_w[0],
+            rgb_d[1] * rgb_w[1],
+            rgb_d[2] * rgb_w[2],
+        ]);
+        conditions.aw = (from_f64::<T>(2.0) * red + green + from_f64::<T>(0.05) * blue) * nbb;
+
+        conditions
+    }
+
+    /// Apply the nonlinear response compression to discounted cone
+    /// responses.
+    fn adapt(&self, components: [T; 3]) -> [T; 3] {
+        let adapt = |component: T| {
+            let factor = float::powf(self.fl * component.abs() / from_f64(100.0), from_f64(0.42));
+            component.signum() * from_f64(400.0) * factor / (factor + from_f64(27.13))
+        };
+
+        [
+            adapt(components[0]),
+            adapt(components[1]),
+            adapt(components[2]),
+        ]
+    }
+
+    /// Undo [`adapt`](ViewingConditions::adapt).
+    fn unadapt(&self, components: [T; 3]) -> [T; 3] {
+        let unadapt = |component: T| {
+            let base = clamp_min(
+                from_f64::<T>(27.13) * component.abs() / (from_f64::<T>(400.0) - component.abs()),
+                T::zero(),
+            );
+            component.signum() * from_f64::<T>(100.0) / self.fl
+                * float::powf(base, from_f64(1.0 / 0.42))
+        };
+
+        [
+            unadapt(components[0]),
+            unadapt(components[1]),
+            unadapt(components[2]),
+        ]
+    }
+
+    /// The eccentricity factor of a hue, in radians.
+    fn eccentricity(&self, hue: T) -> T {
+        from_f64::<T>(0.25) * (float::cos(hue + from_f64(2.0)) + from_f64(3.8))
+    }
+
+    /// The factor that converts between the chroma and the `t` parameter.
+    fn alpha_factor(&self) -> T {
+        float::powf(
+            from_f64::<T>(1.64) - float::powf(from_f64(0.29), self.n),
+            from_f64(0.73),
+        )
+    }
+
+    /// Convert XYZ, from 0 to 100, to the CAM16 lightness, chroma and hue.
+    fn xyz_to_cam16(&self, xyz: [T; 3]) -> (T, T, T) {
+        let [red, green, blue] = multiply(&M16, xyz);
+        let [red, green, blue] = self.adapt([
+            self.rgb_d[0] * red,
+            self.rgb_d[1] * green,
+            self.rgb_d[2] * blue,
+        ]);
+
+        let a = (from_f64::<T>(11.0) * red - from_f64::<T>(12.0) * green + blue) / from_f64(11.0);
+        let b = (red + green - from_f64::<T>(2.0) * blue) / from_f64(9.0);
+        let u =
+            (from_f64::<T>(20.0) * red + from_f64::<T>(20.0) * green + from_f64::<T>(21.0) * blue)
+                / from_f64(20.0);
+        let p2 = (from_f64::<T>(40.0) * red + from_f64::<T>(20.0) * green + blue) / from_f64(20.0);
+
+        let hue = float::atan2(b, a).to_degrees();
+        let hue = if hue < T::zero() {
+            hue + from_f64(360.0)
+        } else {
+            hue
+        };
+
+        let achromatic_response = p2 * self.nbb;
+        let lightness = from_f64::<T>(100.0)
+            * float::powf(
+                clamp_min(achromatic_response / self.aw, T::zero()),
+                self.c * self.z,
+            );
+
+        let p1 = from_f64::<T>(50000.0 / 13.0)
+            * self.eccentricity(hue.to_radians())
+            * self.nc
+            * self.ncb;
+        let t = p1 * float::hypot(a, b) / (u + from_f64(0.305));
+        let chroma = float::powf(t, from_f64(0.9))
+            * self.alpha_factor()
+            * (lightness / from_f64(100.0)).sqrt();
+
+        (lightness, chroma, hue)
+    }
+
+    /// Convert the CAM16 lightness, chroma and hue to XYZ, from 0 to 100.
+    fn cam16_to_xyz(&self, lightness: T, chroma: T, hue: T) -> [T; 3] {
+        let alpha = if chroma == T::zero() || lightness <= T::zero() {
+            T::zero()
+        } else {
+            chroma / (lightness / from_f64(100.0)).sqrt()
+        };
+        let t = float::powf(alpha / self.alpha_factor(), from_f64(1.0 / 0.9));
+
+        let hue = hue.to_radians();
+        let achromatic_response = self.aw
+            * float::powf(
+                clamp_min(lightness, T::zero()) / from_f64(100.0),
+                T::one() / self.c / self.z,
+            );
+        let p1 = self.eccentricity(hue) * from_f64(50000.0 / 13.0) * self.nc * self.ncb;
+        let p2 = achromatic_response / self.nbb;
+
+        let (sin, cos) = float::sin_cos(hue);
+        let gamma = from_f64::<T>(23.0) * (p2 + from_f64(0.305)) * t
+            / (from_f64::<T>(23.0) * p1
+                + from_f64::<T>(11.0) * t * cos
+                + from_f64::<T>(108.0) * t * sin);
+        let a = gamma * cos;
+        let b = gamma * sin;
+
+        let denominator: T = from_f64(1403.0);
+        let red = (from_f64::<T>(460.0) * p2 + from_f64::<T>(451.0) * a + from_f64::<T>(288.0) * b)
+            / denominator;
+        let green =
+            (from_f64::<T>(460.0) * p2 - from_f64::<T>(891.0) * a - from_f64::<T>(261.0) * b)
+                / denominator;
+        let blue =
+            (from_f64::<T>(460.0) * p2 - from_f64::<T>(220.0) * a - from_f64::<T>(6300.0) * b)
+                / denominator;
+
+        let [red, green, blue] = self.unadapt([red, green, blue]);
+        multiply(
+            &M16_INV,
+            [
+                red / self.rgb_d[0],
+                green / self.rgb_d[1],
+                blue / self.rgb_d[2],
+            ],
+        )
+    }
+
+    /// Convert HCT to XYZ by searching for the CAM16 lightness that gives
+    /// the luminance of the tone.
+    fn hct_to_xyz(&self, color: Hct<T>) -> Xyz<D65, T> {
+        if color.tone <= T::zero() {
+            return Xyz::new(T::zero(), T::zero(), T::zero());
+        }
+
+        let hundred: T = from_f64(100.0);
+        let chroma = clamp_min(color.chroma, T::zero());
+        let hue = color.hue.to_raw_degrees();
+        let target = y_from_lstar(color.tone) * hundred;
+        let luminance = |lightness: T| self.cam16_to_xyz(lightness, chroma, hue)[1];
+
+        // Find an upper bound first, since colors with a high chroma can have a
+        // CAM16 lightness above 100.
+        let mut low = T::zero();
+        let mut high = hundred;
+        for _ in 0..8 {
+            if luminance(high) >= target {
+                break;
+            }
+
+            low = high;
+            high = high + high;
+        }
+
+        for _ in 0..LIGHTNESS_ITERATIONS {
+            let middle = (low + high) / from_f64(2.0);
+            if luminance(middle) < target {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+
+        let [x, y, z] = self.cam16_to_xyz((low + high) / from_f64(2.0), chroma, hue);
+        Xyz::new(x / hundred, y / hundred, z / hundred)
+    }
+}
+
+/// Convert HCT to XYZ with the default viewing conditions of Material Design.
+pub(crate) fn hct_to_xyz<T>(color: Hct<T>) -> Xyz<D65, T>
+where
+    T: FloatComponent,
+{
+    ViewingConditions::material().hct_to_xyz(color)
+}
+
+#[cfg(test)]
+mod test {
+    use super::Hct;
+    use crate::convert::FromColorUnclamped;
+    use crate::{FromColor, GetHue, IsWithinBounds, LinSrgb, Mix, ShiftHue, Srgb};
+
+    fn from_srgb(red: u8, green: u8, blue: u8) -> Hct<f64> {
+        Hct::from_color(Srgb::new(red, green, blue).into_format::<f64>())
+    }
+
+    #[test]
+    fn material_reference_values() {
+        // Values from material-color-utilities, which uses a slightly
+        // different sRGB matrix.
+        assert_relative_eq!(
+            from_srgb(255, 0, 0),
+            Hct::new(27.408, 113.358, 53.241),
+            epsilon = 0.01
+        );
+        assert_relative_eq!(
+            from_srgb(0, 255, 0),
+            Hct::new(142.140, 108.410, 87.735),
+            epsilon = 0.01
+        );
+        assert_relative_eq!(
+            from_srgb(0, 0, 255),
+            Hct::new(282.788, 87.231, 32.303),
+            epsilon = 0.05
+        );
+        assert_relative_eq!(
+            from_srgb(255, 255, 255),
+            Hct::new(209.492, 2.869, 100.0),
+            epsilon = 0.01
+        );
+    }
+
+    #[test]
+    fn srgb_round_trip() {
+        for &(red, green, blue) in &[
+            (255u8, 0u8, 0u8),
+            (0, 255, 0),
+            (0, 0, 255),
+            (255, 255, 255),
+            (103, 80, 164),
+            (18, 52, 86),
+            (200, 200, 40),
+            (1, 1, 1),
+        ] {
+            let srgb = Srgb::new(red, green, blue).into_format::<f64>();
+            let hct: Hct<f64> = Hct::from_color(srgb);
+            let back = Srgb::from_color_unclamped(hct);
+            assert_relative_eq!(back, srgb, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn black() {
+        let black = LinSrgb::<f64>::from_color_unclamped(Hct::new(120.0, 40.0, 0.0));
+        assert_relative_eq!(black, LinSrgb::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn map_to_srgb_gamut() {
+        let inside = from_srgb(103, 80, 164);
+        assert_eq!(inside.map_to_srgb_gamut(), inside);
+
+        let outside = Hct::new(280.0f64, 200.0, 40.0);
+        assert!(!LinSrgb::from_color_unclamped(outside).is_within_bounds());
+
+        let mapped = outside.map_to_srgb_gamut();
+        assert!(mapped.chroma < outside.chroma);
+        assert!(mapped.chroma > 50.0);
+        assert_relative_eq!(mapped.hue, outside.hue);
+        assert_relative_eq!(mapped.tone, outside.tone);
+
+        let srgb = outside.into_srgb();
+        assert!(srgb.is_within_bounds());
+
+        let back: Hct<f64> = Hct::from_color(srgb);
+        assert_relative_eq!(back.hue, mapped.hue, epsilon = 0.01);
+        assert_relative_eq!(back.chroma, mapped.chroma, epsilon = 0.01);
+        assert_relative_eq!(back.tone, mapped.tone, epsilon = 0.01);
+    }
+
+    #[test]
+    fn hue() {
+        let color = Hct::new(350.0f64, 40.0, 50.0).shift_hue(20.0);
+        assert_relative_eq!(color.hue.to_positive_degrees(), 10.0, epsilon = 1e-10);
+        assert_eq!(Hct::new(120.0f64, 0.0, 50.0).get_hue(), None);
+
+        let mixed = Hct::new(350.0f64, 40.0, 40.0).mix(Hct::new(30.0, 60.0, 60.0), 0.5);
+        assert_relative_eq!(mixed, Hct::new(10.0, 50.0, 50.0), epsilon = 1e-10);
+
+        let clamped = Hct::new(350.0f64, 40.0, 40.0).mix(Hct::new(30.0, 60.0, 60.0), 2.0);
+        assert_relative_eq!(clamped, Hct::new(30.0, 60.0, 60.0), epsilon = 1e-10);
+    }
+
+    #[test]
+    fn ranges() {
+        assert_ranges! {
+            Hct<f64>;
+            clamped {
+                tone: 0.0 => 100.0
+            }
+            clamped_min {
+                chroma: 0.0 => 200.0
+            }
+            unclamped {
+                hue: -360.0 => 360.0
+            }
+        }
+    }
+
+    #[test]
+    fn check_min_max_components() {
+        assert_relative_eq!(Hct::<f32>::min_chroma(), 0.0);
+        assert_relative_eq!(Hct::<f32>::min_tone(), 0.0);
+        assert_relative_eq!(Hct::<f32>::max_tone(), 100.0);
+    }
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn serialize() {
+        let serialized = ::serde_json::to_string(&Hct::new(270.0, 40.0, 50.0)).unwrap();
+
+        assert_eq!(serialized, r#"{"hue":270.0,"chroma":40.0,"tone":50.0}"#);
+    }
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn deserialize() {
+        let deserialized: Hct =
+            ::serde_json::from_str(r#"{"hue":270.0,"chroma":40.0,"tone":50.0}"#).unwrap();
+
+        assert_eq!(deserialized, Hct::new(270.0, 40.0, 50.0));
+    }
+}
@@ -236,6 +236,7 @@ where
     S1::Space: RgbSpace<T, WhitePoint = <S2::Space as RgbSpace<T>>::WhitePoint>,
 {
     fn from_color_unclamped(hsl: Hsl<S1, T>) -> Self {
+        trace!(conversion hsl);
         if TypeId::of::<S1>() == TypeId::of::<S2>() {
             hsl.reinterpret_as()
         } else {
@@ -251,6 +252,7 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(mut rgb: Rgb<S, T>) -> Self {
+        trace!(conversion rgb);
         // Avoid negative numbers
         rgb.red = rgb.red.max(T::zero());
         rgb.green = rgb.green.max(T::zero());
@@ -299,6 +301,7 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(hsv: Hsv<S, T>) -> Self {
+        trace!(conversion hsv);
         let x = (from_f64::<T>(2.0) - hsv.saturation) * hsv.value;
         let saturation = if !hsv.value.is_normal() {
             T::zero()
@@ -350,6 +353,9 @@ impl<S, T, A> From<Alpha<Hsl<S, T>, A>> for (RgbHue<T>, T, T, A) {
     }
 }
 
+impl_tuple_ref_conversions!([S, T] [H] Hsl<S, T>, (H, T, T) => (RgbHue<T>, T, T));
+impl_tuple_ref_conversions!([S, T, A] [H] Alpha<Hsl<S, T>, A>, (H, T, T, A) => (RgbHue<T>, T, T, A));
+
 impl<S, T> IsWithinBounds for Hsl<S, T>
 where
     T: Component,
@@ -797,7 +803,26 @@ unsafe impl<S: 'static, T> bytemuck::Pod for Hsl<S, T> where T: bytemuck::Pod {}
 #[cfg(test)]
 mod test {
     use super::Hsl;
-    use crate::{FromColor, Hsv, Srgb};
+    use crate::{FromColor, Hsla, Hsv, RgbHue, Srgb};
+
+    #[test]
+    fn reference_conversions() {
+        let color = Hsl::new_srgb(120.0f32, 0.5, 0.25);
+
+        assert_eq!(Hsl::from(&(120.0f32, 0.5, 0.25)), color);
+        assert_eq!(
+            <(RgbHue<f32>, f32, f32)>::from(&color),
+            (RgbHue::from_degrees(120.0), 0.5, 0.25)
+        );
+        assert_eq!(Hsl::from(&[120.0f32, 0.5, 0.25]), color);
+
+        let alpha = Hsla::new_srgb(120.0f32, 0.5, 0.25, 0.75);
+        assert_eq!(Hsla::from(&(120.0f32, 0.5, 0.25, 0.75)), alpha);
+        assert_eq!(
+            <(RgbHue<f32>, f32, f32, f32)>::from(&alpha),
+            (RgbHue::from_degrees(120.0), 0.5, 0.25, 0.75)
+        );
+    }
 
     #[test]
     fn red() {
@@ -178,6 +178,7 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(color: Lchuv<Wp, T>) -> Self {
+        trace!(conversion color);
         // convert the chroma to a saturation based on the max
         // saturation at a particular hue.
         let max_chroma = LuvBounds::from_lightness(color.l).max_chroma_at_hue(color.hue);
@@ -214,6 +215,9 @@ impl<Wp, T, A> From<Alpha<Hsluv<Wp, T>, A>> for (LuvHue<T>, T, T, A) {
     }
 }
 
+impl_tuple_ref_conversions!([Wp, T] [H] Hsluv<Wp, T>, (H, T, T) => (LuvHue<T>, T, T));
+impl_tuple_ref_conversions!([Wp, T, A] [H] Alpha<Hsluv<Wp, T>, A>, (H, T, T, A) => (LuvHue<T>, T, T, A));
+
 impl<Wp, T> IsWithinBounds for Hsluv<Wp, T>
 where
     T: Zero + FromF64 + PartialOrd,
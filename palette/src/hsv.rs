@@ -236,6 +236,7 @@ where
     S1::Space: RgbSpace<T, WhitePoint = <S2::Space as RgbSpace<T>>::WhitePoint>,
 {
     fn from_color_unclamped(hsv: Hsv<S1, T>) -> Self {
+        trace!(conversion hsv);
         if TypeId::of::<S1>() == TypeId::of::<S2>() {
             hsv.reinterpret_as()
         } else {
@@ -251,6 +252,7 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(mut rgb: Rgb<S, T>) -> Self {
+        trace!(conversion rgb);
         // Avoid negative numbers
         rgb.red = rgb.red.max(T::zero());
         rgb.green = rgb.green.max(T::zero());
@@ -294,6 +296,7 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(hsl: Hsl<S, T>) -> Self {
+        trace!(conversion hsl);
         let x = hsl.saturation
             * if hsl.lightness < from_f64(0.5) {
                 hsl.lightness
@@ -321,6 +324,7 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(hwb: Hwb<S, T>) -> Self {
+        trace!(conversion hwb);
         let inv = T::one() - hwb.blackness;
         // avoid divide by zero
         let s = if inv.is_normal() {
@@ -361,6 +365,9 @@ impl<S, T, A> From<Alpha<Hsv<S, T>, A>> for (RgbHue<T>, T, T, A) {
     }
 }
 
+impl_tuple_ref_conversions!([S, T] [H] Hsv<S, T>, (H, T, T) => (RgbHue<T>, T, T));
+impl_tuple_ref_conversions!([S, T, A] [H] Alpha<Hsv<S, T>, A>, (H, T, T, A) => (RgbHue<T>, T, T, A));
+
 impl<S, T> IsWithinBounds for Hsv<S, T>
 where
     T: Component,
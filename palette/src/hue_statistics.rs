@@ -0,0 +1,292 @@
+//! Statistics for sets of hues.
+//!
+//! Hues are angles, so they can't be averaged like other numbers. The mean
+//! of 350° and 10° is 0°, not 180°. [`HueStatistics`] accumulates hues as
+//! unit vectors instead, and reports their circular mean, how concentrated
+//! they are around it, and optionally a histogram:
+//!
+//! ```
+//! use approx::assert_relative_eq;
+//! use palette::hue_statistics::HueStatistics;
+//! use palette::{Hsv, Srgb};
+//!
+//! let mut statistics = HueStatistics::<f64>::with_histogram(12);
+//! statistics.add_hue(350.0);
+//! statistics.add_hue(10.0);
+//! statistics.add_color(&Hsv::new_srgb(20.0f64, 0.8, 0.8));
+//!
+//! // Gray doesn't have a hue, and is only counted as achromatic.
+//! statistics.add_color(&Srgb::new(0.5f64, 0.5, 0.5));
+//! assert_eq!(statistics.achromatic_count(), 1);
+//!
+//! assert_relative_eq!(statistics.mean().unwrap(), 6.71, epsilon = 0.01);
+//! assert!(statistics.resultant_length() > 0.9);
+//! assert_eq!(statistics.histogram(), &[2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0]);
+//! ```
+//!
+//! The hues can be of any hue type that converts to degrees, such as
+//! [`RgbHue`](crate::RgbHue) or [`OklabHue`](crate::OklabHue), or plain
+//! numbers in degrees. It doesn't make sense to mix hues from different
+//! color spaces, since their angles don't match.
+
+use crate::{float, FloatComponent, GetHue};
+
+/// An accumulator for hue statistics.
+///
+/// Each hue has a weight, which is `1.0` unless it's added with
+/// [`add_weighted_hue`](HueStatistics::add_weighted_hue). Weighting by
+/// chroma or by pixel count makes strongly colored or common hues count
+/// more. See the [module documentation](crate::hue_statistics) for an
+/// example.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HueStatistics<T = f32> {
+    sum_cos: T,
+    sum_sin: T,
+    total_weight: T,
+    count: usize,
+    achromatic_count: usize,
+    histogram: Vec<T>,
+}
+
+impl<T> HueStatistics<T>
+where
+    T: FloatComponent,
+{
+    /// Create an empty accumulator, without a histogram.
+    pub fn new() -> Self {
+        Self::with_histogram(0)
+    }
+
+    /// Create an empty accumulator, with a histogram of `bins` equally wide
+    /// bins. The first bin starts at 0°.
+    pub fn with_histogram(bins: usize) -> Self {
+        HueStatistics {
+            sum_cos: T::zero(),
+            sum_sin: T::zero(),
+            total_weight: T::zero(),
+            count: 0,
+            achromatic_count: 0,
+            histogram: vec![T::zero(); bins],
+        }
+    }
+
+    /// Add a hue, with the weight `1.0`.
+    pub fn add_hue<H: Into<T>>(&mut self, hue: H) {
+        self.add_weighted_hue(hue, T::one());
+    }
+
+    /// Add a hue, with the weight `weight`. Hues with a weight of zero or
+    /// less are ignored.
+    pub fn add_weighted_hue<H: Into<T>>(&mut self, hue: H, weight: T) {
+        if weight.is_nan() || weight <= T::zero() {
+            return;
+        }
+
+        let degrees = hue.into();
+        let (sin, cos) = float::sin_cos(degrees.to_radians());
+        self.sum_cos = self.sum_cos + cos * weight;
+        self.sum_sin = self.sum_sin + sin * weight;
+        self.total_weight = self.total_weight + weight;
+        self.count += 1;
+
+        if !self.histogram.is_empty() {
+            let bins = self.histogram.len();
+            let positive = degrees - (degrees / T::from_f64(360.0)).floor() * T::from_f64(360.0);
+            let bin = (positive / T::from_f64(360.0) * T::from_f64(bins as f64))
+                .to_usize()
+                .unwrap_or(0)
+                .min(bins - 1);
+            self.histogram[bin] = self.histogram[bin] + weight;
+        }
+    }
+
+    /// Add the hue of `color`, with the weight `1.0`, or count it as
+    /// achromatic if it doesn't have a hue.
+    pub fn add_color<C>(&mut self, color: &C)
+    where
+        C: GetHue,
+        C::Hue: Into<T>,
+    {
+        match color.get_hue() {
+            Some(hue) => self.add_hue(hue),
+            None => self.achromatic_count += 1,
+        }
+    }
+
+    /// Add the statistics from `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the histograms have different numbers of bins.
+    pub fn merge(&mut self, other: &Self) {
+        assert_eq!(
+            self.histogram.len(),
+            other.histogram.len(),
+            "the histograms have different numbers of bins"
+        );
+
+        self.sum_cos = self.sum_cos + other.sum_cos;
+        self.sum_sin = self.sum_sin + other.sum_sin;
+        self.total_weight = self.total_weight + other.total_weight;
+        self.count += other.count;
+        self.achromatic_count += other.achromatic_count;
+
+        for (bin, &other) in self.histogram.iter_mut().zip(&other.histogram) {
+            *bin = *bin + other;
+        }
+    }
+
+    /// The number of added hues.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Check if no hues have been added.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// The number of added colors that didn't have a hue.
+    pub fn achromatic_count(&self) -> usize {
+        self.achromatic_count
+    }
+
+    /// The sum of the weights of the added hues.
+    pub fn total_weight(&self) -> T {
+        self.total_weight
+    }
+
+    /// The circular mean of the hues, in degrees, in the range `[0, 360)`.
+    ///
+    /// Returns `None` if no hues have been added, or if they cancel each
+    /// other out, such as 0° and 180°.
+    pub fn mean(&self) -> Option<T> {
+        if self.resultant_length() <= T::from_f64(1e-6) {
+            return None;
+        }
+
+        let mean = float::atan2(self.sum_sin, self.sum_cos).to_degrees();
+        if mean < T::zero() {
+            Some(mean + T::from_f64(360.0))
+        } else {
+            Some(mean)
+        }
+    }
+
+    /// The mean resultant length of the hues, from `0.0` to `1.0`.
+    ///
+    /// This is a measure of concentration. It's `1.0` if all hues are the
+    /// same, and close to `0.0` if they are spread around the circle. It's
+    /// `0.0` if no hues have been added.
+    pub fn resultant_length(&self) -> T {
+        if self.total_weight > T::zero() {
+            float::hypot(self.sum_cos, self.sum_sin) / self.total_weight
+        } else {
+            T::zero()
+        }
+    }
+
+    /// The circular variance of the hues, from `0.0` to `1.0`, which is `1.0`
+    /// minus the [resultant length](HueStatistics::resultant_length).
+    pub fn variance(&self) -> T {
+        T::one() - self.resultant_length()
+    }
+
+    /// The circular standard deviation of the hues, in degrees.
+    ///
+    /// It's similar to the ordinary standard deviation for concentrated
+    /// hues, and grows without bounds as they spread out. It's infinite if
+    /// no hues have been added.
+    pub fn standard_deviation(&self) -> T {
+        let length = self.resultant_length();
+        (T::from_f64(-2.0) * length.ln()).sqrt().to_degrees()
+    }
+
+    /// The weights in each bin of the histogram, or an empty slice if there
+    /// is no histogram.
+    pub fn histogram(&self) -> &[T] {
+        &self.histogram
+    }
+
+    /// The range of degrees for the histogram bin at `index`, as `(start,
+    /// end)`, where `start` is included and `end` is not.
+    pub fn bin_range(&self, index: usize) -> (T, T) {
+        let width = T::from_f64(360.0) / T::from_f64(self.histogram.len() as f64);
+        let start = width * T::from_f64(index as f64);
+        (start, start + width)
+    }
+}
+
+impl<T> Default for HueStatistics<T>
+where
+    T: FloatComponent,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, H> Extend<H> for HueStatistics<T>
+where
+    T: FloatComponent,
+    H: Into<T>,
+{
+    fn extend<I: IntoIterator<Item = H>>(&mut self, hues: I) {
+        for hue in hues {
+            self.add_hue(hue);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::HueStatistics;
+    use crate::white_point::D65;
+    use crate::{LabHue, Lch};
+
+    #[test]
+    fn wraps_around() {
+        let mut statistics = HueStatistics::<f64>::new();
+        statistics.extend(vec![350.0, 10.0, 370.0, -10.0]);
+
+        assert_eq!(statistics.len(), 4);
+        let mean = statistics.mean().unwrap();
+        assert_relative_eq!(mean.min(360.0 - mean), 0.0, epsilon = 1e-6);
+        assert_relative_eq!(
+            statistics.resultant_length(),
+            10.0f64.to_radians().cos(),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn spread_out() {
+        let mut statistics = HueStatistics::<f64>::new();
+        statistics.extend(vec![0.0, 90.0, 180.0, 270.0]);
+
+        assert_eq!(statistics.mean(), None);
+        assert_relative_eq!(statistics.variance(), 1.0, epsilon = 1e-9);
+        assert!(HueStatistics::<f64>::new()
+            .standard_deviation()
+            .is_infinite());
+    }
+
+    #[test]
+    fn weights_and_histogram() {
+        let mut a = HueStatistics::<f64>::with_histogram(4);
+        a.add_weighted_hue(LabHue::from(45.0), 3.0);
+        a.add_weighted_hue(135.0, 0.0);
+
+        let mut b = HueStatistics::<f64>::with_histogram(4);
+        b.add_color(&Lch::<D65, f64>::new(50.0, 30.0, 135.0));
+        b.add_color(&Lch::<D65, f64>::new(50.0, 0.0, 0.0));
+        b.add_hue(-45.0);
+
+        a.merge(&b);
+        assert_eq!(a.len(), 3);
+        assert_eq!(a.achromatic_count(), 1);
+        assert_eq!(a.histogram(), &[3.0, 1.0, 0.0, 1.0]);
+        assert_eq!(a.bin_range(1), (90.0, 180.0));
+        assert_relative_eq!(a.total_weight(), 5.0);
+    }
+}
@@ -1,5 +1,5 @@
 use core::cmp::PartialEq;
-use core::ops::{Add, AddAssign, Sub, SubAssign};
+use core::ops::{Add, AddAssign, RangeInclusive, Sub, SubAssign};
 
 #[cfg(feature = "random")]
 use rand::distributions::uniform::{SampleBorrow, SampleUniform, Uniform, UniformSampler};
@@ -9,10 +9,10 @@ use rand::distributions::{Distribution, Standard};
 use rand::Rng;
 
 use crate::float::Float;
-use crate::{from_f64, FromF64};
+use crate::{from_f64, FromF64, GetHue};
 
 macro_rules! make_hues {
-    ($($(#[$doc:meta])+ struct $name:ident;)+) => ($(
+    ($($(#[$doc:meta])+ struct $name:ident { warm: $warm:literal })+) => ($(
         $(#[$doc])+
         ///
         /// The hue is a circular type, where `0` and `360` is the same, and
@@ -75,6 +75,43 @@ macro_rules! make_hues {
             pub fn to_raw_radians(self) -> T {
                 self.0.to_radians()
             }
+
+            /// The warm half of the hue circle, which is centered on the
+            /// orange of this hue type.
+            #[inline]
+            pub fn warm_range() -> HueRange<T> {
+                let center: T = from_f64($warm);
+                HueRange::new(center - from_f64(90.0), center + from_f64(90.0))
+            }
+
+            /// The cool half of the hue circle, which is opposite of the warm
+            /// half.
+            #[inline]
+            pub fn cool_range() -> HueRange<T> {
+                let center: T = from_f64($warm);
+                HueRange::new(center + from_f64(90.0), center + from_f64(270.0))
+            }
+        }
+
+        impl<T: Float + FromF64> MatchHue for $name<T> {
+            type Scalar = T;
+
+            #[inline]
+            fn in_hue_range<R: Into<HueRange<T>>>(&self, range: R) -> bool {
+                range.into().contains(self.0)
+            }
+
+            #[inline]
+            fn is_warm(&self) -> bool {
+                // The boundaries belong to the cool half.
+                let range = Self::warm_range();
+                range.contains(self.0) && !Self::cool_range().contains(self.0)
+            }
+
+            #[inline]
+            fn is_cool(&self) -> bool {
+                Self::cool_range().contains(self.0)
+            }
         }
 
         impl<T> From<T> for $name<T> {
@@ -84,23 +121,23 @@ macro_rules! make_hues {
             }
         }
 
-        impl Into<f64> for $name<f64> {
+        impl From<$name<f64>> for f64 {
             #[inline]
-            fn into(self) -> f64 {
-                normalize_angle(self.0)
+            fn from(hue: $name<f64>) -> f64 {
+                normalize_angle(hue.0)
             }
         }
 
-        impl Into<f32> for $name<f32> {
+        impl From<$name<f32>> for f32 {
             #[inline]
-            fn into(self) -> f32 {
-                normalize_angle(self.0)
+            fn from(hue: $name<f32>) -> f32 {
+                normalize_angle(hue.0)
             }
         }
-        impl Into<f32> for $name<f64> {
+        impl From<$name<f64>> for f32 {
             #[inline]
-            fn into(self) -> f32 {
-                normalize_angle(self.0) as f32
+            fn from(hue: $name<f64>) -> f32 {
+                normalize_angle(hue.0) as f32
             }
         }
 
@@ -275,21 +312,149 @@ make_hues! {
     /// It's measured in degrees and it's based on the four physiological
     /// elementary colors _red_, _yellow_, _green_ and _blue_. This makes it
     /// different from the hue of RGB based color spaces.
-    struct LabHue;
+    struct LabHue { warm: 60.0 }
 
     /// A hue type for the CIE L\*u\*v\* family of color spaces.
-    struct LuvHue;
+    struct LuvHue { warm: 40.0 }
 
     /// A hue type for the RGB family of color spaces.
     ///
     /// It's measured in degrees and uses the three additive primaries _red_,
     /// _green_ and _blue_.
-    struct RgbHue;
+    struct RgbHue { warm: 30.0 }
 
     /// A hue type for the Oklab color space.
     ///
     /// It's measured in degrees.
-    struct OklabHue;
+    struct OklabHue { warm: 55.0 }
+
+    /// A hue type for the CAM16 and HCT color spaces.
+    ///
+    /// It's measured in degrees.
+    struct Cam16Hue { warm: 55.0 }
+}
+
+/// An inclusive range of hues, in degrees.
+///
+/// The range goes from `start` to `end` in the positive direction, and wraps
+/// around at `360`, so `330.0..=30.0` is the 60 degrees around `0`, and not
+/// the other 300 degrees. A range that spans `360` degrees or more includes
+/// all hues.
+///
+/// ```
+/// use palette::{Hsl, HueRange, MatchHue};
+///
+/// let reds = HueRange::new(330.0, 30.0);
+/// assert!(reds.contains(-10.0));
+/// assert!(reds.contains(365.0));
+/// assert!(!reds.contains(180.0));
+///
+/// let orange = Hsl::new_srgb(20.0, 1.0, 0.5);
+/// assert!(orange.in_hue_range(reds));
+/// assert!(orange.in_hue_range(0.0..=40.0));
+/// assert!(orange.is_warm());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct HueRange<T = f32> {
+    /// Where the range starts, in degrees.
+    pub start: T,
+
+    /// Where the range ends, in degrees.
+    pub end: T,
+}
+
+impl<T> HueRange<T> {
+    /// Create a range from `start` to `end`, in degrees.
+    pub const fn new(start: T, end: T) -> Self {
+        HueRange { start, end }
+    }
+}
+
+impl<T: Float + FromF64> HueRange<T> {
+    /// Check if a hue, in degrees, is in the range. The hue doesn't need to be
+    /// normalized.
+    pub fn contains(&self, degrees: T) -> bool {
+        let full_turn = from_f64(360.0);
+        if self.end - self.start >= full_turn {
+            return true;
+        }
+
+        let length = normalize_angle_positive(self.end - self.start);
+        normalize_angle_positive(degrees - self.start) <= length
+    }
+}
+
+impl<T> From<RangeInclusive<T>> for HueRange<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        HueRange::new(start, end)
+    }
+}
+
+/// Hue based predicates, for hues and for colors with a hue.
+///
+/// Colors without a hue, such as the grays, are not in any range, and are
+/// neither warm nor cool. Warm and cool are the halves of the hue circle
+/// around orange and around blue, as given by the `warm_range` and
+/// `cool_range` of each hue type. The hue circles are different for each
+/// color space, so the halves are adjusted to match.
+///
+/// ```
+/// use palette::{Lch, MatchHue, Srgb, IntoColor};
+///
+/// let sky: Lch = Srgb::new(0.4f32, 0.6, 0.9).into_color();
+/// assert!(sky.is_cool());
+/// assert!(!sky.in_hue_range(0.0..=90.0));
+///
+/// let gray: Lch = Srgb::new(0.5f32, 0.5, 0.5).into_color();
+/// assert!(!gray.is_warm() && !gray.is_cool());
+/// ```
+pub trait MatchHue {
+    /// The type of the hue angles.
+    type Scalar;
+
+    /// Check if the hue is in `range`, which is in degrees. See [`HueRange`]
+    /// for how it wraps around.
+    fn in_hue_range<R: Into<HueRange<Self::Scalar>>>(&self, range: R) -> bool;
+
+    /// Check if the hue is in the warm half of the hue circle.
+    fn is_warm(&self) -> bool;
+
+    /// Check if the hue is in the cool half of the hue circle.
+    fn is_cool(&self) -> bool;
+}
+
+impl<C> MatchHue for C
+where
+    C: GetHue,
+    C::Hue: MatchHue,
+{
+    type Scalar = <C::Hue as MatchHue>::Scalar;
+
+    #[inline]
+    fn in_hue_range<R: Into<HueRange<Self::Scalar>>>(&self, range: R) -> bool {
+        match self.get_hue() {
+            Some(hue) => hue.in_hue_range(range),
+            None => false,
+        }
+    }
+
+    #[inline]
+    fn is_warm(&self) -> bool {
+        match self.get_hue() {
+            Some(hue) => hue.is_warm(),
+            None => false,
+        }
+    }
+
+    #[inline]
+    fn is_cool(&self) -> bool {
+        match self.get_hue() {
+            Some(hue) => hue.is_cool(),
+            None => false,
+        }
+    }
 }
 
 #[inline]
@@ -383,11 +548,63 @@ impl_uniform!(UniformLabHue, LabHue);
 impl_uniform!(UniformRgbHue, RgbHue);
 impl_uniform!(UniformLuvHue, LuvHue);
 impl_uniform!(UniformOklabHue, OklabHue);
+impl_uniform!(UniformCam16Hue, Cam16Hue);
 
 #[cfg(test)]
 mod test {
-    use super::{normalize_angle, normalize_angle_positive};
-    use crate::RgbHue;
+    use super::{normalize_angle, normalize_angle_positive, HueRange, MatchHue};
+    use crate::{Hsv, IntoColor, LabHue, Lch, Lchuv, Oklch, RgbHue, Srgb};
+
+    #[test]
+    fn hue_range() {
+        let range = HueRange::new(10.0f64, 50.0);
+        assert!(range.contains(10.0));
+        assert!(range.contains(50.0));
+        assert!(range.contains(370.0));
+        assert!(range.contains(-330.0));
+        assert!(!range.contains(51.0));
+        assert!(!range.contains(9.0));
+
+        let wrapping: HueRange<f64> = (300.0..=-300.0).into();
+        assert!(wrapping.contains(0.0));
+        assert!(wrapping.contains(-60.0));
+        assert!(!wrapping.contains(90.0));
+
+        assert!(HueRange::new(-180.0f64, 180.0).contains(123.0));
+        assert!(HueRange::new(20.0f64, 20.0).contains(380.0));
+        assert!(!HueRange::new(20.0f64, 20.0).contains(21.0));
+    }
+
+    #[test]
+    fn warm_and_cool() {
+        assert!(RgbHue::from(0.0f64).is_warm());
+        assert!(RgbHue::from(119.0f64).is_warm());
+        assert!(RgbHue::from(120.0f64).is_cool());
+        assert!(!RgbHue::from(120.0f64).is_warm());
+        assert!(RgbHue::from(240.0f64).is_cool());
+        assert!(LabHue::from(-30.0f64).is_cool());
+
+        let orange = Srgb::new(1.0f64, 0.5, 0.0);
+        let blue = Srgb::new(0.1f64, 0.3, 0.9);
+        let hsv: Hsv<_, f64> = orange.into_color();
+        assert!(hsv.is_warm());
+
+        for &(warm, cool) in &[(orange, blue), (Srgb::new(0.9, 0.1, 0.1), blue)] {
+            let lch: Lch<_, f64> = warm.into_color();
+            let lchuv: Lchuv<_, f64> = warm.into_color();
+            let oklch: Oklch<f64> = warm.into_color();
+            assert!(lch.is_warm() && lchuv.is_warm() && oklch.is_warm());
+
+            let lch: Lch<_, f64> = cool.into_color();
+            let lchuv: Lchuv<_, f64> = cool.into_color();
+            let oklch: Oklch<f64> = cool.into_color();
+            assert!(lch.is_cool() && lchuv.is_cool() && oklch.is_cool());
+        }
+
+        let gray: Hsv<_, f64> = Srgb::new(0.5f64, 0.5, 0.5).into_color();
+        assert!(!gray.is_warm() && !gray.is_cool());
+        assert!(!gray.in_hue_range(-180.0..=180.0));
+    }
 
     #[test]
     fn normalize_angle_0_360() {
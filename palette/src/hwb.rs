@@ -19,7 +19,7 @@ use crate::{
     RelativeContrast, RgbHue, SetHue, ShiftHue, ShiftHueAssign, WithHue, Xyz,
 };
 
-/// Linear HWB with an alpha component. See the [`Hwba` implementation in
+/// HWB with an alpha component. See the [`Hwba` implementation in
 /// `Alpha`](crate::Alpha#Hwba).
 pub type Hwba<S = Srgb, T = f32> = Alpha<Hwb<S, T>, T>;
 
@@ -31,7 +31,7 @@ pub type Hwba<S = Srgb, T = f32> = Alpha<Hwb<S, T>, T>;
 /// base hue.
 ///
 /// It is very intuitive for humans to use and many color-pickers are based on
-/// the HWB color system
+/// the HWB color system. It's also available in CSS, as `hwb()`.
 #[derive(Debug, ArrayCast, FromColorUnclamped, WithAlpha)]
 #[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
 #[palette(
@@ -56,8 +56,7 @@ pub struct Hwb<S = Srgb, T = f32> {
     /// The blackness of the color. It specifies the amount black to mix into
     /// the hue. It varies from 0 to 1, with 1 being always full black and
     /// 0 always being the color tint (a mixture of a pure hue with white)
-    /// chosen with the other two
-    //controls.
+    /// chosen with the other two controls.
     pub blackness: T,
 
     /// The white point and RGB primaries this color is adapted to. The default
@@ -237,6 +236,7 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(hwb: Hwb<S1, T>) -> Self {
+        trace!(conversion hwb);
         if TypeId::of::<S1>() == TypeId::of::<S2>() {
             hwb.reinterpret_as()
         } else {
@@ -252,6 +252,7 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(color: Hsv<S, T>) -> Self {
+        trace!(conversion color);
         Hwb {
             hue: color.hue,
             whiteness: (T::one() - color.saturation) * color.value,
@@ -285,6 +286,9 @@ impl<S, T, A> From<Alpha<Hwb<S, T>, A>> for (RgbHue<T>, T, T, A) {
     }
 }
 
+impl_tuple_ref_conversions!([S, T] [H] Hwb<S, T>, (H, T, T) => (RgbHue<T>, T, T));
+impl_tuple_ref_conversions!([S, T, A] [H] Alpha<Hwb<S, T>, A>, (H, T, T, A) => (RgbHue<T>, T, T, A));
+
 impl<S, T> IsWithinBounds for Hwb<S, T>
 where
     T: Component,
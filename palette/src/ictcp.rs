@@ -0,0 +1,508 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::color_difference::ColorDifference;
+use crate::convert::FromColorUnclamped;
+use crate::encoding::Rec2020;
+use crate::float;
+use crate::matrix::{mat3_from_f64, multiply_xyz, rgb_to_xyz_matrix, xyz_to_rgb_matrix};
+use crate::rgb::RgbSpace;
+use crate::white_point::{Any, D65};
+use crate::{from_f64, Alpha, Component, FloatComponent, Xyz};
+
+/// ICtCp with an alpha component. See the [`ICtCpa` implementation in
+/// `Alpha`](crate::Alpha#ICtCpa).
+pub type ICtCpa<T = f32> = Alpha<ICtCp<T>, T>;
+
+// The PQ (SMPTE ST 2084) constants.
+const M1: f64 = 2610.0 / 16384.0;
+const M2: f64 = 2523.0 / 4096.0 * 128.0;
+const C1: f64 = 3424.0 / 4096.0;
+const C2: f64 = 2413.0 / 4096.0 * 32.0;
+const C3: f64 = 2392.0 / 4096.0 * 32.0;
+
+/// The luminance that PQ is relative to, in cd/m².
+const PQ_PEAK_LUMINANCE: f64 = 10000.0;
+
+/// The luminance of XYZ with `Y = 1.0`, in cd/m², for the conversions that
+/// don't take a luminance.
+const REFERENCE_LUMINANCE: f64 = 100.0;
+
+#[rustfmt::skip]
+const RGB_TO_LMS: [f64; 9] = [
+    1688.0 / 4096.0, 2146.0 / 4096.0, 262.0 / 4096.0,
+    683.0 / 4096.0, 2951.0 / 4096.0, 462.0 / 4096.0,
+    99.0 / 4096.0, 309.0 / 4096.0, 3688.0 / 4096.0,
+];
+
+#[rustfmt::skip]
+const LMS_TO_RGB: [f64; 9] = [
+    3.4366066943330784, -2.50645211865627, 0.06984542432319148,
+    -0.7913295555989287, 1.9836004517922907, -0.192270896193362,
+    -0.025949899690592672, -0.09891371471172644, 1.1248636144023192,
+];
+
+#[rustfmt::skip]
+const LMS_TO_ICTCP: [f64; 9] = [
+    0.5, 0.5, 0.0,
+    6610.0 / 4096.0, -13613.0 / 4096.0, 7003.0 / 4096.0,
+    17933.0 / 4096.0, -17390.0 / 4096.0, -543.0 / 4096.0,
+];
+
+#[rustfmt::skip]
+const ICTCP_TO_LMS: [f64; 9] = [
+    1.0, 0.008609037037932756, 0.11102962500302596,
+    1.0, -0.008609037037932756, -0.11102962500302596,
+    1.0, 0.5600313357106791, -0.32062717498731885,
+];
+
+/// The ICtCp color space, from ITU-R BT.2100, for HDR and wide gamut
+/// content.
+///
+/// ICtCp is computed from [Rec. 2020](crate::encoding::Rec2020) RGB, with
+/// the PQ transfer function, which makes it an absolute color space. `I` is
+/// the intensity, and `Ct` and `Cp` are the blue-yellow and red-green
+/// components. It has the D65 white point.
+///
+/// The main use of ICtCp in this library is the ΔE ITP color difference
+/// from ITU-R BT.2124, which is what [`ColorDifference`] calculates:
+///
+/// ```
+/// use palette::convert::FromColorUnclamped;
+/// use palette::{ColorDifference, ICtCp, Srgb};
+///
+/// let a = ICtCp::from_color_unclamped(Srgb::new(0.8f64, 0.3, 0.1).into_linear());
+/// let b = ICtCp::from_color_unclamped(Srgb::new(0.8f64, 0.31, 0.1).into_linear());
+///
+/// // A ΔE ITP of 1 is about one just noticeable difference.
+/// let difference = a.get_color_difference(b);
+/// assert!(difference > 0.5 && difference < 5.0);
+/// ```
+///
+/// PQ needs the luminance of the colors, in cd/m². The conversions to and
+/// from [`Xyz`](crate::Xyz), and the colors that convert through it, treat
+/// `Y = 1.0` as 100 cd/m², which is the usual reference white for SDR
+/// content. [`from_xyz_with_luminance`](ICtCp::from_xyz_with_luminance) and
+/// [`into_xyz_with_luminance`](ICtCp::into_xyz_with_luminance) convert with
+/// another luminance, such as the peak luminance of an HDR display.
+///
+/// The conversions are unclamped, since PQ covers a range that is much
+/// larger than what most displays can show.
+#[derive(Debug, ArrayCast, WithAlpha)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[palette(palette_internal, component = "T")]
+#[repr(C)]
+pub struct ICtCp<T = f32> {
+    /// The intensity, from `0.0` for black to `1.0` for 10000 cd/m².
+    pub i: T,
+
+    /// The blue-yellow component, `Ct`, which goes from yellow at negative
+    /// values to blue at positive values.
+    pub ct: T,
+
+    /// The red-green component, `Cp`, which goes from green at negative
+    /// values to red at positive values.
+    pub cp: T,
+}
+
+impl<T> Copy for ICtCp<T> where T: Copy {}
+
+impl<T> Clone for ICtCp<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> ICtCp<T> {
+        ICtCp {
+            i: self.i.clone(),
+            ct: self.ct.clone(),
+            cp: self.cp.clone(),
+        }
+    }
+}
+
+impl<T> ICtCp<T> {
+    /// Create an ICtCp color.
+    pub const fn new(i: T, ct: T, cp: T) -> ICtCp<T> {
+        ICtCp { i, ct, cp }
+    }
+
+    /// Convert to an `(i, ct, cp)` tuple.
+    pub fn into_components(self) -> (T, T, T) {
+        (self.i, self.ct, self.cp)
+    }
+
+    /// Convert from an `(i, ct, cp)` tuple.
+    pub fn from_components((i, ct, cp): (T, T, T)) -> Self {
+        Self::new(i, ct, cp)
+    }
+}
+
+impl<T> ICtCp<T>
+where
+    T: FloatComponent,
+{
+    /// Convert from XYZ, where `Y = 1.0` has the luminance `luminance`, in
+    /// cd/m².
+    pub fn from_xyz_with_luminance(color: Xyz<D65, T>, luminance: T) -> Self {
+        let rgb = multiply_xyz(
+            &xyz_to_rgb_matrix::<Rec2020, T>(),
+            &color.with_white_point::<Any>(),
+        );
+        let lms = multiply_xyz(&mat3_from_f64(&RGB_TO_LMS), &rgb);
+
+        let scale = luminance / from_f64(PQ_PEAK_LUMINANCE);
+        let lms = Xyz::new(
+            pq_encode(lms.x * scale),
+            pq_encode(lms.y * scale),
+            pq_encode(lms.z * scale),
+        );
+        let ictcp = multiply_xyz(&mat3_from_f64(&LMS_TO_ICTCP), &lms);
+
+        ICtCp::new(ictcp.x, ictcp.y, ictcp.z)
+    }
+
+    /// Convert to XYZ, where `Y = 1.0` has the luminance `luminance`, in
+    /// cd/m².
+    pub fn into_xyz_with_luminance(self, luminance: T) -> Xyz<D65, T> {
+        let lms = multiply_xyz(
+            &mat3_from_f64(&ICTCP_TO_LMS),
+            &Xyz::new(self.i, self.ct, self.cp),
+        );
+
+        let scale = from_f64::<T>(PQ_PEAK_LUMINANCE) / luminance;
+        let lms = Xyz::new(
+            pq_decode(lms.x) * scale,
+            pq_decode(lms.y) * scale,
+            pq_decode(lms.z) * scale,
+        );
+        let rgb = multiply_xyz(&mat3_from_f64(&LMS_TO_RGB), &lms);
+
+        multiply_xyz(&rgb_to_xyz_matrix::<Rec2020, T>(), &rgb).with_white_point()
+    }
+}
+
+/// The PQ inverse EOTF, extended to negative values by symmetry.
+fn pq_encode<T: FloatComponent>(value: T) -> T {
+    if value < T::zero() {
+        return -pq_encode(-value);
+    }
+
+    let powered = float::powf(value, from_f64(M1));
+    float::powf(
+        (from_f64::<T>(C1) + from_f64::<T>(C2) * powered)
+            / (T::one() + from_f64::<T>(C3) * powered),
+        from_f64(M2),
+    )
+}
+
+/// The PQ EOTF, extended to negative values by symmetry.
+fn pq_decode<T: FloatComponent>(value: T) -> T {
+    if value < T::zero() {
+        return -pq_decode(-value);
+    }
+
+    let powered = float::powf(value, T::one() / from_f64(M2));
+    let numerator = (powered - from_f64(C1)).max(T::zero());
+    float::powf(
+        numerator / (from_f64::<T>(C2) - from_f64::<T>(C3) * powered),
+        T::one() / from_f64(M1),
+    )
+}
+
+impl<T> PartialEq for ICtCp<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.i == other.i && self.ct == other.ct && self.cp == other.cp
+    }
+}
+
+impl<T> Eq for ICtCp<T> where T: Eq {}
+
+///<span id="ICtCpa"></span>[`ICtCpa`](crate::ICtCpa) implementations.
+impl<T, A> Alpha<ICtCp<T>, A> {
+    /// Create an ICtCp color with transparency.
+    pub const fn new(i: T, ct: T, cp: T, alpha: A) -> Self {
+        Alpha {
+            color: ICtCp::new(i, ct, cp),
+            alpha,
+        }
+    }
+
+    /// Convert to an `(i, ct, cp, alpha)` tuple.
+    pub fn into_components(self) -> (T, T, T, A) {
+        (self.color.i, self.color.ct, self.color.cp, self.alpha)
+    }
+
+    /// Convert from an `(i, ct, cp, alpha)` tuple.
+    pub fn from_components((i, ct, cp, alpha): (T, T, T, A)) -> Self {
+        Self::new(i, ct, cp, alpha)
+    }
+}
+
+impl<T> FromColorUnclamped<ICtCp<T>> for ICtCp<T> {
+    fn from_color_unclamped(color: ICtCp<T>) -> Self {
+        color
+    }
+}
+
+impl<T> FromColorUnclamped<Xyz<D65, T>> for ICtCp<T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Xyz<D65, T>) -> Self {
+        trace!(conversion color);
+        ICtCp::from_xyz_with_luminance(color, from_f64(REFERENCE_LUMINANCE))
+    }
+}
+
+impl<T> FromColorUnclamped<ICtCp<T>> for Xyz<D65, T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: ICtCp<T>) -> Self {
+        trace!(conversion color);
+        color.into_xyz_with_luminance(from_f64(REFERENCE_LUMINANCE))
+    }
+}
+
+impl<S, T> FromColorUnclamped<crate::rgb::Rgb<S, T>> for ICtCp<T>
+where
+    S: crate::rgb::RgbStandard<T>,
+    S::Space: RgbSpace<T, WhitePoint = D65>,
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: crate::rgb::Rgb<S, T>) -> Self {
+        Self::from_color_unclamped(Xyz::<D65, T>::from_color_unclamped(color))
+    }
+}
+
+impl<S, T> FromColorUnclamped<ICtCp<T>> for crate::rgb::Rgb<S, T>
+where
+    S: crate::rgb::RgbStandard<T>,
+    S::Space: RgbSpace<T, WhitePoint = D65>,
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: ICtCp<T>) -> Self {
+        Self::from_color_unclamped(Xyz::<D65, T>::from_color_unclamped(color))
+    }
+}
+
+impl<T> ColorDifference for ICtCp<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    /// Calculate the ΔE ITP color difference from ITU-R BT.2124. A
+    /// difference of `1.0` is about one just noticeable difference.
+    fn get_color_difference(self, other: Self) -> T {
+        let i = self.i - other.i;
+        let t = (self.ct - other.ct) * from_f64(0.5);
+        let p = self.cp - other.cp;
+
+        from_f64::<T>(720.0) * (i * i + t * t + p * p).sqrt()
+    }
+}
+
+impl<T> From<(T, T, T)> for ICtCp<T> {
+    fn from(components: (T, T, T)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T> From<ICtCp<T>> for (T, T, T) {
+    fn from(color: ICtCp<T>) -> (T, T, T) {
+        color.into_components()
+    }
+}
+
+impl<T, A> From<(T, T, T, A)> for Alpha<ICtCp<T>, A> {
+    fn from(components: (T, T, T, A)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T, A> From<Alpha<ICtCp<T>, A>> for (T, T, T, A) {
+    fn from(color: Alpha<ICtCp<T>, A>) -> (T, T, T, A) {
+        color.into_components()
+    }
+}
+
+impl_tuple_ref_conversions!([T] [] ICtCp<T>, (T, T, T) => (T, T, T));
+impl_tuple_ref_conversions!([T, A] [] Alpha<ICtCp<T>, A>, (T, T, T, A) => (T, T, T, A));
+
+impl<T> Default for ICtCp<T>
+where
+    T: Component,
+{
+    fn default() -> ICtCp<T> {
+        ICtCp::new(T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl_array_casts!(ICtCp<T>, [T; 3]);
+impl_component_access!(ICtCp<T>, [T; 3]);
+
+impl<T> AbsDiffEq for ICtCp<T>
+where
+    T: AbsDiffEq,
+    T::Epsilon: Clone,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    #[rustfmt::skip]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.i.abs_diff_eq(&other.i, epsilon.clone()) &&
+            self.ct.abs_diff_eq(&other.ct, epsilon.clone()) &&
+            self.cp.abs_diff_eq(&other.cp, epsilon)
+    }
+}
+
+impl<T> RelativeEq for ICtCp<T>
+where
+    T: RelativeEq,
+    T::Epsilon: Clone,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    #[rustfmt::skip]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.i.relative_eq(&other.i, epsilon.clone(), max_relative.clone()) &&
+            self.ct.relative_eq(&other.ct, epsilon.clone(), max_relative.clone()) &&
+            self.cp.relative_eq(&other.cp, epsilon, max_relative)
+    }
+}
+
+impl<T> UlpsEq for ICtCp<T>
+where
+    T: UlpsEq,
+    T::Epsilon: Clone,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    #[rustfmt::skip]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.i.ulps_eq(&other.i, epsilon.clone(), max_ulps) &&
+            self.ct.ulps_eq(&other.ct, epsilon.clone(), max_ulps) &&
+            self.cp.ulps_eq(&other.cp, epsilon, max_ulps)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Zeroable for ICtCp<T> where T: bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: 'static> bytemuck::Pod for ICtCp<T> where T: bytemuck::Pod {}
+
+#[cfg(test)]
+mod test {
+    use super::{pq_decode, pq_encode, ICtCp};
+    use crate::convert::{FromColorUnclamped, IntoColorUnclamped};
+    use crate::white_point::{WhitePoint, D65};
+    use crate::{ColorDifference, ICtCpa, LinSrgb, Xyz, Xyza};
+
+    #[test]
+    fn pq() {
+        assert_relative_eq!(
+            pq_encode(0.0f64),
+            0.8359375f64.powf(78.84375),
+            epsilon = 1e-15
+        );
+        assert_relative_eq!(pq_encode(1.0f64), 1.0, epsilon = 1e-12);
+        // 100 cd/m² is about 0.508 in PQ.
+        assert_relative_eq!(pq_encode(0.01f64), 0.508, epsilon = 1e-3);
+
+        for &value in &[0.0f64, 0.0001, 0.01, 0.5, 1.0, -0.2] {
+            assert_relative_eq!(pq_decode(pq_encode(value)), value, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn white_is_neutral() {
+        let white = ICtCp::<f64>::from_color_unclamped(
+            <D65 as WhitePoint<f64>>::get_xyz().with_white_point(),
+        );
+        assert_relative_eq!(white.i, 0.508, epsilon = 1e-3);
+        assert_relative_eq!(white.ct, 0.0, epsilon = 1e-4);
+        assert_relative_eq!(white.cp, 0.0, epsilon = 1e-4);
+
+        let hdr_white = ICtCp::from_xyz_with_luminance(
+            <D65 as WhitePoint<f64>>::get_xyz().with_white_point(),
+            10000.0,
+        );
+        assert_relative_eq!(hdr_white.i, 1.0, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn round_trip() {
+        let xyz = Xyz::<D65, f64>::new(0.3, 0.5, 0.2);
+        let ictcp: ICtCp<f64> = xyz.into_color_unclamped();
+        let back: Xyz<D65, f64> = ictcp.into_color_unclamped();
+        assert_relative_eq!(back, xyz, epsilon = 1e-9);
+
+        let hdr = ICtCp::from_xyz_with_luminance(xyz, 1000.0);
+        assert_relative_eq!(hdr.into_xyz_with_luminance(1000.0), xyz, epsilon = 1e-9);
+
+        let rgb = LinSrgb::new(0.8f64, 0.3, 0.1);
+        let back = LinSrgb::from_color_unclamped(ICtCp::from_color_unclamped(rgb));
+        assert_relative_eq!(back, rgb, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn delta_e_itp() {
+        let a = ICtCp::new(0.5f64, 0.01, -0.02);
+        let b = ICtCp::new(0.51, 0.03, -0.02);
+        let expected = 720.0 * (0.01f64 * 0.01 + 0.01 * 0.01).sqrt();
+
+        assert_relative_eq!(a.get_color_difference(b), expected, epsilon = 1e-12);
+        assert_relative_eq!(b.get_color_difference(a), expected, epsilon = 1e-12);
+        assert_eq!(a.get_color_difference(a), 0.0);
+
+        // Lighter differences are easier to see in the dark.
+        let dark = ICtCp::<f64>::from_color_unclamped(LinSrgb::new(0.01, 0.01, 0.01));
+        let darker = ICtCp::<f64>::from_color_unclamped(LinSrgb::new(0.009, 0.009, 0.009));
+        let light = ICtCp::<f64>::from_color_unclamped(LinSrgb::new(0.9, 0.9, 0.9));
+        let lighter = ICtCp::<f64>::from_color_unclamped(LinSrgb::new(0.81, 0.81, 0.81));
+        assert!(dark.get_color_difference(darker) > 1.0);
+        assert!(light.get_color_difference(lighter) > dark.get_color_difference(darker));
+    }
+
+    #[test]
+    fn alpha() {
+        let ictcpa: ICtCpa<f64> = Xyza::<D65, f64>::new(0.3, 0.5, 0.2, 0.5).into_color_unclamped();
+        assert_relative_eq!(ictcpa.alpha, 0.5);
+
+        let back: Xyza<D65, f64> = ictcpa.into_color_unclamped();
+        assert_relative_eq!(back, Xyza::new(0.3, 0.5, 0.2, 0.5), epsilon = 1e-9);
+    }
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn serialize() {
+        let serialized = ::serde_json::to_string(&ICtCp::new(0.3, 0.2, 0.1)).unwrap();
+
+        assert_eq!(serialized, r#"{"i":0.3,"ct":0.2,"cp":0.1}"#);
+    }
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn deserialize() {
+        let deserialized: ICtCp = ::serde_json::from_str(r#"{"i":0.3,"ct":0.2,"cp":0.1}"#).unwrap();
+
+        assert_eq!(deserialized, ICtCp::new(0.3, 0.2, 0.1));
+    }
+}
@@ -0,0 +1,284 @@
+//! Derive interaction state colors from a base color.
+//!
+//! Design systems usually give each interactive element a set of state
+//! colors, for hovering, pressing, being disabled and having focus. Picking
+//! them by hand for each color leads to inconsistent steps, and to states
+//! that are hard to see. [`StateRules`] derives them with fixed lightness
+//! steps in [`Oklch`], where the steps look the same for all colors, and
+//! keeps a minimum contrast against the background:
+//!
+//! ```
+//! use palette::interaction::StateRules;
+//! use palette::{RelativeContrast, Srgb};
+//!
+//! let background = Srgb::new(1.0f32, 1.0, 1.0);
+//! let button = Srgb::new(0.2, 0.45, 0.85);
+//!
+//! let states = StateRules::new().derive(button, background);
+//!
+//! // Hovering and pressing move away from the background.
+//! assert!(states.hover.get_contrast_ratio(background) > button.get_contrast_ratio(background));
+//! assert!(states.pressed.get_contrast_ratio(background) > states.hover.get_contrast_ratio(background));
+//!
+//! // The focus ring is visible against the background.
+//! assert!(states.focus_ring.has_min_contrast_graphics(background));
+//! ```
+//!
+//! The lightness moves away from the background, so colors get darker on
+//! light backgrounds and lighter on dark backgrounds. The chroma is reduced
+//! if a color would otherwise fall outside the sRGB gamut.
+
+use crate::convert::{IntoColor, IntoColorUnclamped};
+use crate::theme::fit_chroma;
+use crate::white_point::D65;
+use crate::{clamp, contrast_ratio, from_f64, FloatComponent, Oklch, Xyz};
+
+/// Rules for deriving interaction state colors.
+///
+/// The lightness steps are in Oklab lightness, and the contrast ratios are
+/// the same as for [`RelativeContrast`](crate::RelativeContrast).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct StateRules<T> {
+    /// The lightness step from the base color to the hover color.
+    pub hover_step: T,
+
+    /// The lightness step from the base color to the pressed color.
+    pub pressed_step: T,
+
+    /// The minimum contrast ratio of the hover and pressed colors against the
+    /// background. They move further than their steps to reach it, if
+    /// possible.
+    pub min_contrast: T,
+
+    /// The fraction of the chroma that disabled colors keep.
+    pub disabled_chroma: T,
+
+    /// How far the lightness of disabled colors moves towards the
+    /// background, from `0.0` to `1.0`.
+    pub disabled_fade: T,
+
+    /// The minimum contrast ratio of the focus ring against the background.
+    pub focus_ring_contrast: T,
+}
+
+impl<T> StateRules<T>
+where
+    T: FloatComponent,
+{
+    /// Create rules with steps of `0.05` for hover and `0.1` for pressed,
+    /// and a minimum contrast of `3.0` for them and the focus ring, which is
+    /// the WCAG level for user interface components. Disabled colors keep a
+    /// quarter of their chroma, and fade halfway to the background.
+    pub fn new() -> Self {
+        StateRules {
+            hover_step: from_f64(0.05),
+            pressed_step: from_f64(0.1),
+            min_contrast: from_f64(3.0),
+            disabled_chroma: from_f64(0.25),
+            disabled_fade: from_f64(0.5),
+            focus_ring_contrast: from_f64(3.0),
+        }
+    }
+
+    /// Change the lightness steps for the hover and pressed colors.
+    pub fn with_steps(mut self, hover_step: T, pressed_step: T) -> Self {
+        self.hover_step = hover_step;
+        self.pressed_step = pressed_step;
+        self
+    }
+
+    /// Change the minimum contrast ratio of the hover and pressed colors.
+    pub fn with_min_contrast(mut self, min_contrast: T) -> Self {
+        self.min_contrast = min_contrast;
+        self
+    }
+
+    /// Change the minimum contrast ratio of the focus ring.
+    pub fn with_focus_ring_contrast(mut self, focus_ring_contrast: T) -> Self {
+        self.focus_ring_contrast = focus_ring_contrast;
+        self
+    }
+
+    /// Derive the state colors for `base` on `background`, in Oklch.
+    pub fn derive_oklch(&self, base: Oklch<T>, background: Oklch<T>) -> StateColors<Oklch<T>> {
+        let background_luminance = luminance(background);
+        let darken = background_luminance > luminance(base);
+        let step = |amount: T| {
+            let l = if darken {
+                base.l - amount
+            } else {
+                base.l + amount
+            };
+
+            let color = fit_chroma(Oklch {
+                l: clamp(l, T::zero(), T::one()),
+                ..base
+            });
+            with_contrast(color, background_luminance, self.min_contrast, darken)
+        };
+
+        let fade = clamp(self.disabled_fade, T::zero(), T::one());
+        let disabled = fit_chroma(Oklch {
+            l: base.l + (background.l - base.l) * fade,
+            chroma: base.chroma * clamp(self.disabled_chroma, T::zero(), T::one()),
+            ..base
+        });
+
+        let focus_ring = with_contrast(
+            fit_chroma(base),
+            background_luminance,
+            self.focus_ring_contrast,
+            darken,
+        );
+
+        StateColors {
+            base,
+            hover: step(self.hover_step),
+            pressed: step(self.pressed_step),
+            disabled,
+            focus_ring,
+        }
+    }
+
+    /// Derive the state colors for `base` on `background`.
+    pub fn derive<C>(&self, base: C, background: C) -> StateColors<C>
+    where
+        C: IntoColorUnclamped<Oklch<T>>,
+        Oklch<T>: IntoColor<C>,
+    {
+        let states = self.derive_oklch(
+            base.into_color_unclamped(),
+            background.into_color_unclamped(),
+        );
+
+        StateColors {
+            base: states.base.into_color(),
+            hover: states.hover.into_color(),
+            pressed: states.pressed.into_color(),
+            disabled: states.disabled.into_color(),
+            focus_ring: states.focus_ring.into_color(),
+        }
+    }
+}
+
+impl<T> Default for StateRules<T>
+where
+    T: FloatComponent,
+{
+    fn default() -> Self {
+        StateRules::new()
+    }
+}
+
+/// The interaction state colors for a base color, from [`StateRules`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct StateColors<C> {
+    /// The base color, for the resting state.
+    pub base: C,
+
+    /// The color when the pointer is over the element.
+    pub hover: C,
+
+    /// The color when the element is pressed or active.
+    pub pressed: C,
+
+    /// The color when the element is disabled.
+    pub disabled: C,
+
+    /// The color of the ring around the element when it has keyboard focus.
+    pub focus_ring: C,
+}
+
+pub(crate) fn luminance<T: FloatComponent>(color: Oklch<T>) -> T {
+    let xyz: Xyz<D65, T> = color.into_color_unclamped();
+    clamp(xyz.y, T::zero(), T::one())
+}
+
+/// Move the lightness of `color` in the direction given by `darken`, until
+/// its contrast against the background reaches `min_contrast`, or as far as
+/// it goes.
+pub(crate) fn with_contrast<T: FloatComponent>(
+    color: Oklch<T>,
+    background_luminance: T,
+    min_contrast: T,
+    darken: bool,
+) -> Oklch<T> {
+    let contrast = |l: T| {
+        let color = fit_chroma(Oklch { l, ..color });
+        contrast_ratio(luminance(color), background_luminance)
+    };
+
+    if contrast(color.l) >= min_contrast {
+        return color;
+    }
+
+    let (mut enough, mut not_enough) = if darken {
+        (T::zero(), color.l)
+    } else {
+        (T::one(), color.l)
+    };
+
+    if contrast(enough) < min_contrast {
+        return fit_chroma(Oklch { l: enough, ..color });
+    }
+
+    for _ in 0..24 {
+        let middle = (enough + not_enough) / from_f64(2.0);
+        if contrast(middle) >= min_contrast {
+            enough = middle;
+        } else {
+            not_enough = middle;
+        }
+    }
+
+    fit_chroma(Oklch { l: enough, ..color })
+}
+
+#[cfg(test)]
+mod test {
+    use super::StateRules;
+    use crate::{FromColor, Oklch, RelativeContrast, Srgb};
+
+    #[test]
+    fn steps_away_from_background() {
+        let rules = StateRules::new().with_min_contrast(1.0);
+        let base = Oklch::new(0.6f64, 0.1, 250.0);
+
+        let light = rules.derive_oklch(base, Oklch::new(1.0, 0.0, 0.0));
+        assert_relative_eq!(light.hover.l, 0.55, epsilon = 1e-9);
+        assert_relative_eq!(light.pressed.l, 0.5, epsilon = 1e-9);
+        assert_eq!(light.hover.hue, base.hue);
+
+        let dark = rules.derive_oklch(base, Oklch::new(0.1, 0.0, 0.0));
+        assert_relative_eq!(dark.hover.l, 0.65, epsilon = 1e-9);
+        assert_relative_eq!(dark.pressed.l, 0.7, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn contrast_floor() {
+        let background = Srgb::new(1.0f64, 1.0, 1.0);
+        let pale = Srgb::new(0.85, 0.9, 0.95);
+        let states = StateRules::new().derive(pale, background);
+
+        assert!(states.hover.get_contrast_ratio(background) >= 2.99);
+        assert!(states.focus_ring.get_contrast_ratio(background) >= 2.99);
+        // Not more than needed.
+        assert!(states.hover.get_contrast_ratio(background) < 3.1);
+    }
+
+    #[test]
+    fn disabled() {
+        let rules = StateRules::<f64>::new();
+        let base = Oklch::new(0.4, 0.12, 30.0);
+        let background = Oklch::new(0.9, 0.0, 0.0);
+        let states = rules.derive_oklch(base, background);
+
+        assert_relative_eq!(states.disabled.l, 0.65, epsilon = 1e-9);
+        assert_relative_eq!(states.disabled.chroma, 0.03, epsilon = 1e-9);
+
+        let srgb = Srgb::from_color(states.disabled);
+        assert!(srgb.get_contrast_ratio(Srgb::from_color(background)) < 3.0);
+    }
+}
@@ -11,10 +11,10 @@ use rand::Rng;
 
 use crate::{
     clamp, clamp_assign, clamp_min_assign,
-    color_difference::{get_ciede_difference, ColorDifference},
+    color_difference::{get_ciede_difference, get_delta_e, ColorDifference, DeltaE, DeltaEFormula},
     contrast_ratio,
     convert::FromColorUnclamped,
-    float::Float,
+    float::{self, Float},
     from_f64,
     white_point::{WhitePoint, D65},
     Alpha, Clamp, ClampAssign, ComponentWise, FloatComponent, FromF64, GetHue, IsWithinBounds,
@@ -169,6 +169,7 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(color: Xyz<Wp, T>) -> Self {
+        trace!(conversion color);
         let Xyz {
             mut x,
             mut y,
@@ -177,11 +178,11 @@ where
         } = color / Wp::get_xyz().with_white_point();
 
         fn convert<T: FloatComponent>(c: T) -> T {
-            let epsilon = from_f64::<T>(6.0 / 29.0).powi(3);
+            let epsilon = float::powi(from_f64::<T>(6.0 / 29.0), 3);
             let kappa: T = from_f64(841.0 / 108.0);
             let delta: T = from_f64(4.0 / 29.0);
             if c > epsilon {
-                c.cbrt()
+                float::cbrt(c)
             } else {
                 (kappa * c) + delta
             }
@@ -205,10 +206,11 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(color: Lch<Wp, T>) -> Self {
+        trace!(conversion color);
         Lab {
             l: color.l,
-            a: color.chroma.max(T::zero()) * color.hue.to_radians().cos(),
-            b: color.chroma.max(T::zero()) * color.hue.to_radians().sin(),
+            a: color.chroma.max(T::zero()) * float::cos(color.hue.to_radians()),
+            b: color.chroma.max(T::zero()) * float::sin(color.hue.to_radians()),
             white_point: PhantomData,
         }
     }
@@ -238,6 +240,9 @@ impl<Wp, T, A> From<Alpha<Lab<Wp, T>, A>> for (T, T, T, A) {
     }
 }
 
+impl_tuple_ref_conversions!([Wp, T] [] Lab<Wp, T>, (T, T, T) => (T, T, T));
+impl_tuple_ref_conversions!([Wp, T, A] [] Alpha<Lab<Wp, T>, A>, (T, T, T, A) => (T, T, T, A));
+
 impl<Wp, T> IsWithinBounds for Lab<Wp, T>
 where
     T: Zero + FromF64 + PartialOrd,
@@ -373,7 +378,7 @@ where
         if self.a == T::zero() && self.b == T::zero() {
             None
         } else {
-            Some(LabHue::from_radians(self.b.atan2(self.a)))
+            Some(LabHue::from_radians(float::atan2(self.b, self.a)))
         }
     }
 }
@@ -390,6 +395,18 @@ where
     }
 }
 
+impl<Wp, T> DeltaE for Lab<Wp, T>
+where
+    T: Float + FromF64,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn delta_e(self, other: Lab<Wp, T>, formula: DeltaEFormula<T>) -> Self::Scalar {
+        get_delta_e(self.into(), other.into(), formula)
+    }
+}
+
 impl<Wp, T> ComponentWise for Lab<Wp, T>
 where
     T: Clone,
@@ -430,6 +447,7 @@ impl_color_mul!(Lab<Wp, T>, [l, a, b], white_point);
 impl_color_div!(Lab<Wp, T>, [l, a, b], white_point);
 
 impl_array_casts!(Lab<Wp, T>, [T; 3]);
+impl_component_access!(Lab<Wp, T>, [T; 3]);
 
 impl<Wp, T> RelativeContrast for Lab<Wp, T>
 where
@@ -546,6 +564,17 @@ mod test {
     use crate::white_point::D65;
     use crate::{FromColor, LinSrgb};
 
+    #[test]
+    fn component_access() {
+        let mut lab = Lab::<D65, f64>::new(50.0, -20.0, 30.0);
+        assert_eq!(lab[0], 50.0);
+        assert_eq!(lab[2], 30.0);
+
+        lab[1] = 10.0;
+        assert_eq!(lab.a, 10.0);
+        assert_eq!(lab.into_iter().sum::<f64>(), 90.0);
+    }
+
     #[test]
     fn red() {
         let a = Lab::from_color(LinSrgb::new(1.0, 0.0, 0.0));
@@ -10,7 +10,7 @@ use rand::distributions::{Distribution, Standard};
 use rand::Rng;
 
 use crate::color_difference::get_ciede_difference;
-use crate::color_difference::ColorDifference;
+use crate::color_difference::{get_delta_e, ColorDifference, DeltaE, DeltaEFormula};
 use crate::convert::{FromColorUnclamped, IntoColorUnclamped};
 use crate::white_point::{WhitePoint, D65};
 use crate::{
@@ -179,6 +179,7 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(color: Lab<Wp, T>) -> Self {
+        trace!(conversion color);
         Lch {
             l: color.l,
             chroma: (color.a * color.a + color.b * color.b).sqrt(),
@@ -212,6 +213,9 @@ impl<Wp, T, A> From<Alpha<Lch<Wp, T>, A>> for (T, T, LabHue<T>, A) {
     }
 }
 
+impl_tuple_ref_conversions!([Wp, T] [H] Lch<Wp, T>, (T, T, H) => (T, T, LabHue<T>));
+impl_tuple_ref_conversions!([Wp, T, A] [H] Alpha<Lch<Wp, T>, A>, (T, T, H, A) => (T, T, LabHue<T>, A));
+
 impl<Wp, T> IsWithinBounds for Lch<Wp, T>
 where
     T: Zero + FromF64 + PartialOrd,
@@ -420,6 +424,19 @@ where
     }
 }
 
+impl<Wp, T> DeltaE for Lch<Wp, T>
+where
+    Self: IntoColorUnclamped<Lab<Wp, T>>,
+    T: Float + FromF64,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn delta_e(self, other: Lch<Wp, T>, formula: DeltaEFormula<T>) -> Self::Scalar {
+        get_delta_e(self.into(), other.into(), formula)
+    }
+}
+
 impl<Wp, T> Saturate for Lch<Wp, T>
 where
     T: FloatComponent,
@@ -10,6 +10,7 @@ use rand::distributions::{Distribution, Standard};
 use rand::Rng;
 
 use crate::convert::FromColorUnclamped;
+use crate::float;
 use crate::luv_bounds::LuvBounds;
 use crate::white_point::{WhitePoint, D65};
 use crate::{
@@ -171,9 +172,10 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(color: Luv<Wp, T>) -> Self {
+        trace!(conversion color);
         Lchuv {
             l: color.l,
-            chroma: color.u.hypot(color.v),
+            chroma: float::hypot(color.u, color.v),
             hue: color.get_hue().unwrap_or_else(|| LuvHue::from(T::zero())),
             white_point: PhantomData,
         }
@@ -185,6 +187,7 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(color: Hsluv<Wp, T>) -> Self {
+        trace!(conversion color);
         // Apply the given saturation as a percentage of the max
         // chroma for that hue.
         let max_chroma = LuvBounds::from_lightness(color.l).max_chroma_at_hue(color.hue);
@@ -221,6 +224,9 @@ impl<Wp, T, A> From<Alpha<Lchuv<Wp, T>, A>> for (T, T, LuvHue<T>, A) {
     }
 }
 
+impl_tuple_ref_conversions!([Wp, T] [H] Lchuv<Wp, T>, (T, T, H) => (T, T, LuvHue<T>));
+impl_tuple_ref_conversions!([Wp, T, A] [H] Alpha<Lchuv<Wp, T>, A>, (T, T, H, A) => (T, T, LuvHue<T>, A));
+
 impl<Wp, T> IsWithinBounds for Lchuv<Wp, T>
 where
     T: Zero + FromF64 + PartialOrd,
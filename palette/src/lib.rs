@@ -149,7 +149,7 @@
 //!
 //! When working with other colors:
 //!
-//! * For HSL, HSV, HWB: Check if they are based on any other color space than
+//! * For HSL, HSV, HWB, CMYK: Check if they are based on any other color space than
 //! sRGB, such as Adobe or Apple RGB.
 //!
 //! * For any of the CIE color spaces, check for a specification of white point
@@ -230,227 +230,157 @@ use luma::Luma;
 
 pub use alpha::{Alpha, WithAlpha};
 pub use blend::Blend;
+pub use cmyk::{Cmyk, Cmyka, RichBlack};
+pub use d50::{LabD50, LabaD50, LchD50, LchaD50, LuvD50, LuvaD50, XyzD50, XyzaD50};
 #[cfg(feature = "std")]
 pub use gradient::Gradient;
 
+pub use hct::{Hct, Hcta};
 pub use hsl::{Hsl, Hsla};
 pub use hsluv::{Hsluv, Hsluva};
 pub use hsv::{Hsv, Hsva};
 pub use hwb::{Hwb, Hwba};
+pub use ictcp::{ICtCp, ICtCpa};
 pub use lab::{Lab, Laba};
 pub use lch::{Lch, Lcha};
 pub use lchuv::{Lchuv, Lchuva};
+pub use lms::{Lms, Lmsa};
 pub use luma::{GammaLuma, GammaLumaa, LinLuma, LinLumaa, SrgbLuma, SrgbLumaa};
 pub use luv::{Luv, Luva};
 pub use oklab::{Oklab, Oklaba};
 pub use oklch::{Oklch, Oklcha};
+pub use oklrab::{Oklrab, Oklraba};
+pub use oklrch::{Oklrch, Oklrcha};
 pub use rgb::{GammaSrgb, GammaSrgba, LinSrgb, LinSrgba, Srgb, Srgba};
 pub use xyz::{Xyz, Xyza};
+pub use ycbcr::{YCbCr, YCbCra, YPbPr, YPbPra, Yiq, Yiqa, Yuv, Yuva};
 pub use yxy::{Yxy, Yxya};
 
-pub use color_difference::ColorDifference;
+pub use color_difference::{
+    delta_e_between, delta_e_ok_between, Cie94Application, ColorDifference, DeltaE, DeltaEFormula,
+};
 pub use component::*;
 pub use convert::{FromColor, IntoColor};
-pub use hues::{LabHue, LuvHue, OklabHue, RgbHue};
+pub use hues::{Cam16Hue, HueRange, LabHue, LuvHue, MatchHue, OklabHue, RgbHue};
 pub use matrix::Mat3;
+pub use palette_derive::Mix;
 pub use relative_contrast::{contrast_ratio, RelativeContrast};
 
-//Helper macro for checking ranges and clamping.
-#[cfg(test)]
-macro_rules! assert_ranges {
-    (@make_tuple $first:pat, $next:ident,) => (($first, $next));
-
-    (@make_tuple $first:pat, $next:ident, $($rest:ident,)*) => (
-        assert_ranges!(@make_tuple ($first, $next), $($rest,)*)
-    );
-
-    (
-        $ty:ident < $($ty_params:ty),+ >;
-        clamped {$($clamped:ident: $clamped_from:expr => $clamped_to:expr),+}
-        clamped_min {$($clamped_min:ident: $clamped_min_from:expr => $clamped_min_to:expr),*}
-        unclamped {$($unclamped:ident: $unclamped_from:expr => $unclamped_to:expr),*}
-    ) => (
-        {
-            use core::iter::repeat;
-            use crate::{Clamp, IsWithinBounds};
-
-            {
-                print!("checking below clamp bounds... ");
-                $(
-                    let from = $clamped_from;
-                    let to = $clamped_to;
-                    let diff = to - from;
-                    let $clamped = (1..11).map(|i| from - (i as f64 / 10.0) * diff);
-                )+
-
-                $(
-                    let from = $clamped_min_from;
-                    let to = $clamped_min_to;
-                    let diff = to - from;
-                    let $clamped_min = (1..11).map(|i| from - (i as f64 / 10.0) * diff);
-                )*
-
-                $(
-                    let from = $unclamped_from;
-                    let to = $unclamped_to;
-                    let diff = to - from;
-                    let $unclamped = (1..11).map(|i| from - (i as f64 / 10.0) * diff);
-                )*
-
-                for assert_ranges!(@make_tuple (), $($clamped,)+ $($clamped_min,)* $($unclamped,)* ) in repeat(()) $(.zip($clamped))+ $(.zip($clamped_min))* $(.zip($unclamped))* {
-                    let color: $ty<$($ty_params),+> = $ty {
-                        $($clamped: $clamped.into(),)+
-                        $($clamped_min: $clamped_min.into(),)*
-                        $($unclamped: $unclamped.into(),)*
-                        ..$ty::default() //This prevents exhaustiveness checking
-                    };
-
-                    let clamped = color.clamp();
-
-                    let expected: $ty<$($ty_params),+> = $ty {
-                        $($clamped: $clamped_from.into(),)+
-                        $($clamped_min: $clamped_min_from.into(),)*
-                        $($unclamped: $unclamped.into(),)*
-                        ..$ty::default() //This prevents exhaustiveness checking
-                    };
-
-                    assert!(!color.is_within_bounds());
-                    assert_relative_eq!(clamped, expected);
-                }
-
-                println!("ok")
-            }
-
-            {
-                print!("checking within clamp bounds... ");
-                $(
-                    let from = $clamped_from;
-                    let to = $clamped_to;
-                    let diff = to - from;
-                    let $clamped = (0..11).map(|i| from + (i as f64 / 10.0) * diff);
-                )+
-
-                $(
-                    let from = $clamped_min_from;
-                    let to = $clamped_min_to;
-                    let diff = to - from;
-                    let $clamped_min = (0..11).map(|i| from + (i as f64 / 10.0) * diff);
-                )*
-
-                $(
-                    let from = $unclamped_from;
-                    let to = $unclamped_to;
-                    let diff = to - from;
-                    let $unclamped = (0..11).map(|i| from + (i as f64 / 10.0) * diff);
-                )*
-
-                for assert_ranges!(@make_tuple (), $($clamped,)+ $($clamped_min,)* $($unclamped,)* ) in repeat(()) $(.zip($clamped))+ $(.zip($clamped_min))* $(.zip($unclamped))* {
-                    let color: $ty<$($ty_params),+> = $ty {
-                        $($clamped: $clamped.into(),)+
-                        $($clamped_min: $clamped_min.into(),)*
-                        $($unclamped: $unclamped.into(),)*
-                        ..$ty::default() //This prevents exhaustiveness checking
-                    };
-
-                    let clamped = color.clamp();
-
-                    assert!(color.is_within_bounds());
-                    assert_relative_eq!(clamped, color);
-                }
-
-                println!("ok")
-            }
-
-            {
-                print!("checking above clamp bounds... ");
-                $(
-                    let from = $clamped_from;
-                    let to = $clamped_to;
-                    let diff = to - from;
-                    let $clamped = (1..11).map(|i| to + (i as f64 / 10.0) * diff);
-                )+
-
-                $(
-                    let from = $clamped_min_from;
-                    let to = $clamped_min_to;
-                    let diff = to - from;
-                    let $clamped_min = (1..11).map(|i| to + (i as f64 / 10.0) * diff);
-                )*
-
-                $(
-                    let from = $unclamped_from;
-                    let to = $unclamped_to;
-                    let diff = to - from;
-                    let $unclamped = (1..11).map(|i| to + (i as f64 / 10.0) * diff);
-                )*
-
-                for assert_ranges!(@make_tuple (), $($clamped,)+ $($clamped_min,)* $($unclamped,)* ) in repeat(()) $(.zip($clamped))+ $(.zip($clamped_min))* $(.zip($unclamped))* {
-                    let color: $ty<$($ty_params),+> = $ty {
-                        $($clamped: $clamped.into(),)+
-                        $($clamped_min: $clamped_min.into(),)*
-                        $($unclamped: $unclamped.into(),)*
-                        ..$ty::default() //This prevents exhaustiveness checking
-                    };
-
-                    let clamped = color.clamp();
-
-                    let expected: $ty<$($ty_params),+> = $ty {
-                        $($clamped: $clamped_to.into(),)+
-                        $($clamped_min: $clamped_min.into(),)*
-                        $($unclamped: $unclamped.into(),)*
-                        ..$ty::default() //This prevents exhaustiveness checking
-                    };
-
-                    assert!(!color.is_within_bounds());
-                    assert_relative_eq!(clamped, expected);
-                }
-
-                println!("ok")
-            }
-        }
-    );
-}
+#[cfg(any(test, feature = "test_utils"))]
+#[macro_use]
+pub mod test_utils;
 
 #[macro_use]
 mod macros;
 
+pub mod accent;
+pub mod ambient;
+pub mod behavior;
 pub mod blend;
+pub mod blurhash;
+#[cfg(feature = "std")]
+pub mod brand;
+#[cfg(feature = "std")]
+pub mod categorical;
+pub mod colormap;
+pub mod compact;
+pub mod d50;
+#[cfg(feature = "std")]
+pub mod delta_encoding;
+pub mod diff;
+pub mod difference_map;
+pub mod duotone;
+pub mod easing;
+pub mod elevation;
+#[cfg(feature = "expression")]
+pub mod expression;
 #[cfg(feature = "std")]
 pub mod gradient;
+pub mod hash_color;
+#[cfg(feature = "std")]
+pub mod hue_statistics;
+#[cfg(feature = "std")]
+pub mod measurement;
+pub mod monochrome;
 
 #[cfg(feature = "named")]
 pub mod named;
 
+pub mod overprint;
+#[cfg(feature = "std")]
+pub mod perceptual_hash;
+#[cfg(feature = "std")]
+pub mod provenance;
+#[cfg(feature = "std")]
+pub mod quantize;
+
+#[cfg(feature = "chromaticity_diagram")]
+pub mod chromaticity_diagram;
 #[cfg(feature = "random")]
 mod random_sampling;
+pub mod roles;
+#[cfg(feature = "std")]
+pub mod scale;
+#[cfg(feature = "schemes")]
+pub mod schemes;
+pub mod select;
+pub mod status;
+#[cfg(feature = "swatch")]
+pub mod swatch;
+pub mod tween;
+#[cfg(feature = "std")]
+pub mod weighted_palette;
+
+#[cfg(feature = "serializing")]
+pub mod settings;
 
 mod alpha;
 pub mod cast;
+mod cmyk;
+mod hct;
 mod hsl;
 mod hsluv;
 mod hsv;
 mod hwb;
+mod ictcp;
 mod lab;
 mod lch;
 mod lchuv;
+pub mod lms;
 pub mod luma;
 mod luv;
 mod oklab;
 mod oklch;
+mod oklrab;
+mod oklrch;
 pub mod rgb;
 mod xyz;
+pub mod ycbcr;
 mod yxy;
 
 mod hues;
 
 pub mod chromatic_adaptation;
+pub mod clipping;
 mod color_difference;
 mod component;
 pub mod convert;
+pub mod cvd;
+pub mod display;
+pub mod dynamic;
 pub mod encoding;
 mod equality;
+pub mod gamut;
+pub mod interaction;
 mod luv_bounds;
+pub mod picker;
+pub mod polar;
+pub mod prelude;
 mod relative_contrast;
+pub mod superpixel;
+pub mod theme;
+#[cfg(feature = "trace")]
+pub mod trace;
 pub mod white_point;
 
 pub mod float;
@@ -643,6 +573,56 @@ where
 /// assert_relative_eq!(a.mix(b, 0.5), LinSrgb::new(0.5, 0.5, 0.5));
 /// assert_relative_eq!(a.mix(b, 1.0), b);
 /// ```
+///
+/// # Deriving
+///
+/// `Mix` can be derived for structs where each field is a color or a scalar,
+/// such as themes and material parameters, to interpolate all of them in one
+/// call. The fields are mixed with their own `Mix` implementations, except
+/// for fields of the scalar type, which are interpolated linearly. The scalar
+/// type is `f32` by default, and can be changed with
+/// `#[palette(component = "T")]`, as for
+/// [`FromColorUnclamped`](crate::convert::FromColorUnclamped).
+///
+/// Mixing is not equally good in every color space, so each field can be
+/// mixed in another space with `#[palette(mix_in = "SomeType")]`. The field is
+/// converted to that space with [`IntoColor`] and back with [`FromColor`].
+/// Fields that should not change, such as names, can be kept from `self` with
+/// `#[palette(mix_skip)]`.
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::{FloatComponent, LinSrgb, Mix, Oklch, Srgb};
+///
+/// #[derive(Mix)]
+/// #[palette(component = "T")]
+/// struct Material<T: FloatComponent> {
+///     albedo: LinSrgb<T>,
+///     #[palette(mix_in = "Oklch<T>")]
+///     emission: Srgb<T>,
+///     roughness: T,
+///     #[palette(mix_skip)]
+///     name: &'static str,
+/// }
+///
+/// let rust = Material {
+///     albedo: LinSrgb::new(0.4, 0.2, 0.1),
+///     emission: Srgb::new(0.0, 0.0, 0.0),
+///     roughness: 0.8,
+///     name: "rust",
+/// };
+/// let gold = Material {
+///     albedo: LinSrgb::new(1.0, 0.8, 0.3),
+///     emission: Srgb::new(0.2, 0.1, 0.0),
+///     roughness: 0.2,
+///     name: "gold",
+/// };
+///
+/// let halfway = rust.mix(gold, 0.5f64);
+/// assert_relative_eq!(halfway.albedo, LinSrgb::new(0.7, 0.5, 0.2));
+/// assert_relative_eq!(halfway.roughness, 0.5);
+/// assert_eq!(halfway.name, "rust");
+/// ```
 pub trait Mix {
     /// The type of the mixing factor.
     type Scalar;
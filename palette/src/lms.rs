@@ -0,0 +1,102 @@
+//! LMS cone response types and matrices.
+
+mod lms;
+
+pub use self::lms::{Lms, Lmsa};
+
+/// A matrix that converts from [`Xyz`](crate::Xyz) to the responses of the
+/// long, medium and short wavelength cones, for [`Lms`](crate::Lms).
+///
+/// These are the same kinds of matrices as the ones that are used for
+/// chromatic adaptation, and each of them models the cone responses a bit
+/// differently.
+pub trait LmsMatrix: 'static {
+    /// The matrix from XYZ to LMS, in row major order.
+    const XYZ_TO_LMS: [f64; 9];
+
+    /// The matrix from LMS to XYZ, in row major order. This is the inverse of
+    /// [`XYZ_TO_LMS`](LmsMatrix::XYZ_TO_LMS).
+    const LMS_TO_XYZ: [f64; 9];
+}
+
+/// The Hunt-Pointer-Estevez matrix, normalized to D65, as used in the von
+/// Kries chromatic adaptation method.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VonKries;
+
+impl LmsMatrix for VonKries {
+    #[rustfmt::skip]
+    const XYZ_TO_LMS: [f64; 9] = [
+        0.40024, 0.7076, -0.08081,
+        -0.2263, 1.16532, 0.0457,
+        0.0, 0.0, 0.91822,
+    ];
+
+    #[rustfmt::skip]
+    const LMS_TO_XYZ: [f64; 9] = [
+        1.8599363874558397, -1.1293816185800916, 0.21989740959619328,
+        0.3611914362417675, 0.6388124632850422, -6.370596838657058e-06,
+        0.0, 0.0, 1.0890636230968613,
+    ];
+}
+
+/// The Bradford matrix, which is "sharpened" to give better chromatic
+/// adaptation results than [`VonKries`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bradford;
+
+impl LmsMatrix for Bradford {
+    #[rustfmt::skip]
+    const XYZ_TO_LMS: [f64; 9] = [
+        0.8951, 0.2664, -0.1614,
+        -0.7502, 1.7135, 0.0367,
+        0.0389, -0.0685, 1.0296,
+    ];
+
+    #[rustfmt::skip]
+    const LMS_TO_XYZ: [f64; 9] = [
+        0.9869929054667121, -0.1470542564209901, 0.15996265166373125,
+        0.4323052697233945, 0.5183602715367776, 0.049291228212855615,
+        -0.008528664575177331, 0.04004282165408486, 0.96848669578755,
+    ];
+}
+
+/// The CAT02 matrix, from the CIECAM02 color appearance model.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cat02;
+
+impl LmsMatrix for Cat02 {
+    #[rustfmt::skip]
+    const XYZ_TO_LMS: [f64; 9] = [
+        0.7328, 0.4296, -0.1624,
+        -0.7036, 1.6975, 0.0061,
+        0.003, 0.0136, 0.9834,
+    ];
+
+    #[rustfmt::skip]
+    const LMS_TO_XYZ: [f64; 9] = [
+        1.0961238208355142, -0.27886900021828726, 0.1827451793827731,
+        0.45436904197535916, 0.4735331543074117, 0.07209780371722913,
+        -0.009627608738429355, -0.005698031216113421, 1.0153256399545427,
+    ];
+}
+
+/// The CAT16 matrix, from the CAM16 color appearance model.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cat16;
+
+impl LmsMatrix for Cat16 {
+    #[rustfmt::skip]
+    const XYZ_TO_LMS: [f64; 9] = [
+        0.401288, 0.650173, -0.051461,
+        -0.250268, 1.204414, 0.045854,
+        -0.002079, 0.048952, 0.953127,
+    ];
+
+    #[rustfmt::skip]
+    const LMS_TO_XYZ: [f64; 9] = [
+        1.8620678550872327, -1.0112546305316843, 0.14918677544445172,
+        0.38752654323613717, 0.6214474419314754, -0.008973985167612518,
+        -0.015841498849333856, -0.03412293802851556, 1.0499644368778493,
+    ];
+}
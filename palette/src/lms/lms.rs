@@ -0,0 +1,385 @@
+use core::marker::PhantomData;
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::convert::FromColorUnclamped;
+use crate::lms::{Bradford, LmsMatrix};
+use crate::matrix::{mat3_from_f64, multiply_xyz};
+use crate::white_point::Any;
+use crate::{Alpha, Component, FloatComponent, Xyz};
+
+/// LMS with an alpha component. See the [`Lmsa` implementation in
+/// `Alpha`](crate::Alpha#Lmsa).
+pub type Lmsa<M = Bradford, T = f32> = Alpha<Lms<M, T>, T>;
+
+/// The responses of the long, medium and short wavelength cones in the eye.
+///
+/// LMS is a linear transformation of [`Xyz`](crate::Xyz), where the matrix
+/// `M` decides how the cone responses are modeled. It's one of
+/// [`VonKries`](crate::lms::VonKries), [`Bradford`](crate::lms::Bradford),
+/// [`Cat02`](crate::lms::Cat02) and [`Cat16`](crate::lms::Cat16). The
+/// default is Bradford, which is also the default for
+/// [chromatic adaptation](crate::chromatic_adaptation).
+///
+/// Chromatic adaptation is done by scaling the cone responses, which is what
+/// [`adapt`](Lms::adapt) does:
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::convert::FromColorUnclamped;
+/// use palette::lms::Cat16;
+/// use palette::white_point::{WhitePoint, A, D65};
+/// use palette::{Lms, Xyz};
+///
+/// let color = Xyz::<A, f64>::new(0.5, 0.4, 0.1);
+/// let source_white = Lms::<Cat16, f64>::from_color_unclamped(A::get_xyz());
+/// let destination_white = Lms::from_color_unclamped(D65::get_xyz());
+///
+/// let adapted = Lms::from_color_unclamped(color).adapt(source_white, destination_white);
+/// let adapted = Xyz::<D65, f64>::from_color_unclamped(adapted);
+///
+/// // The source white point becomes the destination white point.
+/// let white = Xyz::<D65, f64>::from_color_unclamped(source_white.adapt(source_white, destination_white));
+/// assert_relative_eq!(white, D65::get_xyz().with_white_point(), epsilon = 1e-12);
+/// ```
+///
+/// There is no valid range for the components, so the conversions to and
+/// from LMS are always unclamped.
+#[derive(Debug, ArrayCast, WithAlpha)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[palette(palette_internal, component = "T")]
+#[repr(C)]
+pub struct Lms<M = Bradford, T = f32> {
+    /// The response of the long wavelength cones.
+    pub long: T,
+
+    /// The response of the medium wavelength cones.
+    pub medium: T,
+
+    /// The response of the short wavelength cones.
+    pub short: T,
+
+    /// The matrix that models the cone responses. The default is Bradford.
+    #[cfg_attr(feature = "serializing", serde(skip))]
+    #[palette(unsafe_zero_sized)]
+    pub matrix: PhantomData<M>,
+}
+
+impl<M, T> Copy for Lms<M, T> where T: Copy {}
+
+impl<M, T> Clone for Lms<M, T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Lms<M, T> {
+        Lms {
+            long: self.long.clone(),
+            medium: self.medium.clone(),
+            short: self.short.clone(),
+            matrix: PhantomData,
+        }
+    }
+}
+
+impl<M, T> Lms<M, T> {
+    /// Create an LMS color.
+    pub const fn new(long: T, medium: T, short: T) -> Lms<M, T> {
+        Lms {
+            long,
+            medium,
+            short,
+            matrix: PhantomData,
+        }
+    }
+
+    /// Convert to a `(long, medium, short)` tuple.
+    pub fn into_components(self) -> (T, T, T) {
+        (self.long, self.medium, self.short)
+    }
+
+    /// Convert from a `(long, medium, short)` tuple.
+    pub fn from_components((long, medium, short): (T, T, T)) -> Self {
+        Self::new(long, medium, short)
+    }
+}
+
+impl<M, T> Lms<M, T>
+where
+    T: FloatComponent,
+{
+    /// Scale the cone responses by the ratio between `destination_white` and
+    /// `source_white`, which is the von Kries chromatic adaptation transform.
+    ///
+    /// The white points should be converted to LMS with the same matrix as
+    /// the color.
+    #[must_use]
+    pub fn adapt(self, source_white: Self, destination_white: Self) -> Self {
+        Lms::new(
+            self.long * destination_white.long / source_white.long,
+            self.medium * destination_white.medium / source_white.medium,
+            self.short * destination_white.short / source_white.short,
+        )
+    }
+}
+
+impl<M, T> PartialEq for Lms<M, T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.long == other.long && self.medium == other.medium && self.short == other.short
+    }
+}
+
+impl<M, T> Eq for Lms<M, T> where T: Eq {}
+
+///<span id="Lmsa"></span>[`Lmsa`](crate::Lmsa) implementations.
+impl<M, T, A> Alpha<Lms<M, T>, A> {
+    /// Create an LMS color with transparency.
+    pub const fn new(long: T, medium: T, short: T, alpha: A) -> Self {
+        Alpha {
+            color: Lms::new(long, medium, short),
+            alpha,
+        }
+    }
+
+    /// Convert to a `(long, medium, short, alpha)` tuple.
+    pub fn into_components(self) -> (T, T, T, A) {
+        (
+            self.color.long,
+            self.color.medium,
+            self.color.short,
+            self.alpha,
+        )
+    }
+
+    /// Convert from a `(long, medium, short, alpha)` tuple.
+    pub fn from_components((long, medium, short, alpha): (T, T, T, A)) -> Self {
+        Self::new(long, medium, short, alpha)
+    }
+}
+
+impl<M, T> FromColorUnclamped<Lms<M, T>> for Lms<M, T> {
+    fn from_color_unclamped(color: Lms<M, T>) -> Self {
+        color
+    }
+}
+
+impl<Wp, M, T> FromColorUnclamped<Xyz<Wp, T>> for Lms<M, T>
+where
+    M: LmsMatrix,
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Xyz<Wp, T>) -> Self {
+        trace!(conversion color);
+        trace!(matrix "xyz_to_lms");
+        let lms = multiply_xyz(
+            &mat3_from_f64(&M::XYZ_TO_LMS),
+            &color.with_white_point::<Any>(),
+        );
+
+        Lms::new(lms.x, lms.y, lms.z)
+    }
+}
+
+impl<Wp, M, T> FromColorUnclamped<Lms<M, T>> for Xyz<Wp, T>
+where
+    M: LmsMatrix,
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Lms<M, T>) -> Self {
+        trace!(conversion color);
+        trace!(matrix "lms_to_xyz");
+        multiply_xyz(
+            &mat3_from_f64(&M::LMS_TO_XYZ),
+            &Xyz::new(color.long, color.medium, color.short),
+        )
+        .with_white_point()
+    }
+}
+
+impl<M, T> From<(T, T, T)> for Lms<M, T> {
+    fn from(components: (T, T, T)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<M, T> From<Lms<M, T>> for (T, T, T) {
+    fn from(color: Lms<M, T>) -> (T, T, T) {
+        color.into_components()
+    }
+}
+
+impl<M, T, A> From<(T, T, T, A)> for Alpha<Lms<M, T>, A> {
+    fn from(components: (T, T, T, A)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<M, T, A> From<Alpha<Lms<M, T>, A>> for (T, T, T, A) {
+    fn from(color: Alpha<Lms<M, T>, A>) -> (T, T, T, A) {
+        color.into_components()
+    }
+}
+
+impl_tuple_ref_conversions!([M, T] [] Lms<M, T>, (T, T, T) => (T, T, T));
+impl_tuple_ref_conversions!([M, T, A] [] Alpha<Lms<M, T>, A>, (T, T, T, A) => (T, T, T, A));
+
+impl<M, T> Default for Lms<M, T>
+where
+    T: Component,
+{
+    fn default() -> Lms<M, T> {
+        Lms::new(T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl_array_casts!(Lms<M, T>, [T; 3]);
+impl_component_access!(Lms<M, T>, [T; 3]);
+
+impl<M, T> AbsDiffEq for Lms<M, T>
+where
+    T: AbsDiffEq,
+    T::Epsilon: Clone,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    #[rustfmt::skip]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.long.abs_diff_eq(&other.long, epsilon.clone()) &&
+            self.medium.abs_diff_eq(&other.medium, epsilon.clone()) &&
+            self.short.abs_diff_eq(&other.short, epsilon)
+    }
+}
+
+impl<M, T> RelativeEq for Lms<M, T>
+where
+    T: RelativeEq,
+    T::Epsilon: Clone,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    #[rustfmt::skip]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.long.relative_eq(&other.long, epsilon.clone(), max_relative.clone()) &&
+            self.medium.relative_eq(&other.medium, epsilon.clone(), max_relative.clone()) &&
+            self.short.relative_eq(&other.short, epsilon, max_relative)
+    }
+}
+
+impl<M, T> UlpsEq for Lms<M, T>
+where
+    T: UlpsEq,
+    T::Epsilon: Clone,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    #[rustfmt::skip]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.long.ulps_eq(&other.long, epsilon.clone(), max_ulps) &&
+            self.medium.ulps_eq(&other.medium, epsilon.clone(), max_ulps) &&
+            self.short.ulps_eq(&other.short, epsilon, max_ulps)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<M, T> bytemuck::Zeroable for Lms<M, T> where T: bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<M: 'static, T> bytemuck::Pod for Lms<M, T> where T: bytemuck::Pod {}
+
+#[cfg(test)]
+mod test {
+    use super::Lms;
+    use crate::chromatic_adaptation::AdaptInto;
+    use crate::convert::{FromColorUnclamped, IntoColorUnclamped};
+    use crate::lms::{Bradford, Cat02, Cat16, LmsMatrix, VonKries};
+    use crate::white_point::{WhitePoint, A, C, D65};
+    use crate::{Lmsa, Xyz, Xyza};
+
+    fn round_trip<M: LmsMatrix>() {
+        let xyz = Xyz::<D65, f64>::new(0.3, 0.5, 0.2);
+        let lms: Lms<M, f64> = xyz.into_color_unclamped();
+        let back: Xyz<D65, f64> = lms.into_color_unclamped();
+        assert_relative_eq!(back, xyz, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn round_trips() {
+        round_trip::<VonKries>();
+        round_trip::<Bradford>();
+        round_trip::<Cat02>();
+        round_trip::<Cat16>();
+    }
+
+    #[test]
+    fn d65_white() {
+        let white = Lms::<Bradford, f64>::from_color_unclamped(<D65 as WhitePoint<f64>>::get_xyz());
+        assert_relative_eq!(
+            white,
+            Lms::new(0.941429, 1.040417, 1.089533),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn adapt_matches_chromatic_adaptation() {
+        let color = Xyz::<A, f64>::new(0.315756, 0.162732, 0.015905);
+        let expected: Xyz<C, f64> = color.adapt_into();
+
+        let source_white =
+            Lms::<Bradford, f64>::from_color_unclamped(<A as WhitePoint<f64>>::get_xyz());
+        let destination_white = Lms::from_color_unclamped(<C as WhitePoint<f64>>::get_xyz());
+        let adapted = Lms::<Bradford, f64>::from_color_unclamped(color)
+            .adapt(source_white, destination_white);
+
+        assert_relative_eq!(
+            Xyz::<C, f64>::from_color_unclamped(adapted),
+            expected,
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn alpha() {
+        let lmsa: Lmsa<Cat16, f64> =
+            Xyza::<D65, f64>::new(0.3, 0.5, 0.2, 0.5).into_color_unclamped();
+        assert_relative_eq!(lmsa.alpha, 0.5);
+
+        let back: Xyza<D65, f64> = lmsa.into_color_unclamped();
+        assert_relative_eq!(back, Xyza::new(0.3, 0.5, 0.2, 0.5), epsilon = 1e-12);
+    }
+
+    raw_pixel_conversion_tests!(Lms<Bradford>: long, medium, short);
+    raw_pixel_conversion_fail_tests!(Lms<Bradford>: long, medium, short);
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn serialize() {
+        let serialized = ::serde_json::to_string(&Lms::<Bradford>::new(0.3, 0.2, 0.1)).unwrap();
+
+        assert_eq!(serialized, r#"{"long":0.3,"medium":0.2,"short":0.1}"#);
+    }
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn deserialize() {
+        let deserialized: Lms<Bradford> =
+            ::serde_json::from_str(r#"{"long":0.3,"medium":0.2,"short":0.1}"#).unwrap();
+
+        assert_eq!(deserialized, Lms::new(0.3, 0.2, 0.1));
+    }
+}
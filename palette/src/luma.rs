@@ -3,8 +3,9 @@
 pub mod channels;
 mod luma;
 
-use crate::encoding::{Gamma, Linear, Srgb, TransferFn};
+use crate::encoding::{Gamma, Linear, Lstar, Srgb, TransferFn};
 use crate::white_point::{WhitePoint, D65};
+use crate::FromF64;
 
 pub use self::luma::{Luma, Lumaa};
 
@@ -25,6 +26,20 @@ pub type GammaLuma<T = f32> = Luma<Gamma<D65>, T>;
 /// Gamma 2.2 encoded luminance with an alpha component.
 pub type GammaLumaa<T = f32> = Lumaa<Gamma<D65>, T>;
 
+/// Luminance encoded as CIE L\* lightness.
+pub type LstarLuma<Wp = D65, T = f32> = Luma<Lstar<Wp>, T>;
+/// Luminance encoded as CIE L\* lightness, with an alpha component.
+pub type LstarLumaa<Wp = D65, T = f32> = Lumaa<Lstar<Wp>, T>;
+
+/// A packed representation of 8 bit luma, without alpha.
+pub type PackedY8 = crate::cast::Packed<channels::Y, u8>;
+
+/// A packed representation of 16 bit luma, without alpha.
+pub type PackedY16 = crate::cast::Packed<channels::Y, u16>;
+
+/// A packed representation of 8 bit luma and alpha, in LA order.
+pub type PackedYa8 = crate::cast::Packed<channels::La, u16>;
+
 /// A white point and a transfer function.
 pub trait LumaStandard<T>: 'static {
     /// The white point of the color space.
@@ -43,6 +58,52 @@ where
     type TransferFn = Tf;
 }
 
+/// Weights for calculating luma from the red, green and blue components of a
+/// color.
+///
+/// Video formats define luma as a weighted sum of the, usually gamma
+/// encoded, RGB components, and each standard has its own weights. They are
+/// used with [`Luma::from_rgb_weighted`], independently of the standard of
+/// the RGB color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LumaWeights {
+    /// The weights of ITU-R BT.601, used for standard definition video and
+    /// JPEG.
+    Rec601,
+
+    /// The weights of ITU-R BT.709, used for high definition video. They are
+    /// the same as the luminance coefficients of sRGB.
+    Rec709,
+
+    /// The weights of ITU-R BT.2020, used for ultra high definition video.
+    Rec2020,
+
+    /// The same weight for each component, which is the average of them.
+    Equal,
+}
+
+impl LumaWeights {
+    /// Get the weights of the red, green and blue components, in that
+    /// order. They always add up to `1`.
+    ///
+    /// ```
+    /// use palette::luma::LumaWeights;
+    ///
+    /// let [red, green, blue]: [f64; 3] = LumaWeights::Rec601.into_coefficients();
+    /// assert_eq!((red, green, blue), (0.299, 0.587, 0.114));
+    /// ```
+    pub fn into_coefficients<T: FromF64>(self) -> [T; 3] {
+        let [red, green, blue] = match self {
+            LumaWeights::Rec601 => [0.299, 0.587, 0.114],
+            LumaWeights::Rec709 => [0.2126, 0.7152, 0.0722],
+            LumaWeights::Rec2020 => [0.2627, 0.6780, 0.0593],
+            LumaWeights::Equal => [1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0],
+        };
+
+        [T::from_f64(red), T::from_f64(green), T::from_f64(blue)]
+    }
+}
+
 /// A packed representation of Luma+Alpha in LA order.
 pub type PackedLumaa<P = u16> = crate::cast::Packed<channels::La, P>;
 
@@ -1,6 +1,6 @@
 //! Channel orders for packed Luma types.
 
-use crate::{cast::ComponentOrder, luma};
+use crate::{cast::ComponentOrder, luma, Component};
 
 /// Luma+Alpha color packed in LA order.
 ///
@@ -39,3 +39,40 @@ impl<S, T> ComponentOrder<luma::Lumaa<S, T>, [T; 2]> for Al {
         luma::Lumaa::new(luma, alpha)
     }
 }
+
+/// Luma color packed without an alpha component.
+///
+/// The alpha is dropped when packing, and set to the maximum when unpacking.
+/// 8 bit luma is packed into a `u8` and 16 bit luma is packed into a `u16`.
+///
+/// See [Packed](crate::cast::Packed) for more details.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Y;
+
+impl<S, T> ComponentOrder<luma::Lumaa<S, T>, [T; 1]> for Y
+where
+    T: Component,
+{
+    #[inline]
+    fn pack(color: luma::Lumaa<S, T>) -> [T; 1] {
+        [color.color.luma]
+    }
+
+    #[inline]
+    fn unpack(packed: [T; 1]) -> luma::Lumaa<S, T> {
+        let [luma] = packed;
+        luma::Lumaa::new(luma, T::max_intensity())
+    }
+}
+
+impl<S> ComponentOrder<luma::Lumaa<S, u16>, u16> for Y {
+    #[inline]
+    fn pack(color: luma::Lumaa<S, u16>) -> u16 {
+        color.color.luma
+    }
+
+    #[inline]
+    fn unpack(packed: u16) -> luma::Lumaa<S, u16> {
+        luma::Lumaa::new(packed, u16::MAX)
+    }
+}
@@ -3,6 +3,7 @@ use core::convert::TryInto;
 use core::fmt;
 use core::marker::PhantomData;
 use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use core::str::FromStr;
 
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 use num_traits::Zero;
@@ -17,13 +18,17 @@ use crate::blend::PreAlpha;
 use crate::cast::{ComponentOrder, Packed, UintCast};
 use crate::convert::FromColorUnclamped;
 use crate::encoding::linear::LinearFn;
-use crate::encoding::{Linear, Srgb, TransferFn};
-use crate::luma::LumaStandard;
+use crate::encoding::{srgb, Linear, Srgb, TransferFn};
+use crate::luma::{LumaStandard, LumaWeights};
+use crate::rgb::{FromHexError, Rgb};
+use crate::white_point::D65;
 use crate::{
     clamp, clamp_assign, clamp_min_assign, contrast_ratio, Alpha, Blend, Clamp, ClampAssign,
     Component, ComponentWise, FloatComponent, FromComponent, IsWithinBounds, Lighten,
     LightenAssign, Mix, MixAssign, RelativeContrast, Xyz, Yxy,
 };
+#[cfg(feature = "random")]
+use crate::{Rounding, SaturatingFromComponent};
 
 /// Luminance with an alpha component. See the [`Lumaa` implementation
 /// in `Alpha`](crate::Alpha#Lumaa).
@@ -89,6 +94,21 @@ impl<S, T> Luma<S, T> {
         }
     }
 
+    /// Convert into another component type, with
+    /// [stochastic rounding](crate::Rounding::Stochastic).
+    #[cfg(feature = "random")]
+    pub fn into_format_stochastic<U, R>(self, rng: &mut R) -> Luma<S, U>
+    where
+        T: Component,
+        U: SaturatingFromComponent<T>,
+        R: Rng + ?Sized,
+    {
+        Luma {
+            luma: U::saturating_from_component(self.luma, Rounding::Stochastic(rng.gen())),
+            standard: PhantomData,
+        }
+    }
+
     /// Convert from another component type.
     pub fn from_format<U>(color: Luma<S, U>) -> Self
     where
@@ -120,6 +140,34 @@ impl<S, T> Luma<S, T> {
     }
 }
 
+impl<S, T> Luma<S, T>
+where
+    T: FloatComponent,
+{
+    /// Calculate the luma of an RGB color with a specific set of weights.
+    ///
+    /// The weights are applied to the components as they are, without
+    /// decoding or encoding them, which is how video formats define luma.
+    /// The result keeps the encoding of the input, so `S` should usually
+    /// have the same transfer function as `Rs`.
+    ///
+    /// ```
+    /// use palette::luma::LumaWeights;
+    /// use palette::{Srgb, SrgbLuma};
+    ///
+    /// let color = Srgb::new(0.8f32, 0.4, 0.2);
+    /// let sd = SrgbLuma::from_rgb_weighted(color, LumaWeights::Rec601);
+    /// let hd = SrgbLuma::from_rgb_weighted(color, LumaWeights::Rec709);
+    ///
+    /// assert!((sd.luma - 0.4968).abs() < 1e-4);
+    /// assert!((hd.luma - 0.4706).abs() < 1e-4);
+    /// ```
+    pub fn from_rgb_weighted<Rs>(color: Rgb<Rs, T>, weights: LumaWeights) -> Self {
+        let [red, green, blue] = weights.into_coefficients::<T>();
+        Self::new(color.red * red + color.green * green + color.blue * blue)
+    }
+}
+
 impl<S, T> Luma<S, T>
 where
     T: Component,
@@ -229,6 +277,43 @@ where
     }
 }
 
+impl Luma<Srgb, u8> {
+    /// Convert 8 bit sRGB luminance to linear `f32` luminance, using a lookup
+    /// table.
+    ///
+    /// This gives the same result as `color.into_format().into_linear()`,
+    /// but doesn't have to evaluate the transfer function.
+    ///
+    /// ```
+    /// use palette::{LinLuma, SrgbLuma};
+    ///
+    /// let linear: LinLuma = SrgbLuma::new(128u8).into_linear_lut();
+    /// assert!((linear.luma - 0.2158605).abs() < 1e-6);
+    /// ```
+    #[inline]
+    pub fn into_linear_lut(self) -> Luma<Linear<D65>, f32> {
+        Luma::new(srgb::u8_into_linear_f32(self.luma))
+    }
+
+    /// Convert linear `f32` luminance to 8 bit sRGB luminance, using a lookup
+    /// table.
+    ///
+    /// This rounds to the nearest 8 bit value, like
+    /// `Luma::from_linear(color).into_format()`, and values outside of `[0.0,
+    /// 1.0]` are clamped.
+    ///
+    /// ```
+    /// use palette::{LinLuma, SrgbLuma};
+    ///
+    /// let luma = SrgbLuma::from_linear_lut(LinLuma::new(0.2158605));
+    /// assert_eq!(luma, SrgbLuma::new(128u8));
+    /// ```
+    #[inline]
+    pub fn from_linear_lut(color: Luma<Linear<D65>, f32>) -> Self {
+        Luma::new(srgb::linear_f32_into_u8(color.luma))
+    }
+}
+
 impl<S, T> PartialEq for Luma<S, T>
 where
     T: PartialEq,
@@ -301,6 +386,24 @@ impl<S, T, A> Alpha<Luma<S, T>, A> {
         Alpha::<Luma<S, U>, B>::new(U::from_component(self.luma), B::from_component(self.alpha))
     }
 
+    /// Convert into another component type, with
+    /// [stochastic rounding](crate::Rounding::Stochastic) for both
+    /// components.
+    #[cfg(feature = "random")]
+    pub fn into_format_stochastic<U, B, R>(self, rng: &mut R) -> Alpha<Luma<S, U>, B>
+    where
+        T: Component,
+        A: Component,
+        U: SaturatingFromComponent<T>,
+        B: SaturatingFromComponent<A>,
+        R: Rng + ?Sized,
+    {
+        Alpha {
+            color: self.color.into_format_stochastic(rng),
+            alpha: B::saturating_from_component(self.alpha, Rounding::Stochastic(rng.gen())),
+        }
+    }
+
     /// Convert from another component type.
     pub fn from_format<U, B>(color: Alpha<Luma<S, U>, B>) -> Self
     where
@@ -423,6 +526,30 @@ where
     }
 }
 
+impl<A> Alpha<Luma<Srgb, u8>, A> {
+    /// Convert 8 bit sRGB luminance to linear `f32` luminance with
+    /// transparency, using a lookup table. The alpha component is not
+    /// changed.
+    #[inline]
+    pub fn into_linear_lut(self) -> Alpha<Luma<Linear<D65>, f32>, A> {
+        Alpha {
+            color: self.color.into_linear_lut(),
+            alpha: self.alpha,
+        }
+    }
+
+    /// Convert linear `f32` luminance to 8 bit sRGB luminance with
+    /// transparency, using a lookup table. The alpha component is not
+    /// changed.
+    #[inline]
+    pub fn from_linear_lut(color: Alpha<Luma<Linear<D65>, f32>, A>) -> Self {
+        Alpha {
+            color: Luma::from_linear_lut(color.color),
+            alpha: color.alpha,
+        }
+    }
+}
+
 impl<S1, S2, T> FromColorUnclamped<Luma<S2, T>> for Luma<S1, T>
 where
     S1: LumaStandard<T>,
@@ -430,6 +557,7 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(color: Luma<S2, T>) -> Self {
+        trace!(conversion color);
         if TypeId::of::<S1>() == TypeId::of::<S2>() {
             color.reinterpret_as()
         } else {
@@ -444,6 +572,7 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(color: Xyz<S::WhitePoint, T>) -> Self {
+        trace!(conversion color);
         Self::from_linear(Luma {
             luma: color.y,
             standard: PhantomData,
@@ -457,6 +586,7 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(color: Yxy<S::WhitePoint, T>) -> Self {
+        trace!(conversion color);
         Self::from_linear(Luma {
             luma: color.luma,
             standard: PhantomData,
@@ -488,6 +618,9 @@ impl<S, T, A> From<Alpha<Luma<S, T>, A>> for (T, A) {
     }
 }
 
+impl_tuple_ref_conversions!([S, T] [] Luma<S, T>, (T,) => (T,));
+impl_tuple_ref_conversions!([S, T, A] [] Alpha<Luma<S, T>, A>, (T, A) => (T, A));
+
 impl<S, T> IsWithinBounds for Luma<S, T>
 where
     T: Component,
@@ -855,6 +988,7 @@ where
 }
 
 impl_array_casts!(Luma<S, T>, [T; 1]);
+impl_component_access!(Luma<S, T>, [T; 1]);
 
 impl<S, T> AsRef<T> for Luma<S, T> {
     #[inline]
@@ -1074,6 +1208,48 @@ where
     }
 }
 
+impl<S> FromStr for Luma<S, u8> {
+    type Err = FromHexError;
+
+    // Parses a gray hex code of format '#ff' or '#f' into a Luma<S, u8>
+    // instance.
+    fn from_str(hex: &str) -> Result<Self, Self::Err> {
+        let hex_code = hex.strip_prefix('#').unwrap_or(hex);
+        match hex_code.len() {
+            1 => Ok(Luma::new(u8::from_str_radix(hex_code, 16)? * 17)),
+            2 => Ok(Luma::new(u8::from_str_radix(hex_code, 16)?)),
+            _ => Err("invalid hex code format, \
+                please use format '#f', 'f', '#ff' or 'ff'"
+                .into()),
+        }
+    }
+}
+
+impl<S> FromStr for Alpha<Luma<S, u8>, u8> {
+    type Err = FromHexError;
+
+    // Parses a gray hex code with alpha of format '#ffcc' or '#fc' into a
+    // Lumaa<S, u8> instance.
+    fn from_str(hex: &str) -> Result<Self, Self::Err> {
+        let hex_code = hex.strip_prefix('#').unwrap_or(hex);
+        match hex_code.len() {
+            2 => {
+                let luma = u8::from_str_radix(&hex_code[..1], 16)?;
+                let alpha = u8::from_str_radix(&hex_code[1..2], 16)?;
+                Ok(Alpha::<Luma<S, u8>, u8>::new(luma * 17, alpha * 17))
+            }
+            4 => {
+                let luma = u8::from_str_radix(&hex_code[..2], 16)?;
+                let alpha = u8::from_str_radix(&hex_code[2..4], 16)?;
+                Ok(Alpha::<Luma<S, u8>, u8>::new(luma, alpha))
+            }
+            _ => Err("invalid hex code format, \
+                please use format '#fc', 'fc', '#ffcc' or 'ffcc'"
+                .into()),
+        }
+    }
+}
+
 impl<S, T> RelativeContrast for Luma<S, T>
 where
     T: FloatComponent,
@@ -1172,6 +1348,7 @@ unsafe impl<S: 'static, T> bytemuck::Pod for Luma<S, T> where T: bytemuck::Pod {
 #[cfg(test)]
 mod test {
     use crate::encoding::Srgb;
+    use crate::luma::Lumaa;
     use crate::Luma;
 
     #[test]
@@ -1217,6 +1394,71 @@ mod test {
         assert_eq!(format!("{:X}", Luma::<Srgb, u8>::new(161)), "A1");
     }
 
+    #[test]
+    fn weighted() {
+        use crate::luma::LumaWeights;
+        use crate::Srgb as SrgbColor;
+
+        let white = SrgbColor::new(1.0f64, 1.0, 1.0);
+        let green = SrgbColor::new(0.0f64, 1.0, 0.0);
+
+        for &weights in &[
+            LumaWeights::Rec601,
+            LumaWeights::Rec709,
+            LumaWeights::Rec2020,
+            LumaWeights::Equal,
+        ] {
+            let luma = Luma::<Srgb, f64>::from_rgb_weighted(white, weights);
+            assert_relative_eq!(luma.luma, 1.0, epsilon = 1e-12);
+        }
+
+        assert_relative_eq!(
+            Luma::<Srgb, f64>::from_rgb_weighted(green, LumaWeights::Rec2020).luma,
+            0.678
+        );
+        assert_relative_eq!(
+            Luma::<Srgb, f64>::from_rgb_weighted(green, LumaWeights::Equal).luma,
+            1.0 / 3.0
+        );
+    }
+
+    #[test]
+    fn lumaa_hex() {
+        assert_eq!(format!("{:x}", Lumaa::<Srgb, u8>::new(255, 204)), "ffcc");
+        assert_eq!(format!("{:X}", Lumaa::<Srgb, u8>::new(1, 161)), "01A1");
+    }
+
+    #[test]
+    fn from_str() {
+        use core::str::FromStr;
+
+        assert_eq!(Luma::<Srgb, u8>::from_str("#ff").unwrap(), Luma::new(255));
+        assert_eq!(Luma::<Srgb, u8>::from_str("a1").unwrap(), Luma::new(161));
+        assert_eq!(Luma::<Srgb, u8>::from_str("#8").unwrap(), Luma::new(136));
+        assert!(Luma::<Srgb, u8>::from_str("#fff").is_err());
+        assert!(Luma::<Srgb, u8>::from_str("#gg").is_err());
+        assert!(Luma::<Srgb, u8>::from_str("").is_err());
+
+        assert_eq!(
+            Lumaa::<Srgb, u8>::from_str("#ffcc").unwrap(),
+            Lumaa::new(255, 204)
+        );
+        assert_eq!(
+            Lumaa::<Srgb, u8>::from_str("8f").unwrap(),
+            Lumaa::new(136, 255)
+        );
+        assert!(Lumaa::<Srgb, u8>::from_str("#fff").is_err());
+
+        let color: Luma<Srgb, u8> = "#5a".parse().unwrap();
+        assert_eq!(format!("#{:x}", color), "#5a");
+
+        let error = Lumaa::<Srgb, u8>::from_str("#f").unwrap_err();
+        assert_eq!(
+            format!("{}", error),
+            "invalid hex code format, please use format '#fc', 'fc', '#ffcc' or 'ffcc'."
+        );
+    }
+
     #[test]
     fn upper_hex_small_numbers() {
         assert_eq!(format!("{:X}", Luma::<Srgb, u8>::new(1)), "01");
@@ -1266,4 +1508,50 @@ mod test {
         min: Luma::new(0.0f32),
         max: Luma::new(1.0)
     }
+
+    #[test]
+    fn linear_lut() {
+        for value in 0..=255u8 {
+            let color = Luma::<Srgb, u8>::new(value);
+            let linear = color.into_linear_lut();
+            assert_relative_eq!(
+                linear,
+                color.into_format::<f32>().into_linear(),
+                epsilon = 1e-6
+            );
+            assert_eq!(Luma::from_linear_lut(linear), color);
+        }
+
+        for step in 0..=1000 {
+            let linear = crate::LinLuma::new(step as f32 / 1000.0);
+            let expected: Luma<Srgb, u8> = Luma::from_linear(linear).into_format();
+            let lut = Luma::<Srgb, u8>::from_linear_lut(linear);
+            assert!((i16::from(lut.luma) - i16::from(expected.luma)).abs() <= 1);
+        }
+
+        let clamped =
+            crate::Alpha::<Luma<Srgb, u8>, f32>::from_linear_lut(crate::LinLumaa::new(1.5, 0.5));
+        assert_eq!(clamped, crate::Alpha::<Luma<Srgb, u8>, f32>::new(255, 0.5));
+        let dark = Luma::<Srgb, u8>::from_linear_lut(crate::LinLuma::new(-0.5));
+        assert_eq!(dark, Luma::new(0));
+    }
+
+    #[test]
+    fn packed_grayscale() {
+        use crate::cast::{self, Packed};
+        use crate::luma::{channels, PackedY16, PackedY8, PackedYa8};
+
+        let packed = PackedY8::from(Luma::<Srgb, u8>::new(0x60));
+        assert_eq!(packed.color, 0x60);
+        let packed: Packed<channels::Y, u8> = cast::from_uint_slice::<PackedY8>(&[0x60, 0x70])[1];
+        assert_eq!(Lumaa::<Srgb, u8>::from(packed), Lumaa::new(0x70, 0xFF));
+
+        let packed = PackedY16::from(Luma::<Srgb, u16>::new(0x1234));
+        assert_eq!(packed.color, 0x1234);
+        assert_eq!(Lumaa::<Srgb, u16>::from(packed), Lumaa::new(0x1234, 0xFFFF));
+
+        let packed = PackedYa8::from(Lumaa::<Srgb, u8>::new(0x60, 0x80));
+        assert_eq!(packed.color, 0x6080);
+        assert_eq!(Luma::<Srgb, u8>::from(packed), Luma::new(0x60));
+    }
 }
@@ -10,6 +10,7 @@ use rand::distributions::{Distribution, Standard};
 use rand::Rng;
 
 use crate::convert::FromColorUnclamped;
+use crate::float;
 use crate::white_point::{WhitePoint, D65};
 use crate::{
     clamp, clamp_assign, clamp_min_assign, contrast_ratio, from_f64, Alpha, Clamp, ClampAssign,
@@ -167,7 +168,8 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(color: Lchuv<Wp, T>) -> Self {
-        let (sin_hue, cos_hue) = color.hue.to_radians().sin_cos();
+        trace!(conversion color);
+        let (sin_hue, cos_hue) = float::sin_cos(color.hue.to_radians());
         let chroma = color.chroma.max(T::zero());
         Luv::new(color.l, chroma * cos_hue, chroma * sin_hue)
     }
@@ -179,11 +181,12 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(color: Xyz<Wp, T>) -> Self {
+        trace!(conversion color);
         let from_f64 = T::from_f64;
         let w = Wp::get_xyz();
 
-        let kappa = from_f64(29.0 / 3.0).powi(3);
-        let epsilon = from_f64(6.0 / 29.0).powi(3);
+        let kappa = float::powi(from_f64(29.0 / 3.0), 3);
+        let epsilon = float::powi(from_f64(6.0 / 29.0), 3);
 
         let prime_denom = color.x + from_f64(15.0) * color.y + from_f64(3.0) * color.z;
         if prime_denom == from_f64(0.0) {
@@ -200,7 +203,7 @@ where
 
         let y_r = color.y / w.y;
         let l = if y_r > epsilon {
-            from_f64(116.0) * y_r.powf(from_f64(1.0 / 3.0)) - from_f64(16.0)
+            from_f64(116.0) * float::powf(y_r, from_f64(1.0 / 3.0)) - from_f64(16.0)
         } else {
             kappa * y_r
         };
@@ -238,6 +241,9 @@ impl<Wp, T, A> From<Alpha<Luv<Wp, T>, A>> for (T, T, T, A) {
     }
 }
 
+impl_tuple_ref_conversions!([Wp, T] [] Luv<Wp, T>, (T, T, T) => (T, T, T));
+impl_tuple_ref_conversions!([Wp, T, A] [] Alpha<Luv<Wp, T>, A>, (T, T, T, A) => (T, T, T, A));
+
 impl<Wp, T> IsWithinBounds for Luv<Wp, T>
 where
     T: Zero + FromF64 + PartialOrd,
@@ -373,7 +379,7 @@ where
         if self.u == T::zero() && self.v == T::zero() {
             None
         } else {
-            Some(LuvHue::from_radians(self.v.atan2(self.u)))
+            Some(LuvHue::from_radians(float::atan2(self.v, self.u)))
         }
     }
 }
@@ -418,6 +424,7 @@ impl_color_mul!(Luv<Wp, T>, [l, u, v], white_point);
 impl_color_div!(Luv<Wp, T>, [l, u, v], white_point);
 
 impl_array_casts!(Luv<Wp, T>, [T; 3]);
+impl_component_access!(Luv<Wp, T>, [T; 3]);
 
 impl<Wp, T> RelativeContrast for Luv<Wp, T>
 where
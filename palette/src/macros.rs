@@ -1,109 +1,3 @@
-#[cfg(test)]
-macro_rules! raw_pixel_conversion_tests {
-    ($name: ident <$($ty_param: path),+> : $($component: ident),+) => {
-        #[test]
-        fn convert_from_f32_array() {
-            raw_pixel_conversion_tests!(@float_array_test f32, $name<$($ty_param),+>: $($component),+);
-        }
-
-        #[test]
-        fn convert_from_f64_array() {
-            raw_pixel_conversion_tests!(@float_array_test f64, $name<$($ty_param),+>: $($component),+);
-        }
-
-        #[test]
-        fn convert_from_f32_slice() {
-            raw_pixel_conversion_tests!(@float_slice_test f32, $name<$($ty_param),+>: $($component),+);
-        }
-
-        #[test]
-        fn convert_from_f64_slice() {
-            raw_pixel_conversion_tests!(@float_slice_test f64, $name<$($ty_param),+>: $($component),+);
-        }
-    };
-
-    (@float_array_test $float: ty, $name: ident <$($ty_param: path),+> : $($component: ident),+) => {
-        use crate::cast::ArrayCast;
-        use crate::Alpha;
-
-        let mut counter: $float = 0.0;
-        $(
-            counter += 0.1;
-            let $component = counter;
-        )+
-        let alpha = counter + 0.1;
-
-        let raw: <$name<$($ty_param,)+ $float> as ArrayCast>::Array = [$($component),+];
-        let raw_plus_1: <Alpha<$name<$($ty_param,)+ $float>, $float> as ArrayCast>::Array = [
-            $($component,)+
-            alpha
-        ];
-        let color: $name<$($ty_param,)+ $float> = crate::cast::from_array(raw);
-
-        let color_alpha: Alpha<$name<$($ty_param,)+ $float>, $float> = crate::cast::from_array(raw_plus_1);
-
-        assert_eq!(color, $name::new($($component),+));
-
-        assert_eq!(color_alpha, Alpha::<$name<$($ty_param,)+ $float>, $float>::new($($component,)+ alpha));
-    };
-
-    (@float_slice_test $float: ty, $name: ident <$($ty_param: path),+> : $($component: ident),+) => {
-        use core::convert::{TryInto, TryFrom};
-        use crate::Alpha;
-
-        let mut counter: $float = 0.0;
-        $(
-            counter += 0.1;
-            let $component = counter;
-        )+
-        let alpha = counter + 0.1;
-        let extra = counter + 0.2;
-        let raw: &[$float] = &[$($component),+];
-        let raw_plus_1: &[$float] = &[
-            $($component,)+
-            alpha
-        ];
-        let raw_plus_2: &[$float] = &[
-            $($component,)+
-            alpha,
-            extra
-        ];
-        let color: &$name<$($ty_param,)+ $float> = raw.try_into().unwrap();
-        assert!(<&$name<$($ty_param,)+ $float>>::try_from(raw_plus_1).is_err());
-
-        let color_alpha: &Alpha<$name<$($ty_param,)+ $float>, $float> = raw_plus_1.try_into().unwrap();
-        assert!(<&Alpha<$name<$($ty_param,)+ $float>, $float>>::try_from(raw_plus_2).is_err());
-
-        assert_eq!(color, &$name::new($($component),+));
-
-        assert_eq!(color_alpha, &Alpha::<$name<$($ty_param,)+ $float>, $float>::new($($component,)+ alpha));
-    };
-}
-
-#[cfg(test)]
-macro_rules! raw_pixel_conversion_fail_tests {
-    ($name: ident <$($ty_param: path),+> : $($component: ident),+) => {
-        #[test]
-        #[should_panic(expected = "TryFromSliceError")]
-        fn convert_from_short_f32_slice() {
-            raw_pixel_conversion_fail_tests!(@float_slice_test f32, $name<$($ty_param),+>);
-        }
-
-        #[test]
-        #[should_panic(expected = "TryFromSliceError")]
-        fn convert_from_short_f64_slice() {
-            raw_pixel_conversion_fail_tests!(@float_slice_test f64, $name<$($ty_param),+>);
-        }
-    };
-
-    (@float_slice_test $float: ty, $name: ident <$($ty_param: path),+>) => {
-        use core::convert::TryInto;
-
-        let raw: &[$float] = &[0.1];
-        let _: &$name<$($ty_param,)+ $float> = raw.try_into().unwrap();
-    };
-}
-
 #[cfg(all(test, feature = "random"))]
 macro_rules! assert_uniform_distribution {
     ($bins:expr) => {{
@@ -239,6 +133,23 @@ macro_rules! test_uniform_distribution {
     };
 }
 
+/// Record a conversion step, if the `trace` feature is enabled. See the
+/// `trace` module.
+macro_rules! trace {
+    (conversion $from:expr) => {
+        #[cfg(feature = "trace")]
+        $crate::trace::record_conversion::<_, Self>(&$from);
+    };
+    (matrix $name:expr) => {
+        #[cfg(feature = "trace")]
+        $crate::trace::record($crate::trace::TraceEvent::Matrix { name: $name });
+    };
+    (clamp) => {
+        #[cfg(feature = "trace")]
+        $crate::trace::record_clamp::<Self>();
+    };
+}
+
 macro_rules! impl_color_add {
     ($self_ty: ident < $phantom_ty: ident, $component_ty: ident > , [$($element: ident),+], $phantom: ident) => {
         impl<$phantom_ty, $component_ty> Add<Self> for $self_ty<$phantom_ty, $component_ty>
@@ -629,6 +540,77 @@ macro_rules! impl_color_div {
     };
 }
 
+/// Implement conversions from tuple references to colors, and from color
+/// references to tuples, by cloning and using the conversions by value. The
+/// type parameters in the second list are only used by the tuple that is
+/// converted from.
+macro_rules! impl_tuple_ref_conversions {
+    ([$($ty_param:ident),*] [$($from_param:ident),*] $self_ty:ty, $from_tuple:ty => $into_tuple:ty) => {
+        impl<'a, $($ty_param,)* $($from_param,)*> From<&'a $from_tuple> for $self_ty
+        where
+            $from_tuple: Clone,
+            $self_ty: From<$from_tuple>,
+        {
+            #[inline]
+            fn from(components: &'a $from_tuple) -> Self {
+                Self::from(components.clone())
+            }
+        }
+
+        impl<'a, $($ty_param,)*> From<&'a $self_ty> for $into_tuple
+        where
+            $self_ty: Clone + Into<$into_tuple>,
+        {
+            #[inline]
+            fn from(color: &'a $self_ty) -> Self {
+                color.clone().into()
+            }
+        }
+    };
+}
+
+/// Implement indexing and iteration over the components of a color type,
+/// in the same order as in its array representation.
+macro_rules! impl_component_access {
+    ($self_ty: ident < $($ty_param: ident),+ >, [$array_item: ty; $array_len: expr]) => {
+        impl<$($ty_param),+> core::ops::Index<usize> for $self_ty<$($ty_param),+> {
+            type Output = $array_item;
+
+            #[inline]
+            fn index(&self, index: usize) -> &$array_item {
+                &crate::cast::into_array_ref(self)[index]
+            }
+        }
+
+        impl<$($ty_param),+> core::ops::IndexMut<usize> for $self_ty<$($ty_param),+> {
+            #[inline]
+            fn index_mut(&mut self, index: usize) -> &mut $array_item {
+                &mut crate::cast::into_array_mut(self)[index]
+            }
+        }
+
+        impl<'a, $($ty_param),+> IntoIterator for &'a $self_ty<$($ty_param),+> {
+            type Item = &'a $array_item;
+            type IntoIter = core::slice::Iter<'a, $array_item>;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                crate::cast::into_array_ref(self).iter()
+            }
+        }
+
+        impl<'a, $($ty_param),+> IntoIterator for &'a mut $self_ty<$($ty_param),+> {
+            type Item = &'a mut $array_item;
+            type IntoIter = core::slice::IterMut<'a, $array_item>;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                crate::cast::into_array_mut(self).iter_mut()
+            }
+        }
+    };
+}
+
 macro_rules! impl_array_casts {
     ($self_ty: ident < $($ty_param: ident),+ > $($rest: tt)*) => {
         impl_array_casts!([$($ty_param),+] $self_ty < $($ty_param),+ > $($rest)*);
@@ -786,6 +768,28 @@ macro_rules! impl_array_casts {
             }
         }
 
+        impl<'a, $($ty_param)+> From<&'a [$array_item; $array_len]> for $self_ty<$($self_ty_param),+>
+        where
+            $array_item: Clone,
+            $($($where)+)?
+        {
+            #[inline]
+            fn from(array: &'a [$array_item; $array_len]) -> Self {
+                crate::cast::from_array(array.clone())
+            }
+        }
+
+        impl<'a, $($ty_param)+> From<&'a $self_ty<$($self_ty_param),+>> for [$array_item; $array_len]
+        where
+            $array_item: Clone,
+            $($($where)+)?
+        {
+            #[inline]
+            fn from(color: &'a $self_ty<$($self_ty_param),+>) -> Self {
+                crate::cast::into_array_ref(color).clone()
+            }
+        }
+
         #[cfg(feature = "std")]
         impl<$($ty_param)+> From<Box<$self_ty<$($self_ty_param),+>>> for Box<[$array_item; $array_len]>
         $(where $($where)+)?
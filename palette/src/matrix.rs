@@ -8,7 +8,7 @@ use crate::encoding::Linear;
 use crate::float::Float;
 use crate::rgb::{Primaries, Rgb, RgbSpace};
 use crate::white_point::{Any, WhitePoint};
-use crate::{FloatComponent, Xyz};
+use crate::{FloatComponent, FromF64, Xyz};
 
 /// A 9 element array representing a 3x3 matrix.
 pub type Mat3<T> = [T; 9];
@@ -130,8 +130,104 @@ pub fn matrix_inverse<T: Float>(a: &Mat3<T>) -> Mat3<T> {
 }
 
 /// Generates the Srgb to Xyz transformation matrix for a given white point.
+///
+/// The precomputed matrix from [`RgbSpace::rgb_to_xyz_matrix`] is used if
+/// there is one.
 #[inline]
 pub fn rgb_to_xyz_matrix<S: RgbSpace<T>, T: FloatComponent>() -> Mat3<T> {
+    S::rgb_to_xyz_matrix().unwrap_or_else(calculate_rgb_to_xyz_matrix::<S, T>)
+}
+
+/// Generates the Xyz to Srgb transformation matrix for a given white point.
+///
+/// The precomputed matrix from [`RgbSpace::xyz_to_rgb_matrix`] is used if
+/// there is one, otherwise it's the inverse of [`rgb_to_xyz_matrix`].
+#[inline]
+pub fn xyz_to_rgb_matrix<S: RgbSpace<T>, T: FloatComponent>() -> Mat3<T> {
+    S::xyz_to_rgb_matrix().unwrap_or_else(|| matrix_inverse(&rgb_to_xyz_matrix::<S, T>()))
+}
+
+/// Convert a matrix of `f64` constants, such as the ones from
+/// [`rgb_space_constants`], to `T`.
+#[inline]
+pub fn mat3_from_f64<T: FromF64>(m: &Mat3<f64>) -> Mat3<T> {
+    let [m0, m1, m2, m3, m4, m5, m6, m7, m8] = *m;
+
+    [
+        T::from_f64(m0),
+        T::from_f64(m1),
+        T::from_f64(m2),
+        T::from_f64(m3),
+        T::from_f64(m4),
+        T::from_f64(m5),
+        T::from_f64(m6),
+        T::from_f64(m7),
+        T::from_f64(m8),
+    ]
+}
+
+/// Generate Rust source code for the conversion matrices of an RGB space.
+///
+/// This is meant to be used in build scripts, to move the matrix
+/// calculations from run-time to build-time. The output contains two `f64`
+/// constants, `{prefix}_RGB_TO_XYZ` and `{prefix}_XYZ_TO_RGB`, that are
+/// always calculated from the primaries and white point of `S`. They can be
+/// written to a file in `OUT_DIR`, included with `include!`, and returned
+/// from [`RgbSpace::rgb_to_xyz_matrix`] and
+/// [`RgbSpace::xyz_to_rgb_matrix`], using [`mat3_from_f64`]. They will then
+/// be stored as read-only data, instead of being calculated in each
+/// conversion.
+///
+/// This function is only available if the `std` feature is enabled.
+///
+/// ```
+/// use palette::encoding::Srgb;
+/// use palette::matrix::rgb_space_constants;
+///
+/// let source = rgb_space_constants::<Srgb>("SRGB");
+/// assert!(source.starts_with("pub const SRGB_RGB_TO_XYZ: [f64; 9] = ["));
+/// assert!(source.contains("pub const SRGB_XYZ_TO_RGB: [f64; 9] = ["));
+/// ```
+///
+/// A build script could look something like this, with a custom space
+/// called `MySpace`:
+///
+/// ```ignore
+/// // build.rs
+/// let out_dir = std::env::var("OUT_DIR").unwrap();
+/// let source = palette::matrix::rgb_space_constants::<MySpace>("MY_SPACE");
+/// std::fs::write(std::path::Path::new(&out_dir).join("my_space.rs"), source).unwrap();
+///
+/// // my_space.rs
+/// include!(concat!(env!("OUT_DIR"), "/my_space.rs"));
+///
+/// impl<T: FloatComponent> RgbSpace<T> for MySpace {
+///     type Primaries = MySpace;
+///     type WhitePoint = D65;
+///
+///     fn rgb_to_xyz_matrix() -> Option<Mat3<T>> {
+///         Some(mat3_from_f64(&MY_SPACE_RGB_TO_XYZ))
+///     }
+///
+///     fn xyz_to_rgb_matrix() -> Option<Mat3<T>> {
+///         Some(mat3_from_f64(&MY_SPACE_XYZ_TO_RGB))
+///     }
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn rgb_space_constants<S: RgbSpace<f64>>(prefix: &str) -> String {
+    let rgb_to_xyz = calculate_rgb_to_xyz_matrix::<S, f64>();
+    let xyz_to_rgb = matrix_inverse(&rgb_to_xyz);
+
+    format!(
+        "pub const {0}_RGB_TO_XYZ: [f64; 9] = {1:?};\npub const {0}_XYZ_TO_RGB: [f64; 9] = {2:?};\n",
+        prefix, rgb_to_xyz, xyz_to_rgb
+    )
+}
+
+/// Calculates the Rgb to Xyz matrix from the primaries and the white point.
+#[inline]
+fn calculate_rgb_to_xyz_matrix<S: RgbSpace<T>, T: FloatComponent>() -> Mat3<T> {
     let r = S::Primaries::red().into_color_unclamped();
     let g = S::Primaries::green().into_color_unclamped();
     let b = S::Primaries::blue().into_color_unclamped();
@@ -169,10 +265,13 @@ fn mat3_from_primaries<T: FloatComponent>(r: Xyz<Any, T>, g: Xyz<Any, T>, b: Xyz
 
 #[cfg(test)]
 mod test {
-    use super::{matrix_inverse, multiply_3x3, multiply_xyz, rgb_to_xyz_matrix};
+    use super::{
+        calculate_rgb_to_xyz_matrix, matrix_inverse, multiply_3x3, multiply_xyz, rgb_to_xyz_matrix,
+        xyz_to_rgb_matrix,
+    };
     use crate::chromatic_adaptation::AdaptInto;
-    use crate::encoding::{Linear, Srgb};
-    use crate::rgb::Rgb;
+    use crate::encoding::{DciP3, DisplayP3, Linear, ProPhotoRgb, Rec2020, Srgb};
+    use crate::rgb::{Rgb, RgbSpace};
     use crate::white_point::D50;
     use crate::Xyz;
 
@@ -248,4 +347,60 @@ mod test {
         let computed: Rgb<Linear<(Srgb, D50)>> = input.adapt_into();
         assert_relative_eq!(expected, computed, epsilon = 0.000001);
     }
+
+    /// Check that the precomputed matrices of `S` match the ones that are
+    /// calculated from its primaries and white point, so the constants in the
+    /// build script can't drift from the types.
+    fn assert_precomputed_matrices<S: RgbSpace<f64>>() {
+        let rgb_to_xyz = calculate_rgb_to_xyz_matrix::<S, f64>();
+        let xyz_to_rgb = matrix_inverse(&rgb_to_xyz);
+        let precomputed_rgb_to_xyz =
+            S::rgb_to_xyz_matrix().expect("the RGB space should have a precomputed matrix");
+        let precomputed_xyz_to_rgb =
+            S::xyz_to_rgb_matrix().expect("the RGB space should have a precomputed matrix");
+
+        for (e, c) in rgb_to_xyz.iter().zip(&precomputed_rgb_to_xyz) {
+            assert_relative_eq!(e, c, epsilon = 1e-15)
+        }
+        for (e, c) in xyz_to_rgb.iter().zip(&precomputed_xyz_to_rgb) {
+            assert_relative_eq!(e, c, epsilon = 1e-14)
+        }
+    }
+
+    #[test]
+    fn precomputed_matrices() {
+        assert_precomputed_matrices::<Srgb>();
+        assert_precomputed_matrices::<Rec2020>();
+        assert_precomputed_matrices::<DisplayP3>();
+        assert_precomputed_matrices::<ProPhotoRgb>();
+        assert_precomputed_matrices::<DciP3>();
+    }
+
+    #[test]
+    fn calculated_matrices() {
+        type Space = (Srgb, D50);
+        let rgb_to_xyz = rgb_to_xyz_matrix::<Space, f64>();
+        let product = multiply_3x3(&rgb_to_xyz, &xyz_to_rgb_matrix::<Space, f64>());
+
+        let identity = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+        for (e, c) in identity.iter().zip(&product) {
+            assert_relative_eq!(e, c, epsilon = 1e-12)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn rgb_space_constants() {
+        let source = super::rgb_space_constants::<Srgb>("SRGB");
+        let rgb_to_xyz = rgb_to_xyz_matrix::<Srgb, f64>();
+
+        assert_eq!(
+            source.lines().next(),
+            Some(&*format!(
+                "pub const SRGB_RGB_TO_XYZ: [f64; 9] = {:?};",
+                rgb_to_xyz
+            ))
+        );
+        assert_eq!(source.lines().count(), 2);
+    }
 }
@@ -0,0 +1,888 @@
+//! Reading and writing color measurement files.
+//!
+//! Spectrophotometers and color management tools exchange measurements as
+//! text files. This module reads and writes two of the common formats, as
+//! [`SampleSet`]s of [`Lab`] or [`Xyz`] colors:
+//!
+//! * CGATS.17, also known as IT8.7, is a plain text table with a header of
+//!   keywords. The color values are read from the `LAB_L`, `LAB_A` and
+//!   `LAB_B`, or the `XYZ_X`, `XYZ_Y` and `XYZ_Z` columns, and the samples
+//!   are identified by the `SAMPLE_ID` and `SAMPLE_NAME` columns. Only the
+//!   first table in a file is read.
+//! * CxF3 is an XML format. The basic support reads the name, the id and the
+//!   `ColorCIELab` or `ColorCIEXYZ` value of each `Object`, and skips objects
+//!   without that kind of value, such as spectral only objects.
+//!
+//! ```
+//! use palette::measurement::SampleSet;
+//! use palette::white_point::D50;
+//! use palette::{ColorDifference, Lab};
+//!
+//! let measured = "CGATS.17
+//! ORIGINATOR \"Press 3\"
+//! NUMBER_OF_FIELDS 5
+//! BEGIN_DATA_FORMAT
+//! SAMPLE_ID SAMPLE_NAME LAB_L LAB_A LAB_B
+//! END_DATA_FORMAT
+//! NUMBER_OF_SETS 2
+//! BEGIN_DATA
+//! 1 \"Paper\" 95.10 0.52 -2.31
+//! 2 \"Brand red\" 48.02 68.87 47.95
+//! END_DATA
+//! ";
+//!
+//! let set = SampleSet::<Lab<D50, f64>>::from_cgats(measured).unwrap();
+//! assert_eq!(set.keyword("ORIGINATOR"), Some("Press 3"));
+//!
+//! let brand_red = Lab::new(48.0, 69.0, 48.0);
+//! let sample = &set.samples[1];
+//! assert_eq!(sample.name.as_deref(), Some("Brand red"));
+//! assert!(brand_red.get_color_difference(sample.color) < 1.0);
+//! ```
+//!
+//! The white point of the colors is chosen with the color type. It's
+//! usually D50 for measurements, but the files don't always say, so it's up
+//! to the caller to know.
+
+use core::fmt;
+use core::fmt::Write;
+
+use crate::{FloatComponent, Lab, Xyz};
+
+/// A color type that can be read from and written to measurement files.
+pub trait MeasuredColor: Sized {
+    /// The names of the CGATS fields for the components.
+    const CGATS_FIELDS: [&'static str; 3];
+
+    /// The name of the CxF element for the color value.
+    const CXF_ELEMENT: &'static str;
+
+    /// The names of the CxF elements for the components.
+    const CXF_COMPONENTS: [&'static str; 3];
+
+    /// Create a color from its components.
+    fn from_components(components: [f64; 3]) -> Self;
+
+    /// Get the components of the color.
+    fn to_components(&self) -> [f64; 3];
+}
+
+impl<Wp, T> MeasuredColor for Lab<Wp, T>
+where
+    T: FloatComponent,
+{
+    const CGATS_FIELDS: [&'static str; 3] = ["LAB_L", "LAB_A", "LAB_B"];
+    const CXF_ELEMENT: &'static str = "ColorCIELab";
+    const CXF_COMPONENTS: [&'static str; 3] = ["L", "A", "B"];
+
+    fn from_components([l, a, b]: [f64; 3]) -> Self {
+        Lab::new(T::from_f64(l), T::from_f64(a), T::from_f64(b))
+    }
+
+    fn to_components(&self) -> [f64; 3] {
+        [to_f64(self.l), to_f64(self.a), to_f64(self.b)]
+    }
+}
+
+impl<Wp, T> MeasuredColor for Xyz<Wp, T>
+where
+    T: FloatComponent,
+{
+    const CGATS_FIELDS: [&'static str; 3] = ["XYZ_X", "XYZ_Y", "XYZ_Z"];
+    const CXF_ELEMENT: &'static str = "ColorCIEXYZ";
+    const CXF_COMPONENTS: [&'static str; 3] = ["X", "Y", "Z"];
+
+    /// Create a color from components in the range `[0, 100]`, as in the
+    /// files, while `Xyz` is in the range `[0, 1]`.
+    fn from_components([x, y, z]: [f64; 3]) -> Self {
+        Xyz::new(
+            T::from_f64(x / 100.0),
+            T::from_f64(y / 100.0),
+            T::from_f64(z / 100.0),
+        )
+    }
+
+    /// Get the components in the range `[0, 100]`, as in the files, while
+    /// `Xyz` is in the range `[0, 1]`.
+    fn to_components(&self) -> [f64; 3] {
+        [
+            to_f64(self.x) * 100.0,
+            to_f64(self.y) * 100.0,
+            to_f64(self.z) * 100.0,
+        ]
+    }
+}
+
+fn to_f64<T: FloatComponent>(value: T) -> f64 {
+    value.to_f64().unwrap_or(f64::NAN)
+}
+
+/// A measured color, with its identification.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sample<C> {
+    /// The id of the sample. It's the `SAMPLE_ID` field in CGATS, or the
+    /// position of the sample, starting from `1`, if there is no such field.
+    pub id: String,
+
+    /// The name of the sample, if it has one.
+    pub name: Option<String>,
+
+    /// The measured color.
+    pub color: C,
+}
+
+/// A set of measured colors, and the keywords that describe them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SampleSet<C> {
+    /// The keywords and their values, such as `ORIGINATOR` and
+    /// `MEASUREMENT_SOURCE`, in the order they were read. CxF files don't
+    /// have keywords.
+    pub keywords: Vec<(String, String)>,
+
+    /// The samples, in the order they were read.
+    pub samples: Vec<Sample<C>>,
+}
+
+impl<C> SampleSet<C> {
+    /// Create an empty set, without keywords.
+    pub fn new() -> Self {
+        SampleSet {
+            keywords: Vec::new(),
+            samples: Vec::new(),
+        }
+    }
+
+    /// Get the value of the first keyword with the name `name`.
+    pub fn keyword(&self, name: &str) -> Option<&str> {
+        self.keywords
+            .iter()
+            .find(|(keyword, _)| keyword == name)
+            .map(|(_, value)| &**value)
+    }
+
+    /// Iterate over the colors of the samples.
+    pub fn colors(&self) -> impl Iterator<Item = &C> {
+        self.samples.iter().map(|sample| &sample.color)
+    }
+}
+
+impl<C> Default for SampleSet<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C> SampleSet<C>
+where
+    C: MeasuredColor,
+{
+    /// Read the first table of a CGATS.17 file.
+    pub fn from_cgats(text: &str) -> Result<Self, ParseMeasurementError> {
+        let mut set = SampleSet::new();
+        let mut fields: Option<Vec<String>> = None;
+        let mut expected_count: Option<usize> = None;
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'));
+
+        // The first line identifies the kind of file, such as `CGATS.17` or
+        // `IT8.7/2`.
+        if lines.next().is_none() {
+            return Err(ParseMeasurementError::new(
+                ParseMeasurementErrorKind::UnexpectedEnd,
+                1,
+            ));
+        }
+
+        let mut last_line = 1;
+        while let Some((line_number, line)) = lines.next() {
+            last_line = line_number;
+            let tokens = tokenize(line).ok_or_else(|| {
+                ParseMeasurementError::new(ParseMeasurementErrorKind::InvalidSyntax, line_number)
+            })?;
+
+            match &*tokens[0] {
+                "BEGIN_DATA_FORMAT" => {
+                    let mut names = Vec::new();
+                    loop {
+                        let (line_number, line) = lines.next().ok_or_else(|| {
+                            ParseMeasurementError::new(
+                                ParseMeasurementErrorKind::UnexpectedEnd,
+                                last_line,
+                            )
+                        })?;
+                        last_line = line_number;
+
+                        if line == "END_DATA_FORMAT" {
+                            break;
+                        }
+
+                        names.extend(tokenize(line).ok_or_else(|| {
+                            ParseMeasurementError::new(
+                                ParseMeasurementErrorKind::InvalidSyntax,
+                                line_number,
+                            )
+                        })?);
+                    }
+                    fields = Some(names);
+                }
+                "BEGIN_DATA" => {
+                    let fields = fields.as_ref().ok_or_else(|| {
+                        ParseMeasurementError::new(
+                            ParseMeasurementErrorKind::MissingFields,
+                            line_number,
+                        )
+                    })?;
+                    let columns = Columns::new::<C>(fields).ok_or_else(|| {
+                        ParseMeasurementError::new(
+                            ParseMeasurementErrorKind::MissingFields,
+                            line_number,
+                        )
+                    })?;
+
+                    loop {
+                        let (line_number, line) = lines.next().ok_or_else(|| {
+                            ParseMeasurementError::new(
+                                ParseMeasurementErrorKind::UnexpectedEnd,
+                                last_line,
+                            )
+                        })?;
+                        last_line = line_number;
+
+                        if line == "END_DATA" {
+                            break;
+                        }
+
+                        let sample = columns.read_sample(line, set.samples.len() + 1);
+                        set.samples.push(
+                            sample.map_err(|kind| ParseMeasurementError::new(kind, line_number))?,
+                        );
+                    }
+
+                    match expected_count {
+                        Some(count) if count != set.samples.len() => {
+                            return Err(ParseMeasurementError::new(
+                                ParseMeasurementErrorKind::WrongSampleCount,
+                                last_line,
+                            ))
+                        }
+                        _ => return Ok(set),
+                    }
+                }
+                "NUMBER_OF_FIELDS" => {}
+                "NUMBER_OF_SETS" => {
+                    let count = tokens.get(1).and_then(|count| count.parse().ok());
+                    expected_count = Some(count.ok_or_else(|| {
+                        ParseMeasurementError::new(
+                            ParseMeasurementErrorKind::InvalidNumber,
+                            line_number,
+                        )
+                    })?);
+                }
+                _ => {
+                    let mut tokens = tokens.into_iter();
+                    let keyword = tokens.next().unwrap_or_default();
+                    let value = tokens.collect::<Vec<_>>().join(" ");
+                    set.keywords.push((keyword, value));
+                }
+            }
+        }
+
+        Err(ParseMeasurementError::new(
+            ParseMeasurementErrorKind::UnexpectedEnd,
+            last_line,
+        ))
+    }
+
+    /// Write the samples as a CGATS.17 file, with the components rounded to
+    /// four decimals.
+    ///
+    /// The `SAMPLE_NAME` field is only included if some sample has a name.
+    pub fn to_cgats(&self) -> String {
+        let mut text = String::from("CGATS.17\n");
+        let has_names = self.samples.iter().any(|sample| sample.name.is_some());
+
+        for (keyword, value) in &self.keywords {
+            let _ = writeln!(text, "{} \"{}\"", keyword, value.replace('"', "'"));
+        }
+
+        let mut fields = vec!["SAMPLE_ID"];
+        if has_names {
+            fields.push("SAMPLE_NAME");
+        }
+        fields.extend_from_slice(&C::CGATS_FIELDS);
+
+        let _ = writeln!(text, "NUMBER_OF_FIELDS {}", fields.len());
+        let _ = writeln!(
+            text,
+            "BEGIN_DATA_FORMAT\n{}\nEND_DATA_FORMAT",
+            fields.join(" ")
+        );
+        let _ = writeln!(text, "NUMBER_OF_SETS {}\nBEGIN_DATA", self.samples.len());
+
+        for sample in &self.samples {
+            if sample.id.is_empty() || sample.id.contains(char::is_whitespace) {
+                let _ = write!(text, "\"{}\"", sample.id.replace('"', "'"));
+            } else {
+                text.push_str(&sample.id);
+            }
+
+            if has_names {
+                let name = sample.name.as_deref().unwrap_or("");
+                let _ = write!(text, " \"{}\"", name.replace('"', "'"));
+            }
+
+            for component in &sample.color.to_components() {
+                let _ = write!(text, " {:.4}", component);
+            }
+            text.push('\n');
+        }
+
+        text.push_str("END_DATA\n");
+        text
+    }
+
+    /// Read the objects of a CxF3 file.
+    pub fn from_cxf(text: &str) -> Result<Self, ParseMeasurementError> {
+        let mut set = SampleSet::new();
+        let mut reader = XmlReader::new(text);
+        let mut object: Option<(String, Option<String>)> = None;
+        let mut components: [Option<f64>; 3] = [None; 3];
+        let mut in_color = false;
+        let mut has_color = false;
+
+        while let Some(event) = reader.next_event()? {
+            match event {
+                XmlEvent::Start(name, attributes) => {
+                    if name == "Object" {
+                        let id = attribute(&attributes, "Id")
+                            .unwrap_or_else(|| (set.samples.len() + 1).to_string());
+                        object = Some((id, attribute(&attributes, "Name")));
+                        components = [None; 3];
+                        has_color = false;
+                    } else if name == C::CXF_ELEMENT && object.is_some() && !has_color {
+                        in_color = true;
+                    } else if in_color {
+                        if let Some(index) = C::CXF_COMPONENTS.iter().position(|&c| c == name) {
+                            let value = reader.text();
+                            let value = value.trim().parse().map_err(|_| {
+                                reader.error(ParseMeasurementErrorKind::InvalidNumber)
+                            })?;
+                            components[index] = Some(value);
+                        }
+                    }
+                }
+                XmlEvent::End(name) => {
+                    if name == C::CXF_ELEMENT && in_color {
+                        in_color = false;
+                        has_color = true;
+                    } else if name == "Object" {
+                        let (id, name) = match object.take() {
+                            Some(object) => object,
+                            None => continue,
+                        };
+
+                        if !has_color {
+                            continue;
+                        }
+
+                        match components {
+                            [Some(first), Some(second), Some(third)] => set.samples.push(Sample {
+                                id,
+                                name,
+                                color: C::from_components([first, second, third]),
+                            }),
+                            _ => return Err(reader.error(ParseMeasurementErrorKind::MissingFields)),
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(set)
+    }
+
+    /// Write the samples as a basic CxF3 file, with the components rounded
+    /// to four decimals.
+    ///
+    /// The keywords are not included.
+    pub fn to_cxf(&self) -> String {
+        let mut text = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <cc:CxF xmlns:cc=\"http://colorexchangeformat.com/CxF3-core\">\n\
+             \x20 <cc:Resources>\n\
+             \x20   <cc:ObjectCollection>\n",
+        );
+
+        for sample in &self.samples {
+            let _ = write!(
+                text,
+                "      <cc:Object ObjectType=\"Standard\" Id=\"{}\"",
+                escape_xml(&sample.id)
+            );
+            if let Some(name) = &sample.name {
+                let _ = write!(text, " Name=\"{}\"", escape_xml(name));
+            }
+
+            let _ = writeln!(
+                text,
+                ">\n        <cc:ColorValues>\n          <cc:{}>",
+                C::CXF_ELEMENT
+            );
+            for (name, value) in C::CXF_COMPONENTS.iter().zip(&sample.color.to_components()) {
+                let _ = writeln!(text, "            <cc:{0}>{1:.4}</cc:{0}>", name, value);
+            }
+            let _ = writeln!(
+                text,
+                "          </cc:{}>\n        </cc:ColorValues>\n      </cc:Object>",
+                C::CXF_ELEMENT
+            );
+        }
+
+        text.push_str("    </cc:ObjectCollection>\n  </cc:Resources>\n</cc:CxF>\n");
+        text
+    }
+}
+
+/// The positions of the relevant CGATS fields.
+struct Columns {
+    count: usize,
+    id: Option<usize>,
+    name: Option<usize>,
+    components: [usize; 3],
+}
+
+impl Columns {
+    fn new<C: MeasuredColor>(fields: &[String]) -> Option<Self> {
+        let position = |name: &str| fields.iter().position(|field| field == name);
+        let [first, second, third] = C::CGATS_FIELDS;
+
+        Some(Columns {
+            count: fields.len(),
+            id: position("SAMPLE_ID"),
+            name: position("SAMPLE_NAME"),
+            components: [position(first)?, position(second)?, position(third)?],
+        })
+    }
+
+    fn read_sample<C: MeasuredColor>(
+        &self,
+        line: &str,
+        index: usize,
+    ) -> Result<Sample<C>, ParseMeasurementErrorKind> {
+        let values = tokenize(line).ok_or(ParseMeasurementErrorKind::InvalidSyntax)?;
+        if values.len() != self.count {
+            return Err(ParseMeasurementErrorKind::WrongFieldCount);
+        }
+
+        let mut components = [0.0; 3];
+        for (component, &column) in components.iter_mut().zip(&self.components) {
+            *component = values[column]
+                .parse()
+                .map_err(|_| ParseMeasurementErrorKind::InvalidNumber)?;
+        }
+
+        Ok(Sample {
+            id: match self.id {
+                Some(column) => values[column].clone(),
+                None => index.to_string(),
+            },
+            name: self.name.map(|column| values[column].clone()),
+            color: C::from_components(components),
+        })
+    }
+}
+
+/// Split a CGATS line into whitespace separated values, where quoted values
+/// may contain whitespace. Returns `None` if a quote isn't closed.
+fn tokenize(line: &str) -> Option<Vec<String>> {
+    let mut tokens = Vec::new();
+    let mut rest = line.trim_start();
+
+    while !rest.is_empty() {
+        if let Some(quoted) = rest.strip_prefix('"') {
+            let end = quoted.find('"')?;
+            tokens.push(quoted[..end].to_owned());
+            rest = quoted[end + 1..].trim_start();
+        } else {
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            tokens.push(rest[..end].to_owned());
+            rest = rest[end..].trim_start();
+        }
+    }
+
+    Some(tokens)
+}
+
+enum XmlEvent {
+    Start(String, Vec<(String, String)>),
+    End(String),
+}
+
+/// A minimal XML reader, that only reports elements and skips everything
+/// else. Namespace prefixes are removed from the names.
+struct XmlReader<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> XmlReader<'a> {
+    fn new(text: &'a str) -> Self {
+        XmlReader { text, position: 0 }
+    }
+
+    fn next_event(&mut self) -> Result<Option<XmlEvent>, ParseMeasurementError> {
+        loop {
+            let start = match self.text[self.position..].find('<') {
+                Some(offset) => self.position + offset,
+                None => return Ok(None),
+            };
+            self.position = start;
+            let rest = &self.text[start..];
+
+            if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if rest.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if rest.starts_with("<![CDATA[") {
+                self.skip_past("]]>")?;
+            } else if rest.starts_with("<!") {
+                self.skip_past(">")?;
+            } else {
+                let end = self.find_tag_end()?;
+                let tag = &self.text[start + 1..end];
+                self.position = end + 1;
+
+                if let Some(name) = tag.strip_prefix('/') {
+                    return Ok(Some(XmlEvent::End(local_name(name.trim()).to_owned())));
+                }
+
+                let (tag, self_closing) = match tag.strip_suffix('/') {
+                    Some(tag) => (tag, true),
+                    None => (tag, false),
+                };
+                let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+                let name = local_name(&tag[..name_end]).to_owned();
+                let attributes = self.attributes(&tag[name_end..])?;
+
+                if self_closing {
+                    // Self closing elements are handled like empty elements,
+                    // without the end, since empty elements have no values.
+                    continue;
+                }
+
+                return Ok(Some(XmlEvent::Start(name, attributes)));
+            }
+        }
+    }
+
+    /// Read the text until the next tag.
+    fn text(&mut self) -> String {
+        let rest = &self.text[self.position..];
+        let end = rest.find('<').unwrap_or(rest.len());
+        self.position += end;
+        unescape_xml(&rest[..end])
+    }
+
+    fn skip_past(&mut self, pattern: &str) -> Result<(), ParseMeasurementError> {
+        match self.text[self.position..].find(pattern) {
+            Some(offset) => {
+                self.position += offset + pattern.len();
+                Ok(())
+            }
+            None => Err(self.error(ParseMeasurementErrorKind::UnexpectedEnd)),
+        }
+    }
+
+    /// Find the `>` that ends the tag at the current position, skipping
+    /// quoted attribute values.
+    fn find_tag_end(&self) -> Result<usize, ParseMeasurementError> {
+        let mut quote = None;
+
+        for (offset, character) in self.text[self.position..].char_indices() {
+            match (quote, character) {
+                (None, '"') | (None, '\'') => quote = Some(character),
+                (Some(open), _) if open == character => quote = None,
+                (None, '>') => return Ok(self.position + offset),
+                _ => {}
+            }
+        }
+
+        Err(self.error(ParseMeasurementErrorKind::UnexpectedEnd))
+    }
+
+    fn attributes(&self, mut rest: &str) -> Result<Vec<(String, String)>, ParseMeasurementError> {
+        let mut attributes = Vec::new();
+
+        loop {
+            rest = rest.trim_start();
+            if rest.is_empty() {
+                return Ok(attributes);
+            }
+
+            let equals = rest
+                .find('=')
+                .ok_or_else(|| self.error(ParseMeasurementErrorKind::InvalidSyntax))?;
+            let name = local_name(rest[..equals].trim()).to_owned();
+            rest = rest[equals + 1..].trim_start();
+
+            let quote = rest
+                .chars()
+                .next()
+                .filter(|&quote| quote == '"' || quote == '\'')
+                .ok_or_else(|| self.error(ParseMeasurementErrorKind::InvalidSyntax))?;
+            rest = &rest[1..];
+            let end = rest
+                .find(quote)
+                .ok_or_else(|| self.error(ParseMeasurementErrorKind::InvalidSyntax))?;
+
+            attributes.push((name, unescape_xml(&rest[..end])));
+            rest = &rest[end + 1..];
+        }
+    }
+
+    fn error(&self, kind: ParseMeasurementErrorKind) -> ParseMeasurementError {
+        let line = self.text[..self.position].matches('\n').count() + 1;
+        ParseMeasurementError::new(kind, line)
+    }
+}
+
+fn local_name(name: &str) -> &str {
+    match name.find(':') {
+        Some(colon) => &name[colon + 1..],
+        None => name,
+    }
+}
+
+fn attribute(attributes: &[(String, String)], name: &str) -> Option<String> {
+    attributes
+        .iter()
+        .find(|(attribute, _)| attribute == name)
+        .map(|(_, value)| value.clone())
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn unescape_xml(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// The reason why a measurement file couldn't be read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParseMeasurementErrorKind {
+    /// A line or a tag is malformed, such as an unclosed quote.
+    InvalidSyntax,
+    /// The fields or elements for the color components are missing.
+    MissingFields,
+    /// A number couldn't be parsed.
+    InvalidNumber,
+    /// A row of the CGATS data has the wrong number of values.
+    WrongFieldCount,
+    /// The number of CGATS samples doesn't match `NUMBER_OF_SETS`.
+    WrongSampleCount,
+    /// The file ended too early.
+    UnexpectedEnd,
+}
+
+/// An error from reading a measurement file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParseMeasurementError {
+    kind: ParseMeasurementErrorKind,
+    line: usize,
+}
+
+impl ParseMeasurementError {
+    fn new(kind: ParseMeasurementErrorKind, line: usize) -> Self {
+        ParseMeasurementError { kind, line }
+    }
+
+    /// The reason for the error.
+    pub fn kind(&self) -> ParseMeasurementErrorKind {
+        self.kind
+    }
+
+    /// The line in the file where the error was found, starting from `1`.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+impl fmt::Display for ParseMeasurementError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self.kind {
+            ParseMeasurementErrorKind::InvalidSyntax => "invalid syntax",
+            ParseMeasurementErrorKind::MissingFields => "missing color fields",
+            ParseMeasurementErrorKind::InvalidNumber => "invalid number",
+            ParseMeasurementErrorKind::WrongFieldCount => "wrong number of values",
+            ParseMeasurementErrorKind::WrongSampleCount => "wrong number of samples",
+            ParseMeasurementErrorKind::UnexpectedEnd => "unexpected end of file",
+        };
+
+        write!(f, "{} at line {}", description, self.line)
+    }
+}
+
+impl std::error::Error for ParseMeasurementError {}
+
+#[cfg(test)]
+mod test {
+    use super::{ParseMeasurementErrorKind, Sample, SampleSet};
+    use crate::white_point::D50;
+    use crate::{Lab, Xyz};
+
+    type LabSet = SampleSet<Lab<D50, f64>>;
+
+    const CGATS: &str = "IT8.7/2
+# Measured with a handheld device
+ORIGINATOR \"Proof lab\"
+MEASUREMENT_SOURCE \"Illumination=D50 ObserverAngle=2\"
+NUMBER_OF_FIELDS 7
+BEGIN_DATA_FORMAT
+SAMPLE_ID XYZ_X XYZ_Y XYZ_Z
+LAB_L LAB_A LAB_B
+END_DATA_FORMAT
+NUMBER_OF_SETS 2
+BEGIN_DATA
+A1 41.24 21.26 1.93 53.24 80.09 67.20
+A2 95.05 100.00 108.90 100.00 0.00 0.00
+END_DATA
+";
+
+    #[test]
+    fn read_cgats() {
+        let set = LabSet::from_cgats(CGATS).unwrap();
+
+        assert_eq!(set.keywords.len(), 2);
+        assert_eq!(
+            set.keyword("MEASUREMENT_SOURCE"),
+            Some("Illumination=D50 ObserverAngle=2")
+        );
+        assert_eq!(set.samples.len(), 2);
+        assert_eq!(set.samples[0].id, "A1");
+        assert_eq!(set.samples[0].name, None);
+        assert_eq!(set.samples[0].color, Lab::new(53.24, 80.09, 67.20));
+
+        let xyz = SampleSet::<Xyz<D50, f64>>::from_cgats(CGATS).unwrap();
+        assert_relative_eq!(xyz.samples[1].color, Xyz::new(0.9505, 1.0, 1.089));
+    }
+
+    #[test]
+    fn cgats_round_trip() {
+        let mut set = LabSet::new();
+        set.keywords.push(("ORIGINATOR".into(), "palette".into()));
+        set.samples.push(Sample {
+            id: "1".into(),
+            name: Some("Dark \"blue\"".into()),
+            color: Lab::new(20.0, 10.0, -40.0),
+        });
+        set.samples.push(Sample {
+            id: "second sample".into(),
+            name: None,
+            color: Lab::new(90.5, -1.25, 3.0),
+        });
+
+        let text = set.to_cgats();
+        assert!(text.contains("SAMPLE_ID SAMPLE_NAME LAB_L LAB_A LAB_B"));
+
+        let read = LabSet::from_cgats(&text).unwrap();
+        assert_eq!(read.keyword("ORIGINATOR"), Some("palette"));
+        assert_eq!(read.samples[0].name.as_deref(), Some("Dark 'blue'"));
+        assert_eq!(read.samples[1].id, "second sample");
+        assert_eq!(read.samples[1].name.as_deref(), Some(""));
+        assert_eq!(read.samples[1].color, set.samples[1].color);
+    }
+
+    #[test]
+    fn cgats_errors() {
+        let error =
+            SampleSet::<Lab<D50, f64>>::from_cgats(&CGATS.replace("LAB_B", "LAB_X")).unwrap_err();
+        assert_eq!(error.kind(), ParseMeasurementErrorKind::MissingFields);
+        assert_eq!(error.line(), 11);
+
+        let error = LabSet::from_cgats(&CGATS.replace("67.20", "x")).unwrap_err();
+        assert_eq!(error.kind(), ParseMeasurementErrorKind::InvalidNumber);
+        assert_eq!(error.to_string(), "invalid number at line 12");
+
+        let error = LabSet::from_cgats(&CGATS.replace(" 1.93", "")).unwrap_err();
+        assert_eq!(error.kind(), ParseMeasurementErrorKind::WrongFieldCount);
+
+        let error = LabSet::from_cgats(&CGATS.replace("SETS 2", "SETS 3")).unwrap_err();
+        assert_eq!(error.kind(), ParseMeasurementErrorKind::WrongSampleCount);
+
+        let error = LabSet::from_cgats(&CGATS.replace("END_DATA\n", "")).unwrap_err();
+        assert_eq!(error.kind(), ParseMeasurementErrorKind::UnexpectedEnd);
+
+        let error = LabSet::from_cgats("CGATS.17\nORIGINATOR \"open").unwrap_err();
+        assert_eq!(error.kind(), ParseMeasurementErrorKind::InvalidSyntax);
+    }
+
+    #[test]
+    fn read_cxf() {
+        let text = r#"<?xml version="1.0" encoding="UTF-8"?>
+<cc:CxF xmlns:cc="http://colorexchangeformat.com/CxF3-core">
+  <!-- An <Object> in a comment is skipped -->
+  <cc:Resources>
+    <cc:ObjectCollection>
+      <cc:Object ObjectType="Standard" Name="Red &amp; warm" Id="r1">
+        <cc:CreationDate>2020-01-01</cc:CreationDate>
+        <cc:ColorValues>
+          <cc:ColorCIELab ColorSpecification="CS_D50_2">
+            <cc:L>53.24</cc:L>
+            <cc:A>80.09</cc:A>
+            <cc:B>67.2</cc:B>
+          </cc:ColorCIELab>
+        </cc:ColorValues>
+      </cc:Object>
+      <cc:Object ObjectType="Standard" Name="Spectral only" Id="s1">
+        <cc:ColorValues>
+          <cc:ReflectanceSpectrum StartWL="380"/>
+        </cc:ColorValues>
+      </cc:Object>
+    </cc:ObjectCollection>
+  </cc:Resources>
+</cc:CxF>
+"#;
+
+        let set = LabSet::from_cxf(text).unwrap();
+        assert_eq!(set.samples.len(), 1);
+        assert_eq!(set.samples[0].id, "r1");
+        assert_eq!(set.samples[0].name.as_deref(), Some("Red & warm"));
+        assert_eq!(set.samples[0].color, Lab::new(53.24, 80.09, 67.2));
+
+        let error = LabSet::from_cxf(&text.replace("<cc:B>67.2</cc:B>", "")).unwrap_err();
+        assert_eq!(error.kind(), ParseMeasurementErrorKind::MissingFields);
+
+        let error = LabSet::from_cxf(&text.replace("80.09", "eighty")).unwrap_err();
+        assert_eq!(error.kind(), ParseMeasurementErrorKind::InvalidNumber);
+        assert_eq!(error.line(), 11);
+    }
+
+    #[test]
+    fn cxf_round_trip() {
+        let mut set = SampleSet::<Xyz<D50, f32>>::new();
+        set.samples.push(Sample {
+            id: "1".into(),
+            name: Some("<white>".into()),
+            color: Xyz::new(0.9642, 1.0, 0.8252),
+        });
+
+        let text = set.to_cxf();
+        assert!(text.contains("<cc:ColorCIEXYZ>"));
+        assert!(text.contains("<cc:X>96.4200</cc:X>"));
+
+        let read = SampleSet::<Xyz<D50, f32>>::from_cxf(&text).unwrap();
+        assert_eq!(read.samples[0].name.as_deref(), Some("<white>"));
+        assert_relative_eq!(read.samples[0].color, set.samples[0].color, epsilon = 1e-6);
+    }
+}
@@ -0,0 +1,267 @@
+//! Convert colors to a tinted monochrome look.
+//!
+//! A tinted monochrome image, such as a sepia or cyanotype look, replaces
+//! the color of each pixel with a tint color, but keeps its brightness. The
+//! brightness is the relative luminance, computed from linear RGB, so the
+//! exposure of the image stays the same no matter which tint is chosen. A
+//! gray value computed from the encoded components, or from a lightness
+//! that is mixed with the tint afterwards, would make saturated tints look
+//! brighter or darker than the original.
+//!
+//! ```
+//! use approx::assert_relative_eq;
+//! use palette::monochrome::TintedMonochrome;
+//! use palette::{FromColor, Srgb, Xyz};
+//!
+//! // A warm sepia look, at 80% strength.
+//! let sepia = TintedMonochrome::new(Srgb::new(0.44f32, 0.26, 0.08)).with_strength(0.8);
+//!
+//! let color = Srgb::new(0.2f32, 0.6, 0.9);
+//! let toned = sepia.apply(color);
+//!
+//! assert!(toned.red > toned.blue);
+//! let luminance = |color: Srgb| Xyz::from_color(color).y;
+//! assert_relative_eq!(luminance(toned), luminance(color), epsilon = 1e-4);
+//! ```
+//!
+//! The tint and strength are prepared when the [`TintedMonochrome`] is
+//! created, and [`apply_slice`](TintedMonochrome::apply_slice) converts a
+//! whole image in place.
+
+use core::marker::PhantomData;
+
+use crate::encoding::{Linear, TransferFn};
+use crate::matrix::rgb_to_xyz_matrix;
+use crate::rgb::{Rgb, RgbStandard};
+use crate::{cast, clamp, FloatComponent};
+
+/// A tinted monochrome filter for RGB colors with the standard `S`.
+///
+/// The tint is applied in linear RGB, where each color is replaced with a
+/// mix of gray and the tint color, both with the same relative luminance as
+/// the original color. The mix is limited where the tinted color would be
+/// brighter than white in any channel, so bright colors become less tinted
+/// instead of being clipped and losing luminance.
+#[derive(Clone, Copy, Debug)]
+pub struct TintedMonochrome<S = crate::encoding::Srgb, T = f32> {
+    // The linear tint color divided by its luminance, minus one, so it's
+    // the direction from gray towards the tint.
+    tint: [T; 3],
+    strength: T,
+    weights: [T; 3],
+    standard: PhantomData<S>,
+}
+
+impl<S, T> TintedMonochrome<S, T>
+where
+    S: RgbStandard<T>,
+    T: FloatComponent,
+{
+    /// Create a filter that tints with the hue and saturation of `tint`, at
+    /// full strength.
+    ///
+    /// The lightness of `tint` doesn't matter, since the luminance of each
+    /// color is kept. A black or gray tint gives a neutral monochrome image.
+    pub fn new(tint: Rgb<S, T>) -> Self {
+        let [_, _, _, w0, w1, w2, _, _, _] = rgb_to_xyz_matrix::<S::Space, T>();
+        let weights = [w0, w1, w2];
+
+        let tint = tint.into_linear();
+        let tint = [
+            tint.red.max(T::zero()),
+            tint.green.max(T::zero()),
+            tint.blue.max(T::zero()),
+        ];
+        let luminance = dot(weights, tint);
+
+        let tint = if luminance > T::zero() {
+            [
+                tint[0] / luminance - T::one(),
+                tint[1] / luminance - T::one(),
+                tint[2] / luminance - T::one(),
+            ]
+        } else {
+            [T::zero(); 3]
+        };
+
+        TintedMonochrome {
+            tint,
+            strength: T::one(),
+            weights,
+            standard: PhantomData,
+        }
+    }
+
+    /// Create a filter that converts colors to neutral gray.
+    pub fn gray() -> Self {
+        Self::new(Rgb::new(T::zero(), T::zero(), T::zero()))
+    }
+
+    /// Change how much of the tint is applied, from `0.0` for neutral gray
+    /// to `1.0` for the full tint. The strength is clamped to that range.
+    pub fn with_strength(mut self, strength: T) -> Self {
+        self.strength = clamp(strength, T::zero(), T::one());
+        self
+    }
+
+    /// Get the strength of the tint.
+    pub fn strength(&self) -> T {
+        self.strength
+    }
+
+    /// Apply the filter to `color`.
+    pub fn apply(&self, color: Rgb<S, T>) -> Rgb<S, T> {
+        Rgb::from_linear(self.apply_linear(color.into_linear()))
+    }
+
+    /// Apply the filter to a linear RGB color.
+    pub fn apply_linear(&self, color: Rgb<Linear<S::Space>, T>) -> Rgb<Linear<S::Space>, T> {
+        let [red, green, blue] = self.tone([color.red, color.green, color.blue]);
+        Rgb::new(red, green, blue)
+    }
+
+    /// Apply the filter to all colors in `colors`, in place.
+    pub fn apply_slice(&self, colors: &mut [Rgb<S, T>]) {
+        for color in cast::into_array_slice_mut(colors) {
+            let [red, green, blue] = *color;
+            let linear = self.tone([
+                S::TransferFn::into_linear(red),
+                S::TransferFn::into_linear(green),
+                S::TransferFn::into_linear(blue),
+            ]);
+
+            *color = [
+                S::TransferFn::from_linear(linear[0]),
+                S::TransferFn::from_linear(linear[1]),
+                S::TransferFn::from_linear(linear[2]),
+            ];
+        }
+    }
+
+    /// Apply the filter to all linear RGB colors in `colors`, in place.
+    pub fn apply_linear_slice(&self, colors: &mut [Rgb<Linear<S::Space>, T>]) {
+        for color in cast::into_array_slice_mut(colors) {
+            *color = self.tone(*color);
+        }
+    }
+
+    #[inline]
+    fn tone(&self, color: [T; 3]) -> [T; 3] {
+        let luminance = dot(self.weights, color);
+
+        // Limit the strength where a channel would go above white. Gray is
+        // within bounds when the luminance is, so there is a limit that keeps
+        // the luminance. Colors that are brighter than white become gray.
+        let mut strength = self.strength;
+        if luminance > T::zero() {
+            let headroom = (T::one() / luminance - T::one()).max(T::zero());
+            for &direction in &self.tint {
+                if direction * strength > headroom {
+                    strength = headroom / direction;
+                }
+            }
+        }
+
+        let scale = luminance * strength;
+        [
+            luminance + scale * self.tint[0],
+            luminance + scale * self.tint[1],
+            luminance + scale * self.tint[2],
+        ]
+    }
+}
+
+#[inline]
+fn dot<T: FloatComponent>(a: [T; 3], b: [T; 3]) -> T {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[cfg(test)]
+mod test {
+    use super::TintedMonochrome;
+    use crate::convert::FromColorUnclamped;
+    use crate::encoding::Srgb as SrgbStandard;
+    use crate::white_point::D65;
+    use crate::{IsWithinBounds, LinSrgb, Srgb, Xyz};
+
+    fn luminance(color: Srgb<f64>) -> f64 {
+        Xyz::<D65, f64>::from_color_unclamped(color).y
+    }
+
+    #[test]
+    fn keeps_luminance() {
+        let filter = TintedMonochrome::new(Srgb::new(0.2f64, 0.4, 0.9));
+        let colors = [
+            Srgb::new(0.0f64, 0.0, 0.0),
+            Srgb::new(0.2, 0.6, 0.9),
+            Srgb::new(0.9, 0.1, 0.1),
+            Srgb::new(0.95, 0.95, 0.9),
+            Srgb::new(1.0, 1.0, 1.0),
+        ];
+
+        for &color in &colors {
+            let toned = filter.apply(color);
+            assert!(toned.is_within_bounds(), "{:?}", toned);
+            assert_relative_eq!(luminance(toned), luminance(color), epsilon = 1e-6);
+        }
+
+        // Bright colors are less tinted, instead of clipped.
+        let white = filter.apply(Srgb::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(white, Srgb::new(1.0, 1.0, 1.0), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn strength() {
+        let tint = Srgb::new(0.44f64, 0.26, 0.08);
+        let color = Srgb::new(0.3, 0.5, 0.7);
+
+        let gray = TintedMonochrome::new(tint).with_strength(0.0).apply(color);
+        assert_relative_eq!(gray.red, gray.green, epsilon = 1e-9);
+        assert_relative_eq!(gray.green, gray.blue, epsilon = 1e-9);
+        assert_relative_eq!(
+            gray,
+            TintedMonochrome::<SrgbStandard, f64>::gray().apply(color),
+            epsilon = 1e-9
+        );
+
+        let half = TintedMonochrome::new(tint).with_strength(0.5).apply(color);
+        let full = TintedMonochrome::new(tint).apply(color);
+        assert!(full.red - full.blue > half.red - half.blue);
+        assert!(half.red - half.blue > 0.0);
+
+        // The tint keeps its own chromaticity at full strength.
+        let tint_linear = tint.into_linear();
+        let full_linear = full.into_linear();
+        assert_relative_eq!(
+            full_linear.red / full_linear.blue,
+            tint_linear.red / tint_linear.blue,
+            epsilon = 1e-5
+        );
+    }
+
+    #[test]
+    fn slices() {
+        let filter = TintedMonochrome::new(Srgb::new(0.1f64, 0.5, 0.4)).with_strength(0.7);
+        let colors = [
+            Srgb::new(0.2f64, 0.6, 0.9),
+            Srgb::new(0.9, 0.1, 0.1),
+            Srgb::new(0.5, 0.5, 0.5),
+        ];
+
+        let mut encoded = colors;
+        filter.apply_slice(&mut encoded);
+
+        let mut linear: Vec<LinSrgb<f64>> =
+            colors.iter().map(|color| color.into_linear()).collect();
+        filter.apply_linear_slice(&mut linear);
+
+        for ((&color, &encoded), &linear) in colors.iter().zip(&encoded).zip(&linear) {
+            assert_relative_eq!(encoded, filter.apply(color), epsilon = 1e-12);
+            assert_relative_eq!(
+                linear,
+                filter.apply_linear(color.into_linear()),
+                epsilon = 1e-12
+            );
+        }
+    }
+}
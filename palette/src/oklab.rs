@@ -10,6 +10,7 @@ use rand::distributions::{Distribution, Standard};
 use rand::Rng;
 
 use crate::convert::FromColorUnclamped;
+use crate::float;
 use crate::matrix::multiply_xyz;
 use crate::white_point::D65;
 use crate::{
@@ -69,7 +70,7 @@ pub type Oklaba<T = f32> = Alpha<Oklab<T>, T>;
     palette_internal,
     white_point = "D65",
     component = "T",
-    skip_derives(Oklab, Oklch, Xyz)
+    skip_derives(Oklab, Oklch, Oklrab, Xyz)
 )]
 #[repr(C)]
 pub struct Oklab<T = f32> {
@@ -233,15 +234,18 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(color: Xyz<D65, T>) -> Self {
+        trace!(conversion color);
         let m1 = m1();
         let m2 = m2();
 
+        trace!(matrix "oklab_m1");
         let Xyz {
             x: l, y: m, z: s, ..
         } = multiply_xyz(&m1, &color.with_white_point());
 
-        let l_m_s_ = Xyz::new(l.cbrt(), m.cbrt(), s.cbrt());
+        let l_m_s_ = Xyz::new(float::cbrt(l), float::cbrt(m), float::cbrt(s));
 
+        trace!(matrix "oklab_m2");
         let Xyz {
             x: l, y: a, z: b, ..
         } = multiply_xyz(&m2, &l_m_s_);
@@ -255,10 +259,11 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(color: Oklch<T>) -> Self {
+        trace!(conversion color);
         Oklab {
             l: color.l,
-            a: color.chroma.max(T::zero()) * color.hue.to_radians().cos(),
-            b: color.chroma.max(T::zero()) * color.hue.to_radians().sin(),
+            a: color.chroma.max(T::zero()) * float::cos(color.hue.to_radians()),
+            b: color.chroma.max(T::zero()) * float::sin(color.hue.to_radians()),
         }
     }
 }
@@ -287,6 +292,9 @@ impl<T, A: Component> From<Alpha<Oklab<T>, A>> for (T, T, T, A) {
     }
 }
 
+impl_tuple_ref_conversions!([T] [] Oklab<T>, (T, T, T) => (T, T, T));
+impl_tuple_ref_conversions!([T, A] [] Alpha<Oklab<T>, A>, (T, T, T, A) => (T, T, T, A));
+
 impl<T> IsWithinBounds for Oklab<T>
 where
     T: FromF64 + PartialOrd,
@@ -416,7 +424,7 @@ where
         if self.a == T::zero() && self.b == T::zero() {
             None
         } else {
-            Some(OklabHue::from_radians(self.b.atan2(self.a)))
+            Some(OklabHue::from_radians(float::atan2(self.b, self.a)))
         }
     }
 }
@@ -451,6 +459,7 @@ impl_color_mul!(Oklab<T>, [l, a, b]);
 impl_color_div!(Oklab<T>, [l, a, b]);
 
 impl_array_casts!(Oklab<T>, [T; 3]);
+impl_component_access!(Oklab<T>, [T; 3]);
 
 impl<T> RelativeContrast for Oklab<T>
 where
@@ -225,6 +225,7 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(color: Xyz<D65, T>) -> Self {
+        trace!(conversion color);
         let lab: Oklab<T> = color.into_color_unclamped();
         Self::from_color_unclamped(lab)
     }
@@ -235,6 +236,7 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(color: Oklab<T>) -> Self {
+        trace!(conversion color);
         Oklch {
             l: color.l,
             chroma: (color.a * color.a + color.b * color.b).sqrt(),
@@ -267,6 +269,9 @@ impl<T, A> From<Alpha<Oklch<T>, A>> for (T, T, OklabHue<T>, A) {
     }
 }
 
+impl_tuple_ref_conversions!([T] [H] Oklch<T>, (T, T, H) => (T, T, OklabHue<T>));
+impl_tuple_ref_conversions!([T, A] [H] Alpha<Oklch<T>, A>, (T, T, H, A) => (T, T, OklabHue<T>, A));
+
 impl<T> IsWithinBounds for Oklch<T>
 where
     T: Zero + FromF64 + PartialOrd,
@@ -0,0 +1,652 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use num_traits::Zero;
+
+#[cfg(feature = "random")]
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, Uniform, UniformSampler};
+#[cfg(feature = "random")]
+use rand::distributions::{Distribution, Standard};
+#[cfg(feature = "random")]
+use rand::Rng;
+
+use crate::convert::FromColorUnclamped;
+use crate::float;
+use crate::white_point::D65;
+use crate::{
+    clamp, clamp_assign, clamp_min_assign, contrast_ratio, from_f64, Alpha, Clamp, ClampAssign,
+    Component, ComponentWise, FloatComponent, FromF64, GetHue, IsWithinBounds, Lighten,
+    LightenAssign, Mix, MixAssign, Oklab, OklabHue, Oklrch, RelativeContrast, Xyz,
+};
+
+// The constants for the toe function, from the Okhsl reference
+// implementation.
+const K1: f64 = 0.206;
+const K2: f64 = 0.03;
+const K3: f64 = (1.0 + K1) / (1.0 + K2);
+
+/// Convert Oklab's `L` to the `Lr` lightness estimate.
+pub(crate) fn toe<T: FloatComponent>(l: T) -> T {
+    let k1: T = from_f64(K1);
+    let k2: T = from_f64(K2);
+    let k3: T = from_f64(K3);
+
+    let x = k3 * l - k1;
+    from_f64::<T>(0.5) * (x + (x * x + from_f64::<T>(4.0) * k2 * k3 * l).sqrt())
+}
+
+/// Convert the `Lr` lightness estimate to Oklab's `L`.
+pub(crate) fn toe_inv<T: FloatComponent>(lr: T) -> T {
+    let k1: T = from_f64(K1);
+    let k2: T = from_f64(K2);
+    let k3: T = from_f64(K3);
+
+    (lr * lr + k1 * lr) / (k3 * (lr + k2))
+}
+
+/// Oklrab with an alpha component. See the [`Oklraba` implementation in
+/// `Alpha`](crate::Alpha#Oklraba).
+pub type Oklraba<T = f32> = Alpha<Oklrab<T>, T>;
+
+/// [Oklab](crate::Oklab) with the `Lr` lightness estimate.
+///
+/// The lightness of Oklab doesn't match CIE L\*a\*b\* very well for dark
+/// colors. Oklrab replaces it with the `Lr` estimate, from [Björn Ottosson's
+/// Okhsv and Okhsl post](https://bottosson.github.io/posts/colorpicker/#intermission---a-new-lightness-estimate-for-oklab),
+/// which is closer to L\* near black, while `a` and `b` are the same as in
+/// Oklab. It assumes a D65 whitepoint and normal well-lit viewing
+/// conditions, like Oklab.
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::{FromColor, Lab, Oklab, Oklrab, Srgb};
+///
+/// let dark_gray = Srgb::new(0.1f64, 0.1, 0.1);
+/// let lab = Lab::from_color(dark_gray);
+/// let oklab = Oklab::from_color(dark_gray);
+/// let oklrab = Oklrab::from_color(dark_gray);
+///
+/// // Lr is much closer to L* than Oklab's L.
+/// assert!((oklrab.l * 100.0 - lab.l).abs() < 3.0);
+/// assert!((oklab.l * 100.0 - lab.l).abs() > 10.0);
+/// assert_relative_eq!(Oklab::from_color(oklrab), oklab, epsilon = 1e-12);
+/// ```
+#[derive(Debug, PartialEq, ArrayCast, FromColorUnclamped, WithAlpha)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[palette(
+    palette_internal,
+    white_point = "D65",
+    component = "T",
+    skip_derives(Oklab, Oklrab, Oklrch)
+)]
+#[repr(C)]
+pub struct Oklrab<T = f32> {
+    /// L is the lightness of the color. 0 gives absolute black and 1 gives the brightest white.
+    pub l: T,
+
+    /// a goes from red at -1 to green at 1.
+    pub a: T,
+
+    /// b goes from yellow at -1 to blue at 1.
+    pub b: T,
+}
+
+impl<T> Copy for Oklrab<T> where T: Copy {}
+
+impl<T> Clone for Oklrab<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Oklrab<T> {
+        Oklrab {
+            l: self.l.clone(),
+            a: self.a.clone(),
+            b: self.b.clone(),
+        }
+    }
+}
+
+impl<T> AbsDiffEq for Oklrab<T>
+where
+    T: AbsDiffEq,
+    T::Epsilon: Clone,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        self.l.abs_diff_eq(&other.l, epsilon.clone())
+            && self.a.abs_diff_eq(&other.a, epsilon.clone())
+            && self.b.abs_diff_eq(&other.b, epsilon)
+    }
+}
+
+impl<T> RelativeEq for Oklrab<T>
+where
+    T: RelativeEq,
+    T::Epsilon: Clone,
+{
+    fn default_max_relative() -> T::Epsilon {
+        T::default_max_relative()
+    }
+
+    #[rustfmt::skip]
+    fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.l.relative_eq(&other.l, epsilon.clone(), max_relative.clone())
+            && self.a.relative_eq(&other.a, epsilon.clone(), max_relative.clone())
+            && self.b.relative_eq(&other.b, epsilon, max_relative)
+    }
+}
+
+impl<T> UlpsEq for Oklrab<T>
+where
+    T: UlpsEq,
+    T::Epsilon: Clone,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.l.ulps_eq(&other.l, epsilon.clone(), max_ulps)
+            && self.a.ulps_eq(&other.a, epsilon.clone(), max_ulps)
+            && self.b.ulps_eq(&other.b, epsilon, max_ulps)
+    }
+}
+
+impl<T> Oklrab<T> {
+    /// Create an Oklrab color.
+    pub const fn new(l: T, a: T, b: T) -> Self {
+        Self { l, a, b }
+    }
+
+    /// Convert to a `(L, a, b)` tuple.
+    pub fn into_components(self) -> (T, T, T) {
+        (self.l, self.a, self.b)
+    }
+
+    /// Convert from a `(L, a, b)` tuple.
+    pub fn from_components((l, a, b): (T, T, T)) -> Self {
+        Self::new(l, a, b)
+    }
+}
+
+impl<T> Oklrab<T>
+where
+    T: FromF64,
+{
+    /// Return the `l` value minimum.
+    pub fn min_l() -> T {
+        from_f64(0.0)
+    }
+
+    /// Return the `l` value maximum.
+    pub fn max_l() -> T {
+        from_f64(1.0)
+    }
+
+    /// Return the `a` value minimum.
+    pub fn min_a() -> T {
+        from_f64(-1.0)
+    }
+
+    /// Return the `a` value maximum.
+    pub fn max_a() -> T {
+        from_f64(1.0)
+    }
+
+    /// Return the `b` value minimum.
+    pub fn min_b() -> T {
+        from_f64(-1.0)
+    }
+
+    /// Return the `b` value maximum.
+    pub fn max_b() -> T {
+        from_f64(1.0)
+    }
+}
+
+///<span id="Oklraba"></span>[`Oklraba`](crate::Oklraba) implementations.
+impl<T, A> Alpha<Oklrab<T>, A> {
+    /// Create an Oklrab color with transparency.
+    pub const fn new(l: T, a: T, b: T, alpha: A) -> Self {
+        Alpha {
+            color: Oklrab::new(l, a, b),
+            alpha,
+        }
+    }
+
+    /// Convert to a `(L, a, b, alpha)` tuple.
+    pub fn into_components(self) -> (T, T, T, A) {
+        (self.color.l, self.color.a, self.color.b, self.alpha)
+    }
+
+    /// Convert from a `(L, a, b, alpha)` tuple.
+    pub fn from_components((l, a, b, alpha): (T, T, T, A)) -> Self {
+        Self::new(l, a, b, alpha)
+    }
+}
+
+impl<T> FromColorUnclamped<Oklrab<T>> for Oklrab<T> {
+    fn from_color_unclamped(color: Self) -> Self {
+        color
+    }
+}
+
+impl<T> FromColorUnclamped<Oklab<T>> for Oklrab<T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Oklab<T>) -> Self {
+        trace!(conversion color);
+        Self::new(toe(color.l), color.a, color.b)
+    }
+}
+
+impl<T> FromColorUnclamped<Oklrab<T>> for Oklab<T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Oklrab<T>) -> Self {
+        trace!(conversion color);
+        Self::new(toe_inv(color.l), color.a, color.b)
+    }
+}
+
+impl<T> FromColorUnclamped<Oklrch<T>> for Oklrab<T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Oklrch<T>) -> Self {
+        trace!(conversion color);
+        Oklrab {
+            l: color.l,
+            a: color.chroma.max(T::zero()) * float::cos(color.hue.to_radians()),
+            b: color.chroma.max(T::zero()) * float::sin(color.hue.to_radians()),
+        }
+    }
+}
+
+impl<T> From<(T, T, T)> for Oklrab<T> {
+    fn from(components: (T, T, T)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T> From<Oklrab<T>> for (T, T, T) {
+    fn from(color: Oklrab<T>) -> (T, T, T) {
+        color.into_components()
+    }
+}
+
+impl<T, A: Component> From<(T, T, T, A)> for Alpha<Oklrab<T>, A> {
+    fn from(components: (T, T, T, A)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T, A: Component> From<Alpha<Oklrab<T>, A>> for (T, T, T, A) {
+    fn from(color: Alpha<Oklrab<T>, A>) -> (T, T, T, A) {
+        color.into_components()
+    }
+}
+
+impl_tuple_ref_conversions!([T] [] Oklrab<T>, (T, T, T) => (T, T, T));
+impl_tuple_ref_conversions!([T, A] [] Alpha<Oklrab<T>, A>, (T, T, T, A) => (T, T, T, A));
+
+impl<T> IsWithinBounds for Oklrab<T>
+where
+    T: FromF64 + PartialOrd,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn is_within_bounds(&self) -> bool {
+        self.l >= Self::min_l() && self.l <= Self::max_l() &&
+        self.a >= Self::min_a() && self.a <= Self::max_a() &&
+        self.b >= Self::min_b() && self.b <= Self::max_b()
+    }
+}
+
+impl<T> Clamp for Oklrab<T>
+where
+    T: FromF64 + PartialOrd,
+{
+    #[inline]
+    fn clamp(self) -> Self {
+        Self::new(
+            clamp(self.l, Self::min_l(), Self::max_l()),
+            clamp(self.a, Self::min_a(), Self::max_a()),
+            clamp(self.b, Self::min_b(), Self::max_b()),
+        )
+    }
+}
+
+impl<T> ClampAssign for Oklrab<T>
+where
+    T: FromF64 + PartialOrd,
+{
+    #[inline]
+    fn clamp_assign(&mut self) {
+        clamp_assign(&mut self.l, Self::min_l(), Self::max_l());
+        clamp_assign(&mut self.a, Self::min_a(), Self::max_a());
+        clamp_assign(&mut self.b, Self::min_b(), Self::max_b());
+    }
+}
+
+impl<T> Mix for Oklrab<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn mix(self, other: Self, factor: T) -> Self {
+        let factor = clamp(factor, T::zero(), T::one());
+        self + (other - self) * factor
+    }
+}
+
+impl<T> MixAssign for Oklrab<T>
+where
+    T: FloatComponent + AddAssign,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn mix_assign(&mut self, other: Self, factor: T) {
+        let factor = clamp(factor, T::zero(), T::one());
+        *self += (other - *self) * factor;
+    }
+}
+
+impl<T> Lighten for Oklrab<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn lighten(self, factor: T) -> Self {
+        let difference = if factor >= T::zero() {
+            Self::max_l() - self.l
+        } else {
+            self.l
+        };
+
+        let delta = difference.max(T::zero()) * factor;
+
+        Self::new((self.l + delta).max(Self::min_l()), self.a, self.b)
+    }
+
+    #[inline]
+    fn lighten_fixed(self, amount: T) -> Self {
+        Self::new(
+            (self.l + Self::max_l() * amount).max(Self::min_l()),
+            self.a,
+            self.b,
+        )
+    }
+}
+
+impl<T> LightenAssign for Oklrab<T>
+where
+    T: FloatComponent + AddAssign,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn lighten_assign(&mut self, factor: T) {
+        let difference = if factor >= T::zero() {
+            Self::max_l() - self.l
+        } else {
+            self.l
+        };
+
+        self.l += difference.max(T::zero()) * factor;
+        clamp_min_assign(&mut self.l, Self::min_l());
+    }
+
+    #[inline]
+    fn lighten_fixed_assign(&mut self, amount: T) {
+        self.l += Self::max_l() * amount;
+        clamp_min_assign(&mut self.l, Self::min_l());
+    }
+}
+
+impl<T> GetHue for Oklrab<T>
+where
+    T: FloatComponent,
+{
+    type Hue = OklabHue<T>;
+
+    fn get_hue(&self) -> Option<OklabHue<T>> {
+        if self.a == T::zero() && self.b == T::zero() {
+            None
+        } else {
+            Some(OklabHue::from_radians(float::atan2(self.b, self.a)))
+        }
+    }
+}
+
+impl<T> ComponentWise for Oklrab<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    fn component_wise<F: FnMut(T, T) -> T>(&self, other: &Self, mut f: F) -> Self {
+        Self::new(f(self.l, other.l), f(self.a, other.a), f(self.b, other.b))
+    }
+
+    fn component_wise_self<F: FnMut(T) -> T>(&self, mut f: F) -> Self {
+        Self::new(f(self.l), f(self.a), f(self.b))
+    }
+}
+
+impl<T> Default for Oklrab<T>
+where
+    T: Zero,
+{
+    fn default() -> Self {
+        Self::new(T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl_color_add!(Oklrab<T>, [l, a, b]);
+impl_color_sub!(Oklrab<T>, [l, a, b]);
+impl_color_mul!(Oklrab<T>, [l, a, b]);
+impl_color_div!(Oklrab<T>, [l, a, b]);
+
+impl_array_casts!(Oklrab<T>, [T; 3]);
+impl_component_access!(Oklrab<T>, [T; 3]);
+
+impl<T> RelativeContrast for Oklrab<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn get_contrast_ratio(self, other: Self) -> T {
+        use crate::FromColor;
+
+        let xyz1 = Xyz::from_color(self);
+        let xyz2 = Xyz::from_color(other);
+
+        contrast_ratio(xyz1.y, xyz2.y)
+    }
+}
+
+#[cfg(feature = "random")]
+impl<T> Distribution<Oklrab<T>> for Standard
+where
+    T: FloatComponent,
+    Standard: Distribution<T>,
+{
+    // `a` and `b` both range from (-1.0, 1.0)
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Oklrab<T>
+where {
+        Oklrab::new(
+            rng.gen(),
+            rng.gen() * from_f64(2.0) - from_f64(1.0),
+            rng.gen() * from_f64(2.0) - from_f64(1.0),
+        )
+    }
+}
+
+#[cfg(feature = "random")]
+pub struct UniformOklrab<T>
+where
+    T: FloatComponent + SampleUniform,
+{
+    l: Uniform<T>,
+    a: Uniform<T>,
+    b: Uniform<T>,
+}
+
+#[cfg(feature = "random")]
+impl<T> SampleUniform for Oklrab<T>
+where
+    T: FloatComponent + SampleUniform,
+{
+    type Sampler = UniformOklrab<T>;
+}
+
+#[cfg(feature = "random")]
+impl<T> UniformSampler for UniformOklrab<T>
+where
+    T: FloatComponent + SampleUniform,
+{
+    type X = Oklrab<T>;
+
+    fn new<B1, B2>(low_b: B1, high_b: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let low = *low_b.borrow();
+        let high = *high_b.borrow();
+
+        Self {
+            l: Uniform::new::<_, T>(low.l, high.l),
+            a: Uniform::new::<_, T>(low.a, high.a),
+            b: Uniform::new::<_, T>(low.b, high.b),
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low_b: B1, high_b: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let low = *low_b.borrow();
+        let high = *high_b.borrow();
+
+        Self {
+            l: Uniform::new_inclusive::<_, T>(low.l, high.l),
+            a: Uniform::new_inclusive::<_, T>(low.a, high.a),
+            b: Uniform::new_inclusive::<_, T>(low.b, high.b),
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Oklrab<T>
+where {
+        Oklrab::new(self.l.sample(rng), self.a.sample(rng), self.b.sample(rng))
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Zeroable for Oklrab<T> where T: bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Pod for Oklrab<T> where T: bytemuck::Pod {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::convert::FromColorUnclamped;
+    use crate::{FromColor, LinSrgb, Oklab};
+
+    #[test]
+    fn red() {
+        let a = Oklrab::from_color(LinSrgb::new(1.0, 0.0, 0.0));
+        let b = Oklrab::new(0.568120, 0.224840, 0.125798);
+        assert_relative_eq!(a, b, epsilon = 0.00001);
+    }
+
+    #[test]
+    fn black_and_white() {
+        assert_relative_eq!(
+            Oklrab::from_color(LinSrgb::new(0.0, 0.0, 0.0)),
+            Oklrab::new(0.0, 0.0, 0.0),
+            epsilon = 0.00001
+        );
+        assert_relative_eq!(
+            Oklrab::from_color(LinSrgb::new(1.0, 1.0, 1.0)),
+            Oklrab::new(1.0, 0.0, 0.0),
+            epsilon = 0.0001
+        );
+    }
+
+    #[test]
+    fn oklab_round_trip() {
+        for &l in &[0.0, 0.05, 0.2, 0.5, 0.8, 1.0] {
+            let oklab = Oklab::new(l, 0.1, -0.05);
+            let oklrab = Oklrab::from_color_unclamped(oklab);
+            assert_relative_eq!(Oklab::from_color_unclamped(oklrab), oklab, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn ranges() {
+        assert_ranges! {
+            Oklrab<f64>;
+            clamped {
+                l: 0.0 => 1.0,
+                a: -1.0 => 1.0,
+                b: -1.0 => 1.0
+            }
+            clamped_min {}
+            unclamped {}
+        }
+    }
+
+    #[test]
+    fn check_min_max_components() {
+        assert_relative_eq!(Oklrab::<f32>::min_l(), 0.0);
+        assert_relative_eq!(Oklrab::<f32>::min_a(), -1.0);
+        assert_relative_eq!(Oklrab::<f32>::min_b(), -1.0);
+        assert_relative_eq!(Oklrab::<f32>::max_l(), 1.0);
+        assert_relative_eq!(Oklrab::<f32>::max_a(), 1.0);
+        assert_relative_eq!(Oklrab::<f32>::max_b(), 1.0);
+    }
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn serialize() {
+        let serialized = ::serde_json::to_string(&Oklrab::new(0.3, 0.8, 0.1)).unwrap();
+
+        assert_eq!(serialized, r#"{"l":0.3,"a":0.8,"b":0.1}"#);
+    }
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn deserialize() {
+        let deserialized: Oklrab = ::serde_json::from_str(r#"{"l":0.3,"a":0.8,"b":0.1}"#).unwrap();
+
+        assert_eq!(deserialized, Oklrab::new(0.3, 0.8, 0.1));
+    }
+
+    #[cfg(feature = "random")]
+    test_uniform_distribution! {
+        Oklrab {
+            l: (0.0, 1.0),
+            a: (-1.0, 1.0),
+            b: (-1.0, 1.0)
+        },
+        min: Oklrab::new(0.0, -1.0, -1.0),
+        max: Oklrab::new(1.0, 1.0, 1.0)
+    }
+}
@@ -0,0 +1,221 @@
+//! Simulate overprinting inks.
+//!
+//! Printing inks are mostly transparent, so where one ink is printed over
+//! another, both of them filter the light, and the result is darker than
+//! either of them. That happens where spot colors are set to overprint, and
+//! in the thin trap areas where neighboring inks are spread to overlap each
+//! other. Vector graphics tools show this in an overprint preview, instead
+//! of letting the top color hide the bottom color.
+//!
+//! [`Overprint`] simulates it in linear RGB, where each ink is treated as a
+//! filter that lets through the fraction of the light that it reflects,
+//! compared to the paper:
+//!
+//! * [`solid`](Overprint::solid) gives the color where two solid inks
+//!   overlap. The top ink can be partially opaque, which hides some of the
+//!   bottom ink.
+//! * [`halftone`](Overprint::halftone) gives the average color of two
+//!   halftone screens with partial coverage, with the Demichel equations of
+//!   the Neugebauer model. The inks overlap on the fraction of the area
+//!   where both have dots, assuming they are independent.
+//!
+//! ```
+//! use palette::overprint::Overprint;
+//! use palette::Srgb;
+//!
+//! let cyan = Srgb::new(0.0f32, 0.68, 0.94);
+//! let yellow = Srgb::new(1.0, 0.95, 0.0);
+//!
+//! // Cyan and yellow make green.
+//! let green = Overprint::new().solid(yellow, cyan);
+//! assert!(green.green > green.red && green.green > green.blue);
+//!
+//! // An opaque top ink hides the bottom ink.
+//! let hidden = Overprint::new().with_opacity(1.0).solid(yellow, cyan);
+//! assert!((hidden.blue - cyan.blue).abs() < 1e-4);
+//! ```
+
+use crate::convert::{IntoColor, IntoColorUnclamped};
+use crate::{clamp, FloatComponent, LinSrgb};
+
+/// Settings for simulating overprinting inks.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct Overprint<T> {
+    /// The color of the paper, in linear sRGB.
+    pub paper: LinSrgb<T>,
+
+    /// How much of the bottom ink the top ink hides, from `0.0` for a fully
+    /// transparent ink to `1.0` for a fully opaque ink, such as some
+    /// metallic and white inks.
+    pub opacity: T,
+}
+
+impl<T> Overprint<T>
+where
+    T: FloatComponent,
+{
+    /// Create settings for transparent inks on white paper.
+    pub fn new() -> Self {
+        Overprint {
+            paper: LinSrgb::new(T::one(), T::one(), T::one()),
+            opacity: T::zero(),
+        }
+    }
+
+    /// Change the color of the paper.
+    pub fn with_paper<C>(mut self, paper: C) -> Self
+    where
+        C: IntoColorUnclamped<LinSrgb<T>>,
+    {
+        self.paper = paper.into_color_unclamped();
+        self
+    }
+
+    /// Change the opacity of the top ink.
+    pub fn with_opacity(mut self, opacity: T) -> Self {
+        self.opacity = clamp(opacity, T::zero(), T::one());
+        self
+    }
+
+    /// Get the color where the solid `top` ink is printed over the solid
+    /// `bottom` ink.
+    ///
+    /// The ink colors are how they look when they are printed alone on the
+    /// paper.
+    pub fn solid<C>(&self, bottom: C, top: C) -> C
+    where
+        C: IntoColorUnclamped<LinSrgb<T>>,
+        LinSrgb<T>: IntoColor<C>,
+    {
+        self.overlap(bottom.into_color_unclamped(), top.into_color_unclamped())
+            .into_color()
+    }
+
+    /// Get the average color where the `top` ink is printed over the
+    /// `bottom` ink, as halftone screens with the coverages `bottom_coverage`
+    /// and `top_coverage`, from `0.0` to `1.0`.
+    ///
+    /// The ink colors are how they look when they are printed solid and
+    /// alone on the paper.
+    pub fn halftone<C>(&self, bottom: C, bottom_coverage: T, top: C, top_coverage: T) -> C
+    where
+        C: IntoColorUnclamped<LinSrgb<T>>,
+        LinSrgb<T>: IntoColor<C>,
+    {
+        let bottom: LinSrgb<T> = bottom.into_color_unclamped();
+        let top: LinSrgb<T> = top.into_color_unclamped();
+        let overlap = self.overlap(bottom, top);
+
+        let bottom_coverage = clamp(bottom_coverage, T::zero(), T::one());
+        let top_coverage = clamp(top_coverage, T::zero(), T::one());
+
+        // The Demichel weights of each combination of inks.
+        let paper_weight = (T::one() - bottom_coverage) * (T::one() - top_coverage);
+        let bottom_weight = bottom_coverage * (T::one() - top_coverage);
+        let top_weight = (T::one() - bottom_coverage) * top_coverage;
+        let overlap_weight = bottom_coverage * top_coverage;
+
+        let average = self.paper * paper_weight
+            + bottom * bottom_weight
+            + top * top_weight
+            + overlap * overlap_weight;
+        average.into_color()
+    }
+
+    fn overlap(&self, bottom: LinSrgb<T>, top: LinSrgb<T>) -> LinSrgb<T> {
+        let filter = |bottom: T, top: T, paper: T| {
+            let transparent = if paper > T::zero() {
+                bottom * top / paper
+            } else {
+                T::zero()
+            };
+
+            transparent + (top - transparent) * self.opacity
+        };
+
+        LinSrgb::new(
+            filter(bottom.red, top.red, self.paper.red),
+            filter(bottom.green, top.green, self.paper.green),
+            filter(bottom.blue, top.blue, self.paper.blue),
+        )
+    }
+}
+
+impl<T> Default for Overprint<T>
+where
+    T: FloatComponent,
+{
+    fn default() -> Self {
+        Overprint::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Overprint;
+    use crate::{LinSrgb, Srgb};
+
+    #[test]
+    fn multiplies_transparent_inks() {
+        let overprint = Overprint::<f64>::new();
+        let bottom = LinSrgb::new(0.9, 0.5, 0.1);
+        let top = LinSrgb::new(0.2, 0.8, 0.5);
+
+        let result = overprint.solid(bottom, top);
+        assert_relative_eq!(result, LinSrgb::new(0.18, 0.4, 0.05), epsilon = 1e-12);
+
+        // The order doesn't matter for transparent inks.
+        assert_relative_eq!(overprint.solid(top, bottom), result, epsilon = 1e-12);
+
+        // Printing over the paper color changes nothing.
+        let white = LinSrgb::new(1.0, 1.0, 1.0);
+        assert_relative_eq!(overprint.solid(white, top), top, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn opacity() {
+        let bottom = LinSrgb::new(0.9, 0.5, 0.1);
+        let top = LinSrgb::new(0.2, 0.8, 0.5);
+
+        let opaque = Overprint::new().with_opacity(1.0).solid(bottom, top);
+        assert_relative_eq!(opaque, top, epsilon = 1e-12);
+
+        let half = Overprint::new().with_opacity(0.5).solid(bottom, top);
+        assert_relative_eq!(half, LinSrgb::new(0.19, 0.6, 0.275), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn tinted_paper() {
+        let paper = LinSrgb::new(0.9, 0.9, 0.8);
+        let overprint = Overprint::<f64>::new().with_paper(paper);
+
+        // An ink that is as light as the paper acts like no ink.
+        let ink = LinSrgb::new(0.3, 0.6, 0.4);
+        assert_relative_eq!(overprint.solid(paper, ink), ink, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn halftone() {
+        let overprint = Overprint::<f64>::new();
+        let cyan = Srgb::new(0.0, 0.68, 0.94);
+        let magenta = Srgb::new(0.93, 0.0, 0.55);
+
+        let none = overprint.halftone(cyan, 0.0, magenta, 0.0);
+        assert_relative_eq!(none, Srgb::new(1.0, 1.0, 1.0), epsilon = 1e-6);
+
+        let solid = overprint.halftone(cyan, 1.0, magenta, 1.0);
+        assert_relative_eq!(solid, overprint.solid(cyan, magenta), epsilon = 1e-6);
+
+        let only_cyan = overprint.halftone(cyan, 1.0, magenta, 0.0);
+        assert_relative_eq!(only_cyan, cyan, epsilon = 1e-6);
+
+        let half = overprint.halftone(cyan, 0.5, magenta, 0.5);
+        let expected = (LinSrgb::new(1.0, 1.0, 1.0)
+            + cyan.into_linear()
+            + magenta.into_linear()
+            + overprint.solid(cyan, magenta).into_linear())
+            * 0.25;
+        assert_relative_eq!(half.into_linear(), expected, epsilon = 1e-6);
+    }
+}
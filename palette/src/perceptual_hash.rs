@@ -0,0 +1,206 @@
+//! Building blocks for perceptual image hashing.
+//!
+//! Perceptual hashes, such as the difference hash, summarize an image as a
+//! grid of average colors and compare neighboring cells. Averaging encoded
+//! sRGB values makes dark areas look darker than they are, which biases the
+//! hashes, so the averages in this module are calculated in
+//! [`Oklab`](crate::Oklab) instead.
+//!
+//! ```
+//! use palette::perceptual_hash::{difference_hash, hash_distance};
+//! use palette::Srgb;
+//!
+//! // A 36 by 8 pixel horizontal gradient.
+//! let image: Vec<Srgb> = (0..8 * 36)
+//!     .map(|i| {
+//!         let value = (i % 36) as f32 / 35.0;
+//!         Srgb::new(value, value, value)
+//!     })
+//!     .collect();
+//!
+//! let hash = difference_hash(&image, 36);
+//! assert_eq!(hash, u64::MAX);
+//!
+//! let reversed: Vec<Srgb> = image.chunks(36).flat_map(|row| row.iter().rev().copied()).collect();
+//! assert_eq!(hash_distance(hash, difference_hash(&reversed, 36)), 64);
+//! ```
+//!
+//! This module is only available if the `std` feature is enabled (this is the
+//! default).
+
+use crate::convert::IntoColorUnclamped;
+use crate::{from_f64, FloatComponent, Oklab};
+
+/// Calculate the average Oklab color of each block in a grid of
+/// `blocks_wide` by `blocks_high` blocks.
+///
+/// `pixels` is an image in row-major order, that is `width` pixels wide. The
+/// pixels are divided as evenly as possible between the blocks, and the
+/// averages are returned in row-major order. Blocks without pixels, which
+/// happens when the image is smaller than the grid, are left black.
+///
+/// Encoded colors, such as `Srgb<u8>`, have to be converted to a float type,
+/// for example with [`Rgb::into_format`](crate::rgb::Rgb::into_format),
+/// before they can be passed in.
+///
+/// # Panics
+///
+/// Panics if `width` is zero, or the length of `pixels` is not a multiple of
+/// `width`.
+pub fn block_averages<C, T>(
+    pixels: &[C],
+    width: usize,
+    blocks_wide: usize,
+    blocks_high: usize,
+) -> Vec<Oklab<T>>
+where
+    C: Copy + IntoColorUnclamped<Oklab<T>>,
+    T: FloatComponent,
+{
+    assert!(width > 0, "the image width must not be zero");
+
+    let height = pixels.len() / width;
+    assert!(
+        height * width == pixels.len(),
+        "the number of pixels must be a multiple of the width"
+    );
+    let mut sums =
+        vec![(Oklab::new(T::zero(), T::zero(), T::zero()), 0usize); blocks_wide * blocks_high];
+
+    if sums.is_empty() {
+        return Vec::new();
+    }
+
+    for (y, row) in pixels.chunks(width).enumerate() {
+        let block_y = y * blocks_high / height;
+
+        for (x, &pixel) in row.iter().enumerate() {
+            let block_x = x * blocks_wide / width;
+            let (sum, count) = &mut sums[block_y * blocks_wide + block_x];
+            *sum = *sum + pixel.into_color_unclamped();
+            *count += 1;
+        }
+    }
+
+    sums.into_iter()
+        .map(|(sum, count)| {
+            if count == 0 {
+                sum
+            } else {
+                sum / from_f64::<T>(count as f64)
+            }
+        })
+        .collect()
+}
+
+/// Calculate a difference hash from a grid of block averages that is
+/// `blocks_wide` blocks wide.
+///
+/// Each bit compares the lightness of two horizontally neighboring blocks,
+/// and is set if the right one is lighter. The bits are filled from the
+/// least significant bit, row by row, and comparisons beyond the first 64
+/// are ignored. A grid of 9 by 8 blocks gives exactly 64 bits.
+pub fn difference_hash_from_blocks<T>(blocks: &[Oklab<T>], blocks_wide: usize) -> u64
+where
+    T: FloatComponent,
+{
+    if blocks_wide == 0 {
+        return 0;
+    }
+
+    blocks
+        .chunks(blocks_wide)
+        .flat_map(|row| row.windows(2))
+        .take(64)
+        .enumerate()
+        .fold(0, |hash, (bit, pair)| {
+            if pair[1].l > pair[0].l {
+                hash | 1 << bit
+            } else {
+                hash
+            }
+        })
+}
+
+/// Calculate a 64 bit difference hash for an image that is `width` pixels
+/// wide, using a grid of 9 by 8 blocks.
+///
+/// See [`block_averages`] and [`difference_hash_from_blocks`] for the
+/// details.
+///
+/// # Panics
+///
+/// Panics if `width` is zero, or the length of `pixels` is not a multiple of
+/// `width`.
+pub fn difference_hash<C, T>(pixels: &[C], width: usize) -> u64
+where
+    C: Copy + IntoColorUnclamped<Oklab<T>>,
+    T: FloatComponent,
+{
+    difference_hash_from_blocks(&block_averages(pixels, width, 9, 8), 9)
+}
+
+/// The number of bits that differ between two hashes. A lower number means
+/// the images are more similar.
+pub fn hash_distance(a: u64, b: u64) -> u32 {
+    (a ^ b).count_ones()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{block_averages, difference_hash, difference_hash_from_blocks, hash_distance};
+    use crate::{FromColor, Oklab, Srgb};
+
+    #[test]
+    fn averages_in_oklab() {
+        let black = Srgb::new(0.0f64, 0.0, 0.0);
+        let white = Srgb::new(1.0f64, 1.0, 1.0);
+        let pixels = [black, white, black, white];
+
+        let averages = block_averages(&pixels, 2, 1, 1);
+        let expected = (Oklab::from_color(black) + Oklab::from_color(white)) / 2.0;
+
+        assert_eq!(averages.len(), 1);
+        assert_relative_eq!(averages[0], expected, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn uneven_blocks() {
+        let pixels: Vec<Srgb<f64>> = (0..15)
+            .map(|i| {
+                let value = (i % 5) as f64 / 4.0;
+                Srgb::new(value, value, value)
+            })
+            .collect();
+
+        let averages = block_averages(&pixels, 5, 2, 2);
+        assert_eq!(averages.len(), 4);
+        assert!(averages[1].l > averages[0].l);
+        assert_relative_eq!(averages[0], averages[2], epsilon = 1e-12);
+
+        let small = block_averages(&pixels[..5], 5, 2, 2);
+        assert_eq!(small[2], Oklab::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn hash() {
+        let uniform = vec![Srgb::new(0.5f32, 0.2, 0.1); 18 * 16];
+        assert_eq!(difference_hash(&uniform, 18), 0);
+
+        let blocks = [
+            Oklab::new(0.1f32, 0.0, 0.0),
+            Oklab::new(0.2, 0.0, 0.0),
+            Oklab::new(0.1, 0.0, 0.0),
+            Oklab::new(0.3, 0.0, 0.0),
+            Oklab::new(0.4, 0.0, 0.0),
+            Oklab::new(0.5, 0.0, 0.0),
+        ];
+        assert_eq!(difference_hash_from_blocks(&blocks, 3), 0b1101);
+    }
+
+    #[test]
+    fn distance() {
+        assert_eq!(hash_distance(0b1011, 0b0010), 2);
+        assert_eq!(hash_distance(u64::MAX, 0), 64);
+    }
+}
@@ -0,0 +1,288 @@
+//! Coordinates for color picker widgets.
+//!
+//! Color pickers usually combine a hue wheel with a saturation and value
+//! square, or with a triangle of shades, tints and tones. The functions in
+//! this module map between positions in these shapes and [`Hsv`] or [`Hsl`]
+//! colors, in both directions. Positions outside of a shape, and colors
+//! outside of the valid ranges, are clamped to the nearest point inside.
+//!
+//! All positions are `[x, y]` pairs with `y` pointing up, so it has to be
+//! flipped for toolkits where `y` points down:
+//!
+//! * The wheel and the triangle are centered on `[0.0, 0.0]` and fit in a
+//!   circle with the radius `1.0`. The hue is the angle from the positive `x`
+//!   axis, counterclockwise.
+//! * The square goes from `[0.0, 0.0]` to `[1.0, 1.0]`, with the saturation
+//!   along `x` and the value along `y`.
+//!
+//! ```
+//! use approx::assert_relative_eq;
+//! use palette::picker;
+//! use palette::Hsv;
+//!
+//! // A click at the top of the wheel, halfway to the edge.
+//! let color: Hsv = picker::wheel_to_hsv([0.0, 0.5], 1.0);
+//! assert_relative_eq!(color, Hsv::new_srgb(90.0, 0.5, 1.0));
+//!
+//! // Place the marker for an existing color.
+//! let marker = picker::hsv_to_square(Hsv::new_srgb(200.0, 0.25, 0.75));
+//! assert_eq!(marker, [0.25, 0.75]);
+//! ```
+
+use crate::float;
+use crate::{clamp, FloatComponent, Hsl, Hsv, RgbHue};
+
+/// Get the color at `point` in a hue wheel, where the distance from the
+/// center is the saturation, and `value` is the same for the whole wheel.
+pub fn wheel_to_hsv<S, T>(point: [T; 2], value: T) -> Hsv<S, T>
+where
+    T: FloatComponent,
+{
+    let [x, y] = point;
+    let radius = float::hypot(x, y);
+    let hue = if radius > T::zero() {
+        RgbHue::from_radians(float::atan2(y, x))
+    } else {
+        RgbHue::from_degrees(T::zero())
+    };
+
+    Hsv::new(
+        hue,
+        clamp(radius, T::zero(), T::one()),
+        clamp(value, T::zero(), T::one()),
+    )
+}
+
+/// Get the position of `color` in a hue wheel, where the distance from the
+/// center is the saturation.
+pub fn hsv_to_wheel<S, T>(color: Hsv<S, T>) -> [T; 2]
+where
+    T: FloatComponent,
+{
+    let radius = clamp(color.saturation, T::zero(), T::one());
+    let (sin, cos) = float::sin_cos(color.hue.to_radians());
+
+    [cos * radius, sin * radius]
+}
+
+/// Get the color at `point` in a saturation and value square for `hue`.
+pub fn square_to_hsv<S, T, H>(point: [T; 2], hue: H) -> Hsv<S, T>
+where
+    T: FloatComponent,
+    H: Into<RgbHue<T>>,
+{
+    let [x, y] = point;
+    Hsv::new(
+        hue,
+        clamp(x, T::zero(), T::one()),
+        clamp(y, T::zero(), T::one()),
+    )
+}
+
+/// Get the position of `color` in a saturation and value square.
+pub fn hsv_to_square<S, T>(color: Hsv<S, T>) -> [T; 2]
+where
+    T: FloatComponent,
+{
+    [
+        clamp(color.saturation, T::zero(), T::one()),
+        clamp(color.value, T::zero(), T::one()),
+    ]
+}
+
+/// Get the corners of the triangle for `hue`, as the fully saturated color,
+/// white and black, in that order.
+///
+/// The triangle is rotated so the fully saturated corner points at `hue` in
+/// a hue wheel, which makes it possible to place it inside the wheel.
+pub fn triangle_vertices<T, H>(hue: H) -> [[T; 2]; 3]
+where
+    T: FloatComponent,
+    H: Into<RgbHue<T>>,
+{
+    let angle = hue.into().to_radians();
+    let third = T::from_f64(core::f64::consts::PI * 2.0 / 3.0);
+    let vertex = |angle: T| {
+        let (sin, cos) = float::sin_cos(angle);
+        [cos, sin]
+    };
+
+    [vertex(angle), vertex(angle + third), vertex(angle - third)]
+}
+
+/// Get the color at `point` in the triangle for `hue`, with the corners from
+/// [`triangle_vertices`].
+///
+/// The colors in the triangle are mixtures of the fully saturated color,
+/// white and black, so the lightness increases towards white and the
+/// saturation increases towards the fully saturated corner.
+pub fn triangle_to_hsl<S, T, H>(point: [T; 2], hue: H) -> Hsl<S, T>
+where
+    T: FloatComponent,
+    H: Into<RgbHue<T>>,
+{
+    let hue = hue.into();
+    let [chroma, white, _black] = barycentric(point, triangle_vertices(hue));
+
+    // The mixture has `chroma + white` as its largest RGB component and
+    // `white` as its smallest.
+    let two = T::from_f64(2.0);
+    let lightness = (chroma + two * white) / two;
+    let denominator = T::one() - (two * lightness - T::one()).abs();
+    let saturation = if denominator > T::zero() {
+        clamp(chroma / denominator, T::zero(), T::one())
+    } else {
+        T::zero()
+    };
+
+    Hsl::new(hue, saturation, clamp(lightness, T::zero(), T::one()))
+}
+
+/// Get the position of `color` in the triangle for its hue, with the corners
+/// from [`triangle_vertices`].
+pub fn hsl_to_triangle<S, T>(color: Hsl<S, T>) -> [T; 2]
+where
+    T: FloatComponent,
+{
+    let saturation = clamp(color.saturation, T::zero(), T::one());
+    let lightness = clamp(color.lightness, T::zero(), T::one());
+
+    let two = T::from_f64(2.0);
+    let chroma = (T::one() - (two * lightness - T::one()).abs()) * saturation;
+    let white = lightness - chroma / two;
+    let black = T::one() - (lightness + chroma / two);
+
+    let [[hue_x, hue_y], [white_x, white_y], [black_x, black_y]] = triangle_vertices(color.hue);
+    [
+        chroma * hue_x + white * white_x + black * black_x,
+        chroma * hue_y + white * white_y + black * black_y,
+    ]
+}
+
+/// Calculate the barycentric coordinates of `point` in `triangle`, for the
+/// nearest position inside it.
+fn barycentric<T>(point: [T; 2], triangle: [[T; 2]; 3]) -> [T; 3]
+where
+    T: FloatComponent,
+{
+    let [px, py] = point;
+    let [[ax, ay], [bx, by], [cx, cy]] = triangle;
+
+    let determinant = (by - cy) * (ax - cx) + (cx - bx) * (ay - cy);
+    let a = ((by - cy) * (px - cx) + (cx - bx) * (py - cy)) / determinant;
+    let b = ((cy - ay) * (px - cx) + (ax - cx) * (py - cy)) / determinant;
+    let c = T::one() - a - b;
+
+    if a >= T::zero() && b >= T::zero() && c >= T::zero() {
+        return [a, b, c];
+    }
+
+    // The point is outside, so find the nearest point on the edges.
+    let edges = [(0, 1), (1, 2), (2, 0)];
+    let mut nearest = [T::zero(); 3];
+    let mut nearest_distance = T::infinity();
+
+    for &(start, end) in &edges {
+        let [sx, sy] = triangle[start];
+        let [ex, ey] = triangle[end];
+        let (dx, dy) = (ex - sx, ey - sy);
+        let t = clamp(
+            ((px - sx) * dx + (py - sy) * dy) / (dx * dx + dy * dy),
+            T::zero(),
+            T::one(),
+        );
+        let distance = float::hypot(sx + dx * t - px, sy + dy * t - py);
+
+        if distance < nearest_distance {
+            nearest_distance = distance;
+            nearest = [T::zero(); 3];
+            nearest[start] = T::one() - t;
+            nearest[end] = t;
+        }
+    }
+
+    nearest
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        hsl_to_triangle, hsv_to_square, hsv_to_wheel, square_to_hsv, triangle_to_hsl,
+        triangle_vertices, wheel_to_hsv,
+    };
+    use crate::encoding;
+    use crate::{FromColor, Hsl, Hsv, Srgb};
+
+    #[test]
+    fn wheel() {
+        let color: Hsv<encoding::Srgb, f64> = wheel_to_hsv([-0.5, 0.0], 0.8);
+        assert_relative_eq!(color, Hsv::new_srgb(180.0, 0.5, 0.8));
+
+        // Outside the wheel.
+        let color: Hsv<encoding::Srgb, f64> = wheel_to_hsv([0.0, -3.0], 1.0);
+        assert_relative_eq!(color, Hsv::new_srgb(270.0, 1.0, 1.0));
+
+        let point = hsv_to_wheel(Hsv::new_srgb(-90.0f64, 0.25, 1.0));
+        assert_relative_eq!(point[0], 0.0, epsilon = 1e-9);
+        assert_relative_eq!(point[1], -0.25, epsilon = 1e-9);
+
+        // The center has no hue.
+        let center: Hsv<encoding::Srgb, f64> = wheel_to_hsv([0.0, 0.0], 1.0);
+        assert_eq!(center, Hsv::new_srgb(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn square() {
+        let color: Hsv<encoding::Srgb, f64> = square_to_hsv([1.5, -0.5], 120.0);
+        assert_eq!(color, Hsv::new_srgb(120.0, 1.0, 0.0));
+        assert_eq!(hsv_to_square(Hsv::new_srgb(10.0, 2.0, 0.5)), [1.0, 0.5]);
+    }
+
+    #[test]
+    fn triangle_corners() {
+        let [hue, white, black] = triangle_vertices::<f64, _>(60.0);
+
+        let pure: Hsl<encoding::Srgb, f64> = triangle_to_hsl(hue, 60.0);
+        assert_relative_eq!(pure, Hsl::new_srgb(60.0, 1.0, 0.5), epsilon = 1e-9);
+
+        let light: Hsl<encoding::Srgb, f64> = triangle_to_hsl(white, 60.0);
+        assert_relative_eq!(light.lightness, 1.0, epsilon = 1e-9);
+
+        let dark: Hsl<encoding::Srgb, f64> = triangle_to_hsl(black, 60.0);
+        assert_relative_eq!(dark.lightness, 0.0, epsilon = 1e-9);
+
+        // Gray is halfway between white and black.
+        let gray = [(white[0] + black[0]) / 2.0, (white[1] + black[1]) / 2.0];
+        let gray: Hsl<encoding::Srgb, f64> = triangle_to_hsl(gray, 60.0);
+        assert_relative_eq!(gray.saturation, 0.0, epsilon = 1e-9);
+        assert_relative_eq!(gray.lightness, 0.5, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn triangle_round_trip() {
+        for &(saturation, lightness) in &[(0.5, 0.5), (1.0, 0.25), (0.3, 0.9), (0.0, 0.4)] {
+            let color = Hsl::new_srgb(200.0f64, saturation, lightness);
+            let point = hsl_to_triangle(color);
+            let back: Hsl<encoding::Srgb, f64> = triangle_to_hsl(point, 200.0);
+
+            assert_relative_eq!(back, color, epsilon = 1e-6);
+            assert_relative_eq!(
+                Srgb::from_color(back),
+                Srgb::from_color(color),
+                epsilon = 1e-6
+            );
+        }
+    }
+
+    #[test]
+    fn triangle_clamping() {
+        // Far beyond the fully saturated corner.
+        let color: Hsl<encoding::Srgb, f64> = triangle_to_hsl([10.0, 0.0], 0.0);
+        assert_relative_eq!(color, Hsl::new_srgb(0.0, 1.0, 0.5), epsilon = 1e-9);
+
+        // Outside the edge between white and black, which is gray.
+        let color: Hsl<encoding::Srgb, f64> = triangle_to_hsl([-2.0, 0.0], 0.0);
+        assert_relative_eq!(color.saturation, 0.0, epsilon = 1e-9);
+        assert_relative_eq!(color.lightness, 0.5, epsilon = 1e-9);
+    }
+}
@@ -0,0 +1,267 @@
+//! Batch conversion between Cartesian and polar color spaces.
+//!
+//! Converting between [`Lab`] and [`Lch`], or between [`Oklab`] and
+//! [`Oklch`], is dominated by the trigonometric functions, which makes it
+//! the bottleneck in some palette analysis workloads. The functions in this
+//! module convert whole slices at once, without allocating, in tight loops
+//! that the compiler can optimize better than one conversion at a time.
+//!
+//! The results are the same as from
+//! [`FromColorUnclamped`](crate::convert::FromColorUnclamped).
+//!
+//! There are two forms of each conversion. One writes the result to another
+//! slice, and one converts the colors in place and returns them as the new
+//! type:
+//!
+//! ```
+//! use palette::{polar, Lab, Lch};
+//!
+//! let mut colors = [Lab::new(50.0f32, 20.0, 20.0), Lab::new(70.0, 0.0, -30.0)];
+//!
+//! let mut output = [Lch::default(); 2];
+//! polar::lab_to_lch(&colors, &mut output);
+//!
+//! let in_place: &mut [Lch] = polar::lab_to_lch_in_place(&mut colors);
+//! assert_eq!(in_place, output);
+//! ```
+
+use crate::{cast, float, FloatComponent, Lab, LabHue, Lch, Oklab, OklabHue, Oklch};
+
+/// Convert `input` from `Lab` to `Lch`, and write the result to `output`.
+///
+/// # Panics
+///
+/// Panics if `input` and `output` have different lengths.
+pub fn lab_to_lch<Wp, T>(input: &[Lab<Wp, T>], output: &mut [Lch<Wp, T>])
+where
+    T: FloatComponent,
+{
+    convert(
+        cast::into_array_slice(input),
+        cast::into_array_slice_mut(output),
+        to_polar,
+    );
+}
+
+/// Convert `input` from `Lch` to `Lab`, and write the result to `output`.
+///
+/// # Panics
+///
+/// Panics if `input` and `output` have different lengths.
+pub fn lch_to_lab<Wp, T>(input: &[Lch<Wp, T>], output: &mut [Lab<Wp, T>])
+where
+    T: FloatComponent,
+{
+    convert(
+        cast::into_array_slice(input),
+        cast::into_array_slice_mut(output),
+        |color| to_cartesian(color, |hue| LabHue::from_degrees(hue).to_radians()),
+    );
+}
+
+/// Convert `input` from `Oklab` to `Oklch`, and write the result to
+/// `output`.
+///
+/// # Panics
+///
+/// Panics if `input` and `output` have different lengths.
+pub fn oklab_to_oklch<T>(input: &[Oklab<T>], output: &mut [Oklch<T>])
+where
+    T: FloatComponent,
+{
+    convert(
+        cast::into_array_slice(input),
+        cast::into_array_slice_mut(output),
+        to_polar,
+    );
+}
+
+/// Convert `input` from `Oklch` to `Oklab`, and write the result to
+/// `output`.
+///
+/// # Panics
+///
+/// Panics if `input` and `output` have different lengths.
+pub fn oklch_to_oklab<T>(input: &[Oklch<T>], output: &mut [Oklab<T>])
+where
+    T: FloatComponent,
+{
+    convert(
+        cast::into_array_slice(input),
+        cast::into_array_slice_mut(output),
+        |color| to_cartesian(color, |hue| OklabHue::from_degrees(hue).to_radians()),
+    );
+}
+
+/// Convert `colors` from `Lab` to `Lch` in place.
+pub fn lab_to_lch_in_place<Wp, T>(colors: &mut [Lab<Wp, T>]) -> &mut [Lch<Wp, T>]
+where
+    T: FloatComponent,
+{
+    let colors = cast::into_array_slice_mut(colors);
+    convert_in_place(colors, to_polar);
+    cast::from_array_slice_mut(colors)
+}
+
+/// Convert `colors` from `Lch` to `Lab` in place.
+pub fn lch_to_lab_in_place<Wp, T>(colors: &mut [Lch<Wp, T>]) -> &mut [Lab<Wp, T>]
+where
+    T: FloatComponent,
+{
+    let colors = cast::into_array_slice_mut(colors);
+    convert_in_place(colors, |color| {
+        to_cartesian(color, |hue| LabHue::from_degrees(hue).to_radians())
+    });
+    cast::from_array_slice_mut(colors)
+}
+
+/// Convert `colors` from `Oklab` to `Oklch` in place.
+pub fn oklab_to_oklch_in_place<T>(colors: &mut [Oklab<T>]) -> &mut [Oklch<T>]
+where
+    T: FloatComponent,
+{
+    let colors = cast::into_array_slice_mut(colors);
+    convert_in_place(colors, to_polar);
+    cast::from_array_slice_mut(colors)
+}
+
+/// Convert `colors` from `Oklch` to `Oklab` in place.
+pub fn oklch_to_oklab_in_place<T>(colors: &mut [Oklch<T>]) -> &mut [Oklab<T>]
+where
+    T: FloatComponent,
+{
+    let colors = cast::into_array_slice_mut(colors);
+    convert_in_place(colors, |color| {
+        to_cartesian(color, |hue| OklabHue::from_degrees(hue).to_radians())
+    });
+    cast::from_array_slice_mut(colors)
+}
+
+#[inline]
+fn convert<T, F>(input: &[[T; 3]], output: &mut [[T; 3]], convert: F)
+where
+    T: Copy,
+    F: Fn([T; 3]) -> [T; 3],
+{
+    assert_eq!(
+        input.len(),
+        output.len(),
+        "the input and output slices have different lengths"
+    );
+
+    for (input, output) in input.iter().zip(output) {
+        *output = convert(*input);
+    }
+}
+
+#[inline]
+fn convert_in_place<T, F>(colors: &mut [[T; 3]], convert: F)
+where
+    T: Copy,
+    F: Fn([T; 3]) -> [T; 3],
+{
+    for color in colors {
+        *color = convert(*color);
+    }
+}
+
+/// Convert `[l, a, b]` to `[l, chroma, hue]`, where the hue is in degrees.
+#[inline]
+fn to_polar<T: FloatComponent>([l, a, b]: [T; 3]) -> [T; 3] {
+    let chroma = (a * a + b * b).sqrt();
+    let hue = if a == T::zero() && b == T::zero() {
+        T::zero()
+    } else {
+        float::atan2(b, a).to_degrees()
+    };
+
+    [l, chroma, hue]
+}
+
+/// Convert `[l, chroma, hue]` to `[l, a, b]`, where `to_radians` normalizes
+/// the hue and converts it from degrees.
+#[inline]
+fn to_cartesian<T, F>([l, chroma, hue]: [T; 3], to_radians: F) -> [T; 3]
+where
+    T: FloatComponent,
+    F: Fn(T) -> T,
+{
+    let chroma = chroma.max(T::zero());
+    let (sin, cos) = float::sin_cos(to_radians(hue));
+
+    [l, chroma * cos, chroma * sin]
+}
+
+#[cfg(test)]
+mod test {
+    use crate::convert::FromColorUnclamped;
+    use crate::{Lab, Lch, Oklab, Oklch};
+
+    fn lab_colors() -> Vec<Lab<crate::white_point::D65, f64>> {
+        let mut colors = Vec::new();
+
+        for &l in &[0.0, 50.0, 100.0] {
+            for a in -4..=4 {
+                for b in -4..=4 {
+                    colors.push(Lab::new(l, f64::from(a) * 25.0, f64::from(b) * 25.0));
+                }
+            }
+        }
+
+        colors
+    }
+
+    #[test]
+    fn lab_lch_matches_single_conversions() {
+        let input = lab_colors();
+        let mut output = vec![Lch::default(); input.len()];
+        super::lab_to_lch(&input, &mut output);
+
+        for (&lab, &lch) in input.iter().zip(&output) {
+            assert_relative_eq!(lch, Lch::from_color_unclamped(lab), epsilon = 1e-4);
+        }
+
+        let mut back = vec![Lab::default(); input.len()];
+        super::lch_to_lab(&output, &mut back);
+
+        for (&lch, &lab) in output.iter().zip(&back) {
+            assert_relative_eq!(lab, Lab::from_color_unclamped(lch), epsilon = 1e-4);
+        }
+    }
+
+    #[test]
+    fn oklab_oklch_in_place() {
+        let input: Vec<Oklab<f64>> = lab_colors()
+            .into_iter()
+            .map(|lab| Oklab::new(lab.l / 100.0, lab.a / 250.0, lab.b / 250.0))
+            .collect();
+
+        let mut colors = input.clone();
+        let oklch = super::oklab_to_oklch_in_place(&mut colors);
+
+        for (&oklab, &oklch) in input.iter().zip(oklch.iter()) {
+            assert_relative_eq!(oklch, Oklch::from_color_unclamped(oklab), epsilon = 1e-4);
+        }
+
+        let oklab = super::oklch_to_oklab_in_place(oklch);
+
+        for (&expected, &oklab) in input.iter().zip(oklab.iter()) {
+            assert_relative_eq!(oklab, expected, epsilon = 1e-5);
+        }
+    }
+
+    #[test]
+    fn unnormalized_hue() {
+        let mut colors = [Lch::<crate::white_point::D65, f64>::new(50.0, 20.0, 750.0)];
+        let lab = super::lch_to_lab_in_place(&mut colors);
+        assert_relative_eq!(lab[0], Lab::new(50.0, 17.320508, 10.0), epsilon = 1e-4);
+    }
+
+    #[test]
+    #[should_panic(expected = "different lengths")]
+    fn length_mismatch() {
+        let input = [Oklab::new(0.5f32, 0.1, 0.1); 2];
+        let mut output = [Oklch::default(); 3];
+        super::oklab_to_oklch(&input, &mut output);
+    }
+}
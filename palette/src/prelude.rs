@@ -0,0 +1,44 @@
+//! The most commonly used traits and types, for glob importing.
+//!
+//! Most of the functionality of this library is in traits, which have to be
+//! in scope to be used. Importing them one by one gets repetitive, so this
+//! module re-exports the ones for converting, clamping, mixing, adjusting,
+//! comparing and parsing colors, together with the most common color types:
+//!
+//! ```
+//! use palette::prelude::*;
+//!
+//! let orange: Srgb<u8> = "#ff8000".parse().unwrap();
+//! let orange: Oklch = orange.into_format::<f32>().into_color();
+//!
+//! let lighter = orange.lighten(0.2).shift_hue(10.0);
+//! let mixed = lighter.mix(Oklch::new(0.3, 0.1, 250.0), 0.5);
+//!
+//! let mixed = Srgb::from_color(mixed).clamp();
+//! assert!(mixed.is_within_bounds());
+//! assert!(mixed.get_contrast_ratio(Srgb::new(1.0, 1.0, 1.0)) > 1.0);
+//! ```
+//!
+//! Only traits and a few types are included, to keep the risk of name
+//! collisions low. The types for specific tasks are in their own modules,
+//! such as [`gamut`](crate::gamut) and [`theme`](crate::theme).
+
+pub use crate::alpha::WithAlpha;
+pub use crate::blend::Blend;
+pub use crate::chromatic_adaptation::{AdaptFrom, AdaptInto};
+pub use crate::color_difference::{ColorDifference, DeltaE};
+pub use crate::convert::{
+    FromColor, FromColorUnclamped, IntoColor, IntoColorUnclamped, TryFromColor, TryIntoColor,
+};
+pub use crate::relative_contrast::RelativeContrast;
+pub use crate::roles::ColorRole;
+pub use crate::{
+    Clamp, ClampAssign, ComponentWise, Darken, DarkenAssign, Desaturate, DesaturateAssign, GetHue,
+    IsWithinBounds, Lighten, LightenAssign, Mix, MixAssign, Saturate, SaturateAssign, SetHue,
+    ShiftHue, ShiftHueAssign, WithHue,
+};
+
+pub use crate::{
+    Alpha, Hsl, Hsla, Hsv, Hsva, Lab, Laba, Lch, Lcha, LinSrgb, LinSrgba, Oklab, Oklaba, Oklch,
+    Oklcha, Srgb, Srgba, Xyz, Xyza,
+};
@@ -0,0 +1,221 @@
+//! Colors that carry provenance metadata through conversions.
+//!
+//! Professional workflows sometimes need an audit trail for each color, such
+//! as which profile it came from, which rendering intent was used, and
+//! whether it had to be clipped to fit a gamut. [`Tagged`] wraps a color
+//! together with a [`Provenance`] record, and keeps the record when the color
+//! is converted. The inner color is stored as it is, so its memory layout is
+//! not affected, and the wrapper dereferences to it for the color math.
+//!
+//! ```
+//! use palette::provenance::{Provenance, RenderingIntent, Tagged};
+//! use palette::{FromColor, Lch, Srgb};
+//!
+//! let provenance = Provenance::new()
+//!     .with_source_profile("sRGB IEC61966-2.1")
+//!     .with_rendering_intent(RenderingIntent::RelativeColorimetric);
+//! let lch = Tagged::new(Lch::new(60.0, 120.0, 140.0), provenance);
+//!
+//! // Saturated green is outside the sRGB gamut, so it's clipped.
+//! let srgb = Tagged::<Srgb>::from_color(lch);
+//!
+//! assert!(srgb.provenance.clipped);
+//! assert_eq!(srgb.provenance.source_profile.as_deref(), Some("sRGB IEC61966-2.1"));
+//! assert!(srgb.green <= 1.0);
+//! ```
+//!
+//! This module is only available if the `std` feature is enabled (this is the
+//! default).
+
+use core::ops::{Deref, DerefMut};
+
+use crate::convert::{FromColorUnclamped, IntoColorUnclamped};
+use crate::{Clamp, IsWithinBounds};
+
+/// The rendering intent that was used when a color was mapped between
+/// color spaces, as defined by the ICC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub enum RenderingIntent {
+    /// Compress the whole gamut to preserve the relations between colors.
+    Perceptual,
+
+    /// Keep the in-gamut colors, relative to the white point, and clip the
+    /// rest.
+    RelativeColorimetric,
+
+    /// Preserve the saturation, at the expense of hue and lightness.
+    Saturation,
+
+    /// Keep the in-gamut colors without adapting the white point, and clip
+    /// the rest.
+    AbsoluteColorimetric,
+}
+
+/// Metadata about where a color came from and what has been done to it.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct Provenance {
+    /// The name of the profile or color space the color originally came
+    /// from.
+    pub source_profile: Option<String>,
+
+    /// The rendering intent that was applied, if any.
+    pub rendering_intent: Option<RenderingIntent>,
+
+    /// `true` if the color has been clipped to fit a gamut at some point.
+    pub clipped: bool,
+}
+
+impl Provenance {
+    /// Create an empty provenance record.
+    pub fn new() -> Self {
+        Provenance::default()
+    }
+
+    /// Set the name of the source profile.
+    pub fn with_source_profile<S: Into<String>>(mut self, source_profile: S) -> Self {
+        self.source_profile = Some(source_profile.into());
+        self
+    }
+
+    /// Set the applied rendering intent.
+    pub fn with_rendering_intent(mut self, rendering_intent: RenderingIntent) -> Self {
+        self.rendering_intent = Some(rendering_intent);
+        self
+    }
+}
+
+/// A color with a [`Provenance`] record.
+///
+/// Converting with [`FromColorUnclamped`] keeps the record as it is, while
+/// [`Clamp`], and by extension [`FromColor`](crate::FromColor), marks the
+/// record as clipped if the color was out of bounds.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct Tagged<C> {
+    /// The color.
+    pub color: C,
+
+    /// The metadata about the color.
+    pub provenance: Provenance,
+}
+
+impl<C> Tagged<C> {
+    /// Wrap a color together with its provenance.
+    pub fn new(color: C, provenance: Provenance) -> Self {
+        Tagged { color, provenance }
+    }
+
+    /// Set the applied rendering intent.
+    pub fn with_rendering_intent(mut self, rendering_intent: RenderingIntent) -> Self {
+        self.provenance.rendering_intent = Some(rendering_intent);
+        self
+    }
+
+    /// Split the wrapper into the color and its provenance.
+    pub fn split(self) -> (C, Provenance) {
+        (self.color, self.provenance)
+    }
+
+    /// Remove the provenance and return the color.
+    pub fn into_color(self) -> C {
+        self.color
+    }
+}
+
+impl<C1, C2> FromColorUnclamped<Tagged<C1>> for Tagged<C2>
+where
+    C1: IntoColorUnclamped<C2>,
+{
+    fn from_color_unclamped(other: Tagged<C1>) -> Self {
+        Tagged {
+            color: other.color.into_color_unclamped(),
+            provenance: other.provenance,
+        }
+    }
+}
+
+impl<C> Clamp for Tagged<C>
+where
+    C: Clamp + IsWithinBounds,
+{
+    fn clamp(mut self) -> Self {
+        if !self.color.is_within_bounds() {
+            self.color = self.color.clamp();
+            self.provenance.clipped = true;
+        }
+
+        self
+    }
+}
+
+impl<C: IsWithinBounds> IsWithinBounds for Tagged<C> {
+    fn is_within_bounds(&self) -> bool {
+        self.color.is_within_bounds()
+    }
+}
+
+impl<C> From<C> for Tagged<C> {
+    fn from(color: C) -> Self {
+        Tagged::new(color, Provenance::new())
+    }
+}
+
+impl<C> Deref for Tagged<C> {
+    type Target = C;
+
+    fn deref(&self) -> &C {
+        &self.color
+    }
+}
+
+impl<C> DerefMut for Tagged<C> {
+    fn deref_mut(&mut self) -> &mut C {
+        &mut self.color
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Provenance, RenderingIntent, Tagged};
+    use crate::convert::FromColorUnclamped;
+    use crate::{Clamp, FromColor, Hsv, Srgb};
+
+    #[test]
+    fn keeps_provenance() {
+        let provenance = Provenance::new()
+            .with_source_profile("Display P3")
+            .with_rendering_intent(RenderingIntent::Perceptual);
+        let color = Tagged::new(Srgb::new(0.8f32, 0.3, 0.1), provenance.clone());
+
+        let hsv = Tagged::<Hsv>::from_color_unclamped(color.clone());
+        let back = Tagged::<Srgb>::from_color(hsv);
+
+        assert_eq!(back.provenance, provenance);
+        assert_relative_eq!(back.color, color.color, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn clipping() {
+        let inside = Tagged::from(Srgb::new(0.5f32, 0.5, 0.5)).clamp();
+        assert!(!inside.provenance.clipped);
+
+        let outside = Tagged::from(Srgb::new(1.5f32, 0.5, -0.5)).clamp();
+        assert!(outside.provenance.clipped);
+        assert_eq!(outside.color, Srgb::new(1.0, 0.5, 0.0));
+
+        // The flag stays set after later conversions.
+        let hsv = Tagged::<Hsv>::from_color(outside);
+        assert!(hsv.provenance.clipped);
+    }
+
+    #[test]
+    fn deref() {
+        let mut color = Tagged::from(Srgb::new(0.1f32, 0.2, 0.3));
+        color.red = 0.4;
+
+        assert_eq!(color.red, 0.4);
+        assert_eq!(*color, Srgb::new(0.4, 0.2, 0.3));
+    }
+}
@@ -0,0 +1,767 @@
+//! Color quantization with streaming input.
+//!
+//! [`Quantizer`] finds a small palette of colors that represents a larger
+//! set of pixels, using k-means clustering. The pixels are consumed from an
+//! iterator, and only a bounded number of them are kept in memory at any
+//! time, so it works for very large images and video streams, where the
+//! pixels are decoded on the fly. An existing palette can also be refined
+//! with [`Quantizer::update`] as more frames arrive.
+//!
+//! The clustering is done on the raw components of the color type, so a
+//! perceptually uniform space, such as [`Oklab`](crate::Oklab) or
+//! [`Lab`](crate::Lab), gives the best results.
+//!
+//! ```
+//! use palette::quantize::{Quantizer, Sampling};
+//! use palette::Oklab;
+//!
+//! // Pretend this is a huge image that's decoded row by row.
+//! let pixels = (0..100_000).map(|i| {
+//!     if i % 2 == 0 {
+//!         Oklab::new(0.3, 0.1, 0.0)
+//!     } else {
+//!         Oklab::new(0.8, -0.1, 0.05)
+//!     }
+//! });
+//!
+//! let mut quantizer = Quantizer::new(2).with_sampling(Sampling::MiniBatch { batch_size: 1024 });
+//! quantizer.fit(pixels);
+//!
+//! assert_eq!(quantizer.colors().len(), 2);
+//! let index = quantizer.nearest(Oklab::new(0.75, -0.1, 0.05)).unwrap();
+//! assert_eq!(quantizer.colors()[index], Oklab::new(0.8, -0.1, 0.05));
+//! ```
+//!
+//! Quantized and extracted palettes often have several colors that look the
+//! same. [`dedup_by_distance`] merges them into one color each.
+//! [`Quantizer::weighted_palette`] keeps track of how many pixels each color
+//! represents, as a [`WeightedPalette`].
+//!
+//! This module is only available if the `std` feature is enabled (this is the
+//! default).
+
+use crate::cast::{self, ArrayCast};
+use crate::convert::{FromColor, IntoColor};
+use crate::weighted_palette::WeightedPalette;
+use crate::{from_f64, DeltaE, DeltaEFormula, FloatComponent, Lab};
+
+const DEFAULT_SEED: u64 = 0x853c_49e6_748f_ea9b;
+
+/// How the pixels are sampled while fitting a [`Quantizer`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Sampling {
+    /// Pick up to `size` pixels at random from the whole input, using
+    /// reservoir sampling, and cluster them with k-means.
+    ///
+    /// This gives a palette that represents the whole input equally well,
+    /// and uses memory for `size` colors.
+    Reservoir {
+        /// The maximum number of sampled pixels.
+        size: usize,
+    },
+
+    /// Process the pixels in batches of `batch_size`, and gradually move
+    /// the palette colors towards the pixels in each batch. The first batch
+    /// is used for choosing the initial colors.
+    ///
+    /// This is the mini-batch k-means algorithm. It uses memory for
+    /// `batch_size` colors and makes a single pass over the input, which
+    /// makes it suitable for endless streams.
+    MiniBatch {
+        /// The number of pixels in each batch.
+        batch_size: usize,
+    },
+}
+
+impl Default for Sampling {
+    fn default() -> Self {
+        Sampling::Reservoir { size: 65536 }
+    }
+}
+
+/// Finds a palette of up to a fixed number of colors for a stream of
+/// pixels.
+///
+/// See the [module documentation](crate::quantize) for an example.
+#[derive(Clone, Debug)]
+pub struct Quantizer<C> {
+    max_colors: usize,
+    sampling: Sampling,
+    iterations: usize,
+    seed: u64,
+    history: u64,
+    rng: Rng,
+    colors: Vec<C>,
+    counts: Vec<u64>,
+}
+
+impl<C> Quantizer<C> {
+    /// Create a quantizer that will find up to `max_colors` colors.
+    ///
+    /// It uses [`Sampling::default()`], 16 k-means iterations and a fixed
+    /// random seed, so the results are repeatable.
+    pub fn new(max_colors: usize) -> Self {
+        Quantizer {
+            max_colors,
+            sampling: Sampling::default(),
+            iterations: 16,
+            seed: DEFAULT_SEED,
+            history: u64::MAX,
+            rng: Rng::new(DEFAULT_SEED),
+            colors: Vec::new(),
+            counts: Vec::new(),
+        }
+    }
+
+    /// Change how the pixels are sampled.
+    pub fn with_sampling(mut self, sampling: Sampling) -> Self {
+        self.sampling = sampling;
+        self
+    }
+
+    /// Change the number of k-means iterations that are used with
+    /// [`Sampling::Reservoir`].
+    pub fn with_iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Change the seed for the random sampling and initialization.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self.rng = Rng::new(seed);
+        self
+    }
+
+    /// Limit how many pixels each palette color remembers in
+    /// [`update`](Quantizer::update).
+    ///
+    /// The colors move less for each pixel they have already seen, so a
+    /// palette that has been updated with many frames will eventually stop
+    /// changing. Limiting the history keeps it responsive to changes in the
+    /// input, such as scene changes in a video. The default is no limit.
+    pub fn with_history(mut self, pixels: u64) -> Self {
+        self.history = pixels.max(1);
+        self
+    }
+
+    /// The current palette colors.
+    ///
+    /// There may be fewer than the maximum number of colors, if the input
+    /// didn't contain enough distinct colors.
+    pub fn colors(&self) -> &[C] {
+        &self.colors
+    }
+
+    /// Take the palette colors out of the quantizer.
+    pub fn into_colors(self) -> Vec<C> {
+        self.colors
+    }
+}
+
+impl<C, T, const N: usize> Quantizer<C>
+where
+    C: ArrayCast<Array = [T; N]> + Copy,
+    T: FloatComponent,
+{
+    /// Find a new palette for `pixels`, replacing the current one.
+    pub fn fit<I>(&mut self, pixels: I)
+    where
+        I: IntoIterator<Item = C>,
+    {
+        self.colors.clear();
+        self.counts.clear();
+        self.rng = Rng::new(self.seed);
+
+        if self.max_colors == 0 {
+            return;
+        }
+
+        match self.sampling {
+            Sampling::Reservoir { size } => {
+                let sample = reservoir_sample(pixels, size.max(1), &mut self.rng);
+                choose_colors(&mut self.colors, &sample, self.max_colors, &mut self.rng);
+                self.counts = lloyd(&mut self.colors, &sample, self.iterations);
+            }
+            Sampling::MiniBatch { .. } => self.update(pixels),
+        }
+    }
+
+    /// Refine the current palette with more pixels, such as the next frame
+    /// of a video.
+    ///
+    /// The palette colors are gradually moved towards the new pixels, using
+    /// mini-batch k-means, and keep their indices. New colors are only
+    /// added at the end, if the palette has fewer than the maximum number
+    /// of colors, so pixels that have already been mapped to the palette
+    /// keep pointing to similar colors. The pixels are processed in batches
+    /// of the [`Sampling`] size.
+    ///
+    /// ```
+    /// use palette::quantize::Quantizer;
+    /// use palette::Oklab;
+    ///
+    /// let dark = Oklab::new(0.2, 0.0, 0.0);
+    /// let light = Oklab::new(0.9, 0.0, 0.0);
+    ///
+    /// let mut quantizer = Quantizer::new(2);
+    /// quantizer.fit(vec![dark; 100]);
+    /// let dark_index = quantizer.nearest(dark).unwrap();
+    ///
+    /// quantizer.update(vec![dark, light].into_iter().cycle().take(200));
+    /// assert_eq!(quantizer.colors().len(), 2);
+    /// assert_eq!(quantizer.nearest(dark), Some(dark_index));
+    /// ```
+    pub fn update<I>(&mut self, pixels: I)
+    where
+        I: IntoIterator<Item = C>,
+    {
+        if self.max_colors == 0 {
+            return;
+        }
+
+        let batch_size = match self.sampling {
+            Sampling::Reservoir { size } => size,
+            Sampling::MiniBatch { batch_size } => batch_size,
+        }
+        .max(1);
+
+        let mut pixels = pixels.into_iter();
+        let mut batch = Vec::with_capacity(batch_size);
+
+        loop {
+            batch.clear();
+            batch.extend(pixels.by_ref().take(batch_size));
+
+            if batch.is_empty() {
+                break;
+            }
+
+            if self.colors.len() < self.max_colors {
+                choose_colors(&mut self.colors, &batch, self.max_colors, &mut self.rng);
+                self.counts.resize(self.colors.len(), 0);
+            }
+
+            mini_batch_step(&mut self.colors, &mut self.counts, &batch, self.history);
+        }
+    }
+
+    /// Find the index of the palette color that is closest to `color`, or
+    /// `None` if the palette is empty.
+    pub fn nearest(&self, color: C) -> Option<usize> {
+        nearest(&self.colors, &color).map(|(index, _)| index)
+    }
+
+    /// The current palette colors, weighted by the number of pixels each of
+    /// them represents.
+    ///
+    /// ```
+    /// use palette::quantize::Quantizer;
+    /// use palette::Oklab;
+    ///
+    /// let dark = Oklab::new(0.25, 0.0, 0.0);
+    /// let light = Oklab::new(0.75, 0.0, 0.0);
+    ///
+    /// let mut quantizer = Quantizer::new(2);
+    /// quantizer.fit(vec![dark, dark, dark, light]);
+    ///
+    /// let mut palette = quantizer.weighted_palette();
+    /// palette.normalize();
+    /// palette.sort_by_weight();
+    /// assert_eq!(palette[0].color, dark);
+    /// assert_eq!(palette[0].weight, 0.75);
+    /// ```
+    pub fn weighted_palette(&self) -> WeightedPalette<C, T> {
+        self.colors
+            .iter()
+            .zip(&self.counts)
+            .map(|(&color, &count)| (color, from_f64(count as f64)))
+            .collect()
+    }
+}
+
+/// Merge the colors that are within `threshold` of each other, as measured
+/// by `formula` in [`Lab`].
+///
+/// The colors are visited in order, and each color is merged into the first
+/// group whose average is within the threshold, or starts a new group. The
+/// merged colors are the averages of their groups in `Lab`, with every member
+/// weighted equally, so merging a third color into a group of two only moves
+/// it a third of the way. Colors that aren't merged with anything are
+/// returned unchanged, and the groups keep the order of their first members.
+///
+/// ```
+/// use palette::quantize::dedup_by_distance;
+/// use palette::{DeltaEFormula, Srgb};
+///
+/// let colors = [
+///     Srgb::new(0.8f32, 0.2, 0.2),
+///     Srgb::new(0.1, 0.1, 0.6),
+///     Srgb::new(0.805, 0.2, 0.2),
+/// ];
+///
+/// let deduplicated = dedup_by_distance(&colors, 1.0, DeltaEFormula::Ciede2000);
+/// assert_eq!(deduplicated.len(), 2);
+/// assert_eq!(deduplicated[1], colors[1]);
+/// ```
+pub fn dedup_by_distance<C, Wp, T>(colors: &[C], threshold: T, formula: DeltaEFormula<T>) -> Vec<C>
+where
+    C: IntoColor<Lab<Wp, T>> + FromColor<Lab<Wp, T>> + Copy,
+    T: FloatComponent,
+{
+    struct Group<C, Wp, T> {
+        first: C,
+        average: Lab<Wp, T>,
+        count: usize,
+    }
+
+    let mut groups: Vec<Group<C, Wp, T>> = Vec::new();
+
+    for &color in colors {
+        let lab: Lab<Wp, T> = color.into_color();
+        let group = groups
+            .iter_mut()
+            .find(|group| group.average.delta_e(lab, formula) <= threshold);
+
+        if let Some(group) = group {
+            group.count += 1;
+            let weight = T::one() / from_f64(group.count as f64);
+            group.average = group.average + (lab - group.average) * weight;
+        } else {
+            groups.push(Group {
+                first: color,
+                average: lab,
+                count: 1,
+            });
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|group| {
+            if group.count == 1 {
+                group.first
+            } else {
+                C::from_color(group.average)
+            }
+        })
+        .collect()
+}
+
+/// Pick up to `size` items from `pixels`, with equal probability.
+fn reservoir_sample<C, I>(pixels: I, size: usize, rng: &mut Rng) -> Vec<C>
+where
+    I: IntoIterator<Item = C>,
+{
+    let mut sample = Vec::new();
+
+    for (seen, pixel) in pixels.into_iter().enumerate() {
+        if seen < size {
+            sample.push(pixel);
+        } else {
+            let index = rng.below(seen as u64 + 1) as usize;
+            if let Some(slot) = sample.get_mut(index) {
+                *slot = pixel;
+            }
+        }
+    }
+
+    sample
+}
+
+/// Add colors from `pixels` to `colors`, with k-means++, until there are
+/// `max_colors` of them.
+fn choose_colors<C, T, const N: usize>(
+    colors: &mut Vec<C>,
+    pixels: &[C],
+    max_colors: usize,
+    rng: &mut Rng,
+) where
+    C: ArrayCast<Array = [T; N]> + Copy,
+    T: FloatComponent,
+{
+    if pixels.is_empty() {
+        return;
+    }
+
+    if colors.is_empty() {
+        colors.push(pixels[rng.below(pixels.len() as u64) as usize]);
+    }
+
+    let mut distances: Vec<T> = pixels
+        .iter()
+        .map(|pixel| {
+            nearest(colors, pixel)
+                .map(|(_, distance)| distance)
+                .unwrap_or_else(T::zero)
+        })
+        .collect();
+
+    while colors.len() < max_colors {
+        let total = distances
+            .iter()
+            .fold(T::zero(), |sum, &distance| sum + distance);
+        if total.is_nan() || total <= T::zero() {
+            // All remaining pixels are already in the palette.
+            break;
+        }
+
+        let mut target = from_f64::<T>(rng.next_f64()) * total;
+        let mut chosen = pixels.len() - 1;
+        for (index, &distance) in distances.iter().enumerate() {
+            if distance > T::zero() {
+                chosen = index;
+                if target < distance {
+                    break;
+                }
+                target = target - distance;
+            }
+        }
+
+        let color = pixels[chosen];
+        colors.push(color);
+
+        for (distance, pixel) in distances.iter_mut().zip(pixels) {
+            let new_distance = distance_squared(pixel, &color);
+            if new_distance < *distance {
+                *distance = new_distance;
+            }
+        }
+    }
+}
+
+/// Run `iterations` rounds of Lloyd's algorithm and return the number of
+/// pixels that belong to each color.
+fn lloyd<C, T, const N: usize>(colors: &mut [C], pixels: &[C], iterations: usize) -> Vec<u64>
+where
+    C: ArrayCast<Array = [T; N]> + Copy,
+    T: FloatComponent,
+{
+    let mut counts = vec![0; colors.len()];
+    let mut sums = vec![[T::zero(); N]; colors.len()];
+
+    for _ in 0..iterations {
+        counts.fill(0);
+        sums.fill([T::zero(); N]);
+
+        for pixel in pixels {
+            if let Some((index, _)) = nearest(colors, pixel) {
+                counts[index] += 1;
+                for (sum, &component) in sums[index].iter_mut().zip(cast::into_array_ref(pixel)) {
+                    *sum = *sum + component;
+                }
+            }
+        }
+
+        let mut changed = false;
+        for ((color, sum), &count) in colors.iter_mut().zip(&sums).zip(&counts) {
+            // Empty clusters keep their color.
+            if count == 0 {
+                continue;
+            }
+
+            let count = from_f64::<T>(count as f64);
+            for (component, &sum) in cast::into_array_mut(color).iter_mut().zip(sum) {
+                let mean = sum / count;
+                changed |= mean != *component;
+                *component = mean;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    counts
+}
+
+/// Move each color towards the pixels that are closest to it, with a
+/// learning rate that decreases with the number of pixels it has seen, up to
+/// `history`.
+fn mini_batch_step<C, T, const N: usize>(
+    colors: &mut [C],
+    counts: &mut [u64],
+    pixels: &[C],
+    history: u64,
+) where
+    C: ArrayCast<Array = [T; N]> + Copy,
+    T: FloatComponent,
+{
+    for pixel in pixels {
+        if let Some((index, _)) = nearest(colors, pixel) {
+            counts[index] = counts[index].saturating_add(1).min(history);
+            let rate = from_f64::<T>(1.0 / counts[index] as f64);
+
+            let color = cast::into_array_mut(&mut colors[index]);
+            for (component, &target) in color.iter_mut().zip(cast::into_array_ref(pixel)) {
+                *component = *component + (target - *component) * rate;
+            }
+        }
+    }
+}
+
+fn nearest<C, T, const N: usize>(colors: &[C], color: &C) -> Option<(usize, T)>
+where
+    C: ArrayCast<Array = [T; N]>,
+    T: FloatComponent,
+{
+    let mut result: Option<(usize, T)> = None;
+
+    for (index, candidate) in colors.iter().enumerate() {
+        let distance = distance_squared(candidate, color);
+        let is_closer = match result {
+            Some((_, min_distance)) => distance < min_distance,
+            None => true,
+        };
+
+        if is_closer {
+            result = Some((index, distance));
+        }
+    }
+
+    result
+}
+
+fn distance_squared<C, T, const N: usize>(a: &C, b: &C) -> T
+where
+    C: ArrayCast<Array = [T; N]>,
+    T: FloatComponent,
+{
+    cast::into_array_ref(a)
+        .iter()
+        .zip(cast::into_array_ref(b))
+        .fold(T::zero(), |sum, (&a, &b)| {
+            let difference = a - b;
+            sum + difference * difference
+        })
+}
+
+/// A small xorshift64* generator, to avoid depending on `rand`.
+#[derive(Clone, Debug)]
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        // Mix the seed with a splitmix64 step, so that similar seeds give
+        // unrelated streams. The state must not be zero.
+        let mut state = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        state = (state ^ (state >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        state = (state ^ (state >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        state ^= state >> 31;
+
+        Rng(if state == 0 { 1 } else { state })
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// A number in `[0.0, 1.0)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// A number in `[0, bound)`. `bound` must not be zero.
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{dedup_by_distance, Quantizer, Sampling};
+    use crate::white_point::D65;
+    use crate::{DeltaEFormula, FromColor, Lab, Oklab, Srgb};
+
+    fn clusters() -> impl Iterator<Item = Srgb<f32>> {
+        let centers = [
+            Srgb::new(0.1, 0.1, 0.1),
+            Srgb::new(0.9, 0.2, 0.2),
+            Srgb::new(0.2, 0.3, 0.9),
+        ];
+
+        (0..30_000).map(move |i| {
+            let center = centers[i % 3];
+            let offset = ((i / 3) % 11) as f32 * 0.002 - 0.01;
+            Srgb::new(center.red + offset, center.green - offset, center.blue)
+        })
+    }
+
+    fn assert_finds_clusters(quantizer: &Quantizer<Srgb<f32>>) {
+        assert_eq!(quantizer.colors().len(), 3);
+
+        let dark = quantizer.nearest(Srgb::new(0.1, 0.1, 0.1)).unwrap();
+        let red = quantizer.nearest(Srgb::new(0.9, 0.2, 0.2)).unwrap();
+        let blue = quantizer.nearest(Srgb::new(0.2, 0.3, 0.9)).unwrap();
+
+        assert_relative_eq!(
+            quantizer.colors()[dark],
+            Srgb::new(0.1, 0.1, 0.1),
+            epsilon = 0.01
+        );
+        assert_relative_eq!(
+            quantizer.colors()[red],
+            Srgb::new(0.9, 0.2, 0.2),
+            epsilon = 0.01
+        );
+        assert_relative_eq!(
+            quantizer.colors()[blue],
+            Srgb::new(0.2, 0.3, 0.9),
+            epsilon = 0.01
+        );
+    }
+
+    #[test]
+    fn reservoir() {
+        let mut quantizer = Quantizer::new(3).with_sampling(Sampling::Reservoir { size: 500 });
+        quantizer.fit(clusters());
+        assert_finds_clusters(&quantizer);
+    }
+
+    #[test]
+    fn mini_batch() {
+        let mut quantizer =
+            Quantizer::new(3).with_sampling(Sampling::MiniBatch { batch_size: 256 });
+        quantizer.fit(clusters());
+        assert_finds_clusters(&quantizer);
+    }
+
+    #[test]
+    fn repeatable() {
+        let mut quantizer1 = Quantizer::new(3).with_seed(7);
+        let mut quantizer2 = Quantizer::new(3).with_seed(7);
+        quantizer1.fit(clusters());
+        quantizer2.fit(clusters());
+
+        assert_eq!(quantizer1.colors(), quantizer2.colors());
+    }
+
+    #[test]
+    fn different_seeds() {
+        let pixels = || (0..1000).map(|i| Oklab::new(i as f32 / 1000.0, 0.0, 0.0));
+        let quantize = |seed| {
+            let mut quantizer = Quantizer::new(4)
+                .with_seed(seed)
+                .with_sampling(Sampling::Reservoir { size: 20 });
+            quantizer.fit(pixels());
+            quantizer.colors().to_vec()
+        };
+
+        assert_ne!(quantize(0), quantize(1));
+        assert_eq!(quantize(1), quantize(1));
+    }
+
+    #[test]
+    fn few_distinct_colors() {
+        let pixels = vec![Oklab::new(0.5, 0.0, 0.0); 100];
+
+        let mut quantizer = Quantizer::new(8);
+        quantizer.fit(pixels.iter().copied());
+        assert_eq!(quantizer.colors(), &[Oklab::new(0.5, 0.0, 0.0)]);
+
+        let mut quantizer = Quantizer::new(8).with_sampling(Sampling::MiniBatch { batch_size: 16 });
+        quantizer.fit(pixels);
+        assert_eq!(quantizer.colors(), &[Oklab::new(0.5, 0.0, 0.0)]);
+    }
+
+    #[test]
+    fn empty() {
+        let mut quantizer = Quantizer::<Oklab>::new(8);
+        quantizer.fit(None);
+        assert!(quantizer.colors().is_empty());
+        assert_eq!(quantizer.nearest(Oklab::new(0.5, 0.0, 0.0)), None);
+    }
+
+    #[test]
+    fn update_keeps_indices() {
+        let mut quantizer =
+            Quantizer::new(3).with_sampling(Sampling::MiniBatch { batch_size: 256 });
+        quantizer.fit(clusters());
+
+        let dark = quantizer.nearest(Srgb::new(0.1, 0.1, 0.1)).unwrap();
+        let red = quantizer.nearest(Srgb::new(0.9, 0.2, 0.2)).unwrap();
+        let blue = quantizer.nearest(Srgb::new(0.2, 0.3, 0.9)).unwrap();
+
+        // The next frame is slightly brighter.
+        quantizer.update(
+            clusters()
+                .map(|color| Srgb::new(color.red + 0.02, color.green + 0.02, color.blue + 0.02)),
+        );
+
+        assert_eq!(quantizer.colors().len(), 3);
+        assert_eq!(quantizer.nearest(Srgb::new(0.1, 0.1, 0.1)), Some(dark));
+        assert_eq!(quantizer.nearest(Srgb::new(0.9, 0.2, 0.2)), Some(red));
+        assert_eq!(quantizer.nearest(Srgb::new(0.2, 0.3, 0.9)), Some(blue));
+        assert!(quantizer.colors()[dark].red > 0.105);
+    }
+
+    #[test]
+    fn update_adds_colors() {
+        let dark = Oklab::new(0.2, 0.0, 0.0);
+        let light = Oklab::new(0.9, 0.0, 0.0);
+
+        let mut quantizer = Quantizer::new(2);
+        quantizer.update(vec![dark; 10]);
+        assert_eq!(quantizer.colors(), &[dark]);
+
+        quantizer.update(vec![light; 10]);
+        assert_eq!(quantizer.colors(), &[dark, light]);
+
+        // The palette is full, so the closest color moves instead.
+        quantizer.update(vec![Oklab::new(0.5, 0.0, 0.0); 10]);
+        assert_eq!(quantizer.colors().len(), 2);
+        assert!(quantizer.colors()[0].l > 0.2);
+    }
+
+    #[test]
+    fn history() {
+        let start = vec![Oklab::new(0.2, 0.0, 0.0); 1000];
+        let target = vec![Oklab::new(0.4, 0.0, 0.0); 100];
+
+        let mut unlimited = Quantizer::new(1);
+        unlimited.update(start.iter().copied());
+        unlimited.update(target.iter().copied());
+
+        let mut limited = Quantizer::new(1).with_history(100);
+        limited.update(start);
+        limited.update(target);
+
+        assert!(unlimited.colors()[0].l < 0.22);
+        assert!(limited.colors()[0].l > 0.25);
+    }
+
+    #[test]
+    fn dedup() {
+        let colors = [
+            Lab::<D65, f64>::new(50.0, 10.0, 10.0),
+            Lab::new(80.0, 0.0, 0.0),
+            Lab::new(50.3, 10.0, 10.0),
+            Lab::new(50.6, 10.0, 10.0),
+            Lab::new(20.0, -30.0, 5.0),
+        ];
+
+        let deduplicated = dedup_by_distance(&colors, 1.0, DeltaEFormula::Cie76);
+        assert_eq!(deduplicated.len(), 3);
+        assert_relative_eq!(deduplicated[0], Lab::new(50.3, 10.0, 10.0), epsilon = 1e-9);
+        assert_eq!(deduplicated[1], colors[1]);
+        assert_eq!(deduplicated[2], colors[4]);
+    }
+
+    #[test]
+    fn dedup_srgb() {
+        let colors = [Srgb::new(0.5f64, 0.5, 0.5), Srgb::new(0.502, 0.5, 0.5)];
+
+        let deduplicated = dedup_by_distance::<_, D65, _>(&colors, 1.0, DeltaEFormula::Ciede2000);
+        assert_eq!(deduplicated.len(), 1);
+
+        let average = Lab::<D65, f64>::from_color(colors[0])
+            + (Lab::from_color(colors[1]) - Lab::from_color(colors[0])) * 0.5;
+        assert_relative_eq!(deduplicated[0], Srgb::from_color(average), epsilon = 1e-9);
+
+        assert!(dedup_by_distance::<Srgb<f64>, D65, _>(&[], 1.0, DeltaEFormula::Cie76).is_empty());
+    }
+}
@@ -61,6 +61,7 @@
 //! flexibility.
 
 use crate::encoding::{self, Gamma, Linear, TransferFn};
+use crate::matrix::Mat3;
 use crate::white_point::{Any, WhitePoint};
 use crate::{Component, FloatComponent, FromComponent, Yxy};
 
@@ -121,6 +122,28 @@ pub trait RgbSpace<T>: 'static {
 
     /// The white point of the RGB color space.
     type WhitePoint: WhitePoint<T>;
+
+    /// A precomputed matrix for converting linear RGB to XYZ, if available.
+    ///
+    /// The matrix is calculated from the primaries and the white point when
+    /// this returns `None`, which is the default. Spaces that are used on
+    /// targets where that's too expensive, or where the result should be
+    /// stored in read-only memory, can return constants that were generated
+    /// ahead of time. See [`matrix::rgb_space_constants`](crate::matrix::rgb_space_constants) for a way to
+    /// generate them from a build script.
+    #[inline(always)]
+    fn rgb_to_xyz_matrix() -> Option<Mat3<T>> {
+        None
+    }
+
+    /// A precomputed matrix for converting XYZ to linear RGB, if available.
+    ///
+    /// The inverse of the RGB to XYZ matrix is calculated when this returns
+    /// `None`, which is the default.
+    #[inline(always)]
+    fn xyz_to_rgb_matrix() -> Option<Mat3<T>> {
+        None
+    }
 }
 
 impl<T, P, W> RgbSpace<T> for (P, W)
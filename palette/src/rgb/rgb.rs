@@ -19,16 +19,19 @@ use crate::blend::PreAlpha;
 use crate::cast::{ComponentOrder, Packed};
 use crate::convert::FromColorUnclamped;
 use crate::encoding::linear::LinearFn;
-use crate::encoding::{Linear, Srgb};
+use crate::encoding::{srgb, Linear, Srgb};
+use crate::float;
 use crate::luma::LumaStandard;
-use crate::matrix::{matrix_inverse, multiply_xyz_to_rgb, rgb_to_xyz_matrix};
+use crate::matrix::{multiply_xyz_to_rgb, xyz_to_rgb_matrix};
 use crate::rgb::{RgbSpace, RgbStandard, TransferFn};
 use crate::{
     clamp, clamp_assign, clamp_min_assign, contrast_ratio, from_f64, Blend, Clamp, ClampAssign,
     Component, ComponentWise, FloatComponent, FromComponent, GetHue, IsWithinBounds, Lighten,
     LightenAssign, Mix, MixAssign, RelativeContrast,
 };
-use crate::{Hsl, Hsv, Luma, RgbHue, Xyz};
+use crate::{Cmyk, Hsl, Hsv, Luma, RgbHue, Xyz};
+#[cfg(feature = "random")]
+use crate::{Rounding, SaturatingFromComponent};
 
 /// Generic RGB with an alpha component. See the [`Rgba` implementation in
 /// `Alpha`](crate::Alpha#Rgba).
@@ -51,7 +54,7 @@ pub type Rgba<S = Srgb, T = f32> = Alpha<Rgb<S, T>, T>;
     palette_internal,
     rgb_standard = "S",
     component = "T",
-    skip_derives(Xyz, Hsv, Hsl, Luma, Rgb)
+    skip_derives(Xyz, Hsv, Hsl, Luma, Rgb, Cmyk)
 )]
 #[repr(C)]
 pub struct Rgb<S = Srgb, T = f32> {
@@ -111,6 +114,38 @@ impl<S, T> Rgb<S, T> {
         }
     }
 
+    /// Convert into another component type, with
+    /// [stochastic rounding](crate::Rounding::Stochastic).
+    ///
+    /// Each component is rounded up or down at random, which hides the
+    /// banding in smooth gradients without the cost of full error diffusion:
+    ///
+    /// ```
+    /// use palette::Srgb;
+    /// use rand::Rng;
+    ///
+    /// fn quantize<R: Rng>(colors: &[Srgb], rng: &mut R) -> Vec<Srgb<u8>> {
+    ///     colors
+    ///         .iter()
+    ///         .map(|color| color.into_format_stochastic(rng))
+    ///         .collect()
+    /// }
+    /// ```
+    #[cfg(feature = "random")]
+    pub fn into_format_stochastic<U, R>(self, rng: &mut R) -> Rgb<S, U>
+    where
+        T: Component,
+        U: SaturatingFromComponent<T>,
+        R: Rng + ?Sized,
+    {
+        Rgb {
+            red: U::saturating_from_component(self.red, Rounding::Stochastic(rng.gen())),
+            green: U::saturating_from_component(self.green, Rounding::Stochastic(rng.gen())),
+            blue: U::saturating_from_component(self.blue, Rounding::Stochastic(rng.gen())),
+            standard: PhantomData,
+        }
+    }
+
     /// Convert from another component type.
     pub fn from_format<U>(color: Rgb<S, U>) -> Self
     where
@@ -177,6 +212,50 @@ where
 
 impl<S, T> Eq for Rgb<S, T> where T: Eq {}
 
+impl Rgb<Srgb, u8> {
+    /// Convert 8 bit sRGB to linear `f32` RGB, using a lookup table.
+    ///
+    /// This gives the same result as `color.into_format().into_linear()`,
+    /// but doesn't have to evaluate the transfer function, which makes it a
+    /// lot faster for converting large images.
+    ///
+    /// ```
+    /// use palette::{LinSrgb, Srgb};
+    ///
+    /// let linear: LinSrgb = Srgb::new(255u8, 128, 0).into_linear_lut();
+    /// assert!((linear.green - 0.2158605).abs() < 1e-6);
+    /// ```
+    #[inline]
+    pub fn into_linear_lut(self) -> Rgb<Linear<Srgb>, f32> {
+        Rgb::new(
+            srgb::u8_into_linear_f32(self.red),
+            srgb::u8_into_linear_f32(self.green),
+            srgb::u8_into_linear_f32(self.blue),
+        )
+    }
+
+    /// Convert linear `f32` RGB to 8 bit sRGB, using a lookup table.
+    ///
+    /// This rounds to the nearest 8 bit value, like
+    /// `Srgb::from_linear(color).into_format()`, and values outside of `[0.0,
+    /// 1.0]` are clamped.
+    ///
+    /// ```
+    /// use palette::{LinSrgb, Srgb};
+    ///
+    /// let srgb = Srgb::from_linear_lut(LinSrgb::new(1.0, 0.2158605, 0.0));
+    /// assert_eq!(srgb, Srgb::new(255u8, 128, 0));
+    /// ```
+    #[inline]
+    pub fn from_linear_lut(color: Rgb<Linear<Srgb>, f32>) -> Self {
+        Rgb::new(
+            srgb::linear_f32_into_u8(color.red),
+            srgb::linear_f32_into_u8(color.green),
+            srgb::linear_f32_into_u8(color.blue),
+        )
+    }
+}
+
 impl<S> Rgb<S, u8> {
     /// Convert to a packed `u32` with with specifiable component order.
     ///
@@ -324,6 +403,24 @@ impl<S, T, A> Alpha<Rgb<S, T>, A> {
         )
     }
 
+    /// Convert into another component type, with
+    /// [stochastic rounding](crate::Rounding::Stochastic) for all components,
+    /// including the alpha.
+    #[cfg(feature = "random")]
+    pub fn into_format_stochastic<U, B, R>(self, rng: &mut R) -> Alpha<Rgb<S, U>, B>
+    where
+        T: Component,
+        A: Component,
+        U: SaturatingFromComponent<T>,
+        B: SaturatingFromComponent<A>,
+        R: Rng + ?Sized,
+    {
+        Alpha {
+            color: self.color.into_format_stochastic(rng),
+            alpha: B::saturating_from_component(self.alpha, Rounding::Stochastic(rng.gen())),
+        }
+    }
+
     /// Convert from another component type.
     pub fn from_format<U, B>(color: Alpha<Rgb<S, U>, B>) -> Self
     where
@@ -351,6 +448,28 @@ impl<S, T, A> Alpha<Rgb<S, T>, A> {
     }
 }
 
+impl<A> Alpha<Rgb<Srgb, u8>, A> {
+    /// Convert 8 bit sRGB to linear `f32` RGB with transparency, using a
+    /// lookup table. The alpha component is not changed.
+    #[inline]
+    pub fn into_linear_lut(self) -> Alpha<Rgb<Linear<Srgb>, f32>, A> {
+        Alpha {
+            color: self.color.into_linear_lut(),
+            alpha: self.alpha,
+        }
+    }
+
+    /// Convert linear `f32` RGB to 8 bit sRGB with transparency, using a
+    /// lookup table. The alpha component is not changed.
+    #[inline]
+    pub fn from_linear_lut(color: Alpha<Rgb<Linear<Srgb>, f32>, A>) -> Self {
+        Alpha {
+            color: Rgb::from_linear_lut(color.color),
+            alpha: color.alpha,
+        }
+    }
+}
+
 impl<S> Rgba<S, u8> {
     /// Convert to a packed `u32` with with specifiable component order.
     ///
@@ -466,6 +585,7 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(rgb: Rgb<S2, T>) -> Self {
+        trace!(conversion rgb);
         let rgb_space1 = TypeId::of::<<S1::Space as RgbSpace<T>>::Primaries>();
         let rgb_space2 = TypeId::of::<<S2::Space as RgbSpace<T>>::Primaries>();
 
@@ -485,7 +605,9 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(color: Xyz<<S::Space as RgbSpace<T>>::WhitePoint, T>) -> Self {
-        let transform_matrix = matrix_inverse(&rgb_to_xyz_matrix::<S::Space, T>());
+        trace!(conversion color);
+        let transform_matrix = xyz_to_rgb_matrix::<S::Space, T>();
+        trace!(matrix "xyz_to_rgb");
         Self::from_linear(multiply_xyz_to_rgb(&transform_matrix, &color))
     }
 }
@@ -495,6 +617,7 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(hsl: Hsl<S, T>) -> Self {
+        trace!(conversion hsl);
         let c = (T::one() - (hsl.lightness * from_f64(2.0) - T::one()).abs()) * hsl.saturation;
         let h = hsl.hue.to_positive_degrees() / from_f64(60.0);
         let x = c * (T::one() - (h % from_f64(2.0) - T::one()).abs());
@@ -528,6 +651,7 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(hsv: Hsv<S, T>) -> Self {
+        trace!(conversion hsv);
         let c = hsv.value * hsv.saturation;
         let h = hsv.hue.to_positive_degrees() / from_f64(60.0);
         let x = c * (T::one() - (h % from_f64(2.0) - T::one()).abs());
@@ -556,6 +680,23 @@ where
     }
 }
 
+impl<S, T> FromColorUnclamped<Cmyk<S, T>> for Rgb<S, T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(cmyk: Cmyk<S, T>) -> Self {
+        trace!(conversion cmyk);
+        let white = T::one() - cmyk.black;
+
+        Rgb {
+            red: (T::one() - cmyk.cyan) * white,
+            green: (T::one() - cmyk.magenta) * white,
+            blue: (T::one() - cmyk.yellow) * white,
+            standard: PhantomData,
+        }
+    }
+}
+
 impl<S, St, T> FromColorUnclamped<Luma<St, T>> for Rgb<S, T>
 where
     S: RgbStandard<T>,
@@ -563,6 +704,7 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(color: Luma<St, T>) -> Self {
+        trace!(conversion color);
         let luma = color.into_linear();
 
         Self::from_linear(Rgb {
@@ -747,10 +889,10 @@ where
         if self.red == self.green && self.red == self.blue {
             None
         } else {
-            Some(RgbHue::from_radians(
-                (sqrt_3 * (self.green - self.blue))
-                    .atan2(self.red * from_f64(2.0) - self.green - self.blue),
-            ))
+            Some(RgbHue::from_radians(float::atan2(
+                sqrt_3 * (self.green - self.blue),
+                self.red * from_f64(2.0) - self.green - self.blue,
+            )))
         }
     }
 }
@@ -1065,6 +1207,9 @@ impl<S, T, A> From<Alpha<Rgb<S, T>, A>> for (T, T, T, A) {
     }
 }
 
+impl_tuple_ref_conversions!([S, T] [] Rgb<S, T>, (T, T, T) => (T, T, T));
+impl_tuple_ref_conversions!([S, T, A] [] Alpha<Rgb<S, T>, A>, (T, T, T, A) => (T, T, T, A));
+
 impl<S, T> AbsDiffEq for Rgb<S, T>
 where
     T: AbsDiffEq,
@@ -1123,6 +1268,7 @@ where
 }
 
 impl_array_casts!(Rgb<S, T>, [T; 3]);
+impl_component_access!(Rgb<S, T>, [T; 3]);
 
 impl<S, T> fmt::LowerHex for Rgb<S, T>
 where
@@ -1158,12 +1304,13 @@ where
     }
 }
 
-/// Error type for parsing a string of hexadecimal characters to an `Rgb` color.
+/// Error type for parsing a string of hexadecimal characters to an `Rgb` or
+/// `Luma` color.
 #[derive(Debug)]
 pub enum FromHexError {
     /// An error occurred while parsing the string into a valid integer.
     ParseIntError(ParseIntError),
-    /// The hex value was not in a valid 3 or 6 character format.
+    /// The hex value did not have a valid number of characters.
     HexFormatError(&'static str),
 }
 
@@ -1182,11 +1329,7 @@ impl core::fmt::Display for FromHexError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &*self {
             FromHexError::ParseIntError(e) => write!(f, "{}", e),
-            FromHexError::HexFormatError(s) => write!(
-                f,
-                "{}, please use format '#fff', 'fff', '#ffffff' or 'ffffff'.",
-                s
-            ),
+            FromHexError::HexFormatError(s) => write!(f, "{}.", s),
         }
     }
 }
@@ -1223,7 +1366,9 @@ impl<S> FromStr for Rgb<S, u8> {
                 let col: Rgb<S, u8> = Rgb::new(red, green, blue);
                 Ok(col)
             }
-            _ => Err("invalid hex code format".into()),
+            _ => Err("invalid hex code format, \
+                please use format '#fff', 'fff', '#ffffff' or 'ffffff'"
+                .into()),
         }
     }
 }
@@ -1606,6 +1751,46 @@ mod test {
         assert_eq!(c.unwrap(), Rgb::<Srgb, u8>::new(170, 187, 204));
     }
 
+    #[test]
+    fn component_access() {
+        let mut color = Rgb::<Srgb, f32>::new(0.1, 0.2, 0.3);
+        assert_eq!((color[0], color[1], color[2]), (0.1, 0.2, 0.3));
+
+        color[1] = 0.5;
+        assert_eq!(color.green, 0.5);
+
+        for component in &mut color {
+            *component *= 2.0;
+        }
+        let components: Vec<f32> = color.into_iter().copied().collect();
+        assert_eq!(components, [0.2, 1.0, 0.6]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn component_index_out_of_bounds() {
+        let color = Rgb::<Srgb, f32>::new(0.1, 0.2, 0.3);
+        let _ = color[3];
+    }
+
+    #[test]
+    fn reference_conversions() {
+        let array = [0.1f32, 0.2, 0.3];
+        let tuple = (0.1f32, 0.2, 0.3);
+        let color = Rgb::<Srgb, f32>::new(0.1, 0.2, 0.3);
+
+        assert_eq!(Rgb::<Srgb, f32>::from(&array), color);
+        assert_eq!(<[f32; 3]>::from(&color), array);
+        assert_eq!(Rgb::<Srgb, f32>::from(&tuple), color);
+        assert_eq!(<(f32, f32, f32)>::from(&color), tuple);
+
+        let alpha = Rgba::<Srgb, f32>::new(0.1, 0.2, 0.3, 0.4);
+        assert_eq!(Rgba::<Srgb, f32>::from(&[0.1, 0.2, 0.3, 0.4]), alpha);
+        assert_eq!(<[f32; 4]>::from(&alpha), [0.1, 0.2, 0.3, 0.4]);
+        assert_eq!(Rgba::<Srgb, f32>::from(&(0.1, 0.2, 0.3, 0.4)), alpha);
+        assert_eq!(<(f32, f32, f32, f32)>::from(&alpha), (0.1, 0.2, 0.3, 0.4));
+    }
+
     #[test]
     fn check_min_max_components() {
         assert_relative_eq!(Rgb::<Srgb, f32>::min_red(), 0.0);
@@ -1626,4 +1811,67 @@ mod test {
         min: Rgb::new(0.0f32, 0.0, 0.0),
         max: Rgb::new(1.0, 1.0, 1.0)
     }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn into_format_stochastic() {
+        let mut rng = rand_mt::Mt::new(1234); // We want the same seed on every run to avoid random fails
+        let color = Rgba::<Srgb, f32>::new(0.3, 0.5, 1.0, 0.0);
+
+        let mut sum = [0u32; 4];
+        for _ in 0..1000 {
+            let quantized: Rgba<Srgb, u8> = color.into_format_stochastic(&mut rng);
+            let (red, green, blue, alpha) = quantized.into_components();
+            assert!(red == 76 || red == 77);
+            assert!(green == 127 || green == 128);
+            assert_eq!(blue, 255);
+            assert_eq!(alpha, 0);
+
+            sum[0] += u32::from(red);
+            sum[1] += u32::from(green);
+        }
+
+        // The average is close to the unrounded value.
+        assert!((sum[0] as f32 / 1000.0 - 76.5).abs() < 0.1);
+        assert!((sum[1] as f32 / 1000.0 - 127.5).abs() < 0.1);
+    }
+
+    #[test]
+    fn linear_lut() {
+        for value in 0..=255u8 {
+            let color = Rgb::<Srgb, u8>::new(value, 255 - value, value / 2);
+            let linear = color.into_linear_lut();
+            assert_relative_eq!(
+                linear,
+                color.into_format::<f32>().into_linear(),
+                epsilon = 1e-6
+            );
+            assert_eq!(Rgb::from_linear_lut(linear), color);
+        }
+
+        for step in 0..=10000 {
+            let value = step as f32 / 10000.0;
+            let linear = crate::LinSrgb::new(value, value * value, 1.0 - value);
+            let expected: Rgb<Srgb, u8> = Rgb::from_linear(linear).into_format();
+            let lut = Rgb::<Srgb, u8>::from_linear_lut(linear);
+            for (lut, expected) in [lut.red, lut.green, lut.blue].iter().zip(&[
+                expected.red,
+                expected.green,
+                expected.blue,
+            ]) {
+                assert!((i16::from(*lut) - i16::from(*expected)).abs() <= 1);
+            }
+        }
+
+        let clamped = crate::Alpha::<Rgb<Srgb, u8>, f32>::from_linear_lut(crate::LinSrgba::new(
+            1.5,
+            -0.5,
+            f32::NAN,
+            0.5,
+        ));
+        assert_eq!(
+            clamped,
+            crate::Alpha::<Rgb<Srgb, u8>, f32>::new(255, 0, 0, 0.5)
+        );
+    }
 }
@@ -0,0 +1,236 @@
+//! Semantic color roles for design systems.
+//!
+//! A design system gives its colors roles, such as "primary" or "on
+//! primary", where the latter is meant to be drawn on top of the former.
+//! [`define_color_roles!`](crate::define_color_roles) generates a newtype for
+//! each role, so the compiler can tell them apart, and implements
+//! [`ColorRole`] for them. The trait has helpers for conversion and for
+//! checking the contrast between roles.
+//!
+//! ```
+//! use palette::roles::ColorRole;
+//! use palette::{define_color_roles, Srgb};
+//!
+//! define_color_roles! {
+//!     Srgb => {
+//!         /// The main brand color.
+//!         pub Primary,
+//!         /// Text and icons on top of `Primary`.
+//!         pub OnPrimary,
+//!         pub Surface,
+//!     }
+//! }
+//!
+//! let primary = Primary::new(Srgb::new(0.4, 0.2, 0.7));
+//! let on_primary = OnPrimary::new(Srgb::new(1.0, 1.0, 1.0));
+//!
+//! assert!(on_primary.has_min_contrast_text(&primary));
+//!
+//! // The roles dereference to the inner color.
+//! assert_eq!(primary.red, 0.4);
+//! ```
+
+use crate::convert::IntoColor;
+use crate::{from_f64, RelativeContrast};
+
+/// A strongly typed wrapper that gives a color a semantic role.
+///
+/// This is usually implemented with
+/// [`define_color_roles!`](crate::define_color_roles).
+pub trait ColorRole: Sized {
+    /// The type of the wrapped color.
+    type Color;
+
+    /// Give `color` this role.
+    fn new(color: Self::Color) -> Self;
+
+    /// Borrow the wrapped color.
+    fn color(&self) -> &Self::Color;
+
+    /// Remove the role and return the color.
+    fn into_inner(self) -> Self::Color;
+
+    /// Convert the wrapped color to another color type, for example for
+    /// rendering.
+    #[must_use]
+    #[inline]
+    fn convert<C>(self) -> C
+    where
+        Self::Color: IntoColor<C>,
+    {
+        self.into_inner().into_color()
+    }
+
+    /// Calculate the contrast ratio between this role and another role with
+    /// the same color type.
+    #[must_use]
+    #[inline]
+    fn contrast_ratio<R>(&self, other: &R) -> <Self::Color as RelativeContrast>::Scalar
+    where
+        R: ColorRole<Color = Self::Color>,
+        Self::Color: RelativeContrast + Clone,
+    {
+        self.color()
+            .clone()
+            .get_contrast_ratio(other.color().clone())
+    }
+
+    /// Check that the contrast ratio with another role is at least 4.5:1,
+    /// which is WCAG 2.1 level AA for text.
+    #[must_use]
+    #[inline]
+    fn has_min_contrast_text<R>(&self, other: &R) -> bool
+    where
+        R: ColorRole<Color = Self::Color>,
+        Self::Color: RelativeContrast + Clone,
+    {
+        self.contrast_ratio(other) >= from_f64(4.5)
+    }
+
+    /// Check that the contrast ratio with another role is at least 3:1,
+    /// which is WCAG 2.1 level AA for large text and graphics.
+    #[must_use]
+    #[inline]
+    fn has_min_contrast_large_text<R>(&self, other: &R) -> bool
+    where
+        R: ColorRole<Color = Self::Color>,
+        Self::Color: RelativeContrast + Clone,
+    {
+        self.contrast_ratio(other) >= from_f64(3.0)
+    }
+
+    /// Check that the contrast ratio with another role is at least 7:1,
+    /// which is WCAG 2.1 level AAA for text.
+    #[must_use]
+    #[inline]
+    fn has_enhanced_contrast_text<R>(&self, other: &R) -> bool
+    where
+        R: ColorRole<Color = Self::Color>,
+        Self::Color: RelativeContrast + Clone,
+    {
+        self.contrast_ratio(other) >= from_f64(7.0)
+    }
+}
+
+/// Define newtypes for color roles that wrap the same color type.
+///
+/// Each role becomes a tuple struct around the color type, with
+/// `#[repr(transparent)]`, and implements [`ColorRole`](crate::roles::ColorRole),
+/// `Deref` and `DerefMut` to the color, and `From` in both directions. The
+/// roles can have attributes, such as doc comments, and a visibility.
+///
+/// ```
+/// use palette::define_color_roles;
+/// use palette::roles::ColorRole;
+/// use palette::{Hsl, Srgb};
+///
+/// define_color_roles! {
+///     Srgb<f64> => {
+///         Background,
+///         #[derive(Default)]
+///         pub(crate) Accent,
+///     }
+/// }
+///
+/// let background = Background::from(Srgb::new(0.1, 0.1, 0.1));
+/// let hsl: Hsl<_, f64> = background.convert();
+///
+/// assert_eq!(Accent::default().into_inner(), Srgb::new(0.0, 0.0, 0.0));
+/// assert_eq!(hsl.lightness, 0.1);
+/// ```
+#[macro_export]
+macro_rules! define_color_roles {
+    ($color: ty => { $($(#[$attr: meta])* $vis: vis $role: ident),* $(,)? }) => {
+        $(
+            $(#[$attr])*
+            #[derive(Clone, Copy, Debug, PartialEq)]
+            #[repr(transparent)]
+            $vis struct $role(pub $color);
+
+            impl $crate::roles::ColorRole for $role {
+                type Color = $color;
+
+                #[inline]
+                fn new(color: $color) -> Self {
+                    $role(color)
+                }
+
+                #[inline]
+                fn color(&self) -> &$color {
+                    &self.0
+                }
+
+                #[inline]
+                fn into_inner(self) -> $color {
+                    self.0
+                }
+            }
+
+            impl ::core::convert::From<$color> for $role {
+                #[inline]
+                fn from(color: $color) -> Self {
+                    $role(color)
+                }
+            }
+
+            impl ::core::convert::From<$role> for $color {
+                #[inline]
+                fn from(role: $role) -> Self {
+                    role.0
+                }
+            }
+
+            impl ::core::ops::Deref for $role {
+                type Target = $color;
+
+                #[inline]
+                fn deref(&self) -> &$color {
+                    &self.0
+                }
+            }
+
+            impl ::core::ops::DerefMut for $role {
+                #[inline]
+                fn deref_mut(&mut self) -> &mut $color {
+                    &mut self.0
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::ColorRole;
+    use crate::{Lch, Srgb};
+
+    define_color_roles! {
+        Srgb<f64> => {
+            Primary,
+            OnPrimary,
+            Surface
+        }
+    }
+
+    #[test]
+    fn contrast() {
+        let primary = Primary::new(Srgb::new(0.0, 0.0, 0.0));
+        let on_primary = OnPrimary::new(Srgb::new(1.0, 1.0, 1.0));
+        let surface = Surface::new(Srgb::new(0.2, 0.2, 0.2));
+
+        assert_relative_eq!(on_primary.contrast_ratio(&primary), 21.0);
+        assert!(on_primary.has_enhanced_contrast_text(&primary));
+        assert!(!primary.has_min_contrast_large_text(&surface));
+        assert!(on_primary.has_min_contrast_text(&surface));
+    }
+
+    #[test]
+    fn conversion() {
+        let mut surface = Surface::from(Srgb::new(0.5, 0.5, 0.5));
+        surface.red = 0.6;
+
+        let lch: Lch<_, f64> = surface.convert();
+        assert!(lch.chroma > 0.0);
+        assert_eq!(Srgb::from(surface), Srgb::new(0.6, 0.5, 0.5));
+    }
+}
@@ -0,0 +1,334 @@
+//! Discrete color scales, for mapping data to a fixed number of colors.
+//!
+//! A [`DiscreteScale`] divides a range of data values into classes, and gives
+//! each class a color. This is how choropleth maps are usually colored, where
+//! the way the classes are chosen changes the story the map tells, so there
+//! are a few [`Classification`] methods to choose from.
+//!
+//! ```
+//! use palette::scale::{Classification, DiscreteScale};
+//! use palette::{Gradient, LinSrgb};
+//!
+//! let ramp = Gradient::new(vec![
+//!     LinSrgb::new(0.95, 0.95, 0.8),
+//!     LinSrgb::new(0.1, 0.3, 0.5),
+//! ]);
+//! let data = [1.0, 2.0, 2.5, 3.0, 10.0, 11.0, 12.0, 30.0, 31.0];
+//!
+//! let scale = DiscreteScale::new(&data, Classification::Jenks, ramp.take(3));
+//!
+//! assert_eq!(scale.breaks(), &[1.0, 3.0, 12.0, 31.0]);
+//! assert_eq!(scale.class_of(11.0), 1);
+//! assert_eq!(scale.get(30.5), &LinSrgb::new(0.1, 0.3, 0.5));
+//!
+//! for ((low, high), color) in scale.legend() {
+//!     println!("{} to {}: {:?}", low, high, color);
+//! }
+//! ```
+//!
+//! This module is only available if the `std` feature is enabled (this is the
+//! default).
+
+use core::cmp::Ordering;
+
+use crate::float::Float;
+use crate::{from_f64, FromF64};
+
+/// A method for dividing data into classes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub enum Classification {
+    /// Classes of equal width, between the smallest and largest value.
+    EqualInterval,
+
+    /// Classes with about the same number of values in each.
+    Quantile,
+
+    /// Jenks natural breaks, which minimizes the variance within each class.
+    ///
+    /// This takes `O(k·n²)` time for `n` values and `k` classes, so large
+    /// data sets may need to be sampled first.
+    Jenks,
+}
+
+/// A scale that maps data values to the colors of a fixed number of classes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiscreteScale<C, T> {
+    breaks: Vec<T>,
+    colors: Vec<C>,
+}
+
+impl<C, T> DiscreteScale<C, T>
+where
+    T: Float + FromF64,
+{
+    /// Classify `data` with `classification`, and make one class for each of
+    /// the colors in `colors`. A [`Gradient`](crate::Gradient) can be used as
+    /// a color ramp, with [`take`](crate::Gradient::take).
+    ///
+    /// NaN values in `data` are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `colors` is empty or if `data` has no values other than NaN.
+    pub fn new<I>(data: &[T], classification: Classification, colors: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+    {
+        let colors: Vec<C> = colors.into_iter().collect();
+        assert!(
+            !colors.is_empty(),
+            "a DiscreteScale must have at least one color"
+        );
+
+        let mut sorted: Vec<T> = data
+            .iter()
+            .copied()
+            .filter(|value| !value.is_nan())
+            .collect();
+        assert!(
+            !sorted.is_empty(),
+            "a DiscreteScale needs at least one data value"
+        );
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+        let breaks = match classification {
+            Classification::EqualInterval => equal_interval_breaks(&sorted, colors.len()),
+            Classification::Quantile => quantile_breaks(&sorted, colors.len()),
+            Classification::Jenks => jenks_breaks(&sorted, colors.len()),
+        };
+
+        DiscreteScale { breaks, colors }
+    }
+
+    /// Create a scale from already known class boundaries. There is one
+    /// boundary more than there are colors, and they are expected to be in
+    /// ascending order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `colors` is empty or if the number of boundaries doesn't
+    /// match the number of colors.
+    pub fn with_breaks(breaks: Vec<T>, colors: Vec<C>) -> Self {
+        assert!(
+            !colors.is_empty(),
+            "a DiscreteScale must have at least one color"
+        );
+        assert_eq!(
+            breaks.len(),
+            colors.len() + 1,
+            "a DiscreteScale needs one more boundary than colors"
+        );
+
+        DiscreteScale { breaks, colors }
+    }
+
+    /// The class boundaries, from the smallest to the largest value. There
+    /// is one more boundary than classes, and each class includes its upper
+    /// boundary.
+    pub fn breaks(&self) -> &[T] {
+        &self.breaks
+    }
+
+    /// The colors of the classes.
+    pub fn colors(&self) -> &[C] {
+        &self.colors
+    }
+
+    /// The number of classes.
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Always `false`, since a scale has at least one class.
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// Get the index of the class that `value` belongs to. Values outside
+    /// the range of the data belong to the closest class.
+    pub fn class_of(&self, value: T) -> usize {
+        let upper_bounds = &self.breaks[1..self.breaks.len() - 1];
+        upper_bounds
+            .iter()
+            .position(|&upper| value <= upper)
+            .unwrap_or(upper_bounds.len())
+    }
+
+    /// Get the color for `value`.
+    pub fn get(&self, value: T) -> &C {
+        &self.colors[self.class_of(value)]
+    }
+
+    /// Iterate over the lower and upper boundaries of each class, together
+    /// with its color, for making a legend.
+    pub fn legend(&self) -> impl Iterator<Item = ((T, T), &C)> + '_ {
+        self.breaks
+            .windows(2)
+            .map(|bounds| (bounds[0], bounds[1]))
+            .zip(&self.colors)
+    }
+}
+
+fn equal_interval_breaks<T: Float + FromF64>(sorted: &[T], classes: usize) -> Vec<T> {
+    let min = sorted[0];
+    let max = sorted[sorted.len() - 1];
+    let step = (max - min) / from_f64(classes as f64);
+
+    let mut breaks: Vec<T> = (0..classes)
+        .map(|class| min + step * from_f64(class as f64))
+        .collect();
+    breaks.push(max);
+
+    breaks
+}
+
+fn quantile_breaks<T: Float>(sorted: &[T], classes: usize) -> Vec<T> {
+    let mut breaks = Vec::with_capacity(classes + 1);
+    breaks.push(sorted[0]);
+
+    for class in 1..classes {
+        // The last value of each class is its upper boundary.
+        let end = (class * sorted.len() / classes).max(1);
+        breaks.push(sorted[end - 1]);
+    }
+
+    breaks.push(sorted[sorted.len() - 1]);
+    breaks
+}
+
+fn jenks_breaks<T: Float>(sorted: &[T], classes: usize) -> Vec<T> {
+    let count = sorted.len();
+    if classes >= count {
+        return quantile_breaks(sorted, classes);
+    }
+
+    // lower_limits[l][k] is the index, from 1, of the first value in the last
+    // class, for the best division of the first l values into k classes.
+    let mut lower_limits = vec![vec![0usize; classes + 1]; count + 1];
+    let mut variances = vec![vec![T::infinity(); classes + 1]; count + 1];
+
+    for class in 1..=classes {
+        lower_limits[1][class] = 1;
+        variances[1][class] = T::zero();
+    }
+
+    for l in 1..=count {
+        let mut sum = T::zero();
+        let mut sum_squares = T::zero();
+        let mut weight = T::zero();
+        let mut variance = T::zero();
+
+        for m in 1..=l {
+            let lower = l - m + 1;
+            let value = sorted[lower - 1];
+
+            sum = sum + value;
+            sum_squares = sum_squares + value * value;
+            weight = weight + T::one();
+            variance = sum_squares - sum * sum / weight;
+
+            let previous = lower - 1;
+            if previous != 0 {
+                for class in 2..=classes {
+                    let candidate = variance + variances[previous][class - 1];
+                    if variances[l][class] >= candidate {
+                        lower_limits[l][class] = lower;
+                        variances[l][class] = candidate;
+                    }
+                }
+            }
+        }
+
+        lower_limits[l][1] = 1;
+        variances[l][1] = variance;
+    }
+
+    let mut breaks = vec![T::zero(); classes + 1];
+    breaks[0] = sorted[0];
+    breaks[classes] = sorted[count - 1];
+
+    let mut end = count;
+    for class in (2..=classes).rev() {
+        let lower = lower_limits[end][class];
+        breaks[class - 1] = sorted[lower - 2];
+        end = lower - 1;
+    }
+
+    breaks
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Classification, DiscreteScale};
+
+    const DATA: [f64; 10] = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 100.0];
+
+    #[test]
+    fn equal_interval() {
+        let scale = DiscreteScale::new(
+            &DATA,
+            Classification::EqualInterval,
+            vec!['a', 'b', 'c', 'd'],
+        );
+
+        assert_eq!(scale.breaks(), &[0.0, 25.0, 50.0, 75.0, 100.0]);
+        assert_eq!(scale.class_of(8.0), 0);
+        assert_eq!(scale.class_of(25.0), 0);
+        assert_eq!(scale.class_of(25.5), 1);
+        assert_eq!(*scale.get(100.0), 'd');
+    }
+
+    #[test]
+    fn quantile() {
+        let scale = DiscreteScale::new(&DATA, Classification::Quantile, vec!['a', 'b']);
+
+        assert_eq!(scale.breaks(), &[0.0, 4.0, 100.0]);
+
+        let counts = DATA.iter().fold([0; 2], |mut counts, &value| {
+            counts[scale.class_of(value)] += 1;
+            counts
+        });
+        assert_eq!(counts, [5, 5]);
+    }
+
+    #[test]
+    fn jenks() {
+        let data = [
+            4.0,
+            5.0,
+            9.0,
+            10.0,
+            1.0,
+            2.0,
+            3.0,
+            20.0,
+            21.0,
+            22.0,
+            f64::NAN,
+        ];
+        let scale = DiscreteScale::new(&data, Classification::Jenks, vec!['a', 'b', 'c']);
+
+        assert_eq!(scale.breaks(), &[1.0, 5.0, 10.0, 22.0]);
+        assert_eq!(scale.class_of(-3.0), 0);
+        assert_eq!(scale.class_of(9.0), 1);
+        assert_eq!(scale.class_of(50.0), 2);
+    }
+
+    #[test]
+    fn more_classes_than_values() {
+        let scale = DiscreteScale::new(&[1.0, 2.0], Classification::Jenks, vec!['a', 'b', 'c']);
+        assert_eq!(scale.breaks(), &[1.0, 1.0, 1.0, 2.0]);
+        assert_eq!(*scale.get(1.0), 'a');
+        assert_eq!(*scale.get(2.0), 'c');
+    }
+
+    #[test]
+    fn legend() {
+        let scale = DiscreteScale::with_breaks(vec![0.0, 1.0, 3.0], vec!['a', 'b']);
+        let legend: Vec<_> = scale.legend().collect();
+
+        assert_eq!(legend, vec![((0.0, 1.0), &'a'), ((1.0, 3.0), &'b')]);
+        assert_eq!(scale.len(), 2);
+    }
+}
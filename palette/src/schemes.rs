@@ -0,0 +1,996 @@
+//! Classic color schemes for charts and maps. Can be toggled with the
+//! `"schemes"` Cargo feature.
+//!
+//! The schemes are the [ColorBrewer](https://colorbrewer2.org) schemes by
+//! Cynthia Brewer, Mark Harrower and The Pennsylvania State University,
+//! and the Tableau 10 and Tableau 20 schemes. Each [`Scheme`] includes its
+//! colors and some information about how it can be used:
+//!
+//! ```
+//! use palette::schemes::{self, SchemeKind};
+//! use palette::Srgb;
+//!
+//! let blues = schemes::BLUES;
+//! assert_eq!(blues.kind, SchemeKind::Sequential);
+//!
+//! let darkest: Srgb<f32> = blues.colors[8].into_format();
+//!
+//! let safe_diverging = schemes::ALL
+//!     .iter()
+//!     .filter(|scheme| scheme.kind == SchemeKind::Diverging && scheme.cvd_safe);
+//! assert!(safe_diverging.count() > 0);
+//!
+//! assert_eq!(schemes::from_name("rdylbu"), Some(&schemes::RD_YL_BU));
+//! ```
+//!
+//! ColorBrewer publishes each scheme with 3 colors and up to the number
+//! that is included here. The versions with fewer colors are not always
+//! subsets of the larger ones, so they should be taken from the original
+//! source when they are needed. The ColorBrewer schemes are licensed under
+//! the Apache License, Version 2.0.
+
+use crate::Srgb;
+
+/// A color scheme, with information about how it can be used.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Scheme {
+    /// The name of the scheme, as it's published.
+    pub name: &'static str,
+
+    /// The kind of data the scheme is designed for.
+    pub kind: SchemeKind,
+
+    /// Where the scheme comes from.
+    pub source: Source,
+
+    /// The colors, in order.
+    pub colors: &'static [Srgb<u8>],
+
+    /// If the colors can be told apart by people with the common color
+    /// vision deficiencies. This follows the ColorBrewer recommendations for
+    /// the number of colors that is included.
+    pub cvd_safe: bool,
+
+    /// If the colors can be told apart when the scheme is printed. This
+    /// follows the ColorBrewer recommendations for the number of colors that
+    /// is included, and is `false` when there is no recommendation.
+    pub print_safe: bool,
+}
+
+/// The kind of data that a [`Scheme`] is designed for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SchemeKind {
+    /// Ordered data that goes from low to high.
+    Sequential,
+
+    /// Ordered data with a meaningful midpoint, such as zero.
+    Diverging,
+
+    /// Categories without an order.
+    Qualitative,
+}
+
+/// Where a [`Scheme`] comes from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Source {
+    /// ColorBrewer, by Cynthia Brewer.
+    ColorBrewer,
+
+    /// The Tableau visualization software.
+    Tableau,
+}
+
+/// Get a scheme by name. The names are the published names, and are
+/// matched without regard to case.
+pub fn from_name(name: &str) -> Option<&'static Scheme> {
+    ALL.iter()
+        .find(|scheme| scheme.name.eq_ignore_ascii_case(name))
+}
+
+const fn hex(hex: u32) -> Srgb<u8> {
+    Srgb::new((hex >> 16) as u8, (hex >> 8) as u8, hex as u8)
+}
+
+/// The ColorBrewer Blues scheme, with 9 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #f7fbff;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #deebf7;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #c6dbef;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #9ecae1;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #6baed6;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #4292c6;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #2171b5;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #08519c;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #08306b;"></span></div>
+pub const BLUES: Scheme = Scheme {
+    name: "Blues",
+    kind: SchemeKind::Sequential,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0xf7fbff),
+        hex(0xdeebf7),
+        hex(0xc6dbef),
+        hex(0x9ecae1),
+        hex(0x6baed6),
+        hex(0x4292c6),
+        hex(0x2171b5),
+        hex(0x08519c),
+        hex(0x08306b),
+    ],
+    cvd_safe: true,
+    print_safe: false,
+};
+
+/// The ColorBrewer Greens scheme, with 9 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #f7fcf5;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #e5f5e0;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #c7e9c0;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #a1d99b;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #74c476;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #41ab5d;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #238b45;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #006d2c;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #00441b;"></span></div>
+pub const GREENS: Scheme = Scheme {
+    name: "Greens",
+    kind: SchemeKind::Sequential,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0xf7fcf5),
+        hex(0xe5f5e0),
+        hex(0xc7e9c0),
+        hex(0xa1d99b),
+        hex(0x74c476),
+        hex(0x41ab5d),
+        hex(0x238b45),
+        hex(0x006d2c),
+        hex(0x00441b),
+    ],
+    cvd_safe: true,
+    print_safe: false,
+};
+
+/// The ColorBrewer Greys scheme, with 9 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #ffffff;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #f0f0f0;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #d9d9d9;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #bdbdbd;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #969696;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #737373;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #525252;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #252525;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #000000;"></span></div>
+pub const GREYS: Scheme = Scheme {
+    name: "Greys",
+    kind: SchemeKind::Sequential,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0xffffff),
+        hex(0xf0f0f0),
+        hex(0xd9d9d9),
+        hex(0xbdbdbd),
+        hex(0x969696),
+        hex(0x737373),
+        hex(0x525252),
+        hex(0x252525),
+        hex(0x000000),
+    ],
+    cvd_safe: true,
+    print_safe: false,
+};
+
+/// The ColorBrewer Oranges scheme, with 9 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #fff5eb;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fee6ce;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fdd0a2;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fdae6b;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fd8d3c;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #f16913;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #d94801;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #a63603;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #7f2704;"></span></div>
+pub const ORANGES: Scheme = Scheme {
+    name: "Oranges",
+    kind: SchemeKind::Sequential,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0xfff5eb),
+        hex(0xfee6ce),
+        hex(0xfdd0a2),
+        hex(0xfdae6b),
+        hex(0xfd8d3c),
+        hex(0xf16913),
+        hex(0xd94801),
+        hex(0xa63603),
+        hex(0x7f2704),
+    ],
+    cvd_safe: true,
+    print_safe: false,
+};
+
+/// The ColorBrewer Purples scheme, with 9 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #fcfbfd;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #efedf5;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #dadaeb;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #bcbddc;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #9e9ac8;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #807dba;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #6a51a3;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #54278f;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #3f007d;"></span></div>
+pub const PURPLES: Scheme = Scheme {
+    name: "Purples",
+    kind: SchemeKind::Sequential,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0xfcfbfd),
+        hex(0xefedf5),
+        hex(0xdadaeb),
+        hex(0xbcbddc),
+        hex(0x9e9ac8),
+        hex(0x807dba),
+        hex(0x6a51a3),
+        hex(0x54278f),
+        hex(0x3f007d),
+    ],
+    cvd_safe: true,
+    print_safe: false,
+};
+
+/// The ColorBrewer Reds scheme, with 9 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #fff5f0;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fee0d2;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fcbba1;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fc9272;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fb6a4a;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ef3b2c;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #cb181d;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #a50f15;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #67000d;"></span></div>
+pub const REDS: Scheme = Scheme {
+    name: "Reds",
+    kind: SchemeKind::Sequential,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0xfff5f0),
+        hex(0xfee0d2),
+        hex(0xfcbba1),
+        hex(0xfc9272),
+        hex(0xfb6a4a),
+        hex(0xef3b2c),
+        hex(0xcb181d),
+        hex(0xa50f15),
+        hex(0x67000d),
+    ],
+    cvd_safe: true,
+    print_safe: false,
+};
+
+/// The ColorBrewer BuGn scheme, with 9 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #f7fcfd;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #e5f5f9;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ccece6;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #99d8c9;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #66c2a4;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #41ae76;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #238b45;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #006d2c;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #00441b;"></span></div>
+pub const BU_GN: Scheme = Scheme {
+    name: "BuGn",
+    kind: SchemeKind::Sequential,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0xf7fcfd),
+        hex(0xe5f5f9),
+        hex(0xccece6),
+        hex(0x99d8c9),
+        hex(0x66c2a4),
+        hex(0x41ae76),
+        hex(0x238b45),
+        hex(0x006d2c),
+        hex(0x00441b),
+    ],
+    cvd_safe: true,
+    print_safe: false,
+};
+
+/// The ColorBrewer BuPu scheme, with 9 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #f7fcfd;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #e0ecf4;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #bfd3e6;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #9ebcda;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #8c96c6;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #8c6bb1;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #88419d;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #810f7c;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #4d004b;"></span></div>
+pub const BU_PU: Scheme = Scheme {
+    name: "BuPu",
+    kind: SchemeKind::Sequential,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0xf7fcfd),
+        hex(0xe0ecf4),
+        hex(0xbfd3e6),
+        hex(0x9ebcda),
+        hex(0x8c96c6),
+        hex(0x8c6bb1),
+        hex(0x88419d),
+        hex(0x810f7c),
+        hex(0x4d004b),
+    ],
+    cvd_safe: true,
+    print_safe: false,
+};
+
+/// The ColorBrewer GnBu scheme, with 9 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #f7fcf0;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #e0f3db;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ccebc5;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #a8ddb5;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #7bccc4;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #4eb3d3;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #2b8cbe;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #0868ac;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #084081;"></span></div>
+pub const GN_BU: Scheme = Scheme {
+    name: "GnBu",
+    kind: SchemeKind::Sequential,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0xf7fcf0),
+        hex(0xe0f3db),
+        hex(0xccebc5),
+        hex(0xa8ddb5),
+        hex(0x7bccc4),
+        hex(0x4eb3d3),
+        hex(0x2b8cbe),
+        hex(0x0868ac),
+        hex(0x084081),
+    ],
+    cvd_safe: true,
+    print_safe: false,
+};
+
+/// The ColorBrewer OrRd scheme, with 9 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #fff7ec;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fee8c8;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fdd49e;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fdbb84;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fc8d59;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ef6548;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #d7301f;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #b30000;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #7f0000;"></span></div>
+pub const OR_RD: Scheme = Scheme {
+    name: "OrRd",
+    kind: SchemeKind::Sequential,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0xfff7ec),
+        hex(0xfee8c8),
+        hex(0xfdd49e),
+        hex(0xfdbb84),
+        hex(0xfc8d59),
+        hex(0xef6548),
+        hex(0xd7301f),
+        hex(0xb30000),
+        hex(0x7f0000),
+    ],
+    cvd_safe: true,
+    print_safe: false,
+};
+
+/// The ColorBrewer PuBu scheme, with 9 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #fff7fb;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ece7f2;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #d0d1e6;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #a6bddb;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #74a9cf;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #3690c0;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #0570b0;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #045a8d;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #023858;"></span></div>
+pub const PU_BU: Scheme = Scheme {
+    name: "PuBu",
+    kind: SchemeKind::Sequential,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0xfff7fb),
+        hex(0xece7f2),
+        hex(0xd0d1e6),
+        hex(0xa6bddb),
+        hex(0x74a9cf),
+        hex(0x3690c0),
+        hex(0x0570b0),
+        hex(0x045a8d),
+        hex(0x023858),
+    ],
+    cvd_safe: true,
+    print_safe: false,
+};
+
+/// The ColorBrewer PuBuGn scheme, with 9 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #fff7fb;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ece2f0;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #d0d1e6;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #a6bddb;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #67a9cf;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #3690c0;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #02818a;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #016c59;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #014636;"></span></div>
+pub const PU_BU_GN: Scheme = Scheme {
+    name: "PuBuGn",
+    kind: SchemeKind::Sequential,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0xfff7fb),
+        hex(0xece2f0),
+        hex(0xd0d1e6),
+        hex(0xa6bddb),
+        hex(0x67a9cf),
+        hex(0x3690c0),
+        hex(0x02818a),
+        hex(0x016c59),
+        hex(0x014636),
+    ],
+    cvd_safe: true,
+    print_safe: false,
+};
+
+/// The ColorBrewer PuRd scheme, with 9 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #f7f4f9;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #e7e1ef;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #d4b9da;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #c994c7;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #df65b0;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #e7298a;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ce1256;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #980043;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #67001f;"></span></div>
+pub const PU_RD: Scheme = Scheme {
+    name: "PuRd",
+    kind: SchemeKind::Sequential,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0xf7f4f9),
+        hex(0xe7e1ef),
+        hex(0xd4b9da),
+        hex(0xc994c7),
+        hex(0xdf65b0),
+        hex(0xe7298a),
+        hex(0xce1256),
+        hex(0x980043),
+        hex(0x67001f),
+    ],
+    cvd_safe: true,
+    print_safe: false,
+};
+
+/// The ColorBrewer RdPu scheme, with 9 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #fff7f3;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fde0dd;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fcc5c0;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fa9fb5;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #f768a1;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #dd3497;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ae017e;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #7a0177;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #49006a;"></span></div>
+pub const RD_PU: Scheme = Scheme {
+    name: "RdPu",
+    kind: SchemeKind::Sequential,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0xfff7f3),
+        hex(0xfde0dd),
+        hex(0xfcc5c0),
+        hex(0xfa9fb5),
+        hex(0xf768a1),
+        hex(0xdd3497),
+        hex(0xae017e),
+        hex(0x7a0177),
+        hex(0x49006a),
+    ],
+    cvd_safe: true,
+    print_safe: false,
+};
+
+/// The ColorBrewer YlGn scheme, with 9 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #ffffe5;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #f7fcb9;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #d9f0a3;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #addd8e;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #78c679;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #41ab5d;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #238443;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #006837;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #004529;"></span></div>
+pub const YL_GN: Scheme = Scheme {
+    name: "YlGn",
+    kind: SchemeKind::Sequential,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0xffffe5),
+        hex(0xf7fcb9),
+        hex(0xd9f0a3),
+        hex(0xaddd8e),
+        hex(0x78c679),
+        hex(0x41ab5d),
+        hex(0x238443),
+        hex(0x006837),
+        hex(0x004529),
+    ],
+    cvd_safe: true,
+    print_safe: false,
+};
+
+/// The ColorBrewer YlGnBu scheme, with 9 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #ffffd9;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #edf8b1;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #c7e9b4;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #7fcdbb;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #41b6c4;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #1d91c0;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #225ea8;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #253494;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #081d58;"></span></div>
+pub const YL_GN_BU: Scheme = Scheme {
+    name: "YlGnBu",
+    kind: SchemeKind::Sequential,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0xffffd9),
+        hex(0xedf8b1),
+        hex(0xc7e9b4),
+        hex(0x7fcdbb),
+        hex(0x41b6c4),
+        hex(0x1d91c0),
+        hex(0x225ea8),
+        hex(0x253494),
+        hex(0x081d58),
+    ],
+    cvd_safe: true,
+    print_safe: false,
+};
+
+/// The ColorBrewer YlOrBr scheme, with 9 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #ffffe5;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fff7bc;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fee391;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fec44f;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fe9929;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ec7014;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #cc4c02;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #993404;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #662506;"></span></div>
+pub const YL_OR_BR: Scheme = Scheme {
+    name: "YlOrBr",
+    kind: SchemeKind::Sequential,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0xffffe5),
+        hex(0xfff7bc),
+        hex(0xfee391),
+        hex(0xfec44f),
+        hex(0xfe9929),
+        hex(0xec7014),
+        hex(0xcc4c02),
+        hex(0x993404),
+        hex(0x662506),
+    ],
+    cvd_safe: true,
+    print_safe: false,
+};
+
+/// The ColorBrewer YlOrRd scheme, with 9 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #ffffcc;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ffeda0;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fed976;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #feb24c;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fd8d3c;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fc4e2a;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #e31a1c;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #bd0026;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #800026;"></span></div>
+pub const YL_OR_RD: Scheme = Scheme {
+    name: "YlOrRd",
+    kind: SchemeKind::Sequential,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0xffffcc),
+        hex(0xffeda0),
+        hex(0xfed976),
+        hex(0xfeb24c),
+        hex(0xfd8d3c),
+        hex(0xfc4e2a),
+        hex(0xe31a1c),
+        hex(0xbd0026),
+        hex(0x800026),
+    ],
+    cvd_safe: true,
+    print_safe: false,
+};
+
+/// The ColorBrewer BrBG scheme, with 11 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #543005;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #8c510a;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #bf812d;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #dfc27d;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #f6e8c3;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #f5f5f5;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #c7eae5;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #80cdc1;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #35978f;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #01665e;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #003c30;"></span></div>
+pub const BR_BG: Scheme = Scheme {
+    name: "BrBG",
+    kind: SchemeKind::Diverging,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0x543005),
+        hex(0x8c510a),
+        hex(0xbf812d),
+        hex(0xdfc27d),
+        hex(0xf6e8c3),
+        hex(0xf5f5f5),
+        hex(0xc7eae5),
+        hex(0x80cdc1),
+        hex(0x35978f),
+        hex(0x01665e),
+        hex(0x003c30),
+    ],
+    cvd_safe: true,
+    print_safe: false,
+};
+
+/// The ColorBrewer PiYG scheme, with 11 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #8e0152;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #c51b7d;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #de77ae;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #f1b6da;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fde0ef;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #f7f7f7;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #e6f5d0;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #b8e186;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #7fbc41;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #4d9221;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #276419;"></span></div>
+pub const PI_YG: Scheme = Scheme {
+    name: "PiYG",
+    kind: SchemeKind::Diverging,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0x8e0152),
+        hex(0xc51b7d),
+        hex(0xde77ae),
+        hex(0xf1b6da),
+        hex(0xfde0ef),
+        hex(0xf7f7f7),
+        hex(0xe6f5d0),
+        hex(0xb8e186),
+        hex(0x7fbc41),
+        hex(0x4d9221),
+        hex(0x276419),
+    ],
+    cvd_safe: true,
+    print_safe: false,
+};
+
+/// The ColorBrewer PRGn scheme, with 11 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #40004b;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #762a83;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #9970ab;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #c2a5cf;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #e7d4e8;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #f7f7f7;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #d9f0d3;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #a6dba0;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #5aae61;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #1b7837;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #00441b;"></span></div>
+pub const PR_GN: Scheme = Scheme {
+    name: "PRGn",
+    kind: SchemeKind::Diverging,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0x40004b),
+        hex(0x762a83),
+        hex(0x9970ab),
+        hex(0xc2a5cf),
+        hex(0xe7d4e8),
+        hex(0xf7f7f7),
+        hex(0xd9f0d3),
+        hex(0xa6dba0),
+        hex(0x5aae61),
+        hex(0x1b7837),
+        hex(0x00441b),
+    ],
+    cvd_safe: true,
+    print_safe: false,
+};
+
+/// The ColorBrewer PuOr scheme, with 11 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #7f3b08;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #b35806;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #e08214;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fdb863;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fee0b6;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #f7f7f7;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #d8daeb;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #b2abd2;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #8073ac;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #542788;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #2d004b;"></span></div>
+pub const PU_OR: Scheme = Scheme {
+    name: "PuOr",
+    kind: SchemeKind::Diverging,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0x7f3b08),
+        hex(0xb35806),
+        hex(0xe08214),
+        hex(0xfdb863),
+        hex(0xfee0b6),
+        hex(0xf7f7f7),
+        hex(0xd8daeb),
+        hex(0xb2abd2),
+        hex(0x8073ac),
+        hex(0x542788),
+        hex(0x2d004b),
+    ],
+    cvd_safe: true,
+    print_safe: false,
+};
+
+/// The ColorBrewer RdBu scheme, with 11 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #67001f;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #b2182b;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #d6604d;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #f4a582;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fddbc7;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #f7f7f7;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #d1e5f0;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #92c5de;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #4393c3;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #2166ac;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #053061;"></span></div>
+pub const RD_BU: Scheme = Scheme {
+    name: "RdBu",
+    kind: SchemeKind::Diverging,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0x67001f),
+        hex(0xb2182b),
+        hex(0xd6604d),
+        hex(0xf4a582),
+        hex(0xfddbc7),
+        hex(0xf7f7f7),
+        hex(0xd1e5f0),
+        hex(0x92c5de),
+        hex(0x4393c3),
+        hex(0x2166ac),
+        hex(0x053061),
+    ],
+    cvd_safe: true,
+    print_safe: false,
+};
+
+/// The ColorBrewer RdGy scheme, with 11 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #67001f;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #b2182b;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #d6604d;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #f4a582;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fddbc7;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ffffff;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #e0e0e0;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #bababa;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #878787;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #4d4d4d;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #1a1a1a;"></span></div>
+pub const RD_GY: Scheme = Scheme {
+    name: "RdGy",
+    kind: SchemeKind::Diverging,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0x67001f),
+        hex(0xb2182b),
+        hex(0xd6604d),
+        hex(0xf4a582),
+        hex(0xfddbc7),
+        hex(0xffffff),
+        hex(0xe0e0e0),
+        hex(0xbababa),
+        hex(0x878787),
+        hex(0x4d4d4d),
+        hex(0x1a1a1a),
+    ],
+    cvd_safe: false,
+    print_safe: false,
+};
+
+/// The ColorBrewer RdYlBu scheme, with 11 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #a50026;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #d73027;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #f46d43;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fdae61;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fee090;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ffffbf;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #e0f3f8;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #abd9e9;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #74add1;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #4575b4;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #313695;"></span></div>
+pub const RD_YL_BU: Scheme = Scheme {
+    name: "RdYlBu",
+    kind: SchemeKind::Diverging,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0xa50026),
+        hex(0xd73027),
+        hex(0xf46d43),
+        hex(0xfdae61),
+        hex(0xfee090),
+        hex(0xffffbf),
+        hex(0xe0f3f8),
+        hex(0xabd9e9),
+        hex(0x74add1),
+        hex(0x4575b4),
+        hex(0x313695),
+    ],
+    cvd_safe: true,
+    print_safe: false,
+};
+
+/// The ColorBrewer RdYlGn scheme, with 11 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #a50026;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #d73027;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #f46d43;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fdae61;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fee08b;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ffffbf;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #d9ef8b;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #a6d96a;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #66bd63;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #1a9850;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #006837;"></span></div>
+pub const RD_YL_GN: Scheme = Scheme {
+    name: "RdYlGn",
+    kind: SchemeKind::Diverging,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0xa50026),
+        hex(0xd73027),
+        hex(0xf46d43),
+        hex(0xfdae61),
+        hex(0xfee08b),
+        hex(0xffffbf),
+        hex(0xd9ef8b),
+        hex(0xa6d96a),
+        hex(0x66bd63),
+        hex(0x1a9850),
+        hex(0x006837),
+    ],
+    cvd_safe: false,
+    print_safe: false,
+};
+
+/// The ColorBrewer Spectral scheme, with 11 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #9e0142;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #d53e4f;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #f46d43;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fdae61;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fee08b;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ffffbf;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #e6f598;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #abdda4;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #66c2a5;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #3288bd;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #5e4fa2;"></span></div>
+pub const SPECTRAL: Scheme = Scheme {
+    name: "Spectral",
+    kind: SchemeKind::Diverging,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0x9e0142),
+        hex(0xd53e4f),
+        hex(0xf46d43),
+        hex(0xfdae61),
+        hex(0xfee08b),
+        hex(0xffffbf),
+        hex(0xe6f598),
+        hex(0xabdda4),
+        hex(0x66c2a5),
+        hex(0x3288bd),
+        hex(0x5e4fa2),
+    ],
+    cvd_safe: false,
+    print_safe: false,
+};
+
+/// The ColorBrewer Accent scheme, with 8 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #7fc97f;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #beaed4;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fdc086;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ffff99;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #386cb0;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #f0027f;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #bf5b17;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #666666;"></span></div>
+pub const ACCENT: Scheme = Scheme {
+    name: "Accent",
+    kind: SchemeKind::Qualitative,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0x7fc97f),
+        hex(0xbeaed4),
+        hex(0xfdc086),
+        hex(0xffff99),
+        hex(0x386cb0),
+        hex(0xf0027f),
+        hex(0xbf5b17),
+        hex(0x666666),
+    ],
+    cvd_safe: false,
+    print_safe: false,
+};
+
+/// The ColorBrewer Dark2 scheme, with 8 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #1b9e77;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #d95f02;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #7570b3;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #e7298a;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #66a61e;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #e6ab02;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #a6761d;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #666666;"></span></div>
+pub const DARK2: Scheme = Scheme {
+    name: "Dark2",
+    kind: SchemeKind::Qualitative,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0x1b9e77),
+        hex(0xd95f02),
+        hex(0x7570b3),
+        hex(0xe7298a),
+        hex(0x66a61e),
+        hex(0xe6ab02),
+        hex(0xa6761d),
+        hex(0x666666),
+    ],
+    cvd_safe: false,
+    print_safe: true,
+};
+
+/// The ColorBrewer Paired scheme, with 12 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #a6cee3;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #1f78b4;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #b2df8a;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #33a02c;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fb9a99;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #e31a1c;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fdbf6f;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ff7f00;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #cab2d6;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #6a3d9a;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ffff99;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #b15928;"></span></div>
+pub const PAIRED: Scheme = Scheme {
+    name: "Paired",
+    kind: SchemeKind::Qualitative,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0xa6cee3),
+        hex(0x1f78b4),
+        hex(0xb2df8a),
+        hex(0x33a02c),
+        hex(0xfb9a99),
+        hex(0xe31a1c),
+        hex(0xfdbf6f),
+        hex(0xff7f00),
+        hex(0xcab2d6),
+        hex(0x6a3d9a),
+        hex(0xffff99),
+        hex(0xb15928),
+    ],
+    cvd_safe: false,
+    print_safe: true,
+};
+
+/// The ColorBrewer Pastel1 scheme, with 9 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #fbb4ae;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #b3cde3;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ccebc5;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #decbe4;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fed9a6;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ffffcc;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #e5d8bd;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fddaec;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #f2f2f2;"></span></div>
+pub const PASTEL1: Scheme = Scheme {
+    name: "Pastel1",
+    kind: SchemeKind::Qualitative,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0xfbb4ae),
+        hex(0xb3cde3),
+        hex(0xccebc5),
+        hex(0xdecbe4),
+        hex(0xfed9a6),
+        hex(0xffffcc),
+        hex(0xe5d8bd),
+        hex(0xfddaec),
+        hex(0xf2f2f2),
+    ],
+    cvd_safe: false,
+    print_safe: false,
+};
+
+/// The ColorBrewer Pastel2 scheme, with 8 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #b3e2cd;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fdcdac;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #cbd5e8;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #f4cae4;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #e6f5c9;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fff2ae;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #f1e2cc;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #cccccc;"></span></div>
+pub const PASTEL2: Scheme = Scheme {
+    name: "Pastel2",
+    kind: SchemeKind::Qualitative,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0xb3e2cd),
+        hex(0xfdcdac),
+        hex(0xcbd5e8),
+        hex(0xf4cae4),
+        hex(0xe6f5c9),
+        hex(0xfff2ae),
+        hex(0xf1e2cc),
+        hex(0xcccccc),
+    ],
+    cvd_safe: false,
+    print_safe: false,
+};
+
+/// The ColorBrewer Set1 scheme, with 9 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #e41a1c;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #377eb8;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #4daf4a;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #984ea3;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ff7f00;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ffff33;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #a65628;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #f781bf;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #999999;"></span></div>
+pub const SET1: Scheme = Scheme {
+    name: "Set1",
+    kind: SchemeKind::Qualitative,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0xe41a1c),
+        hex(0x377eb8),
+        hex(0x4daf4a),
+        hex(0x984ea3),
+        hex(0xff7f00),
+        hex(0xffff33),
+        hex(0xa65628),
+        hex(0xf781bf),
+        hex(0x999999),
+    ],
+    cvd_safe: false,
+    print_safe: true,
+};
+
+/// The ColorBrewer Set2 scheme, with 8 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #66c2a5;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fc8d62;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #8da0cb;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #e78ac3;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #a6d854;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ffd92f;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #e5c494;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #b3b3b3;"></span></div>
+pub const SET2: Scheme = Scheme {
+    name: "Set2",
+    kind: SchemeKind::Qualitative,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0x66c2a5),
+        hex(0xfc8d62),
+        hex(0x8da0cb),
+        hex(0xe78ac3),
+        hex(0xa6d854),
+        hex(0xffd92f),
+        hex(0xe5c494),
+        hex(0xb3b3b3),
+    ],
+    cvd_safe: false,
+    print_safe: false,
+};
+
+/// The ColorBrewer Set3 scheme, with 12 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #8dd3c7;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ffffb3;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #bebada;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fb8072;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #80b1d3;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fdb462;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #b3de69;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #fccde5;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #d9d9d9;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #bc80bd;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ccebc5;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ffed6f;"></span></div>
+pub const SET3: Scheme = Scheme {
+    name: "Set3",
+    kind: SchemeKind::Qualitative,
+    source: Source::ColorBrewer,
+    colors: &[
+        hex(0x8dd3c7),
+        hex(0xffffb3),
+        hex(0xbebada),
+        hex(0xfb8072),
+        hex(0x80b1d3),
+        hex(0xfdb462),
+        hex(0xb3de69),
+        hex(0xfccde5),
+        hex(0xd9d9d9),
+        hex(0xbc80bd),
+        hex(0xccebc5),
+        hex(0xffed6f),
+    ],
+    cvd_safe: false,
+    print_safe: false,
+};
+
+/// The Tableau Tableau10 scheme, with 10 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #4e79a7;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #f28e2b;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #e15759;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #76b7b2;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #59a14f;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #edc948;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #b07aa1;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ff9da7;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #9c755f;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #bab0ac;"></span></div>
+pub const TABLEAU10: Scheme = Scheme {
+    name: "Tableau10",
+    kind: SchemeKind::Qualitative,
+    source: Source::Tableau,
+    colors: &[
+        hex(0x4e79a7),
+        hex(0xf28e2b),
+        hex(0xe15759),
+        hex(0x76b7b2),
+        hex(0x59a14f),
+        hex(0xedc948),
+        hex(0xb07aa1),
+        hex(0xff9da7),
+        hex(0x9c755f),
+        hex(0xbab0ac),
+    ],
+    cvd_safe: false,
+    print_safe: false,
+};
+
+/// The Tableau Tableau20 scheme, with 20 colors.
+///
+/// <div><span style="display: inline-block; width: 1.5em; height: 1em; background: #1f77b4;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #aec7e8;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ff7f0e;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ffbb78;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #2ca02c;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #98df8a;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #d62728;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #ff9896;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #9467bd;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #c5b0d5;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #8c564b;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #c49c94;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #e377c2;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #f7b6d2;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #7f7f7f;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #c7c7c7;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #bcbd22;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #dbdb8d;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #17becf;"></span><span style="display: inline-block; width: 1.5em; height: 1em; background: #9edae5;"></span></div>
+pub const TABLEAU20: Scheme = Scheme {
+    name: "Tableau20",
+    kind: SchemeKind::Qualitative,
+    source: Source::Tableau,
+    colors: &[
+        hex(0x1f77b4),
+        hex(0xaec7e8),
+        hex(0xff7f0e),
+        hex(0xffbb78),
+        hex(0x2ca02c),
+        hex(0x98df8a),
+        hex(0xd62728),
+        hex(0xff9896),
+        hex(0x9467bd),
+        hex(0xc5b0d5),
+        hex(0x8c564b),
+        hex(0xc49c94),
+        hex(0xe377c2),
+        hex(0xf7b6d2),
+        hex(0x7f7f7f),
+        hex(0xc7c7c7),
+        hex(0xbcbd22),
+        hex(0xdbdb8d),
+        hex(0x17becf),
+        hex(0x9edae5),
+    ],
+    cvd_safe: false,
+    print_safe: false,
+};
+
+/// All schemes: the ColorBrewer sequential, diverging and qualitative
+/// schemes, followed by the Tableau schemes.
+pub const ALL: &[Scheme] = &[
+    BLUES, GREENS, GREYS, ORANGES, PURPLES, REDS, BU_GN, BU_PU, GN_BU, OR_RD, PU_BU, PU_BU_GN,
+    PU_RD, RD_PU, YL_GN, YL_GN_BU, YL_OR_BR, YL_OR_RD, BR_BG, PI_YG, PR_GN, PU_OR, RD_BU, RD_GY,
+    RD_YL_BU, RD_YL_GN, SPECTRAL, ACCENT, DARK2, PAIRED, PASTEL1, PASTEL2, SET1, SET2, SET3,
+    TABLEAU10, TABLEAU20,
+];
+
+#[cfg(test)]
+mod test {
+    use super::{from_name, SchemeKind, ALL};
+    use crate::convert::IntoColorUnclamped;
+    use crate::{Oklab, Srgb};
+
+    #[test]
+    fn names() {
+        for scheme in ALL {
+            assert_eq!(from_name(scheme.name), Some(scheme));
+            assert_eq!(from_name(&scheme.name.to_uppercase()), Some(scheme));
+        }
+
+        assert_eq!(from_name("Tableau10").unwrap().colors.len(), 10);
+        assert_eq!(from_name("Tableau20").unwrap().colors.len(), 20);
+        assert_eq!(from_name("viridis"), None);
+    }
+
+    fn lightness(color: Srgb<u8>) -> f32 {
+        let oklab: Oklab = color.into_format::<f32>().into_color_unclamped();
+        oklab.l
+    }
+
+    #[test]
+    fn sequential_lightness() {
+        for scheme in ALL
+            .iter()
+            .filter(|scheme| scheme.kind == SchemeKind::Sequential)
+        {
+            for pair in scheme.colors.windows(2) {
+                assert!(lightness(pair[0]) > lightness(pair[1]), "{}", scheme.name);
+            }
+        }
+    }
+
+    #[test]
+    fn diverging_midpoint() {
+        for scheme in ALL
+            .iter()
+            .filter(|scheme| scheme.kind == SchemeKind::Diverging)
+        {
+            let middle = lightness(scheme.colors[scheme.colors.len() / 2]);
+            for &color in scheme.colors {
+                assert!(lightness(color) <= middle, "{}", scheme.name);
+            }
+        }
+    }
+}
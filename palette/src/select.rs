@@ -0,0 +1,330 @@
+//! Pick colors from a palette by their lightness or chroma.
+//!
+//! The functions in this module convert each color to a chosen color space,
+//! compare them there, and return the index of the selected color. The
+//! color space decides what "lightest" or "most chromatic" means, so it's
+//! given as a type parameter:
+//!
+//! ```
+//! use palette::{select, Oklab, Oklch, Srgb};
+//!
+//! let theme = [
+//!     Srgb::new(0.95f32, 0.95, 0.92),
+//!     Srgb::new(0.1, 0.1, 0.15),
+//!     Srgb::new(0.9, 0.3, 0.1),
+//! ];
+//!
+//! assert_eq!(select::lightest::<Oklab, _>(theme.iter().copied()), Some(0));
+//! assert_eq!(select::darkest::<Oklab, _>(theme.iter().copied()), Some(1));
+//! assert_eq!(select::most_chromatic::<Oklch, _>(theme.iter().copied()), Some(2));
+//! ```
+//!
+//! The first of several equal colors is selected, and colors that can't be
+//! compared, such as ones with `NaN` components, are never selected. The
+//! result is `None` if there are no colors to select from.
+//!
+//! [`compare`] and [`sort`] order colors by their components instead, one
+//! component at a time, for when any consistent order is good enough.
+
+use core::cmp::Ordering;
+
+use crate::cast::ArrayCast;
+use crate::convert::IntoColorUnclamped;
+use crate::float::{self, Float};
+use crate::{Hsl, Hsluv, Lab, Lch, Lchuv, Luma, Luv, Oklab, Oklch};
+
+/// Colors with a lightness component.
+pub trait Lightness {
+    /// The type of the lightness value.
+    type Scalar;
+
+    /// Get the lightness of the color.
+    fn lightness(&self) -> Self::Scalar;
+}
+
+/// Colors with a chroma, or colorfulness, that can be compared.
+pub trait Chroma {
+    /// The type of the chroma value.
+    type Scalar;
+
+    /// Get the chroma of the color.
+    fn chroma(&self) -> Self::Scalar;
+}
+
+macro_rules! impl_lightness {
+    ($ty:ident $(<$($ty_param:ident),*>)?, $field:ident) => {
+        impl<$($($ty_param,)*)? T> Lightness for $ty<$($($ty_param,)*)? T>
+        where
+            T: Clone,
+        {
+            type Scalar = T;
+
+            #[inline]
+            fn lightness(&self) -> T {
+                self.$field.clone()
+            }
+        }
+    };
+}
+
+impl_lightness!(Lab<Wp>, l);
+impl_lightness!(Lch<Wp>, l);
+impl_lightness!(Luv<Wp>, l);
+impl_lightness!(Lchuv<Wp>, l);
+impl_lightness!(Hsluv<Wp>, l);
+impl_lightness!(Oklab, l);
+impl_lightness!(Oklch, l);
+
+impl<S, T> Lightness for Hsl<S, T>
+where
+    T: Clone,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn lightness(&self) -> T {
+        self.lightness.clone()
+    }
+}
+
+impl<S, T> Lightness for Luma<S, T>
+where
+    T: Clone,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn lightness(&self) -> T {
+        self.luma.clone()
+    }
+}
+
+macro_rules! impl_chroma {
+    ($ty:ident $(<$($ty_param:ident),*>)?, polar) => {
+        impl<$($($ty_param,)*)? T> Chroma for $ty<$($($ty_param,)*)? T>
+        where
+            T: Clone,
+        {
+            type Scalar = T;
+
+            #[inline]
+            fn chroma(&self) -> T {
+                self.chroma.clone()
+            }
+        }
+    };
+    ($ty:ident $(<$($ty_param:ident),*>)?, $first:ident, $second:ident) => {
+        impl<$($($ty_param,)*)? T> Chroma for $ty<$($($ty_param,)*)? T>
+        where
+            T: Float,
+        {
+            type Scalar = T;
+
+            #[inline]
+            fn chroma(&self) -> T {
+                float::hypot(self.$first, self.$second)
+            }
+        }
+    };
+}
+
+impl_chroma!(Lab<Wp>, a, b);
+impl_chroma!(Luv<Wp>, u, v);
+impl_chroma!(Oklab, a, b);
+impl_chroma!(Lch<Wp>, polar);
+impl_chroma!(Lchuv<Wp>, polar);
+impl_chroma!(Oklch, polar);
+
+/// Find the index of the lightest color in `colors`, as measured in the
+/// color space `S`.
+pub fn lightest<S, I>(colors: I) -> Option<usize>
+where
+    S: Lightness,
+    S::Scalar: PartialOrd,
+    I: IntoIterator,
+    I::Item: IntoColorUnclamped<S>,
+{
+    select_by(colors, |color: &S| color.lightness(), Ordering::Greater)
+}
+
+/// Find the index of the darkest color in `colors`, as measured in the color
+/// space `S`.
+pub fn darkest<S, I>(colors: I) -> Option<usize>
+where
+    S: Lightness,
+    S::Scalar: PartialOrd,
+    I: IntoIterator,
+    I::Item: IntoColorUnclamped<S>,
+{
+    select_by(colors, |color: &S| color.lightness(), Ordering::Less)
+}
+
+/// Find the index of the most chromatic color in `colors`, as measured in
+/// the color space `S`.
+pub fn most_chromatic<S, I>(colors: I) -> Option<usize>
+where
+    S: Chroma,
+    S::Scalar: PartialOrd,
+    I: IntoIterator,
+    I::Item: IntoColorUnclamped<S>,
+{
+    select_by(colors, |color: &S| color.chroma(), Ordering::Greater)
+}
+
+/// Find the index of the least chromatic color in `colors`, as measured in
+/// the color space `S`.
+pub fn least_chromatic<S, I>(colors: I) -> Option<usize>
+where
+    S: Chroma,
+    S::Scalar: PartialOrd,
+    I: IntoIterator,
+    I::Item: IntoColorUnclamped<S>,
+{
+    select_by(colors, |color: &S| color.chroma(), Ordering::Less)
+}
+
+/// Compare two colors by their components, in the order they are stored.
+///
+/// The first component that differs decides the order. The result is `None`
+/// if the first such components can't be compared.
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use palette::{select, Srgb};
+///
+/// let a = Srgb::new(0.2f32, 0.8, 0.1);
+/// let b = Srgb::new(0.2f32, 0.5, 0.9);
+/// assert_eq!(select::compare(&a, &b), Some(Ordering::Greater));
+/// ```
+pub fn compare<C>(a: &C, b: &C) -> Option<Ordering>
+where
+    C: ArrayCast,
+    C::Array: PartialOrd,
+{
+    crate::cast::into_array_ref(a).partial_cmp(crate::cast::into_array_ref(b))
+}
+
+/// Sort colors by their components, as in [`compare`].
+///
+/// Colors that can't be compared are treated as equal to each other, so
+/// their relative order is unspecified.
+pub fn sort<C>(colors: &mut [C])
+where
+    C: ArrayCast,
+    C::Array: PartialOrd,
+{
+    colors.sort_unstable_by(|a, b| compare(a, b).unwrap_or(Ordering::Equal));
+}
+
+fn select_by<S, I, K, F>(colors: I, key: F, wanted: Ordering) -> Option<usize>
+where
+    I: IntoIterator,
+    I::Item: IntoColorUnclamped<S>,
+    K: PartialOrd,
+    F: Fn(&S) -> K,
+{
+    let mut best: Option<(usize, K)> = None;
+
+    for (index, color) in colors.into_iter().enumerate() {
+        let value = key(&color.into_color_unclamped());
+
+        // Values that can't be compared with themselves are never selected.
+        if value.partial_cmp(&value).is_none() {
+            continue;
+        }
+
+        let is_better = match best {
+            Some((_, ref best_value)) => value.partial_cmp(best_value) == Some(wanted),
+            None => true,
+        };
+        if is_better {
+            best = Some((index, value));
+        }
+    }
+
+    best.map(|(index, _)| index)
+}
+
+#[cfg(test)]
+mod test {
+    use core::cmp::Ordering;
+
+    use super::{compare, darkest, least_chromatic, lightest, most_chromatic, sort};
+    use crate::white_point::D65;
+    use crate::{Hsl, Lab, Lch, Oklab, Oklch, Srgb, SrgbLuma};
+
+    #[test]
+    fn lightness() {
+        let colors = [
+            Srgb::new(0.5f64, 0.5, 0.5),
+            Srgb::new(0.9, 0.9, 0.9),
+            Srgb::new(0.1, 0.1, 0.1),
+            Srgb::new(0.9, 0.9, 0.9),
+        ];
+
+        assert_eq!(lightest::<Oklab<f64>, _>(colors.iter().copied()), Some(1));
+        assert_eq!(darkest::<Oklab<f64>, _>(colors.iter().copied()), Some(2));
+        assert_eq!(
+            lightest::<Lab<D65, f64>, _>(colors.iter().copied()),
+            Some(1)
+        );
+        assert_eq!(
+            lightest::<Hsl<crate::encoding::Srgb, f64>, _>(colors.iter().copied()),
+            Some(1)
+        );
+        assert_eq!(darkest::<SrgbLuma<f64>, _>(colors.iter().copied()), Some(2));
+    }
+
+    #[test]
+    fn chroma() {
+        let colors = [
+            Oklch::new(0.5f64, 0.1, 30.0),
+            Oklch::new(0.5, 0.2, 130.0),
+            Oklch::new(0.5, 0.0, 0.0),
+        ];
+
+        assert_eq!(
+            most_chromatic::<Oklch<f64>, _>(colors.iter().copied()),
+            Some(1)
+        );
+        assert_eq!(
+            most_chromatic::<Oklab<f64>, _>(colors.iter().copied()),
+            Some(1)
+        );
+        assert_eq!(
+            least_chromatic::<Oklab<f64>, _>(colors.iter().copied()),
+            Some(2)
+        );
+        assert_eq!(
+            most_chromatic::<Lch<D65, f64>, _>(colors.iter().copied()),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn empty_and_nan() {
+        let empty: [Oklab<f64>; 0] = [];
+        assert_eq!(lightest::<Oklab<f64>, _>(empty.iter().copied()), None);
+
+        let colors = [
+            Oklab::new(f64::NAN, 0.0, 0.0),
+            Oklab::new(0.2, 0.0, 0.0),
+            Oklab::new(0.4, 0.0, 0.0),
+        ];
+        assert_eq!(lightest::<Oklab<f64>, _>(colors.iter().copied()), Some(2));
+        assert_eq!(darkest::<Oklab<f64>, _>(colors.iter().copied()), Some(1));
+    }
+
+    #[test]
+    fn lexicographic() {
+        let a = Srgb::new(0.2f64, 0.5, 0.9);
+        let b = Srgb::new(0.2, 0.8, 0.1);
+        assert_eq!(compare(&a, &b), Some(Ordering::Less));
+        assert_eq!(compare(&a, &a), Some(Ordering::Equal));
+        assert_eq!(compare(&Srgb::new(f64::NAN, 0.0, 0.0), &a), None);
+
+        let mut colors = [b, Srgb::new(0.1, 0.9, 0.9), a];
+        sort(&mut colors);
+        assert_eq!(colors, [Srgb::new(0.1, 0.9, 0.9), a, b]);
+    }
+}
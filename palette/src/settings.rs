@@ -0,0 +1,136 @@
+//! Versioned, serializable conversion settings.
+//!
+//! Setting types in this crate, such as
+//! [`chromatic_adaptation::Method`](crate::chromatic_adaptation::Method) and
+//! [`clipping::Thresholds`](crate::clipping::Thresholds), implement
+//! `Serialize` and `Deserialize` when the `serializing` feature is enabled.
+//! They can be wrapped in [`Versioned`] to store them together with the
+//! version of the schema they were written with, so saved settings can be
+//! re-applied the same way in later runs or on other machines.
+//!
+//! This module is only available if the `serializing` feature is enabled.
+//!
+//! ```
+//! use palette::chromatic_adaptation::Method;
+//! use palette::settings::Versioned;
+//!
+//! let saved = serde_json::to_string(&Versioned::new(Method::Bradford)).unwrap();
+//! assert_eq!(saved, r#"{"version":1,"settings":"Bradford"}"#);
+//!
+//! let loaded: Versioned<Method> = serde_json::from_str(&saved).unwrap();
+//! assert_eq!(loaded.into_settings().unwrap(), Method::Bradford);
+//! ```
+
+use core::fmt;
+
+/// The current version of the settings schema.
+///
+/// It's increased whenever the serialized form of any setting type changes
+/// in a way that would make previously saved settings behave differently.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Settings, tagged with the schema version they were serialized with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Versioned<T> {
+    /// The schema version of `settings`.
+    pub version: u32,
+
+    /// The wrapped settings.
+    pub settings: T,
+}
+
+impl<T> Versioned<T> {
+    /// Wrap `settings` and tag them with the current [`SCHEMA_VERSION`].
+    pub const fn new(settings: T) -> Self {
+        Versioned {
+            version: SCHEMA_VERSION,
+            settings,
+        }
+    }
+
+    /// Unwrap the settings, if they were written with a supported schema
+    /// version.
+    pub fn into_settings(self) -> Result<T, UnsupportedVersionError> {
+        if self.version == 0 || self.version > SCHEMA_VERSION {
+            Err(UnsupportedVersionError {
+                version: self.version,
+            })
+        } else {
+            Ok(self.settings)
+        }
+    }
+}
+
+/// Error type for settings with an unsupported schema version.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnsupportedVersionError {
+    /// The version that was found.
+    pub version: u32,
+}
+
+impl fmt::Display for UnsupportedVersionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unsupported settings schema version {}, expected 1 to {}",
+            self.version, SCHEMA_VERSION
+        )
+    }
+}
+
+impl std::error::Error for UnsupportedVersionError {}
+
+#[cfg(test)]
+mod test {
+    use super::{UnsupportedVersionError, Versioned, SCHEMA_VERSION};
+    use crate::chromatic_adaptation::Method;
+    use crate::clipping::Thresholds;
+    use crate::white_point::D65;
+    use crate::Srgb;
+
+    #[test]
+    fn round_trip_thresholds() {
+        let thresholds = Thresholds::new(Srgb::new(0.1, 0.2, 0.3), Srgb::new(0.9, 0.8, 0.7));
+
+        let serialized = serde_json::to_string(&Versioned::new(thresholds)).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"version":1,"settings":{"low":{"red":0.1,"green":0.2,"blue":0.3},"high":{"red":0.9,"green":0.8,"blue":0.7}}}"#
+        );
+
+        let deserialized: Versioned<Thresholds<Srgb>> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.into_settings(), Ok(thresholds));
+    }
+
+    #[test]
+    fn round_trip_method_and_white_point() {
+        let settings = (Method::VonKries, D65);
+
+        let serialized = serde_json::to_string(&Versioned::new(settings)).unwrap();
+        let deserialized: Versioned<(Method, D65)> = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized.into_settings(), Ok(settings));
+    }
+
+    #[test]
+    fn unsupported_version() {
+        let newer = format!(
+            r#"{{"version":{},"settings":"Bradford"}}"#,
+            SCHEMA_VERSION + 1
+        );
+        let deserialized: Versioned<Method> = serde_json::from_str(&newer).unwrap();
+        assert_eq!(
+            deserialized.into_settings(),
+            Err(UnsupportedVersionError {
+                version: SCHEMA_VERSION + 1
+            })
+        );
+
+        let zero: Versioned<Method> =
+            serde_json::from_str(r#"{"version":0,"settings":"Bradford"}"#).unwrap();
+        assert_eq!(
+            zero.into_settings(),
+            Err(UnsupportedVersionError { version: 0 })
+        );
+    }
+}
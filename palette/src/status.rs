@@ -0,0 +1,420 @@
+//! Status colors that stay distinguishable with color vision deficiencies.
+//!
+//! User interfaces use a small set of colors to show the status of
+//! something: green for success, amber for warnings, red for errors and blue
+//! for information. Red and green look alike for people with the most common
+//! color vision deficiencies, so the meaning is lost if the colors only
+//! differ in hue. [`StatusRules`] generates the set for a background color,
+//! with each color having enough contrast against the background, and each
+//! pair of colors being different enough when seen with any of the
+//! deficiencies in [`cvd`](crate::cvd):
+//!
+//! ```
+//! use palette::status::StatusRules;
+//! use palette::{RelativeContrast, Srgb};
+//!
+//! let background = Srgb::new(1.0f32, 1.0, 1.0);
+//! let rules = StatusRules::new();
+//! let status = rules.generate(background);
+//!
+//! assert!(status.error.has_min_contrast_graphics(background));
+//! assert!(status.success.has_min_contrast_graphics(background));
+//!
+//! // The colors can be told apart, even with a color vision deficiency.
+//! assert!(rules.min_difference(&status) >= rules.min_difference);
+//! ```
+//!
+//! The colors are chosen in [`Oklch`], with a fixed hue for each status. The
+//! lightness moves away from the background until the contrast is reached,
+//! and further if that's what it takes to make the colors distinguishable,
+//! which is usually how red and green are kept apart. The information color
+//! and the neutral color can follow a brand hue, with
+//! [`with_brand_hue`](StatusRules::with_brand_hue).
+
+use crate::convert::{IntoColor, IntoColorUnclamped};
+use crate::cvd::{CvdSimulation, Deficiency};
+use crate::interaction::{luminance, with_contrast};
+use crate::theme::fit_chroma;
+use crate::white_point::D65;
+use crate::{
+    contrast_ratio, from_f64, ColorDifference, FloatComponent, Lab, LinSrgb, OklabHue, Oklch,
+};
+
+/// The number of lightness values that are tried for each color.
+const CANDIDATES: usize = 12;
+
+/// Rules for generating status colors.
+///
+/// The contrast ratios are the same as for
+/// [`RelativeContrast`](crate::RelativeContrast), and the color differences
+/// are CIEDE2000 differences.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct StatusRules<T> {
+    /// The hue of the brand, which the information and neutral colors follow.
+    /// The information color keeps its own hue if the brand hue is too close
+    /// to one of the other status colors.
+    pub brand_hue: Option<OklabHue<T>>,
+
+    /// The minimum contrast ratio of each color against the background.
+    pub min_contrast: T,
+
+    /// The minimum color difference between each pair of success, warning,
+    /// error and information colors, with normal vision and with each
+    /// deficiency.
+    pub min_difference: T,
+}
+
+impl<T> StatusRules<T>
+where
+    T: FloatComponent,
+{
+    /// Create rules with a minimum contrast of `3.0`, which is the WCAG level
+    /// for user interface components, and a minimum color difference of
+    /// `10.0`.
+    pub fn new() -> Self {
+        StatusRules {
+            brand_hue: None,
+            min_contrast: from_f64(3.0),
+            min_difference: from_f64(10.0),
+        }
+    }
+
+    /// Let the information and neutral colors follow `brand_hue`.
+    pub fn with_brand_hue<H: Into<OklabHue<T>>>(mut self, brand_hue: H) -> Self {
+        self.brand_hue = Some(brand_hue.into());
+        self
+    }
+
+    /// Change the minimum contrast ratio against the background.
+    pub fn with_min_contrast(mut self, min_contrast: T) -> Self {
+        self.min_contrast = min_contrast;
+        self
+    }
+
+    /// Change the minimum color difference between the status colors.
+    pub fn with_min_difference(mut self, min_difference: T) -> Self {
+        self.min_difference = min_difference;
+        self
+    }
+
+    /// Generate the status colors for `background`, in Oklch.
+    ///
+    /// The colors always have the minimum contrast, if it's possible to reach
+    /// it. The minimum difference may not be possible to reach for very high
+    /// contrast requirements, where the lightness has little room to vary.
+    /// The difference is then as large as the search could make it, and can
+    /// be checked with [`min_difference`](StatusRules::min_difference).
+    pub fn generate_oklch(&self, background: Oklch<T>) -> StatusColors<Oklch<T>> {
+        let background_luminance = luminance(background);
+        let darken = contrast_ratio(background_luminance, T::zero())
+            >= contrast_ratio(background_luminance, T::one());
+
+        let brand_hue = self.brand_hue;
+        let info_hue = match brand_hue {
+            Some(hue)
+                if [ERROR_HUE, WARNING_HUE, SUCCESS_HUE]
+                    .iter()
+                    .all(|&other| hue_distance(hue, other) >= from_f64(45.0)) =>
+            {
+                hue
+            }
+            _ => OklabHue::from_degrees(from_f64(INFO_HUE)),
+        };
+
+        let targets = [
+            (OklabHue::from_degrees(from_f64(ERROR_HUE)), 0.19),
+            (OklabHue::from_degrees(from_f64(SUCCESS_HUE)), 0.16),
+            (OklabHue::from_degrees(from_f64(WARNING_HUE)), 0.16),
+            (info_hue, 0.14),
+        ];
+
+        let simulations = [
+            CvdSimulation::full(Deficiency::Protanopia),
+            CvdSimulation::full(Deficiency::Deuteranopia),
+            CvdSimulation::full(Deficiency::Tritanopia),
+        ];
+
+        let mut chosen: [Option<Views<T>>; 4] = [None; 4];
+        for (index, &(hue, chroma)) in targets.iter().enumerate() {
+            let start = Oklch {
+                l: background.l,
+                chroma: from_f64(chroma),
+                hue,
+            };
+            let edge = with_contrast(start, background_luminance, self.min_contrast, darken);
+            let far: T = if darken {
+                from_f64(0.25)
+            } else {
+                from_f64(0.95)
+            };
+
+            let mut best = None;
+            let mut best_difference = T::zero();
+            for step in 0..CANDIDATES {
+                let factor: T = from_f64(step as f64 / (CANDIDATES - 1) as f64);
+                let l = edge.l + (far - edge.l) * factor;
+                if (darken && l > edge.l) || (!darken && l < edge.l) {
+                    break;
+                }
+
+                let candidate = fit_chroma(Oklch { l, ..start });
+                let is_contrasting = step == 0
+                    || contrast_ratio(luminance(candidate), background_luminance)
+                        >= self.min_contrast;
+                if !is_contrasting {
+                    continue;
+                }
+
+                let views = Views::new(candidate, &simulations);
+                let difference = chosen[..index]
+                    .iter()
+                    .flatten()
+                    .map(|other| views.difference(other))
+                    .fold(T::infinity(), T::min);
+
+                if best.is_none() || difference > best_difference {
+                    best = Some(views);
+                    best_difference = difference;
+                }
+
+                if difference >= self.min_difference {
+                    break;
+                }
+            }
+
+            chosen[index] = best;
+        }
+
+        let color = |index: usize| chosen[index].map_or(background, |views| views.color);
+
+        let neutral = with_contrast(
+            Oklch {
+                l: background.l,
+                chroma: if brand_hue.is_some() {
+                    from_f64(0.02)
+                } else {
+                    T::zero()
+                },
+                hue: brand_hue.unwrap_or(info_hue),
+            },
+            background_luminance,
+            self.min_contrast,
+            darken,
+        );
+
+        StatusColors {
+            success: color(1),
+            warning: color(2),
+            error: color(0),
+            info: color(3),
+            neutral,
+        }
+    }
+
+    /// Generate the status colors for `background`.
+    pub fn generate<C>(&self, background: C) -> StatusColors<C>
+    where
+        C: IntoColorUnclamped<Oklch<T>>,
+        Oklch<T>: IntoColor<C>,
+    {
+        let colors = self.generate_oklch(background.into_color_unclamped());
+
+        StatusColors {
+            success: colors.success.into_color(),
+            warning: colors.warning.into_color(),
+            error: colors.error.into_color(),
+            info: colors.info.into_color(),
+            neutral: colors.neutral.into_color(),
+        }
+    }
+
+    /// Get the smallest color difference between any pair of success,
+    /// warning, error and information colors in `colors`, with normal vision
+    /// or with any of the deficiencies.
+    pub fn min_difference<C>(&self, colors: &StatusColors<C>) -> T
+    where
+        C: Clone + IntoColorUnclamped<Oklch<T>>,
+    {
+        let simulations = [
+            CvdSimulation::full(Deficiency::Protanopia),
+            CvdSimulation::full(Deficiency::Deuteranopia),
+            CvdSimulation::full(Deficiency::Tritanopia),
+        ];
+
+        let views = [
+            Views::new(colors.success.clone().into_color_unclamped(), &simulations),
+            Views::new(colors.warning.clone().into_color_unclamped(), &simulations),
+            Views::new(colors.error.clone().into_color_unclamped(), &simulations),
+            Views::new(colors.info.clone().into_color_unclamped(), &simulations),
+        ];
+
+        let mut min = T::infinity();
+        for (index, first) in views.iter().enumerate() {
+            for second in &views[index + 1..] {
+                min = min.min(first.difference(second));
+            }
+        }
+
+        min
+    }
+}
+
+impl<T> Default for StatusRules<T>
+where
+    T: FloatComponent,
+{
+    fn default() -> Self {
+        StatusRules::new()
+    }
+}
+
+/// A set of status colors, from [`StatusRules`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct StatusColors<C> {
+    /// The color for a successful or positive status.
+    pub success: C,
+
+    /// The color for a warning, that needs attention.
+    pub warning: C,
+
+    /// The color for an error or a destructive action.
+    pub error: C,
+
+    /// The color for neutral information.
+    pub info: C,
+
+    /// A gray, or a gray that is tinted with the brand hue, for an inactive
+    /// or unknown status.
+    pub neutral: C,
+}
+
+/// The hues of the status colors, in degrees.
+const ERROR_HUE: f64 = 27.0;
+const WARNING_HUE: f64 = 70.0;
+const SUCCESS_HUE: f64 = 145.0;
+const INFO_HUE: f64 = 250.0;
+
+fn hue_distance<T: FloatComponent>(hue: OklabHue<T>, degrees: f64) -> T {
+    (hue - OklabHue::from_degrees(from_f64(degrees)))
+        .to_degrees()
+        .abs()
+}
+
+/// A color as it's seen with normal vision and with each deficiency.
+#[derive(Clone, Copy)]
+struct Views<T> {
+    color: Oklch<T>,
+    seen: [Lab<D65, T>; 4],
+}
+
+impl<T: FloatComponent> Views<T> {
+    fn new(color: Oklch<T>, simulations: &[CvdSimulation<T>; 3]) -> Self {
+        let linear: LinSrgb<T> = color.into_color_unclamped();
+        let seen = |simulation: &CvdSimulation<T>| {
+            simulation.simulate_linear(linear).into_color_unclamped()
+        };
+
+        Views {
+            color,
+            seen: [
+                linear.into_color_unclamped(),
+                seen(&simulations[0]),
+                seen(&simulations[1]),
+                seen(&simulations[2]),
+            ],
+        }
+    }
+
+    fn difference(&self, other: &Self) -> T {
+        self.seen
+            .iter()
+            .zip(&other.seen)
+            .map(|(&first, &second)| first.get_color_difference(second))
+            .fold(T::infinity(), T::min)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{StatusColors, StatusRules};
+    use crate::{FromColor, Oklch, RelativeContrast, Srgb};
+
+    #[test]
+    fn light_and_dark_backgrounds() {
+        let rules = StatusRules::<f64>::new();
+
+        for &background in &[
+            Srgb::new(1.0, 1.0, 1.0),
+            Srgb::new(0.96, 0.95, 0.92),
+            Srgb::new(0.07, 0.07, 0.08),
+            Srgb::new(0.15, 0.18, 0.25),
+        ] {
+            let status = rules.generate(background);
+
+            for &color in &[
+                status.success,
+                status.warning,
+                status.error,
+                status.info,
+                status.neutral,
+            ] {
+                assert!(color.get_contrast_ratio(background) >= 3.0 - 1e-6);
+            }
+
+            assert!(rules.min_difference(&status) >= 10.0);
+        }
+    }
+
+    #[test]
+    fn hues() {
+        let status = StatusRules::<f64>::new().generate(Srgb::new(1.0, 1.0, 1.0));
+
+        assert!(status.error.red > status.error.green);
+        assert!(status.success.green > status.success.red);
+        assert!(status.info.blue > status.info.red);
+        assert!(status.warning.red > status.warning.blue);
+
+        let neutral = Oklch::from_color(status.neutral);
+        assert!(neutral.chroma < 1e-6);
+    }
+
+    #[test]
+    fn brand_hue() {
+        let background = Srgb::new(1.0, 1.0, 1.0);
+
+        // A purple brand changes the information color.
+        let purple = StatusRules::<f64>::new()
+            .with_brand_hue(300.0)
+            .generate(background);
+        let info = Oklch::from_color(purple.info);
+        assert_relative_eq!(info.hue.to_positive_degrees(), 300.0, epsilon = 1e-3);
+        let neutral = Oklch::from_color(purple.neutral);
+        assert!(neutral.chroma > 0.0);
+
+        // A red brand would be mistaken for an error.
+        let red = StatusRules::<f64>::new()
+            .with_brand_hue(20.0)
+            .generate(background);
+        let info = Oklch::from_color(red.info);
+        assert_relative_eq!(info.hue.to_positive_degrees(), 250.0, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn min_difference() {
+        let rules = StatusRules::<f64>::new();
+        let red = Srgb::new(0.8, 0.2, 0.1);
+        let green = Srgb::new(0.4, 0.5, 0.1);
+        let status = StatusColors {
+            success: green,
+            warning: Srgb::new(0.9, 0.6, 0.0),
+            error: red,
+            info: Srgb::new(0.1, 0.3, 0.9),
+            neutral: Srgb::new(0.5, 0.5, 0.5),
+        };
+
+        // Red and green are close with a deficiency.
+        assert!(rules.min_difference(&status) < 10.0);
+    }
+}
@@ -0,0 +1,151 @@
+//! Combined spatial and color distances for superpixel segmentation.
+//!
+//! Superpixel algorithms, such as SLIC, group pixels that are both close to
+//! each other and similar in color. [`SlicDistance`] combines the two into a
+//! single distance, using the Euclidean distance between colors in a
+//! perceptually uniform space, such as [`Lab`](crate::Lab) or
+//! [`Oklab`](crate::Oklab), instead of in RGB.
+//!
+//! ```
+//! use palette::superpixel::SlicDistance;
+//! use palette::{FromColor, Lab, Srgb};
+//!
+//! // Aim for about 100 superpixels in a 640 by 480 image.
+//! let slic = SlicDistance::for_image(10.0, 640, 480, 100);
+//!
+//! let center: Lab = Lab::from_color(Srgb::new(0.8, 0.3, 0.2));
+//! let pixel: Lab = Lab::from_color(Srgb::new(0.75, 0.3, 0.25));
+//!
+//! let distance = slic.distance([320.0, 240.0], &center, [330.0, 250.0], &pixel);
+//! assert!(distance > 0.0);
+//! ```
+
+use crate::cast::{self, ArrayCast};
+use crate::{from_f64, FloatComponent};
+
+/// The SLIC distance measure, which weighs the spatial distance against the
+/// color distance.
+///
+/// The distance is `sqrt(d_c² + (d_s / S)² · m²)`, where `d_c` is the color
+/// distance, `d_s` is the spatial distance, `S` is the grid interval and `m`
+/// is the compactness.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct SlicDistance<T> {
+    /// The weight of the spatial distance, relative to the color distance.
+    /// Higher values give more compact and regular superpixels.
+    ///
+    /// It depends on the color space. Values from 1 to 40 are typical for
+    /// CIE L\*a\*b\*, and Oklab needs about a hundredth of that.
+    pub compactness: T,
+
+    /// The expected distance between superpixel centers, in pixels.
+    pub grid_interval: T,
+}
+
+impl<T> SlicDistance<T>
+where
+    T: FloatComponent,
+{
+    /// Create a distance measure with the given compactness and grid
+    /// interval.
+    pub fn new(compactness: T, grid_interval: T) -> Self {
+        SlicDistance {
+            compactness,
+            grid_interval,
+        }
+    }
+
+    /// Create a distance measure for dividing an image of `width` by `height`
+    /// pixels into about `superpixels` superpixels.
+    ///
+    /// The grid interval is `sqrt(width · height / superpixels)`.
+    pub fn for_image(compactness: T, width: usize, height: usize, superpixels: usize) -> Self {
+        let pixels = from_f64::<T>(width as f64) * from_f64(height as f64);
+        let superpixels = from_f64::<T>(superpixels.max(1) as f64);
+
+        SlicDistance::new(compactness, (pixels / superpixels).sqrt())
+    }
+
+    /// Calculate the squared distance between two pixels.
+    ///
+    /// This avoids a square root and gives the same order as
+    /// [`distance`](SlicDistance::distance), which makes it the better
+    /// choice for finding the closest superpixel center.
+    pub fn distance_squared<C, const N: usize>(
+        &self,
+        position_a: [T; 2],
+        color_a: &C,
+        position_b: [T; 2],
+        color_b: &C,
+    ) -> T
+    where
+        C: ArrayCast<Array = [T; N]>,
+    {
+        let color_distance = cast::into_array_ref(color_a)
+            .iter()
+            .zip(cast::into_array_ref(color_b))
+            .fold(T::zero(), |sum, (&a, &b)| sum + (a - b) * (a - b));
+
+        let [xa, ya] = position_a;
+        let [xb, yb] = position_b;
+        let spatial_distance = (xa - xb) * (xa - xb) + (ya - yb) * (ya - yb);
+
+        let weight = self.compactness / self.grid_interval;
+
+        color_distance + spatial_distance * weight * weight
+    }
+
+    /// Calculate the distance between two pixels.
+    pub fn distance<C, const N: usize>(
+        &self,
+        position_a: [T; 2],
+        color_a: &C,
+        position_b: [T; 2],
+        color_b: &C,
+    ) -> T
+    where
+        C: ArrayCast<Array = [T; N]>,
+    {
+        self.distance_squared(position_a, color_a, position_b, color_b)
+            .sqrt()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SlicDistance;
+    use crate::white_point::D65;
+    use crate::Lab;
+
+    #[test]
+    fn color_only() {
+        let slic = SlicDistance::new(10.0, 20.0);
+        let a: Lab<D65, f64> = Lab::new(50.0, 10.0, 0.0);
+        let b = Lab::new(53.0, 6.0, 0.0);
+
+        assert_relative_eq!(slic.distance([1.0, 2.0], &a, [1.0, 2.0], &b), 5.0);
+    }
+
+    #[test]
+    fn spatial_and_color() {
+        let slic = SlicDistance::new(10.0, 20.0);
+        let a: Lab<D65, f64> = Lab::new(50.0, 10.0, 0.0);
+        let b = Lab::new(53.0, 6.0, 0.0);
+
+        // d_s = 10, so the spatial term is (10 / 20)² · 10² = 25.
+        let distance_squared = slic.distance_squared([0.0, 0.0], &a, [6.0, 8.0], &b);
+        assert_relative_eq!(distance_squared, 25.0 + 25.0);
+        assert_relative_eq!(
+            slic.distance([0.0, 0.0], &a, [6.0, 8.0], &b),
+            50.0f64.sqrt()
+        );
+    }
+
+    #[test]
+    fn for_image() {
+        let slic = SlicDistance::for_image(10.0f32, 400, 100, 100);
+        assert_relative_eq!(slic.grid_interval, 20.0);
+        assert_relative_eq!(slic.compactness, 10.0);
+    }
+}
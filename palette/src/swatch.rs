@@ -0,0 +1,382 @@
+//! Render palettes and gradients as swatch images.
+//!
+//! This is a small renderer for visualizing colors in documentation tools
+//! and debuggers, without depending on a graphics library. It draws strips
+//! or grids of swatches, and gradient strips, either as an SVG string or as
+//! an 8 bit RGBA image:
+//!
+//! ```
+//! use palette::swatch::SwatchRenderer;
+//! use palette::Srgb;
+//!
+//! let colors = [
+//!     Srgb::new(0.9f32, 0.2, 0.2),
+//!     Srgb::new(0.2, 0.6, 0.3),
+//!     Srgb::new(0.1, 0.3, 0.8),
+//! ];
+//!
+//! let renderer = SwatchRenderer::new().with_swatch_size(16, 16).with_gap(2);
+//!
+//! let svg = renderer.render_svg(&colors);
+//! assert!(svg.contains(r##"fill="#e63333""##));
+//!
+//! let image = renderer.render_rgba8(&colors);
+//! assert_eq!((image.width, image.height), (52, 16));
+//! let bytes = image.into_raw();
+//! assert_eq!(bytes.len(), 52 * 16 * 4);
+//! ```
+//!
+//! The colors are converted to sRGB before they are drawn, and gradients
+//! are sampled in their own color space, so a gradient in linear RGB or
+//! [`Oklab`](crate::Oklab) looks the same as when it's used in a program.
+//! SVG gradients are drawn with many closely spaced stops, since SVG viewers
+//! would otherwise interpolate in gamma encoded sRGB.
+//!
+//! This module is only available if the `swatch` feature is enabled.
+
+use core::fmt::Write;
+
+use crate::cast;
+use crate::convert::{IntoColor, IntoColorUnclamped};
+use crate::{Clamp, FloatComponent, Gradient, IntoComponent, Mix, Srgb, Srgba};
+
+/// The number of stops in SVG gradients.
+const SVG_GRADIENT_STOPS: usize = 64;
+
+/// Draws strips or grids of color swatches.
+///
+/// The swatches are placed left to right, and wrap to a new row after the
+/// number of [columns](SwatchRenderer::with_columns). Any gaps between them
+/// are transparent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SwatchRenderer {
+    columns: Option<usize>,
+    swatch_width: u32,
+    swatch_height: u32,
+    gap: u32,
+}
+
+impl SwatchRenderer {
+    /// Create a renderer for a single row of 32 by 32 pixel swatches,
+    /// without gaps.
+    pub fn new() -> Self {
+        SwatchRenderer {
+            columns: None,
+            swatch_width: 32,
+            swatch_height: 32,
+            gap: 0,
+        }
+    }
+
+    /// Wrap the swatches into a grid with `columns` swatches per row.
+    ///
+    /// Zero columns is the same as one.
+    pub fn with_columns(mut self, columns: usize) -> Self {
+        self.columns = Some(columns.max(1));
+        self
+    }
+
+    /// Change the size of each swatch, in pixels.
+    pub fn with_swatch_size(mut self, width: u32, height: u32) -> Self {
+        self.swatch_width = width;
+        self.swatch_height = height;
+        self
+    }
+
+    /// Change the space between the swatches, in pixels.
+    pub fn with_gap(mut self, gap: u32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// The size of the image for `count` swatches, as `(width, height)`.
+    pub fn dimensions(&self, count: usize) -> (u32, u32) {
+        let (columns, rows) = self.grid(count);
+
+        (
+            span(columns, self.swatch_width, self.gap),
+            span(rows, self.swatch_height, self.gap),
+        )
+    }
+
+    /// Draw `colors` as an SVG document.
+    pub fn render_svg<C, T>(&self, colors: &[C]) -> String
+    where
+        C: IntoColor<Srgba<T>> + Copy,
+        T: FloatComponent + IntoComponent<u8>,
+    {
+        let (width, height) = self.dimensions(colors.len());
+        let mut svg = svg_header(width, height);
+
+        for (index, &color) in colors.iter().enumerate() {
+            let (x, y) = self.position(index);
+            let color = into_srgba8(color);
+
+            let _ = write!(
+                svg,
+                r#"<rect x="{}" y="{}" width="{}" height="{}""#,
+                x, y, self.swatch_width, self.swatch_height
+            );
+            write_fill(&mut svg, "fill", color);
+            svg.push_str("/>\n");
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Draw `colors` as an 8 bit RGBA image.
+    pub fn render_rgba8<C, T>(&self, colors: &[C]) -> SwatchImage
+    where
+        C: IntoColor<Srgba<T>> + Copy,
+        T: FloatComponent + IntoComponent<u8>,
+    {
+        let (width, height) = self.dimensions(colors.len());
+        let mut image = SwatchImage::new(width, height);
+
+        for (index, &color) in colors.iter().enumerate() {
+            let (x, y) = self.position(index);
+            let color = into_srgba8(color);
+
+            for row in y..y + self.swatch_height {
+                let start = (row * width + x) as usize;
+                let end = start + self.swatch_width as usize;
+                for pixel in &mut image.pixels[start..end] {
+                    *pixel = color;
+                }
+            }
+        }
+
+        image
+    }
+
+    /// The number of used `(columns, rows)` for `count` swatches.
+    fn grid(&self, count: usize) -> (usize, usize) {
+        if count == 0 {
+            return (0, 0);
+        }
+
+        match self.columns {
+            Some(columns) => (columns.min(count), (count - 1) / columns + 1),
+            None => (count, 1),
+        }
+    }
+
+    /// The top left corner of the swatch at `index`.
+    fn position(&self, index: usize) -> (u32, u32) {
+        let (column, row) = match self.columns {
+            Some(columns) => (index % columns, index / columns),
+            None => (index, 0),
+        };
+
+        (
+            column as u32 * (self.swatch_width + self.gap),
+            row as u32 * (self.swatch_height + self.gap),
+        )
+    }
+}
+
+impl Default for SwatchRenderer {
+    fn default() -> Self {
+        SwatchRenderer::new()
+    }
+}
+
+/// An 8 bit sRGB image, stored row by row from the top left corner.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SwatchImage {
+    /// The width of the image, in pixels.
+    pub width: u32,
+
+    /// The height of the image, in pixels.
+    pub height: u32,
+
+    /// The pixels of the image, `width * height` of them.
+    pub pixels: Vec<Srgba<u8>>,
+}
+
+impl SwatchImage {
+    /// Create a fully transparent image.
+    fn new(width: u32, height: u32) -> Self {
+        SwatchImage {
+            width,
+            height,
+            pixels: vec![Srgba::new(0, 0, 0, 0); width as usize * height as usize],
+        }
+    }
+
+    /// The pixel components as a slice of `[r, g, b, a, r, g, b, a, ...]`
+    /// bytes.
+    pub fn as_raw(&self) -> &[u8] {
+        cast::into_component_slice(&self.pixels)
+    }
+
+    /// Take out the pixel components as a vector of
+    /// `[r, g, b, a, r, g, b, a, ...]` bytes, for encoding the image.
+    pub fn into_raw(self) -> Vec<u8> {
+        cast::into_component_vec(self.pixels)
+    }
+}
+
+/// Draw `gradient` as a `width` by `height` pixel SVG document.
+///
+/// The gradient is drawn from left to right, over its whole domain.
+pub fn gradient_svg<C>(gradient: &Gradient<C>, width: u32, height: u32) -> String
+where
+    C: Mix + Clone + IntoColorUnclamped<Srgb<C::Scalar>>,
+    C::Scalar: FloatComponent + IntoComponent<u8>,
+{
+    let mut svg = svg_header(width, height);
+
+    svg.push_str(r#"<defs><linearGradient id="gradient">"#);
+    svg.push('\n');
+    let last = (SVG_GRADIENT_STOPS - 1) as f64;
+    for (index, color) in gradient.take(SVG_GRADIENT_STOPS).enumerate() {
+        let color: Srgb<C::Scalar> = color.into_color_unclamped();
+        let color = Srgba::from(color.clamp().into_format::<u8>());
+
+        let _ = write!(svg, r#"<stop offset="{}""#, index as f64 / last);
+        write_fill(&mut svg, "stop-color", color);
+        svg.push_str("/>\n");
+    }
+    svg.push_str("</linearGradient></defs>\n");
+
+    let _ = writeln!(
+        svg,
+        r#"<rect width="{}" height="{}" fill="url(#gradient)"/>"#,
+        width, height
+    );
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Draw `gradient` as a `width` by `height` pixel 8 bit RGBA image.
+///
+/// The gradient is drawn from left to right, over its whole domain. See
+/// [`Gradient::fill_slice`] for what `dither` does.
+pub fn gradient_rgba8<C>(
+    gradient: &Gradient<C>,
+    width: u32,
+    height: u32,
+    dither: bool,
+) -> SwatchImage
+where
+    C: Mix + Clone + IntoColorUnclamped<Srgb<C::Scalar>>,
+    C::Scalar: FloatComponent,
+{
+    let mut row = vec![Srgb::new(0, 0, 0); width as usize];
+    gradient.fill_slice(&mut row, dither);
+
+    let mut image = SwatchImage::new(width, height);
+    for pixels in image.pixels.chunks_mut(width.max(1) as usize) {
+        for (pixel, &color) in pixels.iter_mut().zip(&row) {
+            *pixel = color.into();
+        }
+    }
+
+    image
+}
+
+/// The length of `count` items of `size`, with `gap` between them.
+fn span(count: usize, size: u32, gap: u32) -> u32 {
+    if count == 0 {
+        0
+    } else {
+        count as u32 * size + (count as u32 - 1) * gap
+    }
+}
+
+fn into_srgba8<C, T>(color: C) -> Srgba<u8>
+where
+    C: IntoColor<Srgba<T>>,
+    T: FloatComponent + IntoComponent<u8>,
+{
+    let color: Srgba<T> = color.into_color();
+    color.into_format()
+}
+
+fn svg_header(width: u32, height: u32) -> String {
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {0} {1}\">\n",
+        width, height
+    )
+}
+
+/// Write `color` as ` attribute="#rrggbb"`, with an opacity attribute if
+/// it's not opaque.
+fn write_fill(svg: &mut String, attribute: &str, color: Srgba<u8>) {
+    let _ = write!(svg, r##" {}="#{:x}""##, attribute, color.color);
+
+    if color.alpha != u8::MAX {
+        let opacity = if attribute == "fill" {
+            "fill-opacity"
+        } else {
+            "stop-opacity"
+        };
+        let _ = write!(svg, r#" {}="{}""#, opacity, f32::from(color.alpha) / 255.0);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{gradient_rgba8, gradient_svg, SwatchRenderer};
+    use crate::{Gradient, LinSrgb, Srgb, Srgba};
+
+    #[test]
+    fn grid_dimensions() {
+        let renderer = SwatchRenderer::new()
+            .with_swatch_size(10, 5)
+            .with_gap(1)
+            .with_columns(3);
+
+        assert_eq!(renderer.dimensions(0), (0, 0));
+        assert_eq!(renderer.dimensions(2), (21, 5));
+        assert_eq!(renderer.dimensions(7), (32, 17));
+    }
+
+    #[test]
+    fn rgba8_grid() {
+        let colors = [
+            Srgba::new(1.0f32, 0.0, 0.0, 1.0),
+            Srgba::new(0.0, 1.0, 0.0, 0.5),
+            Srgba::new(0.0, 0.0, 1.0, 1.0),
+        ];
+        let image = SwatchRenderer::new()
+            .with_swatch_size(2, 2)
+            .with_gap(1)
+            .with_columns(2)
+            .render_rgba8(&colors);
+
+        assert_eq!((image.width, image.height), (5, 5));
+        assert_eq!(image.pixels[0], Srgba::new(255, 0, 0, 255));
+        assert_eq!(image.pixels[2], Srgba::new(0, 0, 0, 0));
+        assert_eq!(image.pixels[4], Srgba::new(0, 255, 0, 128));
+        assert_eq!(image.pixels[3 * 5 + 1], Srgba::new(0, 0, 255, 255));
+        assert_eq!(image.pixels[3 * 5 + 4], Srgba::new(0, 0, 0, 0));
+        assert_eq!(image.as_raw().len(), 5 * 5 * 4);
+    }
+
+    #[test]
+    fn svg_opacity() {
+        let svg = SwatchRenderer::new().render_svg(&[Srgba::new(1.0f32, 1.0, 1.0, 0.5)]);
+        assert!(svg.contains(r##"fill="#ffffff" fill-opacity="0.5019608""##));
+    }
+
+    #[test]
+    fn gradient_is_gamma_correct() {
+        let gradient = Gradient::new(vec![
+            LinSrgb::new(0.0f32, 0.0, 0.0),
+            LinSrgb::new(1.0, 1.0, 1.0),
+        ]);
+
+        let image = gradient_rgba8(&gradient, 3, 2, false);
+        let middle: Srgb<u8> = Srgb::from_linear(LinSrgb::new(0.5f32, 0.5, 0.5)).into_format();
+        assert_eq!(image.pixels[1].color, middle);
+        assert_eq!(image.pixels[4].color, middle);
+
+        let svg = gradient_svg(&gradient, 100, 10);
+        assert_eq!(svg.matches("<stop").count(), 64);
+        assert!(svg.contains(r##"<stop offset="0" stop-color="#000000"/>"##));
+        assert!(svg.contains(r##"<stop offset="1" stop-color="#ffffff"/>"##));
+    }
+}
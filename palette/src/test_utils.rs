@@ -0,0 +1,496 @@
+//! Test helpers for color types.
+//!
+//! These are the macros that palette uses for testing its own color types,
+//! so color types that are implemented outside of palette can be checked
+//! against the same invariants. Can be toggled with the `"test_utils"` Cargo
+//! feature.
+//!
+//! The macros are exported at the root of the crate:
+//!
+//! * [`assert_ranges!`](crate::assert_ranges) checks the bounds of the
+//!   components, and that clamping respects them.
+//! * [`raw_pixel_conversion_tests!`](crate::raw_pixel_conversion_tests)
+//!   generates tests for casting to and from arrays and slices.
+//! * [`raw_pixel_conversion_fail_tests!`](crate::raw_pixel_conversion_fail_tests)
+//!   generates tests for casting from slices that are too short.
+//! * [`assert_colors_eq!`](crate::assert_colors_eq) checks that two colors,
+//!   possibly of different types, look the same.
+
+use core::fmt::Debug;
+
+use crate::convert::IntoColorUnclamped;
+use crate::white_point::D65;
+use crate::{ColorDifference, FloatComponent, Lab};
+
+#[doc(hidden)]
+pub use ::approx::assert_relative_eq;
+
+/// Check that a color type is clamped and checked for bounds correctly.
+///
+/// The components are divided into three groups:
+///
+/// * `clamped` components have a lower and an upper limit.
+/// * `clamped_min` components only have a lower limit.
+/// * `unclamped` components have no limits, such as hues.
+///
+/// Each component is listed with a range, and the macro checks values
+/// below, within and above the range against
+/// [`IsWithinBounds`](crate::IsWithinBounds) and [`Clamp`](crate::Clamp).
+/// The color type also needs to implement `Default` and
+/// [`RelativeEq`](approx::RelativeEq), and the components need to implement
+/// `From<f64>`.
+///
+/// ```
+/// use palette::{assert_ranges, Oklab};
+///
+/// assert_ranges! {
+///     Oklab<f64>;
+///     clamped {
+///         l: 0.0 => 1.0,
+///         a: -1.0 => 1.0,
+///         b: -1.0 => 1.0
+///     }
+///     clamped_min {}
+///     unclamped {}
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_ranges {
+    (@make_tuple $first:pat, $next:ident,) => (($first, $next));
+
+    (@make_tuple $first:pat, $next:ident, $($rest:ident,)*) => (
+        $crate::assert_ranges!(@make_tuple ($first, $next), $($rest,)*)
+    );
+
+    (
+        $ty:ident < $($ty_params:ty),+ >;
+        clamped {$($clamped:ident: $clamped_from:expr => $clamped_to:expr),+}
+        clamped_min {$($clamped_min:ident: $clamped_min_from:expr => $clamped_min_to:expr),*}
+        unclamped {$($unclamped:ident: $unclamped_from:expr => $unclamped_to:expr),*}
+    ) => (
+        {
+            use core::iter::repeat;
+            use $crate::{Clamp, IsWithinBounds};
+
+            {
+                print!("checking below clamp bounds... ");
+                $(
+                    let from = $clamped_from;
+                    let to = $clamped_to;
+                    let diff = to - from;
+                    let $clamped = (1..11).map(|i| from - (i as f64 / 10.0) * diff);
+                )+
+
+                $(
+                    let from = $clamped_min_from;
+                    let to = $clamped_min_to;
+                    let diff = to - from;
+                    let $clamped_min = (1..11).map(|i| from - (i as f64 / 10.0) * diff);
+                )*
+
+                $(
+                    let from = $unclamped_from;
+                    let to = $unclamped_to;
+                    let diff = to - from;
+                    let $unclamped = (1..11).map(|i| from - (i as f64 / 10.0) * diff);
+                )*
+
+                for $crate::assert_ranges!(@make_tuple (), $($clamped,)+ $($clamped_min,)* $($unclamped,)* ) in repeat(()) $(.zip($clamped))+ $(.zip($clamped_min))* $(.zip($unclamped))* {
+                    let color: $ty<$($ty_params),+> = $ty {
+                        $($clamped: $clamped.into(),)+
+                        $($clamped_min: $clamped_min.into(),)*
+                        $($unclamped: $unclamped.into(),)*
+                        ..$ty::default() //This prevents exhaustiveness checking
+                    };
+
+                    let clamped = color.clamp();
+
+                    let expected: $ty<$($ty_params),+> = $ty {
+                        $($clamped: $clamped_from.into(),)+
+                        $($clamped_min: $clamped_min_from.into(),)*
+                        $($unclamped: $unclamped.into(),)*
+                        ..$ty::default() //This prevents exhaustiveness checking
+                    };
+
+                    assert!(!color.is_within_bounds());
+                    $crate::test_utils::assert_relative_eq!(clamped, expected);
+                }
+
+                println!("ok")
+            }
+
+            {
+                print!("checking within clamp bounds... ");
+                $(
+                    let from = $clamped_from;
+                    let to = $clamped_to;
+                    let diff = to - from;
+                    let $clamped = (0..11).map(|i| from + (i as f64 / 10.0) * diff);
+                )+
+
+                $(
+                    let from = $clamped_min_from;
+                    let to = $clamped_min_to;
+                    let diff = to - from;
+                    let $clamped_min = (0..11).map(|i| from + (i as f64 / 10.0) * diff);
+                )*
+
+                $(
+                    let from = $unclamped_from;
+                    let to = $unclamped_to;
+                    let diff = to - from;
+                    let $unclamped = (0..11).map(|i| from + (i as f64 / 10.0) * diff);
+                )*
+
+                for $crate::assert_ranges!(@make_tuple (), $($clamped,)+ $($clamped_min,)* $($unclamped,)* ) in repeat(()) $(.zip($clamped))+ $(.zip($clamped_min))* $(.zip($unclamped))* {
+                    let color: $ty<$($ty_params),+> = $ty {
+                        $($clamped: $clamped.into(),)+
+                        $($clamped_min: $clamped_min.into(),)*
+                        $($unclamped: $unclamped.into(),)*
+                        ..$ty::default() //This prevents exhaustiveness checking
+                    };
+
+                    let clamped = color.clamp();
+
+                    assert!(color.is_within_bounds());
+                    $crate::test_utils::assert_relative_eq!(clamped, color);
+                }
+
+                println!("ok")
+            }
+
+            {
+                print!("checking above clamp bounds... ");
+                $(
+                    let from = $clamped_from;
+                    let to = $clamped_to;
+                    let diff = to - from;
+                    let $clamped = (1..11).map(|i| to + (i as f64 / 10.0) * diff);
+                )+
+
+                $(
+                    let from = $clamped_min_from;
+                    let to = $clamped_min_to;
+                    let diff = to - from;
+                    let $clamped_min = (1..11).map(|i| to + (i as f64 / 10.0) * diff);
+                )*
+
+                $(
+                    let from = $unclamped_from;
+                    let to = $unclamped_to;
+                    let diff = to - from;
+                    let $unclamped = (1..11).map(|i| to + (i as f64 / 10.0) * diff);
+                )*
+
+                for $crate::assert_ranges!(@make_tuple (), $($clamped,)+ $($clamped_min,)* $($unclamped,)* ) in repeat(()) $(.zip($clamped))+ $(.zip($clamped_min))* $(.zip($unclamped))* {
+                    let color: $ty<$($ty_params),+> = $ty {
+                        $($clamped: $clamped.into(),)+
+                        $($clamped_min: $clamped_min.into(),)*
+                        $($unclamped: $unclamped.into(),)*
+                        ..$ty::default() //This prevents exhaustiveness checking
+                    };
+
+                    let clamped = color.clamp();
+
+                    let expected: $ty<$($ty_params),+> = $ty {
+                        $($clamped: $clamped_to.into(),)+
+                        $($clamped_min: $clamped_min.into(),)*
+                        $($unclamped: $unclamped.into(),)*
+                        ..$ty::default() //This prevents exhaustiveness checking
+                    };
+
+                    assert!(!color.is_within_bounds());
+                    $crate::test_utils::assert_relative_eq!(clamped, expected);
+                }
+
+                println!("ok")
+            }
+        }
+    );
+}
+
+/// Generate tests for casting between a color type and arrays or slices of
+/// its components, with and without alpha.
+///
+/// The type is written as its name, its type parameters except for the
+/// component type, and the names of its components in order. The tests are
+/// run for `f32` and `f64` components, and they are generated as `#[test]`
+/// functions, so the macro should be used at the item level of a test
+/// module.
+///
+/// The color type needs to implement [`ArrayCast`](crate::cast::ArrayCast)
+/// and `TryFrom<&[T]>` for references to it, as well as for references to
+/// it with [`Alpha`](crate::Alpha).
+///
+/// ```
+/// #[cfg(test)]
+/// mod test {
+///     use palette::white_point::D65;
+///     use palette::Lab;
+///
+///     palette::raw_pixel_conversion_tests!(Lab<D65>: l, a, b);
+///     palette::raw_pixel_conversion_fail_tests!(Lab<D65>: l, a, b);
+/// }
+/// ```
+#[macro_export]
+macro_rules! raw_pixel_conversion_tests {
+    ($name: ident <$($ty_param: path),+> : $($component: ident),+) => {
+        #[test]
+        fn convert_from_f32_array() {
+            $crate::raw_pixel_conversion_tests!(@float_array_test f32, $name<$($ty_param),+>: $($component),+);
+        }
+
+        #[test]
+        fn convert_from_f64_array() {
+            $crate::raw_pixel_conversion_tests!(@float_array_test f64, $name<$($ty_param),+>: $($component),+);
+        }
+
+        #[test]
+        fn convert_from_f32_slice() {
+            $crate::raw_pixel_conversion_tests!(@float_slice_test f32, $name<$($ty_param),+>: $($component),+);
+        }
+
+        #[test]
+        fn convert_from_f64_slice() {
+            $crate::raw_pixel_conversion_tests!(@float_slice_test f64, $name<$($ty_param),+>: $($component),+);
+        }
+    };
+
+    (@float_array_test $float: ty, $name: ident <$($ty_param: path),+> : $($component: ident),+) => {
+        use $crate::cast::ArrayCast;
+        use $crate::Alpha;
+
+        let mut counter: $float = 0.0;
+        $(
+            counter += 0.1;
+            let $component = counter;
+        )+
+        let alpha = counter + 0.1;
+
+        let raw: <$name<$($ty_param,)+ $float> as ArrayCast>::Array = [$($component),+];
+        let raw_plus_1: <Alpha<$name<$($ty_param,)+ $float>, $float> as ArrayCast>::Array = [
+            $($component,)+
+            alpha
+        ];
+        let color: $name<$($ty_param,)+ $float> = $crate::cast::from_array(raw);
+
+        let color_alpha: Alpha<$name<$($ty_param,)+ $float>, $float> = $crate::cast::from_array(raw_plus_1);
+
+        assert_eq!(color, $name::new($($component),+));
+
+        assert_eq!(color_alpha, Alpha::<$name<$($ty_param,)+ $float>, $float>::new($($component,)+ alpha));
+    };
+
+    (@float_slice_test $float: ty, $name: ident <$($ty_param: path),+> : $($component: ident),+) => {
+        use core::convert::{TryInto, TryFrom};
+        use $crate::Alpha;
+
+        let mut counter: $float = 0.0;
+        $(
+            counter += 0.1;
+            let $component = counter;
+        )+
+        let alpha = counter + 0.1;
+        let extra = counter + 0.2;
+        let raw: &[$float] = &[$($component),+];
+        let raw_plus_1: &[$float] = &[
+            $($component,)+
+            alpha
+        ];
+        let raw_plus_2: &[$float] = &[
+            $($component,)+
+            alpha,
+            extra
+        ];
+        let color: &$name<$($ty_param,)+ $float> = raw.try_into().unwrap();
+        assert!(<&$name<$($ty_param,)+ $float>>::try_from(raw_plus_1).is_err());
+
+        let color_alpha: &Alpha<$name<$($ty_param,)+ $float>, $float> = raw_plus_1.try_into().unwrap();
+        assert!(<&Alpha<$name<$($ty_param,)+ $float>, $float>>::try_from(raw_plus_2).is_err());
+
+        assert_eq!(color, &$name::new($($component),+));
+
+        assert_eq!(color_alpha, &Alpha::<$name<$($ty_param,)+ $float>, $float>::new($($component,)+ alpha));
+    };
+}
+
+/// Generate tests that check that casting a slice that is too short to a
+/// color type fails.
+///
+/// The input is the same as for [`raw_pixel_conversion_tests`].
+#[macro_export]
+macro_rules! raw_pixel_conversion_fail_tests {
+    ($name: ident <$($ty_param: path),+> : $($component: ident),+) => {
+        #[test]
+        #[should_panic(expected = "TryFromSliceError")]
+        fn convert_from_short_f32_slice() {
+            $crate::raw_pixel_conversion_fail_tests!(@float_slice_test f32, $name<$($ty_param),+>);
+        }
+
+        #[test]
+        #[should_panic(expected = "TryFromSliceError")]
+        fn convert_from_short_f64_slice() {
+            $crate::raw_pixel_conversion_fail_tests!(@float_slice_test f64, $name<$($ty_param),+>);
+        }
+    };
+
+    (@float_slice_test $float: ty, $name: ident <$($ty_param: path),+>) => {
+        use core::convert::TryInto;
+
+        let raw: &[$float] = &[0.1];
+        let _: &$name<$($ty_param,)+ $float> = raw.try_into().unwrap();
+    };
+}
+
+/// Assert that two colors look the same, within a maximum color difference.
+///
+/// Both colors are converted to [`Lab`](crate::Lab), with the D65 white
+/// point, and compared with the CIEDE2000 formula. This makes it possible to
+/// compare colors of different types, and the tolerance is perceptual
+/// instead of per component. A difference of around `1.0` is barely
+/// noticeable, and it's the default maximum if `max_delta_e` is omitted.
+///
+/// ```
+/// use palette::{assert_colors_eq, Hsl, Srgb};
+///
+/// let expected = Srgb::new(0.8f32, 0.2, 0.2);
+/// let actual = Hsl::new_srgb(0.0, 0.6, 0.5);
+///
+/// assert_colors_eq!(expected, actual);
+/// assert_colors_eq!(expected, Srgb::new(0.8, 0.205, 0.2), max_delta_e = 0.5);
+/// ```
+///
+/// A failing assertion shows both colors, their `Lab` values and the
+/// difference, and an optional message can be added at the end, as with
+/// `assert_eq!`:
+///
+/// ```should_panic
+/// use palette::{assert_colors_eq, Srgb};
+///
+/// let background = Srgb::new(0.5f32, 0.5, 0.5);
+/// assert_colors_eq!(
+///     background,
+///     Srgb::new(0.5, 0.5, 0.6),
+///     max_delta_e = 0.5,
+///     "the background of {}",
+///     "the sidebar"
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_colors_eq {
+    ($left: expr, $right: expr $(,)?) => {
+        $crate::assert_colors_eq!($left, $right, max_delta_e = 1.0)
+    };
+    ($left: expr, $right: expr, max_delta_e = $max_delta_e: expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if let Err(message) = $crate::test_utils::compare_colors(*left, *right, $max_delta_e) {
+                    panic!("{}", message);
+                }
+            }
+        }
+    };
+    ($left: expr, $right: expr, max_delta_e = $max_delta_e: expr, $($arg: tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if let Err(message) = $crate::test_utils::compare_colors(*left, *right, $max_delta_e) {
+                    panic!("{}: {}", format_args!($($arg)+), message);
+                }
+            }
+        }
+    };
+}
+
+/// Compare two colors for [`assert_colors_eq!`], and describe the
+/// difference if it's too large.
+#[doc(hidden)]
+pub fn compare_colors<A, B, T>(left: A, right: B, max_delta_e: T) -> Result<(), String>
+where
+    A: IntoColorUnclamped<Lab<D65, T>> + Debug,
+    B: IntoColorUnclamped<Lab<D65, T>> + Debug,
+    T: FloatComponent + Debug,
+{
+    let left_description = format!("{:?}", left);
+    let right_description = format!("{:?}", right);
+    let left_lab: Lab<D65, T> = left.into_color_unclamped();
+    let right_lab: Lab<D65, T> = right.into_color_unclamped();
+    let delta_e = left_lab.get_color_difference(right_lab);
+
+    // NaN is never within the limit.
+    if delta_e <= max_delta_e {
+        return Ok(());
+    }
+
+    Err(format!(
+        "assertion failed: `(left ≈ right)`\n  \
+         left: {}\n \
+         right: {}\n  \
+         left: Lab {{ l: {:?}, a: {:?}, b: {:?} }}\n \
+         right: Lab {{ l: {:?}, a: {:?}, b: {:?} }}\n  \
+         diff: Lab {{ l: {:?}, a: {:?}, b: {:?} }}\n\
+         CIEDE2000 ΔE is {:?}, but the maximum is {:?}",
+        left_description,
+        right_description,
+        left_lab.l,
+        left_lab.a,
+        left_lab.b,
+        right_lab.l,
+        right_lab.a,
+        right_lab.b,
+        right_lab.l - left_lab.l,
+        right_lab.a - left_lab.a,
+        right_lab.b - left_lab.b,
+        delta_e,
+        max_delta_e,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::compare_colors;
+    use crate::{Hsv, LinSrgb, Srgb};
+
+    #[test]
+    fn different_types() {
+        let red = Srgb::new(1.0f64, 0.0, 0.0);
+        assert_colors_eq!(red, red.into_linear(), max_delta_e = 1e-6);
+        assert_colors_eq!(red, Hsv::new_srgb(0.0, 1.0, 1.0), max_delta_e = 1e-6);
+        assert_colors_eq!(red, Srgb::new(0.99, 0.0, 0.0));
+    }
+
+    #[test]
+    fn failure_message() {
+        let message = compare_colors(
+            Srgb::new(0.5f64, 0.5, 0.5),
+            LinSrgb::new(0.5, 0.5, 0.5),
+            1.0,
+        )
+        .unwrap_err();
+
+        assert!(message.contains("Lab { l: 53.38"), "{}", message);
+        assert!(
+            message.contains("LinSrgb") || message.contains("Linear"),
+            "{}",
+            message
+        );
+        assert!(message.contains("but the maximum is 1.0"), "{}", message);
+    }
+
+    #[test]
+    #[should_panic(expected = "gray: assertion failed")]
+    fn custom_message() {
+        assert_colors_eq!(
+            Srgb::new(0.5f32, 0.5, 0.5),
+            Srgb::new(0.6, 0.5, 0.5),
+            max_delta_e = 1.0,
+            "{}",
+            "gray"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed")]
+    fn nan() {
+        assert_colors_eq!(Srgb::new(f32::NAN, 0.5, 0.5), Srgb::new(0.5, 0.5, 0.5));
+    }
+}
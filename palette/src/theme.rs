@@ -0,0 +1,269 @@
+//! Derive a dark theme from a light theme, or the other way around.
+//!
+//! A [`ThemeInversion`] reflects the lightness of each color in [`Oklch`],
+//! where the lightness is close to perceptually uniform, and keeps the hue
+//! and chroma. Dark colors become light and light colors become dark, but the
+//! lightness differences between colors, and with that their contrast
+//! relationships, stay in proportion. The chroma is only reduced when a color
+//! would otherwise fall outside the sRGB gamut.
+//!
+//! Brand colors are different, since they need to stay recognizable. Their
+//! lightness can only move within a tolerance, with
+//! [`invert_brand`](ThemeInversion::invert_brand).
+//!
+//! ```
+//! use palette::theme::ThemeInversion;
+//! use palette::Srgb;
+//!
+//! let inversion = ThemeInversion::light_to_dark();
+//!
+//! let background = inversion.invert(Srgb::new(0.98f32, 0.98, 0.97));
+//! let text = inversion.invert(Srgb::new(0.1, 0.1, 0.15));
+//! let brand = inversion.invert_brand(Srgb::new(0.9, 0.3, 0.1));
+//!
+//! assert!(background.red < 0.2);
+//! assert!(text.red > 0.7);
+//! assert!(brand.red > brand.blue);
+//!
+//! // And back again.
+//! let light_background = inversion.inverse().invert(background);
+//! assert!((light_background.red - 0.98).abs() < 0.01);
+//! ```
+
+use crate::convert::{IntoColor, IntoColorUnclamped};
+use crate::{clamp, from_f64, FloatComponent, IsWithinBounds, LinSrgb, Oklch};
+
+/// Settings for inverting the lightness of a theme.
+///
+/// The lightness range `from` is mapped to the range `to`, in reverse, so the
+/// lightest color in `from` becomes the darkest color in `to`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct ThemeInversion<T> {
+    /// The lightness range of the original theme, as `(darkest, lightest)`
+    /// in Oklab lightness.
+    pub from: (T, T),
+
+    /// The lightness range of the derived theme, as `(darkest, lightest)` in
+    /// Oklab lightness.
+    pub to: (T, T),
+
+    /// How much the lightness of brand colors can change.
+    pub brand_tolerance: T,
+}
+
+impl<T> ThemeInversion<T>
+where
+    T: FloatComponent,
+{
+    /// Create an inversion from the lightness range `from` to the lightness
+    /// range `to`, with a brand tolerance of `0.15`.
+    pub fn new(from: (T, T), to: (T, T)) -> Self {
+        ThemeInversion {
+            from,
+            to,
+            brand_tolerance: from_f64(0.15),
+        }
+    }
+
+    /// An inversion from a light theme to a dark theme. The darkest color
+    /// of the dark theme is not pure black, which is harsh against light
+    /// text.
+    pub fn light_to_dark() -> Self {
+        ThemeInversion::new(
+            (from_f64(0.0), from_f64(1.0)),
+            (from_f64(0.15), from_f64(0.97)),
+        )
+    }
+
+    /// An inversion from a dark theme to a light theme, which is the inverse
+    /// of [`light_to_dark`](ThemeInversion::light_to_dark).
+    pub fn dark_to_light() -> Self {
+        ThemeInversion::light_to_dark().inverse()
+    }
+
+    /// Change the brand tolerance.
+    pub fn with_brand_tolerance(mut self, brand_tolerance: T) -> Self {
+        self.brand_tolerance = brand_tolerance;
+        self
+    }
+
+    /// The inversion in the other direction.
+    pub fn inverse(self) -> Self {
+        ThemeInversion {
+            from: self.to,
+            to: self.from,
+            brand_tolerance: self.brand_tolerance,
+        }
+    }
+
+    /// Map a lightness value from the original theme to the derived theme.
+    pub fn invert_lightness(&self, lightness: T) -> T {
+        let (from_dark, from_light) = self.from;
+        let (to_dark, to_light) = self.to;
+
+        let range = from_light - from_dark;
+        let factor = if range > T::zero() {
+            clamp((lightness - from_dark) / range, T::zero(), T::one())
+        } else {
+            T::zero()
+        };
+
+        to_light - factor * (to_light - to_dark)
+    }
+
+    /// Invert a color in Oklch, and reduce its chroma if needed to keep it
+    /// in the sRGB gamut.
+    pub fn invert_oklch(&self, color: Oklch<T>) -> Oklch<T> {
+        fit_chroma(Oklch {
+            l: self.invert_lightness(color.l),
+            ..color
+        })
+    }
+
+    /// Invert a brand color in Oklch. The lightness moves in the same
+    /// direction as for other colors, but not more than the brand
+    /// tolerance.
+    pub fn invert_brand_oklch(&self, color: Oklch<T>) -> Oklch<T> {
+        let inverted = self.invert_lightness(color.l);
+        let l = clamp(
+            inverted,
+            color.l - self.brand_tolerance,
+            color.l + self.brand_tolerance,
+        );
+
+        fit_chroma(Oklch { l, ..color })
+    }
+
+    /// Invert a color.
+    pub fn invert<C>(&self, color: C) -> C
+    where
+        C: IntoColorUnclamped<Oklch<T>>,
+        Oklch<T>: IntoColor<C>,
+    {
+        self.invert_oklch(color.into_color_unclamped()).into_color()
+    }
+
+    /// Invert a brand color.
+    pub fn invert_brand<C>(&self, color: C) -> C
+    where
+        C: IntoColorUnclamped<Oklch<T>>,
+        Oklch<T>: IntoColor<C>,
+    {
+        self.invert_brand_oklch(color.into_color_unclamped())
+            .into_color()
+    }
+}
+
+/// Reduce the chroma of `color` until it's within the sRGB gamut, keeping
+/// the lightness and hue.
+pub(crate) fn fit_chroma<T: FloatComponent>(mut color: Oklch<T>) -> Oklch<T> {
+    let in_gamut = |color: Oklch<T>| {
+        let rgb: LinSrgb<T> = color.into_color_unclamped();
+        rgb.is_within_bounds()
+    };
+
+    if in_gamut(color) {
+        return color;
+    }
+
+    let mut low = T::zero();
+    let mut high = color.chroma;
+    for _ in 0..24 {
+        let middle = (low + high) / from_f64(2.0);
+        if in_gamut(Oklch {
+            chroma: middle,
+            ..color
+        }) {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    color.chroma = low;
+    color
+}
+
+#[cfg(test)]
+mod test {
+    use super::ThemeInversion;
+    use crate::{Oklch, RelativeContrast, Srgb};
+
+    #[test]
+    fn lightness_mapping() {
+        let inversion = ThemeInversion::<f64>::light_to_dark();
+
+        assert_relative_eq!(inversion.invert_lightness(1.0), 0.15);
+        assert_relative_eq!(inversion.invert_lightness(0.0), 0.97);
+        assert_relative_eq!(inversion.invert_lightness(0.5), 0.56);
+
+        let back = inversion.inverse();
+        for &l in &[0.0, 0.2, 0.5, 0.9, 1.0] {
+            assert_relative_eq!(
+                back.invert_lightness(inversion.invert_lightness(l)),
+                l,
+                epsilon = 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn keeps_hue_and_ordering() {
+        let inversion = ThemeInversion::light_to_dark();
+        let colors = [
+            Oklch::new(0.95f64, 0.02, 250.0),
+            Oklch::new(0.7, 0.08, 250.0),
+            Oklch::new(0.3, 0.05, 250.0),
+        ];
+
+        let inverted: Vec<_> = colors
+            .iter()
+            .map(|&color| inversion.invert_oklch(color))
+            .collect();
+
+        assert!(inverted[0].l < inverted[1].l && inverted[1].l < inverted[2].l);
+        for (original, inverted) in colors.iter().zip(&inverted) {
+            assert_eq!(original.hue, inverted.hue);
+            assert_relative_eq!(original.chroma, inverted.chroma);
+        }
+    }
+
+    #[test]
+    fn contrast() {
+        let inversion = ThemeInversion::light_to_dark();
+        let background = Srgb::new(1.0f64, 1.0, 1.0);
+        let text = Srgb::new(0.2, 0.2, 0.2);
+
+        assert!(background.has_enhanced_contrast_text(text));
+        assert!(inversion
+            .invert(background)
+            .has_enhanced_contrast_text(inversion.invert(text)));
+    }
+
+    #[test]
+    fn gamut() {
+        let inversion = ThemeInversion::light_to_dark();
+
+        // Saturated blue is dark, and can't keep its chroma when light.
+        let blue = Oklch::new(0.45f64, 0.31, 264.0);
+        let inverted = inversion.invert_oklch(blue);
+
+        assert!(inverted.chroma < blue.chroma);
+        assert!(inverted.chroma > 0.0);
+        assert_relative_eq!(inverted.l, inversion.invert_lightness(0.45));
+    }
+
+    #[test]
+    fn brand() {
+        let inversion = ThemeInversion::light_to_dark().with_brand_tolerance(0.1);
+        let brand = Oklch::new(0.6f64, 0.15, 30.0);
+
+        let inverted = inversion.invert_brand_oklch(brand);
+        assert_relative_eq!(inverted.l, 0.5);
+        assert_eq!(inverted.hue, brand.hue);
+
+        let light = Oklch::new(0.3f64, 0.1, 140.0);
+        assert_relative_eq!(inversion.invert_brand_oklch(light).l, 0.4);
+    }
+}
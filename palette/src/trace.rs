@@ -0,0 +1,278 @@
+//! Recording of the steps in color conversions, for debugging.
+//!
+//! Conversions between color types often go through several other types,
+//! which isn't visible in generic code. [`trace`] runs a closure and records
+//! the conversions it performs, the matrices that are applied and the colors
+//! that are clamped, in the order they happen:
+//!
+//! ```
+//! use palette::trace::{self, TraceEvent};
+//! use palette::{FromColor, Lab, Srgb};
+//!
+//! let (lab, trace) = trace::trace(|| Lab::from_color(Srgb::new(0.8f32, 0.2, 0.1)));
+//!
+//! assert!(trace
+//!     .events()
+//!     .contains(&TraceEvent::Matrix { name: "rgb_to_xyz" }));
+//! println!("{}", trace);
+//! ```
+//!
+//! The trace is recorded per thread, so only conversions on the calling
+//! thread are included. Conversions that are implemented outside of palette
+//! are only included when they call palette's own conversions.
+//!
+//! This module is only available if the `trace` feature is enabled. The
+//! recording adds some overhead to every conversion, even outside of
+//! [`trace`], so the feature should not be enabled in release builds.
+
+use std::cell::RefCell;
+use std::fmt;
+
+thread_local! {
+    static RECORDER: RefCell<Option<Vec<TraceEvent>>> = RefCell::new(None);
+}
+
+/// A step in a conversion.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TraceEvent {
+    /// A color was converted from one type to another.
+    Conversion {
+        /// The full name of the source type.
+        from: &'static str,
+        /// The full name of the destination type.
+        to: &'static str,
+    },
+
+    /// A matrix was applied to a color.
+    Matrix {
+        /// The name of the matrix, such as `"rgb_to_xyz"`.
+        name: &'static str,
+    },
+
+    /// A color was clamped to its valid range.
+    Clamp {
+        /// The full name of the clamped type.
+        color: &'static str,
+    },
+}
+
+impl fmt::Display for TraceEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            TraceEvent::Conversion { from, to } => {
+                write!(f, "convert {} to {}", ShortName(from), ShortName(to))
+            }
+            TraceEvent::Matrix { name } => write!(f, "apply matrix {}", name),
+            TraceEvent::Clamp { color } => write!(f, "clamp {}", ShortName(color)),
+        }
+    }
+}
+
+/// The recorded events from a call to [`trace`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Trace {
+    events: Vec<TraceEvent>,
+}
+
+impl Trace {
+    /// The recorded events, in the order they happened.
+    pub fn events(&self) -> &[TraceEvent] {
+        &self.events
+    }
+
+    /// Take the recorded events.
+    pub fn into_events(self) -> Vec<TraceEvent> {
+        self.events
+    }
+
+    /// Check if nothing was recorded.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+/// Formats the events as a numbered list, with the module paths removed from
+/// the type names.
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, event) in self.events.iter().enumerate() {
+            writeln!(f, "{}. {}", index + 1, event)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Run `f` and record the conversion steps it performs.
+///
+/// Traces can be nested, and the events that are recorded in an inner trace
+/// are included in the outer trace too.
+pub fn trace<F, R>(f: F) -> (R, Trace)
+where
+    F: FnOnce() -> R,
+{
+    // Restores the outer recorder, even if `f` panics.
+    struct Restore {
+        previous: Option<Option<Vec<TraceEvent>>>,
+    }
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            if let Some(previous) = self.previous.take() {
+                RECORDER.with(|recorder| *recorder.borrow_mut() = previous);
+            }
+        }
+    }
+
+    let mut restore = Restore {
+        previous: Some(RECORDER.with(|recorder| recorder.replace(Some(Vec::new())))),
+    };
+
+    let result = f();
+
+    let events = RECORDER
+        .with(|recorder| recorder.borrow_mut().take())
+        .unwrap_or_default();
+    if let Some(Some(mut previous)) = restore.previous.take() {
+        previous.extend_from_slice(&events);
+        RECORDER.with(|recorder| *recorder.borrow_mut() = Some(previous));
+    }
+
+    (result, Trace { events })
+}
+
+pub(crate) fn record(event: TraceEvent) {
+    // Ignore events while the thread local storage is being destroyed.
+    let _ = RECORDER.try_with(|recorder| {
+        if let Some(events) = recorder.borrow_mut().as_mut() {
+            events.push(event);
+        }
+    });
+}
+
+pub(crate) fn record_conversion<From, To>(_: &From) {
+    record(TraceEvent::Conversion {
+        from: core::any::type_name::<From>(),
+        to: core::any::type_name::<To>(),
+    });
+}
+
+pub(crate) fn record_clamp<C>() {
+    record(TraceEvent::Clamp {
+        color: core::any::type_name::<C>(),
+    });
+}
+
+/// Writes a type name without module paths, so that
+/// `palette::rgb::rgb::Rgb<palette::encoding::srgb::Srgb, f32>` becomes
+/// `Rgb<Srgb, f32>`.
+struct ShortName(&'static str);
+
+impl fmt::Display for ShortName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut segment_start = 0;
+        let mut chars = self.0.char_indices().peekable();
+
+        while let Some((index, character)) = chars.next() {
+            if character.is_alphanumeric() || character == '_' {
+                continue;
+            }
+
+            if character == ':' {
+                if let Some(&(_, ':')) = chars.peek() {
+                    chars.next();
+                    segment_start = index + 2;
+                    continue;
+                }
+            }
+
+            f.write_str(&self.0[segment_start..index])?;
+            write!(f, "{}", character)?;
+            segment_start = index + character.len_utf8();
+        }
+
+        f.write_str(&self.0[segment_start..])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{trace, ShortName, TraceEvent};
+    use crate::convert::FromColorUnclamped;
+    use crate::white_point::D65;
+    use crate::{FromColor, Lab, LinSrgb, Oklab, Srgb, Xyz};
+
+    fn name<T>() -> &'static str {
+        core::any::type_name::<T>()
+    }
+
+    #[test]
+    fn srgb_to_lab() {
+        let (_, trace) = trace(|| Lab::<_, f64>::from_color(Srgb::new(0.8f64, 0.2, 0.1)));
+
+        assert_eq!(
+            trace.events(),
+            &[
+                TraceEvent::Conversion {
+                    from: name::<Srgb<f64>>(),
+                    to: name::<Xyz<D65, f64>>(),
+                },
+                TraceEvent::Matrix { name: "rgb_to_xyz" },
+                TraceEvent::Conversion {
+                    from: name::<Xyz<D65, f64>>(),
+                    to: name::<Lab<D65, f64>>(),
+                },
+                TraceEvent::Clamp {
+                    color: name::<Lab<D65, f64>>(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn display() {
+        let (_, trace) = trace(|| Oklab::<f64>::from_color_unclamped(LinSrgb::new(0.5, 0.5, 0.5)));
+
+        assert_eq!(
+            trace.to_string(),
+            "1. convert Rgb<Linear<Srgb>, f64> to Xyz<D65, f64>\n\
+             2. apply matrix rgb_to_xyz\n\
+             3. convert Xyz<D65, f64> to Oklab<f64>\n\
+             4. apply matrix oklab_m1\n\
+             5. apply matrix oklab_m2\n"
+        );
+    }
+
+    #[test]
+    fn nested() {
+        let (inner, outer) = trace(|| {
+            let _ = Xyz::<_, f64>::from_color_unclamped(LinSrgb::new(0.1, 0.2, 0.3));
+            let (_, inner) =
+                trace(|| Lab::<D65, f64>::from_color_unclamped(Xyz::new(0.1, 0.2, 0.3)));
+            inner
+        });
+
+        assert_eq!(inner.events().len(), 1);
+        assert_eq!(outer.events().len(), 3);
+        assert_eq!(outer.events()[2], inner.events()[0]);
+    }
+
+    #[test]
+    fn not_recording() {
+        let _ = Lab::<_, f64>::from_color(Srgb::new(0.8f64, 0.2, 0.1));
+        let (_, trace) = trace(|| ());
+        assert!(trace.is_empty());
+    }
+
+    #[test]
+    fn short_name() {
+        assert_eq!(
+            ShortName("palette::rgb::rgb::Rgb<palette::encoding::srgb::Srgb, f32>").to_string(),
+            "Rgb<Srgb, f32>"
+        );
+        assert_eq!(
+            ShortName("[palette::lab::Lab<palette::white_point::D65, f64>; 3]").to_string(),
+            "[Lab<D65, f64>; 3]"
+        );
+    }
+}
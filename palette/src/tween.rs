@@ -0,0 +1,564 @@
+//! Color transitions over time, for animations.
+//!
+//! A [`ColorTween`] goes from one color to another over a duration, with an
+//! [easing function](crate::easing) and a working space where the colors are
+//! mixed. The working space makes a big difference for the colors in
+//! between. Mixing in [`Oklab`](crate::Oklab) gives perceptually more even
+//! steps than mixing in linear RGB, for example.
+//!
+//! ```
+//! use approx::assert_relative_eq;
+//! use palette::easing::CubicBezier;
+//! use palette::tween::ColorTween;
+//! use palette::{Oklab, Srgb};
+//!
+//! let red = Srgb::new(1.0, 0.0, 0.0);
+//! let blue = Srgb::new(0.0, 0.0, 1.0);
+//! let tween = ColorTween::<_, Oklab>::new_in(red, blue, 0.3).with_easing(CubicBezier::ease_in_out());
+//!
+//! assert_relative_eq!(tween.at(0.0), red, epsilon = 1e-6);
+//! let halfway = tween.at(0.15);
+//! assert!(halfway.red > 0.3 && halfway.blue > 0.3);
+//! ```
+//!
+//! A [`ConstantSpeedTween`] moves at a constant perceptual speed instead of
+//! over a fixed duration, so the color changes evenly even when the working
+//! space isn't perceptually uniform.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use num_traits::{One, Zero};
+
+use crate::convert::{IntoColor, IntoColorUnclamped};
+use crate::easing::{Easing, Linear};
+use crate::float::Float;
+use crate::white_point::D65;
+use crate::{clamp, from_f64, ColorDifference, FloatComponent, Lab, Mix};
+
+/// A transition from `begin` to `end` over `duration`, where the colors are
+/// mixed in the working space `W`.
+///
+/// The duration and the time can have any unit, as long as they are the
+/// same. Times outside `[0.0, duration]` give the closest end color.
+pub struct ColorTween<C, W = C, E = Linear>
+where
+    W: Mix,
+{
+    /// The color at the start of the transition.
+    pub begin: C,
+
+    /// The color at the end of the transition.
+    pub end: C,
+
+    /// The length of the transition.
+    pub duration: W::Scalar,
+
+    /// The easing function that is applied to the progress.
+    pub easing: E,
+
+    space: PhantomData<fn() -> W>,
+}
+
+impl<C> ColorTween<C>
+where
+    C: Mix,
+{
+    /// Create a linear transition that mixes the colors in their own space.
+    pub fn new(begin: C, end: C, duration: C::Scalar) -> Self {
+        ColorTween::new_in(begin, end, duration)
+    }
+}
+
+impl<C, W> ColorTween<C, W>
+where
+    W: Mix,
+{
+    /// Create a linear transition that mixes the colors in the working space
+    /// `W`. This is necessary for color types that can't be mixed, such as
+    /// [`Srgb`](crate::Srgb).
+    pub fn new_in(begin: C, end: C, duration: W::Scalar) -> Self {
+        ColorTween {
+            begin,
+            end,
+            duration,
+            easing: Linear,
+            space: PhantomData,
+        }
+    }
+}
+
+impl<C, W, E> ColorTween<C, W, E>
+where
+    W: Mix,
+{
+    /// Change the easing function.
+    pub fn with_easing<E2>(self, easing: E2) -> ColorTween<C, W, E2> {
+        ColorTween {
+            begin: self.begin,
+            end: self.end,
+            duration: self.duration,
+            easing,
+            space: PhantomData,
+        }
+    }
+
+    /// Change the working space, where the colors are mixed. It needs to
+    /// have the same scalar type as the current working space.
+    pub fn in_space<W2>(self) -> ColorTween<C, W2, E>
+    where
+        W2: Mix<Scalar = W::Scalar>,
+    {
+        ColorTween {
+            begin: self.begin,
+            end: self.end,
+            duration: self.duration,
+            easing: self.easing,
+            space: PhantomData,
+        }
+    }
+
+    /// Get the progress at `time`, before easing, as a value in `[0.0, 1.0]`.
+    pub fn progress(&self, time: W::Scalar) -> W::Scalar
+    where
+        W::Scalar: Float,
+    {
+        if self.duration > W::Scalar::zero() {
+            clamp(time / self.duration, W::Scalar::zero(), W::Scalar::one())
+        } else {
+            W::Scalar::one()
+        }
+    }
+
+    /// Check if the transition has finished at `time`.
+    pub fn is_finished(&self, time: W::Scalar) -> bool
+    where
+        W::Scalar: Float,
+    {
+        time >= self.duration
+    }
+
+    /// Get the color at `time`.
+    pub fn at(&self, time: W::Scalar) -> C
+    where
+        C: Clone + IntoColorUnclamped<W>,
+        W: IntoColor<C>,
+        W::Scalar: Float,
+        E: Easing<W::Scalar>,
+    {
+        let begin: W = self.begin.clone().into_color_unclamped();
+        let end: W = self.end.clone().into_color_unclamped();
+
+        self.easing
+            .mix(begin, end, self.progress(time))
+            .into_color()
+    }
+}
+
+impl<C, W, E> Clone for ColorTween<C, W, E>
+where
+    C: Clone,
+    W: Mix,
+    W::Scalar: Clone,
+    E: Clone,
+{
+    fn clone(&self) -> Self {
+        ColorTween {
+            begin: self.begin.clone(),
+            end: self.end.clone(),
+            duration: self.duration.clone(),
+            easing: self.easing.clone(),
+            space: PhantomData,
+        }
+    }
+}
+
+impl<C, W, E> Copy for ColorTween<C, W, E>
+where
+    C: Copy,
+    W: Mix,
+    W::Scalar: Copy,
+    E: Copy,
+{
+}
+
+impl<C, W, E> fmt::Debug for ColorTween<C, W, E>
+where
+    C: fmt::Debug,
+    W: Mix,
+    W::Scalar: fmt::Debug,
+    E: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ColorTween")
+            .field("begin", &self.begin)
+            .field("end", &self.end)
+            .field("duration", &self.duration)
+            .field("easing", &self.easing)
+            .finish()
+    }
+}
+
+/// The number of segments that the path of a [`ConstantSpeedTween`] is
+/// divided into, when measuring its length.
+const SEGMENTS: usize = 32;
+
+/// A transition from `begin` to `end` that changes at a constant perceptual
+/// speed, where the colors are mixed in the working space `W`.
+///
+/// The speed is the CIEDE2000 color difference per unit of time. The path
+/// between the colors is measured when the transition is created, and the
+/// time is mapped to the position along it, so each step of the same length
+/// changes the color by the same amount. Mixing in linear RGB, for example,
+/// changes the color a lot faster near black than near white, which a
+/// [`ColorTween`] would show as an uneven transition.
+///
+/// ```
+/// use palette::tween::ConstantSpeedTween;
+/// use palette::{LinSrgb, Srgb};
+///
+/// let off = Srgb::new(0.0f32, 0.0, 0.0);
+/// let on = Srgb::new(0.2, 1.0, 0.4);
+///
+/// // A status light that fades at 100 ΔE per second.
+/// let mut fade = ConstantSpeedTween::<_, LinSrgb>::new_in(off, on, 100.0);
+/// assert!(fade.duration() > 0.5 && fade.duration() < 1.5);
+///
+/// // Called for each frame.
+/// let color = fade.step(1.0 / 60.0);
+/// assert!(color.green > 0.0);
+/// ```
+pub struct ConstantSpeedTween<C, W = C>
+where
+    W: Mix,
+{
+    begin: W,
+    end: W,
+    speed: W::Scalar,
+    lengths: [W::Scalar; SEGMENTS + 1],
+    time: W::Scalar,
+    color: PhantomData<fn() -> C>,
+}
+
+impl<C> ConstantSpeedTween<C>
+where
+    C: Mix + Clone + IntoColorUnclamped<Lab<D65, C::Scalar>>,
+    C::Scalar: FloatComponent,
+{
+    /// Create a transition with `speed`, that mixes the colors in their own
+    /// space.
+    ///
+    /// # Panics
+    ///
+    /// If `speed` isn't positive and finite.
+    pub fn new(begin: C, end: C, speed: C::Scalar) -> Self {
+        ConstantSpeedTween::from_mix(begin, end, speed)
+    }
+}
+
+impl<C, W> ConstantSpeedTween<C, W>
+where
+    W: Mix + Clone + IntoColorUnclamped<Lab<D65, W::Scalar>>,
+    W::Scalar: FloatComponent,
+{
+    /// Create a transition with `speed`, that mixes the colors in the
+    /// working space `W`.
+    ///
+    /// # Panics
+    ///
+    /// If `speed` isn't positive and finite.
+    pub fn new_in(begin: C, end: C, speed: W::Scalar) -> Self
+    where
+        C: IntoColorUnclamped<W>,
+    {
+        ConstantSpeedTween::from_mix(
+            begin.into_color_unclamped(),
+            end.into_color_unclamped(),
+            speed,
+        )
+    }
+
+    fn from_mix(begin: W, end: W, speed: W::Scalar) -> Self {
+        assert!(
+            speed > W::Scalar::zero() && speed.is_finite(),
+            "the speed must be positive and finite"
+        );
+
+        let mut tween = ConstantSpeedTween {
+            begin,
+            end,
+            speed,
+            lengths: [W::Scalar::zero(); SEGMENTS + 1],
+            time: W::Scalar::zero(),
+            color: PhantomData,
+        };
+
+        let mut previous = tween.lab_at(W::Scalar::zero());
+        for index in 1..=SEGMENTS {
+            let current = tween.lab_at(segment_start(index));
+            tween.lengths[index] =
+                tween.lengths[index - 1] + previous.get_color_difference(current);
+            previous = current;
+        }
+
+        tween
+    }
+
+    /// Get the speed, as the color difference per unit of time.
+    pub fn speed(&self) -> W::Scalar {
+        self.speed
+    }
+
+    /// Get the total color difference along the path.
+    pub fn length(&self) -> W::Scalar {
+        self.lengths[SEGMENTS]
+    }
+
+    /// Get the time it takes to reach the end color.
+    pub fn duration(&self) -> W::Scalar {
+        self.length() / self.speed
+    }
+
+    /// Get the time that has passed in [`step`](ConstantSpeedTween::step).
+    pub fn time(&self) -> W::Scalar {
+        self.time
+    }
+
+    /// Check if the transition has finished at `time`.
+    pub fn is_finished(&self, time: W::Scalar) -> bool {
+        time >= self.duration()
+    }
+
+    /// Get the mixing factor between the colors at `time`, as a value in
+    /// `[0.0, 1.0]`.
+    pub fn progress(&self, time: W::Scalar) -> W::Scalar {
+        let length = self.length();
+        if length <= W::Scalar::zero() || time >= self.duration() {
+            return W::Scalar::one();
+        }
+
+        if time <= W::Scalar::zero() {
+            return W::Scalar::zero();
+        }
+
+        let distance = clamp(time * self.speed, W::Scalar::zero(), length);
+        let segment = self.lengths[1..]
+            .iter()
+            .position(|&end| distance < end)
+            .unwrap_or(SEGMENTS - 1);
+
+        // The color difference isn't linear within the segment either, so
+        // the factor is found with a binary search.
+        let remaining = distance - self.lengths[segment];
+        let start = self.lab_at(segment_start(segment));
+        let mut low = segment_start(segment);
+        let mut high = segment_start(segment + 1);
+
+        for _ in 0..16 {
+            let middle = (low + high) / from_f64(2.0);
+            if start.get_color_difference(self.lab_at(middle)) < remaining {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+
+        (low + high) / from_f64(2.0)
+    }
+
+    /// Get the color at `time`.
+    pub fn sample(&self, time: W::Scalar) -> C
+    where
+        W: IntoColor<C>,
+    {
+        self.begin
+            .clone()
+            .mix(self.end.clone(), self.progress(time))
+            .into_color()
+    }
+
+    /// Move the time forward by `delta_time`, and get the color at the new
+    /// time.
+    pub fn step(&mut self, delta_time: W::Scalar) -> C
+    where
+        W: IntoColor<C>,
+    {
+        self.time = self.time + delta_time;
+        self.sample(self.time)
+    }
+
+    /// Move the time back to the start of the transition.
+    pub fn reset(&mut self) {
+        self.time = W::Scalar::zero();
+    }
+
+    fn lab_at(&self, factor: W::Scalar) -> Lab<D65, W::Scalar> {
+        self.begin
+            .clone()
+            .mix(self.end.clone(), factor)
+            .into_color_unclamped()
+    }
+}
+
+/// Get the mixing factor where `segment` starts.
+fn segment_start<T: FloatComponent>(segment: usize) -> T {
+    from_f64(segment as f64 / SEGMENTS as f64)
+}
+
+impl<C, W> Clone for ConstantSpeedTween<C, W>
+where
+    W: Mix + Clone,
+    W::Scalar: Clone,
+{
+    fn clone(&self) -> Self {
+        ConstantSpeedTween {
+            begin: self.begin.clone(),
+            end: self.end.clone(),
+            speed: self.speed.clone(),
+            lengths: self.lengths.clone(),
+            time: self.time.clone(),
+            color: PhantomData,
+        }
+    }
+}
+
+impl<C, W> fmt::Debug for ConstantSpeedTween<C, W>
+where
+    W: Mix + fmt::Debug,
+    W::Scalar: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ConstantSpeedTween")
+            .field("begin", &self.begin)
+            .field("end", &self.end)
+            .field("speed", &self.speed)
+            .field("time", &self.time)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ColorTween, ConstantSpeedTween};
+    use crate::easing::Smoothstep;
+    use crate::{ColorDifference, IntoColor, Lab, LinSrgb, Oklab, Srgb};
+
+    #[test]
+    fn linear() {
+        let tween = ColorTween::new(
+            LinSrgb::new(0.0f64, 0.0, 0.0),
+            LinSrgb::new(1.0, 0.5, 0.0),
+            2.0,
+        );
+
+        assert_eq!(tween.at(-1.0), LinSrgb::new(0.0, 0.0, 0.0));
+        assert_relative_eq!(tween.at(0.5), LinSrgb::new(0.25, 0.125, 0.0));
+        assert_eq!(tween.at(3.0), LinSrgb::new(1.0, 0.5, 0.0));
+        assert!(!tween.is_finished(1.9));
+        assert!(tween.is_finished(2.0));
+    }
+
+    #[test]
+    fn eased() {
+        let tween = ColorTween::new(
+            LinSrgb::new(0.0f64, 0.0, 0.0),
+            LinSrgb::new(1.0, 1.0, 1.0),
+            1.0,
+        )
+        .with_easing(Smoothstep);
+
+        assert_relative_eq!(tween.at(0.25), LinSrgb::new(0.15625, 0.15625, 0.15625));
+        assert_relative_eq!(tween.progress(0.25), 0.25);
+    }
+
+    #[test]
+    fn working_space() {
+        let begin = Srgb::new(1.0f64, 1.0, 1.0);
+        let end = Srgb::new(0.0, 0.0, 0.0);
+
+        let linear = ColorTween::<_, LinSrgb<f64>>::new_in(begin, end, 1.0).at(0.5);
+        let oklab = ColorTween::<_, LinSrgb<f64>>::new_in(begin, end, 1.0)
+            .in_space::<Oklab<f64>>()
+            .at(0.5);
+
+        // Linear light is too bright in the middle, and Oklab is closer to
+        // perceptually halfway.
+        assert!(linear.red > 0.7);
+        assert!(oklab.red > 0.35 && oklab.red < 0.45);
+        assert_relative_eq!(oklab.red, oklab.blue, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn zero_duration() {
+        let tween = ColorTween::new(
+            LinSrgb::new(0.0f32, 0.0, 0.0),
+            LinSrgb::new(1.0, 1.0, 1.0),
+            0.0,
+        );
+        assert_eq!(tween.at(0.0), LinSrgb::new(1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn constant_speed() {
+        let black = Srgb::new(0.0f64, 0.0, 0.0);
+        let white = Srgb::new(1.0, 1.0, 1.0);
+        let tween = ConstantSpeedTween::<_, LinSrgb<f64>>::new_in(black, white, 50.0);
+
+        assert!(tween.length() > 50.0 && tween.length() < 100.0);
+        assert_relative_eq!(tween.duration(), tween.length() / 50.0);
+        assert_relative_eq!(tween.sample(0.0), black, epsilon = 1e-6);
+        assert_relative_eq!(tween.sample(tween.duration()), white, epsilon = 1e-6);
+        assert_eq!(tween.progress(-1.0), 0.0);
+        assert_eq!(tween.progress(10.0), 1.0);
+
+        // Each step changes the color by about the same amount, even though
+        // linear RGB is very uneven.
+        let steps = 10;
+        let step_time = tween.duration() / steps as f64;
+        let mut previous: Lab<_, f64> = tween.sample(0.0).into_color();
+        for step in 1..=steps {
+            let current: Lab<_, f64> = tween.sample(step_time * step as f64).into_color();
+            let difference = previous.get_color_difference(current);
+            assert_relative_eq!(difference, tween.length() / steps as f64, epsilon = 0.3);
+            previous = current;
+        }
+
+        // A linear tween is far from even.
+        let linear = ColorTween::<_, LinSrgb<f64>>::new_in(black, white, 1.0);
+        let first: Lab<_, f64> = linear.at(0.1).into_color();
+        let start: Lab<_, f64> = black.into_color();
+        assert!(start.get_color_difference(first) > 2.0 * tween.length() / steps as f64);
+    }
+
+    #[test]
+    fn constant_speed_step() {
+        let begin = Oklab::new(0.2f64, 0.1, 0.0);
+        let end = Oklab::new(0.8, -0.1, 0.1);
+        let mut tween = ConstantSpeedTween::new(begin, end, 20.0);
+
+        let duration = tween.duration();
+        tween.step(duration / 2.0);
+        tween.step(duration / 4.0);
+        assert_relative_eq!(tween.time(), duration * 0.75);
+        assert!(!tween.is_finished(tween.time()));
+
+        let color = tween.step(duration);
+        assert_relative_eq!(color, end, epsilon = 1e-9);
+        assert!(tween.is_finished(tween.time()));
+
+        tween.reset();
+        assert_eq!(tween.time(), 0.0);
+
+        // No distance to travel.
+        let still = ConstantSpeedTween::new(begin, begin, 1.0);
+        assert_eq!(still.duration(), 0.0);
+        assert_eq!(still.sample(0.0), begin);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_speed() {
+        let color = Oklab::new(0.5f64, 0.0, 0.0);
+        let _ = ConstantSpeedTween::new(color, color, 0.0);
+    }
+}
@@ -0,0 +1,440 @@
+//! Palettes where each color has a weight.
+//!
+//! [`WeightedPalette`] is a list of colors with a weight for each of them,
+//! such as how many pixels of an image it represents. This is what comes out
+//! of palette extraction and quantization, where the weights tell how
+//! important each color is, and what goes into dithering and further
+//! reduction.
+//!
+//! ```
+//! use palette::weighted_palette::WeightedPalette;
+//! use palette::{DeltaEFormula, Srgb};
+//!
+//! let mut palette = WeightedPalette::new();
+//! palette.push(Srgb::new(0.8f32, 0.2, 0.2), 30.0);
+//! palette.push(Srgb::new(0.1, 0.1, 0.6), 60.0);
+//! palette.push(Srgb::new(0.9, 0.9, 0.8), 10.0);
+//!
+//! palette.normalize();
+//! palette.sort_by_weight();
+//!
+//! assert_eq!(palette[0].color, Srgb::new(0.1, 0.1, 0.6));
+//! assert_eq!(palette[0].weight, 0.6);
+//!
+//! let index = palette.nearest(Srgb::new(0.85, 0.85, 0.85), DeltaEFormula::Ciede2000);
+//! assert_eq!(index, Some(2));
+//! ```
+//!
+//! The weights don't have to add up to anything in particular, but
+//! [`normalize`](WeightedPalette::normalize) scales them so they add up to
+//! `1.0`.
+//!
+//! This module is only available if the `std` feature is enabled (this is the
+//! default).
+
+use core::cmp::Ordering;
+use core::iter::FromIterator;
+use core::ops::{Index, IndexMut};
+
+use crate::convert::{FromColor, IntoColor};
+use crate::{DeltaE, DeltaEFormula, FloatComponent, Lab};
+
+/// A color and its weight in a [`WeightedPalette`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct WeightedColor<C, T = f32> {
+    /// The color.
+    pub color: C,
+
+    /// How much of the palette the color represents.
+    pub weight: T,
+}
+
+/// A list of colors with a weight for each of them.
+///
+/// See the [module documentation](crate::weighted_palette) for an example.
+///
+/// The palette is serialized as a list of [`WeightedColor`] entries.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serializing", serde(transparent))]
+pub struct WeightedPalette<C, T = f32> {
+    entries: Vec<WeightedColor<C, T>>,
+}
+
+impl<C, T> WeightedPalette<C, T> {
+    /// Create an empty palette.
+    pub fn new() -> Self {
+        WeightedPalette {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Add `color` with `weight` at the end of the palette.
+    pub fn push(&mut self, color: C, weight: T) {
+        self.entries.push(WeightedColor { color, weight });
+    }
+
+    /// The number of colors in the palette.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if the palette has no colors.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over the colors and their weights.
+    pub fn iter(&self) -> core::slice::Iter<'_, WeightedColor<C, T>> {
+        self.entries.iter()
+    }
+
+    /// Iterate over the colors, without their weights.
+    pub fn colors(&self) -> impl Iterator<Item = &C> + '_ {
+        self.entries.iter().map(|entry| &entry.color)
+    }
+
+    /// Iterate over the weights, in the same order as the colors.
+    pub fn weights(&self) -> impl Iterator<Item = &T> + '_ {
+        self.entries.iter().map(|entry| &entry.weight)
+    }
+
+    /// The colors and their weights as a slice.
+    pub fn as_slice(&self) -> &[WeightedColor<C, T>] {
+        &self.entries
+    }
+
+    /// Take the colors and their weights out of the palette.
+    pub fn into_vec(self) -> Vec<WeightedColor<C, T>> {
+        self.entries
+    }
+}
+
+impl<C, T> WeightedPalette<C, T>
+where
+    T: FloatComponent,
+{
+    /// The sum of all weights.
+    pub fn total_weight(&self) -> T {
+        self.weights().fold(T::zero(), |sum, &weight| sum + weight)
+    }
+
+    /// Scale the weights so they add up to `1.0`.
+    ///
+    /// The weights are left as they are if they add up to zero.
+    pub fn normalize(&mut self) {
+        let total = self.total_weight();
+        if total == T::zero() {
+            return;
+        }
+
+        for entry in &mut self.entries {
+            entry.weight = entry.weight / total;
+        }
+    }
+
+    /// Sort the colors by weight, with the heaviest color first.
+    ///
+    /// The sort is stable, so colors with the same weight keep their order.
+    pub fn sort_by_weight(&mut self) {
+        self.entries
+            .sort_by(|a, b| b.weight.partial_cmp(&a.weight).unwrap_or(Ordering::Equal));
+    }
+
+    /// Add the colors from `other`, with their weights.
+    ///
+    /// Colors that are already in the palette get the weights from `other`
+    /// added to their weights, instead of being added again.
+    pub fn merge(&mut self, other: WeightedPalette<C, T>)
+    where
+        C: PartialEq,
+    {
+        for entry in other.entries {
+            let existing = self
+                .entries
+                .iter_mut()
+                .find(|existing| existing.color == entry.color);
+
+            match existing {
+                Some(existing) => existing.weight = existing.weight + entry.weight,
+                None => self.entries.push(entry),
+            }
+        }
+    }
+
+    /// Merge the colors that are within `threshold` of each other, as
+    /// measured by `formula` in [`Lab`].
+    ///
+    /// This is the weighted version of
+    /// [`dedup_by_distance`](crate::quantize::dedup_by_distance). Each
+    /// merged color is the weighted average of its group in `Lab`, and its
+    /// weight is the sum of the group's weights. Colors that aren't merged
+    /// with anything are kept unchanged.
+    pub fn merge_similar<Wp>(&mut self, threshold: T, formula: DeltaEFormula<T>)
+    where
+        C: IntoColor<Lab<Wp, T>> + FromColor<Lab<Wp, T>> + Copy,
+    {
+        struct Group<C, Wp, T> {
+            first: C,
+            average: Lab<Wp, T>,
+            weight: T,
+            count: usize,
+        }
+
+        let mut groups: Vec<Group<C, Wp, T>> = Vec::new();
+
+        for entry in &self.entries {
+            let lab: Lab<Wp, T> = entry.color.into_color();
+            let group = groups
+                .iter_mut()
+                .find(|group| group.average.delta_e(lab, formula) <= threshold);
+
+            if let Some(group) = group {
+                group.count += 1;
+                group.weight = group.weight + entry.weight;
+                if group.weight > T::zero() {
+                    let factor = entry.weight / group.weight;
+                    group.average = group.average + (lab - group.average) * factor;
+                }
+            } else {
+                groups.push(Group {
+                    first: entry.color,
+                    average: lab,
+                    weight: entry.weight,
+                    count: 1,
+                });
+            }
+        }
+
+        self.entries = groups
+            .into_iter()
+            .map(|group| WeightedColor {
+                color: if group.count == 1 {
+                    group.first
+                } else {
+                    C::from_color(group.average)
+                },
+                weight: group.weight,
+            })
+            .collect();
+    }
+
+    /// Sort the colors by their [`Lab`] lightness, with the darkest color
+    /// first.
+    pub fn sort_by_lightness<Wp>(&mut self)
+    where
+        C: IntoColor<Lab<Wp, T>> + Copy,
+    {
+        let mut keyed: Vec<(T, WeightedColor<C, T>)> = self
+            .entries
+            .drain(..)
+            .map(|entry| {
+                let lab: Lab<Wp, T> = entry.color.into_color();
+                (lab.l, entry)
+            })
+            .collect();
+
+        keyed.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        self.entries
+            .extend(keyed.into_iter().map(|(_, entry)| entry));
+    }
+
+    /// Find the index of the color that is closest to `color`, as measured
+    /// by `formula` in [`Lab`], or `None` if the palette is empty.
+    ///
+    /// The weights are not taken into account.
+    pub fn nearest<Wp>(&self, color: C, formula: DeltaEFormula<T>) -> Option<usize>
+    where
+        C: IntoColor<Lab<Wp, T>> + Copy,
+    {
+        let lab: Lab<Wp, T> = color.into_color();
+        let mut result: Option<(usize, T)> = None;
+
+        for (index, entry) in self.entries.iter().enumerate() {
+            let candidate: Lab<Wp, T> = entry.color.into_color();
+            let distance = candidate.delta_e(lab, formula);
+            let is_closer = match result {
+                Some((_, min_distance)) => distance < min_distance,
+                None => true,
+            };
+
+            if is_closer {
+                result = Some((index, distance));
+            }
+        }
+
+        result.map(|(index, _)| index)
+    }
+}
+
+impl<C, T> Default for WeightedPalette<C, T> {
+    fn default() -> Self {
+        WeightedPalette::new()
+    }
+}
+
+impl<C, T> Index<usize> for WeightedPalette<C, T> {
+    type Output = WeightedColor<C, T>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.entries[index]
+    }
+}
+
+impl<C, T> IndexMut<usize> for WeightedPalette<C, T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.entries[index]
+    }
+}
+
+impl<C, T> From<Vec<WeightedColor<C, T>>> for WeightedPalette<C, T> {
+    fn from(entries: Vec<WeightedColor<C, T>>) -> Self {
+        WeightedPalette { entries }
+    }
+}
+
+impl<C, T> From<WeightedPalette<C, T>> for Vec<WeightedColor<C, T>> {
+    fn from(palette: WeightedPalette<C, T>) -> Self {
+        palette.entries
+    }
+}
+
+impl<C, T> FromIterator<(C, T)> for WeightedPalette<C, T> {
+    fn from_iter<I: IntoIterator<Item = (C, T)>>(iter: I) -> Self {
+        let mut palette = WeightedPalette::new();
+        palette.extend(iter);
+        palette
+    }
+}
+
+impl<C, T> Extend<(C, T)> for WeightedPalette<C, T> {
+    fn extend<I: IntoIterator<Item = (C, T)>>(&mut self, iter: I) {
+        self.entries.extend(
+            iter.into_iter()
+                .map(|(color, weight)| WeightedColor { color, weight }),
+        );
+    }
+}
+
+impl<C, T> IntoIterator for WeightedPalette<C, T> {
+    type Item = WeightedColor<C, T>;
+    type IntoIter = std::vec::IntoIter<WeightedColor<C, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a, C, T> IntoIterator for &'a WeightedPalette<C, T> {
+    type Item = &'a WeightedColor<C, T>;
+    type IntoIter = core::slice::Iter<'a, WeightedColor<C, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{WeightedColor, WeightedPalette};
+    use crate::{DeltaEFormula, Srgb};
+
+    fn palette() -> WeightedPalette<Srgb<f64>, f64> {
+        vec![
+            (Srgb::new(0.9, 0.9, 0.9), 1.0),
+            (Srgb::new(0.1, 0.1, 0.1), 3.0),
+            (Srgb::new(0.5, 0.2, 0.2), 4.0),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn normalize() {
+        let mut palette = palette();
+        palette.normalize();
+        assert_relative_eq!(palette.total_weight(), 1.0);
+        assert_relative_eq!(palette[2].weight, 0.5);
+
+        let mut empty = WeightedPalette::<Srgb<f64>, f64>::new();
+        empty.push(Srgb::new(0.5, 0.5, 0.5), 0.0);
+        empty.normalize();
+        assert_eq!(empty[0].weight, 0.0);
+    }
+
+    #[test]
+    fn sort() {
+        let mut palette = palette();
+        palette.sort_by_weight();
+        let weights: Vec<f64> = palette.weights().cloned().collect();
+        assert_eq!(weights, [4.0, 3.0, 1.0]);
+
+        palette.sort_by_lightness();
+        let weights: Vec<f64> = palette.weights().cloned().collect();
+        assert_eq!(weights, [3.0, 4.0, 1.0]);
+    }
+
+    #[test]
+    fn merge() {
+        let mut palette = palette();
+        let mut other = WeightedPalette::new();
+        other.push(Srgb::new(0.1, 0.1, 0.1), 2.0);
+        other.push(Srgb::new(0.0, 0.0, 1.0), 1.0);
+
+        palette.merge(other);
+        assert_eq!(palette.len(), 4);
+        assert_eq!(palette[1].weight, 5.0);
+        assert_eq!(palette[3].color, Srgb::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn merge_similar() {
+        let mut palette = palette();
+        palette.push(Srgb::new(0.5, 0.2, 0.2), 4.0);
+        palette.push(Srgb::new(0.9, 0.9, 0.9), 1.0);
+        palette.merge_similar(1.0, DeltaEFormula::Ciede2000);
+
+        assert_eq!(palette.len(), 3);
+        assert_relative_eq!(palette.total_weight(), 13.0);
+        assert_eq!(
+            palette[1],
+            WeightedColor {
+                color: Srgb::new(0.1, 0.1, 0.1),
+                weight: 3.0,
+            }
+        );
+        assert_relative_eq!(palette[2].color, Srgb::new(0.5, 0.2, 0.2), epsilon = 1e-6);
+        assert_relative_eq!(palette[2].weight, 8.0);
+    }
+
+    #[test]
+    fn nearest() {
+        let palette = palette();
+        assert_eq!(
+            palette.nearest(Srgb::new(0.4, 0.2, 0.2), DeltaEFormula::Ciede2000),
+            Some(2)
+        );
+        assert_eq!(
+            WeightedPalette::<Srgb<f64>, f64>::new()
+                .nearest(Srgb::new(0.4, 0.2, 0.2), DeltaEFormula::Cie76),
+            None
+        );
+    }
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn serialize() {
+        let mut palette = WeightedPalette::new();
+        palette.push(Srgb::new(0.25f32, 0.5, 1.0), 2.0f32);
+
+        let serialized = ::serde_json::to_string(&palette).unwrap();
+        assert_eq!(
+            serialized,
+            r#"[{"color":{"red":0.25,"green":0.5,"blue":1.0},"weight":2.0}]"#
+        );
+
+        let deserialized: WeightedPalette<Srgb, f32> = ::serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, palette);
+    }
+}
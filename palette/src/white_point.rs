@@ -5,8 +5,14 @@
 //! may be lit by incandescent lights, which are relatively orange compared to
 //! daylight. Defining "white" as daylight will give unacceptable results when
 //! attempting to color-correct a photograph taken with incandescent lighting.
+//!
+//! The tristimulus values of the white points for the 10° Standard Observer
+//! are from ASTM E308-01, Table 5, normalized so `Y` is `1.0`.
 
-use crate::{from_f64, FromF64, Xyz};
+use crate::chromatic_adaptation::{Method, TransformMatrix};
+use crate::convert::{FromColorUnclamped, IntoColorUnclamped};
+use crate::matrix::multiply_xyz;
+use crate::{from_f64, FloatComponent, FromF64, Xyz};
 
 /// Represents an unspecified reference white point.
 ///
@@ -14,6 +20,7 @@ use crate::{from_f64, FromF64, Xyz};
 /// need a known intended white point. `Any` may be used as a placeholder type
 /// in those situations.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
 pub struct Any;
 
 /// WhitePoint defines the Xyz color co-ordinates for a given white point.
@@ -38,6 +45,7 @@ pub trait WhitePoint<T>: 'static {
 /// of a Planckian radiator at a temperature of approximately 2856 K. Uses the
 /// CIE 1932 2° Standard Observer
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
 pub struct A;
 impl<T: FromF64> WhitePoint<T> for A {
     #[inline]
@@ -50,6 +58,7 @@ impl<T: FromF64> WhitePoint<T> for A {
 /// CIE standard illuminant B represents noon sunlight, with a correlated color
 /// temperature (CCT) of 4874 K Uses the CIE 1932 2° Standard Observer
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
 pub struct B;
 impl<T: FromF64> WhitePoint<T> for B {
     #[inline]
@@ -62,6 +71,7 @@ impl<T: FromF64> WhitePoint<T> for B {
 /// CIE standard illuminant C represents the average day light with a CCT of
 /// 6774 K Uses the CIE 1932 2° Standard Observer
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
 pub struct C;
 impl<T: FromF64> WhitePoint<T> for C {
     #[inline]
@@ -74,6 +84,7 @@ impl<T: FromF64> WhitePoint<T> for C {
 /// D50 White Point is the natural daylight with a color temperature of around
 /// 5000K for 2° Standard Observer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
 pub struct D50;
 impl<T: FromF64> WhitePoint<T> for D50 {
     #[inline]
@@ -86,6 +97,7 @@ impl<T: FromF64> WhitePoint<T> for D50 {
 /// D55 White Point is the natural daylight with a color temperature of around
 /// 5500K for 2° Standard Observer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
 pub struct D55;
 impl<T: FromF64> WhitePoint<T> for D55 {
     #[inline]
@@ -98,6 +110,7 @@ impl<T: FromF64> WhitePoint<T> for D55 {
 /// D65 White Point is the natural daylight with a color temperature of 6500K
 /// for 2° Standard Observer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
 pub struct D65;
 impl<T: FromF64> WhitePoint<T> for D65 {
     #[inline]
@@ -110,6 +123,7 @@ impl<T: FromF64> WhitePoint<T> for D65 {
 /// D75 White Point is the natural daylight with a color temperature of around
 /// 7500K for 2° Standard Observer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
 pub struct D75;
 impl<T: FromF64> WhitePoint<T> for D75 {
     #[inline]
@@ -122,6 +136,7 @@ impl<T: FromF64> WhitePoint<T> for D75 {
 /// CIE standard illuminant E represents the equal energy radiator
 /// Uses the CIE 1932 2° Standard Observer
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
 pub struct E;
 impl<T: FromF64> WhitePoint<T> for E {
     #[inline]
@@ -129,10 +144,24 @@ impl<T: FromF64> WhitePoint<T> for E {
         Xyz::new(from_f64(1.0), from_f64(1.0), from_f64(1.0))
     }
 }
+/// CIE fluorescent illuminant series - F1
+///
+/// F1 represents a standard daylight fluorescent lamp, with a CCT of 6430 K,
+/// for 2° Standard Observer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct F1;
+impl<T: FromF64> WhitePoint<T> for F1 {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        Xyz::new(from_f64(0.92834), from_f64(1.0), from_f64(1.03665))
+    }
+}
 /// CIE fluorescent illuminant series - F2
 ///
 /// F2 represents a semi-broadband fluorescent lamp for 2° Standard Observer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
 pub struct F2;
 impl<T: FromF64> WhitePoint<T> for F2 {
     #[inline]
@@ -140,10 +169,63 @@ impl<T: FromF64> WhitePoint<T> for F2 {
         Xyz::new(from_f64(0.99186), from_f64(1.0), from_f64(0.67393))
     }
 }
+/// CIE fluorescent illuminant series - F3
+///
+/// F3 represents a semi-broadband white fluorescent lamp, with a CCT of 3450 K,
+/// for 2° Standard Observer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct F3;
+impl<T: FromF64> WhitePoint<T> for F3 {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        Xyz::new(from_f64(1.03753), from_f64(1.0), from_f64(0.49861))
+    }
+}
+/// CIE fluorescent illuminant series - F4
+///
+/// F4 represents a semi-broadband warm white fluorescent lamp, with a CCT of
+/// 2940 K, for 2° Standard Observer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct F4;
+impl<T: FromF64> WhitePoint<T> for F4 {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        Xyz::new(from_f64(1.09147), from_f64(1.0), from_f64(0.38813))
+    }
+}
+/// CIE fluorescent illuminant series - F5
+///
+/// F5 represents a semi-broadband daylight fluorescent lamp, with a CCT of 6350
+/// K, for 2° Standard Observer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct F5;
+impl<T: FromF64> WhitePoint<T> for F5 {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        Xyz::new(from_f64(0.90872), from_f64(1.0), from_f64(0.98723))
+    }
+}
+/// CIE fluorescent illuminant series - F6
+///
+/// F6 represents a semi-broadband lite white fluorescent lamp, with a CCT of
+/// 4150 K, for 2° Standard Observer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct F6;
+impl<T: FromF64> WhitePoint<T> for F6 {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        Xyz::new(from_f64(0.97309), from_f64(1.0), from_f64(0.60191))
+    }
+}
 /// CIE fluorescent illuminant series - F7
 ///
 /// F7 represents a broadband fluorescent lamp for 2° Standard Observer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
 pub struct F7;
 impl<T: FromF64> WhitePoint<T> for F7 {
     #[inline]
@@ -151,10 +233,50 @@ impl<T: FromF64> WhitePoint<T> for F7 {
         Xyz::new(from_f64(0.95041), from_f64(1.0), from_f64(1.08747))
     }
 }
+/// CIE fluorescent illuminant series - F8
+///
+/// F8 represents a broadband fluorescent lamp that simulates D50, for 2°
+/// Standard Observer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct F8;
+impl<T: FromF64> WhitePoint<T> for F8 {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        Xyz::new(from_f64(0.96413), from_f64(1.0), from_f64(0.82333))
+    }
+}
+/// CIE fluorescent illuminant series - F9
+///
+/// F9 represents a broadband cool white deluxe fluorescent lamp, with a CCT of
+/// 4150 K, for 2° Standard Observer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct F9;
+impl<T: FromF64> WhitePoint<T> for F9 {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        Xyz::new(from_f64(1.00365), from_f64(1.0), from_f64(0.67868))
+    }
+}
+/// CIE fluorescent illuminant series - F10
+///
+/// F10 represents a narrowband fluorescent lamp, with a CCT of 5000 K, for 2°
+/// Standard Observer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct F10;
+impl<T: FromF64> WhitePoint<T> for F10 {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        Xyz::new(from_f64(0.96174), from_f64(1.0), from_f64(0.81712))
+    }
+}
 /// CIE fluorescent illuminant series - F11
 ///
 /// F11 represents a narrowband fluorescent lamp for 2° Standard Observer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
 pub struct F11;
 impl<T: FromF64> WhitePoint<T> for F11 {
     #[inline]
@@ -162,16 +284,161 @@ impl<T: FromF64> WhitePoint<T> for F11 {
         Xyz::new(from_f64(1.00962), from_f64(1.0), from_f64(0.64350))
     }
 }
+/// CIE fluorescent illuminant series - F12
+///
+/// F12 represents a narrowband fluorescent lamp, with a CCT of 3000 K, for 2°
+/// Standard Observer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct F12;
+impl<T: FromF64> WhitePoint<T> for F12 {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        Xyz::new(from_f64(1.08046), from_f64(1.0), from_f64(0.39228))
+    }
+}
+/// CIE LED illuminant series - LED-B1
+///
+/// LED-B1 represents a phosphor converted blue LED lamp, with a CCT of 2733 K,
+/// for 2° Standard Observer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct LedB1;
+impl<T: FromF64> WhitePoint<T> for LedB1 {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        Xyz::new(from_f64(1.11820), from_f64(1.0), from_f64(0.33399))
+    }
+}
+/// CIE LED illuminant series - LED-B2
+///
+/// LED-B2 represents a phosphor converted blue LED lamp, with a CCT of 2998 K,
+/// for 2° Standard Observer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct LedB2;
+impl<T: FromF64> WhitePoint<T> for LedB2 {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        Xyz::new(from_f64(1.08599), from_f64(1.0), from_f64(0.40653))
+    }
+}
+/// CIE LED illuminant series - LED-B3
+///
+/// LED-B3 represents a phosphor converted blue LED lamp, with a CCT of 4103 K,
+/// for 2° Standard Observer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct LedB3;
+impl<T: FromF64> WhitePoint<T> for LedB3 {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        Xyz::new(from_f64(1.00886), from_f64(1.0), from_f64(0.67714))
+    }
+}
+/// CIE LED illuminant series - LED-B4
+///
+/// LED-B4 represents a phosphor converted blue LED lamp, with a CCT of 5109 K,
+/// for 2° Standard Observer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct LedB4;
+impl<T: FromF64> WhitePoint<T> for LedB4 {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        Xyz::new(from_f64(0.97716), from_f64(1.0), from_f64(0.87836))
+    }
+}
+/// CIE LED illuminant series - LED-B5
+///
+/// LED-B5 represents a phosphor converted blue LED lamp, with a CCT of 6598 K,
+/// for 2° Standard Observer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct LedB5;
+impl<T: FromF64> WhitePoint<T> for LedB5 {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        Xyz::new(from_f64(0.96354), from_f64(1.0), from_f64(1.12670))
+    }
+}
+/// CIE LED illuminant series - LED-BH1
+///
+/// LED-BH1 represents a hybrid of phosphor converted blue and red LEDs, with a
+/// CCT of 2851 K, for 2° Standard Observer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct LedBh1;
+impl<T: FromF64> WhitePoint<T> for LedBh1 {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        Xyz::new(from_f64(1.10034), from_f64(1.0), from_f64(0.35908))
+    }
+}
+/// CIE LED illuminant series - LED-RGB1
+///
+/// LED-RGB1 represents a mix of red, green and blue LEDs, with a CCT of 2840 K,
+/// for 2° Standard Observer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct LedRgb1;
+impl<T: FromF64> WhitePoint<T> for LedRgb1 {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        Xyz::new(from_f64(1.08217), from_f64(1.0), from_f64(0.29257))
+    }
+}
+/// CIE LED illuminant series - LED-V1
+///
+/// LED-V1 represents a phosphor converted violet LED lamp, with a CCT of 2724
+/// K, for 2° Standard Observer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct LedV1;
+impl<T: FromF64> WhitePoint<T> for LedV1 {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        Xyz::new(from_f64(1.00264), from_f64(1.0), from_f64(0.19613))
+    }
+}
+/// CIE LED illuminant series - LED-V2
+///
+/// LED-V2 represents a phosphor converted violet LED lamp, with a CCT of 4070
+/// K, for 2° Standard Observer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct LedV2;
+impl<T: FromF64> WhitePoint<T> for LedV2 {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        Xyz::new(from_f64(1.00159), from_f64(1.0), from_f64(0.64742))
+    }
+}
+/// The DCI white point, used in digital cinema
+///
+/// The DCI white is slightly green compared to the daylight illuminants, and
+/// is the white point of [`DciP3`](crate::encoding::DciP3). It has the
+/// chromaticity coordinates `x = 0.314` and `y = 0.351`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct Dci;
+impl<T: FromF64> WhitePoint<T> for Dci {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        Xyz::new(from_f64(0.894587), from_f64(1.0), from_f64(0.954416))
+    }
+}
 /// CIE D series standard illuminant - D50
 ///
 /// D50 White Point is the natural daylight with a color temperature of around
 /// 5000K for 10° Standard Observer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
 pub struct D50Degree10;
 impl<T: FromF64> WhitePoint<T> for D50Degree10 {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(from_f64(0.9672), from_f64(1.0), from_f64(0.8143))
+        Xyz::new(from_f64(0.96720), from_f64(1.0), from_f64(0.81427))
     }
 }
 /// CIE D series standard illuminant - D55
@@ -179,11 +446,12 @@ impl<T: FromF64> WhitePoint<T> for D50Degree10 {
 /// D55 White Point is the natural daylight with a color temperature of around
 /// 5500K for 10° Standard Observer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
 pub struct D55Degree10;
 impl<T: FromF64> WhitePoint<T> for D55Degree10 {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(from_f64(0.958), from_f64(1.0), from_f64(0.9093))
+        Xyz::new(from_f64(0.95799), from_f64(1.0), from_f64(0.90926))
     }
 }
 /// CIE D series standard illuminant - D65
@@ -191,11 +459,12 @@ impl<T: FromF64> WhitePoint<T> for D55Degree10 {
 /// D65 White Point is the natural daylight with a color temperature of 6500K
 /// for 10° Standard Observer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
 pub struct D65Degree10;
 impl<T: FromF64> WhitePoint<T> for D65Degree10 {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(from_f64(0.9481), from_f64(1.0), from_f64(1.073))
+        Xyz::new(from_f64(0.94811), from_f64(1.0), from_f64(1.07304))
     }
 }
 /// CIE D series standard illuminant - D75
@@ -203,10 +472,377 @@ impl<T: FromF64> WhitePoint<T> for D65Degree10 {
 /// D75 White Point is the natural daylight with a color temperature of around
 /// 7500K for 10° Standard Observer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
 pub struct D75Degree10;
 impl<T: FromF64> WhitePoint<T> for D75Degree10 {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(from_f64(0.94416), from_f64(1.0), from_f64(1.2064))
+        Xyz::new(from_f64(0.94416), from_f64(1.0), from_f64(1.20641))
+    }
+}
+/// CIE standard illuminant A
+///
+/// CIE standard illuminant A is intended to represent typical, domestic,
+/// tungsten-filament lighting, for 10° Standard Observer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct ADegree10;
+impl<T: FromF64> WhitePoint<T> for ADegree10 {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        Xyz::new(from_f64(1.11144), from_f64(1.0), from_f64(0.35200))
+    }
+}
+/// CIE standard illuminant C
+///
+/// CIE standard illuminant C represents the average day light with a CCT of
+/// 6774 K, for 10° Standard Observer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct CDegree10;
+impl<T: FromF64> WhitePoint<T> for CDegree10 {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        Xyz::new(from_f64(0.97285), from_f64(1.0), from_f64(1.16145))
+    }
+}
+/// CIE fluorescent illuminant series - F2
+///
+/// F2 represents a semi-broadband fluorescent lamp for 10° Standard Observer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct F2Degree10;
+impl<T: FromF64> WhitePoint<T> for F2Degree10 {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        Xyz::new(from_f64(1.03280), from_f64(1.0), from_f64(0.69026))
+    }
+}
+/// CIE fluorescent illuminant series - F7
+///
+/// F7 represents a broadband fluorescent lamp for 10° Standard Observer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct F7Degree10;
+impl<T: FromF64> WhitePoint<T> for F7Degree10 {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        Xyz::new(from_f64(0.95792), from_f64(1.0), from_f64(1.07687))
+    }
+}
+/// CIE fluorescent illuminant series - F11
+///
+/// F11 represents a narrowband fluorescent lamp for 10° Standard Observer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct F11Degree10;
+impl<T: FromF64> WhitePoint<T> for F11Degree10 {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        Xyz::new(from_f64(1.03866), from_f64(1.0), from_f64(0.65627))
+    }
+}
+
+/// A standard observer, which is the color matching functions that the
+/// tristimulus values of a white point are calculated with.
+pub trait Observer: 'static {}
+
+/// The CIE 1931 2° Standard Observer.
+///
+/// This is the observer of most white points and color spaces, and it
+/// matches the color vision of the center of the visual field.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct Degree2;
+impl Observer for Degree2 {}
+
+/// The CIE 1964 10° Standard Observer.
+///
+/// This observer matches the color vision of a larger part of the visual
+/// field, and is used by many spectrophotometers and in textile and paint
+/// color measurement.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct Degree10;
+impl Observer for Degree10 {}
+
+/// A white point of a standard illuminant, that is tagged with its standard
+/// observer, and that knows the same illuminant for the other observer.
+///
+/// The observer of a white point, such as [`D65`] or [`D65Degree10`], is
+/// then available in generic code, and colors can be moved between the
+/// observers with [`Xyz::into_degree2`] and [`Xyz::into_degree10`]:
+///
+/// ```
+/// use palette::white_point::{Degree10, Observer, ObserverWhitePoint, D65, D65Degree10};
+/// use palette::Xyz;
+///
+/// fn is_degree10<Wp: ObserverWhitePoint<f32>>() -> bool {
+///     core::any::TypeId::of::<Wp::Observer>() == core::any::TypeId::of::<Degree10>()
+/// }
+///
+/// assert!(is_degree10::<D65Degree10>());
+/// assert!(!is_degree10::<D65>());
+///
+/// // The white of one observer is the white of the other.
+/// let white: Xyz<D65Degree10> = Xyz::<D65>::new(0.95047, 1.0, 1.08883).into_degree10();
+/// assert!((white.z - 1.07304).abs() < 1e-4);
+/// ```
+pub trait ObserverWhitePoint<T>: WhitePoint<T> {
+    /// The standard observer of the white point.
+    type Observer: Observer;
+
+    /// The same illuminant for the 2° Standard Observer.
+    type Degree2: ObserverWhitePoint<T, Observer = Degree2>;
+
+    /// The same illuminant for the 10° Standard Observer.
+    type Degree10: ObserverWhitePoint<T, Observer = Degree10>;
+}
+
+macro_rules! impl_observer_white_point {
+    ($($degree2:ident, $degree10:ident;)+) => {
+        $(
+            impl<T: FromF64> ObserverWhitePoint<T> for $degree2 {
+                type Observer = Degree2;
+                type Degree2 = $degree2;
+                type Degree10 = $degree10;
+            }
+
+            impl<T: FromF64> ObserverWhitePoint<T> for $degree10 {
+                type Observer = Degree10;
+                type Degree2 = $degree2;
+                type Degree10 = $degree10;
+            }
+        )+
+    };
+}
+
+impl_observer_white_point! {
+    A, ADegree10;
+    C, CDegree10;
+    D50, D50Degree10;
+    D55, D55Degree10;
+    D65, D65Degree10;
+    D75, D75Degree10;
+    F2, F2Degree10;
+    F7, F7Degree10;
+    F11, F11Degree10;
+}
+
+/// A white point that is only known at run-time, such as the measured white
+/// of a display.
+///
+/// The white points above are types, so they can't describe a white point
+/// that is measured or configured while the program runs. A
+/// `CustomWhitePoint` holds the white point as a value instead, and converts
+/// colors that are relative to it, as [`Xyz<Any, T>`](crate::Xyz), to and from
+/// colors with a white point type, with chromatic adaptation:
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::white_point::{CustomWhitePoint, D65};
+/// use palette::{Srgb, Xyz};
+///
+/// // The measured white of a display that is slightly warm.
+/// let display_white = CustomWhitePoint::from_chromaticity(0.3200f64, 0.3350);
+///
+/// // A measured color, as the display shows it, and how it would look on a
+/// // D65 display.
+/// let measured = Xyz::new(0.35, 0.3, 0.2);
+/// let color: Srgb<f64> = display_white.adapt_into::<D65, _>(measured);
+///
+/// // The display white is white on the D65 display.
+/// let white: Srgb<f64> = display_white.adapt_into::<D65, _>(display_white.xyz());
+/// assert_relative_eq!(white, Srgb::new(1.0, 1.0, 1.0), epsilon = 1e-6);
+/// # let _ = color;
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+pub struct CustomWhitePoint<T> {
+    xyz: Xyz<Any, T>,
+}
+
+impl<T> PartialEq for CustomWhitePoint<T>
+where
+    T: FloatComponent,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.xyz == other.xyz
+    }
+}
+
+impl<T> CustomWhitePoint<T>
+where
+    T: FloatComponent,
+{
+    /// Create a white point from its tristimulus values. They are scaled so
+    /// `Y` is `1.0`.
+    ///
+    /// See [`try_new`](CustomWhitePoint::try_new) for a version that doesn't
+    /// panic, for values that come from user input.
+    ///
+    /// # Panics
+    ///
+    /// If `Y` isn't positive and finite.
+    pub fn new(xyz: Xyz<Any, T>) -> Self {
+        Self::try_new(xyz).expect("the Y value of a white point must be positive and finite")
+    }
+
+    /// Create a white point from its tristimulus values, or `None` if `Y`
+    /// isn't positive and finite. They are scaled so `Y` is `1.0`.
+    pub fn try_new(xyz: Xyz<Any, T>) -> Option<Self> {
+        if xyz.y > T::zero() && xyz.y.is_finite() {
+            Some(CustomWhitePoint { xyz: xyz / xyz.y })
+        } else {
+            None
+        }
+    }
+
+    /// Create a white point from its chromaticity coordinates, `x` and `y`,
+    /// as measured by most colorimeters.
+    ///
+    /// See [`try_from_chromaticity`](CustomWhitePoint::try_from_chromaticity)
+    /// for a version that doesn't panic, for values that come from user
+    /// input.
+    ///
+    /// # Panics
+    ///
+    /// If `y` isn't positive and finite.
+    pub fn from_chromaticity(x: T, y: T) -> Self {
+        Self::try_from_chromaticity(x, y)
+            .expect("the y coordinate of a white point must be positive and finite")
+    }
+
+    /// Create a white point from its chromaticity coordinates, `x` and `y`,
+    /// or `None` if `y` isn't positive and finite.
+    pub fn try_from_chromaticity(x: T, y: T) -> Option<Self> {
+        if y > T::zero() && y.is_finite() {
+            Some(CustomWhitePoint {
+                xyz: Xyz::new(x / y, T::one(), (T::one() - x - y) / y),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Create a white point with the same values as the white point type
+    /// `Wp`.
+    pub fn from_white_point<Wp: WhitePoint<T>>() -> Self {
+        CustomWhitePoint::new(Wp::get_xyz())
+    }
+
+    /// Get the tristimulus values of the white point, where `Y` is `1.0`.
+    pub fn xyz(&self) -> Xyz<Any, T> {
+        self.xyz
+    }
+
+    /// Convert `color`, that is relative to this white point, to a color with
+    /// the white point `Wp`, with Bradford chromatic adaptation.
+    pub fn adapt_into<Wp, C>(&self, color: Xyz<Any, T>) -> C
+    where
+        Wp: WhitePoint<T>,
+        C: FromColorUnclamped<Xyz<Wp, T>>,
+    {
+        self.adapt_into_using::<Wp, C, _>(color, Method::Bradford)
+    }
+
+    /// Convert `color`, that is relative to this white point, to a color with
+    /// the white point `Wp`, with the chromatic adaptation `method`.
+    pub fn adapt_into_using<Wp, C, M>(&self, color: Xyz<Any, T>, method: M) -> C
+    where
+        Wp: WhitePoint<T>,
+        C: FromColorUnclamped<Xyz<Wp, T>>,
+        M: TransformMatrix<T>,
+    {
+        let matrix = method.generate_transform_matrix(self.xyz, Wp::get_xyz());
+        C::from_color_unclamped(multiply_xyz(&matrix, &color).with_white_point())
+    }
+
+    /// Convert `color`, with the white point `Wp`, to a color that is
+    /// relative to this white point, with Bradford chromatic adaptation.
+    pub fn adapt_from<Wp, C>(&self, color: C) -> Xyz<Any, T>
+    where
+        Wp: WhitePoint<T>,
+        C: IntoColorUnclamped<Xyz<Wp, T>>,
+    {
+        self.adapt_from_using::<Wp, C, _>(color, Method::Bradford)
+    }
+
+    /// Convert `color`, with the white point `Wp`, to a color that is
+    /// relative to this white point, with the chromatic adaptation `method`.
+    pub fn adapt_from_using<Wp, C, M>(&self, color: C, method: M) -> Xyz<Any, T>
+    where
+        Wp: WhitePoint<T>,
+        C: IntoColorUnclamped<Xyz<Wp, T>>,
+        M: TransformMatrix<T>,
+    {
+        let color: Xyz<Wp, T> = color.into_color_unclamped();
+        let matrix = method.generate_transform_matrix(Wp::get_xyz(), self.xyz);
+        multiply_xyz(&matrix, &color.with_white_point())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CustomWhitePoint, LedB3, LedRgb1, LedV1, WhitePoint, D50, D65, F1, F12, F5};
+    use crate::chromatic_adaptation::AdaptFrom;
+    use crate::{Lab, Xyz};
+
+    fn chromaticity<Wp: WhitePoint<f64>>() -> (f64, f64) {
+        let xyz = Wp::get_xyz();
+        let sum = xyz.x + xyz.y + xyz.z;
+        (xyz.x / sum, xyz.y / sum)
+    }
+
+    #[test]
+    fn fluorescent_chromaticities() {
+        assert_relative_eq!(chromaticity::<F1>().0, 0.31310, epsilon = 1e-5);
+        assert_relative_eq!(chromaticity::<F1>().1, 0.33727, epsilon = 1e-5);
+        assert_relative_eq!(chromaticity::<F5>().0, 0.31379, epsilon = 1e-5);
+        assert_relative_eq!(chromaticity::<F12>().1, 0.40441, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn led_chromaticities() {
+        assert_relative_eq!(chromaticity::<LedB3>().0, 0.3756, epsilon = 1e-4);
+        assert_relative_eq!(chromaticity::<LedB3>().1, 0.3723, epsilon = 1e-4);
+        assert_relative_eq!(chromaticity::<LedRgb1>().0, 0.4557, epsilon = 1e-4);
+        assert_relative_eq!(chromaticity::<LedV1>().1, 0.4548, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn custom_white_point() {
+        let d50 = CustomWhitePoint::<f64>::from_white_point::<D50>();
+        assert_relative_eq!(d50.xyz(), D50::get_xyz(), epsilon = 1e-12);
+
+        let from_chromaticity = CustomWhitePoint::from_chromaticity(0.34567, 0.35850);
+        assert_relative_eq!(from_chromaticity.xyz(), D50::get_xyz(), epsilon = 1e-3);
+
+        // Works the same as the white point type.
+        let color = Xyz::new(0.3, 0.4, 0.2);
+        let lab: Lab<D65, f64> = d50.adapt_into::<D65, _>(color);
+        let expected: Lab<D65, f64> = Lab::adapt_from(color.with_white_point::<D50>());
+        assert_relative_eq!(lab, expected, epsilon = 1e-6);
+
+        let back = d50.adapt_from::<D65, _>(lab);
+        assert_relative_eq!(back, color, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn custom_white_point_invalid() {
+        assert_eq!(CustomWhitePoint::try_new(Xyz::new(0.5, 0.0, 0.5)), None);
+        assert_eq!(CustomWhitePoint::try_new(Xyz::new(0.5, -1.0, 0.5)), None);
+        assert_eq!(CustomWhitePoint::try_from_chromaticity(0.3, 0.0), None);
+        assert_eq!(
+            CustomWhitePoint::try_from_chromaticity(0.3, f64::INFINITY),
+            None
+        );
+        assert!(CustomWhitePoint::try_from_chromaticity(0.3127f64, 0.3290).is_some());
+    }
+
+    #[test]
+    #[should_panic]
+    fn custom_white_point_zero_y() {
+        CustomWhitePoint::new(Xyz::new(0.5, 0.0, 0.5));
     }
 }
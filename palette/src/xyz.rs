@@ -9,15 +9,17 @@ use rand::distributions::{Distribution, Standard};
 #[cfg(feature = "random")]
 use rand::Rng;
 
+use crate::chromatic_adaptation::AdaptFrom;
 use crate::convert::{FromColorUnclamped, IntoColorUnclamped};
+use crate::float;
 use crate::luma::LumaStandard;
 use crate::matrix::{multiply_rgb_to_xyz, multiply_xyz, rgb_to_xyz_matrix};
 use crate::rgb::{Rgb, RgbSpace, RgbStandard};
-use crate::white_point::{WhitePoint, D65};
+use crate::white_point::{ObserverWhitePoint, WhitePoint, D65};
 use crate::{
-    clamp, clamp_assign, clamp_min_assign, contrast_ratio, from_f64, oklab, Alpha, Clamp,
-    ClampAssign, ComponentWise, FloatComponent, IsWithinBounds, Lab, Lighten, LightenAssign, Luma,
-    Luv, Mix, MixAssign, Oklab, Oklch, RelativeContrast, Yxy,
+    clamp, clamp_assign, clamp_min_assign, contrast_ratio, from_f64, hct, oklab, Alpha, Clamp,
+    ClampAssign, ComponentWise, FloatComponent, Hct, IsWithinBounds, Lab, Lighten, LightenAssign,
+    Luma, Luv, Mix, MixAssign, Oklab, Oklch, RelativeContrast, Yxy,
 };
 
 /// CIE 1931 XYZ with an alpha component. See the [`Xyza` implementation in
@@ -39,7 +41,7 @@ pub type Xyza<Wp = D65, T = f32> = Alpha<Xyz<Wp, T>, T>;
     palette_internal,
     white_point = "Wp",
     component = "T",
-    skip_derives(Xyz, Yxy, Luv, Rgb, Lab, Oklab, Oklch, Luma)
+    skip_derives(Xyz, Yxy, Luv, Rgb, Lab, Oklab, Oklch, Hct, Luma)
 )]
 #[repr(C)]
 pub struct Xyz<Wp = D65, T = f32> {
@@ -151,6 +153,32 @@ where
     }
 }
 
+impl<Wp, T> Xyz<Wp, T>
+where
+    T: FloatComponent,
+    Wp: ObserverWhitePoint<T>,
+{
+    /// Convert the color to the same illuminant for the 2° Standard
+    /// Observer.
+    ///
+    /// This is an approximation, in the same way as
+    /// [`into_degree10`](Xyz::into_degree10).
+    pub fn into_degree2(self) -> Xyz<Wp::Degree2, T> {
+        Xyz::adapt_from(self)
+    }
+
+    /// Convert the color to the same illuminant for the 10° Standard
+    /// Observer.
+    ///
+    /// The color is adapted from the white point of one observer to the other
+    /// with the Bradford method. This is an approximation, since an exact
+    /// conversion needs the spectrum of the color, but it's what's commonly
+    /// used when only the tristimulus values are known.
+    pub fn into_degree10(self) -> Xyz<Wp::Degree10, T> {
+        Xyz::adapt_from(self)
+    }
+}
+
 ///<span id="Xyza"></span>[`Xyza`](crate::Xyza) implementations.
 impl<Wp, T, A> Alpha<Xyz<Wp, T>, A> {
     /// Create a CIE XYZ color with transparency.
@@ -200,7 +228,9 @@ where
     S::Space: RgbSpace<T, WhitePoint = Wp>,
 {
     fn from_color_unclamped(color: Rgb<S, T>) -> Self {
+        trace!(conversion color);
         let transform_matrix = rgb_to_xyz_matrix::<S::Space, T>();
+        trace!(matrix "rgb_to_xyz");
         multiply_rgb_to_xyz(&transform_matrix, &color.into_linear())
     }
 }
@@ -210,6 +240,7 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(color: Yxy<Wp, T>) -> Self {
+        trace!(conversion color);
         let mut xyz = Xyz {
             y: color.luma,
             ..Default::default()
@@ -229,6 +260,7 @@ where
     Wp: WhitePoint<T>,
 {
     fn from_color_unclamped(color: Lab<Wp, T>) -> Self {
+        trace!(conversion color);
         // Recip call shows performance benefits in benchmarks for this function
         let y = (color.l + from_f64(16.0)) * from_f64::<T>(116.0).recip();
         let x = y + (color.a * from_f64::<T>(500.0).recip());
@@ -240,7 +272,7 @@ where
             let delta: T = from_f64(4.0 / 29.0);
 
             if c > epsilon {
-                c.powi(3)
+                float::powi(c, 3)
             } else {
                 (c - delta) * kappa
             }
@@ -256,9 +288,10 @@ where
     Wp: WhitePoint<T>,
 {
     fn from_color_unclamped(color: Luv<Wp, T>) -> Self {
+        trace!(conversion color);
         let from_f64 = T::from_f64;
 
-        let kappa: T = from_f64(29.0 / 3.0).powi(3);
+        let kappa: T = float::powi(from_f64(29.0 / 3.0), 3);
 
         let w = Wp::get_xyz();
         let ref_denom_recip = (w.x + from_f64(15.0) * w.y + from_f64(3.0) * w.z).recip();
@@ -270,7 +303,7 @@ where
         }
 
         let y = if color.l > from_f64(8.0) {
-            ((color.l + from_f64(16.0)) * from_f64(116.0).recip()).powi(3)
+            float::powi((color.l + from_f64(16.0)) * from_f64(116.0).recip(), 3)
         } else {
             color.l * kappa.recip()
         } * w.y;
@@ -289,14 +322,17 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(color: Oklab<T>) -> Self {
+        trace!(conversion color);
         let m1_inv = oklab::m1_inv();
         let m2_inv = oklab::m2_inv();
 
+        trace!(matrix "oklab_m2_inv");
         let Xyz {
             x: l, y: m, z: s, ..
         } = multiply_xyz(&m2_inv, &Xyz::new(color.l, color.a, color.b));
 
-        let lms = Xyz::new(l.powi(3), m.powi(3), s.powi(3));
+        let lms = Xyz::new(float::powi(l, 3), float::powi(m, 3), float::powi(s, 3));
+        trace!(matrix "oklab_m1_inv");
         multiply_xyz(&m1_inv, &lms).with_white_point()
     }
 }
@@ -306,19 +342,32 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(color: Oklch<T>) -> Self {
+        trace!(conversion color);
         let oklab: Oklab<T> = color.into_color_unclamped();
         Self::from_color_unclamped(oklab)
     }
 }
 
+impl<T> FromColorUnclamped<Hct<T>> for Xyz<D65, T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Hct<T>) -> Self {
+        trace!(conversion color);
+        hct::hct_to_xyz(color)
+    }
+}
+
 impl<Wp, T, S> FromColorUnclamped<Luma<S, T>> for Xyz<Wp, T>
 where
+    T: FloatComponent,
     Self: Mul<T, Output = Self>,
     Wp: WhitePoint<T>,
     S: LumaStandard<T, WhitePoint = Wp>,
 {
     fn from_color_unclamped(color: Luma<S, T>) -> Self {
-        Wp::get_xyz().with_white_point::<Wp>() * color.luma
+        trace!(conversion color);
+        Wp::get_xyz().with_white_point::<Wp>() * color.into_linear().luma
     }
 }
 
@@ -346,6 +395,9 @@ impl<Wp, T, A> From<Alpha<Xyz<Wp, T>, A>> for (T, T, T, A) {
     }
 }
 
+impl_tuple_ref_conversions!([Wp, T] [] Xyz<Wp, T>, (T, T, T) => (T, T, T));
+impl_tuple_ref_conversions!([Wp, T, A] [] Alpha<Xyz<Wp, T>, A>, (T, T, T, A) => (T, T, T, A));
+
 impl<Wp, T> IsWithinBounds for Xyz<Wp, T>
 where
     T: Zero + PartialOrd,
@@ -516,6 +568,7 @@ impl_color_mul!(Xyz<Wp, T>, [x, y, z], white_point);
 impl_color_div!(Xyz<Wp, T>, [x, y, z], white_point);
 
 impl_array_casts!(Xyz<Wp, T>, [T; 3]);
+impl_component_access!(Xyz<Wp, T>, [T; 3]);
 
 impl<Wp, T> RelativeContrast for Xyz<Wp, T>
 where
@@ -625,7 +678,7 @@ unsafe impl<Wp: 'static, T> bytemuck::Pod for Xyz<Wp, T> where T: bytemuck::Pod
 mod test {
     use super::Xyz;
     use crate::white_point::D65;
-    use crate::{FromColor, LinLuma, LinSrgb};
+    use crate::{FromColor, LinLuma, LinSrgb, SrgbLuma};
 
     #[cfg(feature = "random")]
     use crate::white_point::WhitePoint;
@@ -634,6 +687,23 @@ mod test {
     const Y_N: f64 = 1.0;
     const Z_N: f64 = 1.08883;
 
+    #[test]
+    fn observers() {
+        use crate::white_point::{D50Degree10, WhitePoint, D50};
+
+        let white: Xyz<D50Degree10, f64> = D50::get_xyz().with_white_point::<D50>().into_degree10();
+        assert_relative_eq!(
+            white,
+            D50Degree10::get_xyz().with_white_point(),
+            epsilon = 1e-6
+        );
+
+        let color = Xyz::<D65, f64>::new(0.3, 0.2, 0.5);
+        let back = color.into_degree10().into_degree2();
+        assert_relative_eq!(back, color, epsilon = 1e-6);
+        assert_relative_eq!(color.into_degree2(), color, epsilon = 1e-6);
+    }
+
     #[test]
     fn luma() {
         let a = Xyz::<D65>::from_color(LinLuma::new(0.5));
@@ -641,6 +711,13 @@ mod test {
         assert_relative_eq!(a, b, epsilon = 0.0001);
     }
 
+    #[test]
+    fn non_linear_luma() {
+        let a = Xyz::<D65>::from_color(SrgbLuma::new(0.5));
+        let b = Xyz::new(0.203440, 0.214041, 0.233054);
+        assert_relative_eq!(a, b, epsilon = 0.0001);
+    }
+
     #[test]
     fn red() {
         let a = Xyz::from_color(LinSrgb::new(1.0, 0.0, 0.0));
@@ -0,0 +1,60 @@
+//! YCbCr, YPbPr, YUV and YIQ types and video standards.
+
+mod ycbcr;
+mod yiq;
+mod ypbpr;
+mod yuv;
+
+use crate::encoding;
+use crate::luma::LumaWeights;
+
+pub use self::ycbcr::{YCbCr, YCbCra};
+pub use self::yiq::{Yiq, Yiqa};
+pub use self::ypbpr::{YPbPr, YPbPra};
+pub use self::yuv::{Yuv, Yuva};
+
+/// The luma weights of a video standard, which decide how
+/// [`YCbCr`](crate::YCbCr), [`YPbPr`](crate::YPbPr) and [`Yuv`](crate::Yuv)
+/// are calculated from RGB.
+pub trait YCbCrStandard: 'static {
+    /// The RGB standard that the luma and color difference components are
+    /// calculated from. Other RGB colors are converted to it first.
+    type RgbStandard;
+
+    /// The weights of the red, green and blue components in the luma
+    /// component.
+    const WEIGHTS: LumaWeights;
+}
+
+/// ITU-R BT.601, used for standard definition video and JPEG.
+///
+/// The RGB standard is sRGB, and not the Rec. 709 style transfer function
+/// that BT.601 specifies. JPEG, through JFIF, and most other software apply
+/// the BT.601 weights directly to sRGB, so this gives the same values as
+/// them. The BT.601 primaries are not included either, but they are almost
+/// the same as the sRGB primaries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bt601;
+
+impl YCbCrStandard for Bt601 {
+    type RgbStandard = encoding::Srgb;
+    const WEIGHTS: LumaWeights = LumaWeights::Rec601;
+}
+
+/// ITU-R BT.709, used for high definition video.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bt709;
+
+impl YCbCrStandard for Bt709 {
+    type RgbStandard = encoding::Rec709;
+    const WEIGHTS: LumaWeights = LumaWeights::Rec709;
+}
+
+/// ITU-R BT.2020, used for ultra high definition video.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bt2020;
+
+impl YCbCrStandard for Bt2020 {
+    type RgbStandard = encoding::Rec2020;
+    const WEIGHTS: LumaWeights = LumaWeights::Rec2020;
+}
@@ -0,0 +1,495 @@
+use core::marker::PhantomData;
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::convert::FromColorUnclamped;
+use crate::rgb::Rgb;
+use crate::ycbcr::{Bt709, YCbCrStandard};
+use crate::{
+    clamp, clamp_assign, Alpha, Clamp, ClampAssign, Component, FloatComponent, IsWithinBounds, Mix,
+    MixAssign,
+};
+
+/// YCbCr with an alpha component. See the [`YCbCra` implementation in
+/// `Alpha`](crate::Alpha#YCbCra).
+pub type YCbCra<St = Bt709, T = f32> = Alpha<YCbCr<St, T>, T>;
+
+/// The YCbCr color space, as used for digital video.
+///
+/// YCbCr splits a color into a luma component, `y`, and two color difference
+/// components, `cb` and `cr`, which are the blue and red differences from
+/// the luma. It's calculated from RGB with the luma weights of the video
+/// standard `St`, which is one of [`Bt601`](crate::ycbcr::Bt601),
+/// [`Bt709`](crate::ycbcr::Bt709) and [`Bt2020`](crate::ycbcr::Bt2020).
+///
+/// The conversion uses the gamma encoded RGB of the video standard, which is
+/// sRGB for BT.601, [`Rec709`](crate::encoding::Rec709) for BT.709 and
+/// [`Rec2020`](crate::encoding::Rec2020) for BT.2020. Other RGB colors,
+/// including linear RGB, are converted to it first. The components are in
+/// full range, where `y` is between 0.0 and 1.0, and `cb` and `cr` are
+/// between -0.5 and 0.5. Conversion to integer studio range is not included.
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::ycbcr::Bt601;
+/// use palette::{FromColor, Srgb, YCbCr};
+///
+/// let red = YCbCr::<Bt601, f64>::from_color(Srgb::new(1.0, 0.0, 0.0));
+/// assert_relative_eq!(red, YCbCr::new(0.299, -0.168736, 0.5), epsilon = 1e-6);
+///
+/// let back = Srgb::from_color(red);
+/// assert_relative_eq!(back, Srgb::new(1.0, 0.0, 0.0), epsilon = 1e-12);
+/// ```
+#[derive(Debug, ArrayCast, WithAlpha)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[palette(palette_internal, component = "T")]
+#[repr(C)]
+pub struct YCbCr<St = Bt709, T = f32> {
+    /// The luma component, from 0.0 to 1.0.
+    pub y: T,
+
+    /// The blue difference component, from -0.5 to 0.5.
+    pub cb: T,
+
+    /// The red difference component, from -0.5 to 0.5.
+    pub cr: T,
+
+    /// The video standard of the color. The default is BT.709.
+    #[cfg_attr(feature = "serializing", serde(skip))]
+    #[palette(unsafe_zero_sized)]
+    pub standard: PhantomData<St>,
+}
+
+impl<St, T> Copy for YCbCr<St, T> where T: Copy {}
+
+impl<St, T> Clone for YCbCr<St, T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> YCbCr<St, T> {
+        YCbCr {
+            y: self.y.clone(),
+            cb: self.cb.clone(),
+            cr: self.cr.clone(),
+            standard: PhantomData,
+        }
+    }
+}
+
+impl<St, T> YCbCr<St, T> {
+    /// Create a YCbCr color.
+    pub const fn new(y: T, cb: T, cr: T) -> YCbCr<St, T> {
+        YCbCr {
+            y,
+            cb,
+            cr,
+            standard: PhantomData,
+        }
+    }
+
+    /// Convert to a `(y, cb, cr)` tuple.
+    pub fn into_components(self) -> (T, T, T) {
+        (self.y, self.cb, self.cr)
+    }
+
+    /// Convert from a `(y, cb, cr)` tuple.
+    pub fn from_components((y, cb, cr): (T, T, T)) -> Self {
+        Self::new(y, cb, cr)
+    }
+}
+
+impl<St, T> YCbCr<St, T>
+where
+    T: FloatComponent,
+{
+    /// Return the `y` value minimum.
+    pub fn min_y() -> T {
+        T::zero()
+    }
+
+    /// Return the `y` value maximum.
+    pub fn max_y() -> T {
+        T::one()
+    }
+
+    /// Return the `cb` value minimum.
+    pub fn min_cb() -> T {
+        T::from_f64(-0.5)
+    }
+
+    /// Return the `cb` value maximum.
+    pub fn max_cb() -> T {
+        T::from_f64(0.5)
+    }
+
+    /// Return the `cr` value minimum.
+    pub fn min_cr() -> T {
+        T::from_f64(-0.5)
+    }
+
+    /// Return the `cr` value maximum.
+    pub fn max_cr() -> T {
+        T::from_f64(0.5)
+    }
+}
+
+impl<St, T> PartialEq for YCbCr<St, T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.y == other.y && self.cb == other.cb && self.cr == other.cr
+    }
+}
+
+impl<St, T> Eq for YCbCr<St, T> where T: Eq {}
+
+///<span id="YCbCra"></span>[`YCbCra`](crate::YCbCra) implementations.
+impl<St, T, A> Alpha<YCbCr<St, T>, A> {
+    /// Create a YCbCr color with transparency.
+    pub const fn new(y: T, cb: T, cr: T, alpha: A) -> Self {
+        Alpha {
+            color: YCbCr::new(y, cb, cr),
+            alpha,
+        }
+    }
+
+    /// Convert to a `(y, cb, cr, alpha)` tuple.
+    pub fn into_components(self) -> (T, T, T, A) {
+        (self.color.y, self.color.cb, self.color.cr, self.alpha)
+    }
+
+    /// Convert from a `(y, cb, cr, alpha)` tuple.
+    pub fn from_components((y, cb, cr, alpha): (T, T, T, A)) -> Self {
+        Self::new(y, cb, cr, alpha)
+    }
+}
+
+impl<St, T> FromColorUnclamped<YCbCr<St, T>> for YCbCr<St, T> {
+    fn from_color_unclamped(color: YCbCr<St, T>) -> Self {
+        color
+    }
+}
+
+impl<S, St, T> FromColorUnclamped<Rgb<S, T>> for YCbCr<St, T>
+where
+    St: YCbCrStandard,
+    T: FloatComponent,
+    Rgb<St::RgbStandard, T>: FromColorUnclamped<Rgb<S, T>>,
+{
+    fn from_color_unclamped(color: Rgb<S, T>) -> Self {
+        trace!(conversion color);
+        let color = Rgb::<St::RgbStandard, T>::from_color_unclamped(color);
+        let [kr, kg, kb]: [T; 3] = St::WEIGHTS.into_coefficients();
+        let two = T::from_f64(2.0);
+
+        let y = kr * color.red + kg * color.green + kb * color.blue;
+        YCbCr::new(
+            y,
+            (color.blue - y) / (two * (T::one() - kb)),
+            (color.red - y) / (two * (T::one() - kr)),
+        )
+    }
+}
+
+impl<S, St, T> FromColorUnclamped<YCbCr<St, T>> for Rgb<S, T>
+where
+    St: YCbCrStandard,
+    T: FloatComponent,
+    Rgb<S, T>: FromColorUnclamped<Rgb<St::RgbStandard, T>>,
+{
+    fn from_color_unclamped(color: YCbCr<St, T>) -> Self {
+        trace!(conversion color);
+        let [kr, kg, kb]: [T; 3] = St::WEIGHTS.into_coefficients();
+        let two = T::from_f64(2.0);
+
+        let red = color.y + two * (T::one() - kr) * color.cr;
+        let blue = color.y + two * (T::one() - kb) * color.cb;
+        let green = (color.y - kr * red - kb * blue) / kg;
+        Self::from_color_unclamped(Rgb::<St::RgbStandard, T>::new(red, green, blue))
+    }
+}
+
+impl<St, T> From<(T, T, T)> for YCbCr<St, T> {
+    fn from(components: (T, T, T)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<St, T> From<YCbCr<St, T>> for (T, T, T) {
+    fn from(color: YCbCr<St, T>) -> (T, T, T) {
+        color.into_components()
+    }
+}
+
+impl<St, T, A> From<(T, T, T, A)> for Alpha<YCbCr<St, T>, A> {
+    fn from(components: (T, T, T, A)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<St, T, A> From<Alpha<YCbCr<St, T>, A>> for (T, T, T, A) {
+    fn from(color: Alpha<YCbCr<St, T>, A>) -> (T, T, T, A) {
+        color.into_components()
+    }
+}
+
+impl_tuple_ref_conversions!([St, T] [] YCbCr<St, T>, (T, T, T) => (T, T, T));
+impl_tuple_ref_conversions!([St, T, A] [] Alpha<YCbCr<St, T>, A>, (T, T, T, A) => (T, T, T, A));
+
+impl<St, T> IsWithinBounds for YCbCr<St, T>
+where
+    T: FloatComponent,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn is_within_bounds(&self) -> bool {
+        self.y >= Self::min_y() && self.y <= Self::max_y() &&
+        self.cb >= Self::min_cb() && self.cb <= Self::max_cb() &&
+        self.cr >= Self::min_cr() && self.cr <= Self::max_cr()
+    }
+}
+
+impl<St, T> Clamp for YCbCr<St, T>
+where
+    T: FloatComponent,
+{
+    #[inline]
+    fn clamp(self) -> Self {
+        Self::new(
+            clamp(self.y, Self::min_y(), Self::max_y()),
+            clamp(self.cb, Self::min_cb(), Self::max_cb()),
+            clamp(self.cr, Self::min_cr(), Self::max_cr()),
+        )
+    }
+}
+
+impl<St, T> ClampAssign for YCbCr<St, T>
+where
+    T: FloatComponent,
+{
+    #[inline]
+    fn clamp_assign(&mut self) {
+        clamp_assign(&mut self.y, Self::min_y(), Self::max_y());
+        clamp_assign(&mut self.cb, Self::min_cb(), Self::max_cb());
+        clamp_assign(&mut self.cr, Self::min_cr(), Self::max_cr());
+    }
+}
+
+impl<St, T> Mix for YCbCr<St, T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn mix(self, other: Self, factor: T) -> Self {
+        let factor = clamp(factor, T::zero(), T::one());
+        YCbCr::new(
+            self.y + (other.y - self.y) * factor,
+            self.cb + (other.cb - self.cb) * factor,
+            self.cr + (other.cr - self.cr) * factor,
+        )
+    }
+}
+
+impl<St, T> MixAssign for YCbCr<St, T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn mix_assign(&mut self, other: Self, factor: T) {
+        *self = self.mix(other, factor);
+    }
+}
+
+impl<St, T> Default for YCbCr<St, T>
+where
+    T: Component,
+{
+    fn default() -> YCbCr<St, T> {
+        YCbCr::new(T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl_array_casts!(YCbCr<St, T>, [T; 3]);
+impl_component_access!(YCbCr<St, T>, [T; 3]);
+
+impl<St, T> AbsDiffEq for YCbCr<St, T>
+where
+    T: AbsDiffEq,
+    T::Epsilon: Clone,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    #[rustfmt::skip]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.y.abs_diff_eq(&other.y, epsilon.clone()) &&
+            self.cb.abs_diff_eq(&other.cb, epsilon.clone()) &&
+            self.cr.abs_diff_eq(&other.cr, epsilon)
+    }
+}
+
+impl<St, T> RelativeEq for YCbCr<St, T>
+where
+    T: RelativeEq,
+    T::Epsilon: Clone,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    #[rustfmt::skip]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.y.relative_eq(&other.y, epsilon.clone(), max_relative.clone()) &&
+            self.cb.relative_eq(&other.cb, epsilon.clone(), max_relative.clone()) &&
+            self.cr.relative_eq(&other.cr, epsilon, max_relative)
+    }
+}
+
+impl<St, T> UlpsEq for YCbCr<St, T>
+where
+    T: UlpsEq,
+    T::Epsilon: Clone,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    #[rustfmt::skip]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.y.ulps_eq(&other.y, epsilon.clone(), max_ulps) &&
+            self.cb.ulps_eq(&other.cb, epsilon.clone(), max_ulps) &&
+            self.cr.ulps_eq(&other.cr, epsilon, max_ulps)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<St, T> bytemuck::Zeroable for YCbCr<St, T> where T: bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<St: 'static, T> bytemuck::Pod for YCbCr<St, T> where T: bytemuck::Pod {}
+
+#[cfg(test)]
+mod test {
+    use super::YCbCr;
+    use crate::encoding::{Rec2020, Rec709};
+    use crate::rgb::Rgb;
+    use crate::ycbcr::{Bt2020, Bt601, Bt709};
+    use crate::{Clamp, FromColor, IntoColor, LinSrgb, Mix, Srgb, Srgba, YCbCra};
+
+    #[test]
+    fn primaries() {
+        let red = YCbCr::<Bt709, f64>::from_color(Rgb::<Rec709, f64>::new(1.0, 0.0, 0.0));
+        assert_relative_eq!(red, YCbCr::new(0.2126, -0.114572, 0.5), epsilon = 1e-6);
+
+        let blue = YCbCr::<Bt2020, f64>::from_color(Rgb::<Rec2020, f64>::new(0.0, 0.0, 1.0));
+        assert_relative_eq!(blue, YCbCr::new(0.0593, 0.5, -0.040214), epsilon = 1e-6);
+
+        let white = YCbCr::<Bt601, f64>::from_color(Srgb::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(white, YCbCr::new(1.0, 0.0, 0.0), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn round_trip() {
+        for &(red, green, blue) in &[
+            (0.2f64, 0.4, 0.8),
+            (0.9, 0.1, 0.5),
+            (0.0, 1.0, 0.0),
+            (0.5, 0.5, 0.5),
+        ] {
+            let rgb = Srgb::new(red, green, blue);
+            let ycbcr: YCbCr<Bt601, f64> = rgb.into_color();
+            assert_relative_eq!(Srgb::from_color(ycbcr), rgb, epsilon = 1e-12);
+
+            let rgb = Rgb::<Rec709, f64>::new(red, green, blue);
+            let ycbcr: YCbCr<Bt709, f64> = rgb.into_color();
+            assert_relative_eq!(Rgb::from_color(ycbcr), rgb, epsilon = 1e-12);
+
+            let rgb = Rgb::<Rec2020, f64>::new(red, green, blue);
+            let ycbcr: YCbCr<Bt2020, f64> = rgb.into_color();
+            assert_relative_eq!(Rgb::from_color(ycbcr), rgb, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn other_rgb_standards() {
+        // Other RGB colors are converted to the standard's RGB first.
+        let srgb = Srgb::new(0.2f64, 0.4, 0.8);
+        let ycbcr: YCbCr<Bt601, f64> = srgb.into_linear().into_color();
+        assert_relative_eq!(ycbcr, YCbCr::from_color(srgb), epsilon = 1e-6);
+        assert_relative_eq!(
+            LinSrgb::from_color(ycbcr),
+            srgb.into_linear(),
+            epsilon = 1e-6
+        );
+
+        let ycbcr: YCbCr<Bt709, f64> = srgb.into_color();
+        let rec709 = Rgb::<Rec709, f64>::from_color(srgb);
+        assert_relative_eq!(ycbcr, YCbCr::from_color(rec709), epsilon = 1e-6);
+        assert_relative_eq!(Srgb::from_color(ycbcr), srgb, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn alpha() {
+        let ycbcra: YCbCra<Bt709, f64> = Srgba::new(0.2, 0.4, 0.8, 0.5).into_color();
+        assert_relative_eq!(ycbcra.alpha, 0.5);
+
+        let back: Srgba<f64> = ycbcra.into_color();
+        assert_relative_eq!(back, Srgba::new(0.2, 0.4, 0.8, 0.5), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn clamp() {
+        let clamped = YCbCr::<Bt709, f64>::new(1.2, -0.7, 0.3).clamp();
+        assert_relative_eq!(clamped, YCbCr::new(1.0, -0.5, 0.3));
+    }
+
+    #[test]
+    fn mix() {
+        let a = YCbCr::<Bt709, f64>::new(0.2, -0.4, 0.1);
+        let b = YCbCr::new(0.6, 0.2, 0.3);
+        assert_relative_eq!(a.mix(b, 0.5), YCbCr::new(0.4, -0.1, 0.2), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn check_min_max_components() {
+        assert_relative_eq!(YCbCr::<Bt709>::min_y(), 0.0);
+        assert_relative_eq!(YCbCr::<Bt709>::max_y(), 1.0);
+        assert_relative_eq!(YCbCr::<Bt709>::min_cb(), -0.5);
+        assert_relative_eq!(YCbCr::<Bt709>::max_cb(), 0.5);
+        assert_relative_eq!(YCbCr::<Bt709>::min_cr(), -0.5);
+        assert_relative_eq!(YCbCr::<Bt709>::max_cr(), 0.5);
+    }
+
+    raw_pixel_conversion_tests!(YCbCr<Bt709>: y, cb, cr);
+    raw_pixel_conversion_fail_tests!(YCbCr<Bt709>: y, cb, cr);
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn serialize() {
+        let serialized = ::serde_json::to_string(&YCbCr::<Bt709>::new(0.3, 0.2, -0.1)).unwrap();
+
+        assert_eq!(serialized, r#"{"y":0.3,"cb":0.2,"cr":-0.1}"#);
+    }
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn deserialize() {
+        let deserialized: YCbCr =
+            ::serde_json::from_str(r#"{"y":0.3,"cb":0.2,"cr":-0.1}"#).unwrap();
+
+        assert_eq!(deserialized, YCbCr::new(0.3, 0.2, -0.1));
+    }
+}
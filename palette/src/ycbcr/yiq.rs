@@ -0,0 +1,475 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::convert::FromColorUnclamped;
+use crate::float;
+use crate::rgb::Rgb;
+use crate::ycbcr::{Bt601, Yuv};
+use crate::{
+    clamp, clamp_assign, Alpha, Clamp, ClampAssign, Component, FloatComponent, IsWithinBounds, Mix,
+    MixAssign,
+};
+
+/// The angle between the `i` and `v` axes, in degrees.
+const ROTATION: f64 = 33.0;
+
+/// The largest magnitude of `i`, rounded up.
+const I_MAX: f64 = 0.596;
+
+/// The largest magnitude of `q`, rounded up.
+const Q_MAX: f64 = 0.523;
+
+/// YIQ with an alpha component. See the [`Yiqa` implementation in
+/// `Alpha`](crate::Alpha#Yiqa).
+pub type Yiqa<T = f32> = Alpha<Yiq<T>, T>;
+
+/// The YIQ color space, as used for NTSC television.
+///
+/// YIQ is [`Yuv`](crate::Yuv) with the BT.601 luma weights, where the
+/// color difference components are rotated by 33°. The in-phase component,
+/// `i`, goes from blue to orange, and the quadrature component, `q`, goes
+/// from green to purple. `y` is between 0.0 and 1.0, `i` is between about
+/// -0.596 and 0.596, and `q` is between about -0.523 and 0.523.
+///
+/// The conversion uses gamma encoded sRGB. Other RGB colors, including
+/// linear RGB, are converted to it first.
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::{FromColor, Srgb, Yiq};
+///
+/// let red = Yiq::<f64>::from_color(Srgb::new(1.0, 0.0, 0.0));
+/// assert_relative_eq!(red, Yiq::new(0.299, 0.595919, 0.211553), epsilon = 1e-6);
+///
+/// let back = Srgb::from_color(red);
+/// assert_relative_eq!(back, Srgb::new(1.0, 0.0, 0.0), epsilon = 1e-12);
+/// ```
+#[derive(Debug, PartialEq, ArrayCast, WithAlpha)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[palette(palette_internal, component = "T")]
+#[repr(C)]
+pub struct Yiq<T = f32> {
+    /// The luma component, from 0.0 to 1.0.
+    pub y: T,
+
+    /// The in-phase component, from about -0.596 to 0.596.
+    pub i: T,
+
+    /// The quadrature component, from about -0.523 to 0.523.
+    pub q: T,
+}
+
+impl<T> Copy for Yiq<T> where T: Copy {}
+
+impl<T> Clone for Yiq<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Yiq<T> {
+        Yiq {
+            y: self.y.clone(),
+            i: self.i.clone(),
+            q: self.q.clone(),
+        }
+    }
+}
+
+impl<T> Yiq<T> {
+    /// Create a YIQ color.
+    pub const fn new(y: T, i: T, q: T) -> Yiq<T> {
+        Yiq { y, i, q }
+    }
+
+    /// Convert to a `(y, i, q)` tuple.
+    pub fn into_components(self) -> (T, T, T) {
+        (self.y, self.i, self.q)
+    }
+
+    /// Convert from a `(y, i, q)` tuple.
+    pub fn from_components((y, i, q): (T, T, T)) -> Self {
+        Self::new(y, i, q)
+    }
+}
+
+impl<T> Yiq<T>
+where
+    T: FloatComponent,
+{
+    /// Return the `y` value minimum.
+    pub fn min_y() -> T {
+        T::zero()
+    }
+
+    /// Return the `y` value maximum.
+    pub fn max_y() -> T {
+        T::one()
+    }
+
+    /// Return the `i` value minimum.
+    pub fn min_i() -> T {
+        T::from_f64(-I_MAX)
+    }
+
+    /// Return the `i` value maximum.
+    pub fn max_i() -> T {
+        T::from_f64(I_MAX)
+    }
+
+    /// Return the `q` value minimum.
+    pub fn min_q() -> T {
+        T::from_f64(-Q_MAX)
+    }
+
+    /// Return the `q` value maximum.
+    pub fn max_q() -> T {
+        T::from_f64(Q_MAX)
+    }
+}
+
+///<span id="Yiqa"></span>[`Yiqa`](crate::Yiqa) implementations.
+impl<T, A> Alpha<Yiq<T>, A> {
+    /// Create a YIQ color with transparency.
+    pub const fn new(y: T, i: T, q: T, alpha: A) -> Self {
+        Alpha {
+            color: Yiq::new(y, i, q),
+            alpha,
+        }
+    }
+
+    /// Convert to a `(y, i, q, alpha)` tuple.
+    pub fn into_components(self) -> (T, T, T, A) {
+        (self.color.y, self.color.i, self.color.q, self.alpha)
+    }
+
+    /// Convert from a `(y, i, q, alpha)` tuple.
+    pub fn from_components((y, i, q, alpha): (T, T, T, A)) -> Self {
+        Self::new(y, i, q, alpha)
+    }
+}
+
+impl<T> FromColorUnclamped<Yiq<T>> for Yiq<T> {
+    fn from_color_unclamped(color: Yiq<T>) -> Self {
+        color
+    }
+}
+
+impl<T> FromColorUnclamped<Yuv<Bt601, T>> for Yiq<T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Yuv<Bt601, T>) -> Self {
+        let (sin, cos) = float::sin_cos(T::from_f64(ROTATION.to_radians()));
+
+        Yiq::new(
+            color.y,
+            color.v * cos - color.u * sin,
+            color.v * sin + color.u * cos,
+        )
+    }
+}
+
+impl<T> FromColorUnclamped<Yiq<T>> for Yuv<Bt601, T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Yiq<T>) -> Self {
+        let (sin, cos) = float::sin_cos(T::from_f64(ROTATION.to_radians()));
+
+        Yuv::new(
+            color.y,
+            color.q * cos - color.i * sin,
+            color.i * cos + color.q * sin,
+        )
+    }
+}
+
+impl<S, T> FromColorUnclamped<Rgb<S, T>> for Yiq<T>
+where
+    T: FloatComponent,
+    Yuv<Bt601, T>: FromColorUnclamped<Rgb<S, T>>,
+{
+    fn from_color_unclamped(color: Rgb<S, T>) -> Self {
+        trace!(conversion color);
+        let yuv = Yuv::<Bt601, T>::from_color_unclamped(color);
+        Self::from_color_unclamped(yuv)
+    }
+}
+
+impl<S, T> FromColorUnclamped<Yiq<T>> for Rgb<S, T>
+where
+    T: FloatComponent,
+    Rgb<S, T>: FromColorUnclamped<Yuv<Bt601, T>>,
+{
+    fn from_color_unclamped(color: Yiq<T>) -> Self {
+        trace!(conversion color);
+        let yuv = Yuv::<Bt601, T>::from_color_unclamped(color);
+        Self::from_color_unclamped(yuv)
+    }
+}
+
+impl<T> From<(T, T, T)> for Yiq<T> {
+    fn from(components: (T, T, T)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T> From<Yiq<T>> for (T, T, T) {
+    fn from(color: Yiq<T>) -> (T, T, T) {
+        color.into_components()
+    }
+}
+
+impl<T, A> From<(T, T, T, A)> for Alpha<Yiq<T>, A> {
+    fn from(components: (T, T, T, A)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T, A> From<Alpha<Yiq<T>, A>> for (T, T, T, A) {
+    fn from(color: Alpha<Yiq<T>, A>) -> (T, T, T, A) {
+        color.into_components()
+    }
+}
+
+impl_tuple_ref_conversions!([T] [] Yiq<T>, (T, T, T) => (T, T, T));
+impl_tuple_ref_conversions!([T, A] [] Alpha<Yiq<T>, A>, (T, T, T, A) => (T, T, T, A));
+
+impl<T> IsWithinBounds for Yiq<T>
+where
+    T: FloatComponent,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn is_within_bounds(&self) -> bool {
+        self.y >= Self::min_y() && self.y <= Self::max_y() &&
+        self.i >= Self::min_i() && self.i <= Self::max_i() &&
+        self.q >= Self::min_q() && self.q <= Self::max_q()
+    }
+}
+
+impl<T> Clamp for Yiq<T>
+where
+    T: FloatComponent,
+{
+    #[inline]
+    fn clamp(self) -> Self {
+        Self::new(
+            clamp(self.y, Self::min_y(), Self::max_y()),
+            clamp(self.i, Self::min_i(), Self::max_i()),
+            clamp(self.q, Self::min_q(), Self::max_q()),
+        )
+    }
+}
+
+impl<T> ClampAssign for Yiq<T>
+where
+    T: FloatComponent,
+{
+    #[inline]
+    fn clamp_assign(&mut self) {
+        clamp_assign(&mut self.y, Self::min_y(), Self::max_y());
+        clamp_assign(&mut self.i, Self::min_i(), Self::max_i());
+        clamp_assign(&mut self.q, Self::min_q(), Self::max_q());
+    }
+}
+
+impl<T> Mix for Yiq<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn mix(self, other: Self, factor: T) -> Self {
+        let factor = clamp(factor, T::zero(), T::one());
+        Yiq::new(
+            self.y + (other.y - self.y) * factor,
+            self.i + (other.i - self.i) * factor,
+            self.q + (other.q - self.q) * factor,
+        )
+    }
+}
+
+impl<T> MixAssign for Yiq<T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn mix_assign(&mut self, other: Self, factor: T) {
+        *self = self.mix(other, factor);
+    }
+}
+
+impl<T> Default for Yiq<T>
+where
+    T: Component,
+{
+    fn default() -> Yiq<T> {
+        Yiq::new(T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl_array_casts!(Yiq<T>, [T; 3]);
+impl_component_access!(Yiq<T>, [T; 3]);
+
+impl<T> AbsDiffEq for Yiq<T>
+where
+    T: AbsDiffEq,
+    T::Epsilon: Clone,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    #[rustfmt::skip]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.y.abs_diff_eq(&other.y, epsilon.clone()) &&
+            self.i.abs_diff_eq(&other.i, epsilon.clone()) &&
+            self.q.abs_diff_eq(&other.q, epsilon)
+    }
+}
+
+impl<T> RelativeEq for Yiq<T>
+where
+    T: RelativeEq,
+    T::Epsilon: Clone,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    #[rustfmt::skip]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.y.relative_eq(&other.y, epsilon.clone(), max_relative.clone()) &&
+            self.i.relative_eq(&other.i, epsilon.clone(), max_relative.clone()) &&
+            self.q.relative_eq(&other.q, epsilon, max_relative)
+    }
+}
+
+impl<T> UlpsEq for Yiq<T>
+where
+    T: UlpsEq,
+    T::Epsilon: Clone,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    #[rustfmt::skip]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.y.ulps_eq(&other.y, epsilon.clone(), max_ulps) &&
+            self.i.ulps_eq(&other.i, epsilon.clone(), max_ulps) &&
+            self.q.ulps_eq(&other.q, epsilon, max_ulps)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Zeroable for Yiq<T> where T: bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Pod for Yiq<T> where T: bytemuck::Pod {}
+
+#[cfg(test)]
+mod test {
+    use super::Yiq;
+    use crate::ycbcr::Bt601;
+    use crate::{Clamp, FromColor, IntoColor, LinSrgb, Mix, Srgb, Srgba, Yiqa, Yuv};
+
+    #[test]
+    fn primaries() {
+        let green = Yiq::<f64>::from_color(Srgb::new(0.0, 1.0, 0.0));
+        assert_relative_eq!(green, Yiq::new(0.587, -0.274578, -0.522742), epsilon = 1e-6);
+
+        let blue = Yiq::<f64>::from_color(Srgb::new(0.0, 0.0, 1.0));
+        assert_relative_eq!(blue, Yiq::new(0.114, -0.321342, 0.311189), epsilon = 1e-6);
+
+        let white = Yiq::<f64>::from_color(Srgb::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(white, Yiq::new(1.0, 0.0, 0.0), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn round_trip() {
+        for &(red, green, blue) in &[
+            (0.2f64, 0.4, 0.8),
+            (0.9, 0.1, 0.5),
+            (0.0, 1.0, 0.0),
+            (0.5, 0.5, 0.5),
+        ] {
+            let rgb = Srgb::new(red, green, blue);
+
+            let yiq: Yiq<f64> = rgb.into_color();
+            assert_relative_eq!(Srgb::from_color(yiq), rgb, epsilon = 1e-12);
+
+            let yuv: Yuv<Bt601, f64> = rgb.into_color();
+            assert_relative_eq!(Yuv::from_color(yiq), yuv, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn linear_rgb() {
+        // Linear RGB is encoded as sRGB first.
+        let srgb = Srgb::new(0.2f64, 0.4, 0.8);
+        let yiq: Yiq<f64> = srgb.into_linear().into_color();
+        assert_relative_eq!(yiq, Yiq::from_color(srgb), epsilon = 1e-6);
+        assert_relative_eq!(LinSrgb::from_color(yiq), srgb.into_linear(), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn alpha() {
+        let yiqa: Yiqa<f64> = Srgba::new(0.2, 0.4, 0.8, 0.5).into_color();
+        assert_relative_eq!(yiqa.alpha, 0.5);
+
+        let back: Srgba<f64> = yiqa.into_color();
+        assert_relative_eq!(back, Srgba::new(0.2, 0.4, 0.8, 0.5), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn clamp() {
+        let clamped = Yiq::<f64>::new(1.2, -0.7, 0.3).clamp();
+        assert_relative_eq!(clamped, Yiq::new(1.0, -0.596, 0.3));
+
+        let red = Yiq::<f64>::from_color(Srgb::new(1.0, 0.0, 0.0));
+        assert_relative_eq!(red.clamp(), red);
+    }
+
+    #[test]
+    fn mix() {
+        let a = Yiq::<f64>::new(0.2, -0.4, 0.1);
+        let b = Yiq::new(0.6, 0.2, 0.3);
+        assert_relative_eq!(a.mix(b, 0.5), Yiq::new(0.4, -0.1, 0.2), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn check_min_max_components() {
+        assert_relative_eq!(Yiq::<f64>::min_y(), 0.0);
+        assert_relative_eq!(Yiq::<f64>::max_y(), 1.0);
+        assert_relative_eq!(Yiq::<f64>::min_i(), -0.596);
+        assert_relative_eq!(Yiq::<f64>::max_i(), 0.596);
+        assert_relative_eq!(Yiq::<f64>::min_q(), -0.523);
+        assert_relative_eq!(Yiq::<f64>::max_q(), 0.523);
+    }
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn serialize() {
+        let serialized = ::serde_json::to_string(&Yiq::new(0.3, 0.2, -0.1)).unwrap();
+
+        assert_eq!(serialized, r#"{"y":0.3,"i":0.2,"q":-0.1}"#);
+    }
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn deserialize() {
+        let deserialized: Yiq = ::serde_json::from_str(r#"{"y":0.3,"i":0.2,"q":-0.1}"#).unwrap();
+
+        assert_eq!(deserialized, Yiq::new(0.3, 0.2, -0.1));
+    }
+}
@@ -0,0 +1,517 @@
+use core::marker::PhantomData;
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::convert::FromColorUnclamped;
+use crate::rgb::Rgb;
+use crate::ycbcr::{Bt709, YCbCr, YCbCrStandard};
+use crate::{
+    clamp, clamp_assign, Alpha, Clamp, ClampAssign, Component, FloatComponent, IsWithinBounds, Mix,
+    MixAssign,
+};
+
+/// YPbPr with an alpha component. See the [`YPbPra` implementation in
+/// `Alpha`](crate::Alpha#YPbPra).
+pub type YPbPra<St = Bt709, T = f32> = Alpha<YPbPr<St, T>, T>;
+
+/// The YPbPr color space, as used for analog component video.
+///
+/// YPbPr is the analog counterpart of [`YCbCr`](crate::YCbCr), with a luma
+/// component, `y`, and two color difference components, `pb` and `pr`, which
+/// are the blue and red differences from the luma. It's calculated from RGB
+/// with the luma weights of the video standard `St`, which is usually
+/// [`Bt601`](crate::ycbcr::Bt601) or [`Bt709`](crate::ycbcr::Bt709).
+///
+/// The conversion uses the gamma encoded RGB of the video standard, as with
+/// [`YCbCr`](crate::YCbCr). Other RGB colors, including linear RGB, are
+/// converted to it first.
+/// `y` is between 0.0 and 1.0, and `pb` and `pr` are between -0.5 and 0.5,
+/// which is the same as full range `YCbCr`. The two can be converted to each
+/// other directly.
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::ycbcr::Bt601;
+/// use palette::{FromColor, Srgb, YPbPr};
+///
+/// let red = YPbPr::<Bt601, f64>::from_color(Srgb::new(1.0, 0.0, 0.0));
+/// assert_relative_eq!(red, YPbPr::new(0.299, -0.168736, 0.5), epsilon = 1e-6);
+///
+/// let back = Srgb::from_color(red);
+/// assert_relative_eq!(back, Srgb::new(1.0, 0.0, 0.0), epsilon = 1e-12);
+/// ```
+#[derive(Debug, ArrayCast, WithAlpha)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[palette(palette_internal, component = "T")]
+#[repr(C)]
+pub struct YPbPr<St = Bt709, T = f32> {
+    /// The luma component, from 0.0 to 1.0.
+    pub y: T,
+
+    /// The blue difference component, from -0.5 to 0.5.
+    pub pb: T,
+
+    /// The red difference component, from -0.5 to 0.5.
+    pub pr: T,
+
+    /// The video standard of the color. The default is BT.709.
+    #[cfg_attr(feature = "serializing", serde(skip))]
+    #[palette(unsafe_zero_sized)]
+    pub standard: PhantomData<St>,
+}
+
+impl<St, T> Copy for YPbPr<St, T> where T: Copy {}
+
+impl<St, T> Clone for YPbPr<St, T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> YPbPr<St, T> {
+        YPbPr {
+            y: self.y.clone(),
+            pb: self.pb.clone(),
+            pr: self.pr.clone(),
+            standard: PhantomData,
+        }
+    }
+}
+
+impl<St, T> YPbPr<St, T> {
+    /// Create a YPbPr color.
+    pub const fn new(y: T, pb: T, pr: T) -> YPbPr<St, T> {
+        YPbPr {
+            y,
+            pb,
+            pr,
+            standard: PhantomData,
+        }
+    }
+
+    /// Convert to a `(y, pb, pr)` tuple.
+    pub fn into_components(self) -> (T, T, T) {
+        (self.y, self.pb, self.pr)
+    }
+
+    /// Convert from a `(y, pb, pr)` tuple.
+    pub fn from_components((y, pb, pr): (T, T, T)) -> Self {
+        Self::new(y, pb, pr)
+    }
+}
+
+impl<St, T> YPbPr<St, T>
+where
+    T: FloatComponent,
+{
+    /// Return the `y` value minimum.
+    pub fn min_y() -> T {
+        T::zero()
+    }
+
+    /// Return the `y` value maximum.
+    pub fn max_y() -> T {
+        T::one()
+    }
+
+    /// Return the `pb` value minimum.
+    pub fn min_pb() -> T {
+        T::from_f64(-0.5)
+    }
+
+    /// Return the `pb` value maximum.
+    pub fn max_pb() -> T {
+        T::from_f64(0.5)
+    }
+
+    /// Return the `pr` value minimum.
+    pub fn min_pr() -> T {
+        T::from_f64(-0.5)
+    }
+
+    /// Return the `pr` value maximum.
+    pub fn max_pr() -> T {
+        T::from_f64(0.5)
+    }
+}
+
+impl<St, T> PartialEq for YPbPr<St, T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.y == other.y && self.pb == other.pb && self.pr == other.pr
+    }
+}
+
+impl<St, T> Eq for YPbPr<St, T> where T: Eq {}
+
+///<span id="YPbPra"></span>[`YPbPra`](crate::YPbPra) implementations.
+impl<St, T, A> Alpha<YPbPr<St, T>, A> {
+    /// Create a YPbPr color with transparency.
+    pub const fn new(y: T, pb: T, pr: T, alpha: A) -> Self {
+        Alpha {
+            color: YPbPr::new(y, pb, pr),
+            alpha,
+        }
+    }
+
+    /// Convert to a `(y, pb, pr, alpha)` tuple.
+    pub fn into_components(self) -> (T, T, T, A) {
+        (self.color.y, self.color.pb, self.color.pr, self.alpha)
+    }
+
+    /// Convert from a `(y, pb, pr, alpha)` tuple.
+    pub fn from_components((y, pb, pr, alpha): (T, T, T, A)) -> Self {
+        Self::new(y, pb, pr, alpha)
+    }
+}
+
+impl<St, T> FromColorUnclamped<YPbPr<St, T>> for YPbPr<St, T> {
+    fn from_color_unclamped(color: YPbPr<St, T>) -> Self {
+        color
+    }
+}
+
+impl<St, T> FromColorUnclamped<YCbCr<St, T>> for YPbPr<St, T> {
+    fn from_color_unclamped(color: YCbCr<St, T>) -> Self {
+        YPbPr::new(color.y, color.cb, color.cr)
+    }
+}
+
+impl<St, T> FromColorUnclamped<YPbPr<St, T>> for YCbCr<St, T> {
+    fn from_color_unclamped(color: YPbPr<St, T>) -> Self {
+        YCbCr::new(color.y, color.pb, color.pr)
+    }
+}
+
+impl<S, St, T> FromColorUnclamped<Rgb<S, T>> for YPbPr<St, T>
+where
+    St: YCbCrStandard,
+    T: FloatComponent,
+    Rgb<St::RgbStandard, T>: FromColorUnclamped<Rgb<S, T>>,
+{
+    fn from_color_unclamped(color: Rgb<S, T>) -> Self {
+        trace!(conversion color);
+        let color = Rgb::<St::RgbStandard, T>::from_color_unclamped(color);
+        let [kr, kg, kb]: [T; 3] = St::WEIGHTS.into_coefficients();
+        let two = T::from_f64(2.0);
+
+        let y = kr * color.red + kg * color.green + kb * color.blue;
+        YPbPr::new(
+            y,
+            (color.blue - y) / (two * (T::one() - kb)),
+            (color.red - y) / (two * (T::one() - kr)),
+        )
+    }
+}
+
+impl<S, St, T> FromColorUnclamped<YPbPr<St, T>> for Rgb<S, T>
+where
+    St: YCbCrStandard,
+    T: FloatComponent,
+    Rgb<S, T>: FromColorUnclamped<Rgb<St::RgbStandard, T>>,
+{
+    fn from_color_unclamped(color: YPbPr<St, T>) -> Self {
+        trace!(conversion color);
+        let [kr, kg, kb]: [T; 3] = St::WEIGHTS.into_coefficients();
+        let two = T::from_f64(2.0);
+
+        let red = color.y + two * (T::one() - kr) * color.pr;
+        let blue = color.y + two * (T::one() - kb) * color.pb;
+        let green = (color.y - kr * red - kb * blue) / kg;
+        Self::from_color_unclamped(Rgb::<St::RgbStandard, T>::new(red, green, blue))
+    }
+}
+
+impl<St, T> From<(T, T, T)> for YPbPr<St, T> {
+    fn from(components: (T, T, T)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<St, T> From<YPbPr<St, T>> for (T, T, T) {
+    fn from(color: YPbPr<St, T>) -> (T, T, T) {
+        color.into_components()
+    }
+}
+
+impl<St, T, A> From<(T, T, T, A)> for Alpha<YPbPr<St, T>, A> {
+    fn from(components: (T, T, T, A)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<St, T, A> From<Alpha<YPbPr<St, T>, A>> for (T, T, T, A) {
+    fn from(color: Alpha<YPbPr<St, T>, A>) -> (T, T, T, A) {
+        color.into_components()
+    }
+}
+
+impl_tuple_ref_conversions!([St, T] [] YPbPr<St, T>, (T, T, T) => (T, T, T));
+impl_tuple_ref_conversions!([St, T, A] [] Alpha<YPbPr<St, T>, A>, (T, T, T, A) => (T, T, T, A));
+
+impl<St, T> IsWithinBounds for YPbPr<St, T>
+where
+    T: FloatComponent,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn is_within_bounds(&self) -> bool {
+        self.y >= Self::min_y() && self.y <= Self::max_y() &&
+        self.pb >= Self::min_pb() && self.pb <= Self::max_pb() &&
+        self.pr >= Self::min_pr() && self.pr <= Self::max_pr()
+    }
+}
+
+impl<St, T> Clamp for YPbPr<St, T>
+where
+    T: FloatComponent,
+{
+    #[inline]
+    fn clamp(self) -> Self {
+        Self::new(
+            clamp(self.y, Self::min_y(), Self::max_y()),
+            clamp(self.pb, Self::min_pb(), Self::max_pb()),
+            clamp(self.pr, Self::min_pr(), Self::max_pr()),
+        )
+    }
+}
+
+impl<St, T> ClampAssign for YPbPr<St, T>
+where
+    T: FloatComponent,
+{
+    #[inline]
+    fn clamp_assign(&mut self) {
+        clamp_assign(&mut self.y, Self::min_y(), Self::max_y());
+        clamp_assign(&mut self.pb, Self::min_pb(), Self::max_pb());
+        clamp_assign(&mut self.pr, Self::min_pr(), Self::max_pr());
+    }
+}
+
+impl<St, T> Mix for YPbPr<St, T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn mix(self, other: Self, factor: T) -> Self {
+        let factor = clamp(factor, T::zero(), T::one());
+        YPbPr::new(
+            self.y + (other.y - self.y) * factor,
+            self.pb + (other.pb - self.pb) * factor,
+            self.pr + (other.pr - self.pr) * factor,
+        )
+    }
+}
+
+impl<St, T> MixAssign for YPbPr<St, T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn mix_assign(&mut self, other: Self, factor: T) {
+        *self = self.mix(other, factor);
+    }
+}
+
+impl<St, T> Default for YPbPr<St, T>
+where
+    T: Component,
+{
+    fn default() -> YPbPr<St, T> {
+        YPbPr::new(T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl_array_casts!(YPbPr<St, T>, [T; 3]);
+impl_component_access!(YPbPr<St, T>, [T; 3]);
+
+impl<St, T> AbsDiffEq for YPbPr<St, T>
+where
+    T: AbsDiffEq,
+    T::Epsilon: Clone,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    #[rustfmt::skip]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.y.abs_diff_eq(&other.y, epsilon.clone()) &&
+            self.pb.abs_diff_eq(&other.pb, epsilon.clone()) &&
+            self.pr.abs_diff_eq(&other.pr, epsilon)
+    }
+}
+
+impl<St, T> RelativeEq for YPbPr<St, T>
+where
+    T: RelativeEq,
+    T::Epsilon: Clone,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    #[rustfmt::skip]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.y.relative_eq(&other.y, epsilon.clone(), max_relative.clone()) &&
+            self.pb.relative_eq(&other.pb, epsilon.clone(), max_relative.clone()) &&
+            self.pr.relative_eq(&other.pr, epsilon, max_relative)
+    }
+}
+
+impl<St, T> UlpsEq for YPbPr<St, T>
+where
+    T: UlpsEq,
+    T::Epsilon: Clone,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    #[rustfmt::skip]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.y.ulps_eq(&other.y, epsilon.clone(), max_ulps) &&
+            self.pb.ulps_eq(&other.pb, epsilon.clone(), max_ulps) &&
+            self.pr.ulps_eq(&other.pr, epsilon, max_ulps)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<St, T> bytemuck::Zeroable for YPbPr<St, T> where T: bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<St: 'static, T> bytemuck::Pod for YPbPr<St, T> where T: bytemuck::Pod {}
+
+#[cfg(test)]
+mod test {
+    use super::YPbPr;
+    use crate::encoding::{Rec2020, Rec709};
+    use crate::rgb::Rgb;
+    use crate::ycbcr::{Bt2020, Bt601, Bt709};
+    use crate::{Clamp, FromColor, IntoColor, LinSrgb, Mix, Srgb, Srgba, YCbCr, YPbPra};
+
+    #[test]
+    fn primaries() {
+        let red = YPbPr::<Bt709, f64>::from_color(Rgb::<Rec709, f64>::new(1.0, 0.0, 0.0));
+        assert_relative_eq!(red, YPbPr::new(0.2126, -0.114572, 0.5), epsilon = 1e-6);
+
+        let blue = YPbPr::<Bt2020, f64>::from_color(Rgb::<Rec2020, f64>::new(0.0, 0.0, 1.0));
+        assert_relative_eq!(blue, YPbPr::new(0.0593, 0.5, -0.040214), epsilon = 1e-6);
+
+        let white = YPbPr::<Bt601, f64>::from_color(Srgb::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(white, YPbPr::new(1.0, 0.0, 0.0), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn round_trip() {
+        for &(red, green, blue) in &[
+            (0.2f64, 0.4, 0.8),
+            (0.9, 0.1, 0.5),
+            (0.0, 1.0, 0.0),
+            (0.5, 0.5, 0.5),
+        ] {
+            let rgb = Srgb::new(red, green, blue);
+            let ypbpr: YPbPr<Bt601, f64> = rgb.into_color();
+            assert_relative_eq!(Srgb::from_color(ypbpr), rgb, epsilon = 1e-12);
+
+            let rgb = Rgb::<Rec709, f64>::new(red, green, blue);
+            let ypbpr: YPbPr<Bt709, f64> = rgb.into_color();
+            assert_relative_eq!(Rgb::from_color(ypbpr), rgb, epsilon = 1e-12);
+
+            let rgb = Rgb::<Rec2020, f64>::new(red, green, blue);
+            let ypbpr: YPbPr<Bt2020, f64> = rgb.into_color();
+            assert_relative_eq!(Rgb::from_color(ypbpr), rgb, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn other_rgb_standards() {
+        // Other RGB colors are converted to the standard's RGB first.
+        let srgb = Srgb::new(0.2f64, 0.4, 0.8);
+        let ypbpr: YPbPr<Bt601, f64> = srgb.into_linear().into_color();
+        assert_relative_eq!(ypbpr, YPbPr::from_color(srgb), epsilon = 1e-6);
+        assert_relative_eq!(
+            LinSrgb::from_color(ypbpr),
+            srgb.into_linear(),
+            epsilon = 1e-6
+        );
+
+        let ypbpr: YPbPr<Bt709, f64> = srgb.into_color();
+        let rec709 = Rgb::<Rec709, f64>::from_color(srgb);
+        assert_relative_eq!(ypbpr, YPbPr::from_color(rec709), epsilon = 1e-6);
+        assert_relative_eq!(Srgb::from_color(ypbpr), srgb, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn ycbcr() {
+        let rgb = Srgb::new(0.2f64, 0.4, 0.8);
+        let ypbpr: YPbPr<Bt709, f64> = rgb.into_color();
+        let ycbcr: YCbCr<Bt709, f64> = rgb.into_color();
+
+        assert_relative_eq!(YPbPr::from_color(ycbcr), ypbpr);
+        assert_relative_eq!(YCbCr::from_color(ypbpr), ycbcr);
+    }
+
+    #[test]
+    fn alpha() {
+        let ypbpra: YPbPra<Bt709, f64> = Srgba::new(0.2, 0.4, 0.8, 0.5).into_color();
+        assert_relative_eq!(ypbpra.alpha, 0.5);
+
+        let back: Srgba<f64> = ypbpra.into_color();
+        assert_relative_eq!(back, Srgba::new(0.2, 0.4, 0.8, 0.5), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn clamp() {
+        let clamped = YPbPr::<Bt709, f64>::new(1.2, -0.7, 0.3).clamp();
+        assert_relative_eq!(clamped, YPbPr::new(1.0, -0.5, 0.3));
+    }
+
+    #[test]
+    fn mix() {
+        let a = YPbPr::<Bt709, f64>::new(0.2, -0.4, 0.1);
+        let b = YPbPr::new(0.6, 0.2, 0.3);
+        assert_relative_eq!(a.mix(b, 0.5), YPbPr::new(0.4, -0.1, 0.2), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn check_min_max_components() {
+        assert_relative_eq!(YPbPr::<Bt709>::min_y(), 0.0);
+        assert_relative_eq!(YPbPr::<Bt709>::max_y(), 1.0);
+        assert_relative_eq!(YPbPr::<Bt709>::min_pb(), -0.5);
+        assert_relative_eq!(YPbPr::<Bt709>::max_pb(), 0.5);
+        assert_relative_eq!(YPbPr::<Bt709>::min_pr(), -0.5);
+        assert_relative_eq!(YPbPr::<Bt709>::max_pr(), 0.5);
+    }
+
+    raw_pixel_conversion_tests!(YPbPr<Bt709>: y, pb, pr);
+    raw_pixel_conversion_fail_tests!(YPbPr<Bt709>: y, pb, pr);
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn serialize() {
+        let serialized = ::serde_json::to_string(&YPbPr::<Bt709>::new(0.3, 0.2, -0.1)).unwrap();
+
+        assert_eq!(serialized, r#"{"y":0.3,"pb":0.2,"pr":-0.1}"#);
+    }
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn deserialize() {
+        let deserialized: YPbPr =
+            ::serde_json::from_str(r#"{"y":0.3,"pb":0.2,"pr":-0.1}"#).unwrap();
+
+        assert_eq!(deserialized, YPbPr::new(0.3, 0.2, -0.1));
+    }
+}
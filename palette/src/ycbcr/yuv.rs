@@ -0,0 +1,533 @@
+use core::marker::PhantomData;
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::convert::FromColorUnclamped;
+use crate::rgb::Rgb;
+use crate::ycbcr::{Bt709, YCbCrStandard, YPbPr};
+use crate::{
+    clamp, clamp_assign, Alpha, Clamp, ClampAssign, Component, FloatComponent, IsWithinBounds, Mix,
+    MixAssign,
+};
+
+/// The largest magnitude of `u`.
+const U_MAX: f64 = 0.436;
+
+/// The largest magnitude of `v`.
+const V_MAX: f64 = 0.615;
+
+/// YUV with an alpha component. See the [`Yuva` implementation in
+/// `Alpha`](crate::Alpha#Yuva).
+pub type Yuva<St = Bt709, T = f32> = Alpha<Yuv<St, T>, T>;
+
+/// The YUV color space, as used for analog composite video.
+///
+/// YUV is a scaled version of [`YPbPr`](crate::YPbPr), where the color
+/// difference components, `u` and `v`, are scaled to fit the composite video
+/// signal. `u` is between -0.436 and 0.436 and `v` is between -0.615 and
+/// 0.615, while the luma component, `y`, is between 0.0 and 1.0. It's
+/// calculated from RGB with the luma weights of the video standard `St`,
+/// which is usually [`Bt601`](crate::ycbcr::Bt601) or
+/// [`Bt709`](crate::ycbcr::Bt709).
+///
+/// The conversion uses the gamma encoded RGB of the video standard, as with
+/// [`YCbCr`](crate::YCbCr). Other RGB colors, including linear RGB, are
+/// converted to it first.
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use palette::ycbcr::Bt601;
+/// use palette::{FromColor, Srgb, Yuv};
+///
+/// let red = Yuv::<Bt601, f64>::from_color(Srgb::new(1.0, 0.0, 0.0));
+/// assert_relative_eq!(red, Yuv::new(0.299, -0.147138, 0.615), epsilon = 1e-6);
+///
+/// let back = Srgb::from_color(red);
+/// assert_relative_eq!(back, Srgb::new(1.0, 0.0, 0.0), epsilon = 1e-12);
+/// ```
+#[derive(Debug, ArrayCast, WithAlpha)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[palette(palette_internal, component = "T")]
+#[repr(C)]
+pub struct Yuv<St = Bt709, T = f32> {
+    /// The luma component, from 0.0 to 1.0.
+    pub y: T,
+
+    /// The scaled blue difference component, from -0.436 to 0.436.
+    pub u: T,
+
+    /// The scaled red difference component, from -0.615 to 0.615.
+    pub v: T,
+
+    /// The video standard of the color. The default is BT.709.
+    #[cfg_attr(feature = "serializing", serde(skip))]
+    #[palette(unsafe_zero_sized)]
+    pub standard: PhantomData<St>,
+}
+
+impl<St, T> Copy for Yuv<St, T> where T: Copy {}
+
+impl<St, T> Clone for Yuv<St, T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Yuv<St, T> {
+        Yuv {
+            y: self.y.clone(),
+            u: self.u.clone(),
+            v: self.v.clone(),
+            standard: PhantomData,
+        }
+    }
+}
+
+impl<St, T> Yuv<St, T> {
+    /// Create a YUV color.
+    pub const fn new(y: T, u: T, v: T) -> Yuv<St, T> {
+        Yuv {
+            y,
+            u,
+            v,
+            standard: PhantomData,
+        }
+    }
+
+    /// Convert to a `(y, u, v)` tuple.
+    pub fn into_components(self) -> (T, T, T) {
+        (self.y, self.u, self.v)
+    }
+
+    /// Convert from a `(y, u, v)` tuple.
+    pub fn from_components((y, u, v): (T, T, T)) -> Self {
+        Self::new(y, u, v)
+    }
+}
+
+impl<St, T> Yuv<St, T>
+where
+    T: FloatComponent,
+{
+    /// Return the `y` value minimum.
+    pub fn min_y() -> T {
+        T::zero()
+    }
+
+    /// Return the `y` value maximum.
+    pub fn max_y() -> T {
+        T::one()
+    }
+
+    /// Return the `u` value minimum.
+    pub fn min_u() -> T {
+        T::from_f64(-U_MAX)
+    }
+
+    /// Return the `u` value maximum.
+    pub fn max_u() -> T {
+        T::from_f64(U_MAX)
+    }
+
+    /// Return the `v` value minimum.
+    pub fn min_v() -> T {
+        T::from_f64(-V_MAX)
+    }
+
+    /// Return the `v` value maximum.
+    pub fn max_v() -> T {
+        T::from_f64(V_MAX)
+    }
+}
+
+impl<St, T> PartialEq for Yuv<St, T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.y == other.y && self.u == other.u && self.v == other.v
+    }
+}
+
+impl<St, T> Eq for Yuv<St, T> where T: Eq {}
+
+///<span id="Yuva"></span>[`Yuva`](crate::Yuva) implementations.
+impl<St, T, A> Alpha<Yuv<St, T>, A> {
+    /// Create a YUV color with transparency.
+    pub const fn new(y: T, u: T, v: T, alpha: A) -> Self {
+        Alpha {
+            color: Yuv::new(y, u, v),
+            alpha,
+        }
+    }
+
+    /// Convert to a `(y, u, v, alpha)` tuple.
+    pub fn into_components(self) -> (T, T, T, A) {
+        (self.color.y, self.color.u, self.color.v, self.alpha)
+    }
+
+    /// Convert from a `(y, u, v, alpha)` tuple.
+    pub fn from_components((y, u, v, alpha): (T, T, T, A)) -> Self {
+        Self::new(y, u, v, alpha)
+    }
+}
+
+impl<St, T> FromColorUnclamped<Yuv<St, T>> for Yuv<St, T> {
+    fn from_color_unclamped(color: Yuv<St, T>) -> Self {
+        color
+    }
+}
+
+impl<St, T> FromColorUnclamped<YPbPr<St, T>> for Yuv<St, T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: YPbPr<St, T>) -> Self {
+        let two = T::from_f64(2.0);
+
+        Yuv::new(
+            color.y,
+            two * T::from_f64(U_MAX) * color.pb,
+            two * T::from_f64(V_MAX) * color.pr,
+        )
+    }
+}
+
+impl<St, T> FromColorUnclamped<Yuv<St, T>> for YPbPr<St, T>
+where
+    T: FloatComponent,
+{
+    fn from_color_unclamped(color: Yuv<St, T>) -> Self {
+        let two = T::from_f64(2.0);
+
+        YPbPr::new(
+            color.y,
+            color.u / (two * T::from_f64(U_MAX)),
+            color.v / (two * T::from_f64(V_MAX)),
+        )
+    }
+}
+
+impl<S, St, T> FromColorUnclamped<Rgb<S, T>> for Yuv<St, T>
+where
+    St: YCbCrStandard,
+    T: FloatComponent,
+    Rgb<St::RgbStandard, T>: FromColorUnclamped<Rgb<S, T>>,
+{
+    fn from_color_unclamped(color: Rgb<S, T>) -> Self {
+        trace!(conversion color);
+        let color = Rgb::<St::RgbStandard, T>::from_color_unclamped(color);
+        let [kr, kg, kb]: [T; 3] = St::WEIGHTS.into_coefficients();
+
+        let y = kr * color.red + kg * color.green + kb * color.blue;
+        Yuv::new(
+            y,
+            T::from_f64(U_MAX) * (color.blue - y) / (T::one() - kb),
+            T::from_f64(V_MAX) * (color.red - y) / (T::one() - kr),
+        )
+    }
+}
+
+impl<S, St, T> FromColorUnclamped<Yuv<St, T>> for Rgb<S, T>
+where
+    St: YCbCrStandard,
+    T: FloatComponent,
+    Rgb<S, T>: FromColorUnclamped<Rgb<St::RgbStandard, T>>,
+{
+    fn from_color_unclamped(color: Yuv<St, T>) -> Self {
+        trace!(conversion color);
+        let [kr, kg, kb]: [T; 3] = St::WEIGHTS.into_coefficients();
+
+        let red = color.y + (T::one() - kr) * color.v / T::from_f64(V_MAX);
+        let blue = color.y + (T::one() - kb) * color.u / T::from_f64(U_MAX);
+        let green = (color.y - kr * red - kb * blue) / kg;
+        Self::from_color_unclamped(Rgb::<St::RgbStandard, T>::new(red, green, blue))
+    }
+}
+
+impl<St, T> From<(T, T, T)> for Yuv<St, T> {
+    fn from(components: (T, T, T)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<St, T> From<Yuv<St, T>> for (T, T, T) {
+    fn from(color: Yuv<St, T>) -> (T, T, T) {
+        color.into_components()
+    }
+}
+
+impl<St, T, A> From<(T, T, T, A)> for Alpha<Yuv<St, T>, A> {
+    fn from(components: (T, T, T, A)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<St, T, A> From<Alpha<Yuv<St, T>, A>> for (T, T, T, A) {
+    fn from(color: Alpha<Yuv<St, T>, A>) -> (T, T, T, A) {
+        color.into_components()
+    }
+}
+
+impl_tuple_ref_conversions!([St, T] [] Yuv<St, T>, (T, T, T) => (T, T, T));
+impl_tuple_ref_conversions!([St, T, A] [] Alpha<Yuv<St, T>, A>, (T, T, T, A) => (T, T, T, A));
+
+impl<St, T> IsWithinBounds for Yuv<St, T>
+where
+    T: FloatComponent,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn is_within_bounds(&self) -> bool {
+        self.y >= Self::min_y() && self.y <= Self::max_y() &&
+        self.u >= Self::min_u() && self.u <= Self::max_u() &&
+        self.v >= Self::min_v() && self.v <= Self::max_v()
+    }
+}
+
+impl<St, T> Clamp for Yuv<St, T>
+where
+    T: FloatComponent,
+{
+    #[inline]
+    fn clamp(self) -> Self {
+        Self::new(
+            clamp(self.y, Self::min_y(), Self::max_y()),
+            clamp(self.u, Self::min_u(), Self::max_u()),
+            clamp(self.v, Self::min_v(), Self::max_v()),
+        )
+    }
+}
+
+impl<St, T> ClampAssign for Yuv<St, T>
+where
+    T: FloatComponent,
+{
+    #[inline]
+    fn clamp_assign(&mut self) {
+        clamp_assign(&mut self.y, Self::min_y(), Self::max_y());
+        clamp_assign(&mut self.u, Self::min_u(), Self::max_u());
+        clamp_assign(&mut self.v, Self::min_v(), Self::max_v());
+    }
+}
+
+impl<St, T> Mix for Yuv<St, T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn mix(self, other: Self, factor: T) -> Self {
+        let factor = clamp(factor, T::zero(), T::one());
+        Yuv::new(
+            self.y + (other.y - self.y) * factor,
+            self.u + (other.u - self.u) * factor,
+            self.v + (other.v - self.v) * factor,
+        )
+    }
+}
+
+impl<St, T> MixAssign for Yuv<St, T>
+where
+    T: FloatComponent,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn mix_assign(&mut self, other: Self, factor: T) {
+        *self = self.mix(other, factor);
+    }
+}
+
+impl<St, T> Default for Yuv<St, T>
+where
+    T: Component,
+{
+    fn default() -> Yuv<St, T> {
+        Yuv::new(T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl_array_casts!(Yuv<St, T>, [T; 3]);
+impl_component_access!(Yuv<St, T>, [T; 3]);
+
+impl<St, T> AbsDiffEq for Yuv<St, T>
+where
+    T: AbsDiffEq,
+    T::Epsilon: Clone,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    #[rustfmt::skip]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.y.abs_diff_eq(&other.y, epsilon.clone()) &&
+            self.u.abs_diff_eq(&other.u, epsilon.clone()) &&
+            self.v.abs_diff_eq(&other.v, epsilon)
+    }
+}
+
+impl<St, T> RelativeEq for Yuv<St, T>
+where
+    T: RelativeEq,
+    T::Epsilon: Clone,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    #[rustfmt::skip]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.y.relative_eq(&other.y, epsilon.clone(), max_relative.clone()) &&
+            self.u.relative_eq(&other.u, epsilon.clone(), max_relative.clone()) &&
+            self.v.relative_eq(&other.v, epsilon, max_relative)
+    }
+}
+
+impl<St, T> UlpsEq for Yuv<St, T>
+where
+    T: UlpsEq,
+    T::Epsilon: Clone,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    #[rustfmt::skip]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.y.ulps_eq(&other.y, epsilon.clone(), max_ulps) &&
+            self.u.ulps_eq(&other.u, epsilon.clone(), max_ulps) &&
+            self.v.ulps_eq(&other.v, epsilon, max_ulps)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<St, T> bytemuck::Zeroable for Yuv<St, T> where T: bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<St: 'static, T> bytemuck::Pod for Yuv<St, T> where T: bytemuck::Pod {}
+
+#[cfg(test)]
+mod test {
+    use super::Yuv;
+    use crate::encoding::{Rec2020, Rec709};
+    use crate::rgb::Rgb;
+    use crate::ycbcr::{Bt2020, Bt601, Bt709};
+    use crate::{Clamp, FromColor, IntoColor, LinSrgb, Mix, Srgb, Srgba, YPbPr, Yuva};
+
+    #[test]
+    fn primaries() {
+        let red = Yuv::<Bt709, f64>::from_color(Rgb::<Rec709, f64>::new(1.0, 0.0, 0.0));
+        assert_relative_eq!(red, Yuv::new(0.2126, -0.099907, 0.615), epsilon = 1e-6);
+
+        let blue = Yuv::<Bt2020, f64>::from_color(Rgb::<Rec2020, f64>::new(0.0, 0.0, 1.0));
+        assert_relative_eq!(blue, Yuv::new(0.0593, 0.436, -0.049464), epsilon = 1e-6);
+
+        let white = Yuv::<Bt601, f64>::from_color(Srgb::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(white, Yuv::new(1.0, 0.0, 0.0), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn round_trip() {
+        for &(red, green, blue) in &[
+            (0.2f64, 0.4, 0.8),
+            (0.9, 0.1, 0.5),
+            (0.0, 1.0, 0.0),
+            (0.5, 0.5, 0.5),
+        ] {
+            let rgb = Srgb::new(red, green, blue);
+            let yuv: Yuv<Bt601, f64> = rgb.into_color();
+            assert_relative_eq!(Srgb::from_color(yuv), rgb, epsilon = 1e-12);
+
+            let rgb = Rgb::<Rec709, f64>::new(red, green, blue);
+            let yuv: Yuv<Bt709, f64> = rgb.into_color();
+            assert_relative_eq!(Rgb::from_color(yuv), rgb, epsilon = 1e-12);
+
+            let rgb = Rgb::<Rec2020, f64>::new(red, green, blue);
+            let yuv: Yuv<Bt2020, f64> = rgb.into_color();
+            assert_relative_eq!(Rgb::from_color(yuv), rgb, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn other_rgb_standards() {
+        // Other RGB colors are converted to the standard's RGB first.
+        let srgb = Srgb::new(0.2f64, 0.4, 0.8);
+        let yuv: Yuv<Bt601, f64> = srgb.into_linear().into_color();
+        assert_relative_eq!(yuv, Yuv::from_color(srgb), epsilon = 1e-6);
+        assert_relative_eq!(LinSrgb::from_color(yuv), srgb.into_linear(), epsilon = 1e-6);
+
+        let yuv: Yuv<Bt709, f64> = srgb.into_color();
+        let rec709 = Rgb::<Rec709, f64>::from_color(srgb);
+        assert_relative_eq!(yuv, Yuv::from_color(rec709), epsilon = 1e-6);
+        assert_relative_eq!(Srgb::from_color(yuv), srgb, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn ypbpr() {
+        let rgb = Srgb::new(0.2f64, 0.4, 0.8);
+        let yuv: Yuv<Bt601, f64> = rgb.into_color();
+        let ypbpr: YPbPr<Bt601, f64> = rgb.into_color();
+
+        assert_relative_eq!(Yuv::from_color(ypbpr), yuv, epsilon = 1e-12);
+        assert_relative_eq!(YPbPr::from_color(yuv), ypbpr, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn alpha() {
+        let yuva: Yuva<Bt709, f64> = Srgba::new(0.2, 0.4, 0.8, 0.5).into_color();
+        assert_relative_eq!(yuva.alpha, 0.5);
+
+        let back: Srgba<f64> = yuva.into_color();
+        assert_relative_eq!(back, Srgba::new(0.2, 0.4, 0.8, 0.5), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn clamp() {
+        let clamped = Yuv::<Bt709, f64>::new(1.2, -0.7, 0.7).clamp();
+        assert_relative_eq!(clamped, Yuv::new(1.0, -0.436, 0.615));
+    }
+
+    #[test]
+    fn mix() {
+        let a = Yuv::<Bt709, f64>::new(0.2, -0.4, 0.1);
+        let b = Yuv::new(0.6, 0.2, 0.3);
+        assert_relative_eq!(a.mix(b, 0.5), Yuv::new(0.4, -0.1, 0.2), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn check_min_max_components() {
+        assert_relative_eq!(Yuv::<Bt709>::min_y(), 0.0);
+        assert_relative_eq!(Yuv::<Bt709>::max_y(), 1.0);
+        assert_relative_eq!(Yuv::<Bt709>::min_u(), -0.436);
+        assert_relative_eq!(Yuv::<Bt709>::max_u(), 0.436);
+        assert_relative_eq!(Yuv::<Bt709>::min_v(), -0.615);
+        assert_relative_eq!(Yuv::<Bt709>::max_v(), 0.615);
+    }
+
+    raw_pixel_conversion_tests!(Yuv<Bt709>: y, u, v);
+    raw_pixel_conversion_fail_tests!(Yuv<Bt709>: y, u, v);
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn serialize() {
+        let serialized = ::serde_json::to_string(&Yuv::<Bt709>::new(0.3, 0.2, -0.1)).unwrap();
+
+        assert_eq!(serialized, r#"{"y":0.3,"u":0.2,"v":-0.1}"#);
+    }
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn deserialize() {
+        let deserialized: Yuv = ::serde_json::from_str(r#"{"y":0.3,"u":0.2,"v":-0.1}"#).unwrap();
+
+        assert_eq!(deserialized, Yuv::new(0.3, 0.2, -0.1));
+    }
+}
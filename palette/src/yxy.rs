@@ -205,6 +205,9 @@ impl<Wp, T, A> From<Alpha<Yxy<Wp, T>, A>> for (T, T, T, A) {
     }
 }
 
+impl_tuple_ref_conversions!([Wp, T] [] Yxy<Wp, T>, (T, T, T) => (T, T, T));
+impl_tuple_ref_conversions!([Wp, T, A] [] Alpha<Yxy<Wp, T>, A>, (T, T, T, A) => (T, T, T, A));
+
 impl<Wp, T> FromColorUnclamped<Yxy<Wp, T>> for Yxy<Wp, T> {
     fn from_color_unclamped(color: Yxy<Wp, T>) -> Self {
         color
@@ -216,6 +219,7 @@ where
     T: FloatComponent,
 {
     fn from_color_unclamped(xyz: Xyz<Wp, T>) -> Self {
+        trace!(conversion xyz);
         let mut yxy = Yxy {
             x: T::zero(),
             y: T::zero(),
@@ -238,6 +242,7 @@ where
     S: LumaStandard<T>,
 {
     fn from_color_unclamped(luma: Luma<S, T>) -> Self {
+        trace!(conversion luma);
         Yxy {
             luma: luma.into_linear().luma,
             ..Default::default()
@@ -418,6 +423,7 @@ impl_color_mul!(Yxy<Wp, T>, [x, y, luma], white_point);
 impl_color_div!(Yxy<Wp, T>, [x, y, luma], white_point);
 
 impl_array_casts!(Yxy<Wp, T>, [T; 3]);
+impl_component_access!(Yxy<Wp, T>, [T; 3]);
 
 impl<Wp, T> RelativeContrast for Yxy<Wp, T>
 where
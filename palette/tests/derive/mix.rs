@@ -0,0 +1,75 @@
+use approx::assert_relative_eq;
+
+use palette::{FloatComponent, FromColor, LinSrgb, Mix, Oklch, Srgb};
+
+#[derive(Mix)]
+struct DefaultComponent {
+    color: LinSrgb,
+    weight: f32,
+}
+
+#[derive(Mix)]
+#[palette(component = "T")]
+struct Tuple<T: FloatComponent>(LinSrgb<T>, T, #[palette(mix_skip)] u8);
+
+#[derive(Mix)]
+#[palette(component = "T")]
+struct MixIn<T: FloatComponent> {
+    #[palette(mix_in = "Oklch<T>")]
+    color: Srgb<T>,
+}
+
+#[test]
+fn default_component() {
+    let a = DefaultComponent {
+        color: LinSrgb::new(0.0, 0.5, 1.0),
+        weight: 0.0,
+    };
+    let b = DefaultComponent {
+        color: LinSrgb::new(1.0, 0.5, 0.0),
+        weight: 2.0,
+    };
+
+    let mixed = a.mix(b, 0.25f32);
+    assert_relative_eq!(mixed.color, LinSrgb::new(0.25, 0.5, 0.75));
+    assert_relative_eq!(mixed.weight, 0.5);
+}
+
+#[test]
+fn tuple_struct() {
+    let a = Tuple(LinSrgb::new(0.0f64, 0.0, 0.0), 1.0, 1);
+    let b = Tuple(LinSrgb::new(1.0, 1.0, 1.0), 3.0, 2);
+
+    let mixed = a.mix(b, 0.5);
+    assert_relative_eq!(mixed.0, LinSrgb::new(0.5, 0.5, 0.5));
+    assert_relative_eq!(mixed.1, 2.0);
+    assert_eq!(mixed.2, 1);
+}
+
+#[test]
+fn clamped_factor() {
+    let a = || Tuple(LinSrgb::new(0.0f64, 0.0, 0.0), 1.0, 1);
+    let b = || Tuple(LinSrgb::new(1.0, 1.0, 1.0), 3.0, 2);
+
+    let below = a().mix(b(), -1.0);
+    assert_relative_eq!(below.0, LinSrgb::new(0.0, 0.0, 0.0));
+    assert_relative_eq!(below.1, 1.0);
+
+    let above = a().mix(b(), 2.0);
+    assert_relative_eq!(above.0, LinSrgb::new(1.0, 1.0, 1.0));
+    assert_relative_eq!(above.1, 3.0);
+}
+
+#[test]
+fn mix_in() {
+    let a = MixIn {
+        color: Srgb::new(1.0f64, 0.0, 0.0),
+    };
+    let b = MixIn {
+        color: Srgb::new(0.0, 0.0, 1.0),
+    };
+
+    let expected =
+        Srgb::from_color(Oklch::from_color(a.color).mix(Oklch::from_color(b.color), 0.5));
+    assert_relative_eq!(a.mix(b, 0.5).color, expected, epsilon = 1e-10);
+}
@@ -115,12 +115,12 @@ fn prepare_from_impl(
 
         let nearest_color_path = util::color_path(nearest_color_name, meta.internal);
         let target_color_rgb_standard = match color_name {
-            "Rgb" | "Hsl" | "Hsv" | "Hwb" => Some(parse_quote!(_S)),
+            "Rgb" | "Hsl" | "Hsv" | "Hwb" | "Cmyk" => Some(parse_quote!(_S)),
             _ => None,
         };
 
         let nearest_color_ty: Type = match nearest_color_name {
-            "Rgb" | "Hsl" | "Hsv" | "Hwb" => {
+            "Rgb" | "Hsl" | "Hsv" | "Hwb" | "Cmyk" => {
                 let rgb_standard = meta.rgb_standard
                     .clone()
                     .or(target_color_rgb_standard)
@@ -145,7 +145,7 @@ fn prepare_from_impl(
                     parse_quote!(#nearest_color_path::<#linear_path<#white_point>, #component>)
                 }
             }
-            "Oklab" | "Oklch" => {
+            "Oklab" | "Oklch" | "Oklrab" | "Oklrch" | "Hct" => {
                 parse_quote!(#nearest_color_path::<#component>)
             }
             _ => {
@@ -81,7 +81,7 @@ pub fn get_convert_color_type(
                 )
             }
         }
-        "Rgb" | "Hsl" | "Hsv" | "Hwb" => {
+        "Rgb" | "Hsl" | "Hsv" | "Hwb" | "Cmyk" => {
             let rgb_standard_path = util::path(&["rgb", "RgbStandard"], internal);
             let rgb_space_path = util::path(&["rgb", "RgbSpace"], internal);
 
@@ -109,7 +109,9 @@ pub fn get_convert_color_type(
                 )
             }
         }
-        "Oklab" | "Oklch" => (parse_quote!(#color_path<#component>), UsedInput::default()),
+        "Oklab" | "Oklch" | "Oklrab" | "Oklrch" | "Hct" => {
+            (parse_quote!(#color_path<#component>), UsedInput::default())
+        }
         _ => (
             parse_quote!(#color_path<#white_point, #component>),
             UsedInput { white_point: true },
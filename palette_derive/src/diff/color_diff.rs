@@ -0,0 +1,120 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+
+use quote::quote;
+use syn::{parse_quote, DeriveInput, LitStr, Type};
+
+use crate::meta::{self, IdentOrIndex, TypeItemAttributes};
+use crate::util;
+
+pub fn derive(tokens: TokenStream) -> std::result::Result<TokenStream, Vec<syn::Error>> {
+    let DeriveInput {
+        ident,
+        attrs,
+        mut generics,
+        data,
+        ..
+    } = syn::parse(tokens).map_err(|error| vec![error])?;
+
+    let item_meta: TypeItemAttributes = meta::parse_namespaced_attributes(attrs)?;
+
+    let (all_fields, fields_meta) = util::struct_fields(data, "ColorDiff")?;
+
+    let component: Type = item_meta
+        .component
+        .clone()
+        .unwrap_or_else(|| parse_quote!(f32));
+    let white_point: Type = item_meta
+        .white_point
+        .clone()
+        .unwrap_or_else(|| util::path_type(&["white_point", "D65"], item_meta.internal));
+
+    let color_diff_path = util::path(["diff", "ColorDiff"], item_meta.internal);
+    let field_path_path = util::path(["diff", "FieldPath"], item_meta.internal);
+    let delta_e_path = util::path(["DeltaE"], item_meta.internal);
+    let delta_e_formula_path = util::path(["DeltaEFormula"], item_meta.internal);
+    let into_color_path = util::path(["convert", "IntoColor"], item_meta.internal);
+    let lab_path = util::path(["Lab"], item_meta.internal);
+    let lab_type: Type = parse_quote!(#lab_path<#white_point, #component>);
+
+    let mut has_colors = false;
+    let mut predicates: Vec<syn::WherePredicate> = vec![parse_quote!(#component: Copy)];
+    let mut comparisons = Vec::new();
+
+    for (index, field) in all_fields.into_iter().enumerate() {
+        let name = field
+            .ident
+            .map(IdentOrIndex::Ident)
+            .unwrap_or_else(|| IdentOrIndex::Index(index.into()));
+        let ty = field.ty;
+
+        if fields_meta.diff_skipped.contains(&name) {
+            continue;
+        }
+
+        let name_string = match name {
+            IdentOrIndex::Ident(ref ident) => {
+                let ident = ident.to_string();
+                ident.trim_start_matches("r#").to_owned()
+            }
+            IdentOrIndex::Index(ref index) => index.index.to_string(),
+        };
+        let name_literal = LitStr::new(&name_string, Span::call_site());
+
+        let comparison = if fields_meta.diff_nested.contains(&name) {
+            predicates.push(parse_quote!(#ty: #color_diff_path<Scalar = #component>));
+
+            quote! {
+                let path = #field_path_path::child(parent, #name_literal);
+                #color_diff_path::visit_differences(
+                    &self.#name,
+                    &other.#name,
+                    formula,
+                    Some(&path),
+                    visitor,
+                );
+            }
+        } else {
+            has_colors = true;
+            predicates.push(parse_quote!(#ty: Clone + #into_color_path<#lab_type>));
+
+            quote! {
+                let path = #field_path_path::child(parent, #name_literal);
+                let reference: #lab_type = #into_color_path::into_color(Clone::clone(&self.#name));
+                let color: #lab_type = #into_color_path::into_color(Clone::clone(&other.#name));
+                visitor(&path, #delta_e_path::delta_e(reference, color, formula));
+            }
+        };
+
+        comparisons.push(comparison);
+    }
+
+    if has_colors {
+        predicates.push(parse_quote!(#lab_type: #delta_e_path<Scalar = #component>));
+    }
+
+    generics.make_where_clause().predicates.extend(predicates);
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    let implementation = quote! {
+        #[automatically_derived]
+        impl #impl_generics #color_diff_path for #ident #type_generics #where_clause {
+            type Scalar = #component;
+
+            #[allow(unused_variables)]
+            fn visit_differences(
+                &self,
+                other: &Self,
+                formula: #delta_e_formula_path<#component>,
+                parent: Option<&#field_path_path<'_>>,
+                visitor: &mut dyn FnMut(&#field_path_path<'_>, #component),
+            ) {
+                #({
+                    #comparisons
+                })*
+            }
+        }
+    };
+
+    Ok(implementation.into())
+}
@@ -0,0 +1,3 @@
+pub use self::color_diff::derive as derive_color_diff;
+
+mod color_diff;
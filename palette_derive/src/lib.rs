@@ -34,12 +34,14 @@ macro_rules! syn_try {
 mod alpha;
 mod cast;
 mod convert;
+mod diff;
 mod meta;
+mod mix;
 mod util;
 
 const COLOR_TYPES: &[&str] = &[
-    "Rgb", "Luma", "Hsl", "Hsluv", "Hsv", "Hwb", "Lab", "Lch", "Lchuv", "Luv", "Oklab", "Oklch",
-    "Xyz", "Yxy",
+    "Rgb", "Luma", "Hsl", "Hsluv", "Hsv", "Hwb", "Cmyk", "Lab", "Lch", "Lchuv", "Luv", "Oklab",
+    "Oklch", "Oklrab", "Oklrch", "Hct", "Xyz", "Yxy",
 ];
 
 const PREFERRED_CONVERSION_SOURCE: &[(&str, &str)] = &[
@@ -49,12 +51,16 @@ const PREFERRED_CONVERSION_SOURCE: &[(&str, &str)] = &[
     ("Hsluv", "Lchuv"),
     ("Hsv", "Rgb"),
     ("Hwb", "Hsv"),
+    ("Cmyk", "Rgb"),
     ("Lab", "Xyz"),
     ("Lch", "Lab"),
     ("Lchuv", "Luv"),
     ("Luv", "Xyz"),
     ("Oklab", "Xyz"),
     ("Oklch", "Oklab"),
+    ("Oklrab", "Oklab"),
+    ("Oklrch", "Oklrab"),
+    ("Hct", "Xyz"),
     ("Yxy", "Xyz"),
 ];
 
@@ -72,3 +78,13 @@ pub fn derive_from_color_unclamped(tokens: TokenStream) -> TokenStream {
 pub fn derive_array_cast(tokens: TokenStream) -> TokenStream {
     syn_try!(cast::derive_array_cast(tokens))
 }
+
+#[proc_macro_derive(Mix, attributes(palette))]
+pub fn derive_mix(tokens: TokenStream) -> TokenStream {
+    syn_try!(mix::derive_mix(tokens))
+}
+
+#[proc_macro_derive(ColorDiff, attributes(palette))]
+pub fn derive_color_diff(tokens: TokenStream) -> TokenStream {
+    syn_try!(diff::derive_color_diff(tokens))
+}
@@ -10,6 +10,10 @@ pub struct FieldAttributes {
     pub alpha_property: Option<(IdentOrIndex, Type)>,
     pub zero_size_fields: HashSet<IdentOrIndex>,
     pub type_substitutes: HashMap<IdentOrIndex, Type>,
+    pub mix_spaces: HashMap<IdentOrIndex, Type>,
+    pub mix_skipped: HashSet<IdentOrIndex>,
+    pub diff_skipped: HashSet<IdentOrIndex>,
+    pub diff_nested: HashSet<IdentOrIndex>,
 }
 
 impl FieldAttributeArgumentParser for FieldAttributes {
@@ -37,6 +41,34 @@ impl FieldAttributeArgumentParser for FieldAttributes {
 
                 self.type_substitutes.insert(field_name.clone(), substitute);
             }
+            Some("mix_in") => {
+                let space = if let Meta::NameValue(MetaNameValue {
+                    lit: Lit::Str(string),
+                    ..
+                }) = argument
+                {
+                    string.parse()?
+                } else {
+                    return Err(::syn::parse::Error::new(
+                        argument.span(),
+                        "expected `mix_in = \"SomeType\"`",
+                    ));
+                };
+
+                self.mix_spaces.insert(field_name.clone(), space);
+            }
+            Some("mix_skip") => {
+                assert_path_meta(&argument)?;
+                self.mix_skipped.insert(field_name.clone());
+            }
+            Some("diff_skip") => {
+                assert_path_meta(&argument)?;
+                self.diff_skipped.insert(field_name.clone());
+            }
+            Some("diff_nested") => {
+                assert_path_meta(&argument)?;
+                self.diff_nested.insert(field_name.clone());
+            }
             Some("unsafe_zero_sized") => {
                 assert_path_meta(&argument)?;
                 self.zero_size_fields.insert(field_name.clone());
@@ -0,0 +1,110 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+
+use quote::quote;
+use syn::{parse_quote, DeriveInput, Type};
+
+use crate::meta::{self, IdentOrIndex, TypeItemAttributes};
+use crate::util;
+
+pub fn derive(tokens: TokenStream) -> std::result::Result<TokenStream, Vec<syn::Error>> {
+    let DeriveInput {
+        ident,
+        attrs,
+        mut generics,
+        data,
+        ..
+    } = syn::parse(tokens).map_err(|error| vec![error])?;
+
+    let item_meta: TypeItemAttributes = meta::parse_namespaced_attributes(attrs)?;
+
+    let (all_fields, fields_meta) = util::struct_fields(data, "Mix")?;
+
+    let component: Type = item_meta
+        .component
+        .clone()
+        .unwrap_or_else(|| parse_quote!(f32));
+    let mix_trait_path = util::path(["Mix"], item_meta.internal);
+    let from_color_path = util::path(["convert", "FromColor"], item_meta.internal);
+    let into_color_path = util::path(["convert", "IntoColor"], item_meta.internal);
+    let float_component_path = util::path(["FloatComponent"], item_meta.internal);
+    let from_f64_path = util::path(["FromF64"], item_meta.internal);
+
+    let mut has_scalars = false;
+    let mut predicates: Vec<syn::WherePredicate> = Vec::new();
+    let mut field_values = Vec::new();
+
+    for (index, field) in all_fields.into_iter().enumerate() {
+        let name = field
+            .ident
+            .map(IdentOrIndex::Ident)
+            .unwrap_or_else(|| IdentOrIndex::Index(index.into()));
+        let ty = field.ty;
+
+        let value = if fields_meta.mix_skipped.contains(&name) {
+            quote!(self.#name)
+        } else if let Some(space) = fields_meta.mix_spaces.get(&name) {
+            predicates.push(parse_quote!(#ty: #into_color_path<#space> + #from_color_path<#space>));
+            predicates.push(parse_quote!(#space: #mix_trait_path<Scalar = #component>));
+
+            quote! {
+                <#ty as #from_color_path<#space>>::from_color(#mix_trait_path::mix(
+                    <#ty as #into_color_path<#space>>::into_color(self.#name),
+                    <#ty as #into_color_path<#space>>::into_color(other.#name),
+                    factor,
+                ))
+            }
+        } else if is_same_type(&ty, &component) {
+            has_scalars = true;
+            quote!(self.#name + (other.#name - self.#name) * scalar_factor)
+        } else {
+            predicates.push(parse_quote!(#ty: #mix_trait_path<Scalar = #component>));
+            quote!(#mix_trait_path::mix(self.#name, other.#name, factor))
+        };
+
+        field_values.push(quote!(#name: #value));
+    }
+
+    let scalar_factor = if has_scalars {
+        predicates.push(parse_quote!(#component: #float_component_path));
+
+        quote! {
+            let zero = <#component as #from_f64_path>::from_f64(0.0);
+            let one = <#component as #from_f64_path>::from_f64(1.0);
+            let scalar_factor = if factor < zero {
+                zero
+            } else if factor > one {
+                one
+            } else {
+                factor
+            };
+        }
+    } else {
+        TokenStream2::new()
+    };
+
+    generics.make_where_clause().predicates.extend(predicates);
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    let implementation = quote! {
+        #[automatically_derived]
+        impl #impl_generics #mix_trait_path for #ident #type_generics #where_clause {
+            type Scalar = #component;
+
+            #[inline]
+            fn mix(self, other: Self, factor: #component) -> Self {
+                #scalar_factor
+
+                #ident {
+                    #(#field_values,)*
+                }
+            }
+        }
+    };
+
+    Ok(implementation.into())
+}
+
+fn is_same_type(a: &Type, b: &Type) -> bool {
+    quote!(#a).to_string() == quote!(#b).to_string()
+}
@@ -0,0 +1,3 @@
+pub use self::mix_fields::derive as derive_mix;
+
+mod mix_fields;
@@ -1,6 +1,10 @@
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::{parse_quote, Ident, Type};
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{parse_quote, Data, Field, Fields, Ident, Type};
+
+use crate::meta::{self, FieldAttributes};
 
 pub fn path<'a, P: AsRef<[&'a str]>>(path: P, internal: bool) -> TokenStream {
     let path = path
@@ -29,6 +33,35 @@ pub fn path_type(path: &[&str], internal: bool) -> Type {
     }
 }
 
+/// Get the fields of a struct and their attributes, or an error if `data` is
+/// an enum or a union, which `derive_name` can't be derived for.
+pub fn struct_fields(
+    data: Data,
+    derive_name: &str,
+) -> Result<(Punctuated<Field, Comma>, FieldAttributes), Vec<syn::Error>> {
+    match data {
+        Data::Struct(struct_item) => {
+            let fields_meta: FieldAttributes =
+                meta::parse_field_attributes(struct_item.fields.clone())?;
+            let all_fields = match struct_item.fields {
+                Fields::Named(fields) => fields.named,
+                Fields::Unnamed(fields) => fields.unnamed,
+                Fields::Unit => Default::default(),
+            };
+
+            Ok((all_fields, fields_meta))
+        }
+        Data::Enum(_) => Err(vec![syn::Error::new(
+            Span::call_site(),
+            format!("`{}` cannot be derived for enums", derive_name),
+        )]),
+        Data::Union(_) => Err(vec![syn::Error::new(
+            Span::call_site(),
+            format!("`{}` cannot be derived for unions", derive_name),
+        )]),
+    }
+}
+
 pub fn color_path(color: &str, internal: bool) -> TokenStream {
     match color {
         "Luma" => path(["luma", "Luma"], internal),
@@ -49,3 +82,58 @@ fn find_crate_name() -> Ident {
         ),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use syn::DeriveInput;
+
+    use super::struct_fields;
+
+    fn error_message(input: DeriveInput, derive_name: &str) -> String {
+        match struct_fields(input.data, derive_name) {
+            Ok(_) => panic!("expected an error"),
+            Err(errors) => errors[0].to_string(),
+        }
+    }
+
+    #[test]
+    fn struct_fields_named_and_tuple() {
+        let input: DeriveInput = syn::parse_quote!(
+            struct Named {
+                a: f32,
+                b: f32,
+            }
+        );
+        let (fields, _) = struct_fields(input.data, "Mix").unwrap();
+        assert_eq!(fields.len(), 2);
+
+        let input: DeriveInput = syn::parse_quote!(
+            struct Tuple(f32);
+        );
+        let (fields, _) = struct_fields(input.data, "Mix").unwrap();
+        assert_eq!(fields.len(), 1);
+    }
+
+    #[test]
+    fn struct_fields_rejects_enums_and_unions() {
+        let input: DeriveInput = syn::parse_quote!(
+            enum Enum {
+                A,
+            }
+        );
+        assert_eq!(
+            error_message(input, "Mix"),
+            "`Mix` cannot be derived for enums"
+        );
+
+        let input: DeriveInput = syn::parse_quote!(
+            union Union {
+                a: f32,
+            }
+        );
+        assert_eq!(
+            error_message(input, "ColorDiff"),
+            "`ColorDiff` cannot be derived for unions"
+        );
+    }
+}
@@ -0,0 +1,360 @@
+//! A C compatible interface for the most common color types in
+//! [`palette`].
+//!
+//! The types in this crate are plain `#[repr(C)]` structs without type
+//! parameters, and the functions use the C calling convention and unmangled
+//! names, so they can be called from C, C++ or any other language with a C
+//! FFI. All functions are prefixed with `palette_`.
+//!
+//! A C header can be generated with
+//! [`cbindgen`](https://github.com/eqrion/cbindgen):
+//!
+//! ```text
+//! cbindgen --lang c --crate palette_ffi --output palette.h
+//! ```
+//!
+//! The crate is built as a `staticlib` and a `cdylib`, to be linked into a C
+//! program. The unmangled symbols are only defined here, and not in
+//! `palette` itself, so libraries that depend on different versions of
+//! `palette` can still be linked into the same binary.
+//!
+//! The FFI types have the same layout as their generic counterparts, so
+//! buffers can be reinterpreted using the [`cast`](palette::cast) module, or
+//! on the C side:
+//!
+//! | FFI type | Rust type |
+//! |---|---|
+//! | [`PaletteSrgb8`] | `Srgb<u8>` |
+//! | [`PaletteLinSrgbF32`] | `LinSrgb<f32>` |
+//! | [`PaletteLab`] | `Lab<D65, f32>` |
+//! | [`PaletteOklab`] | `Oklab<f32>` |
+
+#![doc(html_root_url = "https://docs.rs/palette_ffi/0.6.0/palette_ffi/")]
+#![warn(missing_docs)]
+
+#[cfg(test)]
+#[macro_use]
+extern crate approx;
+
+use palette::convert::FromColorUnclamped;
+use palette::white_point::D65;
+use palette::{FromColor, Lab, LinSrgb, Oklab, Srgb};
+
+/// 8 bit sRGB, with red, green and blue in `[0, 255]`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PaletteSrgb8 {
+    /// The amount of red light.
+    pub red: u8,
+    /// The amount of green light.
+    pub green: u8,
+    /// The amount of blue light.
+    pub blue: u8,
+}
+
+/// Linear sRGB with `f32` components, where `[0.0, 1.0]` is the displayable
+/// range.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PaletteLinSrgbF32 {
+    /// The amount of red light.
+    pub red: f32,
+    /// The amount of green light.
+    pub green: f32,
+    /// The amount of blue light.
+    pub blue: f32,
+}
+
+/// CIE L\*a\*b\* with the D65 white point and `f32` components.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PaletteLab {
+    /// L\* is the lightness of the color, from `0.0` to `100.0`.
+    pub l: f32,
+    /// a\* goes from red = 127 to green = -128.
+    pub a: f32,
+    /// b\* goes from yellow = 127 to blue = -128.
+    pub b: f32,
+}
+
+/// Oklab with `f32` components.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PaletteOklab {
+    /// L is the lightness of the color, from `0.0` to `1.0`.
+    pub l: f32,
+    /// a goes from green to red.
+    pub a: f32,
+    /// b goes from blue to yellow.
+    pub b: f32,
+}
+
+impl From<Srgb<u8>> for PaletteSrgb8 {
+    fn from(color: Srgb<u8>) -> Self {
+        PaletteSrgb8 {
+            red: color.red,
+            green: color.green,
+            blue: color.blue,
+        }
+    }
+}
+
+impl From<PaletteSrgb8> for Srgb<u8> {
+    fn from(color: PaletteSrgb8) -> Self {
+        Srgb::new(color.red, color.green, color.blue)
+    }
+}
+
+impl From<LinSrgb<f32>> for PaletteLinSrgbF32 {
+    fn from(color: LinSrgb<f32>) -> Self {
+        PaletteLinSrgbF32 {
+            red: color.red,
+            green: color.green,
+            blue: color.blue,
+        }
+    }
+}
+
+impl From<PaletteLinSrgbF32> for LinSrgb<f32> {
+    fn from(color: PaletteLinSrgbF32) -> Self {
+        LinSrgb::new(color.red, color.green, color.blue)
+    }
+}
+
+impl From<Lab<D65, f32>> for PaletteLab {
+    fn from(color: Lab<D65, f32>) -> Self {
+        PaletteLab {
+            l: color.l,
+            a: color.a,
+            b: color.b,
+        }
+    }
+}
+
+impl From<PaletteLab> for Lab<D65, f32> {
+    fn from(color: PaletteLab) -> Self {
+        Lab::new(color.l, color.a, color.b)
+    }
+}
+
+impl From<Oklab<f32>> for PaletteOklab {
+    fn from(color: Oklab<f32>) -> Self {
+        PaletteOklab {
+            l: color.l,
+            a: color.a,
+            b: color.b,
+        }
+    }
+}
+
+impl From<PaletteOklab> for Oklab<f32> {
+    fn from(color: PaletteOklab) -> Self {
+        Oklab::new(color.l, color.a, color.b)
+    }
+}
+
+/// Decode 8 bit sRGB to linear sRGB.
+#[no_mangle]
+pub extern "C" fn palette_srgb8_to_lin_srgb(color: PaletteSrgb8) -> PaletteLinSrgbF32 {
+    Srgb::<u8>::from(color)
+        .into_format::<f32>()
+        .into_linear()
+        .into()
+}
+
+/// Encode linear sRGB as 8 bit sRGB. Out-of-range colors are clamped.
+#[no_mangle]
+pub extern "C" fn palette_lin_srgb_to_srgb8(color: PaletteLinSrgbF32) -> PaletteSrgb8 {
+    Srgb::<f32>::from_color(LinSrgb::from(color))
+        .into_format::<u8>()
+        .into()
+}
+
+/// Convert 8 bit sRGB to CIE L\*a\*b\*.
+#[no_mangle]
+pub extern "C" fn palette_srgb8_to_lab(color: PaletteSrgb8) -> PaletteLab {
+    Lab::from_color_unclamped(Srgb::<u8>::from(color).into_format::<f32>()).into()
+}
+
+/// Convert CIE L\*a\*b\* to 8 bit sRGB. Out-of-gamut colors are clamped.
+#[no_mangle]
+pub extern "C" fn palette_lab_to_srgb8(color: PaletteLab) -> PaletteSrgb8 {
+    Srgb::<f32>::from_color(Lab::from(color))
+        .into_format::<u8>()
+        .into()
+}
+
+/// Convert 8 bit sRGB to Oklab.
+#[no_mangle]
+pub extern "C" fn palette_srgb8_to_oklab(color: PaletteSrgb8) -> PaletteOklab {
+    Oklab::from_color_unclamped(Srgb::<u8>::from(color).into_format::<f32>()).into()
+}
+
+/// Convert Oklab to 8 bit sRGB. Out-of-gamut colors are clamped.
+#[no_mangle]
+pub extern "C" fn palette_oklab_to_srgb8(color: PaletteOklab) -> PaletteSrgb8 {
+    Srgb::<f32>::from_color(Oklab::from(color))
+        .into_format::<u8>()
+        .into()
+}
+
+/// Convert linear sRGB to CIE L\*a\*b\*.
+#[no_mangle]
+pub extern "C" fn palette_lin_srgb_to_lab(color: PaletteLinSrgbF32) -> PaletteLab {
+    Lab::from_color_unclamped(LinSrgb::from(color)).into()
+}
+
+/// Convert CIE L\*a\*b\* to linear sRGB, without clamping.
+#[no_mangle]
+pub extern "C" fn palette_lab_to_lin_srgb(color: PaletteLab) -> PaletteLinSrgbF32 {
+    LinSrgb::from_color_unclamped(Lab::from(color)).into()
+}
+
+/// Convert linear sRGB to Oklab.
+#[no_mangle]
+pub extern "C" fn palette_lin_srgb_to_oklab(color: PaletteLinSrgbF32) -> PaletteOklab {
+    Oklab::from_color_unclamped(LinSrgb::from(color)).into()
+}
+
+/// Convert Oklab to linear sRGB, without clamping.
+#[no_mangle]
+pub extern "C" fn palette_oklab_to_lin_srgb(color: PaletteOklab) -> PaletteLinSrgbF32 {
+    LinSrgb::from_color_unclamped(Oklab::from(color)).into()
+}
+
+/// Convert a buffer of 8 bit sRGB colors to Oklab.
+///
+/// # Safety
+///
+/// `input` has to point to `len` readable colors and `output` has to point
+/// to `len` writable colors. The two buffers must not overlap. Nothing is
+/// done if either pointer is null.
+#[no_mangle]
+pub unsafe extern "C" fn palette_srgb8_to_oklab_buffer(
+    input: *const PaletteSrgb8,
+    output: *mut PaletteOklab,
+    len: usize,
+) {
+    if input.is_null() || output.is_null() {
+        return;
+    }
+
+    let input = core::slice::from_raw_parts(input, len);
+    let output = core::slice::from_raw_parts_mut(output, len);
+
+    for (&color, out) in input.iter().zip(output) {
+        *out = palette_srgb8_to_oklab(color);
+    }
+}
+
+/// Convert a buffer of Oklab colors to 8 bit sRGB. Out-of-gamut colors are
+/// clamped.
+///
+/// # Safety
+///
+/// `input` has to point to `len` readable colors and `output` has to point
+/// to `len` writable colors. The two buffers must not overlap. Nothing is
+/// done if either pointer is null.
+#[no_mangle]
+pub unsafe extern "C" fn palette_oklab_to_srgb8_buffer(
+    input: *const PaletteOklab,
+    output: *mut PaletteSrgb8,
+    len: usize,
+) {
+    if input.is_null() || output.is_null() {
+        return;
+    }
+
+    let input = core::slice::from_raw_parts(input, len);
+    let output = core::slice::from_raw_parts_mut(output, len);
+
+    for (&color, out) in input.iter().zip(output) {
+        *out = palette_oklab_to_srgb8(color);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::mem::{align_of, size_of};
+
+    use super::*;
+
+    #[test]
+    fn layout() {
+        assert_eq!(size_of::<PaletteSrgb8>(), size_of::<Srgb<u8>>());
+        assert_eq!(align_of::<PaletteSrgb8>(), align_of::<Srgb<u8>>());
+        assert_eq!(size_of::<PaletteLinSrgbF32>(), size_of::<LinSrgb<f32>>());
+        assert_eq!(align_of::<PaletteLinSrgbF32>(), align_of::<LinSrgb<f32>>());
+        assert_eq!(size_of::<PaletteLab>(), size_of::<Lab<D65, f32>>());
+        assert_eq!(align_of::<PaletteLab>(), align_of::<Lab<D65, f32>>());
+        assert_eq!(size_of::<PaletteOklab>(), size_of::<Oklab<f32>>());
+        assert_eq!(align_of::<PaletteOklab>(), align_of::<Oklab<f32>>());
+    }
+
+    #[test]
+    fn round_trips() {
+        let color = PaletteSrgb8 {
+            red: 12,
+            green: 200,
+            blue: 99,
+        };
+
+        let linear = palette_srgb8_to_lin_srgb(color);
+        assert_eq!(palette_lin_srgb_to_srgb8(linear), color);
+        assert_eq!(palette_lab_to_srgb8(palette_srgb8_to_lab(color)), color);
+        assert_eq!(palette_oklab_to_srgb8(palette_srgb8_to_oklab(color)), color);
+
+        let lab = palette_lin_srgb_to_lab(linear);
+        assert_relative_eq!(
+            LinSrgb::from(palette_lab_to_lin_srgb(lab)),
+            LinSrgb::from(linear),
+            epsilon = 0.0001
+        );
+
+        let oklab = palette_lin_srgb_to_oklab(linear);
+        assert_relative_eq!(
+            LinSrgb::from(palette_oklab_to_lin_srgb(oklab)),
+            LinSrgb::from(linear),
+            epsilon = 0.0001
+        );
+    }
+
+    #[test]
+    fn matches_generic_conversion() {
+        let color = Srgb::new(255u8, 128, 0);
+        let expected: Oklab<f32> = Oklab::from_color(color.into_format::<f32>());
+        let computed = Oklab::from(palette_srgb8_to_oklab(color.into()));
+
+        assert_relative_eq!(computed, expected);
+    }
+
+    #[test]
+    fn buffers() {
+        let input = [
+            PaletteSrgb8 {
+                red: 255,
+                green: 0,
+                blue: 0,
+            },
+            PaletteSrgb8 {
+                red: 0,
+                green: 40,
+                blue: 80,
+            },
+        ];
+        let mut oklab = [PaletteOklab::default(); 2];
+        let mut output = [PaletteSrgb8::default(); 2];
+
+        unsafe {
+            palette_srgb8_to_oklab_buffer(input.as_ptr(), oklab.as_mut_ptr(), input.len());
+            palette_oklab_to_srgb8_buffer(oklab.as_ptr(), output.as_mut_ptr(), oklab.len());
+        }
+
+        assert_eq!(oklab[0], palette_srgb8_to_oklab(input[0]));
+        assert_eq!(output, input);
+
+        unsafe {
+            palette_srgb8_to_oklab_buffer(core::ptr::null(), oklab.as_mut_ptr(), 2);
+        }
+    }
+}
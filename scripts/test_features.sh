@@ -43,3 +43,15 @@ for feature in $features; do
 	echo testing with --no-default-features --features "\"$feature $required_features\""
 	cargo test --no-default-features --features "$feature $required_features"
 done
+
+#Test the float operations without std, using libm and palette's own math
+for float_features in "libm" "libm deterministic"; do
+	echo testing with --no-default-features --features "\"$float_features\""
+	cargo test --no-default-features --features "$float_features" --lib --tests
+done
+
+#Run the no_std conversions with both float backends
+for no_std_features in "" "deterministic"; do
+	echo testing no_std_test with --features "\"$no_std_features\""
+	cargo test --manifest-path ../no_std_test/Cargo.toml --features "$no_std_features"
+done